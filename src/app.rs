@@ -547,6 +547,25 @@ impl ApplicationInner {
             let w = app.runtime.project.borrow().new_property_window(&app, false);
             w.present();
         }));
+        let project_font_info = gtk::gio::SimpleAction::new("project.font-info", None);
+        project_font_info.connect_activate(glib::clone!(@weak obj as app => move |_, _| {
+            crate::views::font_info::show_font_info_window(&app, &app.runtime.project.borrow());
+        }));
+        let project_vertical_metrics =
+            gtk::gio::SimpleAction::new("project.vertical-metrics", None);
+        project_vertical_metrics.connect_activate(glib::clone!(@weak obj as app => move |_, _| {
+            crate::views::vertical_metrics::show_vertical_metrics_window(
+                &app,
+                &app.runtime.project.borrow(),
+            );
+        }));
+        let project_outline_search = gtk::gio::SimpleAction::new("project.outline-search", None);
+        project_outline_search.connect_activate(glib::clone!(@weak obj as app => move |_, _| {
+            crate::views::outline_search::show_outline_search_window(
+                &app,
+                &app.runtime.project.borrow(),
+            );
+        }));
         let project_save = gtk::gio::SimpleAction::new("project.save", None);
         project_save.connect_activate(
             glib::clone!(@weak self.window as window, @weak obj as app => move |_, _| {
@@ -586,6 +605,69 @@ impl ApplicationInner {
                 dialog.emit_close();
             }
         }));
+        let project_compare = gtk::gio::SimpleAction::new("project.compare", None);
+        project_compare.connect_activate(
+            glib::clone!(@weak self.window as window, @weak obj as app => move |_, _| {
+                let dialog = gtk::FileChooserNative::new(
+                    Some("Compare with font.ufo directory..."),
+                    Some(&window),
+                    gtk::FileChooserAction::SelectFolder,
+                    None,
+                    None
+                );
+                crate::return_if_not_ok_or_accept!(dialog.run());
+                dialog.hide();
+
+                let Some(path) = dialog.filename() else { return; };
+                let project = app.runtime.project.borrow().clone();
+                crate::views::glyph_diff::compare_with_ufo(&app, &project, &path);
+            }),
+        );
+        for (action_name, quadratic) in [
+            ("project.convert.cubic", false),
+            ("project.convert.quadratic", true),
+        ] {
+            let convert = gtk::gio::SimpleAction::new(action_name, None);
+            convert.connect_activate(
+                glib::clone!(@weak self.window as window, @weak obj as app => move |_, _| {
+                    let tolerance = if quadratic {
+                        match crate::utils::widgets::ask_number(
+                            Some("Convert layer to quadratic curves"),
+                            "Maximum approximation error in font units:",
+                            1.0,
+                            (0.01, 100.0),
+                            window.upcast_ref(),
+                        ) {
+                            Some(v) => Some(v),
+                            None => return,
+                        }
+                    } else {
+                        None
+                    };
+                    let project = app.runtime.project.borrow().clone();
+                    let layer = project.default_layer.clone();
+                    let glyphs = layer.glyphs().values().cloned().collect::<Vec<_>>();
+                    let mut action = crate::glyphs::Glyph::convert_curves(
+                        glyphs,
+                        tolerance,
+                        Rc::new(glib::clone!(@weak project => move |glyph: &Rc<RefCell<crate::glyphs::Glyph>>| {
+                            project.glyph_changed(glyph);
+                        })),
+                    );
+                    (action.redo)();
+                    app.undo_db.borrow_mut().event(action);
+                }),
+            );
+            application.add_action(&convert);
+        }
+        #[cfg(feature = "git")]
+        {
+            let project_git = gtk::gio::SimpleAction::new("project.git", None);
+            project_git.connect_activate(glib::clone!(@weak self.window as window => move |_, _| {
+                window.open_git_tab();
+            }));
+            application.add_action(&project_git);
+        }
         let bug_report = gtk::gio::SimpleAction::new("bug_report", None);
         let app = application.clone();
         bug_report.connect_activate(move |_, _| {
@@ -603,7 +685,11 @@ impl ApplicationInner {
             }
         });
         application.add_action(&project_properties);
+        application.add_action(&project_font_info);
+        application.add_action(&project_vertical_metrics);
+        application.add_action(&project_outline_search);
         application.add_action(&project_save);
+        application.add_action(&project_compare);
         application.add_action(&project_export);
         application.add_action(&import_glyphs);
         application.add_action(&import_ufo2);
@@ -674,6 +760,30 @@ impl ApplicationInner {
             file_menu.append(Some("_Export"), Some("app.project.export"));
             let project_section = gio::Menu::new();
             project_section.append(Some("_Properties"), Some("app.project.properties"));
+            project_section.append(Some("_Font info"), Some("app.project.font-info"));
+            project_section.append(
+                Some("_Vertical metrics"),
+                Some("app.project.vertical-metrics"),
+            );
+            project_section.append(
+                Some("Find and _replace outlines"),
+                Some("app.project.outline-search"),
+            );
+            project_section.append(Some("_Compare with..."), Some("app.project.compare"));
+            let convert_menu = gio::Menu::new();
+            convert_menu.append(
+                Some("Convert to _cubic curves"),
+                Some("app.project.convert.cubic"),
+            );
+            convert_menu.append(
+                Some("Convert to _quadratic curves"),
+                Some("app.project.convert.quadratic"),
+            );
+            project_section.append_submenu(Some("Con_vert outlines"), &convert_menu);
+            #[cfg(feature = "git")]
+            {
+                project_section.append(Some("_Version control"), Some("app.project.git"));
+            }
             #[cfg(feature = "python")]
             {
                 project_section.append(Some("Open Python Shell"), Some("app.shell"));
@@ -563,6 +563,72 @@ impl SettingsInner {
         self.file.borrow().as_ref().map(|(p, _)| p.to_path_buf())
     }
 
+    /// Saved glyph collection searches, by name.
+    pub fn saved_searches(&self) -> IndexMap<String, String> {
+        self.document
+            .borrow()
+            .get(Settings::SAVED_SEARCHES)
+            .and_then(TomlItem::as_table_like)
+            .map(|t| {
+                t.iter()
+                    .filter_map(|(k, v)| Some((k.to_string(), v.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn set_saved_searches(
+        &self,
+        searches: &IndexMap<String, String>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        {
+            let mut table = toml_edit::Table::new();
+            for (name, query) in searches {
+                table.insert(name, toml_value(query.as_str()));
+            }
+            self.document.borrow_mut()[Settings::SAVED_SEARCHES] = TomlItem::Table(table);
+        }
+        self.save_settings()
+    }
+
+    /// Saved layout of the glyph collection of the project at `project_path`.
+    pub fn collection_layout(
+        &self,
+        project_path: &Path,
+    ) -> Option<crate::views::collection::CollectionLayout> {
+        let table = self
+            .document
+            .borrow()
+            .get(Settings::COLLECTION_LAYOUTS)?
+            .as_table()?
+            .get(&project_path.display().to_string())?
+            .as_table()?
+            .clone();
+        toml_edit::de::from_document(Document::from(table))
+            .ok()
+            .map(crate::views::collection::CollectionLayout::normalized)
+    }
+
+    pub fn set_collection_layout(
+        &self,
+        project_path: &Path,
+        layout: &crate::views::collection::CollectionLayout,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        {
+            let table = toml_edit::ser::to_document(layout)?.as_table().clone();
+            let mut document = self.document.borrow_mut();
+            if !document.contains_table(Settings::COLLECTION_LAYOUTS) {
+                let mut layouts = toml_edit::Table::new();
+                layouts.set_implicit(true);
+                document[Settings::COLLECTION_LAYOUTS] = TomlItem::Table(layouts);
+            }
+            if let Some(layouts) = document[Settings::COLLECTION_LAYOUTS].as_table_mut() {
+                layouts.insert(&project_path.display().to_string(), TomlItem::Table(table));
+            }
+        }
+        self.save_settings()
+    }
+
     fn friendly_name(&self, obj: &glib::Object) -> String {
         if let Some(name) = self
             .obj_entries
@@ -610,6 +676,8 @@ impl Settings {
     pub const WARP_CURSOR: &str = "warp-cursor";
     pub const MARK_COLOR: &str = "mark-color";
     pub const SHOW_PRERELEASE_WARNING: &str = "show-prerelease-warning";
+    pub const SAVED_SEARCHES: &str = "saved-searches";
+    pub const COLLECTION_LAYOUTS: &str = "collection-layouts";
 
     pub fn new() -> Self {
         glib::Object::new::<Self>(&[]).unwrap()
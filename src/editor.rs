@@ -32,14 +32,22 @@ use crate::views::{
     overlay::Child,
 };
 
+mod align;
+mod clipboard;
+mod corners;
+mod glyph_layers;
 mod layers;
 mod menu;
 mod settings;
 mod shortcuts;
 mod state;
+mod stroke;
+mod text;
 mod tools;
+pub use corners::CornerSettings;
 pub use settings::EditorSettings;
 pub use state::State;
+pub use stroke::StrokeSettings;
 
 use tools::{PanningTool, SelectionModifier, Tool, ToolImpl};
 
@@ -49,7 +57,13 @@ type StatusBarMessage = u32;
 pub struct EditorInner {
     app: OnceCell<Application>,
     project: OnceCell<Project>,
-    glyph: OnceCell<Rc<RefCell<Glyph>>>,
+    glyph: RefCell<Option<Rc<RefCell<Glyph>>>>,
+    text: RefCell<Vec<Rc<RefCell<Glyph>>>>,
+    active_index: Cell<usize>,
+    text_entry: gtk::Entry,
+    active_layer: RefCell<Option<ufo::objects::Layer>>,
+    background_layers: RefCell<Vec<ufo::objects::Layer>>,
+    layer_list: gtk::ListBox,
     state: OnceCell<Rc<RefCell<State>>>,
     viewport: Canvas,
     statusbar_context_id: Cell<Option<u32>>,
@@ -77,6 +91,9 @@ pub struct EditorInner {
     precision: Cell<(Option<StatusBarMessage>, tools::constraints::Precision)>,
     shortcuts: shortcuts::Shortcuts,
     shortcut_status: gtk::Box,
+    stroke: StrokeSettings,
+    corners: CornerSettings,
+    transform_panel: OnceCell<gtk::Window>,
 }
 
 #[glib::object_subclass]
@@ -91,6 +108,8 @@ impl ObjectImpl for EditorInner {
         self.parent_constructed(obj);
         self.lock_guidelines.set(false);
         self.setup_shortcuts(obj);
+        self.setup_clipboard(obj);
+        self.setup_align(obj);
         self.shortcuts.rebuild();
         self.show_glyph_guidelines.set(true);
         self.show_project_guidelines.set(true);
@@ -110,6 +129,15 @@ impl ObjectImpl for EditorInner {
 
         self.viewport.connect_button_press_event(
             clone!(@weak obj => @default-return Inhibit(false), move |viewport, event| {
+                if event.button() == gtk::gdk::BUTTON_PRIMARY
+                    && event.event_type() == gtk::gdk::EventType::ButtonPress
+                {
+                    let position = viewport.view_to_unit_point(ViewPoint(event.position().into()));
+                    if let Some(index) = obj.text_index_at(position) {
+                        obj.set_active_glyph(index);
+                        return Inhibit(true);
+                    }
+                }
                 let prev_mouse = viewport.get_mouse();
                 let retval = Tool::on_button_press_event(obj, viewport, event);
                 if retval == Inhibit(true) {
@@ -156,6 +184,26 @@ impl ObjectImpl for EditorInner {
             }),
         );
 
+        self.viewport.add_layer(
+            LayerBuilder::new()
+                .set_name(Some("background"))
+                .set_active(true)
+                .set_hidden(false)
+                .set_callback(Some(Box::new(clone!(@weak obj => @default-return Inhibit(false), move |viewport: &Canvas, mut cr: ContextRef<'_, '_>| {
+                    glyph_layers::draw_background_layers(viewport, cr.push(), obj)
+                }))))
+                .build(),
+        );
+        self.viewport.add_layer(
+            LayerBuilder::new()
+                .set_name(Some("text"))
+                .set_active(true)
+                .set_hidden(false)
+                .set_callback(Some(Box::new(clone!(@weak obj => @default-return Inhibit(false), move |viewport: &Canvas, mut cr: ContextRef<'_, '_>| {
+                    text::draw_text_layer(viewport, cr.push(), obj)
+                }))))
+                .build(),
+        );
         self.viewport.add_layer(
             LayerBuilder::new()
                 .set_name(Some("glyph"))
@@ -166,6 +214,22 @@ impl ObjectImpl for EditorInner {
                 }))))
                 .build(),
         );
+        self.viewport.add_layer(
+            LayerBuilder::new()
+                .set_name(Some("stroke preview"))
+                .set_active(true)
+                .set_hidden(false)
+                .set_callback(Some(Box::new(clone!(@weak obj => @default-return Inhibit(false), move |viewport: &Canvas, mut cr: ContextRef<'_, '_>| {
+                    layers::draw_stroke_preview(viewport, cr.push(), obj)
+                }))))
+                .build(),
+        );
+        self.stroke.connect_notify_local(
+            None,
+            clone!(@weak self.viewport as viewport => move |_, _| {
+                viewport.queue_draw();
+            }),
+        );
         self.viewport.add_pre_layer(
             LayerBuilder::new()
                 .set_name(Some("guidelines"))
@@ -189,6 +253,11 @@ impl ObjectImpl for EditorInner {
             .add_overlay(Child::new(self.toolbar_box.clone()));
         self.overlay
             .add_overlay(Child::new(self.create_layer_widget()).expanded(false));
+        self.setup_text_entry(obj);
+        self.overlay
+            .add_overlay(Child::new(self.text_entry.clone()).expandable(false));
+        self.overlay
+            .add_overlay(Child::new(self.create_glyph_layers_widget(obj)).expanded(false));
         obj.add(&self.overlay);
         obj.set_visible(true);
         obj.set_expand(true);
@@ -336,7 +405,24 @@ impl ObjectImpl for EditorInner {
                     .get()
                     .map(|s| s.borrow().glyph.borrow().name_markup())
                 {
-                    format!("edit <i>{}</i>", name).to_value()
+                    match self.active_layer.borrow().as_ref() {
+                        Some(layer)
+                            if self
+                                .project
+                                .get()
+                                .map_or(false, |p| *layer != p.default_layer) =>
+                        {
+                            format!(
+                                "edit <i>{}</i> ({})",
+                                name,
+                                glib::markup_escape_text(
+                                    &layer.property::<String>(ufo::objects::Layer::NAME)
+                                )
+                            )
+                            .to_value()
+                        }
+                        _ => format!("edit <i>{}</i>", name).to_value(),
+                    }
                 } else {
                     "edit glyph".to_value()
                 }
@@ -506,8 +592,8 @@ impl EditorInner {
         self.project.get().unwrap()
     }
 
-    pub fn glyph(&self) -> &Rc<RefCell<Glyph>> {
-        self.glyph.get().unwrap()
+    pub fn glyph(&self) -> Rc<RefCell<Glyph>> {
+        self.glyph.borrow().clone().unwrap()
     }
 
     pub fn app_settings(&self) -> &Settings {
@@ -518,7 +604,7 @@ impl EditorInner {
         let statusbar = self.app().statusbar();
         if self.statusbar_context_id.get().is_none() {
             self.statusbar_context_id.set(Some(
-                statusbar.context_id(&format!("Editor-{:?}", &self.glyph.get().unwrap())),
+                statusbar.context_id(&format!("Editor-{:?}", &self.glyph())),
             ));
         }
         if let Some(cid) = self.statusbar_context_id.get().as_ref() {
@@ -575,7 +661,8 @@ impl Editor {
 
     pub fn new(app: Application, glyph: Rc<RefCell<Glyph>>) -> Self {
         let ret: Self = glib::Object::new(&[]).unwrap();
-        ret.glyph.set(glyph.clone()).unwrap();
+        *ret.glyph.borrow_mut() = Some(glyph.clone());
+        *ret.text.borrow_mut() = vec![glyph.clone()];
         ret.app.set(app.clone()).unwrap();
         let project = app.runtime.project.borrow().clone();
         ret.connect_map(|self_| {
@@ -667,7 +754,49 @@ impl Editor {
                 ret.viewport.clone(),
             ))))
             .expect("Failed to create glyph state");
+        for signal in [Project::GLYPH_RELOADED, Project::GLYPH_CHANGED] {
+            project.connect_local(
+                signal,
+                false,
+                clone!(@weak ret => @default-return None, move |v: &[gtk::glib::Value]| {
+                    let layer = v[1].get::<ufo::objects::Layer>().unwrap();
+                    let name = v[2].get::<String>().unwrap();
+                    let Some(glyph) = layer.glyphs().get(&name).cloned() else {
+                        return None;
+                    };
+                    if Rc::ptr_eq(&glyph, &ret.state().borrow().glyph) {
+                        ret.state().borrow_mut().reload();
+                        ret.viewport.queue_draw();
+                    } else if ret.text.borrow().iter().any(|g| Rc::ptr_eq(g, &glyph)) {
+                        ret.viewport.queue_draw();
+                    }
+                    None
+                }),
+            );
+        }
+        *ret.background_layers.borrow_mut() =
+            project.background_layer.borrow().iter().cloned().collect();
+        project.connect_local(
+            Project::LAYERS_CHANGED,
+            false,
+            clone!(@weak ret => @default-return None, move |_| {
+                let layers = ret.project().layers();
+                ret.background_layers
+                    .borrow_mut()
+                    .retain(|l| layers.contains(l));
+                if layers.contains(&ret.active_layer()) {
+                    ret.update_glyph_layers_widget();
+                    ret.notify(Self::TITLE);
+                } else {
+                    let default_layer = ret.project().default_layer.clone();
+                    ret.set_active_layer(&default_layer);
+                }
+                ret.viewport.queue_draw();
+                None
+            }),
+        );
         ret.project.set(project).unwrap();
+        ret.update_glyph_layers_widget();
         Tool::setup_toolbox(&ret, glyph);
         ret.setup_menu(&ret);
         ret
@@ -705,7 +834,10 @@ impl Editor {
         }
 
         let new = Rc::new(selection.to_vec());
-        let old = Rc::new(self.state().borrow().selection.clone());
+        let (old, glyph) = {
+            let state = self.state().borrow();
+            (Rc::new(state.selection.clone()), state.glyph.clone())
+        };
         let mut action = Action {
             stamp: EventStamp {
                 t: std::any::TypeId::of::<Self>(),
@@ -714,7 +846,11 @@ impl Editor {
             },
             compress: true,
             redo: Box::new(
-                clone!(@weak self as obj, @strong new, @strong old => move || {
+                clone!(@weak self as obj, @weak glyph, @strong new, @strong old => move || {
+                    if !Rc::ptr_eq(&obj.state().borrow().glyph, &glyph) {
+                        /* The selection belongs to a glyph that is no longer edited. */
+                        return;
+                    }
                     let State {
                         ref mut selection,
                         ref mut selection_set,
@@ -746,7 +882,11 @@ impl Editor {
                 }),
             ),
             undo: Box::new(
-                clone!(@weak self as obj, @strong new, @strong old => move || {
+                clone!(@weak self as obj, @weak glyph, @strong new, @strong old => move || {
+                    if !Rc::ptr_eq(&obj.state().borrow().glyph, &glyph) {
+                        /* The selection belongs to a glyph that is no longer edited. */
+                        return;
+                    }
                     let State {
                         ref mut selection,
                         ref mut selection_set,
@@ -769,4 +909,42 @@ impl Editor {
     pub fn state(&self) -> &Rc<RefCell<State>> {
         self.state.get().unwrap()
     }
+
+    /// Replaces the edited glyph, when switching to another glyph of the text line or to
+    /// another layer.
+    fn set_edited_glyph(&self, glyph: Rc<RefCell<Glyph>>) {
+        self.set_selection(&[], SelectionModifier::Replace);
+        *self.glyph.borrow_mut() = Some(glyph.clone());
+        {
+            let mut state = self.state().borrow_mut();
+            state.set_glyph(glyph.clone());
+            if let Some(tool) = state
+                .tools
+                .get(&tools::ImageTool::static_type())
+                .and_then(|t| t.downcast_ref::<tools::ImageTool>())
+            {
+                tool.set_glyph(glyph.clone(), self.project());
+            }
+        }
+        self.viewport.transformation.set_property(
+            Transformation::CONTENT_WIDTH,
+            glyph
+                .borrow()
+                .width()
+                .unwrap_or_else(|| self.property::<f64>(Self::UNITS_PER_EM)),
+        );
+        self.notify(Self::TITLE);
+        self.viewport.queue_draw();
+    }
+
+    /// Replaces all contours of the glyph, as one undoable action.
+    pub fn replace_contours(&self, contours: Vec<Contour>) {
+        self.set_selection(&[], SelectionModifier::Replace);
+        let state = self.state().borrow();
+        let mut action = state.replace_contours(contours);
+        (action.redo)();
+        state.add_undo_action(action);
+        drop(state);
+        self.queue_draw();
+    }
 }
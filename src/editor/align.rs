@@ -0,0 +1,408 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Aligning and distributing the selection.
+//!
+//! Contours whose points are all selected move as a whole, every other selected point moves
+//! on its own. Alignment is relative to the bounding box of the selection or to the nearest
+//! guideline, depending on the state of [`Editor::ALIGN_TO_ACTION`].
+
+use super::*;
+use gtk::gdk::keys::constants as keys;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    HorizontalCenter,
+    VerticalCenter,
+}
+
+impl Alignment {
+    /// Whether this alignment moves items horizontally.
+    fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right | Self::HorizontalCenter)
+    }
+
+    /// The coordinate of a box that is aligned.
+    fn edge(self, (min, max): (Point, Point)) -> f64 {
+        match self {
+            Self::Left => min.x,
+            Self::Right => max.x,
+            Self::Top => max.y,
+            Self::Bottom => min.y,
+            Self::HorizontalCenter => (min.x + max.x) / 2.0,
+            Self::VerticalCenter => (min.y + max.y) / 2.0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Distribution {
+    Horizontal,
+    Vertical,
+}
+
+/// Offsets that align every box in `boxes` to `reference`.
+fn align_offsets(boxes: &[(Point, Point)], alignment: Alignment, reference: f64) -> Vec<Point> {
+    boxes
+        .iter()
+        .map(|b| {
+            let delta = reference - alignment.edge(*b);
+            if alignment.is_horizontal() {
+                Point::from((delta, 0.0))
+            } else {
+                Point::from((0.0, delta))
+            }
+        })
+        .collect()
+}
+
+/// Offsets that make the gaps between consecutive boxes equal. The outermost boxes stay put.
+fn distribute_offsets(boxes: &[(Point, Point)], distribution: Distribution) -> Vec<Point> {
+    let axis = |p: Point| match distribution {
+        Distribution::Horizontal => p.x,
+        Distribution::Vertical => p.y,
+    };
+    let mut ret = vec![Point::default(); boxes.len()];
+    if boxes.len() < 3 {
+        return ret;
+    }
+    let mut order = (0..boxes.len()).collect::<Vec<usize>>();
+    order.sort_by(|&a, &b| axis(boxes[a].0).total_cmp(&axis(boxes[b].0)));
+    let start = axis(boxes[order[0]].0);
+    let end = boxes
+        .iter()
+        .map(|b| axis(b.1))
+        .fold(f64::NEG_INFINITY, f64::max);
+    let sizes = boxes.iter().map(|b| axis(b.1) - axis(b.0)).sum::<f64>();
+    let gap = (end - start - sizes) / (boxes.len() - 1) as f64;
+    let mut position = start;
+    for i in order {
+        let delta = position - axis(boxes[i].0);
+        ret[i] = match distribution {
+            Distribution::Horizontal => Point::from((delta, 0.0)),
+            Distribution::Vertical => Point::from((0.0, delta)),
+        };
+        position += axis(boxes[i].1) - axis(boxes[i].0) + gap;
+    }
+    ret
+}
+
+fn bounding_box(points: impl Iterator<Item = Point>) -> Option<(Point, Point)> {
+    let mut ret: Option<(Point, Point)> = None;
+    for p in points {
+        let (min, max) = ret.get_or_insert((p, p));
+        min.x = min.x.min(p.x);
+        min.y = min.y.min(p.y);
+        max.x = max.x.max(p.x);
+        max.y = max.y.max(p.y);
+    }
+    ret
+}
+
+impl Editor {
+    pub const ALIGN_LEFT_ACTION: &str = "align.left";
+    pub const ALIGN_RIGHT_ACTION: &str = "align.right";
+    pub const ALIGN_TOP_ACTION: &str = "align.top";
+    pub const ALIGN_BOTTOM_ACTION: &str = "align.bottom";
+    pub const ALIGN_HORIZONTAL_CENTER_ACTION: &str = "align.horizontal-center";
+    pub const ALIGN_VERTICAL_CENTER_ACTION: &str = "align.vertical-center";
+    pub const DISTRIBUTE_HORIZONTALLY_ACTION: &str = "distribute.horizontally";
+    pub const DISTRIBUTE_VERTICALLY_ACTION: &str = "distribute.vertically";
+    /// Stateful action with the reference of alignments: `"selection"`, `"metrics"` for the
+    /// nearest metrics guideline or `"guidelines"` for the nearest glyph or project guideline.
+    pub const ALIGN_TO_ACTION: &str = "align.to";
+
+    /// The selection as groups of points that move together, with their bounding boxes.
+    fn selection_items(&self) -> Vec<(Vec<GlyphPointIndex>, (Point, Point))> {
+        let state = self.state().borrow();
+        let glyph = state.glyph.borrow();
+        let mut by_contour: IndexMap<usize, Vec<GlyphPointIndex>> = IndexMap::new();
+        for idx in state.get_selection() {
+            by_contour.entry(idx.contour_index).or_default().push(*idx);
+        }
+        let mut ret = vec![];
+        for (contour_index, indices) in by_contour {
+            let Some(contour) = glyph.contours.get(contour_index) else {
+                continue;
+            };
+            let curves = contour.curves();
+            let position = |idx: &GlyphPointIndex| {
+                curves
+                    .get(idx.curve_index)?
+                    .points()
+                    .iter()
+                    .find(|cp| cp.uuid == idx.uuid)
+                    .map(|cp| cp.position)
+            };
+            let all = curves
+                .iter()
+                .flat_map(|c| c.points().iter().map(|cp| cp.uuid).collect::<Vec<_>>())
+                .collect::<HashSet<_>>();
+            let selected = indices.iter().map(|i| i.uuid).collect::<HashSet<_>>();
+            if selected == all {
+                if let Some(bbox) = bounding_box(indices.iter().filter_map(position)) {
+                    ret.push((indices, bbox));
+                }
+            } else {
+                for idx in indices {
+                    if let Some(p) = position(&idx) {
+                        ret.push((vec![idx], (p, p)));
+                    }
+                }
+            }
+        }
+        ret
+    }
+
+    /// The coordinate to align to: an edge of the selection's bounding box or the nearest
+    /// guideline that is parallel to the aligned edges.
+    fn align_reference(&self, alignment: Alignment, selection: (Point, Point)) -> Option<f64> {
+        let target = self
+            .action_group
+            .action_state(Self::ALIGN_TO_ACTION)
+            .and_then(|v| v.get::<String>())
+            .unwrap_or_default();
+        let edge = alignment.edge(selection);
+        let guidelines = match target.as_str() {
+            "metrics" => self.project().metric_guidelines.borrow().clone(),
+            "guidelines" => {
+                let mut ret = self.state().borrow().glyph.borrow().guidelines().to_vec();
+                ret.extend(self.project().guidelines.borrow().iter().cloned());
+                ret
+            }
+            _ => return Some(edge),
+        };
+        guidelines
+            .iter()
+            .filter_map(|g| {
+                let angle = g.property::<f64>(Guideline::ANGLE).rem_euclid(180.0);
+                if alignment.is_horizontal() && (angle - 90.0).abs() < 1e-6 {
+                    Some(g.property::<f64>(Guideline::X))
+                } else if !alignment.is_horizontal() && (angle.abs() < 1e-6 || angle > 180.0 - 1e-6)
+                {
+                    Some(g.property::<f64>(Guideline::Y))
+                } else {
+                    None
+                }
+            })
+            .min_by(|a, b| (a - edge).abs().total_cmp(&(b - edge).abs()))
+    }
+
+    /// Moves each group of points by its offset as one undoable action.
+    fn move_items(&self, items: Vec<(Vec<GlyphPointIndex>, (Point, Point))>, offsets: Vec<Point>) {
+        let state = self.state().borrow();
+        let actions = items
+            .iter()
+            .zip(offsets)
+            .filter(|(_, offset)| offset.norm() > f64::EPSILON)
+            .map(|((indices, _), offset)| {
+                state.transform_points(indices, Matrix::new(1.0, 0.0, 0.0, 1.0, offset.x, offset.y))
+            })
+            .collect::<Vec<Action>>();
+        if actions.is_empty() {
+            return;
+        }
+        let actions = Rc::new(RefCell::new(actions));
+        let mut action = Action {
+            stamp: EventStamp {
+                t: std::any::TypeId::of::<Self>(),
+                property: "align",
+                id: Box::new([0; 0]),
+            },
+            compress: false,
+            redo: Box::new(clone!(@strong actions => move || {
+                for a in actions.borrow_mut().iter_mut() {
+                    (a.redo)();
+                }
+            })),
+            undo: Box::new(move || {
+                for a in actions.borrow_mut().iter_mut().rev() {
+                    (a.undo)();
+                }
+            }),
+        };
+        (action.redo)();
+        state.add_undo_action(action);
+        drop(state);
+        self.queue_draw();
+    }
+
+    /// Aligns the selection. Nothing happens if aligning to guidelines and there is no
+    /// suitable guideline.
+    pub fn align(&self, alignment: Alignment) {
+        let items = self.selection_items();
+        let Some(selection) = bounding_box(items.iter().flat_map(|(_, (min, max))| [*min, *max]))
+        else {
+            return;
+        };
+        let Some(reference) = self.align_reference(alignment, selection) else {
+            self.imp()
+                .new_statusbar_message("No guideline to align to.");
+            return;
+        };
+        let boxes = items.iter().map(|(_, b)| *b).collect::<Vec<_>>();
+        self.move_items(items, align_offsets(&boxes, alignment, reference));
+    }
+
+    /// Spaces the selection evenly between its outermost items.
+    pub fn distribute(&self, distribution: Distribution) {
+        let items = self.selection_items();
+        let boxes = items.iter().map(|(_, b)| *b).collect::<Vec<_>>();
+        self.move_items(items, distribute_offsets(&boxes, distribution));
+    }
+}
+
+impl EditorInner {
+    pub fn setup_align(&self, obj: &Editor) {
+        use Editor as A;
+
+        for (name, alignment) in [
+            (A::ALIGN_LEFT_ACTION, Alignment::Left),
+            (A::ALIGN_RIGHT_ACTION, Alignment::Right),
+            (A::ALIGN_TOP_ACTION, Alignment::Top),
+            (A::ALIGN_BOTTOM_ACTION, Alignment::Bottom),
+            (
+                A::ALIGN_HORIZONTAL_CENTER_ACTION,
+                Alignment::HorizontalCenter,
+            ),
+            (A::ALIGN_VERTICAL_CENTER_ACTION, Alignment::VerticalCenter),
+        ] {
+            let a = gtk::gio::SimpleAction::new(name, None);
+            a.connect_activate(glib::clone!(@weak obj => move |_, _| {
+                obj.align(alignment);
+            }));
+            self.action_group.add_action(&a);
+        }
+        for (name, distribution) in [
+            (A::DISTRIBUTE_HORIZONTALLY_ACTION, Distribution::Horizontal),
+            (A::DISTRIBUTE_VERTICALLY_ACTION, Distribution::Vertical),
+        ] {
+            let a = gtk::gio::SimpleAction::new(name, None);
+            a.connect_activate(glib::clone!(@weak obj => move |_, _| {
+                obj.distribute(distribution);
+            }));
+            self.action_group.add_action(&a);
+        }
+        let a = gtk::gio::SimpleAction::new_stateful(
+            A::ALIGN_TO_ACTION,
+            Some(glib::VariantTy::STRING),
+            &"selection".to_variant(),
+        );
+        a.connect_activate(|a, target| {
+            if let Some(target) = target {
+                a.set_state(target);
+            }
+        });
+        self.action_group.add_action(&a);
+
+        let mut sh = self.shortcuts.entries.borrow_mut();
+        for (desc, shortcut, action_name) in [
+            (
+                "align left",
+                Shortcut::empty().control().shift().key(keys::Left),
+                A::ALIGN_LEFT_ACTION,
+            ),
+            (
+                "align right",
+                Shortcut::empty().control().shift().key(keys::Right),
+                A::ALIGN_RIGHT_ACTION,
+            ),
+            (
+                "align top",
+                Shortcut::empty().control().shift().key(keys::Up),
+                A::ALIGN_TOP_ACTION,
+            ),
+            (
+                "align bottom",
+                Shortcut::empty().control().shift().key(keys::Down),
+                A::ALIGN_BOTTOM_ACTION,
+            ),
+            (
+                "align centre",
+                Shortcut::empty().control().shift().char('C'),
+                A::ALIGN_HORIZONTAL_CENTER_ACTION,
+            ),
+            (
+                "align middle",
+                Shortcut::empty().control().shift().char('M'),
+                A::ALIGN_VERTICAL_CENTER_ACTION,
+            ),
+            (
+                "distribute horizontally",
+                Shortcut::empty().control().shift().char('H'),
+                A::DISTRIBUTE_HORIZONTALLY_ACTION,
+            ),
+            (
+                "distribute vertically",
+                Shortcut::empty().control().shift().char('K'),
+                A::DISTRIBUTE_VERTICALLY_ACTION,
+            ),
+        ] {
+            sh.push(ShortcutAction::new(
+                desc.into(),
+                shortcut,
+                Box::new(move |group| {
+                    group.activate_action(action_name, None);
+                    true
+                }),
+                None,
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_align_distribute_offsets() {
+    let boxes = [
+        (Point::from((0.0, 0.0)), Point::from((10.0, 10.0))),
+        (Point::from((50.0, 20.0)), Point::from((70.0, 30.0))),
+        (Point::from((20.0, 5.0)), Point::from((25.0, 8.0))),
+    ];
+    assert_eq!(
+        align_offsets(&boxes, Alignment::Left, 0.0),
+        vec![
+            Point::from((0.0, 0.0)),
+            Point::from((-50.0, 0.0)),
+            Point::from((-20.0, 0.0))
+        ]
+    );
+    assert_eq!(
+        align_offsets(&boxes, Alignment::Top, 30.0),
+        vec![
+            Point::from((0.0, 20.0)),
+            Point::from((0.0, 0.0)),
+            Point::from((0.0, 22.0))
+        ]
+    );
+    /* Total width 70, sizes 35: gaps of 17.5. */
+    assert_eq!(
+        distribute_offsets(&boxes, Distribution::Horizontal),
+        vec![
+            Point::from((0.0, 0.0)),
+            Point::from((0.0, 0.0)),
+            Point::from((7.5, 0.0))
+        ]
+    );
+}
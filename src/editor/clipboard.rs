@@ -0,0 +1,405 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Copying and pasting outlines through the system clipboard.
+//!
+//! Outlines are offered in a native format that remembers where they were copied from, as
+//! `.glif` XML, as an SVG document and as SVG path data text.
+
+use super::*;
+use crate::glyphs::{svg, transform::control_box, Anchor, Component};
+use crate::ufo::glif::Glif;
+use crate::utils::curves::Bezier;
+use gtk::gdk::keys::constants as keys;
+use serde::{Deserialize, Serialize};
+
+const NATIVE_TARGET: &str = "application/x-gerb-outline";
+const GLIF_TARGET: &str = "application/x-glif+xml";
+const SVG_TARGET: &str = "image/svg+xml";
+
+/// Clipboard contents in the native format.
+#[derive(Debug, Serialize, Deserialize)]
+struct ClipboardData {
+    /// Path of the project the outline was copied from.
+    project: PathBuf,
+    /// Name of the glyph the outline was copied from.
+    glyph: String,
+    glif: String,
+}
+
+impl Editor {
+    pub const COPY_ACTION: &str = "copy";
+    pub const CUT_ACTION: &str = "cut";
+    pub const PASTE_ACTION: &str = "paste";
+    pub const PASTE_IN_PLACE_ACTION: &str = "paste.in-place";
+    pub const PASTE_AS_COMPONENT_ACTION: &str = "paste.as-component";
+}
+
+impl EditorInner {
+    pub fn setup_clipboard(&self, obj: &Editor) {
+        use Editor as A;
+
+        for name in [A::COPY_ACTION, A::CUT_ACTION] {
+            let a = gtk::gio::SimpleAction::new(name, None);
+            a.connect_activate(glib::clone!(@weak obj => move |_, _| {
+                obj.copy(name == A::CUT_ACTION);
+            }));
+            self.action_group.add_action(&a);
+        }
+        for (name, mode) in [
+            (A::PASTE_ACTION, PasteMode::AtPointer),
+            (A::PASTE_IN_PLACE_ACTION, PasteMode::InPlace),
+            (A::PASTE_AS_COMPONENT_ACTION, PasteMode::AsComponent),
+        ] {
+            let a = gtk::gio::SimpleAction::new(name, None);
+            a.connect_activate(glib::clone!(@weak obj => move |_, _| {
+                if let Err(err) = obj.paste(mode) {
+                    let dialog = crate::utils::widgets::new_simple_error_dialog(
+                        Some("Error: Could not paste"),
+                        &err.to_string(),
+                        None,
+                        obj.app().window.upcast_ref(),
+                    );
+                    dialog.run();
+                    dialog.emit_close();
+                }
+            }));
+            self.action_group.add_action(&a);
+        }
+
+        let mut sh = self.shortcuts.entries.borrow_mut();
+        for (desc, shortcut, action_name) in [
+            (
+                "copy",
+                Shortcut::empty().control().char('c'),
+                A::COPY_ACTION,
+            ),
+            ("cut", Shortcut::empty().control().char('x'), A::CUT_ACTION),
+            (
+                "paste",
+                Shortcut::empty().control().char('v'),
+                A::PASTE_ACTION,
+            ),
+            (
+                "paste in place",
+                Shortcut::empty().control().shift().char('V'),
+                A::PASTE_IN_PLACE_ACTION,
+            ),
+            (
+                "paste as component",
+                Shortcut::empty().control().key(keys::Insert),
+                A::PASTE_AS_COMPONENT_ACTION,
+            ),
+        ] {
+            sh.push(ShortcutAction::new(
+                desc.into(),
+                shortcut,
+                Box::new(move |group| {
+                    group.activate_action(action_name, None);
+                    true
+                }),
+                None,
+            ));
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PasteMode {
+    /// Center the pasted outline on the mouse pointer.
+    AtPointer,
+    /// Keep the pasted outline's coordinates.
+    InPlace,
+    /// Add a component referencing the glyph the outline was copied from.
+    AsComponent,
+}
+
+impl Editor {
+    /// Copies the selection to the clipboard, or the whole glyph if nothing is selected. If
+    /// `cut` is true, exactly what was copied is removed afterwards.
+    pub fn copy(&self, cut: bool) {
+        let (copied, whole, remaining) = {
+            let state = self.state().borrow();
+            selected_outline(&state)
+        };
+        let contours = copied.contours.clone();
+        let data = ClipboardData {
+            project: self.project().path.borrow().clone(),
+            glyph: self.glyph().borrow().name().to_string(),
+            glif: Glif::from(&copied).to_xml(),
+        };
+        let native = serde_json::to_string(&data).unwrap();
+        let glif = data.glif;
+        let svg_document = svg::svg_document(&contours);
+        let path_data = svg::path_data(&contours);
+
+        let targets = [
+            gtk::TargetEntry::new(NATIVE_TARGET, gtk::TargetFlags::empty(), 0),
+            gtk::TargetEntry::new(GLIF_TARGET, gtk::TargetFlags::empty(), 1),
+            gtk::TargetEntry::new(SVG_TARGET, gtk::TargetFlags::empty(), 2),
+            gtk::TargetEntry::new("UTF8_STRING", gtk::TargetFlags::empty(), 3),
+            gtk::TargetEntry::new("text/plain;charset=utf-8", gtk::TargetFlags::empty(), 3),
+            gtk::TargetEntry::new("text/plain", gtk::TargetFlags::empty(), 3),
+        ];
+        let clipboard = gtk::Clipboard::get(&gdk::SELECTION_CLIPBOARD);
+        clipboard.set_with_data(&targets, move |_, selection, info| {
+            let bytes = match info {
+                0 => native.as_bytes(),
+                1 => glif.as_bytes(),
+                2 => svg_document.as_bytes(),
+                _ => path_data.as_bytes(),
+            };
+            selection.set(&selection.target(), 8, bytes);
+        });
+
+        if cut && (whole || !contours.is_empty()) {
+            let state = self.state().borrow();
+            let (components, anchors) = if whole {
+                (vec![], vec![])
+            } else {
+                let glyph = state.glyph.borrow();
+                let anchors = glyph.metadata.anchors.borrow().clone();
+                (glyph.components.clone(), anchors)
+            };
+            let mut action = state.replace_outline(remaining, components, anchors);
+            (action.redo)();
+            state.add_undo_action(action);
+            drop(state);
+            self.set_selection(&[], SelectionModifier::Replace);
+            self.viewport.queue_draw();
+        }
+    }
+
+    /// Pastes the clipboard contents into the glyph. Clipboard text is accepted as `.glif` XML,
+    /// an SVG document or SVG path data.
+    pub fn paste(&self, mode: PasteMode) -> Result<(), Box<dyn std::error::Error>> {
+        let clipboard = gtk::Clipboard::get(&gdk::SELECTION_CLIPBOARD);
+        let data: Option<ClipboardData> = clipboard
+            .wait_for_contents(&gdk::Atom::intern(NATIVE_TARGET))
+            .and_then(|s| serde_json::from_slice(&s.data()).ok());
+        let project = self.project();
+
+        if mode == PasteMode::AsComponent {
+            let Some(data) = data else {
+                return Err(
+                    "The clipboard does not contain an outline copied from a glyph.".into(),
+                );
+            };
+            if data.project != *project.path.borrow() {
+                return Err(
+                    "Only outlines copied from a glyph of this project can be pasted as a component."
+                        .into(),
+                );
+            }
+            let Some(base) = project.default_layer.glyphs().get(&data.glyph).cloned() else {
+                return Err(format!("Glyph {} does not exist.", data.glyph).into());
+            };
+            if Rc::ptr_eq(&base, &self.glyph()) {
+                return Err("A glyph cannot contain itself as a component.".into());
+            }
+            let state = self.state().borrow();
+            let (contours, mut components, anchors) = current_outline(&state);
+            components.push(Component {
+                base_name: data.glyph,
+                base: Rc::downgrade(&base),
+                x_offset: 0.0,
+                y_offset: 0.0,
+                x_scale: 1.0,
+                xy_scale: 0.0,
+                yx_scale: 0.0,
+                y_scale: 1.0,
+            });
+            let mut action = state.replace_outline(contours, components, anchors);
+            (action.redo)();
+            state.add_undo_action(action);
+            drop(state);
+            self.viewport.queue_draw();
+            return Ok(());
+        }
+
+        let pasted: Glyph = if let Some(data) = data {
+            let mut glyph: Glyph = Glif::from_str(&data.glif)?.into();
+            if data.project != *project.path.borrow() {
+                /* Components only make sense within their own project. */
+                glyph.components.clear();
+            }
+            glyph
+        } else if let Some(glif) = clipboard
+            .wait_for_contents(&gdk::Atom::intern(GLIF_TARGET))
+            .and_then(|s| String::from_utf8(s.data()).ok())
+        {
+            Glif::from_str(&glif)?.into()
+        } else {
+            let text = clipboard
+                .wait_for_contents(&gdk::Atom::intern(SVG_TARGET))
+                .and_then(|s| String::from_utf8(s.data()).ok())
+                .or_else(|| clipboard.wait_for_text().map(|s| s.to_string()))
+                .ok_or("The clipboard is empty.")?;
+            if text.contains("<glyph") {
+                Glif::from_str(&text)?.into()
+            } else {
+                let mut glyph = Glyph::new_empty(String::new(), ' ');
+                glyph.contours = svg::parse_svg(&text)?;
+                glyph
+            }
+        };
+        for c in pasted.components.iter() {
+            if let Some(base) = project.default_layer.glyphs().get(&c.base_name) {
+                if Rc::ptr_eq(base, &self.glyph()) {
+                    return Err("A glyph cannot contain itself as a component.".into());
+                }
+            }
+        }
+
+        if mode == PasteMode::AtPointer {
+            if let Some((min, max)) = control_box(&pasted.contours) {
+                let UnitPoint(mouse) = self.viewport.view_to_unit_point(self.viewport.get_mouse());
+                let delta = mouse - (min + max) / 2.0;
+                for contour in pasted.contours.iter() {
+                    for curv in contour.curves().iter() {
+                        for i in 0..curv.points().len() {
+                            curv.modify_point(i, |cp| {
+                                cp.position = cp.position + delta;
+                            });
+                        }
+                    }
+                }
+                for a in pasted.metadata.anchors.borrow_mut().iter_mut() {
+                    a.x += delta.x;
+                    a.y += delta.y;
+                }
+            }
+        }
+
+        let state = self.state().borrow();
+        let (mut contours, mut components, mut anchors) = current_outline(&state);
+        let first_pasted = contours.len();
+        contours.extend(pasted.contours.iter().cloned());
+        components.extend(pasted.components.iter().map(|c| {
+            let mut c = c.clone();
+            if let Some(base) = project.default_layer.glyphs().get(&c.base_name) {
+                c.base = Rc::downgrade(base);
+            }
+            c
+        }));
+        anchors.extend(pasted.metadata.anchors.borrow().iter().cloned());
+        let mut action = state.replace_outline(contours, components, anchors);
+        (action.redo)();
+        state.add_undo_action(action);
+        let selection = {
+            let glyph = state.glyph.borrow();
+            glyph.contours[first_pasted..]
+                .iter()
+                .enumerate()
+                .flat_map(|(i, contour)| {
+                    contour
+                        .curves()
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(curve_index, curv)| {
+                            curv.points()
+                                .iter()
+                                .map(|p| p.glyph_index(first_pasted + i, curve_index))
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+        drop(state);
+        self.set_selection(&selection, SelectionModifier::Replace);
+        self.viewport.queue_draw();
+        Ok(())
+    }
+}
+
+/// The copied part of the glyph: completely selected contours, and runs of selected curves of
+/// other contours as open contours. When nothing or everything is selected the whole glyph
+/// is copied, including components and anchors. Also returns whether the whole glyph was
+/// copied and the contours that are left when the copied part is cut: the rest of partially
+/// selected contours is left as open contours.
+fn selected_outline(state: &State) -> (Glyph, bool, Vec<Contour>) {
+    let glyph = state.glyph.borrow();
+    let selection = state.get_selection_set();
+    let is_selected = |curv: &Bezier| curv.points().iter().all(|p| selection.contains(&p.uuid));
+    let whole = selection.is_empty()
+        || glyph
+            .contours
+            .iter()
+            .all(|c| c.curves().iter().all(is_selected));
+    let mut ret = Glyph::new_empty(glyph.name().to_string(), ' ');
+    ret.metadata.width.set(glyph.width());
+    if whole {
+        ret.contours = glyph.contours.iter().map(Contour::duplicate).collect();
+        ret.components = glyph.components.clone();
+        *ret.metadata.anchors.borrow_mut() = glyph.metadata.anchors.borrow().clone();
+        return (ret, true, vec![]);
+    }
+    let mut remaining = vec![];
+    for contour in glyph.contours.iter() {
+        let curves = contour.curves();
+        if curves.iter().all(is_selected) {
+            ret.contours.push(contour.duplicate());
+            continue;
+        }
+        if !curves.iter().any(is_selected) {
+            remaining.push(contour.clone());
+            continue;
+        }
+        /* Start closed contours at an unselected curve, so that no run wraps around the
+         * start point. */
+        let start = if contour.property::<bool>(Contour::OPEN) {
+            0
+        } else {
+            curves.iter().position(|c| !is_selected(c)).unwrap_or(0)
+        };
+        let (mut selected_run, mut unselected_run) = (vec![], vec![]);
+        for curv in curves[start..].iter().chain(curves[..start].iter()) {
+            if is_selected(curv) {
+                if !unselected_run.is_empty() {
+                    remaining.push(Contour::new_with_curves(std::mem::take(
+                        &mut unselected_run,
+                    )));
+                }
+                selected_run.push(curv.duplicate());
+            } else {
+                if !selected_run.is_empty() {
+                    ret.contours
+                        .push(Contour::new_with_curves(std::mem::take(&mut selected_run)));
+                }
+                unselected_run.push(curv.duplicate());
+            }
+        }
+        if !selected_run.is_empty() {
+            ret.contours.push(Contour::new_with_curves(selected_run));
+        }
+        if !unselected_run.is_empty() {
+            remaining.push(Contour::new_with_curves(unselected_run));
+        }
+    }
+    (ret, false, remaining)
+}
+
+fn current_outline(state: &State) -> (Vec<Contour>, Vec<Component>, Vec<Anchor>) {
+    let glyph = state.glyph.borrow();
+    let anchors = glyph.metadata.anchors.borrow().clone();
+    (glyph.contours.clone(), glyph.components.clone(), anchors)
+}
@@ -0,0 +1,386 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use super::{Editor, SelectionModifier};
+use crate::glyphs::corners::{Corner, CornerKind};
+use crate::glyphs::Contour;
+use crate::prelude::*;
+
+glib::wrapper! {
+    pub struct CornerSettings(ObjectSubclass<CornerSettingsInner>);
+}
+
+impl std::ops::Deref for CornerSettings {
+    type Target = CornerSettingsInner;
+    fn deref(&self) -> &Self::Target {
+        self.imp()
+    }
+}
+
+/// Parameters of new corner treatments.
+#[derive(Debug, Default)]
+pub struct CornerSettingsInner {
+    kind: Cell<CornerKind>,
+    size: Cell<f64>,
+    depth: Cell<f64>,
+    glyph: RefCell<String>,
+}
+
+#[glib::object_subclass]
+impl ObjectSubclass for CornerSettingsInner {
+    const NAME: &'static str = "CornerSettings";
+    type Type = CornerSettings;
+    type ParentType = glib::Object;
+    type Interfaces = ();
+}
+
+impl ObjectImpl for CornerSettingsInner {
+    fn constructed(&self, obj: &Self::Type) {
+        self.parent_constructed(obj);
+        self.size.set(CornerSettings::DEFAULT_SIZE);
+        self.depth.set(CornerSettings::DEFAULT_DEPTH);
+    }
+
+    fn properties() -> &'static [glib::ParamSpec] {
+        static PROPERTIES: once_cell::sync::Lazy<Vec<glib::ParamSpec>> =
+            once_cell::sync::Lazy::new(|| {
+                vec![
+                    glib::ParamSpecEnum::new(
+                        CornerSettings::KIND,
+                        CornerSettings::KIND,
+                        "Shape that replaces the corner",
+                        CornerKind::static_type(),
+                        CornerKind::Round as i32,
+                        glib::ParamFlags::READWRITE | UI_EDITABLE,
+                    ),
+                    def_param!(f64 CornerSettings::SIZE, 0.0, CornerSettings::DEFAULT_SIZE),
+                    def_param!(f64 CornerSettings::DEPTH, 0.0, CornerSettings::DEFAULT_DEPTH),
+                    glib::ParamSpecString::new(
+                        CornerSettings::GLYPH,
+                        CornerSettings::GLYPH,
+                        "Name of the glyph whose first contour is the custom corner shape",
+                        Some(""),
+                        glib::ParamFlags::READWRITE | UI_EDITABLE,
+                    ),
+                ]
+            });
+        PROPERTIES.as_ref()
+    }
+
+    fn property(&self, _obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+        match pspec.name() {
+            CornerSettings::KIND => self.kind.get().to_value(),
+            CornerSettings::SIZE => self.size.get().to_value(),
+            CornerSettings::DEPTH => self.depth.get().to_value(),
+            CornerSettings::GLYPH => self.glyph.borrow().to_value(),
+            _ => unimplemented!("{}", pspec.name()),
+        }
+    }
+
+    fn set_property(
+        &self,
+        _obj: &Self::Type,
+        _id: usize,
+        value: &glib::Value,
+        pspec: &glib::ParamSpec,
+    ) {
+        match pspec.name() {
+            CornerSettings::KIND => {
+                self.kind.set(value.get().unwrap());
+            }
+            CornerSettings::SIZE => {
+                self.size.set(value.get().unwrap());
+            }
+            CornerSettings::DEPTH => {
+                self.depth.set(value.get().unwrap());
+            }
+            CornerSettings::GLYPH => {
+                *self.glyph.borrow_mut() =
+                    value.get::<Option<String>>().unwrap().unwrap_or_default();
+            }
+            _ => unimplemented!("{}", pspec.name()),
+        }
+    }
+}
+
+impl Default for CornerSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CornerSettings {
+    pub const KIND: &str = "kind";
+    pub const SIZE: &str = "size";
+    pub const DEPTH: &str = "depth";
+    pub const GLYPH: &str = "glyph";
+    const DEFAULT_SIZE: f64 = 20.0;
+    const DEFAULT_DEPTH: f64 = 10.0;
+
+    pub fn new() -> Self {
+        glib::Object::new::<Self>(&[]).unwrap()
+    }
+
+    pub fn corner(&self) -> Corner {
+        Corner::new(
+            self.kind.get(),
+            self.size.get(),
+            self.depth.get(),
+            self.glyph.borrow().clone(),
+        )
+    }
+}
+
+impl_property_window!(CornerSettings, { "Corners" });
+
+impl Editor {
+    /// The first contour of the corner glyph of a custom treatment.
+    fn corner_shape(&self, corner: &Corner) -> Option<Contour> {
+        if corner.kind != CornerKind::Custom {
+            return None;
+        }
+        let glyph = self
+            .project()
+            .default_layer
+            .glyphs()
+            .get(&corner.glyph)
+            .cloned()?;
+        let glyph = glyph.borrow();
+        glyph.contours.first().cloned()
+    }
+
+    /// Replaces the contours and the corner treatments in the glyph lib as one action.
+    fn replace_contours_and_corners(&self, contours: Vec<Contour>, corners: Vec<Corner>) {
+        self.set_selection(&[], SelectionModifier::Replace);
+        let state = self.state().borrow();
+        let contours_action = Rc::new(RefCell::new(state.replace_contours(contours)));
+        let other = Rc::new(RefCell::new(corners));
+        let swap = clone!(@strong other, @weak state.glyph as glyph => move || {
+            let mut glyph = glyph.borrow_mut();
+            let current = Corner::load(&glyph.lib);
+            Corner::store(&mut glyph.lib, &other.borrow());
+            *other.borrow_mut() = current;
+        });
+        let mut action = Action {
+            stamp: EventStamp {
+                t: std::any::TypeId::of::<Corner>(),
+                property: Corner::LIB_KEY,
+                id: Box::new([0; 0]),
+            },
+            compress: false,
+            redo: Box::new(clone!(@strong contours_action, @strong swap => move || {
+                (contours_action.borrow_mut().redo)();
+                swap();
+            })),
+            undo: Box::new(move || {
+                (contours_action.borrow_mut().undo)();
+                swap();
+            }),
+        };
+        (action.redo)();
+        state.add_undo_action(action);
+        drop(state);
+        self.queue_draw();
+    }
+
+    /// Applies the corner settings to the selected corners. Selected points of an existing
+    /// treatment replace that treatment.
+    pub fn apply_corners(&self) {
+        let template = self.imp().corners.corner();
+        if template.kind == CornerKind::Custom && self.corner_shape(&template).is_none() {
+            self.imp().new_statusbar_message(&format!(
+                "Corner glyph {:?} does not exist or has no contours.",
+                template.glyph
+            ));
+            return;
+        }
+        let (mut contours, mut corners, selected) = {
+            let state = self.state().borrow();
+            let glyph = state.glyph.borrow();
+            let mut selected: IndexMap<usize, Vec<Point>> = IndexMap::new();
+            for idx in state.get_selection() {
+                if let Some(p) = glyph
+                    .contours
+                    .get(idx.contour_index)
+                    .and_then(|c| c.get_point(*idx))
+                {
+                    selected.entry(idx.contour_index).or_default().push(p);
+                }
+            }
+            (glyph.contours.clone(), Corner::load(&glyph.lib), selected)
+        };
+        let mut applied = 0;
+        for (contour_index, points) in selected {
+            let mut contour = contours[contour_index].clone();
+            /* Existing treatments with a selected point are edited, other points are new
+             * corners. */
+            let mut positions = vec![];
+            let mut k = 0;
+            while k < corners.len() {
+                let corner_points = if corners[k].contour == contour_index {
+                    contour.corner_points(&corners[k])
+                } else {
+                    vec![]
+                };
+                if points
+                    .iter()
+                    .any(|p| corner_points.iter().any(|q| p.distance(*q) < 0.01))
+                {
+                    let corner = corners.remove(k);
+                    if let Some((restored, position)) =
+                        contour.without_corner(&corner, &mut corners)
+                    {
+                        contour = restored;
+                        positions.push(position);
+                        continue;
+                    }
+                    corners.insert(k, corner);
+                }
+                k += 1;
+            }
+            for p in points {
+                if !positions.iter().any(|q| p.distance(*q) < 0.01) {
+                    positions.push(p);
+                }
+            }
+            for position in positions {
+                let mut corner = Corner::with_parameters_of(&template);
+                corner.contour = contour_index;
+                corner.position = position;
+                let shape = self.corner_shape(&corner);
+                if let Some(treated) =
+                    contour.with_corner(&mut corner, shape.as_ref(), &mut corners)
+                {
+                    contour = treated;
+                    corners.push(corner);
+                    applied += 1;
+                }
+            }
+            contours[contour_index] = contour;
+        }
+        if applied > 0 {
+            self.replace_contours_and_corners(contours, corners);
+        }
+        self.imp()
+            .new_statusbar_message(&format!("Applied {} corner(s).", applied));
+    }
+
+    /// Restores every stored corner treatment and applies it again, for example after the
+    /// outline has changed.
+    pub fn reapply_corners(&self) {
+        let (mut contours, mut corners) = {
+            let state = self.state().borrow();
+            let glyph = state.glyph.borrow();
+            (glyph.contours.clone(), Corner::load(&glyph.lib))
+        };
+        if corners.is_empty() {
+            self.imp()
+                .new_statusbar_message("This glyph has no corner treatments.");
+            return;
+        }
+        let (mut reapplied, mut missing) = (0, 0);
+        let mut k = 0;
+        while k < corners.len() {
+            /* Take the treatment out while it is re-applied, so that only the others are moved
+             * along with the curves it adds or removes. */
+            let corner = corners.remove(k);
+            let i = corner.contour;
+            let Some((restored, position)) = contours
+                .get(i)
+                .and_then(|c| c.without_corner(&corner, &mut corners))
+            else {
+                missing += 1;
+                corners.insert(k, corner);
+                k += 1;
+                continue;
+            };
+            let mut new = Corner::with_parameters_of(&corner);
+            new.contour = i;
+            new.position = position;
+            let shape = self.corner_shape(&new);
+            match restored.with_corner(&mut new, shape.as_ref(), &mut corners) {
+                Some(treated) => {
+                    contours[i] = treated;
+                    corners.insert(k, new);
+                    k += 1;
+                    reapplied += 1;
+                }
+                None => {
+                    /* The treatment no longer fits: keep the sharp corner. */
+                    contours[i] = restored;
+                    missing += 1;
+                }
+            }
+        }
+        self.replace_contours_and_corners(contours, corners);
+        self.imp().new_statusbar_message(&format!(
+            "Re-applied {} corner(s), {} could not be re-applied.",
+            reapplied, missing
+        ));
+    }
+
+    /// Restores the sharp corners of the selected treatments, or of all treatments if nothing
+    /// is selected.
+    pub fn remove_corners(&self) {
+        let (mut contours, mut corners, selected) = {
+            let state = self.state().borrow();
+            let glyph = state.glyph.borrow();
+            let selected = state
+                .get_selection()
+                .iter()
+                .filter_map(|idx| {
+                    let p = glyph.contours.get(idx.contour_index)?.get_point(*idx)?;
+                    Some((idx.contour_index, p))
+                })
+                .collect::<Vec<(usize, Point)>>();
+            (glyph.contours.clone(), Corner::load(&glyph.lib), selected)
+        };
+        let mut removed = 0;
+        let mut k = 0;
+        while k < corners.len() {
+            let i = corners[k].contour;
+            let points = contours
+                .get(i)
+                .map(|c| c.corner_points(&corners[k]))
+                .unwrap_or_default();
+            if !points.is_empty()
+                && (selected.is_empty()
+                    || selected
+                        .iter()
+                        .any(|(c, p)| *c == i && points.iter().any(|q| p.distance(*q) < 0.01)))
+            {
+                let corner = corners.remove(k);
+                if let Some((restored, _)) = contours[i].without_corner(&corner, &mut corners) {
+                    contours[i] = restored;
+                    removed += 1;
+                    continue;
+                }
+                corners.insert(k, corner);
+            }
+            k += 1;
+        }
+        if removed > 0 {
+            self.replace_contours_and_corners(contours, corners);
+        }
+        self.imp()
+            .new_statusbar_message(&format!("Removed {} corner(s).", removed));
+    }
+}
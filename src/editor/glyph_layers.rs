@@ -0,0 +1,436 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! The layers of the UFO in the editor: the edited glyph can be switched to its version in
+//! another layer, other layers can be shown as a translucent background, and outlines can be
+//! copied or swapped between layers.
+
+use super::*;
+use crate::glyphs::{outline::Outline, Advance};
+use crate::ufo::objects::Layer as UfoLayer;
+use crate::utils::colors::*;
+
+impl EditorInner {
+    pub fn create_glyph_layers_widget(&self, obj: &Editor) -> gtk::Box {
+        self.layer_list.set_selection_mode(gtk::SelectionMode::None);
+        self.layer_list
+            .add_events(gtk::gdk::EventMask::BUTTON_PRESS_MASK);
+        self.layer_list.set_visible(true);
+        self.layer_list
+            .connect_row_activated(clone!(@weak obj => move |_, row| {
+                let layers = obj.project().layers();
+                if let Some(layer) = layers.get(row.index() as usize) {
+                    obj.set_active_layer(layer);
+                }
+            }));
+        self.layer_list.connect_button_press_event(
+            clone!(@weak obj => @default-return Inhibit(false), move |list, event| {
+                if event.button() != gtk::gdk::BUTTON_SECONDARY {
+                    return Inhibit(false);
+                }
+                let (_, y) = event.position();
+                let Some(row) = list.row_at_y(y as i32) else {
+                    return Inhibit(false);
+                };
+                let layers = obj.project().layers();
+                if let Some(layer) = layers.get(row.index() as usize) {
+                    obj.glyph_layer_menu(layer).popup(event.time());
+                }
+                Inhibit(true)
+            }),
+        );
+        let new_button = gtk::Button::builder()
+            .label("New layer…")
+            .relief(gtk::ReliefStyle::None)
+            .visible(true)
+            .build();
+        new_button.connect_clicked(clone!(@weak obj => move |_| {
+            let Some(name) = obj.layer_name_dialog("New layer", "") else {
+                return;
+            };
+            match obj.project().new_layer(&name) {
+                Ok(layer) => obj.set_active_layer(&layer),
+                Err(err) => obj.show_layer_error("Error: Could not create layer.", &err.to_string()),
+            }
+        }));
+        let ret = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .name("glyph-layers")
+            .tooltip_text("UFO layers. Click a layer to edit it, right click for more actions.")
+            .halign(gtk::Align::End)
+            .valign(gtk::Align::End)
+            .expand(false)
+            .visible(true)
+            .build();
+        let label = gtk::Label::new(Some("UFO layers"));
+        label.set_visible(true);
+        label.set_sensitive(false);
+        ret.pack_start(&label, false, false, 0);
+        ret.pack_start(&self.layer_list, false, false, 0);
+        ret.pack_start(&new_button, false, false, 0);
+        ret
+    }
+}
+
+impl Editor {
+    pub fn active_layer(&self) -> UfoLayer {
+        self.active_layer
+            .borrow()
+            .clone()
+            .unwrap_or_else(|| self.project().default_layer.clone())
+    }
+
+    /// Rebuilds the rows of the layer panel.
+    pub fn update_glyph_layers_widget(&self) {
+        let list = &self.layer_list;
+        for row in list.children() {
+            list.remove(&row);
+        }
+        let active = self.active_layer();
+        let name = self.glyph().borrow().name().to_string();
+        for layer in self.project().layers() {
+            let layer_name = layer.property::<String>(UfoLayer::NAME);
+            let has_glyph = layer.glyphs().contains_key(&name);
+            let show = gtk::CheckButton::builder()
+                .tooltip_text("Show as background")
+                .active(self.background_layers.borrow().contains(&layer))
+                .sensitive(layer != active)
+                .visible(true)
+                .build();
+            show.connect_toggled(clone!(@weak self as obj, @weak layer => move |show| {
+                let mut background_layers = obj.background_layers.borrow_mut();
+                if show.is_active() {
+                    if !background_layers.contains(&layer) {
+                        background_layers.push(layer);
+                    }
+                } else {
+                    background_layers.retain(|l| l != &layer);
+                }
+                drop(background_layers);
+                obj.viewport.queue_draw();
+            }));
+            let label = gtk::Label::builder()
+                .label(&if layer == active {
+                    format!("<b>{}</b>", glib::markup_escape_text(&layer_name))
+                } else if has_glyph {
+                    glib::markup_escape_text(&layer_name).to_string()
+                } else {
+                    format!("<i>{}</i>", glib::markup_escape_text(&layer_name))
+                })
+                .use_markup(true)
+                .tooltip_text(if has_glyph {
+                    "The glyph exists in this layer."
+                } else {
+                    "The glyph does not exist in this layer yet."
+                })
+                .halign(gtk::Align::Start)
+                .margin(3)
+                .visible(true)
+                .build();
+            let row_box = gtk::Box::builder()
+                .orientation(gtk::Orientation::Horizontal)
+                .visible(true)
+                .build();
+            row_box.pack_start(&show, false, false, 0);
+            row_box.pack_start(&label, true, true, 0);
+            list.add(&row_box);
+        }
+    }
+
+    /// Makes the edited glyph's version in `layer` the edited glyph.
+    pub fn set_active_layer(&self, layer: &UfoLayer) {
+        let base = self.text.borrow()[self.active_index.get()].clone();
+        let glyph = self.layer_glyph(layer, &base);
+        *self.active_layer.borrow_mut() = Some(layer.clone());
+        self.background_layers.borrow_mut().retain(|l| l != layer);
+        self.set_edited_glyph(glyph);
+        self.update_glyph_layers_widget();
+    }
+
+    /// The glyph with the name of `base` in `layer`. A missing glyph is made empty, with the
+    /// metadata and advance width of `base`, and is added to `layer` only once it is modified,
+    /// so that switching layers does not create glyphs by itself.
+    pub fn layer_glyph(&self, layer: &UfoLayer, base: &Rc<RefCell<Glyph>>) -> Rc<RefCell<Glyph>> {
+        let name = base.borrow().name().to_string();
+        if let Some(glyph) = layer.glyphs().get(&name) {
+            return glyph.clone();
+        }
+        let glyph = {
+            let base = base.borrow();
+            let glyph: Glyph = base.kinds().0.clone().into();
+            *glyph.metadata.name.borrow_mut() = name.clone();
+            *glyph.metadata.kinds.borrow_mut() = base.kinds().clone();
+            *glyph.metadata.unicode.borrow_mut() = base.metadata.unicode.borrow().clone();
+            *glyph.metadata.filename.borrow_mut() = layer.new_filename(&name);
+            glyph.metadata.width.set(base.width());
+            glyph.metadata.advance.set(base.metadata.advance.get());
+            glyph
+        };
+        let metadata = glyph.metadata.clone();
+        let glyph = Rc::new(RefCell::new(glyph));
+        metadata.glyph_ref.set(glyph.clone()).unwrap();
+        metadata.connect_notify_local(
+            Some(GlyphMetadata::MODIFIED),
+            clone!(@weak self as obj, @weak layer, @weak glyph => move |metadata, _| {
+                if !metadata.modified() {
+                    return;
+                }
+                /* Whatever modified the glyph may still be borrowing it. */
+                glib::idle_add_local_once(clone!(@weak obj, @weak layer, @weak glyph => move || {
+                    let name = glyph.borrow().name().to_string();
+                    if layer.glyphs().contains_key(&name) {
+                        return;
+                    }
+                    /* Other glyphs may have been added to the layer since. */
+                    let filename = layer.new_filename(&name);
+                    *glyph.borrow().metadata.filename.borrow_mut() = filename;
+                    if let Err(err) = layer.new_glyph(name, glyph.clone()) {
+                        obj.show_layer_error("Error: Could not add glyph to layer.", &err.to_string());
+                        return;
+                    }
+                    obj.project().glyph_changed(&glyph);
+                    obj.update_glyph_layers_widget();
+                }));
+            }),
+        );
+        glyph
+    }
+
+    fn glyph_layer_menu(&self, layer: &UfoLayer) -> crate::utils::menu::Menu {
+        let layer_name = layer.property::<String>(UfoLayer::NAME);
+        let menu = crate::utils::menu::Menu::new()
+            .title(Some(layer_name.clone().into()))
+            .add_button_cb(
+                "Edit this layer",
+                clone!(@weak self as obj, @weak layer => move |_| {
+                    obj.set_active_layer(&layer);
+                }),
+            );
+        if *layer == self.active_layer() {
+            return menu;
+        }
+        let menu = menu
+            .add_button_cb(
+                "Copy outline to this layer",
+                clone!(@weak self as obj, @weak layer => move |_| {
+                    obj.copy_outline_to_layer(&layer, false);
+                }),
+            )
+            .add_button_cb(
+                "Swap outline with this layer",
+                clone!(@weak self as obj, @weak layer => move |_| {
+                    obj.copy_outline_to_layer(&layer, true);
+                }),
+            );
+        if *layer == self.project().default_layer {
+            return menu;
+        }
+        menu.add_button_cb(
+            "Rename layer…",
+            clone!(@weak self as obj, @weak layer => move |_| {
+                let old = layer.property::<String>(UfoLayer::NAME);
+                let Some(new) = obj.layer_name_dialog("Rename layer", &old) else {
+                    return;
+                };
+                if let Err(err) = obj.project().rename_layer(&layer, &new) {
+                    obj.show_layer_error("Error: Could not rename layer.", &err.to_string());
+                }
+            }),
+        )
+        .add_button_cb(
+            "Delete layer",
+            clone!(@weak self as obj, @weak layer => move |_| {
+                let dialog = crate::utils::widgets::new_simple_info_dialog(
+                    Some("Delete layer?"),
+                    &format!(
+                        "Layer {} and all its glyphs will be deleted when the project is saved.",
+                        layer.property::<String>(UfoLayer::NAME)
+                    ),
+                    None,
+                    obj.app().window.upcast_ref(),
+                );
+                dialog.add_button("Delete", gtk::ResponseType::Accept);
+                let response = dialog.run();
+                dialog.emit_close();
+                if response != gtk::ResponseType::Accept {
+                    return;
+                }
+                if let Err(err) = obj.project().delete_layer(&layer) {
+                    obj.show_layer_error("Error: Could not delete layer.", &err.to_string());
+                }
+            }),
+        )
+    }
+
+    /// Copies the outline of the edited glyph to its version in `layer`, or swaps the two
+    /// outlines, as one undoable action. The outline includes contours, components, anchors and
+    /// the advance width.
+    pub fn copy_outline_to_layer(&self, layer: &UfoLayer, swap: bool) {
+        let base = self.text.borrow()[self.active_index.get()].clone();
+        let target = self.layer_glyph(layer, &base);
+        let source = self.glyph();
+        if Rc::ptr_eq(&source, &target) {
+            return;
+        }
+        self.set_selection(&[], SelectionModifier::Replace);
+        let outline = |glyph: &Glyph| -> Outline {
+            (
+                glyph.contours.iter().map(|c| c.duplicate()).collect(),
+                glyph.components.clone(),
+                glyph.metadata.anchors.borrow().clone(),
+            )
+        };
+        let (target_outline, source_outline, metrics) = {
+            let (source_ref, target_ref) = (source.borrow(), target.borrow());
+            let advance = source_ref
+                .width()
+                .map(|width| Advance {
+                    width,
+                    height: source_ref
+                        .metadata
+                        .advance
+                        .get()
+                        .map(|a| a.height)
+                        .unwrap_or(0.0),
+                })
+                .or_else(|| source_ref.metadata.advance.get());
+            let mut metrics = vec![(target.clone(), source_ref.width(), advance)];
+            if swap {
+                metrics.push((
+                    source.clone(),
+                    target_ref.width(),
+                    target_ref.metadata.advance.get(),
+                ));
+            }
+            (
+                outline(&source_ref),
+                swap.then(|| outline(&target_ref)),
+                metrics,
+            )
+        };
+        let project = self.project();
+        let on_change: Rc<dyn Fn(&Rc<RefCell<Glyph>>)> =
+            Rc::new(clone!(@weak project => move |glyph: &Rc<RefCell<Glyph>>| {
+                project.glyph_changed(glyph);
+            }));
+        let target_action = Glyph::swap_outlines(
+            vec![(target, target_outline)],
+            "layer-outline",
+            on_change.clone(),
+        );
+        let source_action = source_outline.map(|(contours, components, anchors)| {
+            self.state()
+                .borrow()
+                .replace_outline(contours, components, anchors)
+        });
+        let actions = Rc::new(RefCell::new((target_action, source_action)));
+        let metrics = Rc::new(RefCell::new(metrics));
+        let cl = Box::new(move || {
+            for (glyph, width, advance) in metrics.borrow_mut().iter_mut() {
+                let glyph = glyph.borrow();
+                *width = glyph.metadata.width.replace(*width);
+                *advance = glyph.metadata.advance.replace(*advance);
+            }
+            let mut actions = actions.borrow_mut();
+            /* Both actions swap outlines, so redoing them again undoes them. */
+            (actions.0.redo)();
+            if let Some(source_action) = actions.1.as_mut() {
+                (source_action.redo)();
+                on_change(&source);
+            }
+        });
+        let mut action = Action {
+            stamp: EventStamp {
+                t: std::any::TypeId::of::<Self>(),
+                property: "layer-outline",
+                id: layer.property::<String>(UfoLayer::NAME).as_bytes().into(),
+            },
+            compress: false,
+            redo: cl.clone(),
+            undo: cl,
+        };
+        (action.redo)();
+        self.state().borrow().add_undo_action(action);
+        self.update_glyph_layers_widget();
+    }
+
+    fn layer_name_dialog(&self, title: &str, name: &str) -> Option<String> {
+        let dialog = gtk::Dialog::builder()
+            .attached_to(&self.app().window)
+            .application(self.app())
+            .border_width(10)
+            .destroy_with_parent(true)
+            .modal(true)
+            .title(title)
+            .build();
+        dialog.add_button("Ok", gtk::ResponseType::Accept);
+        dialog.add_button("Cancel", gtk::ResponseType::Close);
+        dialog.set_default_response(gtk::ResponseType::Accept);
+        let entry = gtk::Entry::builder()
+            .visible(true)
+            .activates_default(true)
+            .placeholder_text("Layer name")
+            .text(name)
+            .build();
+        dialog.content_area().pack_start(&entry, true, false, 5);
+        let response = dialog.run();
+        let name = entry.text().to_string();
+        dialog.emit_close();
+        (response == gtk::ResponseType::Accept && !name.is_empty()).then_some(name)
+    }
+
+    pub fn show_layer_error(&self, title: &str, msg: &str) {
+        let dialog = crate::utils::widgets::new_simple_error_dialog(
+            Some(title),
+            msg,
+            None,
+            self.app().window.upcast_ref(),
+        );
+        dialog.run();
+        dialog.emit_close();
+    }
+}
+
+pub fn draw_background_layers(viewport: &Canvas, mut cr: ContextRef, obj: Editor) -> Inhibit {
+    let background_layers = obj.background_layers.borrow();
+    if background_layers.is_empty() || obj.property::<bool>(Editor::PREVIEW) {
+        return Inhibit(false);
+    }
+    let units_per_em = obj.property::<f64>(Editor::UNITS_PER_EM);
+    let name = obj.glyph().borrow().name().to_string();
+    let active = obj.active_layer();
+    cr.transform(viewport.transformation.matrix());
+    for layer in background_layers.iter().filter(|l| **l != active) {
+        let Some(glyph) = layer.glyphs().get(&name).cloned() else {
+            continue;
+        };
+        glyph.borrow().draw(
+            cr.push(),
+            GlyphDrawingOptions {
+                outline: (Color::new_alpha(0, 0, 0, 0), 0.0).into(),
+                inner_fill: Some((Color::new_alpha(89, 89, 89, 70), 0.0).into()),
+                units_per_em,
+                ..Default::default()
+            },
+        );
+    }
+    Inhibit(false)
+}
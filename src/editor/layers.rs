@@ -186,6 +186,40 @@ pub fn draw_glyph_layer(viewport: &Canvas, mut cr: ContextRef, obj: Editor) -> I
     Inhibit(false)
 }
 
+pub fn draw_stroke_preview(viewport: &Canvas, mut cr: ContextRef, obj: Editor) -> Inhibit {
+    use crate::glyphs::offset::stroke_outline;
+
+    let stroke = &obj.imp().stroke;
+    if !stroke.property::<bool>(StrokeSettings::PREVIEW) {
+        return Inhibit(false);
+    }
+    let options = stroke.options();
+    let state = obj.state().borrow();
+    let glyph = state.glyph.borrow();
+    cr.transform(viewport.transformation.matrix());
+    cr.set_fill_rule(cairo::FillRule::Winding);
+    for contour in glyph.contours.iter() {
+        let closed = !contour.property::<bool>(Contour::OPEN);
+        for path in stroke_outline(&contour.segments(), closed, &options) {
+            if let Some(start) = path.first().map(|s| s[0]) {
+                cr.move_to(start.x, start.y);
+            }
+            for segment in path {
+                match segment.as_slice() {
+                    [_, b, c, d] => cr.curve_to(b.x, b.y, c.x, c.y, d.x, d.y),
+                    [_, b] => cr.line_to(b.x, b.y),
+                    _ => {}
+                }
+            }
+            cr.close_path();
+        }
+    }
+    cr.set_source_color_alpha(Color::new_alpha(0, 0, 0, 90));
+    cr.fill().unwrap();
+
+    Inhibit(false)
+}
+
 pub fn draw_guidelines(viewport: &Canvas, mut cr: ContextRef, obj: Editor) -> Inhibit {
     let state = obj.state();
     let matrix = viewport.transformation.matrix();
@@ -20,7 +20,10 @@
  */
 
 use super::{Editor, EditorInner};
-use crate::glyphs::Contour;
+use crate::glyphs::{
+    transform::{TransformOperation, TransformOrigin},
+    Contour, Glyph,
+};
 use crate::prelude::*;
 use crate::views::Canvas;
 use gtk::{gio, glib::subclass::prelude::*, prelude::*};
@@ -43,6 +46,57 @@ impl EditorInner {
         let app = self.app();
         let menumodel = gio::Menu::new();
         let action_group = gtk::gio::SimpleActionGroup::new();
+        {
+            let edit_menu = gio::Menu::new();
+            new_accel_item(&edit_menu, app, "Cut", "view.cut");
+            new_accel_item(&edit_menu, app, "Copy", "view.copy");
+            new_accel_item(&edit_menu, app, "Paste", "view.paste");
+            new_accel_item(&edit_menu, app, "Paste in place", "view.paste.in-place");
+            new_accel_item(
+                &edit_menu,
+                app,
+                "Paste as component",
+                "view.paste.as-component",
+            );
+            {
+                let align_menu = gio::Menu::new();
+                new_accel_item(&align_menu, app, "Align left", "view.align.left");
+                new_accel_item(&align_menu, app, "Align right", "view.align.right");
+                new_accel_item(&align_menu, app, "Align top", "view.align.top");
+                new_accel_item(&align_menu, app, "Align bottom", "view.align.bottom");
+                new_accel_item(
+                    &align_menu,
+                    app,
+                    "Align centre",
+                    "view.align.horizontal-center",
+                );
+                new_accel_item(
+                    &align_menu,
+                    app,
+                    "Align middle",
+                    "view.align.vertical-center",
+                );
+                new_accel_item(
+                    &align_menu,
+                    app,
+                    "Distribute horizontally",
+                    "view.distribute.horizontally",
+                );
+                new_accel_item(
+                    &align_menu,
+                    app,
+                    "Distribute vertically",
+                    "view.distribute.vertically",
+                );
+                let align_to_menu = gio::Menu::new();
+                align_to_menu.append(Some("Selection"), Some("view.align.to::selection"));
+                align_to_menu.append(Some("Metrics"), Some("view.align.to::metrics"));
+                align_to_menu.append(Some("Guidelines"), Some("view.align.to::guidelines"));
+                align_menu.append_section(Some("Align to"), &align_to_menu);
+                edit_menu.append_submenu(Some("Align"), &align_menu);
+            }
+            menumodel.append_submenu(Some("_Edit"), &edit_menu);
+        }
         {
             let glyph_menu = gio::Menu::new();
             new_accel_item(&glyph_menu, app, "Preview", "view.preview");
@@ -61,8 +115,56 @@ impl EditorInner {
                     "Show total area",
                     "glyph.show.total-area",
                 );
+                new_accel_item(
+                    &view_glyph_menu,
+                    app,
+                    "Keep measurements",
+                    "view.measure.persistent",
+                );
                 glyph_menu.append_section(None, &view_glyph_menu);
             }
+            {
+                let outline_menu = gio::Menu::new();
+                new_accel_item(
+                    &outline_menu,
+                    app,
+                    "Add extremes",
+                    "glyph.outline.add-extremes",
+                );
+                new_accel_item(&outline_menu, app, "Simplify", "glyph.outline.simplify");
+                new_accel_item(&outline_menu, app, "Transform", "glyph.transform");
+                glyph_menu.append_section(None, &outline_menu);
+            }
+            {
+                let stroke_menu = gio::Menu::new();
+                new_accel_item(
+                    &stroke_menu,
+                    app,
+                    "Stroke settings",
+                    "glyph.stroke.settings",
+                );
+                new_accel_item(&stroke_menu, app, "Preview stroke", "glyph.stroke.preview");
+                new_accel_item(&stroke_menu, app, "Expand stroke", "glyph.stroke.expand");
+                glyph_menu.append_section(None, &stroke_menu);
+            }
+            {
+                let corner_menu = gio::Menu::new();
+                new_accel_item(
+                    &corner_menu,
+                    app,
+                    "Corner settings",
+                    "glyph.corner.settings",
+                );
+                new_accel_item(&corner_menu, app, "Apply corner", "glyph.corner.apply");
+                new_accel_item(
+                    &corner_menu,
+                    app,
+                    "Re-apply corners",
+                    "glyph.corner.reapply",
+                );
+                new_accel_item(&corner_menu, app, "Remove corners", "glyph.corner.remove");
+                glyph_menu.append_section(None, &corner_menu);
+            }
             menumodel.append_submenu(Some("_Glyph"), &glyph_menu);
             for (action_name, property) in [
                 ("show.grid", Canvas::SHOW_GRID),
@@ -227,6 +329,148 @@ impl EditorInner {
                 dialog.emit_close();
             }));
             action_group.add_action(&export_svg);
+            for (action_name, quadratic) in
+                [("curve.make_cubic", false), ("curve.make_quadratic", true)]
+            {
+                let convert = gtk::gio::SimpleAction::new(action_name, None);
+                convert.connect_activate(clone!(@weak obj => move |_, _| {
+                    let tolerance = if quadratic {
+                        match crate::utils::widgets::ask_number(
+                            Some("Make quadratic"),
+                            "Maximum approximation error in font units:",
+                            1.0,
+                            (0.01, 100.0),
+                            obj.app().window.upcast_ref(),
+                        ) {
+                            Some(v) => Some(v),
+                            None => return,
+                        }
+                    } else {
+                        None
+                    };
+                    let project = obj.project();
+                    let glyph = obj.state().borrow().glyph.clone();
+                    let mut action = Glyph::convert_curves(
+                        vec![glyph],
+                        tolerance,
+                        Rc::new(clone!(@weak project => move |glyph: &Rc<RefCell<Glyph>>| {
+                            project.glyph_changed(glyph);
+                        })),
+                    );
+                    (action.redo)();
+                    obj.app().undo_db.borrow_mut().event(action);
+                }));
+                action_group.add_action(&convert);
+            }
+            let add_extremes = gtk::gio::SimpleAction::new("outline.add-extremes", None);
+            add_extremes.connect_activate(clone!(@weak obj => move |_, _| {
+                let contours = obj.state().borrow().glyph.borrow().contours.clone();
+                let (contours, added) = contours.iter().map(Contour::with_extremes).fold(
+                    (vec![], 0),
+                    |(mut acc, sum), (c, n)| {
+                        acc.push(c);
+                        (acc, sum + n)
+                    },
+                );
+                if added > 0 {
+                    obj.replace_contours(contours);
+                }
+                obj.imp()
+                    .new_statusbar_message(&format!("Added {} point(s) at extrema.", added));
+            }));
+            action_group.add_action(&add_extremes);
+            let simplify = gtk::gio::SimpleAction::new("outline.simplify", None);
+            simplify.connect_activate(clone!(@weak obj => move |_, _| {
+                let tolerance = match crate::utils::widgets::ask_number(
+                    Some("Simplify"),
+                    "Maximum deviation from the outline in font units:",
+                    1.0,
+                    (0.01, 100.0),
+                    obj.app().window.upcast_ref(),
+                ) {
+                    Some(v) => v,
+                    None => return,
+                };
+                let contours = obj.state().borrow().glyph.borrow().contours.clone();
+                let (contours, removed) = contours
+                    .iter()
+                    .map(|c| c.simplified(tolerance))
+                    .fold((vec![], 0), |(mut acc, sum), (c, n)| {
+                        acc.push(c);
+                        (acc, sum + n)
+                    });
+                if removed > 0 {
+                    obj.replace_contours(contours);
+                }
+                obj.imp()
+                    .new_statusbar_message(&format!("Removed {} point(s).", removed));
+            }));
+            action_group.add_action(&simplify);
+            let transform = gtk::gio::SimpleAction::new("transform", None);
+            transform.connect_activate(clone!(@weak obj => move |_, _| {
+                obj.transform_panel().present();
+            }));
+            action_group.add_action(&transform);
+            let stroke_settings = gtk::gio::SimpleAction::new("stroke.settings", None);
+            stroke_settings.connect_activate(glib::clone!(@weak obj, @weak app => move |_, _| {
+                let w = obj.imp().stroke.new_property_window(&app, false);
+                w.present();
+            }));
+            action_group.add_action(&stroke_settings);
+            action_group.add_action(&gtk::gio::PropertyAction::new(
+                "stroke.preview",
+                &self.stroke,
+                StrokeSettings::PREVIEW,
+            ));
+            let expand_stroke = gtk::gio::SimpleAction::new("stroke.expand", None);
+            expand_stroke.connect_activate(clone!(@weak obj => move |_, _| {
+                let options = obj.imp().stroke.options();
+                let (contours, selected) = {
+                    let state = obj.state().borrow();
+                    let selected = state
+                        .get_selection()
+                        .iter()
+                        .map(|i| i.contour_index)
+                        .collect::<HashSet<usize>>();
+                    let contours = state.glyph.borrow().contours.clone();
+                    (contours, selected)
+                };
+                /* Expand the contours that have selected points, or all of them. */
+                let mut expanded = 0;
+                let mut new_contours = vec![];
+                for (i, contour) in contours.iter().enumerate() {
+                    if selected.is_empty() || selected.contains(&i) {
+                        new_contours.extend(contour.stroked(&options));
+                        expanded += 1;
+                    } else {
+                        new_contours.push(contour.clone());
+                    }
+                }
+                if expanded > 0 {
+                    obj.replace_contours(new_contours);
+                    obj.imp().stroke.set_property(StrokeSettings::PREVIEW, false);
+                }
+                obj.imp()
+                    .new_statusbar_message(&format!("Expanded {} contour(s).", expanded));
+            }));
+            action_group.add_action(&expand_stroke);
+            let corner_settings = gtk::gio::SimpleAction::new("corner.settings", None);
+            corner_settings.connect_activate(glib::clone!(@weak obj, @weak app => move |_, _| {
+                let w = obj.imp().corners.new_property_window(&app, false);
+                w.present();
+            }));
+            action_group.add_action(&corner_settings);
+            for (name, method) in [
+                ("corner.apply", Editor::apply_corners as fn(&Editor)),
+                ("corner.reapply", Editor::reapply_corners),
+                ("corner.remove", Editor::remove_corners),
+            ] {
+                let action = gtk::gio::SimpleAction::new(name, None);
+                action.connect_activate(clone!(@weak obj => move |_, _| {
+                    method(&obj);
+                }));
+                action_group.add_action(&action);
+            }
             self.menubar
                 .insert_action_group("glyph", Some(&action_group));
             obj.insert_action_group("glyph", Some(&action_group));
@@ -236,6 +480,61 @@ impl EditorInner {
 }
 
 impl Editor {
+    /// The transform panel of this editor. Operations apply to the selected points about their
+    /// bounding box, or to the whole glyph if nothing is selected.
+    pub fn transform_panel(&self) -> &gtk::Window {
+        self.imp().transform_panel.get_or_init(|| {
+            let view = self.clone();
+            let apply: Rc<dyn Fn(TransformOperation, TransformOrigin)> = Rc::new(
+                clone!(@weak view => move |operation: TransformOperation, origin: TransformOrigin| {
+                    let state = view.state().borrow();
+                    if state.get_selection().is_empty() {
+                        let (contours, components, anchors) = state
+                            .glyph
+                            .borrow()
+                            .transformed_outline(operation, origin);
+                        let mut action = state.replace_outline(contours, components, anchors);
+                        (action.redo)();
+                        state.add_undo_action(action);
+                    } else {
+                        let bbox = {
+                            let glyph = state.glyph.borrow();
+                            let mut bbox: Option<(Point, Point)> = None;
+                            for idx in state.get_selection() {
+                                let contour = &glyph.contours[idx.contour_index];
+                                let curves = contour.curves();
+                                let Some(p) = curves[idx.curve_index]
+                                    .points()
+                                    .iter()
+                                    .find(|cp| cp.uuid == idx.uuid)
+                                    .map(|cp| cp.position) else { continue };
+                                let (min, max) = bbox.get_or_insert((p, p));
+                                min.x = min.x.min(p.x);
+                                min.y = min.y.min(p.y);
+                                max.x = max.x.max(p.x);
+                                max.y = max.y.max(p.y);
+                            }
+                            bbox.unwrap_or_default()
+                        };
+                        state.transform_selection(operation.matrix(origin.point(bbox)), false);
+                    }
+                    drop(state);
+                    view.queue_draw();
+                }),
+            );
+            let panel = crate::views::transform_panel::new_transform_panel(
+                &format!("Transform {}", self.glyph().borrow().name()),
+                self.app().window.upcast_ref(),
+                apply,
+            );
+            panel.connect_delete_event(|panel, _| {
+                panel.hide();
+                Inhibit(true)
+            });
+            panel
+        })
+    }
+
     pub fn make_debug_window(&self) {
         let state = self.state().borrow();
         let glyph = state.glyph.borrow();
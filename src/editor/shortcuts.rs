@@ -49,6 +49,8 @@ impl Editor {
     pub const SELECT_ALL_ACTION: &str = "select.all";
     pub const SELECT_NONE_ACTION: &str = "select.none";
     pub const SELECT_INVERT_ACTION: &str = "select.invert";
+    pub const DELETE_ACTION: &str = "selection.delete";
+    pub const JOIN_ACTION: &str = "selection.join";
 }
 
 impl EditorInner {
@@ -202,6 +204,43 @@ impl EditorInner {
                 }),
                 None,
             ));
+            for (desc, shortcut, action_name) in [
+                (
+                    "delete",
+                    Shortcut::empty().key(keys::Delete),
+                    A::DELETE_ACTION,
+                ),
+                (
+                    "delete",
+                    Shortcut::empty().key(keys::BackSpace),
+                    A::DELETE_ACTION,
+                ),
+                ("join", Shortcut::empty().char('j'), A::JOIN_ACTION),
+            ] {
+                sh.push(ShortcutAction::new(
+                    desc.into(),
+                    shortcut,
+                    Box::new(move |group| {
+                        group.activate_action(action_name, None);
+                        true
+                    }),
+                    None,
+                ));
+            }
+            {
+                let a = gtk::gio::SimpleAction::new(A::DELETE_ACTION, None);
+                a.connect_activate(glib::clone!(@weak obj => move |_, _| {
+                    let t = obj.property::<super::PanningTool>(A::PANNING_TOOL);
+                    t.delete_selection(&obj);
+                }));
+                obj.action_group.add_action(&a);
+                let a = gtk::gio::SimpleAction::new(A::JOIN_ACTION, None);
+                a.connect_activate(glib::clone!(@weak obj => move |_, _| {
+                    let t = obj.property::<super::PanningTool>(A::PANNING_TOOL);
+                    t.join_selection(&obj);
+                }));
+                obj.action_group.add_action(&a);
+            }
             for (name, dir) in [
                 (A::MOVE_UP_ACTION, MoveDirection::Up),
                 (A::MOVE_DOWN_ACTION, MoveDirection::Down),
@@ -20,6 +20,7 @@
  */
 
 use super::*;
+use crate::glyphs::{Anchor, Component};
 
 #[derive(Debug, Clone)]
 pub struct State {
@@ -32,10 +33,17 @@ pub struct State {
     pub selection: Vec<GlyphPointIndex>,
     pub selection_set: HashSet<uuid::Uuid>,
     pub kd_tree: Rc<RefCell<crate::utils::range_query::KdTree>>,
+    /// The point index of every glyph edited so far. Undo actions hold the index of the glyph
+    /// they were created for, so each glyph has to keep its own.
+    kd_trees: Vec<(
+        Rc<RefCell<Glyph>>,
+        Rc<RefCell<crate::utils::range_query::KdTree>>,
+    )>,
 }
 
 impl State {
     pub fn new(glyph: &Rc<RefCell<Glyph>>, app: Application, viewport: Canvas) -> Self {
+        let kd_tree = Rc::new(RefCell::new(crate::utils::range_query::KdTree::new(&[])));
         let ret = Self {
             app,
             glyph: Rc::clone(glyph),
@@ -45,7 +53,8 @@ impl State {
             panning_tool: PanningTool::static_type(),
             selection: vec![],
             selection_set: HashSet::new(),
-            kd_tree: Rc::new(RefCell::new(crate::utils::range_query::KdTree::new(&[]))),
+            kd_tree: kd_tree.clone(),
+            kd_trees: vec![(Rc::clone(glyph), kd_tree)],
         };
 
         for (contour_index, contour) in glyph.borrow().contours.iter().enumerate() {
@@ -54,6 +63,33 @@ impl State {
         ret
     }
 
+    /// Rebuilds the point index after the glyph's contours were replaced, for example when it
+    /// was reloaded from disk.
+    pub fn reload(&mut self) {
+        self.selection.clear();
+        self.selection_set.clear();
+        *self.kd_tree.borrow_mut() = crate::utils::range_query::KdTree::new(&[]);
+        let glyph = self.glyph.clone();
+        for (contour_index, contour) in glyph.borrow().contours.iter().enumerate() {
+            (self.add_contour(contour, contour_index).redo)();
+        }
+    }
+
+    /// Makes `glyph` the edited glyph and switches to its point index.
+    pub fn set_glyph(&mut self, glyph: Rc<RefCell<Glyph>>) {
+        let kd_tree =
+            if let Some((_, kd_tree)) = self.kd_trees.iter().find(|(g, _)| Rc::ptr_eq(g, &glyph)) {
+                kd_tree.clone()
+            } else {
+                let kd_tree = Rc::new(RefCell::new(crate::utils::range_query::KdTree::new(&[])));
+                self.kd_trees.push((glyph.clone(), kd_tree.clone()));
+                kd_tree
+            };
+        self.glyph = glyph;
+        self.kd_tree = kd_tree;
+        self.reload();
+    }
+
     pub fn add_contour(&self, contour: &Contour, contour_index: usize) -> Action {
         Action {
             stamp: EventStamp {
@@ -175,6 +211,57 @@ impl State {
         }
     }
 
+    /// Replaces the glyph's contours, components and anchors. Undoing restores the previous
+    /// ones.
+    pub fn replace_outline(
+        &self,
+        contours: Vec<Contour>,
+        components: Vec<Component>,
+        anchors: Vec<Anchor>,
+    ) -> Action {
+        let other = Rc::new(RefCell::new((contours, components, anchors)));
+        let cl = Box::new(
+            clone!(@strong other, @weak self.kd_tree as kd_tree, @weak self.glyph as glyph, @weak self.viewport as viewport => move || {
+                let mut glyph = glyph.borrow_mut();
+                let mut other = other.borrow_mut();
+                std::mem::swap(&mut glyph.contours, &mut other.0);
+                std::mem::swap(&mut glyph.components, &mut other.1);
+                std::mem::swap(&mut *glyph.metadata.anchors.borrow_mut(), &mut other.2);
+                glyph.metadata.set_property(GlyphMetadata::MODIFIED, true);
+                let mut kd_tree = kd_tree.borrow_mut();
+                *kd_tree = crate::utils::range_query::KdTree::new(&[]);
+                for (contour_index, contour) in glyph.contours.iter().enumerate() {
+                    for (curve_index, curve) in contour.curves().iter().enumerate() {
+                        for p in curve.points().iter() {
+                            kd_tree.add(p.glyph_index(contour_index, curve_index), p.position);
+                        }
+                    }
+                }
+                viewport.queue_draw();
+            }),
+        );
+        Action {
+            stamp: EventStamp {
+                t: std::any::TypeId::of::<Self>(),
+                property: "outline",
+                id: Box::new([]),
+            },
+            compress: false,
+            redo: cl.clone(),
+            undo: cl,
+        }
+    }
+
+    /// Replaces the glyph's contours, keeping its components and anchors.
+    pub fn replace_contours(&self, contours: Vec<Contour>) -> Action {
+        let (components, anchors) = {
+            let glyph = self.glyph.borrow();
+            let anchors = glyph.metadata.anchors.borrow().clone();
+            (glyph.components.clone(), anchors)
+        };
+        self.replace_outline(contours, components, anchors)
+    }
+
     pub fn add_undo_action(&self, action: Action) {
         self.app.undo_db.borrow_mut().event(action);
     }
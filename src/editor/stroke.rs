@@ -0,0 +1,167 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use crate::glyphs::offset::{LineCap, LineJoin, StrokeOptions};
+use crate::prelude::*;
+
+glib::wrapper! {
+    pub struct StrokeSettings(ObjectSubclass<StrokeSettingsInner>);
+}
+
+impl std::ops::Deref for StrokeSettings {
+    type Target = StrokeSettingsInner;
+    fn deref(&self) -> &Self::Target {
+        self.imp()
+    }
+}
+
+/// Parameters for expanding skeleton contours into outlines, and whether the expanded
+/// outline is previewed on the canvas.
+#[derive(Debug, Default)]
+pub struct StrokeSettingsInner {
+    width: Cell<f64>,
+    cap: Cell<LineCap>,
+    join: Cell<LineJoin>,
+    miter_limit: Cell<f64>,
+    preview: Cell<bool>,
+}
+
+#[glib::object_subclass]
+impl ObjectSubclass for StrokeSettingsInner {
+    const NAME: &'static str = "StrokeSettings";
+    type Type = StrokeSettings;
+    type ParentType = glib::Object;
+    type Interfaces = ();
+}
+
+impl ObjectImpl for StrokeSettingsInner {
+    fn constructed(&self, obj: &Self::Type) {
+        self.parent_constructed(obj);
+        let defaults = StrokeOptions::default();
+        self.width.set(defaults.width);
+        self.miter_limit.set(defaults.miter_limit);
+    }
+
+    fn properties() -> &'static [glib::ParamSpec] {
+        static PROPERTIES: once_cell::sync::Lazy<Vec<glib::ParamSpec>> =
+            once_cell::sync::Lazy::new(|| {
+                vec![
+                    def_param!(f64 StrokeSettings::WIDTH, 0.0, StrokeOptions::default().width),
+                    glib::ParamSpecEnum::new(
+                        StrokeSettings::CAP,
+                        StrokeSettings::CAP,
+                        "Shape of the ends of open contours",
+                        LineCap::static_type(),
+                        LineCap::Butt as i32,
+                        glib::ParamFlags::READWRITE | UI_EDITABLE,
+                    ),
+                    glib::ParamSpecEnum::new(
+                        StrokeSettings::JOIN,
+                        StrokeSettings::JOIN,
+                        "Shape of the outside of corners",
+                        LineJoin::static_type(),
+                        LineJoin::Miter as i32,
+                        glib::ParamFlags::READWRITE | UI_EDITABLE,
+                    ),
+                    def_param!(
+                        f64 StrokeSettings::MITER_LIMIT,
+                        1.0,
+                        StrokeOptions::default().miter_limit
+                    ),
+                    glib::ParamSpecBoolean::new(
+                        StrokeSettings::PREVIEW,
+                        StrokeSettings::PREVIEW,
+                        "Draw the expanded outline of the glyph's contours",
+                        false,
+                        glib::ParamFlags::READWRITE | UI_EDITABLE,
+                    ),
+                ]
+            });
+        PROPERTIES.as_ref()
+    }
+
+    fn property(&self, _obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+        match pspec.name() {
+            StrokeSettings::WIDTH => self.width.get().to_value(),
+            StrokeSettings::CAP => self.cap.get().to_value(),
+            StrokeSettings::JOIN => self.join.get().to_value(),
+            StrokeSettings::MITER_LIMIT => self.miter_limit.get().to_value(),
+            StrokeSettings::PREVIEW => self.preview.get().to_value(),
+            _ => unimplemented!("{}", pspec.name()),
+        }
+    }
+
+    fn set_property(
+        &self,
+        _obj: &Self::Type,
+        _id: usize,
+        value: &glib::Value,
+        pspec: &glib::ParamSpec,
+    ) {
+        match pspec.name() {
+            StrokeSettings::WIDTH => {
+                self.width.set(value.get().unwrap());
+            }
+            StrokeSettings::CAP => {
+                self.cap.set(value.get().unwrap());
+            }
+            StrokeSettings::JOIN => {
+                self.join.set(value.get().unwrap());
+            }
+            StrokeSettings::MITER_LIMIT => {
+                self.miter_limit.set(value.get().unwrap());
+            }
+            StrokeSettings::PREVIEW => {
+                self.preview.set(value.get().unwrap());
+            }
+            _ => unimplemented!("{}", pspec.name()),
+        }
+    }
+}
+
+impl Default for StrokeSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StrokeSettings {
+    pub const WIDTH: &str = "width";
+    pub const CAP: &str = "cap";
+    pub const JOIN: &str = "join";
+    pub const MITER_LIMIT: &str = "miter-limit";
+    pub const PREVIEW: &str = "preview";
+
+    pub fn new() -> Self {
+        glib::Object::new::<Self>(&[]).unwrap()
+    }
+
+    pub fn options(&self) -> StrokeOptions {
+        StrokeOptions {
+            width: self.width.get(),
+            cap: self.cap.get(),
+            join: self.join.get(),
+            miter_limit: self.miter_limit.get(),
+        }
+    }
+}
+
+impl_property_window!(StrokeSettings, { "Stroke" });
@@ -0,0 +1,228 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! A line of text shown in the editor: the edited glyph is drawn among other glyphs, laid out
+//! by advance width, so that spacing can be judged in context. Clicking another glyph of the
+//! line makes it the edited glyph.
+
+use super::*;
+use crate::glyphs::GlyphKind;
+use crate::utils::colors::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextItem {
+    Char(char),
+    Name(String),
+}
+
+/// Parses the text line. Glyphs are entered by character, or by name as `/name` ended by a
+/// space or the next slash. `//` is a slash.
+pub fn parse_text(text: &str) -> Vec<TextItem> {
+    let mut ret = vec![];
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '/' {
+            ret.push(TextItem::Char(c));
+            continue;
+        }
+        if chars.next_if_eq(&'/').is_some() {
+            ret.push(TextItem::Char('/'));
+            continue;
+        }
+        let mut name = String::new();
+        while let Some(c) = chars.next_if(|&c| c != '/' && !c.is_whitespace()) {
+            name.push(c);
+        }
+        if name.is_empty() {
+            ret.push(TextItem::Char('/'));
+        } else {
+            chars.next_if_eq(&' ');
+            ret.push(TextItem::Name(name));
+        }
+    }
+    ret
+}
+
+impl EditorInner {
+    pub fn setup_text_entry(&self, obj: &Editor) {
+        self.text_entry
+            .set_placeholder_text(Some("Text, e.g. nn/a/noo"));
+        self.text_entry.set_tooltip_text(Some(
+            "Glyphs shown next to the edited glyph. Type characters, or glyph names as /name \
+             followed by a space. Click a glyph to edit it.",
+        ));
+        self.text_entry.set_width_chars(30);
+        self.text_entry.set_halign(gtk::Align::Center);
+        self.text_entry.set_valign(gtk::Align::Start);
+        self.text_entry.set_margin_top(5);
+        self.text_entry.set_visible(true);
+        self.text_entry
+            .connect_changed(clone!(@weak obj => move |entry| {
+                obj.set_text(&entry.text());
+            }));
+    }
+}
+
+impl Editor {
+    /// Sets the glyphs of the text line. The edited glyph stays at its first occurrence in the
+    /// text, or is put before the text if it does not occur in it. The line always shows the
+    /// glyphs of the default layer.
+    pub fn set_text(&self, text: &str) {
+        let active = self.text.borrow()[self.active_index.get()].clone();
+        let mut line = {
+            let glyphs = self.project().default_layer.glyphs();
+            parse_text(text)
+                .into_iter()
+                .filter_map(|item| match item {
+                    TextItem::Name(name) => glyphs.get(&name).cloned(),
+                    TextItem::Char(c) => glyphs
+                        .values()
+                        .find(|g| g.borrow().kinds().0 == GlyphKind::Char(c))
+                        .cloned(),
+                })
+                .collect::<Vec<_>>()
+        };
+        let index = match line.iter().position(|g| Rc::ptr_eq(g, &active)) {
+            Some(index) => index,
+            None => {
+                line.insert(0, active);
+                0
+            }
+        };
+        *self.text.borrow_mut() = line;
+        self.active_index.set(index);
+        self.viewport.queue_draw();
+    }
+
+    /// Horizontal offsets of the glyphs of the text line in units, relative to the edited
+    /// glyph.
+    pub fn text_offsets(&self) -> Vec<f64> {
+        let text = self.text.borrow();
+        let mut x = 0.0;
+        let mut offsets = Vec::with_capacity(text.len());
+        for glyph in text.iter() {
+            offsets.push(x);
+            x += glyph.borrow().width().unwrap_or(0.0);
+        }
+        let origin = offsets.get(self.active_index.get()).copied().unwrap_or(0.0);
+        offsets.into_iter().map(|x| x - origin).collect()
+    }
+
+    /// The index of the glyph of the text line whose advance box contains `position`, unless it
+    /// is the edited glyph.
+    pub fn text_index_at(&self, UnitPoint(position): UnitPoint) -> Option<usize> {
+        let descender = self.property::<f64>(Self::DESCENDER);
+        let ascender = self.property::<f64>(Self::ASCENDER);
+        if !(descender..=ascender).contains(&position.y) {
+            return None;
+        }
+        let active = self.active_index.get();
+        let text = self.text.borrow();
+        text.iter()
+            .zip(self.text_offsets())
+            .enumerate()
+            .filter(|&(i, _)| i != active)
+            .find(|(_, (glyph, x))| {
+                (*x..x + glyph.borrow().width().unwrap_or(0.0)).contains(&position.x)
+            })
+            .map(|(i, _)| i)
+    }
+
+    /// Makes the `index`-th glyph of the text line the edited glyph, in the active layer. The
+    /// camera follows, so that the line stays in place on screen.
+    pub fn set_active_glyph(&self, index: usize) {
+        if index == self.active_index.get() {
+            return;
+        }
+        let Some(base) = self.text.borrow().get(index).cloned() else {
+            return;
+        };
+        let glyph = self.layer_glyph(&self.active_layer(), &base);
+        let dx = self.text_offsets()[index];
+        self.active_index.set(index);
+        let t = &self.viewport.transformation;
+        let factor = t.property::<f64>(Transformation::PIXELS_PER_UNIT)
+            * t.property::<f64>(Transformation::SCALE);
+        t.move_camera_by_delta(ViewPoint((dx * factor, 0.0).into()));
+        self.set_edited_glyph(glyph);
+        self.update_glyph_layers_widget();
+    }
+}
+
+pub fn draw_text_layer(viewport: &Canvas, mut cr: ContextRef, obj: Editor) -> Inhibit {
+    let text = obj.text.borrow();
+    if text.len() < 2 {
+        return Inhibit(false);
+    }
+    let scale: f64 = viewport
+        .transformation
+        .property::<f64>(Transformation::SCALE);
+    let ppu: f64 = viewport
+        .transformation
+        .property::<f64>(Transformation::PIXELS_PER_UNIT);
+    let line_width = obj.app_settings().property::<f64>(Settings::LINE_WIDTH) / (scale * ppu);
+    let units_per_em = obj.property::<f64>(Editor::UNITS_PER_EM);
+    let color = if obj.property::<bool>(Editor::PREVIEW) {
+        Color::BLACK
+    } else {
+        Color::new(89, 89, 89)
+    };
+    let active = obj.active_index.get();
+
+    cr.transform(viewport.transformation.matrix());
+    for (i, (glyph, x)) in text.iter().zip(obj.text_offsets()).enumerate() {
+        if i == active {
+            continue;
+        }
+        let cr1 = cr.push();
+        cr1.translate(x, 0.0);
+        glyph.borrow().draw(
+            cr1,
+            GlyphDrawingOptions {
+                outline: (Color::new_alpha(0, 0, 0, 0), 0.0).into(),
+                inner_fill: Some((color, line_width).into()),
+                units_per_em,
+                ..Default::default()
+            },
+        );
+    }
+    Inhibit(false)
+}
+
+#[test]
+fn test_parse_text() {
+    use TextItem::*;
+
+    assert_eq!(parse_text("ab"), vec![Char('a'), Char('b')]);
+    assert_eq!(
+        parse_text("n/a.sc n/b/c //"),
+        vec![
+            Char('n'),
+            Name("a.sc".to_string()),
+            Char('n'),
+            Name("b".to_string()),
+            Name("c".to_string()),
+            Char(' '),
+            Char('/'),
+        ]
+    );
+    assert_eq!(parse_text("/ a"), vec![Char('/'), Char(' '), Char('a')]);
+}
@@ -24,6 +24,8 @@ use super::*;
 mod bezier;
 mod bspline;
 mod image;
+mod knife;
+mod measure;
 mod panning;
 mod shapes;
 mod tool_impl;
@@ -31,6 +33,8 @@ mod zoom;
 pub use self::image::*;
 pub use bezier::*;
 pub use bspline::*;
+pub use knife::*;
+pub use measure::*;
 pub use panning::*;
 pub use shapes::*;
 pub use tool_impl::*;
@@ -176,6 +180,8 @@ impl Tool {
             //BSplineTool::new().upcast::<ToolImpl>(),
             QuadrilateralTool::new().upcast::<ToolImpl>(),
             EllipseTool::new().upcast::<ToolImpl>(),
+            MeasureTool::new().upcast::<ToolImpl>(),
+            KnifeTool::new().upcast::<ToolImpl>(),
             ImageTool::new(glyph, obj.project.get().unwrap().clone()).upcast::<ToolImpl>(),
             ZoomInTool::new().upcast::<ToolImpl>(),
             ZoomOutTool::new().upcast::<ToolImpl>(),
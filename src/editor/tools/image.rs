@@ -32,7 +32,7 @@ pub struct ImageToolInner {
     matrix: Cell<cairo::Matrix>,
     color: Cell<Option<Color>>,
     layer: OnceCell<Layer>,
-    glyph: OnceCell<Rc<RefCell<Glyph>>>,
+    glyph: RefCell<Option<Rc<RefCell<Glyph>>>>,
     active: Cell<bool>,
     descender: Cell<f64>,
     ascender: Cell<f64>,
@@ -207,26 +207,31 @@ impl ImageTool {
                 .build();
         }
 
+        ret.set_glyph(glyph, &project);
+        ret
+    }
+
+    /// Loads the background image of `glyph`, replacing the image of the previous glyph.
+    pub fn set_glyph(&self, glyph: Rc<RefCell<Glyph>>, project: &Project) {
+        *self.imp().image_data.borrow_mut() = None;
         if let Some(image_ref) = glyph.borrow().image.borrow().as_ref() {
             if let Some(file_name) = image_ref.file_name.as_ref() {
                 // [ref:FIXME] error handling
-                *ret.imp().image_data.borrow_mut() = Some(project.load_image(file_name).unwrap());
-                ret.imp().active.set(true);
-                ret.imp().color.set(image_ref.color);
+                *self.imp().image_data.borrow_mut() = Some(project.load_image(file_name).unwrap());
+                self.imp().active.set(true);
+                self.imp().color.set(image_ref.color);
                 let xx = image_ref.x_scale;
                 let yy = image_ref.y_scale;
                 let xy = image_ref.xy_scale;
                 let yx = image_ref.yx_scale;
                 let x0 = image_ref.x_offset;
                 let y0 = image_ref.y_offset;
-                ret.imp()
+                self.imp()
                     .matrix
                     .set(cairo::Matrix::new(xx, yx, xy, yy, x0, y0));
             }
         }
-        ret.imp().glyph.set(glyph).unwrap();
-
-        ret
+        *self.imp().glyph.borrow_mut() = Some(glyph);
     }
 
     pub fn draw_layer(viewport: &Canvas, cr: ContextRef, obj: Editor) -> Inhibit {
@@ -0,0 +1,285 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use super::{measure_contours, tool_impl::*};
+use crate::views::canvas::{Layer, LayerBuilder};
+use gtk::Inhibit;
+
+use crate::prelude::*;
+
+// [ref:needs_user_doc]
+#[derive(Default)]
+pub struct KnifeToolInner {
+    layer: OnceCell<Layer>,
+    active: Cell<bool>,
+    line: Cell<Option<(Point, Point)>>,
+}
+
+#[glib::object_subclass]
+impl ObjectSubclass for KnifeToolInner {
+    const NAME: &'static str = "KnifeTool";
+    type ParentType = ToolImpl;
+    type Type = KnifeTool;
+}
+
+impl ObjectImpl for KnifeToolInner {
+    fn constructed(&self, obj: &Self::Type) {
+        self.parent_constructed(obj);
+        obj.set_property::<bool>(KnifeTool::ACTIVE, false);
+        obj.set_property::<String>(ToolImpl::NAME, "knife".to_string());
+        obj.set_property::<String>(
+            ToolImpl::DESCRIPTION,
+            "Slice contours along a line".to_string(),
+        );
+        obj.set_property::<gtk::Image>(
+            ToolImpl::ICON,
+            crate::resources::icons::KNIFE_ICON.to_image_widget(),
+        );
+    }
+
+    fn properties() -> &'static [glib::ParamSpec] {
+        static PROPERTIES: once_cell::sync::Lazy<Vec<glib::ParamSpec>> =
+            once_cell::sync::Lazy::new(|| {
+                vec![glib::ParamSpecBoolean::new(
+                    KnifeTool::ACTIVE,
+                    KnifeTool::ACTIVE,
+                    KnifeTool::ACTIVE,
+                    true,
+                    glib::ParamFlags::READWRITE,
+                )]
+            });
+        PROPERTIES.as_ref()
+    }
+
+    fn property(&self, _obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+        match pspec.name() {
+            KnifeTool::ACTIVE => self.active.get().to_value(),
+            _ => unimplemented!("{}", pspec.name()),
+        }
+    }
+
+    fn set_property(
+        &self,
+        _obj: &Self::Type,
+        _id: usize,
+        value: &glib::Value,
+        pspec: &glib::ParamSpec,
+    ) {
+        match pspec.name() {
+            KnifeTool::ACTIVE => self.active.set(value.get().unwrap()),
+            _ => unimplemented!("{}", pspec.name()),
+        }
+    }
+}
+
+impl ToolImplImpl for KnifeToolInner {
+    fn on_button_press_event(
+        &self,
+        _obj: &ToolImpl,
+        _view: Editor,
+        viewport: &Canvas,
+        event: &gtk::gdk::EventButton,
+    ) -> Inhibit {
+        if !self.active.get() {
+            return Inhibit(false);
+        }
+        match event.button() {
+            gtk::gdk::BUTTON_PRIMARY => {
+                let UnitPoint(position) =
+                    viewport.view_to_unit_point(ViewPoint(event.position().into()));
+                self.line.set(Some((position, position)));
+            }
+            gtk::gdk::BUTTON_SECONDARY => {
+                self.line.set(None);
+            }
+            _ => return Inhibit(false),
+        }
+        viewport.queue_draw();
+        Inhibit(true)
+    }
+
+    fn on_button_release_event(
+        &self,
+        _obj: &ToolImpl,
+        view: Editor,
+        viewport: &Canvas,
+        event: &gtk::gdk::EventButton,
+    ) -> Inhibit {
+        if !self.active.get() || event.button() != gtk::gdk::BUTTON_PRIMARY {
+            return Inhibit(false);
+        }
+        let Some((a, b)) = self.line.take() else {
+            return Inhibit(false);
+        };
+        if a.distance(b) > f64::EPSILON {
+            KnifeTool::cut(&view, a, b);
+        }
+        viewport.queue_draw();
+        Inhibit(true)
+    }
+
+    fn on_motion_notify_event(
+        &self,
+        _obj: &ToolImpl,
+        _view: Editor,
+        viewport: &Canvas,
+        event: &gtk::gdk::EventMotion,
+    ) -> Inhibit {
+        if !self.active.get() {
+            return Inhibit(false);
+        }
+        let Some((a, _)) = self.line.get() else {
+            return Inhibit(false);
+        };
+        let UnitPoint(mut b) = viewport.view_to_unit_point(ViewPoint(event.position().into()));
+        if event.state().contains(gtk::gdk::ModifierType::SHIFT_MASK) {
+            /* Constrain to multiples of 15 degrees. */
+            let d = b - a;
+            let step = std::f64::consts::PI / 12.0;
+            let angle = (d.y.atan2(d.x) / step).round() * step;
+            b = a + d.norm() * Point::from((angle.cos(), angle.sin()));
+        }
+        self.line.set(Some((a, b)));
+        viewport.queue_draw();
+        Inhibit(true)
+    }
+
+    fn setup_toolbox(&self, obj: &ToolImpl, toolbar: &gtk::Toolbar, view: &Editor) {
+        let layer =
+            LayerBuilder::new()
+                .set_name(Some("knife"))
+                .set_active(false)
+                .set_hidden(true)
+                .set_callback(Some(Box::new(clone!(@weak view => @default-return Inhibit(false), move |viewport: &Canvas, cr: ContextRef| {
+                    KnifeTool::draw_layer(viewport, cr, view)
+                }))))
+                .build();
+        self.instance()
+            .bind_property(KnifeTool::ACTIVE, &layer, Layer::ACTIVE)
+            .flags(glib::BindingFlags::SYNC_CREATE)
+            .build();
+        self.layer.set(layer.clone()).unwrap();
+        view.viewport.add_post_layer(layer);
+
+        self.parent_setup_toolbox(obj, toolbar, view)
+    }
+
+    fn on_activate(&self, obj: &ToolImpl, view: &Editor) {
+        self.instance()
+            .set_property::<bool>(KnifeTool::ACTIVE, true);
+        view.viewport.set_cursor("crosshair");
+        self.parent_on_activate(obj, view)
+    }
+
+    fn on_deactivate(&self, obj: &ToolImpl, view: &Editor) {
+        self.line.set(None);
+        self.instance()
+            .set_property::<bool>(KnifeTool::ACTIVE, false);
+        view.viewport.set_cursor("default");
+        self.parent_on_deactivate(obj, view)
+    }
+}
+
+glib::wrapper! {
+    pub struct KnifeTool(ObjectSubclass<KnifeToolInner>)
+        @extends ToolImpl;
+}
+
+impl Default for KnifeTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KnifeTool {
+    pub const ACTIVE: &str = "active";
+
+    pub fn new() -> Self {
+        glib::Object::new(&[]).unwrap()
+    }
+
+    /// Slices every contour of the glyph that the segment from `a` to `b` cuts through, as one
+    /// undoable action.
+    pub fn cut(view: &Editor, a: Point, b: Point) {
+        let (contours, pieces) = {
+            let state = view.state().borrow();
+            let glyph = state.glyph.borrow();
+            let mut pieces = 0;
+            let mut contours = vec![];
+            for contour in glyph.contours.iter() {
+                match contour.sliced(a, b) {
+                    Some(mut sliced) => {
+                        pieces += sliced.len();
+                        contours.append(&mut sliced);
+                    }
+                    None => contours.push(contour.duplicate()),
+                }
+            }
+            (contours, pieces)
+        };
+        if pieces == 0 {
+            view.imp()
+                .new_statusbar_message("The cut does not go through any contour.");
+            return;
+        }
+        view.replace_contours(contours);
+    }
+
+    pub fn draw_layer(viewport: &Canvas, cr: ContextRef, obj: Editor) -> Inhibit {
+        let state = obj.state().borrow();
+        if Self::static_type() != state.active_tool {
+            return Inhibit(false);
+        }
+        let t = state.tools[&state.active_tool]
+            .clone()
+            .downcast::<Self>()
+            .unwrap();
+        if !t.imp().active.get() {
+            return Inhibit(false);
+        }
+        let Some((a, b)) = t.imp().line.get() else {
+            return Inhibit(false);
+        };
+        let line_width = obj
+            .settings
+            .get()
+            .unwrap()
+            .property::<f64>(Settings::LINE_WIDTH);
+        let to_view = |p: Point| viewport.unit_to_view_point(UnitPoint(p)).0;
+        let (va, vb) = (to_view(a), to_view(b));
+        cr.set_line_width(line_width);
+        cr.set_dash(&[4.0, 4.0], 0.0);
+        cr.set_source_rgba(0.9, 0.1, 0.1, 0.9);
+        cr.move_to(va.x, va.y);
+        cr.line_to(vb.x, vb.y);
+        cr.stroke().expect("Invalid cairo surface state");
+        cr.set_dash(&[], 0.0);
+        for p in measure_contours(&state.glyph.borrow().contours, a, b)
+            .into_iter()
+            .map(to_view)
+        {
+            cr.arc(p.x, p.y, 3.0, 0.0, 2.0 * std::f64::consts::PI);
+            cr.fill().expect("Invalid cairo surface state");
+        }
+
+        Inhibit(true)
+    }
+}
@@ -0,0 +1,474 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+use super::tool_impl::*;
+use crate::glyphs::Contour;
+use crate::views::canvas::{Layer, LayerBuilder};
+use gtk::Inhibit;
+
+use crate::prelude::*;
+
+/// Intersections of the segment from `a` to `b` with the curves of `contours`, ordered by their
+/// distance from `a`. Consecutive intersections bound stems and bowls.
+pub fn measure_contours(contours: &[Contour], a: Point, b: Point) -> Vec<Point> {
+    let mut ret = contours
+        .iter()
+        .flat_map(|c| {
+            c.curves()
+                .iter()
+                .flat_map(|curve| curve.line_intersections(a, b))
+                .map(|(_, p)| p)
+                .collect::<Vec<Point>>()
+        })
+        .collect::<Vec<Point>>();
+    ret.sort_by(|p, q| p.distance(a).total_cmp(&q.distance(a)));
+    /* Curves that meet on the line report the same point twice. */
+    ret.dedup_by(|p, q| p.distance(*q) < 1e-3);
+    ret
+}
+
+/// Distance from `p` to the segment from `a` to `b`.
+fn segment_distance(p: Point, (a, b): (Point, Point)) -> f64 {
+    let d = b - a;
+    let len2 = d.dot(d);
+    if len2 <= f64::EPSILON {
+        return p.distance(a);
+    }
+    let t = (d.dot(p - a) / len2).clamp(0.0, 1.0);
+    p.distance(a + t * d)
+}
+
+// [ref:needs_user_doc]
+#[derive(Default)]
+pub struct MeasureToolInner {
+    layer: OnceCell<Layer>,
+    active: Cell<bool>,
+    persistent: Cell<bool>,
+    dragging: Cell<bool>,
+    line: Cell<Option<(Point, Point)>>,
+}
+
+#[glib::object_subclass]
+impl ObjectSubclass for MeasureToolInner {
+    const NAME: &'static str = "MeasureTool";
+    type ParentType = ToolImpl;
+    type Type = MeasureTool;
+}
+
+impl ObjectImpl for MeasureToolInner {
+    fn constructed(&self, obj: &Self::Type) {
+        self.parent_constructed(obj);
+        obj.set_property::<bool>(MeasureTool::ACTIVE, false);
+        obj.set_property::<String>(ToolImpl::NAME, "measure".to_string());
+        obj.set_property::<String>(
+            ToolImpl::DESCRIPTION,
+            "Measure distances, angles and stem widths".to_string(),
+        );
+        obj.set_property::<gtk::Image>(
+            ToolImpl::ICON,
+            crate::resources::icons::MEASURE_ICON.to_image_widget(),
+        );
+    }
+
+    fn properties() -> &'static [glib::ParamSpec] {
+        static PROPERTIES: once_cell::sync::Lazy<Vec<glib::ParamSpec>> =
+            once_cell::sync::Lazy::new(|| {
+                vec![
+                    glib::ParamSpecBoolean::new(
+                        MeasureTool::ACTIVE,
+                        MeasureTool::ACTIVE,
+                        MeasureTool::ACTIVE,
+                        true,
+                        glib::ParamFlags::READWRITE,
+                    ),
+                    glib::ParamSpecBoolean::new(
+                        MeasureTool::PERSISTENT,
+                        MeasureTool::PERSISTENT,
+                        "Keep measurement lines in the glyph's lib.",
+                        false,
+                        glib::ParamFlags::READWRITE,
+                    ),
+                ]
+            });
+        PROPERTIES.as_ref()
+    }
+
+    fn property(&self, _obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+        match pspec.name() {
+            MeasureTool::ACTIVE => self.active.get().to_value(),
+            MeasureTool::PERSISTENT => self.persistent.get().to_value(),
+            _ => unimplemented!("{}", pspec.name()),
+        }
+    }
+
+    fn set_property(
+        &self,
+        _obj: &Self::Type,
+        _id: usize,
+        value: &glib::Value,
+        pspec: &glib::ParamSpec,
+    ) {
+        match pspec.name() {
+            MeasureTool::ACTIVE => self.active.set(value.get().unwrap()),
+            MeasureTool::PERSISTENT => self.persistent.set(value.get().unwrap()),
+            _ => unimplemented!("{}", pspec.name()),
+        }
+    }
+}
+
+impl ToolImplImpl for MeasureToolInner {
+    fn on_button_press_event(
+        &self,
+        _obj: &ToolImpl,
+        view: Editor,
+        viewport: &Canvas,
+        event: &gtk::gdk::EventButton,
+    ) -> Inhibit {
+        if !self.active.get() {
+            return Inhibit(false);
+        }
+        let UnitPoint(position) = viewport.view_to_unit_point(ViewPoint(event.position().into()));
+        match event.button() {
+            gtk::gdk::BUTTON_PRIMARY => {
+                self.line.set(Some((position, position)));
+                self.dragging.set(true);
+            }
+            gtk::gdk::BUTTON_SECONDARY => {
+                self.dragging.set(false);
+                self.line.set(None);
+                if self.persistent.get() {
+                    /* Remove the stored measurement under the cursor, if any. */
+                    let scale: f64 = viewport
+                        .transformation
+                        .property::<f64>(Transformation::SCALE);
+                    let ppu: f64 = viewport
+                        .transformation
+                        .property::<f64>(Transformation::PIXELS_PER_UNIT);
+                    let mut lines = {
+                        let state = view.state().borrow();
+                        let glyph = state.glyph.borrow();
+                        MeasureTool::stored_measurements(&glyph)
+                    };
+                    if let Some(i) = lines
+                        .iter()
+                        .position(|l| segment_distance(position, *l) < 5.0 / (scale * ppu))
+                    {
+                        lines.remove(i);
+                        MeasureTool::replace_stored_measurements(&view, lines);
+                    }
+                }
+            }
+            _ => return Inhibit(false),
+        }
+        viewport.queue_draw();
+        Inhibit(true)
+    }
+
+    fn on_button_release_event(
+        &self,
+        _obj: &ToolImpl,
+        view: Editor,
+        viewport: &Canvas,
+        event: &gtk::gdk::EventButton,
+    ) -> Inhibit {
+        if !self.active.get() || !self.dragging.get() || event.button() != gtk::gdk::BUTTON_PRIMARY
+        {
+            return Inhibit(false);
+        }
+        self.dragging.set(false);
+        if let Some((a, b)) = self.line.get() {
+            if self.persistent.get() && a.distance(b) > f64::EPSILON {
+                let mut lines = {
+                    let state = view.state().borrow();
+                    let glyph = state.glyph.borrow();
+                    MeasureTool::stored_measurements(&glyph)
+                };
+                lines.push((a, b));
+                MeasureTool::replace_stored_measurements(&view, lines);
+                self.line.set(None);
+            }
+        }
+        viewport.queue_draw();
+        Inhibit(true)
+    }
+
+    fn on_motion_notify_event(
+        &self,
+        _obj: &ToolImpl,
+        _view: Editor,
+        viewport: &Canvas,
+        event: &gtk::gdk::EventMotion,
+    ) -> Inhibit {
+        if !self.active.get() || !self.dragging.get() {
+            return Inhibit(false);
+        }
+        let Some((a, _)) = self.line.get() else {
+            return Inhibit(false);
+        };
+        let UnitPoint(mut b) = viewport.view_to_unit_point(ViewPoint(event.position().into()));
+        if event.state().contains(gtk::gdk::ModifierType::SHIFT_MASK) {
+            /* Constrain to multiples of 15 degrees. */
+            let d = b - a;
+            let step = std::f64::consts::PI / 12.0;
+            let angle = (d.y.atan2(d.x) / step).round() * step;
+            b = a + d.norm() * Point::from((angle.cos(), angle.sin()));
+        }
+        self.line.set(Some((a, b)));
+        viewport.queue_draw();
+        Inhibit(true)
+    }
+
+    fn setup_toolbox(&self, obj: &ToolImpl, toolbar: &gtk::Toolbar, view: &Editor) {
+        let layer =
+            LayerBuilder::new()
+                .set_name(Some("measure"))
+                .set_active(false)
+                .set_hidden(true)
+                .set_callback(Some(Box::new(clone!(@weak view => @default-return Inhibit(false), move |viewport: &Canvas, cr: ContextRef| {
+                    MeasureTool::draw_layer(viewport, cr, view)
+                }))))
+                .build();
+        self.instance()
+            .bind_property(MeasureTool::ACTIVE, &layer, Layer::ACTIVE)
+            .flags(glib::BindingFlags::SYNC_CREATE)
+            .build();
+        self.layer.set(layer.clone()).unwrap();
+        view.viewport.add_post_layer(layer);
+        {
+            let prop_action = gtk::gio::PropertyAction::new(
+                Editor::MEASURE_PERSISTENT_ACTION,
+                &self.instance(),
+                MeasureTool::PERSISTENT,
+            );
+            view.action_group.add_action(&prop_action);
+        }
+
+        self.parent_setup_toolbox(obj, toolbar, view)
+    }
+
+    fn on_activate(&self, obj: &ToolImpl, view: &Editor) {
+        self.instance()
+            .set_property::<bool>(MeasureTool::ACTIVE, true);
+        view.viewport.set_cursor("crosshair");
+        self.parent_on_activate(obj, view)
+    }
+
+    fn on_deactivate(&self, obj: &ToolImpl, view: &Editor) {
+        self.dragging.set(false);
+        self.line.set(None);
+        self.instance()
+            .set_property::<bool>(MeasureTool::ACTIVE, false);
+        view.viewport.set_cursor("default");
+        self.parent_on_deactivate(obj, view)
+    }
+}
+
+glib::wrapper! {
+    pub struct MeasureTool(ObjectSubclass<MeasureToolInner>)
+        @extends ToolImpl;
+}
+
+impl Default for MeasureTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Editor {
+    pub const MEASURE_PERSISTENT_ACTION: &str = "measure.persistent";
+}
+
+impl MeasureTool {
+    pub const ACTIVE: &str = "active";
+    pub const PERSISTENT: &str = "persistent";
+    /// Key of the measurement lines in the glyph lib, stored as an array of dictionaries with
+    /// `x1`, `y1`, `x2` and `y2` keys.
+    pub const LIB_KEY: &str = "io.github.epilys.gerb.measurements";
+
+    pub fn new() -> Self {
+        glib::Object::new(&[]).unwrap()
+    }
+
+    pub fn stored_measurements(glyph: &Glyph) -> Vec<(Point, Point)> {
+        let Some(lines) = glyph
+            .lib
+            .get(Self::LIB_KEY)
+            .and_then(plist::Value::as_array)
+        else {
+            return vec![];
+        };
+        lines
+            .iter()
+            .filter_map(plist::Value::as_dictionary)
+            .filter_map(|d| {
+                let get = |key: &str| d.get(key).and_then(plist::Value::as_real);
+                Some((
+                    Point::from((get("x1")?, get("y1")?)),
+                    Point::from((get("x2")?, get("y2")?)),
+                ))
+            })
+            .collect()
+    }
+
+    pub fn set_stored_measurements(glyph: &mut Glyph, lines: &[(Point, Point)]) {
+        if lines.is_empty() {
+            glyph.lib.shift_remove(Self::LIB_KEY);
+        } else {
+            let lines = lines
+                .iter()
+                .map(|(a, b)| {
+                    let mut d = plist::Dictionary::new();
+                    d.insert("x1".into(), a.x.into());
+                    d.insert("y1".into(), a.y.into());
+                    d.insert("x2".into(), b.x.into());
+                    d.insert("y2".into(), b.y.into());
+                    plist::Value::Dictionary(d)
+                })
+                .collect::<Vec<plist::Value>>();
+            glyph
+                .lib
+                .insert(Self::LIB_KEY.to_string(), plist::Value::Array(lines));
+        }
+        glyph.metadata.set_property(GlyphMetadata::MODIFIED, true);
+    }
+
+    /// Replaces the stored measurement lines of the edited glyph as an undoable action.
+    fn replace_stored_measurements(view: &Editor, lines: Vec<(Point, Point)>) {
+        let state = view.state().borrow();
+        let other = Rc::new(RefCell::new(lines));
+        let swap = Rc::new(
+            clone!(@strong other, @weak state.glyph as glyph, @weak view.viewport as viewport => move || {
+                let mut glyph = glyph.borrow_mut();
+                let current = Self::stored_measurements(&glyph);
+                Self::set_stored_measurements(&mut glyph, &other.borrow());
+                *other.borrow_mut() = current;
+                viewport.queue_draw();
+            }),
+        );
+        let mut action = Action {
+            stamp: EventStamp {
+                t: std::any::TypeId::of::<Self>(),
+                property: Self::LIB_KEY,
+                id: Box::new([0; 0]),
+            },
+            compress: false,
+            redo: Box::new(clone!(@strong swap => move || swap())),
+            undo: Box::new(move || swap()),
+        };
+        (action.redo)();
+        state.add_undo_action(action);
+    }
+
+    pub fn draw_layer(viewport: &Canvas, cr: ContextRef, obj: Editor) -> Inhibit {
+        let state = obj.state().borrow();
+        if Self::static_type() != state.active_tool {
+            return Inhibit(false);
+        }
+        let t = state.tools[&state.active_tool]
+            .clone()
+            .downcast::<Self>()
+            .unwrap();
+        if !t.imp().active.get() {
+            return Inhibit(false);
+        }
+        let glyph = state.glyph.borrow();
+        let mut lines = if t.imp().persistent.get() {
+            Self::stored_measurements(&glyph)
+        } else {
+            vec![]
+        };
+        lines.extend(t.imp().line.get());
+        let line_width = obj
+            .settings
+            .get()
+            .unwrap()
+            .property::<f64>(Settings::LINE_WIDTH);
+        let to_view = |p: Point| viewport.unit_to_view_point(UnitPoint(p)).0;
+        cr.set_line_width(line_width);
+        for (a, b) in lines {
+            let (va, vb) = (to_view(a), to_view(b));
+            cr.set_source_rgba(0.2, 0.2, 0.2, 0.8);
+            cr.move_to(va.x, va.y);
+            cr.line_to(vb.x, vb.y);
+            cr.stroke().expect("Invalid cairo surface state");
+
+            let d = b - a;
+            let label = format!("{:.1} units, {:.1}°", d.norm(), d.y.atan2(d.x).to_degrees());
+            cr.move_to(vb.x + 5.0, vb.y - 5.0);
+            cr.show_text(&label).expect("Invalid cairo surface state");
+
+            let intersections = measure_contours(&glyph.contours, a, b);
+            cr.set_source_rgba(0.9, 0.1, 0.1, 0.9);
+            for p in intersections.iter().map(|p| to_view(*p)) {
+                cr.arc(p.x, p.y, 3.0, 0.0, 2.0 * std::f64::consts::PI);
+                cr.fill().expect("Invalid cairo surface state");
+            }
+            for pair in intersections.windows(2) {
+                let mid = to_view(0.5 * (pair[0] + pair[1]));
+                let label = format!("{:.1}", pair[0].distance(pair[1]));
+                cr.move_to(mid.x + 5.0, mid.y + 12.0);
+                cr.show_text(&label).expect("Invalid cairo surface state");
+            }
+        }
+
+        Inhibit(true)
+    }
+}
+
+#[test]
+fn test_measure_contours() {
+    use crate::utils::curves::Bezier;
+
+    /* A 100 units wide square with a 20 units thick hole: two stems of 20 units. */
+    let square = |x0: f64, y0: f64, x1: f64, y1: f64| {
+        let contour = Contour::new();
+        let corners = [(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)];
+        for w in corners.windows(2) {
+            contour.push_curve(Bezier::new(vec![w[0].into(), w[1].into()]));
+        }
+        contour.close();
+        contour
+    };
+    let contours = [
+        square(0.0, 0.0, 100.0, 100.0),
+        square(20.0, 20.0, 80.0, 80.0),
+    ];
+    let points = measure_contours(
+        &contours,
+        Point::from((-10.0, 50.0)),
+        Point::from((110.0, 50.0)),
+    );
+    assert_eq!(points.len(), 4);
+    let widths = points
+        .windows(2)
+        .map(|w| w[0].distance(w[1]))
+        .collect::<Vec<f64>>();
+    for (w, expected) in widths.iter().zip([20.0, 60.0, 20.0]) {
+        assert!((w - expected).abs() < 1e-6, "{w} != {expected}");
+    }
+    /* Crossing through a corner reports it once. */
+    let points = measure_contours(
+        &contours[..1],
+        Point::from((-10.0, -10.0)),
+        Point::from((50.0, 50.0)),
+    );
+    assert_eq!(points.len(), 1);
+}
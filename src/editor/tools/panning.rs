@@ -23,7 +23,7 @@ use super::{constraints::*, tool_impl::*, MoveDirection, SelectionAction, Select
 
 use gtk::cairo::Matrix;
 use gtk::Inhibit;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 use crate::glyphs::Contour;
 use crate::prelude::*;
@@ -571,7 +571,7 @@ impl ToolImplImpl for PanningToolInner {
             }
             _ if event_button == gtk::gdk::BUTTON_SECONDARY => {
                 self.set_default_cursor(&view);
-                let (is_corner, on_curve_query) = {
+                let (is_corner, hit, on_curve_query, position) = {
                     let scale: f64 = viewport
                         .transformation
                         .property::<f64>(Transformation::SCALE);
@@ -597,8 +597,21 @@ impl ToolImplImpl for PanningToolInner {
                                 .and_then(|cp| Some((*p, cp.continuity?)))
                         })
                     };
+                    let hit = pts.first().and_then(|p| {
+                        let contour = &glyph.contours[p.contour_index];
+                        let point_index = contour.curves()[p.curve_index]
+                            .points()
+                            .iter()
+                            .position(|cp| cp.uuid == p.uuid)?;
+                        Some((
+                            p.contour_index,
+                            p.curve_index,
+                            point_index,
+                            contour.oncurve_index(p.curve_index, point_index),
+                        ))
+                    });
                     let on_curve_query = glyph.on_curve_query(position, &[]);
-                    (is_corner, on_curve_query)
+                    (is_corner, hit, on_curve_query, position)
                 };
                 if let Some((idx, corner_continuity)) = is_corner {
                     let mut menu = crate::utils::menu::Menu::new()
@@ -652,10 +665,48 @@ impl ToolImplImpl for PanningToolInner {
                     } else {
                         menu.add_button("make tangent")
                     };
+                    if let Some(hit) = hit {
+                        menu = point_menu(menu.separator(), &view, hit);
+                    }
                     menu.popup(event.time());
                     return Inhibit(true);
-                } else if let Some(((contour_index, _), _curve)) = on_curve_query {
-                    crate::utils::menu::Menu::new()
+                } else if let Some(hit) = hit {
+                    point_menu(crate::utils::menu::Menu::new(), &view, hit).popup(event.time());
+                    return Inhibit(true);
+                } else if let Some(((contour_index, curve_index), curve)) = on_curve_query {
+                    let t = curve.nearest_t(position);
+                    let (is_line, is_open) = (
+                        curve.degree() == Some(1),
+                        view.state().borrow().glyph.borrow().contours[contour_index]
+                            .imp()
+                            .open
+                            .get(),
+                    );
+                    let mut menu = crate::utils::menu::Menu::new()
+                        .add_button_cb(
+                            "insert point",
+                            clone!(@strong view => move |_| {
+                                let new = view.state().borrow().glyph.borrow().contours[contour_index]
+                                    .with_point_inserted(curve_index, t);
+                                replace_contour(&view, contour_index, vec![new]);
+                            }),
+                        )
+                        .add_button_cb(
+                            if is_line { "make curve" } else { "make line" },
+                            clone!(@strong view => move |_| {
+                                let new = {
+                                    let state = view.state().borrow();
+                                    let glyph = state.glyph.borrow();
+                                    let contour = &glyph.contours[contour_index];
+                                    if is_line {
+                                        contour.with_line_as_curve(curve_index)
+                                    } else {
+                                        contour.with_curve_as_line(curve_index)
+                                    }
+                                };
+                                replace_contour(&view, contour_index, vec![new]);
+                            }),
+                        )
                         .add_button_cb(
                             "reverse",
                             clone!(@strong view => move |_| {
@@ -667,8 +718,18 @@ impl ToolImplImpl for PanningToolInner {
                                 let undo_db = app.undo_db.borrow();
                                 undo_db.event(action);
                             }),
-                        )
-                        .popup(event.time());
+                        );
+                    if is_open {
+                        menu = menu.add_button_cb(
+                            "close contour",
+                            clone!(@strong view => move |_| {
+                                let new = view.state().borrow().glyph.borrow().contours[contour_index]
+                                    .joined(None);
+                                replace_contour(&view, contour_index, vec![new]);
+                            }),
+                        );
+                    }
+                    menu.popup(event.time());
                     return Inhibit(true);
                 }
                 return Inhibit(false);
@@ -1444,6 +1505,113 @@ impl PanningTool {
         view.queue_draw();
     }
 
+    /// Deletes the selected points. On-curve points are removed and their neighbouring curves
+    /// refitted, handles are retracted.
+    pub fn delete_selection(&self, view: &Editor) {
+        let contours = {
+            let state = view.state().borrow();
+            let glyph = state.glyph.borrow();
+            let selection = state.get_selection_set();
+            if selection.is_empty() {
+                return;
+            }
+            let mut contours = glyph.contours.clone();
+            for contour_index in (0..contours.len()).rev() {
+                let (mut handles, mut oncurves) = (vec![], BTreeSet::new());
+                let contour = contours[contour_index].clone();
+                for (curve_index, curve) in contour.curves().iter().enumerate() {
+                    for (point_index, cp) in curve.points().iter().enumerate() {
+                        if !selection.contains(&cp.uuid) {
+                            continue;
+                        }
+                        match contour.oncurve_index(curve_index, point_index) {
+                            Some(i) => {
+                                oncurves.insert(i);
+                            }
+                            None => handles.push((curve_index, point_index)),
+                        }
+                    }
+                }
+                if handles.is_empty() && oncurves.is_empty() {
+                    continue;
+                }
+                let mut new = Some(contour);
+                for (curve_index, point_index) in handles.into_iter().rev() {
+                    new = new.map(|c| c.with_handle_retracted(curve_index, point_index));
+                }
+                for i in oncurves.into_iter().rev() {
+                    new = new.and_then(|c| c.with_point_deleted(i));
+                }
+                match new {
+                    Some(c) => contours[contour_index] = c,
+                    None => {
+                        contours.remove(contour_index);
+                    }
+                }
+            }
+            contours
+        };
+        view.replace_contours(contours);
+    }
+
+    /// Joins the two selected end points of open contours. If both belong to the same contour,
+    /// it is closed.
+    pub fn join_selection(&self, view: &Editor) {
+        let contours = {
+            let state = view.state().borrow();
+            let glyph = state.glyph.borrow();
+            let selection = state.get_selection_set();
+            let mut ends = vec![];
+            for (contour_index, contour) in glyph.contours.iter().enumerate() {
+                if !contour.imp().open.get() {
+                    continue;
+                }
+                let curves = contour.curves();
+                let (Some(first), Some(last)) = (curves.first(), curves.last()) else {
+                    continue;
+                };
+                if first
+                    .points()
+                    .first()
+                    .map(|cp| selection.contains(&cp.uuid))
+                    == Some(true)
+                {
+                    ends.push((contour_index, true));
+                }
+                /* A contour of a single point has only one end. */
+                let single_point = curves.len() == 1 && first.points().len() == 1;
+                if !single_point
+                    && last.points().last().map(|cp| selection.contains(&cp.uuid)) == Some(true)
+                {
+                    ends.push((contour_index, false));
+                }
+            }
+            let [(a, a_is_start), (b, b_is_start)] = ends[..] else {
+                return;
+            };
+            let mut contours = glyph.contours.clone();
+            if a == b {
+                contours[a] = contours[a].joined(None);
+            } else {
+                /* Connect the end of `a` to the start of `b`. */
+                let first = if a_is_start {
+                    contours[a].reversed()
+                } else {
+                    contours[a].clone()
+                };
+                let second = if b_is_start {
+                    contours[b].clone()
+                } else {
+                    contours[b].reversed()
+                };
+                contours[a] = first.joined(Some(&second));
+                contours.remove(b);
+            }
+            contours
+        };
+        view.replace_contours(contours);
+    }
+
     pub fn selection_action(&self, view: &Editor, action: SelectionAction) {
         match action {
             SelectionAction::All => {
@@ -1523,6 +1691,49 @@ fn snap_to_closest_anchor(
     candidates.get(0).map(|(p, _)| *p)
 }
 
+/// Context menu entries for the point `(contour_index, curve_index, point_index, on-curve
+/// index)`.
+fn point_menu(
+    menu: crate::utils::menu::Menu,
+    view: &Editor,
+    (contour_index, curve_index, point_index, oncurve_index): (usize, usize, usize, Option<usize>),
+) -> crate::utils::menu::Menu {
+    let view = view.clone();
+    let menu = menu.add_button_cb(
+        "delete point",
+        clone!(@strong view => move |_| {
+            let new = {
+                let state = view.state().borrow();
+                let glyph = state.glyph.borrow();
+                let contour = &glyph.contours[contour_index];
+                match oncurve_index {
+                    Some(i) => contour.with_point_deleted(i),
+                    None => Some(contour.with_handle_retracted(curve_index, point_index)),
+                }
+            };
+            replace_contour(&view, contour_index, new.into_iter().collect());
+        }),
+    );
+    let Some(oncurve_index) = oncurve_index else {
+        return menu;
+    };
+    menu.add_button_cb(
+        "break contour here",
+        clone!(@strong view => move |_| {
+            let new = view.state().borrow().glyph.borrow().contours[contour_index]
+                .broken_at(oncurve_index);
+            replace_contour(&view, contour_index, new);
+        }),
+    )
+}
+
+/// Replaces contour `contour_index` with `new` contours, as one undoable action.
+fn replace_contour(view: &Editor, contour_index: usize, new: Vec<Contour>) {
+    let mut contours = view.state().borrow().glyph.borrow().contours.clone();
+    contours.splice(contour_index..=contour_index, new);
+    view.replace_contours(contours);
+}
+
 fn change_continuity(
     view: &Editor,
     index: GlyphPointIndex,
@@ -21,6 +21,7 @@
 
 use crate::prelude::*;
 
+pub mod conflict;
 pub mod tab;
 pub use tab::*;
 
@@ -146,6 +147,367 @@ impl Repository {
             self.repository.get().unwrap().status_file(path).ok()
         }
     }
+
+    pub fn workdir(&self) -> FieldRef<'_, PathBuf> {
+        self.workdir.borrow().into()
+    }
+
+    /// Returns `path` relative to the repository's working directory.
+    pub fn relative_path<'p>(&self, path: &'p Path) -> Option<Cow<'p, Path>> {
+        if !path.is_absolute() {
+            return Some(Cow::Borrowed(path));
+        }
+        let workdir = self.workdir.borrow();
+        if let Ok(p) = path.strip_prefix(&*workdir) {
+            return Some(Cow::Owned(p.to_path_buf()));
+        }
+        let canonical = std::fs::canonicalize(path).ok()?;
+        canonical
+            .strip_prefix(&*workdir)
+            .ok()
+            .map(|p| Cow::Owned(p.to_path_buf()))
+    }
+
+    /// Lists every path in the working directory or index that differs from `HEAD`, along
+    /// with its status.
+    pub fn changed_files(
+        &self,
+    ) -> Result<Vec<(PathBuf, git2::Status)>, Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        let statuses = r.statuses(Some(
+            git2::StatusOptions::new()
+                .include_untracked(true)
+                .recurse_untracked_dirs(true)
+                .include_ignored(false)
+                .renames_head_to_index(true),
+        ))?;
+        Ok(statuses
+            .iter()
+            .filter(|e| !e.status().is_ignored())
+            .filter_map(|e| Some((PathBuf::from(e.path()?), e.status())))
+            .collect())
+    }
+
+    /// Adds `path` (relative to the working directory) to the index. Deleted files are removed
+    /// from the index instead.
+    pub fn stage(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        let mut index = r.index()?;
+        if self.workdir.borrow().join(path).exists() {
+            index.add_path(path)?;
+        } else {
+            index.remove_path(path)?;
+        }
+        index.write()?;
+        Ok(())
+    }
+
+    /// Resets the index entry of `path` to its state in `HEAD`.
+    pub fn unstage(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        match r.head().ok().and_then(|h| h.peel_to_commit().ok()) {
+            Some(head) => {
+                r.reset_default(Some(head.as_object()), [path])?;
+            }
+            None => {
+                // No commits yet, so there is nothing to reset to.
+                let mut index = r.index()?;
+                index.remove_path(path)?;
+                index.write()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates a commit on `HEAD` from the current index.
+    pub fn commit(&self, message: &str) -> Result<git2::Oid, Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        if message.trim().is_empty() {
+            return Err("Commit message is empty.".into());
+        }
+        let signature = r
+            .signature()
+            .map_err(|err| format!("Could not find author identity in git config: {err}"))?;
+        let mut index = r.index()?;
+        let tree_id = index.write_tree()?;
+        let tree = r.find_tree(tree_id)?;
+        let parent = r.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents = parent.iter().collect::<Vec<&git2::Commit>>();
+        let oid = r.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            &parents,
+        )?;
+        self.state.set(r.state().into());
+        Ok(oid)
+    }
+
+    /// Walks history from `HEAD`, newest first, returning at most `limit` entries.
+    pub fn log(&self, limit: usize) -> Result<Vec<CommitInfo>, Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        if r.head().is_err() {
+            return Ok(vec![]);
+        }
+        let mut revwalk = r.revwalk()?;
+        revwalk.push_head()?;
+        revwalk.set_sorting(git2::Sort::TIME)?;
+        let mut ret = vec![];
+        for oid in revwalk.take(limit) {
+            let commit = r.find_commit(oid?)?;
+            ret.push(CommitInfo::from(&commit));
+        }
+        Ok(ret)
+    }
+
+    /// Paths changed by commit `oid` relative to its first parent.
+    pub fn commit_changed_files(
+        &self,
+        oid: git2::Oid,
+    ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        let commit = r.find_commit(oid)?;
+        let tree = commit.tree()?;
+        let parent_tree = commit.parent(0).ok().map(|p| p.tree()).transpose()?;
+        let diff = r.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?;
+        Ok(diff
+            .deltas()
+            .filter_map(|d| d.new_file().path().or_else(|| d.old_file().path()))
+            .map(Path::to_path_buf)
+            .collect())
+    }
+
+    /// Contents of `path` at `revision`, which is any revspec git understands (e.g. `HEAD`,
+    /// `HEAD~2` or an object id). Returns `Ok(None)` if the file did not exist then.
+    pub fn file_at_revision(
+        &self,
+        revision: &str,
+        path: &Path,
+    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        let tree = match r.revparse_single(revision).and_then(|o| o.peel_to_tree()) {
+            Ok(t) => t,
+            Err(err) if err.code() == git2::ErrorCode::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let entry = match tree.get_path(path) {
+            Ok(e) => e,
+            Err(err) if err.code() == git2::ErrorCode::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let blob = r.find_blob(entry.id())?;
+        Ok(Some(String::from_utf8_lossy(blob.content()).into_owned()))
+    }
+
+    /// Paths that differ between `revision` and the working tree, including staged and
+    /// untracked files.
+    pub fn changed_since(
+        &self,
+        revision: &str,
+    ) -> Result<HashSet<PathBuf>, Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        let tree = r.revparse_single(revision)?.peel_to_tree()?;
+        let diff = r.diff_tree_to_workdir_with_index(
+            Some(&tree),
+            Some(
+                git2::DiffOptions::new()
+                    .include_untracked(true)
+                    .recurse_untracked_dirs(true),
+            ),
+        )?;
+        Ok(diff
+            .deltas()
+            .filter_map(|d| d.new_file().path().or_else(|| d.old_file().path()))
+            .map(Path::to_path_buf)
+            .collect())
+    }
+
+    /// Names of local branches.
+    pub fn branches(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        let mut ret = vec![];
+        for b in r.branches(Some(git2::BranchType::Local))? {
+            if let Some(name) = b?.0.name()? {
+                ret.push(name.to_string());
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Path of the index file, which changes whenever something is staged.
+    pub fn index_path(&self) -> PathBuf {
+        self.repository.get().unwrap().path().join("index")
+    }
+
+    /// Unresolved merge conflicts of `.glif` files in the index, with the contents of each
+    /// stage.
+    pub fn conflicts(&self) -> Result<Vec<Conflict>, Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        let index = r.index()?;
+        if !index.has_conflicts() {
+            return Ok(vec![]);
+        }
+        let blob = |entry: &Option<git2::IndexEntry>| -> Result<Option<String>, git2::Error> {
+            entry
+                .as_ref()
+                .map(|e| {
+                    r.find_blob(e.id)
+                        .map(|b| String::from_utf8_lossy(b.content()).into_owned())
+                })
+                .transpose()
+        };
+        let mut ret = vec![];
+        for conflict in index.conflicts()? {
+            let conflict = conflict?;
+            let Some(entry) = conflict
+                .our
+                .as_ref()
+                .or(conflict.their.as_ref())
+                .or(conflict.ancestor.as_ref())
+            else {
+                continue;
+            };
+            let path = PathBuf::from(String::from_utf8_lossy(&entry.path).into_owned());
+            if path.extension().and_then(|e| e.to_str()) != Some("glif") {
+                continue;
+            }
+            ret.push(Conflict {
+                path,
+                ancestor: blob(&conflict.ancestor)?,
+                ours: blob(&conflict.our)?,
+                theirs: blob(&conflict.their)?,
+            });
+        }
+        Ok(ret)
+    }
+
+    /// Writes `contents` to `path` in the working tree and stages it, which marks its conflict
+    /// as resolved.
+    pub fn resolve(&self, path: &Path, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        std::fs::write(self.workdir.borrow().join(path), contents)?;
+        let mut index = r.index()?;
+        index.add_path(path)?;
+        index.write()?;
+        Ok(())
+    }
+
+    /// Contents of `path` as currently staged in the index.
+    pub fn file_in_index(&self, path: &Path) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        let r = self.repository.get().unwrap();
+        let index = r.index()?;
+        let Some(entry) = index.get_path(path, 0) else {
+            return Ok(None);
+        };
+        let blob = r.find_blob(entry.id)?;
+        Ok(Some(String::from_utf8_lossy(blob.content()).into_owned()))
+    }
+}
+
+/// The three sides of a conflicted file. A side is `None` if the file does not exist in it.
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    pub path: PathBuf,
+    pub ancestor: Option<String>,
+    pub ours: Option<String>,
+    pub theirs: Option<String>,
+}
+
+/// Summary of a commit for display purposes.
+#[derive(Debug, Clone)]
+pub struct CommitInfo {
+    pub oid: git2::Oid,
+    pub summary: String,
+    pub message: String,
+    pub author: String,
+    pub time: i64,
+}
+
+impl From<&git2::Commit<'_>> for CommitInfo {
+    fn from(commit: &git2::Commit<'_>) -> Self {
+        Self {
+            oid: commit.id(),
+            summary: commit.summary().unwrap_or_default().to_string(),
+            message: commit.message().unwrap_or_default().to_string(),
+            author: commit.author().name().unwrap_or_default().to_string(),
+            time: commit.time().seconds(),
+        }
+    }
+}
+
+impl CommitInfo {
+    pub fn short_id(&self) -> String {
+        let mut s = self.oid.to_string();
+        s.truncate(7);
+        s
+    }
+}
+
+/// Git status of a glyph's `.glif` file, as shown in the glyph collection.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "GlyphGitStatus")]
+pub enum GlyphGitStatus {
+    #[default]
+    Unchanged,
+    Modified,
+    New,
+    Staged,
+    Conflicted,
+}
+
+impl From<git2::Status> for GlyphGitStatus {
+    fn from(status: git2::Status) -> Self {
+        if status.is_conflicted() {
+            Self::Conflicted
+        } else if status.is_wt_new() {
+            Self::New
+        } else if status.intersects(
+            git2::Status::WT_MODIFIED
+                | git2::Status::WT_DELETED
+                | git2::Status::WT_RENAMED
+                | git2::Status::WT_TYPECHANGE,
+        ) {
+            Self::Modified
+        } else if is_staged(status) {
+            Self::Staged
+        } else {
+            Self::Unchanged
+        }
+    }
+}
+
+/// Short human readable label for a file's [`git2::Status`].
+pub fn status_label(status: git2::Status) -> &'static str {
+    if status.is_conflicted() {
+        "conflicted"
+    } else if status.is_index_new() || status.is_wt_new() {
+        "new"
+    } else if status.is_index_deleted() || status.is_wt_deleted() {
+        "deleted"
+    } else if status.is_index_renamed() || status.is_wt_renamed() {
+        "renamed"
+    } else if status.is_index_modified()
+        || status.is_wt_modified()
+        || status.is_index_typechange()
+        || status.is_wt_typechange()
+    {
+        "modified"
+    } else {
+        ""
+    }
+}
+
+/// Whether any of `status`'s changes are in the index.
+pub fn is_staged(status: git2::Status) -> bool {
+    status.intersects(
+        git2::Status::INDEX_NEW
+            | git2::Status::INDEX_MODIFIED
+            | git2::Status::INDEX_DELETED
+            | git2::Status::INDEX_RENAMED
+            | git2::Status::INDEX_TYPECHANGE,
+    )
 }
 
 /// A listing of the possible states that a repository can be in.
@@ -0,0 +1,412 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Resolution of merge conflicts in `.glif` files by picking whole contours from each side.
+
+use super::{Conflict, Repository};
+use crate::glyphs::{Glyph, GlyphDrawingOptions};
+use crate::prelude::*;
+use crate::ufo::glif::{Glif, Outline, OutlineEntry};
+use crate::ufo::objects::Layer;
+use gtk::cairo::{Context, Matrix};
+
+// [ref:hardcoded_color_value]
+const PICKED_COLOR: Color = Color::from_hex("#1f77b4");
+
+/// One side of a conflict, with each of its contours converted to a glyph so that they can be
+/// drawn individually.
+#[derive(Debug)]
+struct Side {
+    glif: Option<Glif>,
+    contours: Vec<OutlineEntry>,
+    contour_glyphs: Vec<Glyph>,
+    picked: RefCell<Vec<bool>>,
+}
+
+impl Side {
+    /// `source` is `None` if the glyph does not exist on this side.
+    fn new(source: Option<&str>, picked: bool) -> Result<Self, Box<dyn std::error::Error>> {
+        let glif = source.map(Glif::from_str).transpose()?;
+        let contours = glif
+            .iter()
+            .flat_map(|g| g.outline.iter())
+            .flat_map(|o| o.contours.iter())
+            .filter(|e| matches!(e, OutlineEntry::Contour(_)))
+            .cloned()
+            .collect::<Vec<_>>();
+        let contour_glyphs = glif
+            .as_ref()
+            .map(|g| {
+                contours
+                    .iter()
+                    .map(|c| {
+                        let mut g = g.clone();
+                        g.outline = Some(Outline {
+                            contours: vec![c.clone()],
+                        });
+                        g.into()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Self {
+            picked: RefCell::new(vec![picked; contours.len()]),
+            glif,
+            contours,
+            contour_glyphs,
+        })
+    }
+
+    fn picked_contours(&self) -> impl Iterator<Item = &OutlineEntry> + '_ {
+        let picked = self.picked.borrow().clone();
+        self.contours
+            .iter()
+            .zip(picked.into_iter())
+            .filter_map(|(c, p)| p.then_some(c))
+    }
+}
+
+#[derive(Debug)]
+struct Resolver {
+    app: Application,
+    layer: Option<Layer>,
+    units_per_em: f64,
+    conflict: Conflict,
+    base: Option<Glyph>,
+    ours: Side,
+    theirs: Side,
+    metadata_from_theirs: Cell<bool>,
+}
+
+impl Resolver {
+    /// Merged glyph: components, anchors and metadata from one side, plus the picked contours
+    /// of both.
+    fn result(&self) -> Option<Glif> {
+        let (primary, secondary) = if self.metadata_from_theirs.get() {
+            (&self.theirs, &self.ours)
+        } else {
+            (&self.ours, &self.theirs)
+        };
+        let mut ret = primary.glif.clone().or_else(|| secondary.glif.clone())?;
+        let mut entries = primary
+            .glif
+            .iter()
+            .flat_map(|g| g.outline.iter())
+            .flat_map(|o| o.contours.iter())
+            .filter(|e| matches!(e, OutlineEntry::Component(_)))
+            .cloned()
+            .collect::<Vec<_>>();
+        entries.extend(self.ours.picked_contours().cloned());
+        entries.extend(self.theirs.picked_contours().cloned());
+        ret.outline = Some(Outline { contours: entries });
+        Some(ret)
+    }
+
+    fn draw(&self, area: &gtk::DrawingArea, mut ctx: &Context, glyphs: &[(&Glyph, Color)]) {
+        let colors = self.app.colors();
+        let width = f64::from(area.allocated_width());
+        let height = f64::from(area.allocated_height());
+        let mut cr = ctx.push();
+        cr.set_source_color(colors.theme_base_color);
+        cr.paint().expect("Invalid cairo surface state");
+        let units_per_em = self.units_per_em;
+        let advance = glyphs
+            .first()
+            .and_then(|(g, _)| g.width())
+            .unwrap_or(units_per_em);
+        let scale = 0.8 * height.min(width) / units_per_em;
+        let mut matrix = Matrix::identity();
+        matrix.translate((width - advance * scale) / 2.0, 0.8 * height);
+        matrix.scale(scale, -scale);
+        for (glyph, color) in glyphs {
+            let options = GlyphDrawingOptions {
+                outline: (color.with_alpha(220), 1.5).into(),
+                inner_fill: Some((color.with_alpha(60), 1.5).into()),
+                highlight: None,
+                matrix,
+                units_per_em,
+                ..Default::default()
+            };
+            glyph.draw(cr.push(), options);
+        }
+    }
+
+    /// Draws every contour of `side`, highlighting the picked ones.
+    fn draw_side(&self, side: &Side, area: &gtk::DrawingArea, ctx: &Context) {
+        let fg = self.app.colors().theme_text_color;
+        let picked = side.picked.borrow();
+        let glyphs = side
+            .contour_glyphs
+            .iter()
+            .zip(picked.iter())
+            .map(|(g, p)| (g, if *p { PICKED_COLOR } else { fg.with_alpha(60) }))
+            .collect::<Vec<_>>();
+        self.draw(area, ctx, &glyphs);
+    }
+}
+
+fn column(title: &str, area: &gtk::DrawingArea) -> gtk::Box {
+    let ret = gtk::Box::builder()
+        .orientation(gtk::Orientation::Vertical)
+        .spacing(5)
+        .expand(true)
+        .visible(true)
+        .build();
+    ret.pack_start(
+        &gtk::Label::builder().label(title).visible(true).build(),
+        false,
+        false,
+        0,
+    );
+    area.set_visible(true);
+    area.set_expand(true);
+    area.set_size_request(200, 200);
+    ret.pack_start(area, true, true, 0);
+    ret
+}
+
+/// Opens a window for resolving `conflict`. Once resolved, the glyph is written, staged and
+/// reloaded into `project` and `on_resolved` is called. Fails if a side of the conflict is not a
+/// valid `.glif` file.
+pub fn resolve_conflict_window(
+    app: &Application,
+    project: &Project,
+    repository: &Repository,
+    conflict: Conflict,
+    on_resolved: Box<dyn Fn()>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let abs_path = repository.workdir().join(&conflict.path);
+    let layer = project
+        .all_layers
+        .borrow()
+        .iter()
+        .find(|l| Some(l.path().as_path()) == abs_path.parent())
+        .cloned();
+    let path = conflict.path.display();
+    let base = conflict
+        .ancestor
+        .as_deref()
+        .map(Glif::from_str)
+        .transpose()
+        .map_err(|err| format!("Couldn't parse base version of {path}: {err}"))?
+        .map(|g| Glyph::from_glif_in_layer(g, layer.as_ref()));
+    let ours = Side::new(conflict.ours.as_deref(), true)
+        .map_err(|err| format!("Couldn't parse our version of {path}: {err}"))?;
+    let theirs = Side::new(conflict.theirs.as_deref(), false)
+        .map_err(|err| format!("Couldn't parse their version of {path}: {err}"))?;
+    let resolver = Rc::new(Resolver {
+        app: app.clone(),
+        units_per_em: project.fontinfo().property(FontInfo::UNITS_PER_EM),
+        ours,
+        theirs,
+        base,
+        layer,
+        conflict,
+        metadata_from_theirs: Cell::new(false),
+    });
+
+    let base_area = gtk::DrawingArea::new();
+    let ours_area = gtk::DrawingArea::new();
+    let theirs_area = gtk::DrawingArea::new();
+    let result_area = gtk::DrawingArea::new();
+    base_area.connect_draw(
+        clone!(@strong resolver => @default-return Inhibit(false), move |area, ctx| {
+            let fg = resolver.app.colors().theme_text_color;
+            let glyphs = resolver.base.iter().map(|g| (g, fg)).collect::<Vec<_>>();
+            resolver.draw(area, ctx, &glyphs);
+            Inhibit(false)
+        }),
+    );
+    ours_area.connect_draw(
+        clone!(@strong resolver => @default-return Inhibit(false), move |area, ctx| {
+            resolver.draw_side(&resolver.ours, area, ctx);
+            Inhibit(false)
+        }),
+    );
+    theirs_area.connect_draw(
+        clone!(@strong resolver => @default-return Inhibit(false), move |area, ctx| {
+            resolver.draw_side(&resolver.theirs, area, ctx);
+            Inhibit(false)
+        }),
+    );
+    result_area.connect_draw(
+        clone!(@strong resolver => @default-return Inhibit(false), move |area, ctx| {
+            let fg = resolver.app.colors().theme_text_color;
+            let result = resolver
+                .result()
+                .map(|g| Glyph::from_glif_in_layer(g, resolver.layer.as_ref()));
+            let glyphs = result.iter().map(|g| (g, fg)).collect::<Vec<_>>();
+            resolver.draw(area, ctx, &glyphs);
+            Inhibit(false)
+        }),
+    );
+
+    let columns = gtk::Box::builder()
+        .orientation(gtk::Orientation::Horizontal)
+        .spacing(5)
+        .homogeneous(true)
+        .expand(true)
+        .visible(true)
+        .build();
+    columns.pack_start(&column("Base", &base_area), true, true, 0);
+    for (title, side, area, is_theirs) in [
+        ("Ours", &resolver.ours, &ours_area, false),
+        ("Theirs", &resolver.theirs, &theirs_area, true),
+    ] {
+        let col = column(title, area);
+        let list = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .visible(true)
+            .build();
+        if side.glif.is_none() {
+            list.pack_start(
+                &gtk::Label::builder()
+                    .label("Glyph does not exist on this side.")
+                    .visible(true)
+                    .build(),
+                false,
+                false,
+                0,
+            );
+        }
+        for (i, c) in side.contours.iter().enumerate() {
+            let n = match c {
+                OutlineEntry::Contour(c) => c.point.len(),
+                OutlineEntry::Component(_) => 0,
+            };
+            let check = gtk::CheckButton::builder()
+                .label(&format!("Contour #{i} ({n} points)"))
+                .active(side.picked.borrow()[i])
+                .visible(true)
+                .build();
+            check.connect_toggled(
+                clone!(@strong resolver, @weak area, @weak result_area => move |check| {
+                    let side = if is_theirs { &resolver.theirs } else { &resolver.ours };
+                    side.picked.borrow_mut()[i] = check.is_active();
+                    area.queue_draw();
+                    result_area.queue_draw();
+                }),
+            );
+            list.pack_start(&check, false, false, 0);
+        }
+        col.pack_start(&list, false, false, 0);
+        columns.pack_start(&col, true, true, 0);
+    }
+    let result_col = column("Result", &result_area);
+    let ours_button = gtk::RadioButton::builder()
+        .label("Components, anchors and metadata from ours")
+        .visible(true)
+        .active(true)
+        .build();
+    let theirs_button = gtk::RadioButton::builder()
+        .label("Components, anchors and metadata from theirs")
+        .visible(true)
+        .group(&ours_button)
+        .build();
+    theirs_button.connect_toggled(
+        clone!(@strong resolver, @weak result_area => move |button| {
+            resolver.metadata_from_theirs.set(button.is_active());
+            result_area.queue_draw();
+        }),
+    );
+    result_col.pack_start(&ours_button, false, false, 0);
+    result_col.pack_start(&theirs_button, false, false, 0);
+    columns.pack_start(&result_col, true, true, 0);
+
+    let window = gtk::Window::builder()
+        .title(&format!(
+            "Resolve conflict in {}",
+            resolver.conflict.path.display()
+        ))
+        .attached_to(&app.window)
+        .transient_for(&app.window)
+        .modal(true)
+        .default_width(1000)
+        .default_height(600)
+        .build();
+    let vbox = gtk::Box::builder()
+        .orientation(gtk::Orientation::Vertical)
+        .spacing(5)
+        .border_width(10)
+        .visible(true)
+        .build();
+    vbox.pack_start(&columns, true, true, 0);
+    let buttons = gtk::ButtonBox::builder()
+        .orientation(gtk::Orientation::Horizontal)
+        .layout_style(gtk::ButtonBoxStyle::End)
+        .spacing(5)
+        .visible(true)
+        .build();
+    let cancel = gtk::Button::builder().label("Cancel").visible(true).build();
+    cancel.connect_clicked(clone!(@weak window => move |_| {
+        window.close();
+    }));
+    let resolve = gtk::Button::builder()
+        .label("Write and mark resolved")
+        .visible(true)
+        .build();
+    resolve.connect_clicked(
+        clone!(@strong resolver, @weak window, @weak repository, @weak project => move |_| {
+            let result = resolver
+                .result()
+                .ok_or_else(|| "The glyph does not exist on either side.".to_string())
+                .and_then(|glif| {
+                    repository
+                        .resolve(&resolver.conflict.path, &glif.to_xml())
+                        .map(|()| glif)
+                        .map_err(|err| err.to_string())
+                });
+            let glif = match result {
+                Ok(glif) => glif,
+                Err(err) => {
+                    let dialog = crate::utils::widgets::new_simple_error_dialog(
+                        Some("Error: could not resolve conflict"),
+                        &err,
+                        None,
+                        window.upcast_ref(),
+                    );
+                    dialog.run();
+                    dialog.emit_close();
+                    return;
+                }
+            };
+            let layer = resolver.layer.as_ref().unwrap_or(&project.default_layer);
+            if let Err(err) = project.reload_glyph(layer, &glif.name) {
+                let dialog = crate::utils::widgets::new_simple_error_dialog(
+                    Some("Error: could not reload glyph"),
+                    &err.to_string(),
+                    None,
+                    window.upcast_ref(),
+                );
+                dialog.run();
+                dialog.emit_close();
+            }
+            on_resolved();
+            window.close();
+        }),
+    );
+    buttons.add(&cancel);
+    buttons.add(&resolve);
+    vbox.pack_start(&buttons, false, false, 0);
+    window.set_child(Some(&vbox));
+    window.show_all();
+    Ok(())
+}
@@ -19,8 +19,26 @@
  * along with gerb. If not, see <http://www.gnu.org/licenses/>.
  */
 
-use super::Repository;
+use super::{is_staged, status_label, Repository};
+use crate::glyphs::{Glyph, GlyphDrawingOptions};
 use crate::prelude::*;
+use crate::ufo::glif;
+use crate::ufo::objects::Layer;
+use crate::views::GlyphDiffView;
+use gtk::cairo::Context;
+use std::collections::HashMap;
+
+const LOG_LIMIT: usize = 200;
+const THUMBNAIL_SIZE: i32 = 48;
+
+/// Revisions to compare in the before/after view.
+#[derive(Debug, Clone)]
+enum Comparison {
+    /// `HEAD` against the working tree.
+    WorkingTree,
+    /// A commit against its first parent.
+    Commit(git2::Oid),
+}
 
 #[derive(Debug, Default)]
 pub struct GitSpaceInner {
@@ -28,7 +46,27 @@ pub struct GitSpaceInner {
     project: OnceCell<Project>,
     settings: OnceCell<Settings>,
     treeview: gtk::TreeView,
+    changes_store: OnceCell<gtk::ListStore>,
+    log_view: gtk::TreeView,
+    log_store: OnceCell<gtk::ListStore>,
+    commit_files_view: gtk::TreeView,
+    commit_files_store: OnceCell<gtk::ListStore>,
+    message_view: gtk::TextView,
+    before_area: gtk::DrawingArea,
+    after_area: gtk::DrawingArea,
+    before_label: gtk::Label,
+    after_label: gtk::Label,
+    overlay_view: OnceCell<GlyphDiffView>,
+    preview_notebook: gtk::Notebook,
+    before: RefCell<Option<Glyph>>,
+    after: RefCell<Option<Glyph>>,
     repo: OnceCell<Repository>,
+    /// `.glif` paths of the project, rebuilt on every refresh. See [`Self::glyph_paths`].
+    glyph_paths: RefCell<HashMap<PathBuf, (Layer, String)>>,
+    /// `HEAD` when the log was last loaded, so that saves do not reload an unchanged history.
+    log_head: Cell<Option<git2::Oid>>,
+    /// Idle callback that fills in log thumbnails one commit at a time.
+    thumbnail_source: RefCell<Option<glib::SourceId>>,
 
     menubar: gtk::MenuBar,
     action_group: gio::SimpleActionGroup,
@@ -45,18 +83,239 @@ impl ObjectSubclass for GitSpaceInner {
 impl ObjectImpl for GitSpaceInner {
     fn constructed(&self, obj: &Self::Type) {
         self.parent_constructed(obj);
-        let store = gtk::TreeStore::new(&[String::static_type(), String::static_type()]);
+        /* Working tree changes: staged, status, glyph name, layer, path */
+        let store = gtk::ListStore::new(&[
+            bool::static_type(),
+            String::static_type(),
+            String::static_type(),
+            String::static_type(),
+            String::static_type(),
+        ]);
         self.treeview.set_model(Some(&store));
         self.treeview.set_headers_visible(true);
-        for n in ["type", "name"] {
+        self.treeview.set_visible(true);
+        self.treeview.set_expand(true);
+        {
+            let column = gtk::TreeViewColumn::new();
+            column.set_title("staged");
+            let cell = gtk::CellRendererToggle::new();
+            cell.set_activatable(true);
+            cell.connect_toggled(clone!(@weak obj, @weak store => move |_self, treepath| {
+                let Some(iter) = store.iter(&treepath) else { return; };
+                let staged: bool = store.value(&iter, 0).get().unwrap();
+                let path: String = store.value(&iter, 4).get().unwrap();
+                let repo = obj.repo.get().unwrap();
+                let result = if staged {
+                    repo.unstage(Path::new(&path))
+                } else {
+                    repo.stage(Path::new(&path))
+                };
+                if let Err(err) = result {
+                    obj.show_error("Error: could not update index.", &err.to_string());
+                }
+                obj.emit_by_name::<()>(GitSpace::UPDATE, &[]);
+            }));
+            column.pack_start(&cell, false);
+            column.add_attribute(&cell, "active", 0);
+            self.treeview.append_column(&column);
+        }
+        for (i, n) in [(1, "status"), (2, "glyph"), (3, "layer"), (4, "path")] {
             let column = gtk::TreeViewColumn::new();
             column.set_title(n);
+            column.set_resizable(true);
+            column.set_sort_column_id(i);
             let cell = gtk::CellRendererText::new();
 
             column.pack_start(&cell, true);
-            column.add_attribute(&cell, "text", 0);
+            column.add_attribute(&cell, "text", i);
             self.treeview.append_column(&column);
         }
+        self.treeview
+            .selection()
+            .connect_changed(clone!(@weak obj => move |selection| {
+                if let Some((model, iter)) = selection.selected() {
+                    let path: String = model.value(&iter, 4).get().unwrap();
+                    obj.show_comparison(&Comparison::WorkingTree, Path::new(&path));
+                }
+            }));
+        self.treeview
+            .connect_row_activated(clone!(@weak obj => move |treeview, tree_path, _| {
+                let Some(model) = treeview.model() else { return };
+                let Some(iter) = model.iter(tree_path) else { return };
+                let status: String = model.value(&iter, 1).get().unwrap();
+                if status == "conflicted" {
+                    let path: String = model.value(&iter, 4).get().unwrap();
+                    obj.resolve_conflict(Some(Path::new(&path)));
+                }
+            }));
+        self.changes_store.set(store).unwrap();
+
+        /* Log: thumbnail, id, summary, author, date, full oid */
+        let log_store = gtk::ListStore::new(&[
+            gdk_pixbuf::Pixbuf::static_type(),
+            String::static_type(),
+            String::static_type(),
+            String::static_type(),
+            String::static_type(),
+            String::static_type(),
+        ]);
+        self.log_view.set_model(Some(&log_store));
+        self.log_view.set_headers_visible(true);
+        self.log_view.set_visible(true);
+        self.log_view.set_expand(true);
+        {
+            let column = gtk::TreeViewColumn::new();
+            let cell = gtk::CellRendererPixbuf::new();
+            column.pack_start(&cell, false);
+            column.add_attribute(&cell, "pixbuf", 0);
+            self.log_view.append_column(&column);
+        }
+        for (i, n) in [(1, "commit"), (2, "summary"), (3, "author"), (4, "date")] {
+            let column = gtk::TreeViewColumn::new();
+            column.set_title(n);
+            column.set_resizable(true);
+            let cell = gtk::CellRendererText::new();
+            column.pack_start(&cell, true);
+            column.add_attribute(&cell, "text", i);
+            self.log_view.append_column(&column);
+        }
+        self.log_view
+            .selection()
+            .connect_changed(clone!(@weak obj => move |selection| {
+                if let Some((model, iter)) = selection.selected() {
+                    let oid: String = model.value(&iter, 5).get().unwrap();
+                    if let Ok(oid) = git2::Oid::from_str(&oid) {
+                        obj.update_commit_files(oid);
+                    }
+                }
+            }));
+        self.log_store.set(log_store).unwrap();
+
+        /* Files changed by the selected commit: glyph name, path, full oid */
+        let commit_files_store = gtk::ListStore::new(&[
+            String::static_type(),
+            String::static_type(),
+            String::static_type(),
+        ]);
+        self.commit_files_view.set_model(Some(&commit_files_store));
+        self.commit_files_view.set_headers_visible(true);
+        self.commit_files_view.set_visible(true);
+        self.commit_files_view.set_expand(true);
+        for (i, n) in [(0, "glyph"), (1, "path")] {
+            let column = gtk::TreeViewColumn::new();
+            column.set_title(n);
+            column.set_resizable(true);
+            let cell = gtk::CellRendererText::new();
+            column.pack_start(&cell, true);
+            column.add_attribute(&cell, "text", i);
+            self.commit_files_view.append_column(&column);
+        }
+        self.commit_files_view
+            .selection()
+            .connect_changed(clone!(@weak obj => move |selection| {
+                if let Some((model, iter)) = selection.selected() {
+                    let path: String = model.value(&iter, 1).get().unwrap();
+                    let oid: String = model.value(&iter, 2).get().unwrap();
+                    if let Ok(oid) = git2::Oid::from_str(&oid) {
+                        obj.show_comparison(&Comparison::Commit(oid), Path::new(&path));
+                    }
+                }
+            }));
+        self.commit_files_store.set(commit_files_store).unwrap();
+
+        let changes_box = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .spacing(5)
+            .expand(true)
+            .visible(true)
+            .build();
+        changes_box.pack_start(&scrolled(&self.treeview), true, true, 0);
+        self.message_view.set_visible(true);
+        self.message_view.set_monospace(true);
+        self.message_view.set_height_request(80);
+        self.message_view.set_wrap_mode(gtk::WrapMode::Word);
+        changes_box.pack_start(
+            &gtk::Label::builder()
+                .label("Commit message:")
+                .halign(gtk::Align::Start)
+                .visible(true)
+                .build(),
+            false,
+            false,
+            0,
+        );
+        changes_box.pack_start(&self.message_view, false, false, 0);
+        let buttons = gtk::Box::builder()
+            .orientation(gtk::Orientation::Horizontal)
+            .spacing(5)
+            .visible(true)
+            .build();
+        for (label, action) in [
+            ("Stage all", "git.stage.all"),
+            ("Refresh", "git.refresh"),
+            ("Commit", "git.commit"),
+        ] {
+            let button = gtk::Button::builder()
+                .label(label)
+                .visible(true)
+                .action_name(action)
+                .build();
+            buttons.pack_end(&button, false, false, 0);
+        }
+        changes_box.pack_start(&buttons, false, false, 0);
+
+        let history_pane = gtk::Paned::new(gtk::Orientation::Vertical);
+        history_pane.set_visible(true);
+        history_pane.pack1(&scrolled(&self.log_view), true, false);
+        history_pane.pack2(&scrolled(&self.commit_files_view), true, false);
+
+        let notebook = gtk::Notebook::builder().visible(true).expand(true).build();
+        notebook.append_page(&changes_box, Some(&gtk::Label::new(Some("Changes"))));
+        notebook.append_page(&history_pane, Some(&gtk::Label::new(Some("History"))));
+
+        let preview_box = gtk::Box::builder()
+            .orientation(gtk::Orientation::Horizontal)
+            .spacing(5)
+            .homogeneous(true)
+            .expand(true)
+            .visible(true)
+            .build();
+        for (label, area, is_after) in [
+            (&self.before_label, &self.before_area, false),
+            (&self.after_label, &self.after_area, true),
+        ] {
+            let vbox = gtk::Box::builder()
+                .orientation(gtk::Orientation::Vertical)
+                .spacing(5)
+                .expand(true)
+                .visible(true)
+                .build();
+            label.set_visible(true);
+            label.set_label(if is_after { "After" } else { "Before" });
+            area.set_visible(true);
+            area.set_expand(true);
+            area.connect_draw(
+                clone!(@weak obj => @default-return Inhibit(false), move |area, ctx| {
+                    let glyph = if is_after { obj.after.borrow() } else { obj.before.borrow() };
+                    obj.draw_preview(area, ctx, glyph.as_ref());
+                    Inhibit(false)
+                }),
+            );
+            vbox.pack_start(label, false, false, 0);
+            vbox.pack_start(area, true, true, 0);
+            preview_box.pack_start(&vbox, true, true, 0);
+        }
+
+        self.preview_notebook.set_visible(true);
+        self.preview_notebook.set_expand(true);
+        self.preview_notebook
+            .append_page(&preview_box, Some(&gtk::Label::new(Some("Side by side"))));
+
+        let paned = gtk::Paned::new(gtk::Orientation::Horizontal);
+        paned.set_visible(true);
+        paned.pack1(&notebook, true, false);
+        paned.pack2(&self.preview_notebook, true, false);
+        obj.set_child(Some(&paned));
         obj.set_visible(true);
         obj.set_expand(true);
         obj.set_can_focus(true);
@@ -110,8 +369,9 @@ impl ObjectImpl for GitSpaceInner {
     }
 
     fn signals() -> &'static [Signal] {
-        static SIGNALS: Lazy<Vec<Signal>> =
-            Lazy::new(|| vec![Signal::builder("update", &[], <()>::static_type().into()).build()]);
+        static SIGNALS: Lazy<Vec<Signal>> = Lazy::new(|| {
+            vec![Signal::builder(GitSpace::UPDATE, &[], <()>::static_type().into()).build()]
+        });
         SIGNALS.as_ref()
     }
 }
@@ -120,6 +380,428 @@ impl WidgetImpl for GitSpaceInner {}
 impl ContainerImpl for GitSpaceInner {}
 impl BinImpl for GitSpaceInner {}
 
+fn scrolled(child: &impl IsA<gtk::Widget>) -> gtk::ScrolledWindow {
+    let ret = gtk::ScrolledWindow::builder()
+        .expand(true)
+        .visible(true)
+        .can_focus(true)
+        .build();
+    ret.set_child(Some(child));
+    ret
+}
+
+impl GitSpaceInner {
+    fn setup_menu(&self, obj: &GitSpace) {
+        let menumodel = gio::Menu::new();
+        {
+            let repo_menu = gio::Menu::new();
+            repo_menu.append(Some("Refresh"), Some("git.refresh"));
+            repo_menu.append(Some("Stage all"), Some("git.stage.all"));
+            repo_menu.append(Some("Commit"), Some("git.commit"));
+            repo_menu.append(Some("Resolve conflict..."), Some("git.resolve"));
+            menumodel.append_submenu(Some("_Repository"), &repo_menu);
+        }
+        let refresh = gio::SimpleAction::new("refresh", None);
+        refresh.connect_activate(clone!(@weak obj => move |_, _| {
+            obj.emit_by_name::<()>(GitSpace::UPDATE, &[]);
+        }));
+        self.action_group.add_action(&refresh);
+        let stage_all = gio::SimpleAction::new("stage.all", None);
+        stage_all.connect_activate(clone!(@weak obj => move |_, _| {
+            let repo = obj.repo.get().unwrap();
+            for (path, _, _) in obj.changed_glyphs() {
+                if let Err(err) = repo.stage(&path) {
+                    obj.show_error("Error: could not update index.", &err.to_string());
+                    break;
+                }
+            }
+            obj.emit_by_name::<()>(GitSpace::UPDATE, &[]);
+        }));
+        self.action_group.add_action(&stage_all);
+        let commit = gio::SimpleAction::new("commit", None);
+        commit.connect_activate(clone!(@weak obj => move |_, _| {
+            let buffer = obj.message_view.buffer().unwrap();
+            let (start, end) = buffer.bounds();
+            let message = buffer
+                .text(&start, &end, false)
+                .map(|gstr| gstr.to_string())
+                .unwrap_or_default();
+            match obj.repo.get().unwrap().commit(&message) {
+                Ok(oid) => {
+                    buffer.set_text("");
+                    let status = obj.app.get().unwrap().statusbar();
+                    status.push(
+                        status.context_id("main"),
+                        &format!("Created commit {oid}."),
+                    );
+                    obj.emit_by_name::<()>(GitSpace::UPDATE, &[]);
+                }
+                Err(err) => obj.show_error("Error: could not commit.", &err.to_string()),
+            }
+        }));
+        self.action_group.add_action(&commit);
+        let resolve = gio::SimpleAction::new("resolve", None);
+        resolve.connect_activate(clone!(@weak obj => move |_, _| {
+            let selected = obj
+                .treeview
+                .selection()
+                .selected()
+                .map(|(model, iter)| model.value(&iter, 4).get::<String>().unwrap());
+            obj.resolve_conflict(selected.as_deref().map(Path::new));
+        }));
+        self.action_group.add_action(&resolve);
+        self.menubar.bind_model(Some(&menumodel), None, true);
+    }
+
+    /// Maps `.glif` paths, relative to the repository working directory, to their layer and
+    /// glyph name.
+    fn update_glyph_paths(&self) {
+        let repo = self.repo.get().unwrap();
+        let project = self.project.get().unwrap();
+        let mut ret = HashMap::default();
+        for layer in project.all_layers.borrow().iter() {
+            let layer_path = layer.path().clone();
+            for (name, filename) in layer.contents_plist.borrow().glyphs().iter() {
+                if let Some(rel) = repo.relative_path(&layer_path.join(filename)) {
+                    ret.insert(rel.into_owned(), (layer.clone(), name.clone()));
+                }
+            }
+        }
+        *self.glyph_paths.borrow_mut() = ret;
+    }
+
+    fn glyph_paths(&self) -> Ref<'_, HashMap<PathBuf, (Layer, String)>> {
+        self.glyph_paths.borrow()
+    }
+
+    /// Changed files that belong to a glyph, with the glyph's layer and name. Deleted glyphs
+    /// are no longer in `contents.plist`, so any `.glif` change is listed as well.
+    fn changed_glyphs(&self) -> Vec<(PathBuf, git2::Status, Option<(Layer, String)>)> {
+        let repo = self.repo.get().unwrap();
+        let glyph_paths = self.glyph_paths();
+        match repo.changed_files() {
+            Ok(files) => files
+                .into_iter()
+                .filter_map(|(path, status)| {
+                    let glyph = glyph_paths.get(&path).cloned();
+                    if glyph.is_none() && path.extension().and_then(|e| e.to_str()) != Some("glif")
+                    {
+                        return None;
+                    }
+                    Some((path, status, glyph))
+                })
+                .collect(),
+            Err(err) => {
+                self.show_error("Error: could not read repository status.", &err.to_string());
+                vec![]
+            }
+        }
+    }
+
+    fn update_changes(&self) {
+        let store = self.changes_store.get().unwrap();
+        store.clear();
+        for (path, status, glyph) in self.changed_glyphs() {
+            let (layer, name) = glyph
+                .map(|(l, n)| (l.property::<String>(Layer::NAME), n))
+                .unwrap_or_default();
+            store.insert_with_values(
+                None,
+                &[
+                    (0, &is_staged(status)),
+                    (1, &status_label(status)),
+                    (2, &name),
+                    (3, &layer),
+                    (4, &path.display().to_string()),
+                ],
+            );
+        }
+    }
+
+    /// Reloads the history if `HEAD` moved. Thumbnails are drawn afterwards in an idle
+    /// callback, since they need a diff and a parse for every commit.
+    fn update_log(&self) {
+        let repo = self.repo.get().unwrap();
+        let head = repo
+            .repository
+            .get()
+            .unwrap()
+            .head()
+            .ok()
+            .and_then(|h| h.target());
+        if head.is_some() && head == self.log_head.get() {
+            return;
+        }
+        self.log_head.set(head);
+        if let Some(source) = self.thumbnail_source.take() {
+            source.remove();
+        }
+        let store = self.log_store.get().unwrap();
+        store.clear();
+        let log = match repo.log(LOG_LIMIT) {
+            Ok(log) => log,
+            Err(err) => {
+                self.show_error("Error: could not read history.", &err.to_string());
+                return;
+            }
+        };
+        for info in log.iter() {
+            let date = glib::DateTime::from_unix_local(info.time)
+                .and_then(|d| d.format("%Y-%m-%d %H:%M"))
+                .map(|s| s.to_string())
+                .unwrap_or_default();
+            store.insert_with_values(
+                None,
+                &[
+                    (1, &info.short_id()),
+                    (2, &info.summary),
+                    (3, &info.author),
+                    (4, &date),
+                    (5, &info.oid.to_string()),
+                ],
+            );
+        }
+        self.load_thumbnails();
+    }
+
+    fn load_thumbnails(&self) {
+        let obj = self.instance();
+        let store = self.log_store.get().unwrap();
+        let mut next = store.iter_first();
+        let source = glib::idle_add_local(
+            clone!(@weak obj, @weak store => @default-return glib::Continue(false), move || {
+                let Some(iter) = next.take() else {
+                    obj.thumbnail_source.take();
+                    return glib::Continue(false);
+                };
+                let oid: String = store.value(&iter, 5).get().unwrap();
+                if let Some(thumbnail) = git2::Oid::from_str(&oid)
+                    .ok()
+                    .and_then(|oid| obj.commit_thumbnail(oid))
+                {
+                    store.set_value(&iter, 0, &thumbnail.to_value());
+                }
+                if store.iter_next(&iter) {
+                    next = Some(iter);
+                }
+                glib::Continue(true)
+            }),
+        );
+        *self.thumbnail_source.borrow_mut() = Some(source);
+    }
+
+    /// Thumbnail of the first glyph changed by commit `oid`.
+    fn commit_thumbnail(&self, oid: git2::Oid) -> Option<gdk_pixbuf::Pixbuf> {
+        let path = {
+            let glyph_paths = self.glyph_paths();
+            self.repo
+                .get()
+                .unwrap()
+                .commit_changed_files(oid)
+                .ok()?
+                .into_iter()
+                .find(|p| glyph_paths.contains_key(p))?
+        };
+        let glyph = self.glyph_at(&oid.to_string(), &path)?;
+        self.thumbnail(&glyph)
+    }
+
+    fn update_commit_files(&self, oid: git2::Oid) {
+        let store = self.commit_files_store.get().unwrap();
+        store.clear();
+        let glyph_paths = self.glyph_paths();
+        let Ok(files) = self.repo.get().unwrap().commit_changed_files(oid) else {
+            return;
+        };
+        for path in files {
+            let name = glyph_paths
+                .get(&path)
+                .map(|(_, n)| n.clone())
+                .unwrap_or_default();
+            if name.is_empty() && path.extension().and_then(|e| e.to_str()) != Some("glif") {
+                continue;
+            }
+            store.insert_with_values(
+                None,
+                &[
+                    (0, &name),
+                    (1, &path.display().to_string()),
+                    (2, &oid.to_string()),
+                ],
+            );
+        }
+    }
+
+    /// Builds a glyph from `glif` and resolves its components against the current project.
+    fn to_glyph(&self, path: &Path, glif: glif::Glif) -> Glyph {
+        let glyph_paths = self.glyph_paths();
+        Glyph::from_glif_in_layer(glif, glyph_paths.get(path).map(|(layer, _)| layer))
+    }
+
+    fn glif_at(&self, revision: &str, path: &Path) -> Option<glif::Glif> {
+        let source = self
+            .repo
+            .get()
+            .unwrap()
+            .file_at_revision(revision, path)
+            .ok()??;
+        glif::Glif::from_str(&source).ok()
+    }
+
+    fn glif_in_workdir(&self, path: &Path) -> Option<glif::Glif> {
+        let abs_path = self.repo.get().unwrap().workdir().join(path);
+        let source = std::fs::read_to_string(abs_path).ok()?;
+        glif::Glif::from_str(&source).ok()
+    }
+
+    fn glyph_at(&self, revision: &str, path: &Path) -> Option<Glyph> {
+        self.glif_at(revision, path)
+            .map(|glif| self.to_glyph(path, glif))
+    }
+
+    fn show_comparison(&self, comparison: &Comparison, path: &Path) {
+        let (before, after, before_label, after_label) = match comparison {
+            Comparison::WorkingTree => (
+                self.glif_at("HEAD", path),
+                self.glif_in_workdir(path),
+                "HEAD".to_string(),
+                "Working tree".to_string(),
+            ),
+            Comparison::Commit(oid) => (
+                self.glif_at(&format!("{oid}^"), path),
+                self.glif_at(&oid.to_string(), path),
+                format!("{oid}^"),
+                oid.to_string(),
+            ),
+        };
+        let label = |l: String, g: &Option<glif::Glif>| {
+            if g.is_none() {
+                format!("{l} (no glyph)")
+            } else {
+                l
+            }
+        };
+        self.before_label.set_label(&label(before_label, &before));
+        self.after_label.set_label(&label(after_label, &after));
+        let layer = self.glyph_paths().get(path).map(|(layer, _)| layer.clone());
+        self.overlay_view
+            .get()
+            .unwrap()
+            .set_glifs(before.as_ref(), after.as_ref(), layer.as_ref());
+        *self.before.borrow_mut() = before.map(|g| self.to_glyph(path, g));
+        *self.after.borrow_mut() = after.map(|g| self.to_glyph(path, g));
+        self.before_area.queue_draw();
+        self.after_area.queue_draw();
+    }
+
+    fn units_per_em(&self) -> f64 {
+        self.project
+            .get()
+            .unwrap()
+            .fontinfo()
+            .property(FontInfo::UNITS_PER_EM)
+    }
+
+    fn draw_glyph(&self, mut ctx: &Context, width: f64, height: f64, glyph: &Glyph, color: Color) {
+        let units_per_em = self.units_per_em();
+        let scale = 0.8 * height.min(width) / units_per_em;
+        let glyph_width = glyph.width().unwrap_or(units_per_em) * scale;
+        let mut matrix = gtk::cairo::Matrix::identity();
+        matrix.translate((width - glyph_width) / 2.0, 0.8 * height);
+        matrix.scale(scale, -scale);
+        let options = GlyphDrawingOptions {
+            outline: (Color::new_alpha(0, 0, 0, 0), 1.5).into(),
+            inner_fill: Some((color, 1.5).into()),
+            highlight: None,
+            matrix,
+            units_per_em,
+            ..Default::default()
+        };
+        glyph.draw(ctx.push(), options);
+    }
+
+    fn draw_preview(&self, area: &gtk::DrawingArea, mut ctx: &Context, glyph: Option<&Glyph>) {
+        let colors = self.app.get().unwrap().colors();
+        let width = f64::from(area.allocated_width());
+        let height = f64::from(area.allocated_height());
+        let cr = ctx.push();
+        cr.set_source_color(colors.theme_base_color);
+        cr.paint().expect("Invalid cairo surface state");
+        if let Some(glyph) = glyph {
+            self.draw_glyph(&cr, width, height, glyph, colors.theme_text_color);
+        }
+    }
+
+    fn thumbnail(&self, glyph: &Glyph) -> Option<gdk_pixbuf::Pixbuf> {
+        let surface =
+            cairo::ImageSurface::create(cairo::Format::ARgb32, THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+                .ok()?;
+        {
+            let ctx = Context::new(&surface).ok()?;
+            let size = f64::from(THUMBNAIL_SIZE);
+            self.draw_glyph(
+                &ctx,
+                size,
+                size,
+                glyph,
+                self.app.get().unwrap().colors().theme_text_color,
+            );
+        }
+        gdk::pixbuf_get_from_surface(&surface, 0, 0, THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+    }
+
+    /// Opens the conflict resolver for `path`, or for the first conflicted file if `path` is
+    /// not conflicted.
+    fn resolve_conflict(&self, path: Option<&Path>) {
+        let repo = self.repo.get().unwrap();
+        let mut conflicts = match repo.conflicts() {
+            Ok(c) => c,
+            Err(err) => {
+                self.show_error("Error: could not read conflicts.", &err.to_string());
+                return;
+            }
+        };
+        let Some(i) = conflicts
+            .iter()
+            .position(|c| Some(c.path.as_path()) == path)
+            .or_else(|| (!conflicts.is_empty()).then_some(0))
+        else {
+            let dialog = crate::utils::widgets::new_simple_info_dialog(
+                Some("No conflicts"),
+                "There are no merge conflicts in the index.",
+                None,
+                self.app.get().unwrap().window.upcast_ref(),
+            );
+            dialog.run();
+            dialog.emit_close();
+            return;
+        };
+        let obj = self.instance();
+        if let Err(err) = super::conflict::resolve_conflict_window(
+            self.app.get().unwrap(),
+            self.project.get().unwrap(),
+            repo,
+            conflicts.swap_remove(i),
+            Box::new(clone!(@weak obj => move || {
+                obj.emit_by_name::<()>(GitSpace::UPDATE, &[]);
+            })),
+        ) {
+            self.show_error("Error: could not open conflict.", &err.to_string());
+        }
+    }
+
+    fn show_error(&self, title: &str, err: &str) {
+        let dialog = crate::utils::widgets::new_simple_error_dialog(
+            Some(title),
+            err,
+            None,
+            self.app.get().unwrap().window.upcast_ref(),
+        );
+        dialog.run();
+        dialog.emit_close();
+    }
+}
+
 glib::wrapper! {
     pub struct GitSpace(ObjectSubclass<GitSpaceInner>)
         @extends gtk::Widget, gtk::Container, gtk::Bin;
@@ -137,6 +819,7 @@ impl GitSpace {
     pub const TITLE: &str = Workspace::TITLE;
     pub const IS_MENU_VISIBLE: &str = Workspace::IS_MENU_VISIBLE;
     pub const MENUBAR: &str = Workspace::MENUBAR;
+    pub const UPDATE: &str = "update";
 
     pub fn new(app: Application, project: Project, repository: Repository) -> Self {
         let ret: Self = glib::Object::new(&[]).unwrap();
@@ -156,8 +839,31 @@ impl GitSpace {
         ret.insert_action_group("git", Some(&ret.action_group));
         ret.menubar
             .insert_action_group("git", Some(&ret.action_group));
+        let overlay_view =
+            GlyphDiffView::new(app, project.fontinfo().property(FontInfo::UNITS_PER_EM));
+        ret.preview_notebook
+            .append_page(&overlay_view, Some(&gtk::Label::new(Some("Overlay"))));
+        ret.overlay_view.set(overlay_view).unwrap();
         ret.project.set(project).unwrap();
-        //ret.setup_menu(&ret);
+        ret.setup_menu(&ret);
+        ret.connect_local(GitSpace::UPDATE, false, |v: &[gtk::glib::Value]| {
+            let obj = v[0].get::<GitSpace>().unwrap();
+            obj.update_glyph_paths();
+            obj.update_changes();
+            obj.update_log();
+            obj.commit_files_store.get().unwrap().clear();
+            None
+        });
+        // Saving glyphs changes the working tree.
+        ret.project.get().unwrap().connect_notify_local(
+            Some(Project::MODIFIED),
+            clone!(@weak ret => move |project, _| {
+                if !project.property::<bool>(Project::MODIFIED) {
+                    ret.emit_by_name::<()>(GitSpace::UPDATE, &[]);
+                }
+            }),
+        );
+        ret.emit_by_name::<()>(GitSpace::UPDATE, &[]);
         ret
     }
 }
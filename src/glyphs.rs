@@ -41,7 +41,16 @@ pub use crate::ufo::glif::{self, Advance, Anchor, ImageRef, Unicode};
 mod contours;
 pub use contours::*;
 
+pub mod composites;
+pub mod corners;
+pub mod extents;
 pub mod metadata;
+pub mod offset;
+pub mod outline;
+pub mod outline_search;
+pub mod query;
+pub mod svg;
+pub mod transform;
 pub use metadata::GlyphMetadata;
 
 #[derive(Debug, Clone)]
@@ -280,16 +289,58 @@ impl Glyph {
         }
 
         for g in glyphs_with_refs {
-            let mut deref = g.borrow_mut();
-            for c in deref.components.iter_mut() {
-                if let Some(o) = ret.get(&c.base_name) {
-                    c.base = Rc::downgrade(o);
-                }
-            }
+            g.borrow_mut().resolve_components(&ret);
         }
         Ok(ret)
     }
 
+    /// Points every component's `base` to the glyph with the same name in `glyphs`.
+    pub fn resolve_components(&mut self, glyphs: &IndexMap<String, Rc<RefCell<Self>>>) {
+        for c in self.components.iter_mut() {
+            if let Some(o) = glyphs.get(&c.base_name) {
+                c.base = Rc::downgrade(o);
+            }
+        }
+    }
+
+    /// Replaces the outline, lib and file metadata of `self` with those of `other`, which was
+    /// read from disk. `self.metadata` is kept, along with its property bindings, its link to the
+    /// layer and `glyph_ref`, so that views showing the glyph follow the change.
+    pub fn reload_from(&mut self, other: Self) {
+        let Self {
+            contours,
+            components,
+            guidelines,
+            lib,
+            metadata,
+        } = other;
+        self.contours = contours;
+        self.components = components;
+        self.guidelines = guidelines;
+        self.lib = lib;
+        let m = &self.metadata;
+        m.image.replace(metadata.image.take());
+        m.advance.set(metadata.advance.get());
+        m.unicode.replace(metadata.unicode.take());
+        m.anchors.replace(metadata.anchors.take());
+        m.kinds.replace(metadata.kinds.borrow().clone());
+        m.glif_source.replace(metadata.glif_source.take());
+        m.set_property(GlyphMetadata::NAME, metadata.name.borrow().as_str());
+        m.set_property(GlyphMetadata::FILENAME, metadata.filename.borrow().as_str());
+        m.width.set(metadata.width.get());
+        m.notify(GlyphMetadata::WIDTH);
+        m.set_property(GlyphMetadata::MODIFIED, false);
+    }
+
+    /// Converts `glif` to a glyph that can be drawn, resolving its components in `layer`.
+    pub fn from_glif_in_layer(glif: glif::Glif, layer: Option<&ufo::objects::Layer>) -> Self {
+        let mut ret: Self = glif.into();
+        if let Some(layer) = layer {
+            ret.resolve_components(&layer.glyphs());
+        }
+        ret
+    }
+
     pub fn new(name: String, char: char, curves: Vec<Bezier>) -> Self {
         let contours = if curves.is_empty() {
             vec![]
@@ -687,54 +738,58 @@ impl Glyph {
         }
     }
 
-    /*
-    pub fn into_cubic(&mut self) {
-        if self.is_empty() {
-            return;
-        }
-        for contour in self.contours.iter_mut() {
-            let mut pen_position: Option<Point> = None;
-            let mut curves = contour.imp().curves.borrow_mut();
-            if !contour.property::<bool>(Contour::OPEN) {
-                if let Some(point) = curves.last().and_then(|b| b.points().last().cloned()) {
-                    pen_position = Some(point.position);
-                }
-            }
-
-            for curv in curves.iter_mut() {
-                let curv_points = curv.points();
-                if curv_points.len() == 3 {
-                    let a = if let Some(v) = pen_position.take() {
-                        v
-                    } else {
-                        curv_points[0].position
-                    };
-                    let b = curv_points[1].position;
-                    let c = curv_points[2].position;
-                    let new_points = vec![
-                        a,
-                        (
-                            2.0 / 3.0 * b.x + 1.0 / 3.0 * a.x,
-                            2.0 / 3.0 * b.y + 1.0 / 3.0 * a.y,
-                        )
-                            .into(),
-                        (
-                            2.0 / 3.0 * b.x + 1.0 / 3.0 * c.x,
-                            2.0 / 3.0 * b.y + 1.0 / 3.0 * c.y,
-                        )
-                            .into(),
-                        c,
-                    ];
-                    drop(curv_points);
-                    *curv = Bezier::new(new_points);
-                    pen_position = Some(c);
-                } else if let Some(last_p) = curv_points.last() {
-                    pen_position = Some(last_p.position);
+    /// Undoable conversion of every contour in `glyphs` to quadratic curves within `tolerance`
+    /// font units when it is `Some`, or to cubic curves otherwise. `on_change` is called for
+    /// each glyph after redoing or undoing.
+    pub fn convert_curves(
+        glyphs: Vec<Rc<RefCell<Glyph>>>,
+        tolerance: Option<f64>,
+        on_change: Rc<dyn Fn(&Rc<RefCell<Glyph>>)>,
+    ) -> crate::app::Action {
+        let swaps = Rc::new(RefCell::new(
+            glyphs
+                .iter()
+                .map(|g| {
+                    let contours = g
+                        .borrow()
+                        .contours
+                        .iter()
+                        .map(|c| {
+                            let curves = match tolerance {
+                                Some(t) => c.to_quadratic_curves(t),
+                                None => c.to_cubic_curves(),
+                            };
+                            (c.clone(), curves)
+                        })
+                        .collect::<Vec<_>>();
+                    (g.clone(), contours)
+                })
+                .collect::<Vec<_>>(),
+        ));
+        let cl = Box::new(move || {
+            for (glyph, contours) in swaps.borrow_mut().iter_mut() {
+                for (contour, curves) in contours.iter_mut() {
+                    let old = contour.set_curves(std::mem::take(curves));
+                    *curves = old;
                 }
+                glyph
+                    .borrow()
+                    .metadata
+                    .set_property(GlyphMetadata::MODIFIED, true);
+                on_change(glyph);
             }
+        });
+        crate::app::Action {
+            stamp: crate::app::EventStamp {
+                t: std::any::TypeId::of::<Self>(),
+                property: "convert-curves",
+                id: Box::new([]),
+            },
+            compress: false,
+            redo: cl.clone(),
+            undo: cl,
         }
     }
-    */
 
     pub fn save_to_svg<P: AsRef<std::path::Path>>(
         &self,
@@ -814,7 +869,9 @@ impl Glyph {
             .create(true)
             .truncate(true)
             .open(&path)?;
-        file.write_all(glif.to_xml().as_bytes())?;
+        let xml = glif.to_xml();
+        file.write_all(xml.as_bytes())?;
+        *self.metadata.glif_source.borrow_mut() = xml;
         for g in self.guidelines.iter().filter(|obj| obj.modified()) {
             g.set_property(Guideline::MODIFIED, false);
         }
@@ -908,32 +965,51 @@ impl From<glif::Glif> for Glyph {
                 if points.is_empty() {
                     continue;
                 }
+                let super_ = crate::glyphs::Contour::new();
                 let mut c;
                 let mut prev_point;
                 let mut last_oncurve;
+                /* Whether the start point of the next curve is smooth. */
+                let mut smooth_start;
                 if points.front().unwrap().is_move() {
                     open = true;
                     // Open contour
                     let p = points.pop_front().unwrap();
                     prev_point = (p.x, p.y);
                     last_oncurve = prev_point;
-                    c = vec![prev_point];
-                } else {
+                    smooth_start = p.smooth == Some(true);
                     c = vec![];
-                    let first_point = points.front().unwrap();
-                    last_oncurve = (first_point.x, first_point.y);
-                    // Closed contour
-                    while points.front().unwrap().is_curve() {
-                        points.rotate_left(1);
-                    }
+                } else if let Some(first_oncurve) = points
+                    .iter()
+                    .position(|p| !matches!(p.type_, PointKind::Offcurve))
+                {
+                    c = vec![];
+                    // Closed contour: start right after the first on-curve point, so that it
+                    // becomes the start of the first curve.
+                    points.rotate_left(first_oncurve + 1);
                     let last_point = points.back().unwrap();
                     prev_point = (last_point.x, last_point.y);
+                    last_oncurve = prev_point;
+                    smooth_start = last_point.smooth == Some(true);
+                } else {
+                    // Closed TrueType contour with only off-curve points: every on-curve point
+                    // is implied.
+                    let offcurves = points
+                        .iter()
+                        .map(|p| crate::utils::Point::from((p.x, p.y)))
+                        .collect::<Vec<_>>();
+                    let start = 0.5 * (offcurves[offcurves.len() - 1] + offcurves[0]);
+                    for curv in quadratic_spline(start, &offcurves, start) {
+                        super_.push_curve(curv);
+                    }
+                    if let Some(last) = super_.curves().last() {
+                        last.set_property(Bezier::IMPLIED_END, true);
+                    }
+                    super_.close();
+                    super_.is_contour_modified.set(true);
+                    ret.contours.push(super_);
+                    continue;
                 }
-                if points.front().map(|p| p.is_line()).unwrap_or(false) {
-                    let p = points.back().unwrap();
-                    prev_point = (p.x, p.y);
-                }
-                let super_ = crate::glyphs::Contour::new();
                 loop {
                     match points.pop_front() {
                         Some(Point {
@@ -962,9 +1038,8 @@ impl From<glif::Glif> for Glyph {
                             c.push(prev_point);
                             c.insert(0, last_oncurve);
                             let curv = Bezier::new(c.into_iter().map(Into::into).collect());
-                            if *smooth == Some(true) {
-                                curv.set_property(Bezier::SMOOTH, true);
-                            }
+                            curv.set_property(Bezier::SMOOTH, smooth_start);
+                            smooth_start = *smooth == Some(true);
                             super_.push_curve(curv);
                             c = vec![];
                             last_oncurve = prev_point;
@@ -973,6 +1048,7 @@ impl From<glif::Glif> for Glyph {
                             type_: PointKind::Line,
                             x,
                             y,
+                            smooth,
                             ..
                         }) => {
                             assert!(c.is_empty() || c.len() == 1);
@@ -980,25 +1056,49 @@ impl From<glif::Glif> for Glyph {
                                 c.push(prev_point);
                             }
                             c.push((*x, *y));
-                            super_.push_curve(Bezier::new(c.into_iter().map(Into::into).collect()));
+                            let curv = Bezier::new(c.into_iter().map(Into::into).collect());
+                            curv.set_property(Bezier::SMOOTH, smooth_start);
+                            smooth_start = *smooth == Some(true);
+                            super_.push_curve(curv);
                             c = vec![];
                             prev_point = (*x, *y);
                             last_oncurve = prev_point;
                         }
                         Some(Point {
                             type_: PointKind::Qcurve,
+                            x,
+                            y,
+                            smooth,
                             ..
                         }) => {
-                            todo!()
+                            let offcurves = c
+                                .into_iter()
+                                .map(crate::utils::Point::from)
+                                .collect::<Vec<_>>();
+                            let curves =
+                                quadratic_spline(last_oncurve.into(), &offcurves, (*x, *y).into());
+                            if smooth_start {
+                                curves[0].set_property(Bezier::SMOOTH, true);
+                            }
+                            smooth_start = *smooth == Some(true);
+                            for curv in curves {
+                                super_.push_curve(curv);
+                            }
+                            c = vec![];
+                            prev_point = (*x, *y);
+                            last_oncurve = prev_point;
                         }
                         None => {
                             if !c.is_empty() {
                                 if !c.contains(&prev_point) {
                                     c.push(prev_point);
                                 }
-                                super_.push_curve(Bezier::new(
-                                    c.into_iter().map(Into::into).collect(),
-                                ));
+                                let curv = Bezier::new(c.into_iter().map(Into::into).collect());
+                                curv.set_property(Bezier::SMOOTH, smooth_start);
+                                super_.push_curve(curv);
+                            } else if open && super_.curves().is_empty() {
+                                /* Lone move point */
+                                super_.push_curve(Bezier::new(vec![prev_point.into()]));
                             }
                             break;
                         }
@@ -0,0 +1,422 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Composite glyphs built from a base glyph and mark components.
+//!
+//! Recipes are derived from canonical Unicode decompositions, e.g. `á` decomposes to `a` and
+//! U+0301 COMBINING ACUTE ACCENT, or read from a recipe file with one recipe per line:
+//!
+//! ```text
+//! # Comments start with a hash sign.
+//! aacute = a + acutecomb@top
+//! Aogonek = A + ogonekcomb@ogonek
+//! ldot = l + periodcentered@280,0
+//! ```
+//!
+//! A mark is attached by an anchor name, by an explicit `x,y` offset or, without a `@` suffix,
+//! by the first anchor of the base that matches one of the mark's `_` anchors. Marks without
+//! a matching anchor are centred on the base's advance width. Marks attach to the anchors of
+//! the marks before them, so stacked accents such as `uni1EA5 = a + circumflexcomb + acutecomb`
+//! work.
+
+use super::{Advance, Anchor, Component, Glyph};
+use crate::prelude::*;
+use crate::unicode::agl::{name_to_char, production_name};
+use crate::unicode::properties::CharProperties;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Attachment {
+    Auto,
+    Anchor(String),
+    Offset(f64, f64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recipe {
+    pub name: String,
+    pub base: String,
+    pub marks: Vec<(String, Attachment)>,
+}
+
+impl std::str::FromStr for Recipe {
+    type Err = Box<dyn std::error::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn glyph_name(s: &str) -> Result<String, String> {
+            if s.is_empty() || s.contains(char::is_whitespace) {
+                Err(format!("Invalid glyph name `{s}`."))
+            } else {
+                Ok(s.to_string())
+            }
+        }
+
+        let (name, rest) = s
+            .split_once('=')
+            .ok_or_else(|| format!("Expected `name = base + mark`, found `{}`.", s.trim()))?;
+        let mut parts = rest.split('+').map(str::trim);
+        let name = glyph_name(name.trim())?;
+        let base = glyph_name(parts.next().unwrap_or_default())?;
+        let mut marks = vec![];
+        for part in parts {
+            let (mark, attachment) = match part.split_once('@') {
+                None => (part, Attachment::Auto),
+                Some((mark, at)) => {
+                    let at = at.trim();
+                    let attachment = if let Some((x, y)) = at.split_once(',') {
+                        match (x.trim().parse::<f64>(), y.trim().parse::<f64>()) {
+                            (Ok(x), Ok(y)) => Attachment::Offset(x, y),
+                            _ => return Err(format!("Invalid offset `{at}`.").into()),
+                        }
+                    } else {
+                        Attachment::Anchor(glyph_name(at)?)
+                    };
+                    (mark.trim(), attachment)
+                }
+            };
+            marks.push((glyph_name(mark)?, attachment));
+        }
+        Ok(Self { name, base, marks })
+    }
+}
+
+impl Recipe {
+    /// Parses a recipe file. Errors mention the offending line number.
+    pub fn parse_file(text: &str) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut ret = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            ret.push(
+                line.parse::<Self>()
+                    .map_err(|err| format!("Line {}: {err}", i + 1))?,
+            );
+        }
+        Ok(ret)
+    }
+
+    /// The character of the composite, if its name is an AGLFN or production name without a
+    /// suffix.
+    pub fn unicode(&self) -> Option<char> {
+        if self.name.contains('.') {
+            return None;
+        }
+        name_to_char(&self.name)
+    }
+}
+
+/// Components, anchors and advance width of a built composite.
+#[derive(Debug, Clone)]
+pub struct Composite {
+    pub components: Vec<Component>,
+    pub anchors: Vec<Anchor>,
+    pub width: Option<f64>,
+}
+
+impl Composite {
+    /// Replaces the outline of `glyph` with the composite. Contours are kept.
+    pub fn apply(self, glyph: &mut Glyph) {
+        glyph.components = self.components;
+        *glyph.metadata.anchors.borrow_mut() = self.anchors;
+        if let Some(width) = self.width {
+            let height = glyph
+                .metadata
+                .advance
+                .get()
+                .map(|a| a.height)
+                .unwrap_or(0.0);
+            glyph.metadata.width.set(Some(width));
+            glyph.metadata.advance.set(Some(Advance { width, height }));
+        }
+    }
+}
+
+/// Spacing accents to fall back to when the combining mark has no glyph.
+const SPACING_MARKS: &[(char, char)] = &[
+    ('\u{0300}', '\u{0060}'),
+    ('\u{0301}', '\u{00B4}'),
+    ('\u{0302}', '\u{02C6}'),
+    ('\u{0303}', '\u{02DC}'),
+    ('\u{0304}', '\u{00AF}'),
+    ('\u{0306}', '\u{02D8}'),
+    ('\u{0307}', '\u{02D9}'),
+    ('\u{0308}', '\u{00A8}'),
+    ('\u{030A}', '\u{02DA}'),
+    ('\u{030B}', '\u{02DD}'),
+    ('\u{030C}', '\u{02C7}'),
+    ('\u{0327}', '\u{00B8}'),
+    ('\u{0328}', '\u{02DB}'),
+];
+
+/// Whether `mark` is a combining mark placed above its base.
+fn is_above(mark: char) -> bool {
+    matches!(
+        mark as u32,
+        0x0300..=0x0315
+            | 0x033D..=0x0344
+            | 0x0346
+            | 0x034A..=0x034C
+            | 0x0350..=0x0352
+            | 0x0357
+            | 0x035B
+            | 0x0363..=0x036F
+    )
+}
+
+pub struct CompositeBuilder<'a> {
+    glyphs: &'a IndexMap<String, Rc<RefCell<Glyph>>>,
+    chars: HashMap<char, String>,
+}
+
+impl<'a> CompositeBuilder<'a> {
+    pub fn new(glyphs: &'a IndexMap<String, Rc<RefCell<Glyph>>>) -> Self {
+        let mut chars = HashMap::default();
+        for (name, glyph) in glyphs.iter() {
+            if let super::GlyphKind::Char(c) = glyph.borrow().kinds().0 {
+                chars.entry(c).or_insert_with(|| name.clone());
+            }
+        }
+        Self { glyphs, chars }
+    }
+
+    /// The name of the glyph that maps `c`, or of the glyph with its production name.
+    pub fn glyph_for(&self, c: char) -> Option<String> {
+        self.chars.get(&c).cloned().or_else(|| {
+            let name = production_name(c);
+            self.glyphs.contains_key(&name).then_some(name)
+        })
+    }
+
+    /// The recipe of `c` from its canonical decomposition, or `None` if it has none or some of
+    /// its parts have no glyph. Bases without a glyph are decomposed further, e.g. `ǘ` is
+    /// built from `u`, a dieresis and an acute accent if there is no `udieresis` glyph. `i`
+    /// and `j` are replaced by their dotless forms under marks above, if those exist.
+    pub fn recipe_for_char(&self, c: char) -> Option<Recipe> {
+        let decomposition = c.decomposition().filter(|d| d.tag.is_none())?;
+        let (&base, marks) = decomposition.chars.split_first()?;
+        let base = match (base, marks.first()) {
+            ('i', Some(&m)) if is_above(m) && self.glyph_for('\u{0131}').is_some() => '\u{0131}',
+            ('j', Some(&m)) if is_above(m) && self.glyph_for('\u{0237}').is_some() => '\u{0237}',
+            _ => base,
+        };
+        let name = self.glyph_for(c).unwrap_or_else(|| production_name(c));
+        let mut recipe = match self.glyph_for(base) {
+            Some(base) => Recipe {
+                name,
+                base,
+                marks: vec![],
+            },
+            None => Recipe {
+                name,
+                ..self.recipe_for_char(base)?
+            },
+        };
+        for &mark in marks {
+            let mark = self.glyph_for(mark).or_else(|| {
+                SPACING_MARKS
+                    .iter()
+                    .find(|&&(m, _)| m == mark)
+                    .and_then(|&(_, spacing)| self.glyph_for(spacing))
+            })?;
+            recipe.marks.push((mark, Attachment::Auto));
+        }
+        Some(recipe)
+    }
+
+    pub fn build(&self, recipe: &Recipe) -> Result<Composite, Box<dyn std::error::Error>> {
+        let get = |name: &str| {
+            self.glyphs
+                .get(name)
+                .ok_or_else(|| format!("{}: glyph `{name}` does not exist.", recipe.name))
+        };
+        let component = |name: &str, glyph: &Rc<RefCell<Glyph>>, x_offset, y_offset| Component {
+            base_name: name.to_string(),
+            base: Rc::downgrade(glyph),
+            x_offset,
+            y_offset,
+            x_scale: 1.0,
+            xy_scale: 0.0,
+            yx_scale: 0.0,
+            y_scale: 1.0,
+        };
+
+        let base = get(&recipe.base)?;
+        let width = base.borrow().width();
+        let mut anchors: IndexMap<String, (f64, f64)> = base
+            .borrow()
+            .anchors
+            .borrow()
+            .iter()
+            .map(|a| (a.name.clone(), (a.x, a.y)))
+            .collect();
+        let mut components = vec![component(&recipe.base, base, 0.0, 0.0)];
+        for (name, attachment) in &recipe.marks {
+            let mark = get(name)?;
+            let mark_ref = mark.borrow();
+            let mark_anchors = mark_ref.anchors.borrow();
+            let centred = || {
+                (
+                    (width.unwrap_or(0.0) - mark_ref.width().unwrap_or(0.0)) / 2.0,
+                    0.0,
+                )
+            };
+            let (dx, dy) = match attachment {
+                Attachment::Offset(x, y) => (*x, *y),
+                Attachment::Anchor(anchor) => {
+                    let &(x, y) = anchors.get(anchor).ok_or_else(|| {
+                        format!(
+                            "{}: glyph `{}` has no anchor `{anchor}` for `{name}`.",
+                            recipe.name, recipe.base
+                        )
+                    })?;
+                    match mark_anchors
+                        .iter()
+                        .find(|a| a.name.strip_prefix('_') == Some(anchor.as_str()))
+                    {
+                        Some(a) => (x - a.x, y - a.y),
+                        None => (x - mark_ref.width().unwrap_or(0.0) / 2.0, 0.0),
+                    }
+                }
+                Attachment::Auto => mark_anchors
+                    .iter()
+                    .find_map(|a| {
+                        let &(x, y) = anchors.get(a.name.strip_prefix('_')?)?;
+                        Some((x - a.x, y - a.y))
+                    })
+                    .unwrap_or_else(centred),
+            };
+            for a in mark_anchors.iter().filter(|a| !a.name.starts_with('_')) {
+                anchors.insert(a.name.clone(), (a.x + dx, a.y + dy));
+            }
+            components.push(component(name, mark, dx, dy));
+        }
+        Ok(Composite {
+            components,
+            anchors: anchors
+                .into_iter()
+                .map(|(name, (x, y))| Anchor { name, x, y })
+                .collect(),
+            width,
+        })
+    }
+}
+
+#[test]
+fn test_composites() {
+    use super::GlyphKind;
+
+    assert_eq!(
+        "aacute = a + acutecomb@top".parse::<Recipe>().unwrap(),
+        Recipe {
+            name: "aacute".to_string(),
+            base: "a".to_string(),
+            marks: vec![(
+                "acutecomb".to_string(),
+                Attachment::Anchor("top".to_string())
+            )],
+        }
+    );
+    assert!("aacute a + acutecomb".parse::<Recipe>().is_err());
+    assert!("aacute = a + acutecomb@1,x".parse::<Recipe>().is_err());
+    let recipes = Recipe::parse_file("# comment\n\nldot = l + periodcentered@280,0 # dot\nfoo =\n");
+    assert!(recipes.unwrap_err().to_string().starts_with("Line 4:"));
+    assert_eq!(
+        Recipe::parse_file("ldot = l + periodcentered@280,0 # dot").unwrap()[0].marks,
+        vec![("periodcentered".to_string(), Attachment::Offset(280.0, 0.0))]
+    );
+
+    let glyph = |c: char, name: &str, width: f64, anchors: &[(&str, f64, f64)]| {
+        let glyph: Glyph = GlyphKind::Char(c).into();
+        *glyph.metadata.name.borrow_mut() = name.to_string();
+        glyph.metadata.width.set(Some(width));
+        *glyph.metadata.anchors.borrow_mut() = anchors
+            .iter()
+            .map(|&(name, x, y)| Anchor {
+                name: name.to_string(),
+                x,
+                y,
+            })
+            .collect();
+        (name.to_string(), Rc::new(RefCell::new(glyph)))
+    };
+    let glyphs: IndexMap<String, Rc<RefCell<Glyph>>> = [
+        glyph('a', "a", 500.0, &[("top", 250.0, 500.0)]),
+        glyph('i', "i", 250.0, &[("top", 125.0, 700.0)]),
+        glyph('\u{0131}', "dotlessi", 250.0, &[("top", 125.0, 500.0)]),
+        glyph('u', "u", 550.0, &[]),
+        glyph('\u{00A8}', "dieresis", 300.0, &[]),
+        glyph(
+            '\u{0301}',
+            "acutecomb",
+            0.0,
+            &[("_top", 0.0, 500.0), ("top", 0.0, 700.0)],
+        ),
+    ]
+    .into_iter()
+    .collect();
+    let builder = CompositeBuilder::new(&glyphs);
+
+    let aacute = builder.recipe_for_char('á').unwrap();
+    assert_eq!(aacute.name, "aacute");
+    assert_eq!(aacute.unicode(), Some('á'));
+    assert_eq!(builder.recipe_for_char('í').unwrap().base, "dotlessi");
+    /* `ǘ` expands to `u`, falls back to the spacing dieresis and centres it. */
+    let udieresisacute = builder.recipe_for_char('ǘ').unwrap();
+    assert_eq!(udieresisacute.base, "u");
+    assert_eq!(
+        udieresisacute
+            .marks
+            .iter()
+            .map(|(m, _)| m.as_str())
+            .collect::<Vec<_>>(),
+        vec!["dieresis", "acutecomb"]
+    );
+    assert!(builder.recipe_for_char('ñ').is_none());
+    assert!(builder.recipe_for_char('a').is_none());
+
+    let composite = builder.build(&aacute).unwrap();
+    assert_eq!(composite.width, Some(500.0));
+    assert_eq!(composite.components.len(), 2);
+    assert_eq!(composite.components[1].x_offset, 250.0);
+    assert_eq!(composite.components[1].y_offset, 0.0);
+    assert_eq!(
+        composite.anchors,
+        vec![Anchor {
+            name: "top".to_string(),
+            x: 250.0,
+            y: 700.0
+        }]
+    );
+    let stacked = builder
+        .build(&"x = a + acutecomb + acutecomb".parse().unwrap())
+        .unwrap();
+    assert_eq!(stacked.components[2].y_offset, 200.0);
+    let composite = builder.build(&udieresisacute).unwrap();
+    assert_eq!(composite.components[1].x_offset, 125.0);
+    assert!(builder
+        .build(&"x = a + acutecomb@bottom".parse().unwrap())
+        .is_err());
+    assert!(builder
+        .build(&"x = b + acutecomb".parse().unwrap())
+        .is_err());
+}
@@ -58,6 +58,15 @@ impl Contour {
         ret
     }
 
+    /// A new contour with duplicates of this contour's curves.
+    pub fn duplicate(&self) -> Self {
+        let ret = Self::new_with_curves(self.curves().iter().map(Bezier::duplicate).collect());
+        if !self.imp().open.get() {
+            ret.close();
+        }
+        ret
+    }
+
     pub fn curves(&self) -> crate::utils::FieldRef<'_, Vec<Bezier>> {
         self.imp().curves.borrow().into()
     }
@@ -241,6 +250,19 @@ impl Contour {
         for c in curves.iter() {
             c.reverse();
         }
+        /* An implied point now ends the curve that used to follow it. */
+        let implied = curves
+            .iter()
+            .map(|c| c.property::<bool>(Bezier::IMPLIED_END))
+            .collect::<Vec<bool>>();
+        let closed = !self.imp().open.get();
+        for (i, c) in curves.iter().enumerate() {
+            let value = match implied.get(i + 1) {
+                Some(v) => *v,
+                None => closed && implied[0],
+            };
+            c.set_property(Bezier::IMPLIED_END, value);
+        }
     }
 
     // [ref:needs_unit_test]
@@ -308,10 +330,39 @@ impl Contour {
                         cp.position *= m;
                     })
                 );
+                if pts_len == 3 && i == 1 {
+                    // Point is a quadratic handle.
+                    // Implied oncurve points stay at the midpoint of their neighbouring handles.
+                    let handle = curr.points()[1].position;
+                    let prev_implied = (closed || curr_idx != 0)
+                        && prev.points().len() == 3
+                        && prev.property::<bool>(Bezier::IMPLIED_END);
+                    if prev_implied {
+                        let mid = (prev.points()[1].position + handle) / 2.0;
+                        updated!(prev_idx, prev.modify_point(2, |cp| { cp.position = mid }));
+                        updated!(curr_idx, curr.modify_point(0, |cp| { cp.position = mid }));
+                    }
+                    if (closed || curr_idx + 1 != curves.len())
+                        && next.points().len() == 3
+                        && curr.property::<bool>(Bezier::IMPLIED_END)
+                    {
+                        let mid = (handle + next.points()[1].position) / 2.0;
+                        updated!(curr_idx, curr.modify_point(2, |cp| { cp.position = mid }));
+                        updated!(next_idx, next.modify_point(0, |cp| { cp.position = mid }));
+                    }
+                    if prev_implied {
+                        continue;
+                    }
+                }
                 if i == 0 {
                     // Point is first oncurve point.
                     // also transform prev last oncurve point and its handle
 
+                    /* An implied point that is moved explicitly becomes a real point */
+                    if prev.property::<bool>(Bezier::IMPLIED_END) {
+                        prev.set_property(Bezier::IMPLIED_END, false);
+                    }
+
                     /* Points handle if it's not quadratic */
                     {
                         if pts_len > 2 && !extra_uuids.contains(&(curr_idx, curr.points()[1].uuid))
@@ -343,6 +394,11 @@ impl Contour {
                     // Point is last oncurve point.
                     // also transform next first oncurve point and its handle
 
+                    /* An implied point that is moved explicitly becomes a real point */
+                    if curr.property::<bool>(Bezier::IMPLIED_END) {
+                        curr.set_property(Bezier::IMPLIED_END, false);
+                    }
+
                     /* Points handle if it's not quadratic */
                     {
                         if pts_len > 2
@@ -576,6 +632,512 @@ impl Contour {
         curves.pop()
     }
 
+    /// Replaces all curves and returns the previous ones.
+    pub fn set_curves(&self, curves: Vec<Bezier>) -> Vec<Bezier> {
+        let closed = !self.open.get();
+        let old = std::mem::take(&mut *self.curves.borrow_mut());
+        self.biggest_curve.set(None);
+        self.open.set(true);
+        for c in curves {
+            self.push_curve(c);
+        }
+        if closed {
+            self.close();
+        }
+        self.is_contour_modified.set(true);
+        old
+    }
+
+    /// New curves where every cubic is approximated by a quadratic spline within `tolerance`
+    /// units. Other curves are copied.
+    pub fn to_quadratic_curves(&self, tolerance: f64) -> Vec<Bezier> {
+        let mut ret = vec![];
+        for curv in self.curves().iter() {
+            let points = curv.points();
+            if let [p0, p1, p2, p3] = points.as_slice() {
+                let spline = crate::utils::quadratic::cubic_to_quadratic_lossy(
+                    [p0.position, p1.position, p2.position, p3.position],
+                    tolerance,
+                );
+                let new = quadratic_spline(
+                    spline[0],
+                    &spline[1..spline.len() - 1],
+                    spline[spline.len() - 1],
+                );
+                if curv.property::<bool>(Bezier::SMOOTH) {
+                    new[0].set_property(Bezier::SMOOTH, true);
+                }
+                ret.extend(new);
+            } else {
+                ret.push(curv.duplicate());
+            }
+        }
+        ret
+    }
+
+    /// New curves where every quadratic is elevated to an equivalent cubic. Other curves are
+    /// copied.
+    pub fn to_cubic_curves(&self) -> Vec<Bezier> {
+        let mut implied_start = !self.open.get()
+            && self
+                .curves()
+                .last()
+                .map(|c| c.property::<bool>(Bezier::IMPLIED_END))
+                .unwrap_or(false);
+        let mut ret = vec![];
+        for curv in self.curves().iter() {
+            let points = curv.points();
+            let new = if let [p0, p1, p2] = points.as_slice() {
+                let cubic = crate::utils::quadratic::quadratic_to_cubic([
+                    p0.position,
+                    p1.position,
+                    p2.position,
+                ]);
+                let new = Bezier::new(cubic.to_vec());
+                new.set_property(
+                    Bezier::SMOOTH,
+                    implied_start || curv.property::<bool>(Bezier::SMOOTH),
+                );
+                new
+            } else {
+                curv.duplicate()
+            };
+            new.set_property(Bezier::IMPLIED_END, false);
+            implied_start = curv.property::<bool>(Bezier::IMPLIED_END);
+            ret.push(new);
+        }
+        ret
+    }
+
+    fn rebuilt(curves: Vec<Bezier>, closed: bool) -> Self {
+        let ret = Self::new_with_curves(curves);
+        if closed {
+            ret.close();
+        }
+        ret
+    }
+
+    fn duplicate_curves(&self) -> Vec<Bezier> {
+        self.curves().iter().map(Bezier::duplicate).collect()
+    }
+
+    /// The on-curve point at `point_index` of curve `curve_index` as the index of the curve it
+    /// starts, or the number of curves for the end of an open contour. `None` for handles.
+    pub fn oncurve_index(&self, curve_index: usize, point_index: usize) -> Option<usize> {
+        let curves = self.curves();
+        let len = curves.get(curve_index)?.points().len();
+        if point_index == 0 {
+            Some(curve_index)
+        } else if point_index + 1 == len {
+            if self.imp().open.get() {
+                Some(curve_index + 1)
+            } else {
+                Some((curve_index + 1) % curves.len())
+            }
+        } else {
+            None
+        }
+    }
+
+    /// A copy of this contour where curve `curve_index` is split at `t`.
+    pub fn with_point_inserted(&self, curve_index: usize, t: f64) -> Self {
+        let mut curves = self.duplicate_curves();
+        let (left, right) = curves[curve_index].split(t);
+        curves.splice(curve_index..=curve_index, [left, right]);
+        Self::rebuilt(curves, !self.imp().open.get())
+    }
+
+    /// A copy of this contour without the on-curve point `oncurve_index` (see
+    /// [`Self::oncurve_index`]). The two curves around it are refitted into one curve. Returns
+    /// `None` if nothing would be left of the contour.
+    pub fn with_point_deleted(&self, oncurve_index: usize) -> Option<Self> {
+        let closed = !self.imp().open.get();
+        let mut curves = self.duplicate_curves();
+        let n = curves.len();
+        if (closed && n <= 2) || (!closed && n <= 1) {
+            return None;
+        }
+        if !closed && oncurve_index == 0 {
+            curves.remove(0);
+            return Some(Self::rebuilt(curves, false));
+        }
+        if !closed && oncurve_index >= n {
+            curves.pop();
+            return Some(Self::rebuilt(curves, false));
+        }
+        let (prev_index, curr_index) = ((oncurve_index + n - 1) % n, oncurve_index % n);
+        let (prev, curr) = (&curves[prev_index], &curves[curr_index]);
+        let merged = if prev.degree() == Some(1) && curr.degree() == Some(1) {
+            let (start, end) = (prev.points()[0].position, curr.points()[1].position);
+            Bezier::new(vec![start, end])
+        } else {
+            const SAMPLES: usize = 16;
+            let samples = (0..SAMPLES)
+                .map(|i| prev.compute(i as f64 / SAMPLES as f64))
+                .chain((0..=SAMPLES).map(|i| curr.compute(i as f64 / SAMPLES as f64)))
+                .collect::<Vec<Point>>();
+            let fit = crate::utils::curves::fit_cubic(
+                &samples,
+                prev.start_direction(),
+                curr.end_direction(),
+            );
+            Bezier::new(fit.to_vec())
+        };
+        merged.set_property(Bezier::SMOOTH, prev.property::<bool>(Bezier::SMOOTH));
+        if curr_index == 0 {
+            /* The deleted point started a closed contour: the merged curve is the new start. */
+            curves.pop();
+            curves[0] = merged;
+        } else {
+            curves.splice(prev_index..=curr_index, [merged]);
+        }
+        Some(Self::rebuilt(curves, closed))
+    }
+
+    /// A copy of this contour where handle `point_index` of curve `curve_index` is retracted to
+    /// its on-curve point. A curve without handles becomes a line.
+    pub fn with_handle_retracted(&self, curve_index: usize, point_index: usize) -> Self {
+        let mut curves = self.duplicate_curves();
+        let mut points = curves[curve_index]
+            .points()
+            .iter()
+            .map(|cp| cp.position)
+            .collect::<Vec<Point>>();
+        let last = points.len() - 1;
+        if points.len() == 3 {
+            points.remove(1);
+        } else if point_index == 1 {
+            points[1] = points[0];
+        } else if point_index + 1 == last {
+            points[point_index] = points[last];
+        }
+        if points.len() == 4 && points[1] == points[0] && points[2] == points[3] {
+            points = vec![points[0], points[3]];
+        }
+        curves[curve_index] = Bezier::new(points);
+        Self::rebuilt(curves, !self.imp().open.get())
+    }
+
+    /// A copy of this contour where curve `curve_index` becomes a line.
+    pub fn with_curve_as_line(&self, curve_index: usize) -> Self {
+        let mut curves = self.duplicate_curves();
+        let (start, end) = {
+            let points = curves[curve_index].points();
+            (points[0].position, points[points.len() - 1].position)
+        };
+        curves[curve_index] = Bezier::new(vec![start, end]);
+        Self::rebuilt(curves, !self.imp().open.get())
+    }
+
+    /// A copy of this contour where line `curve_index` becomes a cubic curve with handles at a
+    /// third of its length from each end.
+    pub fn with_line_as_curve(&self, curve_index: usize) -> Self {
+        let mut curves = self.duplicate_curves();
+        let (a, b) = {
+            let points = curves[curve_index].points();
+            (points[0].position, points[points.len() - 1].position)
+        };
+        curves[curve_index] = Bezier::new(vec![
+            a,
+            a + (1.0 / 3.0) * (b - a),
+            a + (2.0 / 3.0) * (b - a),
+            b,
+        ]);
+        Self::rebuilt(curves, !self.imp().open.get())
+    }
+
+    /// Breaks the contour at on-curve point `oncurve_index` (see [`Self::oncurve_index`]). A
+    /// closed contour becomes an open one that starts and ends there, an open contour is
+    /// split in two.
+    pub fn broken_at(&self, oncurve_index: usize) -> Vec<Self> {
+        let mut curves = self.duplicate_curves();
+        let n = curves.len();
+        if !self.imp().open.get() {
+            curves.rotate_left(oncurve_index % n.max(1));
+            return vec![Self::rebuilt(curves, false)];
+        }
+        if oncurve_index == 0 || oncurve_index >= n {
+            return vec![Self::rebuilt(curves, false)];
+        }
+        let tail = curves.split_off(oncurve_index);
+        vec![Self::rebuilt(curves, false), Self::rebuilt(tail, false)]
+    }
+
+    /// A copy of this open contour with its direction reversed.
+    pub fn reversed(&self) -> Self {
+        let ret = Self::rebuilt(self.duplicate_curves(), !self.imp().open.get());
+        ret.reverse_direction();
+        ret
+    }
+
+    /// Joins the end of this open contour to the start of `other`, or closes this contour if
+    /// `other` is `None`. A line is added between the two points if they do not coincide.
+    pub fn joined(&self, other: Option<&Self>) -> Self {
+        let mut curves = self.duplicate_curves();
+        let mut append = match other {
+            Some(other) => other.duplicate_curves(),
+            None => vec![],
+        };
+        let end = curves
+            .last()
+            .and_then(|c| c.points().last().map(|cp| cp.position));
+        let start = append
+            .first()
+            .or_else(|| curves.first())
+            .and_then(|c| c.points().first().map(|cp| cp.position));
+        if let (Some(end), Some(start)) = (end, start) {
+            if end != start {
+                curves.push(Bezier::new(vec![end, start]));
+            }
+        }
+        curves.append(&mut append);
+        Self::rebuilt(curves, other.is_none())
+    }
+
+    /// Slices the contour along the segment from `a` to `b`. A closed contour is cut into
+    /// closed pieces that are completed with lines along the cut, an open contour into open
+    /// pieces. Returns `None` if the segment does not cut through the contour.
+    pub fn sliced(&self, a: Point, b: Point) -> Option<Vec<Self>> {
+        const EPSILON: f64 = 1e-6;
+        const DELTA: f64 = 1e-4;
+        let closed = !self.imp().open.get();
+        let curves = self.duplicate_curves();
+        let n = curves.len();
+        let direction = b - a;
+        let side = |p: Point| {
+            let p = p - a;
+            (direction.x * p.y - direction.y * p.x).signum()
+        };
+        /* Crossings as (curve index, t). A crossing at a joint belongs to the start of the
+         * next curve, and it only counts if the contour passes to the other side there. */
+        let mut crossings: Vec<(usize, f64)> = vec![];
+        for (i, curve) in curves.iter().enumerate() {
+            for (t, _) in curve.line_intersections(a, b) {
+                let (mut ci, ct) = if t > 1.0 - EPSILON {
+                    (i + 1, 0.0)
+                } else if t < EPSILON {
+                    (i, 0.0)
+                } else {
+                    (i, t)
+                };
+                if closed {
+                    ci %= n;
+                } else if (ci == 0 && ct == 0.0) || ci == n {
+                    continue;
+                }
+                let (before, after) = if ct == 0.0 {
+                    (
+                        curves[(ci + n - 1) % n].compute(1.0 - DELTA),
+                        curves[ci].compute(DELTA),
+                    )
+                } else {
+                    (
+                        curves[ci].compute(ct - DELTA),
+                        curves[ci].compute(ct + DELTA),
+                    )
+                };
+                if side(before) != side(after) {
+                    crossings.push((ci, ct));
+                }
+            }
+        }
+        crossings.sort_by(|x, y| x.0.cmp(&y.0).then(x.1.total_cmp(&y.1)));
+        crossings.dedup_by(|x, y| x.0 == y.0 && (x.1 - y.1).abs() < EPSILON);
+        if closed {
+            /* Keep the crossings that bound a stretch of the cut inside the contour, so that a
+             * cut which starts or ends inside the contour does not split it there. */
+            let at = |&(ci, t): &(usize, f64)| curves[ci].compute(t);
+            let mut order = (0..crossings.len()).collect::<Vec<usize>>();
+            order.sort_by(|&x, &y| {
+                direction
+                    .dot(at(&crossings[x]) - a)
+                    .total_cmp(&direction.dot(at(&crossings[y]) - a))
+            });
+            let mut keep = vec![false; crossings.len()];
+            let mut k = 0;
+            while k + 1 < order.len() {
+                let mid = 0.5 * (at(&crossings[order[k]]) + at(&crossings[order[k + 1]]));
+                if winding_number(&curves, mid) != 0 {
+                    keep[order[k]] = true;
+                    keep[order[k + 1]] = true;
+                    k += 2;
+                } else {
+                    k += 1;
+                }
+            }
+            let mut keep = keep.into_iter();
+            crossings.retain(|_| keep.next().unwrap());
+        }
+        let m = crossings.len();
+        if m == 0 {
+            return None;
+        }
+
+        /* Split the curves at every crossing. `pieces[k + 1]` starts at crossing `k`. */
+        let mut pieces: Vec<Vec<Bezier>> = vec![vec![]];
+        let mut next = crossings.iter().peekable();
+        for (i, curve) in curves.into_iter().enumerate() {
+            let mut rest = curve;
+            let mut prev_t = 0.0;
+            while let Some(&&(ci, t)) = next.peek() {
+                if ci != i {
+                    break;
+                }
+                next.next();
+                if t > 0.0 {
+                    let (left, right) = rest.split((t - prev_t) / (1.0 - prev_t));
+                    pieces.last_mut().unwrap().push(left);
+                    rest = right;
+                    prev_t = t;
+                }
+                rest.set_property(Bezier::SMOOTH, false);
+                pieces.push(vec![]);
+            }
+            pieces.last_mut().unwrap().push(rest);
+        }
+        if !closed {
+            return Some(
+                pieces
+                    .into_iter()
+                    .filter(|p| !p.is_empty())
+                    .map(|p| Self::rebuilt(p, false))
+                    .collect(),
+            );
+        }
+        /* The curves before the first crossing continue the last piece. */
+        let head = pieces.remove(0);
+        pieces.last_mut().unwrap().extend(head);
+        let points = pieces
+            .iter()
+            .map(|p| p[0].points()[0].position)
+            .collect::<Vec<Point>>();
+
+        /* Along the cut, the kept crossings pair up in order: each pair bounds a stretch of the
+         * cut that lies inside the contour. */
+        let mut order = (0..m).collect::<Vec<usize>>();
+        order.sort_by(|&x, &y| {
+            direction
+                .dot(points[x] - a)
+                .total_cmp(&direction.dot(points[y] - a))
+        });
+        let mut partner = vec![0; m];
+        for pair in order.chunks(2) {
+            partner[pair[0]] = pair[1];
+            partner[pair[1]] = pair[0];
+        }
+
+        /* Follow a piece to its end, then jump across the cut to the partner crossing and
+         * continue with the piece that starts there, until the loop closes. */
+        let mut used = vec![false; m];
+        let mut ret = vec![];
+        for start in 0..m {
+            if used[start] {
+                continue;
+            }
+            let mut new_curves = vec![];
+            let mut k = start;
+            while !used[k] {
+                used[k] = true;
+                new_curves.append(&mut pieces[k]);
+                let end = (k + 1) % m;
+                k = partner[end];
+                new_curves.push(Bezier::new(vec![points[end], points[k]]));
+            }
+            ret.push(Self::rebuilt(new_curves, true));
+        }
+        Some(ret)
+    }
+
+    /// A copy of this contour with an on-curve point at every horizontal and vertical extremum
+    /// of its curves, and the number of points that were added.
+    pub fn with_extremes(&self) -> (Self, usize) {
+        let mut curves = vec![];
+        let mut added = 0;
+        for curve in self.curves().iter() {
+            let mut rest = curve.duplicate();
+            let mut prev_t = 0.0;
+            for t in curve.extrema() {
+                let (left, right) = rest.split((t - prev_t) / (1.0 - prev_t));
+                curves.push(left);
+                rest = right;
+                prev_t = t;
+                added += 1;
+            }
+            curves.push(rest);
+        }
+        (Self::rebuilt(curves, !self.imp().open.get()), added)
+    }
+
+    /// A copy of this contour where runs of consecutive curves that a single curve approximates
+    /// within `tolerance` font units are merged, and the number of points that were removed.
+    /// On-curve points at extrema between curves are kept.
+    pub fn simplified(&self, tolerance: f64) -> (Self, usize) {
+        const SAMPLES: usize = 16;
+        let curves = self.curves();
+        let n = curves.len();
+        let is_extreme_joint = |prev: &Bezier, next: &Bezier| {
+            if prev.degree() == Some(1) || next.degree() == Some(1) {
+                return false;
+            }
+            let d = prev.end_direction();
+            d.x.abs() < 1e-3 || d.y.abs() < 1e-3
+        };
+        let try_merge = |run: &[Bezier]| -> Option<Bezier> {
+            let start = run[0].points()[0].position;
+            let end = {
+                let last = run[run.len() - 1].points();
+                last[last.len() - 1].position
+            };
+            if run.iter().all(|c| c.degree() == Some(1)) {
+                let line = Bezier::new(vec![start, end]);
+                let within = run[1..].iter().all(|c| {
+                    let joint = c.points()[0].position;
+                    line.compute(line.nearest_t(joint)).distance(joint) <= tolerance
+                });
+                return within.then_some(line);
+            }
+            let samples = run
+                .iter()
+                .enumerate()
+                .flat_map(|(i, c)| {
+                    let skip = usize::from(i > 0);
+                    (skip..=SAMPLES).map(move |s| c.compute(s as f64 / SAMPLES as f64))
+                })
+                .collect::<Vec<Point>>();
+            let fit = crate::utils::curves::fit_cubic(
+                &samples,
+                run[0].start_direction(),
+                run[run.len() - 1].end_direction(),
+            );
+            let merged = Bezier::new(fit.to_vec());
+            samples
+                .iter()
+                .all(|&p| merged.compute(merged.nearest_t(p)).distance(p) <= tolerance)
+                .then_some(merged)
+        };
+        let mut ret = vec![];
+        let mut i = 0;
+        while i < n {
+            let mut end = i + 1;
+            let mut merged = curves[i].duplicate();
+            while end < n && !is_extreme_joint(&curves[end - 1], &curves[end]) {
+                match try_merge(&curves[i..=end]) {
+                    Some(m) => {
+                        m.set_property(Bezier::SMOOTH, curves[i].property::<bool>(Bezier::SMOOTH));
+                        merged = m;
+                        end += 1;
+                    }
+                    None => break,
+                }
+            }
+            ret.push(merged);
+            i = end;
+        }
+        let removed = n - ret.len();
+        drop(curves);
+        (Self::rebuilt(ret, !self.imp().open.get()), removed)
+    }
+
     // [ref:needs_unit_test]
     pub fn change_continuity(
         &self,
@@ -755,6 +1317,31 @@ impl ObjectImpl for ContourInner {
     }
 }
 
+/// Quadratic curves from `start` to `end` through `offcurves`, with TrueType implied on-curve
+/// points halfway between consecutive off-curve points. Without off-curve points this is a line.
+pub fn quadratic_spline(start: Point, offcurves: &[Point], end: Point) -> Vec<Bezier> {
+    if offcurves.is_empty() {
+        return vec![Bezier::new(vec![start, end])];
+    }
+    let mut ret = Vec::with_capacity(offcurves.len());
+    let mut prev = start;
+    for (i, off) in offcurves.iter().enumerate() {
+        let (next, implied) = match offcurves.get(i + 1) {
+            Some(next_off) => (0.5 * (*off + *next_off), true),
+            None => (end, false),
+        };
+        let curv = Bezier::new(vec![prev, *off, next]);
+        curv.set_property(Bezier::IMPLIED_END, implied);
+        if i > 0 {
+            /* Implied points are always smooth. */
+            curv.set_property(Bezier::SMOOTH, true);
+        }
+        ret.push(curv);
+        prev = next;
+    }
+    ret
+}
+
 /// Given two cubic Bézier curves with control points [P0, P1, P2, P3] and [P3, P4, P5, P6]
 /// respectively, the constraints for ensuring continuity at P3 can be defined as follows:
 #[derive(Clone, Debug, Default, PartialEq, Copy, glib::Boxed)]
@@ -779,3 +1366,207 @@ impl Continuity {
         matches!(self, Self::Positional)
     }
 }
+
+/// Winding number of the closed outline made of `curves` around `p`, with the curves
+/// flattened to line segments.
+fn winding_number(curves: &[Bezier], p: Point) -> i32 {
+    const STEPS: usize = 32;
+    let mut ret = 0;
+    for curve in curves {
+        let mut u = curve.compute(0.0);
+        for s in 1..=STEPS {
+            let v = curve.compute(s as f64 / STEPS as f64);
+            let cross = (v.x - u.x) * (p.y - u.y) - (p.x - u.x) * (v.y - u.y);
+            if u.y <= p.y {
+                if v.y > p.y && cross > 0.0 {
+                    ret += 1;
+                }
+            } else if v.y <= p.y && cross < 0.0 {
+                ret -= 1;
+            }
+            u = v;
+        }
+    }
+    ret
+}
+
+#[test]
+fn test_contour_point_editing() {
+    let square = [(0.0, 0.0), (0.0, 100.0), (100.0, 100.0), (100.0, 0.0)]
+        .iter()
+        .zip([(0.0, 100.0), (100.0, 100.0), (100.0, 0.0), (0.0, 0.0)].iter())
+        .map(|(&a, &b)| Bezier::new(vec![a.into(), b.into()]))
+        .collect::<Vec<_>>();
+    let contour = Contour::new_with_curves(square);
+    contour.close();
+
+    let inserted = contour.with_point_inserted(0, 0.5);
+    assert_eq!(inserted.curves().len(), 5);
+    assert_eq!(
+        inserted.curves()[1].points()[0].position,
+        Point::from((0.0, 50.0))
+    );
+    let deleted = inserted.with_point_deleted(1).unwrap();
+    assert_eq!(deleted.curves().len(), 4);
+    assert_eq!(
+        deleted.curves()[0].points()[1].position,
+        Point::from((0.0, 100.0))
+    );
+
+    let curved = contour.with_line_as_curve(1);
+    assert_eq!(curved.curves()[1].degree(), Some(3));
+    assert_eq!(curved.with_curve_as_line(1).curves()[1].degree(), Some(1));
+
+    let broken = contour.broken_at(2);
+    assert_eq!(broken.len(), 1);
+    assert!(broken[0].imp().open.get());
+    assert_eq!(
+        broken[0].curves()[0].points()[0].position,
+        Point::from((100.0, 100.0))
+    );
+    let halves = broken[0].broken_at(1);
+    assert_eq!(halves.len(), 2);
+    let joined = halves[0].joined(Some(&halves[1])).joined(None);
+    assert!(!joined.imp().open.get());
+    assert_eq!(joined.curves().len(), 4);
+}
+
+#[test]
+fn test_contour_extremes_and_simplify() {
+    /* A circle-ish closed contour drawn with two curves, without points at extrema. */
+    let contour = Contour::new_with_curves(vec![
+        Bezier::new(vec![
+            (0.0, -100.0).into(),
+            (-140.0, -100.0).into(),
+            (-140.0, 100.0).into(),
+            (0.0, 100.0).into(),
+        ]),
+        Bezier::new(vec![
+            (0.0, 100.0).into(),
+            (140.0, 100.0).into(),
+            (140.0, -100.0).into(),
+            (0.0, -100.0).into(),
+        ]),
+    ]);
+    contour.close();
+    let (with_extremes, added) = contour.with_extremes();
+    assert_eq!(added, 2);
+    assert_eq!(with_extremes.curves().len(), 4);
+    let leftmost = with_extremes.curves()[1].points()[0].position;
+    assert!((leftmost.y).abs() < 1e-6);
+    assert!((leftmost.x + 105.0).abs() < 1e-6);
+    assert_eq!(with_extremes.with_extremes().1, 0);
+
+    /* Splitting and simplifying gives back the same number of curves. */
+    let split = contour
+        .with_point_inserted(0, 0.25)
+        .with_point_inserted(2, 0.6);
+    assert_eq!(split.curves().len(), 4);
+    let (simplified, removed) = split.simplified(0.5);
+    assert_eq!(removed, 2);
+    assert_eq!(simplified.curves().len(), 2);
+}
+
+#[test]
+fn test_contour_sliced() {
+    /* A U shape cut across both arms: the bottom and the two arm tops. */
+    let corners: [(f64, f64); 8] = [
+        (0.0, 0.0),
+        (100.0, 0.0),
+        (100.0, 100.0),
+        (70.0, 100.0),
+        (70.0, 30.0),
+        (30.0, 30.0),
+        (30.0, 100.0),
+        (0.0, 100.0),
+    ];
+    let contour = Contour::new_with_curves(
+        (0..corners.len())
+            .map(|i| {
+                Bezier::new(vec![
+                    corners[i].into(),
+                    corners[(i + 1) % corners.len()].into(),
+                ])
+            })
+            .collect(),
+    );
+    contour.close();
+    let pieces = contour
+        .sliced((-10.0, 60.0).into(), (110.0, 60.0).into())
+        .unwrap();
+    assert_eq!(pieces.len(), 3);
+    let sizes = pieces
+        .iter()
+        .map(|p| p.curves().len())
+        .collect::<Vec<usize>>();
+    assert_eq!(sizes, vec![4, 8, 4]);
+    for piece in &pieces {
+        let curves = piece.curves();
+        let first = curves[0].points()[0].position;
+        let last = curves[curves.len() - 1].points()[1].position;
+        assert_eq!(first, last);
+    }
+
+    /* A segment that ends inside the contour does not cut it. */
+    assert!(contour
+        .sliced((-10.0, 60.0).into(), (15.0, 60.0).into())
+        .is_none());
+
+    /* A segment from one arm to the other only crosses the gap between them. */
+    assert!(contour
+        .sliced((15.0, 60.0).into(), (85.0, 60.0).into())
+        .is_none());
+
+    /* Only the stretch of the cut inside the right arm splits the contour. */
+    let pieces = contour
+        .sliced((15.0, 60.0).into(), (110.0, 60.0).into())
+        .unwrap();
+    assert_eq!(pieces.len(), 2);
+    let curves = pieces.iter().map(|p| p.curves()).collect::<Vec<_>>();
+    let winding = |p: (f64, f64)| {
+        curves
+            .iter()
+            .map(|c| winding_number(c, p.into()))
+            .collect::<Vec<i32>>()
+    };
+    assert_eq!(winding((85.0, 80.0)).iter().filter(|&&w| w != 0).count(), 1);
+    assert_eq!(winding((15.0, 80.0)).iter().filter(|&&w| w != 0).count(), 1);
+    assert_eq!(winding((50.0, 60.0)), vec![0, 0]);
+}
+
+#[test]
+fn test_contour_sliced_o() {
+    /* An "o": a square with a square counter drawn in the opposite direction. */
+    let square = |corners: [(f64, f64); 4]| {
+        let contour = Contour::new_with_curves(
+            (0..4)
+                .map(|i| Bezier::new(vec![corners[i].into(), corners[(i + 1) % 4].into()]))
+                .collect(),
+        );
+        contour.close();
+        contour
+    };
+    let outer = square([(0.0, 0.0), (100.0, 0.0), (100.0, 100.0), (0.0, 100.0)]);
+    let inner = square([(20.0, 20.0), (20.0, 80.0), (80.0, 80.0), (80.0, 20.0)]);
+    let (a, b) = (Point::from((-10.0, 50.0)), Point::from((110.0, 50.0)));
+    let mut pieces = outer.sliced(a, b).unwrap();
+    assert_eq!(pieces.len(), 2);
+    let counter = inner.sliced(a, b).unwrap();
+    assert_eq!(counter.len(), 2);
+    pieces.extend(counter);
+    let curves = pieces.iter().map(|p| p.curves()).collect::<Vec<_>>();
+    let winding =
+        |p: (f64, f64)| -> i32 { curves.iter().map(|c| winding_number(c, p.into())).sum() };
+    /* The pieces still fill the ring and leave the counter empty. */
+    for p in [(10.0, 30.0), (10.0, 70.0), (90.0, 30.0), (50.0, 90.0)] {
+        assert_ne!(winding(p), 0, "{p:?}");
+    }
+    for p in [(50.0, 30.0), (50.0, 70.0), (110.0, 30.0)] {
+        assert_eq!(winding(p), 0, "{p:?}");
+    }
+
+    /* A cut that starts in the counter does not split either contour. */
+    let (a, b) = (Point::from((50.0, 50.0)), Point::from((150.0, 50.0)));
+    assert!(outer.sliced(a, b).is_none());
+    assert!(inner.sliced(a, b).is_none());
+}
@@ -0,0 +1,525 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Corner treatments: rounded corners, chamfers, ink traps and custom corner shapes.
+//!
+//! A treatment replaces a sharp corner with curves that start and end `size` font units away
+//! from it. The parameters are kept in the glyph lib with what is needed to restore the sharp
+//! corner, so that a treatment can be edited or re-applied after the outline changes.
+
+use super::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "CornerKind")]
+#[serde(rename_all = "kebab-case")]
+pub enum CornerKind {
+    #[default]
+    Round,
+    Chamfer,
+    Inktrap,
+    Custom,
+}
+
+impl EnumValue<'_> for CornerKind {
+    fn kebab_case_variants() -> &'static [&'static str] {
+        &["round", "chamfer", "inktrap", "custom"]
+    }
+}
+
+/// Distance in font units within which a stored point matches a point of the outline.
+const TOLERANCE: f64 = 0.01;
+
+/// A corner treatment, as stored in the glyph lib under [`Corner::LIB_KEY`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Corner {
+    pub kind: CornerKind,
+    /// Distance from the corner where the treatment starts and ends.
+    pub size: f64,
+    /// How far an ink trap cuts past the corner.
+    pub depth: f64,
+    /// Name of the corner glyph of a custom treatment. Its first contour is fitted between the
+    /// two ends of the treatment.
+    pub glyph: String,
+    /// Position of the sharp corner when the treatment was applied.
+    pub position: Point,
+    /// Index of the contour in the glyph.
+    pub contour: usize,
+    /// Index of the first curve of the treatment in its contour.
+    pub index: usize,
+    /// Number of curves of the treatment.
+    pub curves: usize,
+}
+
+impl Corner {
+    pub const LIB_KEY: &str = "io.github.epilys.gerb.corners";
+
+    pub fn new(kind: CornerKind, size: f64, depth: f64, glyph: String) -> Self {
+        Self {
+            kind,
+            size,
+            depth,
+            glyph,
+            position: Point::default(),
+            contour: 0,
+            index: 0,
+            curves: 0,
+        }
+    }
+
+    /// A treatment with the same parameters, not yet applied to any corner.
+    pub fn with_parameters_of(other: &Self) -> Self {
+        Self::new(other.kind, other.size, other.depth, other.glyph.clone())
+    }
+
+    fn to_plist(&self) -> plist::Value {
+        let mut d = plist::Dictionary::new();
+        d.insert("kind".into(), self.kind.name().into());
+        d.insert("size".into(), self.size.into());
+        d.insert("depth".into(), self.depth.into());
+        d.insert("glyph".into(), self.glyph.clone().into());
+        d.insert("x".into(), self.position.x.into());
+        d.insert("y".into(), self.position.y.into());
+        d.insert("contour".into(), (self.contour as u64).into());
+        d.insert("index".into(), (self.index as u64).into());
+        d.insert("curves".into(), (self.curves as u64).into());
+        plist::Value::Dictionary(d)
+    }
+
+    fn from_plist(value: &plist::Value) -> Option<Self> {
+        let d = value.as_dictionary()?;
+        let real = |key: &str| d.get(key).and_then(plist::Value::as_real);
+        let unsigned = |key: &str| {
+            d.get(key)
+                .and_then(plist::Value::as_unsigned_integer)
+                .map(|v| v as usize)
+        };
+        Some(Self {
+            kind: CornerKind::kebab_str_deserialize(d.get("kind")?.as_string()?)?,
+            size: real("size")?,
+            depth: real("depth").unwrap_or(0.0),
+            glyph: d
+                .get("glyph")
+                .and_then(plist::Value::as_string)
+                .unwrap_or_default()
+                .to_string(),
+            position: Point::from((real("x")?, real("y")?)),
+            contour: unsigned("contour")?,
+            index: unsigned("index")?,
+            curves: unsigned("curves")?,
+        })
+    }
+
+    /// The treatments stored in a glyph lib.
+    pub fn load(lib: &IndexMap<String, plist::Value>) -> Vec<Self> {
+        lib.get(Self::LIB_KEY)
+            .and_then(plist::Value::as_array)
+            .map(|a| a.iter().filter_map(Self::from_plist).collect())
+            .unwrap_or_default()
+    }
+
+    /// Replaces the treatments stored in a glyph lib.
+    pub fn store(lib: &mut IndexMap<String, plist::Value>, corners: &[Self]) {
+        if corners.is_empty() {
+            lib.shift_remove(Self::LIB_KEY);
+        } else {
+            lib.insert(
+                Self::LIB_KEY.to_string(),
+                plist::Value::Array(corners.iter().map(Self::to_plist).collect()),
+            );
+        }
+    }
+}
+
+/// The `t` where `curve` is `size` units away from `corner`, which is its end if `from_end`
+/// and its start otherwise. `None` if the curve is too short.
+fn t_at_distance(curve: &Bezier, corner: Point, size: f64, from_end: bool) -> Option<f64> {
+    let (mut far, mut near) = if from_end { (0.0, 1.0) } else { (1.0, 0.0) };
+    if size <= 0.0 || curve.compute(far).distance(corner) <= size {
+        return None;
+    }
+    for _ in 0..50 {
+        let mid = (far + near) / 2.0;
+        if curve.compute(mid).distance(corner) > size {
+            far = mid;
+        } else {
+            near = mid;
+        }
+    }
+    Some((far + near) / 2.0)
+}
+
+/// Where the line through `a` in direction `da` meets the line through `b` in direction
+/// `db`, or `None` if they are parallel.
+fn line_intersection(a: Point, da: Point, b: Point, db: Point) -> Option<Point> {
+    let cross = |u: Point, v: Point| u.x * v.y - u.y * v.x;
+    let denom = cross(da, db);
+    if denom.abs() < 1e-9 {
+        return None;
+    }
+    Some(a + (cross(b - a, db) / denom) * da)
+}
+
+/// A copy of `curve` with its end, or its start if `!at_end`, moved to `p`. The neighbouring
+/// handle moves along with it, so that a `p` on the tangent keeps the curve's direction there.
+fn extended_to(curve: &Bezier, p: Point, at_end: bool) -> Bezier {
+    let mut points = curve
+        .points()
+        .iter()
+        .map(|cp| cp.position)
+        .collect::<Vec<Point>>();
+    let n = points.len();
+    let (end, handle) = if at_end { (n - 1, n - 2) } else { (0, 1) };
+    let delta = p - points[end];
+    points[end] = p;
+    if n > 2 {
+        points[handle] = points[handle] + delta;
+    }
+    let ret = Bezier::new(points);
+    ret.set_property(
+        Bezier::SMOOTH,
+        at_end && curve.property::<bool>(Bezier::SMOOTH),
+    );
+    ret.set_property(
+        Bezier::IMPLIED_END,
+        curve.property::<bool>(Bezier::IMPLIED_END),
+    );
+    ret
+}
+
+impl Contour {
+    /// Replaces the sharp corner at `corner.position` with the treatment `corner` describes,
+    /// in contour `corner.contour`. `shape` is the first contour of the corner glyph of a
+    /// custom treatment. On success the rest of `corner` is filled in to restore the corner
+    /// later, and `others` treatments of the same contour that come after it are moved along.
+    pub fn with_corner<'a>(
+        &self,
+        corner: &mut Corner,
+        shape: Option<&Contour>,
+        others: impl IntoIterator<Item = &'a mut Corner>,
+    ) -> Option<Self> {
+        let closed = !self.open.get();
+        let curves = self.curves();
+        let n = curves.len();
+        let i = (0..n).find(|&i| {
+            (closed || i > 0)
+                && curves[i].points()[0].position.distance(corner.position) < TOLERANCE
+        })?;
+        let ip = (i + n - 1) % n;
+        if ip == i {
+            return None;
+        }
+        let (prev, next) = (&curves[ip], &curves[i]);
+        let p = next.points()[0].position;
+        let t_in = t_at_distance(prev, p, corner.size, true)?;
+        let t_out = t_at_distance(next, p, corner.size, false)?;
+        let (prev_left, _) = prev.split(t_in);
+        let (_, next_right) = next.split(t_out);
+        let (a, b) = (prev.compute(t_in), next.compute(t_out));
+        let treatment = match corner.kind {
+            CornerKind::Chamfer => vec![Bezier::new(vec![a, b])],
+            CornerKind::Round => {
+                /* Handles towards the corner approximate a circular arc for right angles. */
+                const K: f64 = 0.5523;
+                vec![Bezier::new(vec![a, a + K * (p - a), b + K * (p - b), b])]
+            }
+            CornerKind::Inktrap => {
+                /* Cut a notch past the corner, away from the angle between the two curves. */
+                let w = (a - p).unit() + (b - p).unit();
+                let w = if w.norm() < 1e-9 {
+                    let d = (a - p).unit();
+                    Point::from((-d.y, d.x))
+                } else {
+                    w.unit()
+                };
+                let q = p - corner.depth * w;
+                vec![Bezier::new(vec![a, q]), Bezier::new(vec![q, b])]
+            }
+            CornerKind::Custom => {
+                let shape = shape?;
+                let shape_curves = shape.curves();
+                let s = shape_curves.first()?.points().first()?.position;
+                let e = shape_curves.last()?.points().last()?.position;
+                let d = e - s;
+                let len2 = d.dot(d);
+                if len2 <= f64::EPSILON {
+                    return None;
+                }
+                /* The similarity transformation that maps `s` to `a` and `e` to `b`. */
+                let t = b - a;
+                let (rx, ry) = (
+                    (t.x * d.x + t.y * d.y) / len2,
+                    (t.y * d.x - t.x * d.y) / len2,
+                );
+                let map = |z: Point| {
+                    let z = z - s;
+                    a + Point::from((rx * z.x - ry * z.y, rx * z.y + ry * z.x))
+                };
+                shape_curves
+                    .iter()
+                    .map(|c| Bezier::new(c.points().iter().map(|cp| map(cp.position)).collect()))
+                    .collect()
+            }
+        };
+        /* A curve's SMOOTH flag is about its start point: the treatment starts at `a` and
+         * the outgoing curve at `b`, where a round corner meets the curves tangentially. */
+        let smooth = corner.kind == CornerKind::Round;
+        treatment[0].set_property(Bezier::SMOOTH, smooth);
+        next_right.set_property(Bezier::SMOOTH, smooth);
+        corner.position = p;
+        corner.index = ip + 1;
+        corner.curves = treatment.len();
+        for other in others {
+            if other.contour == corner.contour && other.index > ip {
+                other.index += corner.curves;
+            }
+        }
+
+        let mut treatment = Some(treatment);
+        let mut new_curves = vec![];
+        for (idx, curve) in curves.iter().enumerate() {
+            if idx == ip {
+                new_curves.push(prev_left.clone());
+                new_curves.extend(treatment.take().into_iter().flatten());
+            } else if idx == i {
+                new_curves.push(next_right.clone());
+            } else {
+                new_curves.push(curve.duplicate());
+            }
+        }
+        let ret = Self::new_with_curves(new_curves);
+        if closed {
+            ret.close();
+        }
+        Some(ret)
+    }
+
+    /// Index of the first curve of treatment `corner` in this contour, if the contour has room
+    /// for the treatment and the curves around it.
+    pub fn locate_corner(&self, corner: &Corner) -> Option<usize> {
+        let n = self.curves().len();
+        let fits = if self.open.get() {
+            corner.index > 0 && corner.index + corner.curves < n
+        } else {
+            corner.index < n && corner.curves + 2 <= n
+        };
+        fits.then_some(corner.index)
+    }
+
+    /// The on-curve points of treatment `corner` in this contour, from its start to its end.
+    pub fn corner_points(&self, corner: &Corner) -> Vec<Point> {
+        let Some(i) = self.locate_corner(corner) else {
+            return vec![];
+        };
+        let curves = self.curves();
+        let n = curves.len();
+        (0..=corner.curves)
+            .map(|m| curves[(i + m) % n].points()[0].position)
+            .collect()
+    }
+
+    /// Restores the sharp corner of treatment `corner`, and returns it with the position of
+    /// the corner. The corner is where the tangents of the curves around the treatment meet,
+    /// so that it follows edits of those curves. `others` treatments of the same contour that
+    /// come after it are moved along.
+    pub fn without_corner<'a>(
+        &self,
+        corner: &Corner,
+        others: impl IntoIterator<Item = &'a mut Corner>,
+    ) -> Option<(Self, Point)> {
+        let i = self.locate_corner(corner)?;
+        let closed = !self.open.get();
+        let curves = self.curves();
+        let n = curves.len();
+        let ip = (i + n - 1) % n;
+        let inext = (i + corner.curves) % n;
+        let a = curves[ip].points().last()?.position;
+        let b = curves[inext].points().first()?.position;
+        let position = line_intersection(
+            a,
+            curves[ip].end_direction(),
+            b,
+            curves[inext].start_direction(),
+        )
+        .unwrap_or(corner.position);
+        let prev = extended_to(&curves[ip], position, true);
+        let next = extended_to(&curves[inext], position, false);
+        let treatment = (0..corner.curves)
+            .map(|m| (i + m) % n)
+            .collect::<HashSet<usize>>();
+        for other in others {
+            if other.contour == corner.contour {
+                other.index -= treatment.iter().filter(|&&t| t < other.index).count();
+            }
+        }
+        let mut new_curves = vec![];
+        for (idx, curve) in curves.iter().enumerate() {
+            if idx == ip {
+                new_curves.push(prev.clone());
+            } else if idx == inext {
+                new_curves.push(next.clone());
+            } else if !treatment.contains(&idx) {
+                new_curves.push(curve.duplicate());
+            }
+        }
+        let ret = Self::new_with_curves(new_curves);
+        if closed {
+            ret.close();
+        }
+        Some((ret, position))
+    }
+}
+
+#[test]
+fn test_corners() {
+    let square = || {
+        let corners: [(f64, f64); 4] = [(0.0, 0.0), (100.0, 0.0), (100.0, 100.0), (0.0, 100.0)];
+        let contour = Contour::new_with_curves(
+            (0..4)
+                .map(|i| Bezier::new(vec![corners[i].into(), corners[(i + 1) % 4].into()]))
+                .collect(),
+        );
+        contour.close();
+        contour
+    };
+    let start =
+        |contour: &Contour, corner: &Corner| contour.curves()[corner.index].points()[0].position;
+    for (kind, curves) in [
+        (CornerKind::Round, 5),
+        (CornerKind::Chamfer, 5),
+        (CornerKind::Inktrap, 6),
+    ] {
+        let mut corner = Corner::new(kind, 20.0, 10.0, String::new());
+        corner.position = (100.0, 0.0).into();
+        let treated = square().with_corner(&mut corner, None, []).unwrap();
+        assert_eq!(treated.curves().len(), curves);
+        assert!(start(&treated, &corner).distance((80.0, 0.0).into()) < 1e-6);
+        assert_eq!(treated.corner_points(&corner).len(), corner.curves + 1);
+        /* Smoothness is stored on the curve that starts at a point: the treatment and the
+         * outgoing curve start where a round corner meets the sides. */
+        let smooth = |i: usize| treated.curves()[i].property::<bool>(Bezier::SMOOTH);
+        assert_eq!(smooth(corner.index), kind == CornerKind::Round);
+        assert_eq!(
+            smooth(corner.index + corner.curves),
+            kind == CornerKind::Round
+        );
+        let (restored, position) = treated.without_corner(&corner, []).unwrap();
+        assert_eq!(restored.curves().len(), 4);
+        assert!(position.distance((100.0, 0.0).into()) < 1e-6);
+        assert!(!restored.curves()[1].property::<bool>(Bezier::SMOOTH));
+        /* Round trip through the lib. */
+        let mut lib = IndexMap::default();
+        Corner::store(&mut lib, &[corner.clone()]);
+        assert_eq!(Corner::load(&lib), vec![corner]);
+    }
+
+    /* The corner at the start of a closed contour. */
+    let mut corner = Corner::new(CornerKind::Chamfer, 20.0, 0.0, String::new());
+    corner.position = (0.0, 0.0).into();
+    let treated = square().with_corner(&mut corner, None, []).unwrap();
+    assert_eq!(treated.curves().len(), 5);
+    let (restored, position) = treated.without_corner(&corner, []).unwrap();
+    assert_eq!(restored.curves().len(), 4);
+    assert!(position.distance((0.0, 0.0).into()) < 1e-6);
+
+    /* Too big for the corner. */
+    let mut corner = Corner::new(CornerKind::Round, 200.0, 0.0, String::new());
+    corner.position = (100.0, 0.0).into();
+    assert!(square().with_corner(&mut corner, None, []).is_none());
+}
+
+#[test]
+fn test_corners_after_edit() {
+    let square = {
+        let corners: [(f64, f64); 4] = [(0.0, 0.0), (100.0, 0.0), (100.0, 100.0), (0.0, 100.0)];
+        let contour = Contour::new_with_curves(
+            (0..4)
+                .map(|i| Bezier::new(vec![corners[i].into(), corners[(i + 1) % 4].into()]))
+                .collect(),
+        );
+        contour.close();
+        contour
+    };
+    /* Two treatments on the same contour: the second moves the first one along. */
+    let mut first = Corner::new(CornerKind::Round, 20.0, 0.0, String::new());
+    first.position = (100.0, 100.0).into();
+    let treated = square.with_corner(&mut first, None, []).unwrap();
+    let mut second = Corner::new(CornerKind::Chamfer, 20.0, 0.0, String::new());
+    second.position = (100.0, 0.0).into();
+    let treated = treated
+        .with_corner(&mut second, None, [&mut first])
+        .unwrap();
+    let close_to = |points: Vec<Point>, expected: [(f64, f64); 2]| {
+        points.len() == 2
+            && points
+                .iter()
+                .zip(expected)
+                .all(|(p, q)| p.distance(q.into()) < 1e-6)
+    };
+    assert_eq!(treated.curves().len(), 6);
+    assert!(close_to(
+        treated.corner_points(&first),
+        [(100.0, 80.0), (80.0, 100.0)]
+    ));
+
+    /* Lengthen the right side of the glyph: the outgoing curve of the first treatment and the
+     * curve after it now go up to 150 units. */
+    let edited = Contour::new_with_curves(
+        treated
+            .curves()
+            .iter()
+            .map(|c| {
+                Bezier::new(
+                    c.points()
+                        .iter()
+                        .map(|cp| {
+                            if cp.position.distance((80.0, 100.0).into()) < 1e-6 {
+                                Point::from((80.0, 150.0))
+                            } else if cp.position.distance((0.0, 100.0).into()) < 1e-6 {
+                                Point::from((0.0, 150.0))
+                            } else if cp.position.distance((100.0, 80.0).into()) < 1e-6 {
+                                Point::from((100.0, 130.0))
+                            } else {
+                                cp.position
+                            }
+                        })
+                        .collect(),
+                )
+            })
+            .collect(),
+    );
+    edited.close();
+
+    /* Re-applying finds both treatments by index and restores the corners where the edited
+     * curves meet, not where they used to be. */
+    let (restored, position) = edited.without_corner(&second, [&mut first]).unwrap();
+    assert!(position.distance((100.0, 0.0).into()) < 1e-6);
+    let (restored, position) = restored.without_corner(&first, []).unwrap();
+    assert!(position.distance((100.0, 150.0).into()) < 1e-6);
+    assert_eq!(restored.curves().len(), 4);
+    let mut again = Corner::with_parameters_of(&first);
+    again.position = position;
+    let treated = restored.with_corner(&mut again, None, []).unwrap();
+    assert!(close_to(
+        treated.corner_points(&again),
+        [(100.0, 130.0), (80.0, 150.0)]
+    ));
+}
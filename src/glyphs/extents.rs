@@ -0,0 +1,75 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Bounding boxes of glyph outlines.
+
+use super::*;
+
+impl Glyph {
+    /// Lowest and highest y coordinates of the outline, components included, taking curve
+    /// extrema into account. `None` if the glyph has no outline.
+    pub fn vertical_extents(&self) -> Option<(f64, f64)> {
+        self.extents().map(|(min, max)| (min.y, max.y))
+    }
+
+    /// Bounding box of the outline, components included, taking curve extrema into account.
+    /// `None` if the glyph has no outline.
+    pub fn extents(&self) -> Option<(Point, Point)> {
+        self.extents_with(Matrix::identity())
+    }
+
+    fn extents_with(&self, m: Matrix) -> Option<(Point, Point)> {
+        let mut ret: Option<(Point, Point)> = None;
+        let mut add = |p: Point| {
+            let (min, max) = ret.get_or_insert((p, p));
+            min.x = min.x.min(p.x);
+            min.y = min.y.min(p.y);
+            max.x = max.x.max(p.x);
+            max.y = max.y.max(p.y);
+        };
+        for contour in self.contours.iter().map(|c| c.transformed(m)) {
+            for curv in contour.curves().iter() {
+                if curv.points().is_empty() {
+                    continue;
+                }
+                for p in [curv.compute(0.0), curv.compute(1.0)]
+                    .into_iter()
+                    .chain(curv.extrema().into_iter().map(|t| curv.compute(t)))
+                {
+                    add(p);
+                }
+            }
+        }
+        for c in self.components.iter() {
+            let Some(base) = c.base.upgrade() else {
+                continue;
+            };
+            let own = Matrix::new(
+                c.x_scale, c.xy_scale, c.yx_scale, c.y_scale, c.x_offset, c.y_offset,
+            );
+            if let Some((min, max)) = base.borrow().extents_with(Matrix::multiply(&own, &m)) {
+                add(min);
+                add(max);
+            }
+        }
+        ret
+    }
+}
@@ -0,0 +1,453 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Offset curves and stroke expansion of paths.
+
+use super::*;
+use crate::utils::curves::fit_cubic;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "LineCap")]
+#[serde(rename_all = "kebab-case")]
+pub enum LineCap {
+    #[default]
+    Butt,
+    Round,
+    Square,
+}
+
+impl EnumValue<'_> for LineCap {
+    fn kebab_case_variants() -> &'static [&'static str] {
+        &["butt", "round", "square"]
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "LineJoin")]
+#[serde(rename_all = "kebab-case")]
+pub enum LineJoin {
+    #[default]
+    Miter,
+    Round,
+    Bevel,
+}
+
+impl EnumValue<'_> for LineJoin {
+    fn kebab_case_variants() -> &'static [&'static str] {
+        &["miter", "round", "bevel"]
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StrokeOptions {
+    pub width: f64,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    /// Maximum ratio of a miter's length to half the width, after which the join is beveled.
+    pub miter_limit: f64,
+}
+
+impl Default for StrokeOptions {
+    fn default() -> Self {
+        Self {
+            width: 50.0,
+            cap: LineCap::default(),
+            join: LineJoin::default(),
+            miter_limit: 4.0,
+        }
+    }
+}
+
+/// Maximum distance in font units between a computed offset curve and the true offset.
+const TOLERANCE: f64 = 0.25;
+const MAX_DEPTH: usize = 8;
+
+/// A curve as its control points: two for a line, three for a quadratic and four for a
+/// cubic curve.
+pub type Segment = Vec<Point>;
+
+fn right_normal(direction: Point) -> Point {
+    Point::from((direction.y, -direction.x))
+}
+
+fn cross(a: Point, b: Point) -> f64 {
+    a.x * b.y - a.y * b.x
+}
+
+/// Degree elevation of quadratic segments, so that only lines and cubics remain.
+fn elevated(segment: &[Point]) -> Segment {
+    match segment {
+        [p0, p1, p2] => vec![
+            *p0,
+            *p0 + (2.0 / 3.0) * (*p1 - *p0),
+            *p2 + (2.0 / 3.0) * (*p1 - *p2),
+            *p2,
+        ],
+        other => other.to_vec(),
+    }
+}
+
+fn cubic_point(c: &[Point], t: f64) -> Point {
+    let mt = 1.0 - t;
+    (mt * mt * mt) * c[0]
+        + (3.0 * mt * mt * t) * c[1]
+        + (3.0 * mt * t * t) * c[2]
+        + (t * t * t) * c[3]
+}
+
+fn start_direction(segment: &[Point]) -> Point {
+    segment[1..]
+        .iter()
+        .map(|p| *p - segment[0])
+        .find(|d| d.norm() > f64::EPSILON)
+        .map(|d| d.unit())
+        .unwrap_or_default()
+}
+
+fn end_direction(segment: &[Point]) -> Point {
+    let end = segment[segment.len() - 1];
+    segment[..segment.len() - 1]
+        .iter()
+        .rev()
+        .map(|p| end - *p)
+        .find(|d| d.norm() > f64::EPSILON)
+        .map(|d| d.unit())
+        .unwrap_or_default()
+}
+
+/// Unit tangent of cubic `c` at `t`, falling back to the chord around `t` at cusps.
+fn cubic_tangent(c: &[Point], t: f64) -> Point {
+    if t <= 0.0 {
+        return start_direction(c);
+    } else if t >= 1.0 {
+        return end_direction(c);
+    }
+    let mt = 1.0 - t;
+    let d = (3.0 * mt * mt) * (c[1] - c[0])
+        + (6.0 * mt * t) * (c[2] - c[1])
+        + (3.0 * t * t) * (c[3] - c[2]);
+    if d.norm() > f64::EPSILON {
+        return d.unit();
+    }
+    let chord = cubic_point(c, (t + 1e-3).min(1.0)) - cubic_point(c, (t - 1e-3).max(0.0));
+    if chord.norm() > f64::EPSILON {
+        chord.unit()
+    } else {
+        Point::default()
+    }
+}
+
+fn split_cubic(c: &[Point], t: f64) -> (Segment, Segment) {
+    let mut points = c.to_vec();
+    let mut left = vec![points[0]];
+    let mut right = vec![points[points.len() - 1]];
+    while points.len() > 1 {
+        points = points
+            .windows(2)
+            .map(|w| w[0] + t * (w[1] - w[0]))
+            .collect();
+        left.push(points[0]);
+        right.push(points[points.len() - 1]);
+    }
+    right.reverse();
+    (left, right)
+}
+
+fn offset_cubic(c: &[Point], distance: f64, depth: usize, ret: &mut Vec<Segment>) {
+    const STEPS: usize = 8;
+    let offset_at = |t: f64| cubic_point(c, t) + distance * right_normal(cubic_tangent(c, t));
+    let samples = (0..=STEPS)
+        .map(|i| offset_at(i as f64 / STEPS as f64))
+        .collect::<Vec<Point>>();
+    let fit = fit_cubic(&samples, start_direction(c), end_direction(c));
+    let error = (0..STEPS)
+        .map(|i| {
+            let target = offset_at((i as f64 + 0.5) / STEPS as f64);
+            (0..=64)
+                .map(|j| cubic_point(&fit, j as f64 / 64.0).distance(target))
+                .fold(f64::INFINITY, f64::min)
+        })
+        .fold(0.0, f64::max);
+    if error <= TOLERANCE || depth >= MAX_DEPTH {
+        ret.push(fit.to_vec());
+    } else {
+        let (left, right) = split_cubic(c, 0.5);
+        offset_cubic(&left, distance, depth + 1, ret);
+        offset_cubic(&right, distance, depth + 1, ret);
+    }
+}
+
+/// Offsets `segment` by `distance` to its right.
+fn offset_segment(segment: &[Point], distance: f64, ret: &mut Vec<Segment>) {
+    if segment.len() == 2 {
+        let normal = distance * right_normal(start_direction(segment));
+        ret.push(vec![segment[0] + normal, segment[1] + normal]);
+    } else {
+        offset_cubic(segment, distance, 0, ret);
+    }
+}
+
+/// Cubic approximation of a circular arc around `center`.
+fn arc(center: Point, radius: f64, start_angle: f64, sweep: f64, ret: &mut Vec<Segment>) {
+    let parts = (sweep.abs() / std::f64::consts::FRAC_PI_2).ceil().max(1.0) as usize;
+    let theta = sweep / parts as f64;
+    let kappa = 4.0 / 3.0 * (theta / 4.0).tan() * radius;
+    for i in 0..parts {
+        let (a0, a1) = (
+            start_angle + i as f64 * theta,
+            start_angle + (i + 1) as f64 * theta,
+        );
+        let p0 = center + radius * Point::from((a0.cos(), a0.sin()));
+        let p3 = center + radius * Point::from((a1.cos(), a1.sin()));
+        ret.push(vec![
+            p0,
+            p0 + kappa * Point::from((-a0.sin(), a0.cos())),
+            p3 - kappa * Point::from((-a1.sin(), a1.cos())),
+            p3,
+        ]);
+    }
+}
+
+/// Connects the right offsets of two segments that meet at `vertex`.
+fn join(
+    vertex: Point,
+    incoming: Point,
+    outgoing: Point,
+    distance: f64,
+    options: &StrokeOptions,
+    ret: &mut Vec<Segment>,
+) {
+    let a = vertex + distance * right_normal(incoming);
+    let b = vertex + distance * right_normal(outgoing);
+    if a.distance(b) <= f64::EPSILON * distance.max(1.0) {
+        return;
+    }
+    let turn = cross(incoming, outgoing);
+    if turn < 0.0 {
+        /* Inner side of the turn: the offsets overlap, so pass through the vertex. */
+        ret.push(vec![a, vertex]);
+        ret.push(vec![vertex, b]);
+        return;
+    }
+    match options.join {
+        LineJoin::Round => {
+            let (u, v) = (a - vertex, b - vertex);
+            arc(
+                vertex,
+                distance,
+                u.y.atan2(u.x),
+                cross(u, v).atan2(u.dot(v)),
+                ret,
+            );
+        }
+        LineJoin::Miter if turn.abs() > f64::EPSILON => {
+            let s = cross(b - a, outgoing) / turn;
+            let miter = a + s * incoming;
+            if miter.distance(vertex) <= options.miter_limit * distance {
+                ret.push(vec![a, miter]);
+                ret.push(vec![miter, b]);
+            } else {
+                ret.push(vec![a, b]);
+            }
+        }
+        LineJoin::Miter | LineJoin::Bevel => ret.push(vec![a, b]),
+    }
+}
+
+/// Connects the right offset to the left offset at the end of a path that ends at `vertex`
+/// in `direction`.
+fn cap(vertex: Point, direction: Point, distance: f64, cap: LineCap, ret: &mut Vec<Segment>) {
+    let normal = distance * right_normal(direction);
+    let (a, b) = (vertex + normal, vertex - normal);
+    match cap {
+        LineCap::Butt => ret.push(vec![a, b]),
+        LineCap::Square => {
+            let extension = distance * direction;
+            ret.push(vec![a, a + extension]);
+            ret.push(vec![a + extension, b + extension]);
+            ret.push(vec![b + extension, b]);
+        }
+        LineCap::Round => arc(
+            vertex,
+            distance,
+            normal.y.atan2(normal.x),
+            std::f64::consts::PI,
+            ret,
+        ),
+    }
+}
+
+fn offset_path(
+    segments: &[Segment],
+    closed: bool,
+    distance: f64,
+    options: &StrokeOptions,
+) -> Vec<Segment> {
+    let mut ret = vec![];
+    for (i, segment) in segments.iter().enumerate() {
+        offset_segment(segment, distance, &mut ret);
+        if i + 1 < segments.len() || closed {
+            let next = &segments[(i + 1) % segments.len()];
+            join(
+                segment[segment.len() - 1],
+                end_direction(segment),
+                start_direction(next),
+                distance,
+                options,
+                &mut ret,
+            );
+        }
+    }
+    ret
+}
+
+/// Outlines of the stroke of a path made of `segments`, as closed paths. A closed path has two
+/// outlines, an open path has one.
+pub fn stroke_outline(
+    segments: &[Segment],
+    closed: bool,
+    options: &StrokeOptions,
+) -> Vec<Vec<Segment>> {
+    let distance = options.width / 2.0;
+    let segments = segments
+        .iter()
+        .filter(|s| start_direction(s).norm() > f64::EPSILON)
+        .map(|s| elevated(s))
+        .collect::<Vec<Segment>>();
+    if segments.is_empty() || distance <= 0.0 {
+        return vec![];
+    }
+    let reversed = segments
+        .iter()
+        .rev()
+        .map(|s| s.iter().rev().copied().collect())
+        .collect::<Vec<Segment>>();
+    let mut right = offset_path(&segments, closed, distance, options);
+    let mut left = offset_path(&reversed, closed, distance, options);
+    if closed {
+        return vec![right, left];
+    }
+    let (first, last) = (&segments[0], &segments[segments.len() - 1]);
+    cap(
+        last[last.len() - 1],
+        end_direction(last),
+        distance,
+        options.cap,
+        &mut right,
+    );
+    right.append(&mut left);
+    cap(
+        first[0],
+        -1.0 * start_direction(first),
+        distance,
+        options.cap,
+        &mut right,
+    );
+    vec![right]
+}
+
+impl Contour {
+    /// This contour's curves as segments.
+    pub fn segments(&self) -> Vec<Segment> {
+        self.curves()
+            .iter()
+            .map(|c| c.points().iter().map(|cp| cp.position).collect())
+            .collect()
+    }
+
+    /// The outline of this contour when drawn as a stroke, as new closed contours.
+    pub fn stroked(&self, options: &StrokeOptions) -> Vec<Contour> {
+        stroke_outline(
+            &self.segments(),
+            !self.property::<bool>(Contour::OPEN),
+            options,
+        )
+        .into_iter()
+        .map(|path| {
+            let ret = Contour::new_with_curves(path.into_iter().map(Bezier::new).collect());
+            ret.close();
+            ret
+        })
+        .collect()
+    }
+}
+
+#[test]
+fn test_stroke_outline() {
+    fn area(path: &[Segment]) -> f64 {
+        let mut points = vec![];
+        for s in path {
+            let s = elevated(s);
+            if s.len() == 2 {
+                points.push(s[0]);
+            } else {
+                points.extend((0..16).map(|i| cubic_point(&s, i as f64 / 16.0)));
+            }
+        }
+        let n = points.len();
+        (0..n)
+            .map(|i| cross(points[i], points[(i + 1) % n]))
+            .sum::<f64>()
+            / 2.0
+    }
+
+    let line: Vec<Segment> = vec![vec![(0.0, 0.0).into(), (100.0, 0.0).into()]];
+    for (cap, expected) in [
+        (LineCap::Butt, 2000.0),
+        (LineCap::Square, 2400.0),
+        (LineCap::Round, 2000.0 + std::f64::consts::PI * 100.0),
+    ] {
+        let options = StrokeOptions {
+            width: 20.0,
+            cap,
+            ..StrokeOptions::default()
+        };
+        let outline = stroke_outline(&line, false, &options);
+        assert_eq!(outline.len(), 1);
+        let path = &outline[0];
+        for (i, s) in path.iter().enumerate() {
+            let next = &path[(i + 1) % path.len()];
+            assert!(s[s.len() - 1].distance(next[0]) < 1e-6);
+        }
+        assert!((area(path) - expected).abs() < 1.0);
+    }
+
+    /* A circle gives an outer and an inner contour in opposite directions. */
+    let k = 0.552_284_75 * 100.0;
+    let circle: Vec<Segment> = [(1.0, 0.0), (0.0, 1.0), (-1.0, 0.0), (0.0, -1.0)]
+        .iter()
+        .map(|&(x, y): &(f64, f64)| {
+            let (start, dir) = (Point::from((100.0 * x, 100.0 * y)), Point::from((-y, x)));
+            let end = Point::from((-100.0 * y, 100.0 * x));
+            vec![start, start + k * dir, end + k * Point::from((x, y)), end]
+        })
+        .collect();
+    let options = StrokeOptions {
+        width: 20.0,
+        join: LineJoin::Round,
+        ..StrokeOptions::default()
+    };
+    let outline = stroke_outline(&circle, true, &options);
+    assert_eq!(outline.len(), 2);
+    assert!((area(&outline[0]) - std::f64::consts::PI * 110.0 * 110.0).abs() < 100.0);
+    assert!((area(&outline[1]) + std::f64::consts::PI * 90.0 * 90.0).abs() < 100.0);
+}
@@ -0,0 +1,62 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Outlines of glyphs as plain values, and undoable replacement of them.
+
+use super::*;
+
+/// Contours, components and anchors of a glyph.
+pub type Outline = (Vec<Contour>, Vec<Component>, Vec<Anchor>);
+
+impl Glyph {
+    /// Undoable replacement of the outline of each glyph in `swaps` with the paired outline.
+    /// The returned action has to be redone once to take effect. `property` tells actions of
+    /// different operations apart.
+    pub fn swap_outlines(
+        swaps: Vec<(Rc<RefCell<Glyph>>, Outline)>,
+        property: &'static str,
+        on_change: Rc<dyn Fn(&Rc<RefCell<Glyph>>)>,
+    ) -> crate::app::Action {
+        let swaps = Rc::new(RefCell::new(swaps));
+        let cl = Box::new(move || {
+            for (glyph, (contours, components, anchors)) in swaps.borrow_mut().iter_mut() {
+                {
+                    let mut glyph = glyph.borrow_mut();
+                    std::mem::swap(&mut glyph.contours, contours);
+                    std::mem::swap(&mut glyph.components, components);
+                    std::mem::swap(&mut *glyph.metadata.anchors.borrow_mut(), anchors);
+                    glyph.metadata.set_property(GlyphMetadata::MODIFIED, true);
+                }
+                on_change(glyph);
+            }
+        });
+        crate::app::Action {
+            stamp: crate::app::EventStamp {
+                t: std::any::TypeId::of::<Self>(),
+                property,
+                id: Box::new([]),
+            },
+            compress: false,
+            redo: cl.clone(),
+            undo: cl,
+        }
+    }
+}
@@ -0,0 +1,458 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Font-wide search and replace of outline parts.
+//!
+//! Components are found by the name of their base glyph and anchors by their name. Contours
+//! are compared by shape: the points of both contours are taken relative to the bottom left
+//! corner of their control box, and a contour matches if for some start point and direction
+//! every point lies within a tolerance of the corresponding point of the query. A tolerance of
+//! zero finds exact copies anywhere in the font.
+
+use super::outline::Outline;
+use super::transform::control_box;
+use super::{Component, Contour, Glyph};
+use crate::prelude::*;
+use gtk::cairo::Matrix;
+
+/// The points of a contour relative to the bottom left corner of its control box.
+#[derive(Debug, Clone)]
+pub struct ContourShape {
+    pub contour: Contour,
+    min: Point,
+    curves: Vec<Vec<Point>>,
+    open: bool,
+}
+
+impl ContourShape {
+    pub fn new(contour: &Contour) -> Self {
+        let min = control_box(std::slice::from_ref(contour))
+            .map(|(min, _)| min)
+            .unwrap_or_default();
+        let curves = contour
+            .curves()
+            .iter()
+            .map(|c| c.points().iter().map(|cp| cp.position - min).collect())
+            .collect();
+        Self {
+            contour: contour.clone(),
+            min,
+            curves,
+            open: contour.property::<bool>(Contour::OPEN),
+        }
+    }
+
+    /// Whether `other` has the same shape within `tolerance` units. Returns whether `other`
+    /// runs in the opposite direction on a match. Only closed contours can start anywhere.
+    pub fn matches(&self, other: &Self, tolerance: f64) -> Option<bool> {
+        if self.open != other.open || self.curves.len() != other.curves.len() {
+            return None;
+        }
+        let close = |a: &[Point], b: &[Point]| {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b.iter())
+                    .all(|(p, q)| p.distance(*q) <= tolerance + 1e-6)
+        };
+        let reversed = other
+            .curves
+            .iter()
+            .rev()
+            .map(|c| c.iter().rev().copied().collect())
+            .collect::<Vec<Vec<Point>>>();
+        let n = self.curves.len();
+        let starts = if self.open { 1 } else { n.max(1) };
+        for (is_reversed, curves) in [(false, &other.curves), (true, &reversed)] {
+            for start in 0..starts {
+                if (0..n).all(|i| close(&self.curves[i], &curves[(i + start) % n])) {
+                    return Some(is_reversed);
+                }
+            }
+        }
+        None
+    }
+
+    /// A copy of this shape moved to the position of `at`, reversed if `at` runs in the
+    /// opposite direction of the query it matched.
+    pub fn placed(&self, at: &Self, reversed: bool) -> Contour {
+        let delta = at.min - self.min;
+        let ret = self
+            .contour
+            .transformed(Matrix::new(1.0, 0.0, 0.0, 1.0, delta.x, delta.y));
+        if reversed {
+            ret.reverse_direction();
+        }
+        ret
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SearchTarget {
+    /// Components with this base glyph.
+    Component(String),
+    /// Contours with this shape.
+    Contour { shape: ContourShape, tolerance: f64 },
+    /// Anchors with this name.
+    Anchor(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Match {
+    pub glyph_name: String,
+    pub glyph: Rc<RefCell<Glyph>>,
+    /// Index of the component, contour or anchor in the glyph.
+    pub index: usize,
+    /// Whether a matched contour runs in the opposite direction of the query.
+    pub reversed: bool,
+}
+
+impl Match {
+    pub fn description(&self, target: &SearchTarget) -> String {
+        let glyph = self.glyph.borrow();
+        match target {
+            SearchTarget::Component(_) => glyph
+                .components
+                .get(self.index)
+                .map(|c| {
+                    format!(
+                        "Component {} at ({}, {})",
+                        self.index, c.x_offset, c.y_offset
+                    )
+                })
+                .unwrap_or_default(),
+            SearchTarget::Contour { .. } => format!(
+                "Contour {}{}",
+                self.index,
+                if self.reversed { ", reversed" } else { "" }
+            ),
+            SearchTarget::Anchor(_) => glyph
+                .metadata
+                .anchors
+                .borrow()
+                .get(self.index)
+                .map(|a| format!("Anchor {} at ({}, {})", self.index, a.x, a.y))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Every occurrence of `target` in `glyphs`, in glyph order.
+pub fn search(glyphs: &IndexMap<String, Rc<RefCell<Glyph>>>, target: &SearchTarget) -> Vec<Match> {
+    let mut ret = vec![];
+    for (name, glyph) in glyphs {
+        let mut push = |index, reversed| {
+            ret.push(Match {
+                glyph_name: name.clone(),
+                glyph: glyph.clone(),
+                index,
+                reversed,
+            })
+        };
+        let g = glyph.borrow();
+        match target {
+            SearchTarget::Component(base) => {
+                for (i, c) in g.components.iter().enumerate() {
+                    if c.base_name == *base {
+                        push(i, false);
+                    }
+                }
+            }
+            SearchTarget::Contour { shape, tolerance } => {
+                for (i, c) in g.contours.iter().enumerate() {
+                    if let Some(reversed) = shape.matches(&ContourShape::new(c), *tolerance) {
+                        push(i, reversed);
+                    }
+                }
+            }
+            SearchTarget::Anchor(anchor) => {
+                for (i, a) in g.metadata.anchors.borrow().iter().enumerate() {
+                    if a.name == *anchor {
+                        push(i, false);
+                    }
+                }
+            }
+        }
+    }
+    ret
+}
+
+#[derive(Debug, Clone)]
+pub enum Replacement {
+    Remove,
+    /// New base glyph of components or new name of anchors.
+    Rename(String),
+    /// New contour, placed at the control box of each matched contour.
+    Shape(ContourShape),
+}
+
+/// The outline of `glyph` with the parts at `matches` replaced.
+fn replaced_outline(
+    glyph: &Glyph,
+    matches: &[&Match],
+    target: &SearchTarget,
+    replacement: &Replacement,
+    glyphs: &IndexMap<String, Rc<RefCell<Glyph>>>,
+) -> Result<Outline, Box<dyn std::error::Error>> {
+    let find = |i: usize| matches.iter().find(|m| m.index == i);
+    let mut contours = vec![];
+    for (i, c) in glyph.contours.iter().enumerate() {
+        match (find(i), target, replacement) {
+            (Some(_), SearchTarget::Contour { .. }, Replacement::Remove) => {}
+            (Some(m), SearchTarget::Contour { .. }, Replacement::Shape(shape)) => {
+                contours.push(shape.placed(&ContourShape::new(c), m.reversed));
+            }
+            _ => contours.push(c.duplicate()),
+        }
+    }
+    let mut components = vec![];
+    for (i, c) in glyph.components.iter().enumerate() {
+        match (find(i), target, replacement) {
+            (Some(_), SearchTarget::Component(_), Replacement::Remove) => {}
+            (Some(_), SearchTarget::Component(_), Replacement::Rename(base)) => {
+                let Some(base_glyph) = glyphs.get(base) else {
+                    return Err(format!("Glyph `{base}` does not exist.").into());
+                };
+                components.push(Component {
+                    base_name: base.clone(),
+                    base: Rc::downgrade(base_glyph),
+                    ..c.clone()
+                });
+            }
+            _ => components.push(c.clone()),
+        }
+    }
+    let mut anchors = vec![];
+    for (i, a) in glyph.metadata.anchors.borrow().iter().enumerate() {
+        match (find(i), target, replacement) {
+            (Some(_), SearchTarget::Anchor(_), Replacement::Remove) => {}
+            (Some(_), SearchTarget::Anchor(_), Replacement::Rename(name)) => {
+                let mut a = a.clone();
+                a.name = name.clone();
+                anchors.push(a);
+            }
+            _ => anchors.push(a.clone()),
+        }
+    }
+    Ok((contours, components, anchors))
+}
+
+/// The new outline of every glyph with a match in `matches`, to be applied with
+/// [`Glyph::swap_outlines`].
+pub fn replace(
+    matches: &[Match],
+    target: &SearchTarget,
+    replacement: &Replacement,
+    glyphs: &IndexMap<String, Rc<RefCell<Glyph>>>,
+) -> Result<Vec<(Rc<RefCell<Glyph>>, Outline)>, Box<dyn std::error::Error>> {
+    match (target, replacement) {
+        (_, Replacement::Remove)
+        | (SearchTarget::Component(_) | SearchTarget::Anchor(_), Replacement::Rename(_))
+        | (SearchTarget::Contour { .. }, Replacement::Shape(_)) => {}
+        (SearchTarget::Contour { .. }, Replacement::Rename(_)) => {
+            return Err("Contours can only be replaced by another contour.".into());
+        }
+        (_, Replacement::Shape(_)) => {
+            return Err("Only contours can be replaced by a contour.".into());
+        }
+    }
+    let mut by_glyph: IndexMap<&str, Vec<&Match>> = IndexMap::new();
+    for m in matches {
+        by_glyph.entry(m.glyph_name.as_str()).or_default().push(m);
+    }
+    let swaps = by_glyph
+        .into_iter()
+        .map(|(name, matches)| {
+            let glyph = matches[0].glyph.clone();
+            let outline = replaced_outline(&glyph.borrow(), &matches, target, replacement, glyphs)?;
+            Ok((name, glyph, outline))
+        })
+        .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    if let (SearchTarget::Component(_), Replacement::Rename(_)) = (target, replacement) {
+        let new_outlines = swaps
+            .iter()
+            .map(|(name, _, outline)| (*name, outline))
+            .collect::<IndexMap<&str, &Outline>>();
+        for name in new_outlines.keys() {
+            if has_component_cycle(name, &new_outlines, glyphs) {
+                return Err(
+                    format!("The replacement would make `{name}` a component of itself.").into(),
+                );
+            }
+        }
+    }
+    Ok(swaps
+        .into_iter()
+        .map(|(_, glyph, outline)| (glyph, outline))
+        .collect())
+}
+
+/// Whether glyph `name` is a component of itself, directly or through other components, once
+/// the glyphs in `new_outlines` have their new outline.
+fn has_component_cycle(
+    name: &str,
+    new_outlines: &IndexMap<&str, &Outline>,
+    glyphs: &IndexMap<String, Rc<RefCell<Glyph>>>,
+) -> bool {
+    let bases = |name: &str| -> Vec<String> {
+        if let Some((_, components, _)) = new_outlines.get(name) {
+            components.iter().map(|c| c.base_name.clone()).collect()
+        } else if let Some(glyph) = glyphs.get(name) {
+            glyph
+                .borrow()
+                .components
+                .iter()
+                .map(|c| c.base_name.clone())
+                .collect()
+        } else {
+            vec![]
+        }
+    };
+    let mut seen = HashSet::new();
+    let mut stack = bases(name);
+    while let Some(base) = stack.pop() {
+        if base == name {
+            return true;
+        }
+        if seen.insert(base.clone()) {
+            stack.extend(bases(&base));
+        }
+    }
+    false
+}
+
+#[test]
+fn test_outline_search() {
+    use super::{Anchor, GlyphKind};
+    use crate::utils::curves::Bezier;
+
+    let square = |x: f64, y: f64, size: f64| {
+        let corners = [(x, y), (x, y + size), (x + size, y + size), (x + size, y)];
+        let contour = Contour::new_with_curves(
+            (0..4)
+                .map(|i| Bezier::new(vec![corners[i].into(), corners[(i + 1) % 4].into()]))
+                .collect(),
+        );
+        contour.close();
+        contour
+    };
+    let glyph = |name: &str, contours: Vec<Contour>, components: &[&str]| {
+        let mut glyph: Glyph = GlyphKind::Component(name.to_string()).into();
+        glyph.contours = contours;
+        glyph.components = components
+            .iter()
+            .map(|base| Component {
+                base_name: base.to_string(),
+                base: std::rc::Weak::new(),
+                x_offset: 0.0,
+                y_offset: 0.0,
+                x_scale: 1.0,
+                xy_scale: 0.0,
+                yx_scale: 0.0,
+                y_scale: 1.0,
+            })
+            .collect();
+        *glyph.metadata.anchors.borrow_mut() = vec![Anchor {
+            name: "top".to_string(),
+            x: 0.0,
+            y: 700.0,
+        }];
+        (name.to_string(), Rc::new(RefCell::new(glyph)))
+    };
+    let glyphs: IndexMap<String, Rc<RefCell<Glyph>>> = [
+        glyph("dot", vec![square(0.0, 0.0, 100.0)], &[]),
+        glyph(
+            "colon",
+            vec![square(300.0, 0.0, 100.0), square(0.0, 400.0, 101.0)],
+            &[],
+        ),
+        glyph("reversed", vec![square(0.0, 0.0, 100.0).reversed()], &[]),
+        glyph("i", vec![], &["dotlessi", "dot"]),
+        glyph("dotlessi", vec![], &[]),
+        glyph("idieresis", vec![], &["i"]),
+    ]
+    .into_iter()
+    .collect();
+
+    let shape = ContourShape::new(&glyphs["dot"].borrow().contours[0]);
+    let exact = SearchTarget::Contour {
+        shape: shape.clone(),
+        tolerance: 0.0,
+    };
+    let found = search(&glyphs, &exact)
+        .into_iter()
+        .map(|m| (m.glyph_name, m.index, m.reversed))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        vec![
+            ("dot".to_string(), 0, false),
+            ("colon".to_string(), 0, false),
+            ("reversed".to_string(), 0, true),
+        ]
+    );
+    let similar = SearchTarget::Contour {
+        shape,
+        tolerance: 1.5,
+    };
+    assert_eq!(search(&glyphs, &similar).len(), 4);
+
+    let components = SearchTarget::Component("dot".to_string());
+    let matches = search(&glyphs, &components);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].index, 1);
+    let swaps = replace(
+        &matches,
+        &components,
+        &Replacement::Rename("dotlessi".to_string()),
+        &glyphs,
+    )
+    .unwrap();
+    assert_eq!(swaps[0].1 .1[1].base_name, "dotlessi");
+    assert!(swaps[0].1 .1[1].base.upgrade().is_some());
+    /* Directly, indirectly through `idieresis`, or not at all. */
+    for name in ["i", "idieresis", "missing"] {
+        assert!(replace(
+            &matches,
+            &components,
+            &Replacement::Rename(name.to_string()),
+            &glyphs
+        )
+        .is_err());
+    }
+
+    let anchors = SearchTarget::Anchor("top".to_string());
+    let matches = search(&glyphs, &anchors);
+    assert_eq!(matches.len(), glyphs.len());
+    let swaps = replace(&matches, &anchors, &Replacement::Remove, &glyphs).unwrap();
+    assert!(swaps.iter().all(|(_, (_, _, anchors))| anchors.is_empty()));
+
+    /* The replacement is moved to where each match was. */
+    let matches = search(&glyphs, &exact);
+    let replacement = Replacement::Shape(ContourShape::new(&square(0.0, 0.0, 50.0)));
+    let swaps = replace(&matches, &exact, &replacement, &glyphs).unwrap();
+    let (_, (contours, _, _)) = &swaps[1];
+    assert_eq!(contours.len(), 2);
+    assert_eq!(
+        control_box(&contours[..1]),
+        Some(((300.0, 0.0).into(), (350.0, 50.0).into()))
+    );
+    assert!(replace(&matches, &exact, &Replacement::Rename("x".into()), &glyphs).is_err());
+}
@@ -0,0 +1,324 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Search queries over glyphs.
+//!
+//! A query is a list of space separated terms that all have to match. A term is either a bare
+//! word, which matches glyph names that contain it or the glyph's character, or a `key:value`
+//! pair. Values with spaces can be quoted, and a `-` prefix negates a term.
+//!
+//! | key           | value                                 | example               |
+//! |---------------|---------------------------------------|-----------------------|
+//! | `name`        | regular expression on the glyph name  | `name:^[a-z]\.sc$`    |
+//! | `u`           | codepoint or range                    | `u:U+0300:U+036F`     |
+//! | `uname`       | words of the Unicode name             | `uname:"small letter"`|
+//! | `block`       | words of the Unicode block name       | `block:cyrillic`      |
+//! | `mark`        | mark colour, `any` or `none`          | `mark:#ff0000`        |
+//! | `is`          | `empty`, `modified` or `component`    | `-is:empty`           |
+//! | `has`         | `components` or `contours`            | `has:components`      |
+//! | `contours`    | number or range of contours           | `contours:>2`         |
+//! | `width`       | number or range of advance width      | `width:500..600`      |
+//!
+//! Numeric ranges are written as `N`, `<N`, `<=N`, `>N`, `>=N` or `N..M` (inclusive).
+
+use super::{Glyph, GlyphKind, GlyphMetadata};
+use crate::unicode::blocks::{CharBlock, UNICODE_BLOCKS};
+use crate::unicode::names::CharName;
+use crate::utils::colors::Color;
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumRange {
+    Exactly(f64),
+    Less(f64),
+    LessOrEqual(f64),
+    Greater(f64),
+    GreaterOrEqual(f64),
+    Between(f64, f64),
+}
+
+impl NumRange {
+    pub fn parse(s: &str) -> Option<Self> {
+        let num = |s: &str| s.trim().parse::<f64>().ok();
+        if let Some(rest) = s.strip_prefix("<=") {
+            Some(Self::LessOrEqual(num(rest)?))
+        } else if let Some(rest) = s.strip_prefix(">=") {
+            Some(Self::GreaterOrEqual(num(rest)?))
+        } else if let Some(rest) = s.strip_prefix('<') {
+            Some(Self::Less(num(rest)?))
+        } else if let Some(rest) = s.strip_prefix('>') {
+            Some(Self::Greater(num(rest)?))
+        } else if let Some((start, end)) = s.split_once("..") {
+            Some(Self::Between(num(start)?, num(end)?))
+        } else {
+            Some(Self::Exactly(num(s)?))
+        }
+    }
+
+    pub fn contains(&self, v: f64) -> bool {
+        match *self {
+            Self::Exactly(n) => (v - n).abs() < 1e-6,
+            Self::Less(n) => v < n,
+            Self::LessOrEqual(n) => v <= n,
+            Self::Greater(n) => v > n,
+            Self::GreaterOrEqual(n) => v >= n,
+            Self::Between(a, b) => a.min(b) <= v && v <= a.max(b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MarkFilter {
+    Any,
+    None,
+    Color(Color),
+}
+
+#[derive(Debug, Clone)]
+pub enum Term {
+    /// Bare word; matches the glyph name or the glyph's character.
+    Text(String),
+    Name(Regex),
+    Codepoints(char, char),
+    UnicodeName(String),
+    Block(String),
+    Mark(MarkFilter),
+    Empty,
+    Modified,
+    Component,
+    HasComponents,
+    HasContours,
+    Contours(NumRange),
+    Width(NumRange),
+}
+
+impl Term {
+    fn parse(token: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let Some((key, value)) = token.split_once(':') else {
+            return Ok(Self::Text(token.to_string()));
+        };
+        let invalid = || format!("Invalid value {:?} for `{}`.", value, key);
+        Ok(match key {
+            "name" => Self::Name(Regex::new(value)?),
+            "u" | "unicode" => {
+                let chars = GlyphKind::from_range(value)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|k| match k {
+                        GlyphKind::Char(c) => Some(c),
+                        GlyphKind::Component(_) => None,
+                    })
+                    .collect::<Vec<char>>();
+                match (chars.first(), chars.last()) {
+                    (Some(&start), Some(&end)) => Self::Codepoints(start, end),
+                    _ => return Err(invalid().into()),
+                }
+            }
+            "uname" => Self::UnicodeName(value.to_ascii_uppercase()),
+            "block" => Self::Block(value.to_ascii_lowercase()),
+            "mark" => Self::Mark(match value {
+                "any" => MarkFilter::Any,
+                "none" => MarkFilter::None,
+                _ => MarkFilter::Color(
+                    Color::try_from_hex(value)
+                        .or_else(|| Color::try_parse(value))
+                        .ok_or_else(invalid)?,
+                ),
+            }),
+            "is" => match value {
+                "empty" => Self::Empty,
+                "modified" => Self::Modified,
+                "component" => Self::Component,
+                _ => return Err(invalid().into()),
+            },
+            "has" => match value {
+                "components" => Self::HasComponents,
+                "contours" => Self::HasContours,
+                _ => return Err(invalid().into()),
+            },
+            "contours" => Self::Contours(NumRange::parse(value).ok_or_else(invalid)?),
+            "width" => Self::Width(NumRange::parse(value).ok_or_else(invalid)?),
+            _ => return Err(format!("Unknown search key `{}`.", key).into()),
+        })
+    }
+
+    fn matches(&self, glyph: &Glyph) -> bool {
+        let chars = || {
+            let kinds = glyph.kinds();
+            std::iter::once(kinds.0.clone())
+                .chain(kinds.1.iter().cloned())
+                .filter_map(|k| match k {
+                    GlyphKind::Char(c) => Some(c),
+                    GlyphKind::Component(_) => None,
+                })
+                .collect::<Vec<char>>()
+        };
+        match self {
+            Self::Text(t) => {
+                glyph.name().contains(t.as_str()) || {
+                    let mut iter = t.chars();
+                    matches!((iter.next(), iter.next()), (Some(c), None) if chars().contains(&c))
+                }
+            }
+            Self::Name(re) => re.is_match(glyph.name().as_str()),
+            Self::Codepoints(start, end) => chars().iter().any(|c| (*start..=*end).contains(c)),
+            Self::UnicodeName(words) => chars().into_iter().any(|c| {
+                c.char_name()
+                    .map(|n| n.to_string().contains(words.as_str()))
+                    .unwrap_or(false)
+            }),
+            Self::Block(words) => chars().into_iter().any(|c| {
+                c.char_block()
+                    .map(|idx| UNICODE_BLOCKS[idx].1.to_ascii_lowercase().contains(words))
+                    .unwrap_or(false)
+            }),
+            Self::Mark(filter) => {
+                let color = glyph.metadata.mark_color.get();
+                match filter {
+                    MarkFilter::Any => color.is_visible(),
+                    MarkFilter::None => !color.is_visible(),
+                    MarkFilter::Color(c) => {
+                        color.is_visible()
+                            && (color.red(), color.green(), color.blue())
+                                == (c.red(), c.green(), c.blue())
+                    }
+                }
+            }
+            Self::Empty => glyph.is_empty(),
+            Self::Modified => glyph.metadata.property::<bool>(GlyphMetadata::MODIFIED),
+            Self::Component => matches!(glyph.kinds().0, GlyphKind::Component(_)),
+            Self::HasComponents => !glyph.components.is_empty(),
+            Self::HasContours => !glyph.contours.is_empty(),
+            Self::Contours(range) => range.contains(glyph.contours.len() as f64),
+            Self::Width(range) => glyph.width().map(|w| range.contains(w)).unwrap_or(false),
+        }
+    }
+}
+
+/// A parsed search query; see the module documentation for the syntax.
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    /// Terms and whether they are negated.
+    pub terms: Vec<(bool, Term)>,
+}
+
+impl Query {
+    pub fn parse(input: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut terms = vec![];
+        for token in tokenize(input)? {
+            let (negated, token) = match token.strip_prefix('-') {
+                Some(rest) if !rest.is_empty() => (true, rest),
+                _ => (false, token.as_str()),
+            };
+            terms.push((negated, Term::parse(token)?));
+        }
+        Ok(Self { terms })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn matches(&self, glyph: &Glyph) -> bool {
+        self.terms
+            .iter()
+            .all(|(negated, term)| term.matches(glyph) != *negated)
+    }
+}
+
+/// Splits `input` on whitespace outside of double quotes, removing the quotes.
+fn tokenize(input: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut ret = vec![];
+    let mut cur = String::new();
+    let mut quoted = false;
+    for c in input.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !cur.is_empty() {
+                    ret.push(std::mem::take(&mut cur));
+                }
+            }
+            c => cur.push(c),
+        }
+    }
+    if quoted {
+        return Err("Unterminated quote.".into());
+    }
+    if !cur.is_empty() {
+        ret.push(cur);
+    }
+    Ok(ret)
+}
+
+#[test]
+fn test_query() {
+    use super::{Component, Contour};
+
+    assert_eq!(
+        tokenize(r#"uname:"small letter" -is:empty"#).unwrap(),
+        vec!["uname:small letter".to_string(), "-is:empty".to_string()]
+    );
+    assert!(tokenize(r#"uname:"small"#).is_err());
+    assert!(Query::parse("colour:red").is_err());
+    assert!(Query::parse("contours:many").is_err());
+    assert!(Query::parse("name:(").is_err());
+
+    let a: Glyph = GlyphKind::Char('a').into();
+    let mut aacute: Glyph = GlyphKind::Char('á').into();
+    *aacute.metadata.name.borrow_mut() = "aacute".to_string();
+    aacute.components.push(Component {
+        base_name: "a".to_string(),
+        base: std::rc::Weak::new(),
+        x_offset: 0.0,
+        y_offset: 0.0,
+        x_scale: 1.0,
+        xy_scale: 0.0,
+        yx_scale: 0.0,
+        y_scale: 1.0,
+    });
+    let mut zhe: Glyph = GlyphKind::Char('ж').into();
+    *zhe.metadata.name.borrow_mut() = "zhe-cy".to_string();
+    zhe.contours.push(Contour::new());
+    zhe.contours.push(Contour::new());
+    zhe.metadata.mark_color.set(Color::RED);
+
+    let find = |q: &str| {
+        let query = Query::parse(q).unwrap();
+        [&a, &aacute, &zhe]
+            .into_iter()
+            .filter(|g| query.matches(g))
+            .map(|g| g.name().to_string())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(find(""), vec!["a", "aacute", "zhe-cy"]);
+    assert_eq!(find("a"), vec!["a", "aacute"]);
+    assert_eq!(find("name:^a$"), vec!["a"]);
+    assert_eq!(find("u:U+0000:U+00FF"), vec!["a", "aacute"]);
+    assert_eq!(find("ж"), vec!["zhe-cy"]);
+    assert_eq!(find(r#"uname:"small letter a""#), vec!["a", "aacute"]);
+    assert_eq!(find("block:cyrillic"), vec!["zhe-cy"]);
+    assert_eq!(find("-block:cyrillic has:components"), vec!["aacute"]);
+    assert_eq!(find("mark:#ff0000"), vec!["zhe-cy"]);
+    assert_eq!(find("mark:none"), vec!["a", "aacute"]);
+    assert_eq!(find("contours:>=2"), vec!["zhe-cy"]);
+    assert_eq!(find("contours:0..1 -has:components"), vec!["a"]);
+}
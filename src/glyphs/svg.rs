@@ -0,0 +1,460 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Conversion between contours and SVG path data.
+//!
+//! SVG's y axis grows downwards, so y coordinates are negated in both directions: a path that
+//! is written and read back keeps its font unit coordinates.
+
+use super::Contour;
+use crate::prelude::*;
+use crate::utils::{curves::Bezier, Point};
+
+fn num(v: f64) -> f64 {
+    if v == 0.0 {
+        0.0
+    } else {
+        v
+    }
+}
+
+/// SVG path data (the contents of a `<path>` element's `d` attribute) of `contours`.
+pub fn path_data(contours: &[Contour]) -> String {
+    use std::fmt::Write;
+
+    let mut ret = String::new();
+    for contour in contours {
+        let curves = contour.curves();
+        let closed = !contour.imp().open.get();
+        let Some(start) = curves
+            .first()
+            .and_then(|c| c.points().first().map(|p| p.position))
+        else {
+            continue;
+        };
+        if !ret.is_empty() {
+            ret.push(' ');
+        }
+        write!(&mut ret, "M{} {}", num(start.x), num(-start.y)).unwrap();
+        for (i, curv) in curves.iter().enumerate() {
+            let points = curv.points();
+            let cmd = match points.len() {
+                2 if closed && i + 1 == curves.len() => continue,
+                2 => 'L',
+                3 => 'Q',
+                4 => 'C',
+                _ => continue,
+            };
+            write!(&mut ret, " {}", cmd).unwrap();
+            for (j, p) in points[1..].iter().enumerate() {
+                if j > 0 {
+                    ret.push(' ');
+                }
+                write!(&mut ret, "{} {}", num(p.position.x), num(-p.position.y)).unwrap();
+            }
+        }
+        if closed {
+            ret.push_str(" Z");
+        }
+    }
+    ret
+}
+
+/// A standalone SVG document with a single path that draws `contours`.
+pub fn svg_document(contours: &[Contour]) -> String {
+    let (mut min, mut max) = (
+        Point::from((f64::INFINITY, f64::INFINITY)),
+        Point::from((f64::NEG_INFINITY, f64::NEG_INFINITY)),
+    );
+    for contour in contours {
+        for curv in contour.curves().iter() {
+            for p in curv.points().iter() {
+                min.x = min.x.min(p.position.x);
+                min.y = min.y.min(p.position.y);
+                max.x = max.x.max(p.position.x);
+                max.y = max.y.max(p.position.y);
+            }
+        }
+    }
+    if min.x > max.x {
+        min = Point::from((0.0, 0.0));
+        max = min;
+    }
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\" \
+         viewBox=\"{} {} {} {}\" width=\"{}\" height=\"{}\">\n<path d=\"{}\"/>\n</svg>\n",
+        num(min.x),
+        num(-max.y),
+        max.x - min.x,
+        max.y - min.y,
+        max.x - min.x,
+        max.y - min.y,
+        path_data(contours)
+    )
+}
+
+/// Parses the `d` attributes of all `<path>` elements in an SVG document. Element transforms
+/// and other shape elements are ignored. Text without any markup is parsed as path data.
+pub fn parse_svg(text: &str) -> Result<Vec<Contour>, Box<dyn std::error::Error>> {
+    static PATH_RE: Lazy<regex::Regex> = Lazy::new(|| {
+        regex::Regex::new(r#"<path\b[^>]*?\sd\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap()
+    });
+    if !text.contains('<') {
+        return parse_path_data(text);
+    }
+    let mut ret = vec![];
+    for cap in PATH_RE.captures_iter(text) {
+        if let Some(d) = cap.get(1).or_else(|| cap.get(2)) {
+            ret.extend(parse_path_data(d.as_str())?);
+        }
+    }
+    if ret.is_empty() {
+        return Err("No paths found in SVG.".into());
+    }
+    Ok(ret)
+}
+
+struct PathParser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl PathParser<'_> {
+    fn skip_separators(&mut self) {
+        while self.pos < self.s.len()
+            && (self.s[self.pos].is_ascii_whitespace() || self.s[self.pos] == b',')
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn command(&mut self) -> Option<u8> {
+        self.skip_separators();
+        let c = *self.s.get(self.pos)?;
+        if c.is_ascii_alphabetic() && c != b'e' && c != b'E' {
+            self.pos += 1;
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    fn at_number(&mut self) -> bool {
+        self.skip_separators();
+        matches!(self.s.get(self.pos), Some(b'0'..=b'9' | b'-' | b'+' | b'.'))
+    }
+
+    fn number(&mut self) -> Result<f64, Box<dyn std::error::Error>> {
+        self.skip_separators();
+        let start = self.pos;
+        if matches!(self.s.get(self.pos), Some(b'-' | b'+')) {
+            self.pos += 1;
+        }
+        let mut seen_dot = false;
+        while let Some(&c) = self.s.get(self.pos) {
+            match c {
+                b'0'..=b'9' => {}
+                b'.' if !seen_dot => seen_dot = true,
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        if matches!(self.s.get(self.pos), Some(b'e' | b'E')) {
+            self.pos += 1;
+            if matches!(self.s.get(self.pos), Some(b'-' | b'+')) {
+                self.pos += 1;
+            }
+            while matches!(self.s.get(self.pos), Some(b'0'..=b'9')) {
+                self.pos += 1;
+            }
+        }
+        std::str::from_utf8(&self.s[start..self.pos])?
+            .parse::<f64>()
+            .map_err(|err| format!("Invalid number at offset {}: {}", start, err).into())
+    }
+
+    fn point(&mut self) -> Result<Point, Box<dyn std::error::Error>> {
+        Ok(Point::from((self.number()?, self.number()?)))
+    }
+
+    fn flag(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+        self.skip_separators();
+        match self.s.get(self.pos) {
+            Some(b'0') => {
+                self.pos += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.pos += 1;
+                Ok(true)
+            }
+            _ => Err(format!("Invalid arc flag at offset {}.", self.pos).into()),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Subpath {
+    start: Point,
+    curves: Vec<Vec<Point>>,
+    closed: bool,
+}
+
+/// Parses SVG path data into contours. Elliptical arcs are approximated with cubic curves.
+pub fn parse_path_data(d: &str) -> Result<Vec<Contour>, Box<dyn std::error::Error>> {
+    let mut parser = PathParser {
+        s: d.as_bytes(),
+        pos: 0,
+    };
+    let mut subpaths: Vec<Subpath> = vec![];
+    let mut current = Point::from((0.0, 0.0));
+    // Reflected control point for the smooth `S` and `T` commands.
+    let mut last_control: Option<(u8, Point)> = None;
+    let mut cmd: Option<u8> = None;
+    loop {
+        if let Some(c) = parser.command() {
+            cmd = Some(c);
+        } else if parser.pos >= parser.s.len() {
+            break;
+        } else if !parser.at_number() || cmd.is_none() {
+            return Err(format!("Unexpected character at offset {}.", parser.pos).into());
+        }
+        let c = cmd.unwrap();
+        let relative = c.is_ascii_lowercase();
+        let origin = if relative {
+            current
+        } else {
+            Point::from((0.0, 0.0))
+        };
+        macro_rules! sub {
+            () => {{
+                if subpaths.last().map(|s| s.closed).unwrap_or(true) {
+                    subpaths.push(Subpath {
+                        start: current,
+                        ..Subpath::default()
+                    });
+                }
+                subpaths.last_mut().unwrap()
+            }};
+        }
+        match c.to_ascii_uppercase() {
+            b'M' => {
+                current = origin + parser.point()?;
+                subpaths.push(Subpath {
+                    start: current,
+                    ..Subpath::default()
+                });
+                // Subsequent coordinate pairs are implicit line commands.
+                cmd = Some(if relative { b'l' } else { b'L' });
+                last_control = None;
+            }
+            b'Z' => {
+                if let Some(sub) = subpaths.last_mut().filter(|s| !s.closed) {
+                    if current != sub.start {
+                        sub.curves.push(vec![current, sub.start]);
+                    }
+                    sub.closed = true;
+                    current = sub.start;
+                }
+                cmd = None;
+                last_control = None;
+            }
+            b'L' | b'H' | b'V' => {
+                let end = match c.to_ascii_uppercase() {
+                    b'L' => origin + parser.point()?,
+                    b'H' => Point::from((origin.x + parser.number()?, current.y)),
+                    _ => Point::from((current.x, origin.y + parser.number()?)),
+                };
+                sub!().curves.push(vec![current, end]);
+                current = end;
+                last_control = None;
+            }
+            b'C' | b'S' => {
+                let c1 = if c.to_ascii_uppercase() == b'C' {
+                    origin + parser.point()?
+                } else {
+                    match last_control {
+                        Some((b'C', p)) => 2.0 * current - p,
+                        _ => current,
+                    }
+                };
+                let c2 = origin + parser.point()?;
+                let end = origin + parser.point()?;
+                sub!().curves.push(vec![current, c1, c2, end]);
+                current = end;
+                last_control = Some((b'C', c2));
+            }
+            b'Q' | b'T' => {
+                let c1 = if c.to_ascii_uppercase() == b'Q' {
+                    origin + parser.point()?
+                } else {
+                    match last_control {
+                        Some((b'Q', p)) => 2.0 * current - p,
+                        _ => current,
+                    }
+                };
+                let end = origin + parser.point()?;
+                sub!().curves.push(vec![current, c1, end]);
+                current = end;
+                last_control = Some((b'Q', c1));
+            }
+            b'A' => {
+                let (rx, ry) = (parser.number()?, parser.number()?);
+                let angle = parser.number()?;
+                let (large_arc, sweep) = (parser.flag()?, parser.flag()?);
+                let end = origin + parser.point()?;
+                let start = current;
+                let sub = sub!();
+                match arc_to_cubics(start, (rx, ry), angle, large_arc, sweep, end) {
+                    Some(cubics) => sub.curves.extend(cubics.into_iter().map(|c| c.to_vec())),
+                    None if start != end => sub.curves.push(vec![start, end]),
+                    None => {}
+                }
+                current = end;
+                last_control = None;
+            }
+            other => {
+                return Err(format!("Unsupported path command `{}`.", other as char).into());
+            }
+        }
+    }
+    Ok(subpaths
+        .into_iter()
+        .filter(|s| !s.curves.is_empty())
+        .map(|s| {
+            let contour = Contour::new();
+            for points in s.curves {
+                contour.push_curve(Bezier::new(
+                    points
+                        .into_iter()
+                        .map(|p| Point::from((p.x, num(-p.y))))
+                        .collect(),
+                ));
+            }
+            if s.closed {
+                contour.close();
+            }
+            contour
+        })
+        .collect())
+}
+
+/// Approximates an SVG elliptical arc with cubic curves of at most 90 degrees each, following
+/// the endpoint to center parameterization of the SVG specification. Returns `None` for
+/// degenerate arcs which are drawn as straight lines.
+fn arc_to_cubics(
+    p0: Point,
+    (rx, ry): (f64, f64),
+    angle: f64,
+    large_arc: bool,
+    sweep: bool,
+    p1: Point,
+) -> Option<Vec<[Point; 4]>> {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if p0 == p1 || rx == 0.0 || ry == 0.0 {
+        return None;
+    }
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let (dx2, dy2) = ((p0.x - p1.x) / 2.0, (p0.y - p1.y) / 2.0);
+    let x1p = cos * dx2 + sin * dy2;
+    let y1p = -sin * dx2 + cos * dy2;
+    let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+    let num = rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p;
+    let den = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+    let mut coef = (num / den).max(0.0).sqrt();
+    if large_arc == sweep {
+        coef = -coef;
+    }
+    let cxp = coef * rx * y1p / ry;
+    let cyp = -coef * ry * x1p / rx;
+    let cx = cos * cxp - sin * cyp + (p0.x + p1.x) / 2.0;
+    let cy = sin * cxp + cos * cyp + (p0.y + p1.y) / 2.0;
+    let vector_angle =
+        |ux: f64, uy: f64, vx: f64, vy: f64| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+    let theta1 = vector_angle(1.0, 0.0, (x1p - cxp) / rx, (y1p - cyp) / ry);
+    let mut dtheta = vector_angle(
+        (x1p - cxp) / rx,
+        (y1p - cyp) / ry,
+        (-x1p - cxp) / rx,
+        (-y1p - cyp) / ry,
+    );
+    if !sweep && dtheta > 0.0 {
+        dtheta -= 2.0 * PI;
+    } else if sweep && dtheta < 0.0 {
+        dtheta += 2.0 * PI;
+    }
+    let segments = (dtheta.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+    let delta = dtheta / segments as f64;
+    let k = 4.0 / 3.0 * (delta / 4.0).tan();
+    let map = |u: f64, v: f64| {
+        Point::from((
+            cx + rx * u * cos - ry * v * sin,
+            cy + rx * u * sin + ry * v * cos,
+        ))
+    };
+    let mut ret = Vec::with_capacity(segments);
+    let mut start = p0;
+    for i in 0..segments {
+        let t1 = theta1 + i as f64 * delta;
+        let t2 = t1 + delta;
+        let (s1, c1) = t1.sin_cos();
+        let (s2, c2) = t2.sin_cos();
+        let end = if i + 1 == segments { p1 } else { map(c2, s2) };
+        ret.push([
+            start,
+            map(c1 - k * s1, s1 + k * c1),
+            map(c2 + k * s2, s2 - k * c2),
+            end,
+        ]);
+        start = end;
+    }
+    Some(ret)
+}
+
+#[test]
+fn test_svg_path_data() {
+    let d = "M0 0 L100 0 Q150 50 100 100 C50 150 0 100 0 50 Z M10 10 L20 20";
+    let contours = parse_path_data(d).unwrap();
+    assert_eq!(contours.len(), 2);
+    assert_eq!(contours[0].curves().len(), 4);
+    assert_eq!(
+        contours[0].curves()[1].points()[1].position,
+        Point::from((150.0, -50.0))
+    );
+    assert!(contours[1].imp().open.get());
+    assert_eq!(path_data(&contours), d);
+
+    let relative = parse_path_data("m0,0 h100 v-100 h-100z").unwrap();
+    assert_eq!(path_data(&relative), "M0 0 L100 0 L100 -100 L0 -100 Z");
+
+    let circle = parse_path_data("M0 50 A50 50 0 1 0 100 50 A50 50 0 1 0 0 50 Z").unwrap();
+    assert_eq!(circle[0].curves().len(), 4);
+    for curv in circle[0].curves().iter() {
+        let mid = curv.compute(0.5);
+        assert!((mid.distance(Point::from((50.0, -50.0))) - 50.0).abs() < 0.1);
+    }
+}
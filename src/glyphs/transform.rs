@@ -0,0 +1,255 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Numeric transformations of outlines about a reference point of their bounding box.
+
+use super::outline::Outline;
+use super::*;
+
+/// One of the nine reference points of a bounding box.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum TransformOrigin {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl TransformOrigin {
+    /// All origins in row order, top row first.
+    pub const ALL: [Self; 9] = [
+        Self::TopLeft,
+        Self::Top,
+        Self::TopRight,
+        Self::Left,
+        Self::Center,
+        Self::Right,
+        Self::BottomLeft,
+        Self::Bottom,
+        Self::BottomRight,
+    ];
+
+    /// The reference point of the box with corners `min` and `max`.
+    pub fn point(self, (min, max): (Point, Point)) -> Point {
+        let index = Self::ALL.iter().position(|o| *o == self).unwrap_or(4);
+        let (column, row) = ((index % 3) as f64, (index / 3) as f64);
+        Point::from((
+            min.x + column / 2.0 * (max.x - min.x),
+            max.y - row / 2.0 * (max.y - min.y),
+        ))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TransformOperation {
+    Move {
+        x: f64,
+        y: f64,
+    },
+    /// Scale factors, `1.0` leaves the outline unchanged.
+    Scale {
+        x: f64,
+        y: f64,
+    },
+    /// Counter-clockwise rotation in degrees.
+    Rotate {
+        degrees: f64,
+    },
+    /// Skew angles in degrees along each axis.
+    Skew {
+        x: f64,
+        y: f64,
+    },
+    FlipHorizontal,
+    FlipVertical,
+}
+
+impl TransformOperation {
+    /// The matrix of this operation about `origin`. Moves ignore the origin.
+    pub fn matrix(self, origin: Point) -> Matrix {
+        let op = match self {
+            Self::Move { x, y } => return Matrix::new(1.0, 0.0, 0.0, 1.0, x, y),
+            Self::Scale { x, y } => Matrix::new(x, 0.0, 0.0, y, 0.0, 0.0),
+            Self::Rotate { degrees } => {
+                let (sin, cos) = degrees.to_radians().sin_cos();
+                Matrix::new(cos, sin, -sin, cos, 0.0, 0.0)
+            }
+            Self::Skew { x, y } => Matrix::new(
+                1.0,
+                y.to_radians().tan(),
+                x.to_radians().tan(),
+                1.0,
+                0.0,
+                0.0,
+            ),
+            Self::FlipHorizontal => Matrix::new(-1.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+            Self::FlipVertical => Matrix::new(1.0, 0.0, 0.0, -1.0, 0.0, 0.0),
+        };
+        let to_origin = Matrix::new(1.0, 0.0, 0.0, 1.0, -origin.x, -origin.y);
+        let from_origin = Matrix::new(1.0, 0.0, 0.0, 1.0, origin.x, origin.y);
+        Matrix::multiply(&Matrix::multiply(&to_origin, &op), &from_origin)
+    }
+}
+
+/// Bounding box of the points of `contours`, including off-curve points.
+pub fn control_box(contours: &[Contour]) -> Option<(Point, Point)> {
+    let mut ret: Option<(Point, Point)> = None;
+    for contour in contours {
+        for curv in contour.curves().iter() {
+            for p in curv.points().iter() {
+                let (min, max) = ret.get_or_insert((p.position, p.position));
+                min.x = min.x.min(p.position.x);
+                min.y = min.y.min(p.position.y);
+                max.x = max.x.max(p.position.x);
+                max.y = max.y.max(p.position.y);
+            }
+        }
+    }
+    ret
+}
+
+impl Contour {
+    /// A copy of this contour with every point transformed by `m`. Mirroring transformations
+    /// also reverse the contour, so that its direction stays the same.
+    pub fn transformed(&self, m: Matrix) -> Self {
+        let curves = self
+            .curves()
+            .iter()
+            .map(|curv| {
+                let new = Bezier::new(
+                    curv.points()
+                        .iter()
+                        .map(|cp| m.transform_point(cp.position.x, cp.position.y).into())
+                        .collect(),
+                );
+                new.set_property(Bezier::SMOOTH, curv.property::<bool>(Bezier::SMOOTH));
+                new.set_property(
+                    Bezier::IMPLIED_END,
+                    curv.property::<bool>(Bezier::IMPLIED_END),
+                );
+                new
+            })
+            .collect();
+        let ret = Self::new_with_curves(curves);
+        if !self.property::<bool>(Self::OPEN) {
+            ret.close();
+        }
+        if m.xx() * m.yy() - m.xy() * m.yx() < 0.0 {
+            ret.reverse_direction();
+        }
+        ret
+    }
+}
+
+impl Glyph {
+    /// This glyph's contours, components and anchors after applying `operation` about `origin`
+    /// of the glyph's control box.
+    pub fn transformed_outline(
+        &self,
+        operation: TransformOperation,
+        origin: TransformOrigin,
+    ) -> Outline {
+        let bbox = control_box(&self.contours).unwrap_or_default();
+        let m = operation.matrix(origin.point(bbox));
+        let contours = self.contours.iter().map(|c| c.transformed(m)).collect();
+        let components = self
+            .components
+            .iter()
+            .map(|c| {
+                let own = Matrix::new(
+                    c.x_scale, c.xy_scale, c.yx_scale, c.y_scale, c.x_offset, c.y_offset,
+                );
+                let new = Matrix::multiply(&own, &m);
+                Component {
+                    x_scale: new.xx(),
+                    xy_scale: new.yx(),
+                    yx_scale: new.xy(),
+                    y_scale: new.yy(),
+                    x_offset: new.x0(),
+                    y_offset: new.y0(),
+                    ..c.clone()
+                }
+            })
+            .collect();
+        let anchors = self
+            .metadata
+            .anchors
+            .borrow()
+            .iter()
+            .map(|a| {
+                let (x, y) = m.transform_point(a.x, a.y);
+                Anchor { x, y, ..a.clone() }
+            })
+            .collect();
+        (contours, components, anchors)
+    }
+
+    /// Undoable application of `operation` to every glyph in `glyphs`, each about `origin` of
+    /// its own control box. `on_change` is called for each glyph after redoing or undoing.
+    pub fn transform_glyphs(
+        glyphs: Vec<Rc<RefCell<Glyph>>>,
+        operation: TransformOperation,
+        origin: TransformOrigin,
+        on_change: Rc<dyn Fn(&Rc<RefCell<Glyph>>)>,
+    ) -> crate::app::Action {
+        let swaps = glyphs
+            .into_iter()
+            .map(|g| {
+                let outline = g.borrow().transformed_outline(operation, origin);
+                (g, outline)
+            })
+            .collect::<Vec<_>>();
+        Self::swap_outlines(swaps, "transform", on_change)
+    }
+}
+
+#[test]
+fn test_transform_operation() {
+    let bbox = (Point::from((0.0, 0.0)), Point::from((100.0, 200.0)));
+    assert_eq!(
+        TransformOrigin::TopLeft.point(bbox),
+        Point::from((0.0, 200.0))
+    );
+    assert_eq!(
+        TransformOrigin::Center.point(bbox),
+        Point::from((50.0, 100.0))
+    );
+    assert_eq!(
+        TransformOrigin::BottomRight.point(bbox),
+        Point::from((100.0, 0.0))
+    );
+
+    let m = TransformOperation::Scale { x: 2.0, y: 0.5 }.matrix((50.0, 100.0).into());
+    assert_eq!(m.transform_point(100.0, 200.0), (150.0, 150.0));
+    let m = TransformOperation::Rotate { degrees: 90.0 }.matrix((50.0, 0.0).into());
+    let (x, y) = m.transform_point(100.0, 0.0);
+    assert!((x - 50.0).abs() < 1e-9 && (y - 50.0).abs() < 1e-9);
+    let m = TransformOperation::FlipHorizontal.matrix((50.0, 0.0).into());
+    assert_eq!(m.transform_point(0.0, 10.0), (100.0, 10.0));
+    let m = TransformOperation::Move { x: 5.0, y: -5.0 }.matrix((50.0, 0.0).into());
+    assert_eq!(m.transform_point(0.0, 0.0), (5.0, -5.0));
+}
@@ -24,9 +24,12 @@ use crate::git;
 
 use std::path::{Path, PathBuf};
 
-use crate::glyphs::{Glyph, Guideline};
+use crate::glyphs::{Glyph, GlyphKind, Guideline, Unicode};
 use crate::prelude::*;
 
+mod watcher;
+pub use watcher::is_modified_in_memory;
+
 // [ref:FIXME]: how do we detect if a Project is no longer modified when a user undos the modifications?
 //
 // An idea is to keep a counter of single modifications, and decrease it when the user performs an
@@ -46,8 +49,15 @@ pub struct ProjectInner {
     pub default_layer: ufo::objects::Layer,
     pub background_layer: RefCell<Option<ufo::objects::Layer>>,
     pub all_layers: RefCell<Vec<ufo::objects::Layer>>,
+    /// Directories of deleted layers, removed on the next save.
+    deleted_layers: RefCell<Vec<PathBuf>>,
+    /// Glyph renames to apply to kerning, groups, features and the font lib on the next save.
+    renamed_glyphs: RefCell<Vec<(String, String)>>,
     #[cfg(feature = "git")]
     pub repository: RefCell<Result<Option<git::Repository>, Box<dyn std::error::Error>>>,
+    monitors: RefCell<Vec<gio::FileMonitor>>,
+    pending_changes: RefCell<HashSet<PathBuf>>,
+    reload_pending: Cell<bool>,
 }
 
 impl Default for ProjectInner {
@@ -65,8 +75,13 @@ impl Default for ProjectInner {
             default_layer: ufo::objects::Layer::new(),
             background_layer: RefCell::new(None),
             all_layers: RefCell::new(vec![]),
+            deleted_layers: RefCell::new(vec![]),
+            renamed_glyphs: RefCell::new(vec![]),
             #[cfg(feature = "git")]
             repository: RefCell::new(Ok(None)),
+            monitors: RefCell::new(vec![]),
+            pending_changes: RefCell::new(HashSet::default()),
+            reload_pending: Cell::new(false),
         }
     }
 }
@@ -148,6 +163,43 @@ impl ObjectImpl for ProjectInner {
             _ => unimplemented!("{}", pspec.name()),
         }
     }
+
+    fn signals() -> &'static [Signal] {
+        static SIGNALS: Lazy<Vec<Signal>> = Lazy::new(|| {
+            vec![
+                Signal::builder(
+                    Project::GLYPH_RELOADED,
+                    &[
+                        ufo::objects::Layer::static_type().into(),
+                        String::static_type().into(),
+                    ],
+                    <()>::static_type().into(),
+                )
+                .build(),
+                Signal::builder(
+                    Project::GLYPH_CHANGED,
+                    &[
+                        ufo::objects::Layer::static_type().into(),
+                        String::static_type().into(),
+                    ],
+                    <()>::static_type().into(),
+                )
+                .build(),
+                Signal::builder(
+                    Project::GLYPH_CHANGED_ON_DISK,
+                    &[
+                        ufo::objects::Layer::static_type().into(),
+                        String::static_type().into(),
+                    ],
+                    <()>::static_type().into(),
+                )
+                .build(),
+                Signal::builder(Project::LAYERS_CHANGED, &[], <()>::static_type().into()).build(),
+                Signal::builder(Project::FILES_CHANGED, &[], <()>::static_type().into()).build(),
+            ]
+        });
+        SIGNALS.as_ref()
+    }
 }
 
 glib::wrapper! {
@@ -166,6 +218,20 @@ impl Project {
     pub const MODIFIED: &str = "modified";
     pub const NAME: &str = "name";
     pub const FILENAME_STEM: &str = "filename-stem";
+    /// Emitted with a layer and glyph name when a glyph was reloaded from disk, added or removed
+    /// because of an external change.
+    pub const GLYPH_RELOADED: &str = "glyph-reloaded";
+    /// Emitted with a layer and glyph name when an operation outside the glyph's editor, or an
+    /// undoable action that replaces its outline, changed a glyph in memory. Use
+    /// [`Project::glyph_changed`] to emit it.
+    pub const GLYPH_CHANGED: &str = "glyph-changed";
+    /// Emitted with a layer and glyph name when a glyph with unsaved changes was changed on disk.
+    pub const GLYPH_CHANGED_ON_DISK: &str = "glyph-changed-on-disk";
+    /// Emitted when a layer was added, renamed or deleted.
+    pub const LAYERS_CHANGED: &str = "layers-changed";
+    /// Emitted after files of the project, or the index of its git repository, were changed on
+    /// disk and the changes were processed.
+    pub const FILES_CHANGED: &str = "files-changed";
 
     pub fn new() -> Self {
         let ret: Self = glib::Object::new::<Self>(&[]).unwrap();
@@ -316,9 +382,28 @@ impl Project {
             }
         }
         fontinfo.save()?;
+        for path in self.deleted_layers.borrow_mut().drain(..) {
+            if path.exists() {
+                std::fs::remove_dir_all(&path).map_err(|err| {
+                    format!("Could not delete layer directory {}: {err}", path.display())
+                })?;
+            }
+        }
         for obj in self.all_layers.borrow().iter().filter(|obj| obj.modified()) {
             obj.save(&mut self.layercontents.borrow_mut())?;
         }
+        {
+            let mut renamed_glyphs = self.renamed_glyphs.borrow_mut();
+            ufo::rename_glyphs_in_font_files(&self.path.borrow(), &renamed_glyphs)?;
+            renamed_glyphs.clear();
+        }
+        {
+            let mut layercontents = self.layercontents.borrow_mut();
+            if layercontents.modified {
+                layercontents.save(&self.path.borrow().join("layercontents.plist"))?;
+                layercontents.modified = false;
+            }
+        }
         for g in self
             .metric_guidelines
             .borrow()
@@ -381,6 +466,179 @@ impl Project {
         layer.new_glyph(name, glyph)
     }
 
+    /// Adds an empty glyph for character `c` to the default layer, named after its production
+    /// name. Returns `None` if a glyph with that name already exists.
+    pub fn new_placeholder_glyph(
+        &self,
+        c: char,
+    ) -> Result<Option<Rc<RefCell<Glyph>>>, Box<dyn std::error::Error>> {
+        let name = crate::unicode::agl::production_name(c);
+        if self.default_layer.glyphs().contains_key(&name) {
+            return Ok(None);
+        }
+        let glyph: Glyph = GlyphKind::Char(c).into();
+        *glyph.metadata.name.borrow_mut() = name.clone();
+        *glyph.metadata.filename.borrow_mut() = self.default_layer.new_filename(&name);
+        *glyph.metadata.unicode.borrow_mut() = vec![Unicode::new(format!("{:04X}", c as u32))];
+        let metadata = glyph.metadata.clone();
+        let glyph = Rc::new(RefCell::new(glyph));
+        metadata.glyph_ref.set(glyph.clone()).unwrap();
+        self.new_glyph(name, glyph.clone(), None)?;
+        metadata.set_property(GlyphMetadata::MODIFIED, true);
+        Ok(Some(glyph))
+    }
+
+    /// Renames glyph `old` to `new` in every layer that has it, along with the components that
+    /// refer to it in any layer. Kerning, groups, features and the font lib are updated when
+    /// the project is saved.
+    pub fn rename_glyph(&self, old: &str, new: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if old == new {
+            return Ok(());
+        }
+        self.check_rename(old, new)?;
+        let mut changed = vec![];
+        for layer in self.layers() {
+            if layer.glyphs().contains_key(old) {
+                layer.rename_glyph(old, new.to_string())?;
+                changed.push((layer.clone(), new.to_string()));
+            }
+            for name in layer.rename_components(old, new) {
+                changed.push((layer.clone(), name));
+            }
+        }
+        self.renamed_glyphs
+            .borrow_mut()
+            .push((old.to_string(), new.to_string()));
+        self.set_property(Self::MODIFIED, true);
+        for (layer, name) in changed {
+            self.emit_by_name::<()>(Self::GLYPH_CHANGED, &[&layer, &name]);
+        }
+        Ok(())
+    }
+
+    fn check_rename(&self, old: &str, new: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let layers = self.layers();
+        if let Some(l) = layers.iter().find(|l| l.glyphs().contains_key(new)) {
+            return Err(format!(
+                "Glyph `{new}` already exists in layer {}.",
+                l.property::<String>(ufo::objects::Layer::NAME)
+            )
+            .into());
+        }
+        if !layers.iter().any(|l| l.glyphs().contains_key(old)) {
+            return Err(format!("Glyph `{old}` does not exist.").into());
+        }
+        Ok(())
+    }
+
+    /// Undoable [`Project::rename_glyph`]. The returned action has to be redone once to take
+    /// effect.
+    pub fn rename_glyph_action(
+        &self,
+        old: &str,
+        new: &str,
+    ) -> Result<crate::app::Action, Box<dyn std::error::Error>> {
+        self.check_rename(old, new)?;
+        let rename = |from: String, to: String| {
+            clone!(@weak self as project => move || {
+                if let Err(err) = project.rename_glyph(&from, &to) {
+                    eprintln!("Could not rename glyph `{from}` to `{to}`: {err}");
+                }
+            })
+        };
+        Ok(crate::app::Action {
+            stamp: crate::app::EventStamp {
+                t: std::any::TypeId::of::<Self>(),
+                property: "glyph-name",
+                id: old.as_bytes().into(),
+            },
+            compress: false,
+            redo: Box::new(rename(old.to_string(), new.to_string())),
+            undo: Box::new(rename(new.to_string(), old.to_string())),
+        })
+    }
+
+    /// The layer that contains `glyph`.
+    pub fn glyph_layer(&self, glyph: &Rc<RefCell<Glyph>>) -> Option<ufo::objects::Layer> {
+        let name = glyph.borrow().name().to_string();
+        self.layers()
+            .into_iter()
+            .find(|l| matches!(l.glyphs().get(&name), Some(g) if Rc::ptr_eq(g, glyph)))
+    }
+
+    /// Emits [`Project::GLYPH_CHANGED`] for `glyph` with the layer that contains it.
+    pub fn glyph_changed(&self, glyph: &Rc<RefCell<Glyph>>) {
+        let Some(layer) = self.glyph_layer(glyph) else {
+            return;
+        };
+        let name = glyph.borrow().name().to_string();
+        self.emit_by_name::<()>(Self::GLYPH_CHANGED, &[&layer, &name]);
+    }
+
+    /// All layers, starting with the default layer.
+    pub fn layers(&self) -> Vec<ufo::objects::Layer> {
+        let mut ret = self.all_layers.borrow().clone();
+        if !ret.contains(&self.default_layer) {
+            ret.insert(0, self.default_layer.clone());
+        }
+        ret
+    }
+
+    /// Adds an empty layer. Its directory is created when the project is saved.
+    pub fn new_layer(&self, name: &str) -> Result<ufo::objects::Layer, Box<dyn std::error::Error>> {
+        let mut layercontents = self.layercontents.borrow_mut();
+        layercontents.validate_name(name)?;
+        let dir_name = layercontents.new_dir_name(name);
+        let layer = ufo::objects::Layer::new();
+        layer.init_from_path(name.to_string(), dir_name, self.path.borrow().clone(), true)?;
+        layer.set_property(ufo::objects::Layer::MODIFIED, true);
+        self.link(&layer);
+        layercontents.insert_layer(layer.clone());
+        drop(layercontents);
+        self.all_layers.borrow_mut().push(layer.clone());
+        self.layers_changed();
+        Ok(layer)
+    }
+
+    pub fn rename_layer(
+        &self,
+        layer: &ufo::objects::Layer,
+        new: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let old = layer.property::<String>(ufo::objects::Layer::NAME);
+        self.layercontents
+            .borrow_mut()
+            .rename_layer(&old, new.to_string())?;
+        self.layers_changed();
+        Ok(())
+    }
+
+    /// Removes `layer`. Its directory is deleted when the project is saved.
+    pub fn delete_layer(
+        &self,
+        layer: &ufo::objects::Layer,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let name = layer.property::<String>(ufo::objects::Layer::NAME);
+        self.layercontents.borrow_mut().remove_layer(&name)?;
+        self.all_layers.borrow_mut().retain(|l| l != layer);
+        self.deleted_layers
+            .borrow_mut()
+            .push(layer.path().to_path_buf());
+        self.layers_changed();
+        Ok(())
+    }
+
+    fn layers_changed(&self) {
+        *self.background_layer.borrow_mut() = self
+            .layercontents
+            .borrow()
+            .objects
+            .get("public.background")
+            .cloned();
+        self.set_property(Self::MODIFIED, true);
+        self.emit_by_name::<()>(Self::LAYERS_CHANGED, &[]);
+    }
+
     pub fn fontinfo(&self) -> FieldRef<'_, FontInfo> {
         self.fontinfo.borrow().into()
     }
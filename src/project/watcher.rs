@@ -0,0 +1,285 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Watching the UFO directory for changes made by other programs.
+//!
+//! Glyphs and plists that have no unsaved changes in memory are reloaded automatically. Glyphs
+//! that do are reported with [`Project::GLYPH_CHANGED_ON_DISK`] so that the user can decide which
+//! version to keep.
+
+use super::Project;
+use crate::glyphs::Glyph;
+use crate::prelude::*;
+use crate::ufo::glif;
+use crate::ufo::objects::Layer;
+
+impl Project {
+    /// Starts monitoring the project directory, every layer directory and the git index.
+    pub fn watch(&self) {
+        let mut monitors = self.monitors.borrow_mut();
+        monitors.clear();
+        let mut dirs = vec![self.path.borrow().clone()];
+        dirs.extend(self.all_layers.borrow().iter().map(|l| l.path().clone()));
+        for dir in dirs {
+            match gio::File::for_path(&dir)
+                .monitor_directory(gio::FileMonitorFlags::WATCH_MOVES, gio::Cancellable::NONE)
+            {
+                Ok(monitor) => {
+                    monitor.connect_changed(
+                        clone!(@weak self as obj => move |_, file, other_file, event| {
+                            use gio::FileMonitorEvent::*;
+                            match event {
+                                ChangesDoneHint | Created | Deleted | MovedIn | MovedOut => {}
+                                Renamed => {
+                                    if let Some(path) = other_file.and_then(|f| f.path()) {
+                                        obj.schedule_reload(path);
+                                    }
+                                }
+                                _ => return,
+                            }
+                            if let Some(path) = file.path() {
+                                obj.schedule_reload(path);
+                            }
+                        }),
+                    );
+                    monitors.push(monitor);
+                }
+                Err(err) => {
+                    eprintln!("Could not watch {} for changes: {err}", dir.display());
+                }
+            }
+        }
+        #[cfg(feature = "git")]
+        if let Ok(Some(repo)) = &*self.repository.borrow() {
+            let index_path = repo.index_path();
+            match gio::File::for_path(&index_path)
+                .monitor_file(gio::FileMonitorFlags::NONE, gio::Cancellable::NONE)
+            {
+                Ok(monitor) => {
+                    monitor.connect_changed(clone!(@weak self as obj => move |_, _, _, _| {
+                        obj.schedule_reload(index_path.clone());
+                    }));
+                    monitors.push(monitor);
+                }
+                Err(err) => {
+                    eprintln!(
+                        "Could not watch {} for changes: {err}",
+                        index_path.display()
+                    );
+                }
+            }
+        }
+    }
+
+    /// File monitors fire several events per write, so coalesce them and process all pending
+    /// paths at once.
+    fn schedule_reload(&self, path: PathBuf) {
+        self.pending_changes.borrow_mut().insert(path);
+        if self.reload_pending.replace(true) {
+            return;
+        }
+        glib::timeout_add_local_once(
+            std::time::Duration::from_millis(300),
+            clone!(@weak self as obj => move || {
+                obj.reload_pending.set(false);
+                obj.process_pending_changes();
+            }),
+        );
+    }
+
+    fn process_pending_changes(&self) {
+        let paths = std::mem::take(&mut *self.pending_changes.borrow_mut());
+        let layers = self.all_layers.borrow().clone();
+        /* Handle contents.plist first, so that new glyph files are known before they are
+         * looked up. */
+        let (plists, others): (Vec<PathBuf>, Vec<PathBuf>) = paths
+            .into_iter()
+            .partition(|p| p.extension().and_then(|e| e.to_str()) == Some("plist"));
+        for path in plists {
+            let Some(file_name) = path.file_name().and_then(|f| f.to_str()) else {
+                continue;
+            };
+            let parent = path.parent();
+            if file_name == "fontinfo.plist" && parent == Some(self.path.borrow().as_path()) {
+                if let Err(err) = self.reload_fontinfo() {
+                    eprintln!("Could not reload {}: {err}", path.display());
+                }
+            } else if file_name == "contents.plist" {
+                if let Some(layer) = layers.iter().find(|l| Some(l.path().as_path()) == parent) {
+                    if let Err(err) = self.reload_contents(layer) {
+                        eprintln!("Could not reload {}: {err}", path.display());
+                    }
+                }
+            }
+        }
+        for path in others {
+            if path.extension().and_then(|e| e.to_str()) != Some("glif") {
+                continue;
+            }
+            let Some(layer) = layers
+                .iter()
+                .find(|l| Some(l.path().as_path()) == path.parent())
+            else {
+                continue;
+            };
+            let Some(file_name) = path.file_name().map(|f| f.to_string_lossy()) else {
+                continue;
+            };
+            let name = layer
+                .contents_plist
+                .borrow()
+                .glyphs()
+                .iter()
+                .find(|(_, f)| f.as_str() == file_name)
+                .map(|(n, _)| n.clone());
+            let Some(name) = name else {
+                continue;
+            };
+            /* Deleted glyphs are removed when contents.plist changes. */
+            let Ok(source) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let Some(glyph) = layer.glyphs().get(&name).cloned() else {
+                continue;
+            };
+            if *glyph.borrow().glif_source.borrow() == source {
+                /* Our own save, or a write that did not change anything. */
+                continue;
+            }
+            if is_modified_in_memory(&glyph.borrow()) {
+                self.emit_by_name::<()>(Self::GLYPH_CHANGED_ON_DISK, &[layer, &name]);
+            } else if let Err(err) = self.reload_glyph(layer, &name) {
+                eprintln!("Could not reload {}: {err}", path.display());
+            }
+        }
+        self.emit_by_name::<()>(Self::FILES_CHANGED, &[]);
+    }
+
+    /// Replaces glyph `name` of `layer` with its contents on disk. The glyph's `Rc` and metadata
+    /// object are kept so that open views and bindings see the change.
+    pub fn reload_glyph(
+        &self,
+        layer: &Layer,
+        name: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(filename) = layer.contents_plist.borrow().glyphs().get(name).cloned() else {
+            return Err(format!("Glyph `{name}` is not in layer {}.", layer.name.borrow()).into());
+        };
+        let path = layer.path().join(&filename);
+        let source = std::fs::read_to_string(&path)
+            .map_err(|err| format!("Couldn't read {}: {}", path.display(), err))?;
+        let glif = glif::Glif::from_str(&source)
+            .map_err(|err| format!("Couldn't parse {}: {}", path.display(), err))?;
+        let mut glyph = Glyph::from_glif_in_layer(glif, Some(layer));
+        *glyph.metadata.filename.borrow_mut() = filename;
+        *glyph.metadata.glif_source.borrow_mut() = source;
+        let existing = layer.glyphs().get(name).cloned();
+        if let Some(existing) = existing {
+            existing.borrow_mut().reload_from(glyph);
+        } else {
+            layer.link(&glyph.metadata);
+            let glyph = Rc::new(RefCell::new(glyph));
+            glyph
+                .borrow()
+                .metadata
+                .glyph_ref
+                .set(glyph.clone())
+                .unwrap();
+            layer.glyphs.borrow_mut().insert(name.to_string(), glyph);
+        }
+        self.emit_by_name::<()>(Self::GLYPH_RELOADED, &[layer, &name]);
+        Ok(())
+    }
+
+    /// Adds glyphs that were added to `contents.plist` and drops the ones that were removed,
+    /// unless they have unsaved changes.
+    fn reload_contents(&self, layer: &Layer) -> Result<(), Box<dyn std::error::Error>> {
+        let mut contents = ufo::Contents::from_path(&layer.path().join("contents.plist"), false)?;
+        let mut removed = vec![];
+        for (name, glyph) in layer.glyphs().iter() {
+            if contents.glyphs().contains_key(name) {
+                continue;
+            }
+            let glyph = glyph.borrow();
+            if is_modified_in_memory(&glyph) {
+                contents.insert(name.clone(), glyph.filename().to_string());
+            } else {
+                removed.push(name.clone());
+            }
+        }
+        let added = contents
+            .glyphs()
+            .keys()
+            .filter(|name| !layer.glyphs().contains_key(name.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        *layer.contents_plist.borrow_mut() = contents;
+        for name in removed {
+            layer.glyphs.borrow_mut().shift_remove(&name);
+            self.emit_by_name::<()>(Self::GLYPH_RELOADED, &[layer, &name]);
+        }
+        for name in added {
+            self.reload_glyph(layer, &name)?;
+        }
+        Ok(())
+    }
+
+    /// Reloads `fontinfo.plist` if it has no unsaved changes.
+    fn reload_fontinfo(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let fontinfo = self.fontinfo();
+        if fontinfo.modified() {
+            return Ok(());
+        }
+        let new = FontInfo::from_path(fontinfo.path().to_path_buf())?;
+        if *new.source.borrow() == *fontinfo.source.borrow() {
+            return Ok(());
+        }
+        let project_modified = self.property::<bool>(Self::MODIFIED);
+        for pspec in new.list_properties().iter() {
+            let name = pspec.name();
+            if name == FontInfo::MODIFIED || !pspec.flags().contains(glib::ParamFlags::WRITABLE) {
+                continue;
+            }
+            fontinfo.set_property_from_value(name, &new.property_value(name));
+        }
+        *fontinfo.source.borrow_mut() = new.source.borrow().clone();
+        *fontinfo.guidelines.borrow_mut() = new.guidelines.borrow().clone();
+        fontinfo.set_property(FontInfo::MODIFIED, false);
+        self.set_property(Self::MODIFIED, project_modified);
+        Ok(())
+    }
+}
+
+/// Whether `glyph` differs from what was last read from or written to its `.glif` file.
+///
+/// Both sides go through the same [`Glyph`] to [`glif::Glif`] conversion, so that details the
+/// conversion does not preserve do not count as modifications.
+pub fn is_modified_in_memory(glyph: &Glyph) -> bool {
+    if glyph.modified() {
+        return true;
+    }
+    let source = glyph.glif_source.borrow();
+    let Ok(on_disk) = glif::Glif::from_str(&source) else {
+        return true;
+    };
+    let on_disk: Glyph = on_disk.into();
+    glif::Glif::from(&on_disk).to_xml() != glif::Glif::from(glyph).to_xml()
+}
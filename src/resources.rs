@@ -100,6 +100,8 @@ pub mod icons {
     decl_icon! {BSPLINE_ICON, "./resources/b-spline-icon"}
     decl_icon! {RECTANGLE_ICON, "./resources/rectangle-icon"}
     decl_icon! {ELLIPSE_ICON, "./resources/ellipse-icon"}
+    decl_icon! {MEASURE_ICON, "./resources/measure-icon"}
+    decl_icon! {KNIFE_ICON, "./resources/knife-icon"}
     decl_icon! {RIGHT_MOUSE_BUTTON, "./resources/icons/right_mouse_button"}
     decl_icon! {LEFT_MOUSE_BUTTON, "./resources/icons/left_mouse_button"}
     decl_icon! {ESC_BUTTON, "./resources/icons/esc_button"}
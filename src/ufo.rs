@@ -27,6 +27,8 @@ pub mod import;
 pub mod constants;
 pub mod glif;
 pub mod objects;
+pub mod opentype;
+pub mod vertical_metrics;
 
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
@@ -125,6 +127,25 @@ pub struct FontInfo {
     /// Caret offset value. Corresponds to the OpenType hhea table caretOffset field.
     #[serde(default)]
     pub open_type_hhea_caret_offset: Option<f64>,
+    // OpenType vhea Table Fields
+    /// Ascender value. Corresponds to the OpenType vhea table vertTypoAscender field.
+    #[serde(default)]
+    pub open_type_vhea_vert_typo_ascender: Option<i64>,
+    /// Descender value. Corresponds to the OpenType vhea table vertTypoDescender field.
+    #[serde(default)]
+    pub open_type_vhea_vert_typo_descender: Option<i64>,
+    /// Line gap value. Corresponds to the OpenType vhea table vertTypoLineGap field.
+    #[serde(default)]
+    pub open_type_vhea_vert_typo_line_gap: Option<i64>,
+    /// Caret slope rise value. Corresponds to the OpenType vhea table caretSlopeRise field.
+    #[serde(default)]
+    pub open_type_vhea_caret_slope_rise: Option<i64>,
+    /// Caret slope run value. Corresponds to the OpenType vhea table caretSlopeRun field.
+    #[serde(default)]
+    pub open_type_vhea_caret_slope_run: Option<i64>,
+    /// Caret offset value. Corresponds to the OpenType vhea table caretOffset field.
+    #[serde(default)]
+    pub open_type_vhea_caret_offset: Option<i64>,
     // OpenType Name Table Fields
     /// Designer name. Corresponds to the OpenType name table name ID 9.
     #[serde(default)]
@@ -337,6 +358,49 @@ pub struct FontInfo {
     /// integer   Strikeout position. Corresponds to the OpenType OS/2 table yStrikeoutPosition field.
     #[serde(default, rename = "openTypeOS2StrikeoutPosition")]
     pub open_type_os2_strikeout_position: Option<i64>,
+
+    // Macintosh FOND Resource Data
+    /// Family ID number. Corresponds to the ffFamID in the FOND resource.
+    #[serde(default, rename = "macintoshFONDFamilyID")]
+    pub macintosh_fond_family_id: Option<i64>,
+    /// Font name for the FOND resource.
+    #[serde(default, rename = "macintoshFONDName")]
+    pub macintosh_fond_name: Option<String>,
+
+    // WOFF Data
+    /// Major version of the font.
+    #[serde(default)]
+    pub woff_major_version: Option<u64>,
+    /// Minor version of the font.
+    #[serde(default)]
+    pub woff_minor_version: Option<u64>,
+    /// Identification string. Corresponds to the WOFF uniqueid.
+    #[serde(default, rename = "woffMetadataUniqueID")]
+    pub woff_metadata_unique_id: Option<plist::Dictionary>,
+    /// Font vendor. Corresponds to the WOFF vendor element.
+    #[serde(default)]
+    pub woff_metadata_vendor: Option<plist::Dictionary>,
+    /// Font credits. Corresponds to the WOFF credits element.
+    #[serde(default)]
+    pub woff_metadata_credits: Option<plist::Dictionary>,
+    /// Font description. Corresponds to the WOFF description element.
+    #[serde(default)]
+    pub woff_metadata_description: Option<plist::Dictionary>,
+    /// Font license. Corresponds to the WOFF license element.
+    #[serde(default)]
+    pub woff_metadata_license: Option<plist::Dictionary>,
+    /// Font copyright. Corresponds to the WOFF copyright element.
+    #[serde(default)]
+    pub woff_metadata_copyright: Option<plist::Dictionary>,
+    /// Font trademark. Corresponds to the WOFF trademark element.
+    #[serde(default)]
+    pub woff_metadata_trademark: Option<plist::Dictionary>,
+    /// Font licensee. Corresponds to the WOFF licensee element.
+    #[serde(default)]
+    pub woff_metadata_licensee: Option<plist::Dictionary>,
+    /// List of metadata extension records. Corresponds to the WOFF extension elements.
+    #[serde(default)]
+    pub woff_metadata_extensions: Option<Vec<plist::Dictionary>>,
 }
 
 /// gasp Range Record Format
@@ -348,15 +412,18 @@ pub struct FontInfo {
 #[derive(Default, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenTypeGaspRangeRecord {
+    /// The upper limit of the range, in PPEM. If any records are in the list, the final record
+    /// should use 65535 (0xFFFF) as defined in the OpenType gasp specification.
     #[serde(rename = "rangeMaxPPEM")]
-    range_max_ppem: u8,
+    pub range_max_ppem: u16,
+    /// A list of bit numbers indicating the flags to be set.
     #[serde(default)]
-    range_gasp_behavior: Vec<RangeGaspBehavior>,
+    pub range_gasp_behavior: Vec<RangeGaspBehavior>,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
 pub enum RangeGaspBehavior {
     /// 0x0001
     GASP_GRIDFIT = 0,
@@ -368,29 +435,55 @@ pub enum RangeGaspBehavior {
     GASP_SYMMETRIC_SMOOTHING = 3,
 }
 
+impl RangeGaspBehavior {
+    pub const ALL: [Self; 4] = [
+        Self::GASP_GRIDFIT,
+        Self::GASP_DOGRAY,
+        Self::GASP_SYMMETRIC_GRIDFIT,
+        Self::GASP_SYMMETRIC_SMOOTHING,
+    ];
+}
+
+impl TryFrom<u8> for RangeGaspBehavior {
+    type Error = String;
+
+    fn try_from(bit: u8) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|b| *b as u8 == bit)
+            .ok_or_else(|| format!("{bit} is not a valid gasp behavior bit number."))
+    }
+}
+
+impl From<RangeGaspBehavior> for u8 {
+    fn from(val: RangeGaspBehavior) -> Self {
+        val as u8
+    }
+}
+
 /// Name Record Format
 #[derive(Default, PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NameRecord {
     /// The name ID.
-    #[serde(default)]
-    name_id: Option<u64>,
+    #[serde(default, rename = "nameID")]
+    pub name_id: Option<u64>,
     /// The platform ID.
-    #[serde(default)]
-    platform_id: Option<u64>,
+    #[serde(default, rename = "platformID")]
+    pub platform_id: Option<u64>,
     /// The encoding ID.
-    #[serde(default)]
-    encoding_id: Option<u64>,
+    #[serde(default, rename = "encodingID")]
+    pub encoding_id: Option<u64>,
     /// The language ID.
-    #[serde(default)]
-    language_id: Option<u64>,
+    #[serde(default, rename = "languageID")]
+    pub language_id: Option<u64>,
     /// The string value for the record.
     #[serde(default)]
-    string: Option<String>,
+    pub string: Option<String>,
 }
 
-#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
 pub enum PostscriptWindowsCharacterSet {
     ANSI = 1,
     Default = 2,
@@ -414,6 +507,48 @@ pub enum PostscriptWindowsCharacterSet {
     OEM = 20,
 }
 
+impl PostscriptWindowsCharacterSet {
+    pub const ALL: [Self; 20] = [
+        Self::ANSI,
+        Self::Default,
+        Self::Symbol,
+        Self::Macintosh,
+        Self::ShiftJIS,
+        Self::Hangul,
+        Self::HangulJohab,
+        Self::GB2312,
+        Self::ChineseBIG5,
+        Self::Greek,
+        Self::Turkish,
+        Self::Vietnamese,
+        Self::Hebrew,
+        Self::Arabic,
+        Self::Baltic,
+        Self::Bitstream,
+        Self::Cyrillic,
+        Self::Thai,
+        Self::EuropeanEastern,
+        Self::OEM,
+    ];
+}
+
+impl TryFrom<u8> for PostscriptWindowsCharacterSet {
+    type Error = String;
+
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|c| *c as u8 == val)
+            .ok_or_else(|| format!("{val} is not a valid Windows character set value."))
+    }
+}
+
+impl From<PostscriptWindowsCharacterSet> for u8 {
+    fn from(val: PostscriptWindowsCharacterSet) -> Self {
+        val as u8
+    }
+}
+
 #[derive(Default, PartialEq, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GuidelineInfo {
@@ -461,6 +596,225 @@ impl FontInfo {
         plist::to_writer_xml_with_options(file, self, &opts)?;
         Ok(())
     }
+
+    /// Checks the values against the restrictions of the UFO 3 fontinfo specification. All
+    /// problems found are reported, one per line.
+    pub fn validate(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let problems = self.problems();
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems.join("\n").into())
+        }
+    }
+
+    /// The problems found by [`FontInfo::validate`]. Each starts with the key of the field it
+    /// is about, followed by a colon.
+    pub fn problems(&self) -> Vec<String> {
+        use opentype::*;
+
+        let mut problems: Vec<String> = vec![];
+        macro_rules! check_bits {
+            ($field:expr, $table:expr, $name:literal) => {{
+                let invalid = invalid_bits($field, $table);
+                if !invalid.is_empty() {
+                    problems.push(format!("{}: invalid bit numbers {:?}.", $name, invalid));
+                }
+            }};
+        }
+        macro_rules! check_len {
+            ($field:expr, $max:literal, even, $name:literal) => {{
+                if let Some(list) = $field.as_ref() {
+                    if list.len() > $max || list.len() % 2 != 0 {
+                        problems.push(format!(
+                            "{}: must contain an even number of values, at most {}.",
+                            $name, $max
+                        ));
+                    }
+                }
+            }};
+            ($field:expr, $max:literal, $name:literal) => {{
+                if let Some(list) = $field.as_ref() {
+                    if list.len() > $max {
+                        problems.push(format!("{}: must contain at most {} values.", $name, $max));
+                    }
+                }
+            }};
+        }
+
+        if !matches!(
+            self.style_map_style_name.as_str(),
+            "" | "regular" | "italic" | "bold" | "bold italic"
+        ) {
+            problems.push(
+                "styleMapStyleName: must be one of regular, italic, bold or bold italic."
+                    .to_string(),
+            );
+        }
+        if matches!(self.units_per_em, Some(upm) if upm <= 0.0) {
+            problems.push("unitsPerEm: must be a positive number.".to_string());
+        }
+        if let Some(created) = self.open_type_head_created.as_ref() {
+            if !is_valid_head_created(created) {
+                problems.push(
+                    "openTypeHeadCreated: must be of the format YYYY/MM/DD HH:MM:SS.".to_string(),
+                );
+            }
+        }
+        check_bits!(&self.open_type_head_flags, HEAD_FLAGS, "openTypeHeadFlags");
+        if let Some(records) = self.open_type_gasp_range_records.as_ref() {
+            if records
+                .windows(2)
+                .any(|w| w[0].range_max_ppem >= w[1].range_max_ppem)
+            {
+                problems.push(
+                    "openTypeGaspRangeRecords: records must be sorted in ascending order of rangeMaxPPEM."
+                        .to_string(),
+                );
+            }
+            if matches!(records.last(), Some(r) if r.range_max_ppem != u16::MAX) {
+                problems.push(
+                    "openTypeGaspRangeRecords: the last record must have a rangeMaxPPEM of 65535."
+                        .to_string(),
+                );
+            }
+        }
+        for (i, r) in self
+            .open_type_name_records
+            .iter()
+            .flat_map(|r| r.iter())
+            .enumerate()
+        {
+            if r.name_id.is_none()
+                || r.platform_id.is_none()
+                || r.encoding_id.is_none()
+                || r.language_id.is_none()
+                || r.string.is_none()
+            {
+                problems.push(format!(
+                    "openTypeNameRecords: record {} must define nameID, platformID, encodingID, languageID and string.",
+                    i + 1
+                ));
+            }
+        }
+        if matches!(self.open_type_os2_width_class, Some(w) if !(1..=9).contains(&w)) {
+            problems.push("openTypeOS2WidthClass: must be in the range 1-9.".to_string());
+        }
+        if matches!(self.open_type_os2_weight_class, Some(w) if !(1..=1000).contains(&w)) {
+            problems.push("openTypeOS2WeightClass: must be in the range 1-1000.".to_string());
+        }
+        if let Some(selection) = self.open_type_os2_selection.as_ref() {
+            check_bits!(selection, OS2_SELECTION, "openTypeOS2Selection");
+        }
+        if let Some(vendor_id) = self.open_type_os2_vendor_id.as_ref() {
+            if vendor_id.len() > 4 || !vendor_id.chars().all(|c| c.is_ascii_graphic() || c == ' ') {
+                problems.push(
+                    "openTypeOS2VendorID: must be at most four printable ASCII characters."
+                        .to_string(),
+                );
+            }
+        }
+        if matches!(self.open_type_os2_panose.as_ref(), Some(p) if p.len() != 10) {
+            problems.push("openTypeOS2Panose: must contain 10 values.".to_string());
+        }
+        if let Some(class) = self.open_type_os2_family_class.as_ref() {
+            if !matches!(class.as_slice(), [c, s] if *c <= 14 && *s <= 15) {
+                problems.push(
+                    "openTypeOS2FamilyClass: must contain a class ID in the range 0-14 and a subclass ID in the range 0-15."
+                        .to_string(),
+                );
+            }
+        }
+        if let Some(ranges) = self.open_type_os2_unicode_ranges.as_ref() {
+            check_bits!(ranges, OS2_UNICODE_RANGES, "openTypeOS2UnicodeRanges");
+        }
+        if let Some(ranges) = self.open_type_os2_code_page_ranges.as_ref() {
+            check_bits!(ranges, OS2_CODE_PAGE_RANGES, "openTypeOS2CodePageRanges");
+        }
+        if let Some(type_) = self.open_type_os2_type.as_ref() {
+            check_bits!(type_, OS2_TYPE, "openTypeOS2Type");
+            if type_.iter().filter(|b| (1..=3).contains(*b)).count() > 1 {
+                problems.push(
+                    "openTypeOS2Type: only one of the bits 1, 2 and 3 may be set.".to_string(),
+                );
+            }
+        }
+        check_len!(
+            self.postscript_blue_values,
+            14,
+            even,
+            "postscriptBlueValues"
+        );
+        check_len!(
+            self.postscript_other_blues,
+            10,
+            even,
+            "postscriptOtherBlues"
+        );
+        check_len!(
+            self.postscript_family_blues,
+            14,
+            even,
+            "postscriptFamilyBlues"
+        );
+        check_len!(
+            self.postscript_family_other_blues,
+            10,
+            even,
+            "postscriptFamilyOtherBlues"
+        );
+        check_len!(self.postscript_stem_snap_h, 12, "postscriptStemSnapH");
+        check_len!(self.postscript_stem_snap_v, 12, "postscriptStemSnapV");
+        if let Some(name) = self.postscript_font_name.as_ref() {
+            if name.len() > 63
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_graphic() && !"[](){}<>/%".contains(c))
+            {
+                problems.push(
+                    "postscriptFontName: must be at most 63 printable ASCII characters, without spaces or any of [](){}<>/%."
+                        .to_string(),
+                );
+            }
+        }
+        problems
+    }
+}
+
+/// Whether `value` is a date of the format “YYYY/MM/DD HH:MM:SS”, as required by
+/// `openTypeHeadCreated`.
+fn is_valid_head_created(value: &str) -> bool {
+    let Some((date, time)) = value.split_once(' ') else {
+        return false;
+    };
+    let parse = |s: &str, sep: char, lens: [usize; 3]| -> Option<[u32; 3]> {
+        let parts = s.split(sep).collect::<Vec<&str>>();
+        if parts.len() != 3 {
+            return None;
+        }
+        let mut ret = [0; 3];
+        for ((p, len), r) in parts.iter().zip(lens).zip(ret.iter_mut()) {
+            if p.len() != len || !p.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            *r = p.parse().ok()?;
+        }
+        Some(ret)
+    };
+    let (Some([year, month, day]), Some([hour, minute, second])) =
+        (parse(date, '/', [4, 2, 2]), parse(time, ':', [2, 2, 2]))
+    else {
+        return false;
+    };
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let month_days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=month_days).contains(&day) && hour <= 23 && minute <= 59 && second <= 59
 }
 
 /// contents.plist
@@ -576,6 +930,69 @@ impl Contents {
         self.glyphs.insert(name, filename);
         self.modified = true;
     }
+
+    /// Renames the entry of glyph `old`, keeping its file name.
+    pub fn rename(&mut self, old: &str, new: String) -> Option<()> {
+        let filename = self.glyphs.shift_remove(old)?;
+        self.glyphs.insert(new, filename);
+        self.modified = true;
+        Some(())
+    }
+
+    /// A file name for a new glyph `name` that no file name of the layer clashes with, even on
+    /// case-insensitive file systems.
+    ///
+    /// Follows the user name to file name convention of UFO 3: illegal characters become `_`,
+    /// uppercase letters are followed by `_`, reserved names and a leading period are escaped
+    /// and clashes get a 15 digit counter.
+    ///
+    /// <https://unifiedfontobject.org/versions/ufo3/conventions/#user-name-to-file-name-convention>
+    pub fn new_filename(&self, name: &str) -> String {
+        const SUFFIX: &str = ".glif";
+        const MAX_LENGTH: usize = 255;
+        const ILLEGAL: &[char] = &['"', '*', '+', '/', ':', '<', '>', '?', '[', '\\', ']', '|'];
+        const RESERVED: &[&str] = &[
+            "con", "prn", "aux", "clock$", "nul", "a:-z:", "com1", "lpt1", "lpt2", "lpt3", "com2",
+            "com3", "com4",
+        ];
+        let existing = self
+            .glyphs
+            .values()
+            .map(|f| f.to_lowercase())
+            .collect::<std::collections::HashSet<String>>();
+        let mut escaped = String::with_capacity(name.len());
+        for (i, c) in name.chars().enumerate() {
+            if (i == 0 && c == '.') || c.is_control() || ILLEGAL.contains(&c) {
+                escaped.push('_');
+            } else if c.to_lowercase().ne(std::iter::once(c)) {
+                escaped.push(c);
+                escaped.push('_');
+            } else {
+                escaped.push(c);
+            }
+        }
+        let truncate = |s: String, len: usize| s.chars().take(len).collect::<String>();
+        let escaped = truncate(escaped, MAX_LENGTH - SUFFIX.len())
+            .split('.')
+            .map(|part| {
+                if RESERVED.contains(&part.to_lowercase().as_str()) {
+                    format!("_{part}")
+                } else {
+                    part.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(".");
+        let filename = format!("{escaped}{SUFFIX}");
+        if !existing.contains(&filename.to_lowercase()) {
+            return filename;
+        }
+        let escaped = truncate(escaped, MAX_LENGTH - SUFFIX.len() - 15);
+        (1_u64..)
+            .map(|counter| format!("{escaped}{counter:015}{SUFFIX}"))
+            .find(|f| !existing.contains(&f.to_lowercase()))
+            .unwrap()
+    }
 }
 
 /// metainfo.plist
@@ -656,6 +1073,9 @@ pub struct LayerContents {
     pub layers: IndexMap<String, String>,
     #[serde(skip)]
     pub objects: IndexMap<String, objects::Layer>,
+    /// Whether layers were added, renamed or removed since the file was read.
+    #[serde(skip)]
+    pub modified: bool,
 }
 
 impl Default for LayerContents {
@@ -665,6 +1085,7 @@ impl Default for LayerContents {
         Self {
             layers,
             objects: IndexMap::default(),
+            modified: false,
         }
     }
 }
@@ -753,6 +1174,7 @@ impl LayerContents {
         let mut ret = Self {
             objects: IndexMap::with_capacity(layers.len()),
             layers,
+            modified: false,
         };
         fn validate_fn(
             ret: &LayerContents,
@@ -865,6 +1287,116 @@ impl LayerContents {
         plist::to_writer_xml_with_options(file, self, &opts)?;
         Ok(())
     }
+
+    /// Checks that `name` can be given to a new or renamed layer.
+    pub fn validate_name(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if name.is_empty() {
+            return Err("Layer names must be at least one character long.".into());
+        }
+        if self.layers.contains_key(name) {
+            return Err(Self::new_duplicate_names_err(name).into());
+        }
+        if name.starts_with("public.") && name != "public.background" {
+            return Err(Self::new_starts_with_public(name).into());
+        }
+        Ok(())
+    }
+
+    /// A directory name for layer `name`, made with the user name to file name convention of
+    /// the UFO specification and unique among the directories of the other layers.
+    ///
+    /// > Illegal characters are replaced by an underscore, and uppercase letters are followed
+    /// > by an underscore. A number is appended if the result clashes case insensitively with
+    /// > an existing name.
+    pub fn new_dir_name(&self, name: &str) -> String {
+        let mut dir_name = String::from("glyphs.");
+        for (i, c) in name.chars().enumerate() {
+            match c {
+                '.' if i == 0 => dir_name.push('_'),
+                '"' | '*' | '+' | '/' | ':' | '<' | '>' | '?' | '[' | '\\' | ']' | '|' => {
+                    dir_name.push('_')
+                }
+                c if c.is_control() => dir_name.push('_'),
+                c if c.is_uppercase() => {
+                    dir_name.push(c);
+                    dir_name.push('_');
+                }
+                c => dir_name.push(c),
+            }
+        }
+        let existing = self
+            .layers
+            .values()
+            .map(|d| d.to_lowercase())
+            .collect::<std::collections::HashSet<String>>();
+        if !existing.contains(&dir_name.to_lowercase()) {
+            return dir_name;
+        }
+        (1..)
+            .map(|n| format!("{dir_name}{n:015}"))
+            .find(|d| !existing.contains(&d.to_lowercase()))
+            .unwrap()
+    }
+
+    /// Adds `layer` after the existing layers.
+    pub fn insert_layer(&mut self, layer: objects::Layer) {
+        let name = layer.name.borrow().clone();
+        self.layers
+            .insert(name.clone(), layer.dir_name.borrow().clone());
+        self.objects.insert(name, layer);
+        self.modified = true;
+    }
+
+    /// Renames layer `old` in place. The layer keeps its directory.
+    pub fn rename_layer(
+        &mut self,
+        old: &str,
+        new: String,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if old == "public.default" {
+            return Err("The default layer cannot be renamed.".into());
+        }
+        if !self.layers.contains_key(old) {
+            return Err(format!("Layer `{old}` does not exist.").into());
+        }
+        self.validate_name(&new)?;
+        fn rename_key<V>(map: &mut IndexMap<String, V>, old: &str, new: &str) {
+            *map = std::mem::take(map)
+                .into_iter()
+                .map(|(k, v)| {
+                    if k == old {
+                        (new.to_string(), v)
+                    } else {
+                        (k, v)
+                    }
+                })
+                .collect();
+        }
+        rename_key(&mut self.layers, old, &new);
+        rename_key(&mut self.objects, old, &new);
+        if let Some(layer) = self.objects.get(&new) {
+            use gtk::glib::prelude::ObjectExt;
+
+            layer.set_property(objects::Layer::NAME, new.clone());
+        }
+        self.modified = true;
+        Ok(())
+    }
+
+    /// Removes layer `name` and returns it, so that its directory can be deleted.
+    pub fn remove_layer(
+        &mut self,
+        name: &str,
+    ) -> Result<Option<objects::Layer>, Box<dyn std::error::Error>> {
+        if name == "public.default" {
+            return Err("The default layer cannot be deleted.".into());
+        }
+        if self.layers.shift_remove(name).is_none() {
+            return Err(format!("Layer `{name}` does not exist.").into());
+        }
+        self.modified = true;
+        Ok(self.objects.shift_remove(name))
+    }
 }
 
 /// lib.plist
@@ -895,6 +1427,251 @@ impl Lib {
     }
 }
 
+/// Applies glyph renames, made one after another, to the font level files of the UFO at `path`:
+/// the pairs of `kerning.plist`, the members of `groups.plist`, the glyph names of
+/// `features.fea` and the `public.glyphOrder`, `public.postscriptNames` and
+/// `public.openTypeCategories` entries of `lib.plist`. Each name in the files is looked up
+/// once in the combined renames, so swaps and chains apply correctly. Files that do not change
+/// are not written.
+pub fn rename_glyphs_in_font_files(
+    path: &Path,
+    renames: &[(String, String)],
+) -> Result<(), Box<dyn std::error::Error>> {
+    use plist::{Dictionary, Value};
+
+    if renames.is_empty() {
+        return Ok(());
+    }
+    let renames = combined_renames(renames);
+    let rename = |name: &str| {
+        renames
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| name.to_string())
+    };
+    let rename_keys =
+        |d: Dictionary| -> Dictionary { d.into_iter().map(|(k, v)| (rename(&k), v)).collect() };
+    let rename_strings = |v: Value| match v {
+        Value::Array(a) => Value::Array(
+            a.into_iter()
+                .map(|v| match v {
+                    Value::String(s) => Value::String(rename(&s)),
+                    v => v,
+                })
+                .collect(),
+        ),
+        v => v,
+    };
+    let kerning = |v: Value| match v {
+        Value::Dictionary(d) => Value::Dictionary(
+            rename_keys(d)
+                .into_iter()
+                .map(|(k, v)| match v {
+                    Value::Dictionary(d) => (k, Value::Dictionary(rename_keys(d))),
+                    v => (k, v),
+                })
+                .collect(),
+        ),
+        v => v,
+    };
+    let groups = |v: Value| match v {
+        Value::Dictionary(d) => {
+            Value::Dictionary(d.into_iter().map(|(k, v)| (k, rename_strings(v))).collect())
+        }
+        v => v,
+    };
+    let lib = |v: Value| match v {
+        Value::Dictionary(d) => Value::Dictionary(
+            d.into_iter()
+                .map(|(k, v)| match (k.as_str(), v) {
+                    ("public.glyphOrder", v) => (k, rename_strings(v)),
+                    (
+                        "public.postscriptNames" | "public.openTypeCategories",
+                        Value::Dictionary(d),
+                    ) => (k, Value::Dictionary(rename_keys(d))),
+                    (_, v) => (k, v),
+                })
+                .collect(),
+        ),
+        v => v,
+    };
+    let edits: [(&str, &dyn Fn(Value) -> Value); 3] = [
+        ("kerning.plist", &kerning),
+        ("groups.plist", &groups),
+        ("lib.plist", &lib),
+    ];
+    for (file, edit) in edits {
+        let file = path.join(file);
+        if !file.exists() {
+            continue;
+        }
+        let value = Value::from_file(&file)
+            .map_err(|err| format!("Could not read {}: {err}", file.display()))?;
+        let new_value = edit(value.clone());
+        if new_value != value {
+            #[allow(deprecated)]
+            let opts = plist::XmlWriteOptions::default()
+                .indent_string("    ")
+                .root_element(true);
+            let writer = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&file)?;
+            plist::to_writer_xml_with_options(writer, &new_value, &opts)?;
+        }
+    }
+    let file = path.join("features.fea");
+    if file.exists() {
+        let features = std::fs::read_to_string(&file)?;
+        let new_features = rename_in_features(&features, rename);
+        if new_features != features {
+            std::fs::write(&file, new_features)?;
+        }
+    }
+    Ok(())
+}
+
+/// The final name of each glyph that `renames`, made one after another, renamed, by its
+/// original name.
+fn combined_renames(renames: &[(String, String)]) -> IndexMap<String, String> {
+    /* Current name to original name. */
+    let mut originals: IndexMap<String, String> = IndexMap::new();
+    for (old, new) in renames {
+        let original = originals.shift_remove(old).unwrap_or_else(|| old.clone());
+        originals.insert(new.clone(), original);
+    }
+    originals
+        .into_iter()
+        .filter(|(name, original)| name != original)
+        .map(|(name, original)| (original, name))
+        .collect()
+}
+
+/// `features` with each glyph name replaced by `rename(name)`. Class names, comments and
+/// strings are left as they are.
+fn rename_in_features(features: &str, rename: impl Fn(&str) -> String) -> String {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-');
+    let mut ret = String::with_capacity(features.len());
+    let mut rest = features;
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            '#' => rest.find('\n').unwrap_or(rest.len()),
+            '"' => rest[1..].find('"').map_or(rest.len(), |i| i + 2),
+            '@' => {
+                1 + rest[1..]
+                    .find(|c| !is_name_char(c))
+                    .unwrap_or(rest.len() - 1)
+            }
+            c if is_name_char(c) => {
+                let len = rest.find(|c| !is_name_char(c)).unwrap_or(rest.len());
+                ret.push_str(&rename(&rest[..len]));
+                rest = &rest[len..];
+                continue;
+            }
+            c => c.len_utf8(),
+        };
+        ret.push_str(&rest[..len]);
+        rest = &rest[len..];
+    }
+    ret
+}
+
+#[test]
+fn test_rename_in_features() {
+    let features = r#"@a = [a b \a.sc a-z];
+# sub a by b;
+feature ss01 {
+    featureNames { name "a"; };
+    sub a' b by a.alt;
+    sub @a by \a;
+} ss01;
+"#;
+    let renamed = rename_in_features(features, |n| {
+        if n == "a" {
+            "uni0061".to_string()
+        } else {
+            n.to_string()
+        }
+    });
+    assert_eq!(
+        renamed,
+        r#"@a = [uni0061 b \a.sc a-z];
+# sub a by b;
+feature ss01 {
+    featureNames { name "a"; };
+    sub uni0061' b by a.alt;
+    sub @a by \uni0061;
+} ss01;
+"#
+    );
+}
+
+#[test]
+fn test_rename_glyphs_in_font_files() {
+    let path = std::env::temp_dir().join(format!("gerb-renames-{}", std::process::id()));
+    std::fs::create_dir_all(&path).unwrap();
+    let plist = |body: &str| {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple Computer//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+{body}
+</dict>
+</plist>
+"#
+        )
+    };
+    std::fs::write(
+        path.join("kerning.plist"),
+        plist("<key>a</key><dict><key>b</key><integer>-10</integer></dict>"),
+    )
+    .unwrap();
+    std::fs::write(
+        path.join("groups.plist"),
+        plist("<key>public.kern1.ab</key><array><string>a</string><string>b</string><string>c</string></array>"),
+    )
+    .unwrap();
+    std::fs::write(path.join("features.fea"), "sub a by b;\nsub c by a;\n").unwrap();
+    /* `a` and `b` are swapped through `tmp`, then `c` takes the name `d`. */
+    let renames = [("a", "tmp"), ("b", "a"), ("tmp", "b"), ("c", "d")]
+        .map(|(old, new)| (old.to_string(), new.to_string()));
+    rename_glyphs_in_font_files(&path, &renames).unwrap();
+
+    let dict = |file: &str| {
+        plist::Value::from_file(path.join(file))
+            .unwrap()
+            .into_dictionary()
+            .unwrap()
+    };
+    let kerning = dict("kerning.plist");
+    assert_eq!(kerning.keys().collect::<Vec<_>>(), vec!["b"]);
+    assert_eq!(
+        kerning["b"]
+            .as_dictionary()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>(),
+        vec!["a"]
+    );
+    let groups = dict("groups.plist");
+    assert_eq!(
+        groups["public.kern1.ab"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_string().unwrap())
+            .collect::<Vec<_>>(),
+        vec!["b", "a", "d"]
+    );
+    assert_eq!(
+        std::fs::read_to_string(path.join("features.fea")).unwrap(),
+        "sub b by a;\nsub d by b;\n"
+    );
+    std::fs::remove_dir_all(&path).unwrap();
+}
+
 #[test]
 fn test_fontinfo_plist_parse() {
     let p: FontInfo = FontInfo::new_from_str(PLIST).unwrap();
@@ -940,6 +1717,36 @@ fn test_fontinfo_plist_parse() {
     );
 }
 
+#[test]
+fn test_contents_new_filename() {
+    let mut c = Contents::default();
+    for (name, filename) in [
+        ("a", "a.glif"),
+        ("A", "A_.glif"),
+        ("AE", "A_E_.glif"),
+        ("aE", "aE_.glif"),
+        ("A.alt", "A_.alt.glif"),
+        ("T_H", "T__H_.glif"),
+        ("f_f_i", "f_f_i.glif"),
+        (".notdef", "_notdef.glif"),
+        ("con", "_con.glif"),
+        ("CON", "C_O_N_.glif"),
+        ("alt.con", "alt._con.glif"),
+        ("a/b", "a_b.glif"),
+    ] {
+        assert_eq!(c.new_filename(name), filename, "{name}");
+    }
+    /* `aacute` was renamed, and keeps its file name. */
+    c.insert("uni00E1".to_string(), "aacute.glif".to_string());
+    assert_eq!(c.new_filename("aacute"), "aacute000000000000001.glif");
+    c.insert("aacute".to_string(), c.new_filename("aacute"));
+    assert_eq!(c.new_filename("aacute"), "aacute000000000000002.glif");
+    /* File names differing only in case clash on case-insensitive file systems. */
+    c.insert("x".to_string(), "B_.glif".to_string());
+    assert_eq!(c.new_filename("B"), "B_000000000000001.glif");
+    assert_eq!(c.new_filename("b_"), "b_000000000000001.glif");
+}
+
 #[test]
 fn test_metainfo_plist_parse() {
     let m: MetaInfo = MetaInfo::new_from_str(
@@ -1148,6 +1955,44 @@ fn test_layercontents_plist_parse() {
     }
 }
 
+#[test]
+fn test_layercontents_edit() {
+    let mut l = LayerContents::default();
+    assert_eq!(l.new_dir_name("Sketches"), "glyphs.S_ketches");
+    assert_eq!(l.new_dir_name(".a/b"), "glyphs._a_b");
+    l.layers
+        .insert("Sketches".to_string(), "glyphs.S_ketches".to_string());
+    assert_eq!(l.new_dir_name("sketches"), "glyphs.sketches");
+    assert_eq!(
+        l.new_dir_name("Sketches"),
+        "glyphs.S_ketches000000000000001"
+    );
+    assert!(l.validate_name("").is_err());
+    assert!(l.validate_name("Sketches").is_err());
+    assert!(l.validate_name("public.foreground").is_err());
+    assert!(l.validate_name("public.background").is_ok());
+
+    l.layers
+        .insert("Bold".to_string(), "glyphs.B_old".to_string());
+    l.rename_layer("Sketches", "Drafts".to_string()).unwrap();
+    assert!(l.modified);
+    assert_eq!(
+        l.layers.keys().collect::<Vec<_>>(),
+        vec!["public.default", "Drafts", "Bold"]
+    );
+    assert_eq!(l.layers["Drafts"], "glyphs.S_ketches");
+    assert!(l
+        .rename_layer("public.default", "Regular".to_string())
+        .is_err());
+    assert!(l.rename_layer("Bold", "Drafts".to_string()).is_err());
+    assert!(l.remove_layer("public.default").is_err());
+    l.remove_layer("Drafts").unwrap();
+    assert_eq!(
+        l.layers.keys().collect::<Vec<_>>(),
+        vec!["public.default", "Bold"]
+    );
+}
+
 #[test]
 fn test_lib_plist_parse() {
     let l: Lib = Lib::new_from_str(
@@ -1244,6 +2089,51 @@ fn test_plist_write() {
     assert_eq!(p, p2);
 }
 
+#[test]
+fn test_fontinfo_validate() {
+    let mut p: FontInfo = FontInfo::new_from_str(PLIST).unwrap();
+    p.validate().unwrap();
+    p.open_type_head_created = Some("2023/02/29 10:00:00".to_string());
+    p.open_type_os2_selection = Some(vec![0, 7]);
+    p.open_type_os2_panose = Some(vec![0; 9]);
+    p.open_type_gasp_range_records = Some(vec![
+        OpenTypeGaspRangeRecord {
+            range_max_ppem: 8,
+            range_gasp_behavior: vec![RangeGaspBehavior::GASP_DOGRAY],
+        },
+        OpenTypeGaspRangeRecord {
+            range_max_ppem: u16::MAX,
+            range_gasp_behavior: RangeGaspBehavior::ALL.to_vec(),
+        },
+    ]);
+    let err = p.validate().unwrap_err().to_string();
+    assert_eq!(err.lines().count(), 3, "{err}");
+    assert!(err.contains("openTypeHeadCreated"));
+    assert!(err.contains("openTypeOS2Selection: invalid bit numbers [0]."));
+    assert!(err.contains("openTypeOS2Panose"));
+    p.open_type_head_created = Some("2024/02/29 10:00:00".to_string());
+    p.open_type_os2_selection = Some(vec![7]);
+    p.open_type_os2_panose = Some(vec![0; 10]);
+    p.validate().unwrap();
+    p.open_type_head_flags = vec![0, 5, 10, 15];
+    assert_eq!(
+        p.problems(),
+        vec!["openTypeHeadFlags: invalid bit numbers [15].".to_string()]
+    );
+    p.open_type_head_flags = vec![0, 5, 10];
+    p.validate().unwrap();
+
+    #[allow(deprecated)]
+    let opts = plist::XmlWriteOptions::default()
+        .indent_string("  ")
+        .root_element(true);
+    let mut s = vec![];
+    plist::to_writer_xml_with_options(std::io::Cursor::new(&mut s), &p, &opts).unwrap();
+    let s = String::from_utf8(s).unwrap();
+    assert!(s.contains("<integer>65535</integer>"), "{s}");
+    assert_eq!(FontInfo::new_from_str(&s).unwrap(), p);
+}
+
 #[cfg(test)]
 const PLIST: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
 <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
@@ -30,6 +30,8 @@ use serde::{Deserialize, Serialize};
 use glib::subclass::types::ObjectSubclassIsExt;
 use indexmap::IndexMap;
 
+pub mod diff;
+
 fn color_serialize<S>(v: &Option<Color>, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -457,63 +459,64 @@ impl From<&glyphs::Glyph> for Glif {
             })
         }));
         outline.extend(glyph.contours.iter().map(|c| {
+            use crate::utils::curves::Bezier;
+            use glib::prelude::ObjectExt;
+
+            fn new_point(p: &crate::utils::CurvePoint, type_: PointKind) -> Point {
+                Point {
+                    x: p.position.x,
+                    y: p.position.y,
+                    name: None,
+                    identifier: None,
+                    type_,
+                    smooth: None,
+                }
+            }
+            /* An on-curve point is smooth if the curve that starts at it is. */
+            fn smooth(next: Option<&Bezier>) -> Option<bool> {
+                next.filter(|c| c.property::<bool>(Bezier::SMOOTH))
+                    .map(|_| true)
+            }
+            fn end_kind(degree: Option<usize>) -> PointKind {
+                match degree {
+                    Some(3) => PointKind::Curve,
+                    Some(2) => PointKind::Qcurve,
+                    _ => PointKind::Line,
+                }
+            }
+
             let mut point = vec![];
-            if c.imp().open.get() {
-                let mut first = true;
-                for curv in c.curves().iter() {
-                    let degree = curv.degree();
-                    point.extend(curv.points().iter().enumerate().map(|(i, cp)| Point {
-                        x: cp.position.x,
-                        y: cp.position.y,
-                        name: None,
-                        identifier: None,
-                        type_: if first {
-                            first = false;
-                            PointKind::Move
-                        } else {
-                            match (i, degree) {
-                                (0 | 3, Some(3)) => PointKind::Curve,
-                                (1 | 2, Some(3)) => PointKind::Offcurve,
-                                (0 | 2, Some(2)) => PointKind::Curve,
-                                (1, Some(2)) => PointKind::Qcurve,
-                                (0 | 1, Some(1)) => PointKind::Line,
-                                _ => PointKind::Move,
-                            }
-                        },
-                        smooth: None,
-                    }));
+            let open = c.imp().open.get();
+            let curves = c.curves();
+            /* Each curve contributes its handles and its end point; the start point of the
+             * contour comes first. In closed contours it is also the end of the last curve,
+             * and TrueType implied on-curve points are not written at all. */
+            if let Some(first) = curves.first().and_then(|c| c.points().first().cloned()) {
+                let last = curves.last().unwrap();
+                if open {
+                    point.push(new_point(&first, PointKind::Move));
+                } else if !last.property::<bool>(Bezier::IMPLIED_END) {
+                    point.push(Point {
+                        smooth: smooth(curves.first()),
+                        ..new_point(&first, end_kind(last.degree()))
+                    });
+                }
+            }
+            for (i, curv) in curves.iter().enumerate() {
+                let points = curv.points();
+                if points.len() < 2 {
+                    continue;
                 }
-            } else {
-                let mut last = false;
-                for curv in c.curves().iter() {
-                    let degree = curv.degree();
-                    point.extend(curv.points().iter().enumerate().filter_map(|(i, cp)| {
-                        if last {
-                            last = false;
-                            None
-                        } else {
-                            if Some(i + 1) == degree {
-                                last = true;
-                            }
-
-                            Some(Point {
-                                x: cp.position.x,
-                                y: cp.position.y,
-                                name: None,
-                                identifier: None,
-                                type_: match (i, degree) {
-                                    (0 | 3, Some(3)) => PointKind::Curve,
-                                    (1 | 2, Some(3)) => PointKind::Offcurve,
-                                    (0 | 2, Some(2)) => PointKind::Curve,
-                                    (1, Some(2)) => PointKind::Qcurve,
-                                    (0 | 1, Some(1)) => PointKind::Line,
-                                    _ => PointKind::Move,
-                                },
-                                smooth: None,
-                            })
-                        }
-                    }));
+                for handle in &points[1..points.len() - 1] {
+                    point.push(new_point(handle, PointKind::Offcurve));
                 }
+                if (!open && i + 1 == curves.len()) || curv.property::<bool>(Bezier::IMPLIED_END) {
+                    continue;
+                }
+                point.push(Point {
+                    smooth: smooth(curves.get(i + 1)),
+                    ..new_point(&points[points.len() - 1], end_kind(curv.degree()))
+                });
             }
             OutlineEntry::Contour(Contour {
                 identifier: None,
@@ -568,6 +571,57 @@ fn test_glif_write() {
     assert_eq!(g.to_xml(), g2.to_xml());
 }
 
+#[test]
+fn test_glif_qcurve_roundtrip() {
+    const QUADRATIC_GLIF: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<glyph name="q" format="2">
+	<advance width="300"/>
+	<outline>
+		<contour>
+			<point x="0" y="0" type="line"/>
+			<point x="0" y="100"/>
+			<point x="100" y="200"/>
+			<point x="200" y="100" type="qcurve" smooth="yes"/>
+			<point x="200" y="0" type="line"/>
+		</contour>
+	</outline>
+</glyph>
+"##;
+    fn points(g: &Glif) -> Vec<(f64, f64, PointKind, bool)> {
+        g.outline
+            .iter()
+            .flat_map(|o| o.contours.iter())
+            .filter_map(|e| match e {
+                OutlineEntry::Contour(c) => Some(
+                    c.point
+                        .iter()
+                        .map(|p| (p.x, p.y, p.type_, p.smooth == Some(true))),
+                ),
+                OutlineEntry::Component(_) => None,
+            })
+            .flatten()
+            .collect()
+    }
+    let glif: Glif = quick_xml::de::from_str(QUADRATIC_GLIF).unwrap();
+    let glyph: glyphs::Glyph = glif.clone().into();
+    assert_eq!(glyph.contours[0].curves().len(), 4);
+    let glif2 = Glif::from(&glyph);
+    assert_eq!(points(&glif), points(&glif2));
+
+    /* Smooth points, including the implied one, stay smooth through conversions. */
+    let contour = &glyph.contours[0];
+    contour.set_curves(contour.to_cubic_curves());
+    let cubic = points(&Glif::from(&glyph));
+    assert!(cubic.contains(&(50.0, 150.0, PointKind::Curve, true)));
+    assert!(cubic.contains(&(200.0, 100.0, PointKind::Curve, true)));
+    assert!(cubic.contains(&(200.0, 0.0, PointKind::Line, false)));
+    contour.set_curves(contour.to_quadratic_curves(0.01));
+    let quadratic = points(&Glif::from(&glyph));
+    assert!(quadratic.contains(&(50.0, 150.0, PointKind::Qcurve, true)));
+    assert!(quadratic.contains(&(200.0, 100.0, PointKind::Qcurve, true)));
+    assert!(quadratic.contains(&(200.0, 0.0, PointKind::Line, false)));
+}
+
 const _LOWERCASE_B_GLIF: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
 <glyph name="b" format="2">
 	<unicode hex="0062"/>
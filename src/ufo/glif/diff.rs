@@ -0,0 +1,623 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Structural differences between two versions of a `.glif` file.
+//!
+//! Contours are paired by identifier when both sides have one, otherwise by proximity of their
+//! centroids. Points inside paired contours are aligned with an edit distance so that a single
+//! inserted point does not show up as every following point having moved.
+
+use super::{Anchor, Component, Contour, Glif, OutlineEntry, Point, PointKind};
+use crate::prelude::*;
+
+/// Cost of inserting or deleting a point in the alignment. Substituting a point with one of the
+/// same kind at another position (a move) costs `1.0`, so a move is always preferred to a
+/// deletion followed by an insertion.
+const INDEL_COST: f64 = 1.2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PointDiff {
+    Added {
+        index: usize,
+        point: Point,
+    },
+    Removed {
+        index: usize,
+        point: Point,
+    },
+    Moved {
+        old_index: usize,
+        new_index: usize,
+        from: (f64, f64),
+        to: (f64, f64),
+    },
+    /// An on-curve point whose type changed, e.g. from `line` to `curve`.
+    Retyped {
+        old_index: usize,
+        new_index: usize,
+        at: (f64, f64),
+        from: PointKind,
+        to: PointKind,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContourDiff {
+    Added {
+        index: usize,
+        contour: Contour,
+    },
+    Removed {
+        index: usize,
+        contour: Contour,
+    },
+    Changed {
+        old_index: usize,
+        new_index: usize,
+        points: Vec<PointDiff>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentDiff {
+    Added(Component),
+    Removed(Component),
+    Transformed { old: Component, new: Component },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnchorDiff {
+    Added(Anchor),
+    Removed(Anchor),
+    Moved { old: Anchor, new: Anchor },
+}
+
+/// A changed non-outline field, as `(field name, old value, new value)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataDiff {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GlifDiff {
+    pub contours: Vec<ContourDiff>,
+    pub components: Vec<ComponentDiff>,
+    pub anchors: Vec<AnchorDiff>,
+    pub metadata: Vec<MetadataDiff>,
+}
+
+impl GlifDiff {
+    pub fn new(old: &Glif, new: &Glif) -> Self {
+        let (old_contours, old_components) = split_outline(old);
+        let (new_contours, new_components) = split_outline(new);
+        Self {
+            contours: diff_contours(&old_contours, &new_contours),
+            components: diff_components(&old_components, &new_components),
+            anchors: diff_anchors(&old.anchors, &new.anchors),
+            metadata: diff_metadata(old, new),
+        }
+    }
+
+    /// Diff of a glyph that did not exist in `old`, or no longer exists in `new`.
+    pub fn new_optional(old: Option<&Glif>, new: Option<&Glif>) -> Self {
+        let empty = Glif {
+            name: String::new(),
+            format: None,
+            unicode: vec![],
+            image: None,
+            advance: None,
+            outline: None,
+            anchors: vec![],
+            guidelines: vec![],
+            lib: IndexMap::default(),
+        };
+        Self::new(old.unwrap_or(&empty), new.unwrap_or(&empty))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contours.is_empty()
+            && self.components.is_empty()
+            && self.anchors.is_empty()
+            && self.metadata.is_empty()
+    }
+
+    /// Counts of added, removed and moved or retyped points over all contours.
+    pub fn point_counts(&self) -> (usize, usize, usize) {
+        let (mut added, mut removed, mut moved) = (0, 0, 0);
+        for c in self.contours.iter() {
+            match c {
+                ContourDiff::Added { contour, .. } => added += contour.point.len(),
+                ContourDiff::Removed { contour, .. } => removed += contour.point.len(),
+                ContourDiff::Changed { points, .. } => {
+                    for p in points {
+                        match p {
+                            PointDiff::Added { .. } => added += 1,
+                            PointDiff::Removed { .. } => removed += 1,
+                            PointDiff::Moved { .. } | PointDiff::Retyped { .. } => moved += 1,
+                        }
+                    }
+                }
+            }
+        }
+        (added, removed, moved)
+    }
+
+    /// Human readable description, one change per line.
+    pub fn summary(&self) -> Vec<String> {
+        let mut ret = vec![];
+        for c in self.contours.iter() {
+            match c {
+                ContourDiff::Added { index, contour } => ret.push(format!(
+                    "Added contour #{index} ({} points)",
+                    contour.point.len()
+                )),
+                ContourDiff::Removed { index, contour } => ret.push(format!(
+                    "Removed contour #{index} ({} points)",
+                    contour.point.len()
+                )),
+                ContourDiff::Changed {
+                    old_index,
+                    new_index,
+                    points,
+                } => {
+                    let (mut added, mut removed, mut moved, mut retyped) = (0, 0, 0, 0);
+                    for p in points {
+                        match p {
+                            PointDiff::Added { .. } => added += 1,
+                            PointDiff::Removed { .. } => removed += 1,
+                            PointDiff::Moved { .. } => moved += 1,
+                            PointDiff::Retyped { .. } => retyped += 1,
+                        }
+                    }
+                    let index = if old_index == new_index {
+                        format!("#{new_index}")
+                    } else {
+                        format!("#{old_index} → #{new_index}")
+                    };
+                    ret.push(if retyped == 0 {
+                        format!(
+                            "Contour {index}: {added} added, {removed} removed, {moved} moved points"
+                        )
+                    } else {
+                        format!(
+                            "Contour {index}: {added} added, {removed} removed, {moved} moved, \
+                             {retyped} retyped points"
+                        )
+                    });
+                }
+            }
+        }
+        for c in self.components.iter() {
+            ret.push(match c {
+                ComponentDiff::Added(c) => format!("Added component {}", c.base),
+                ComponentDiff::Removed(c) => format!("Removed component {}", c.base),
+                ComponentDiff::Transformed { new, .. } => {
+                    format!("Changed transformation of component {}", new.base)
+                }
+            });
+        }
+        for a in self.anchors.iter() {
+            ret.push(match a {
+                AnchorDiff::Added(a) => format!("Added anchor {}", a.name),
+                AnchorDiff::Removed(a) => format!("Removed anchor {}", a.name),
+                AnchorDiff::Moved { old, new } => format!(
+                    "Moved anchor {} from ({}, {}) to ({}, {})",
+                    new.name, old.x, old.y, new.x, new.y
+                ),
+            });
+        }
+        for MetadataDiff { field, old, new } in self.metadata.iter() {
+            ret.push(format!("Changed {field}: {old:?} → {new:?}"));
+        }
+        ret
+    }
+}
+
+fn split_outline(glif: &Glif) -> (Vec<&Contour>, Vec<&Component>) {
+    let mut contours = vec![];
+    let mut components = vec![];
+    for e in glif.outline.iter().flat_map(|o| o.contours.iter()) {
+        match e {
+            OutlineEntry::Contour(c) => contours.push(c),
+            OutlineEntry::Component(c) => components.push(c),
+        }
+    }
+    (contours, components)
+}
+
+fn centroid(contour: &Contour) -> (f64, f64) {
+    if contour.point.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = contour.point.len() as f64;
+    let (x, y) = contour
+        .point
+        .iter()
+        .fold((0.0, 0.0), |(x, y), p| (x + p.x, y + p.y));
+    (x / n, y / n)
+}
+
+fn diff_contours(old: &[&Contour], new: &[&Contour]) -> Vec<ContourDiff> {
+    let mut old_matched: Vec<Option<usize>> = vec![None; old.len()];
+    let mut new_matched: Vec<bool> = vec![false; new.len()];
+
+    /* Pair by identifier first. */
+    for (i, o) in old.iter().enumerate() {
+        let Some(id) = o.identifier.as_ref() else {
+            continue;
+        };
+        if let Some(j) = new.iter().position(|n| n.identifier.as_ref() == Some(id)) {
+            if !new_matched[j] {
+                old_matched[i] = Some(j);
+                new_matched[j] = true;
+            }
+        }
+    }
+
+    /* Then greedily by cost, cheapest pairs first. */
+    let mut candidates = vec![];
+    for (i, o) in old.iter().enumerate() {
+        if old_matched[i].is_some() {
+            continue;
+        }
+        let (ox, oy) = centroid(o);
+        for (j, n) in new.iter().enumerate() {
+            if new_matched[j] {
+                continue;
+            }
+            let (nx, ny) = centroid(n);
+            let len_diff = (o.point.len() as f64 - n.point.len() as f64).abs();
+            candidates.push(((ox - nx).hypot(oy - ny) + 10.0 * len_diff, i, j));
+        }
+    }
+    candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+    for (_, i, j) in candidates {
+        if old_matched[i].is_none() && !new_matched[j] {
+            old_matched[i] = Some(j);
+            new_matched[j] = true;
+        }
+    }
+
+    let mut ret = vec![];
+    for (i, m) in old_matched.iter().enumerate() {
+        match m {
+            None => ret.push(ContourDiff::Removed {
+                index: i,
+                contour: old[i].clone(),
+            }),
+            Some(j) => {
+                let points = diff_points(&old[i].point, &new[*j].point);
+                if !points.is_empty() {
+                    ret.push(ContourDiff::Changed {
+                        old_index: i,
+                        new_index: *j,
+                        points,
+                    });
+                }
+            }
+        }
+    }
+    for (j, m) in new_matched.iter().enumerate() {
+        if !m {
+            ret.push(ContourDiff::Added {
+                index: j,
+                contour: new[j].clone(),
+            });
+        }
+    }
+    ret
+}
+
+/// Whether `a` can be aligned with `b`: an off-curve point never stands for an on-curve one,
+/// but on-curve points of different types can, and are reported as retyped.
+fn same_kind(a: &Point, b: &Point) -> bool {
+    a.type_ == b.type_ || (a.type_ != PointKind::Offcurve && b.type_ != PointKind::Offcurve)
+}
+
+/// Aligns two point sequences with minimal edit cost.
+fn diff_points(old: &[Point], new: &[Point]) -> Vec<PointDiff> {
+    let (n, m) = (old.len(), new.len());
+    let subst = |a: &Point, b: &Point| -> f64 {
+        if !same_kind(a, b) {
+            return f64::INFINITY;
+        }
+        let moved = if a.x == b.x && a.y == b.y { 0.0 } else { 1.0 };
+        let retyped = if a.type_ == b.type_ { 0.0 } else { 1.0 };
+        moved + retyped
+    };
+    let mut cost = vec![vec![0.0; m + 1]; n + 1];
+    for (i, row) in cost.iter_mut().enumerate() {
+        row[0] = i as f64 * INDEL_COST;
+    }
+    for (j, c) in cost[0].iter_mut().enumerate() {
+        *c = j as f64 * INDEL_COST;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            cost[i][j] = (cost[i - 1][j - 1] + subst(&old[i - 1], &new[j - 1]))
+                .min(cost[i - 1][j] + INDEL_COST)
+                .min(cost[i][j - 1] + INDEL_COST);
+        }
+    }
+    let mut ret = vec![];
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 {
+            let s = subst(&old[i - 1], &new[j - 1]);
+            if s.is_finite() && cost[i][j] == cost[i - 1][j - 1] + s {
+                let (o, n) = (&old[i - 1], &new[j - 1]);
+                if o.type_ != n.type_ {
+                    ret.push(PointDiff::Retyped {
+                        old_index: i - 1,
+                        new_index: j - 1,
+                        at: (n.x, n.y),
+                        from: o.type_,
+                        to: n.type_,
+                    });
+                }
+                if o.x != n.x || o.y != n.y {
+                    ret.push(PointDiff::Moved {
+                        old_index: i - 1,
+                        new_index: j - 1,
+                        from: (o.x, o.y),
+                        to: (n.x, n.y),
+                    });
+                }
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if i > 0 && cost[i][j] == cost[i - 1][j] + INDEL_COST {
+            ret.push(PointDiff::Removed {
+                index: i - 1,
+                point: old[i - 1].clone(),
+            });
+            i -= 1;
+        } else {
+            ret.push(PointDiff::Added {
+                index: j - 1,
+                point: new[j - 1].clone(),
+            });
+            j -= 1;
+        }
+    }
+    ret.reverse();
+    ret
+}
+
+fn same_transform(a: &Component, b: &Component) -> bool {
+    a.x_offset == b.x_offset
+        && a.y_offset == b.y_offset
+        && a.x_scale == b.x_scale
+        && a.xy_scale == b.xy_scale
+        && a.yx_scale == b.yx_scale
+        && a.y_scale == b.y_scale
+}
+
+fn diff_components(old: &[&Component], new: &[&Component]) -> Vec<ComponentDiff> {
+    let mut ret = vec![];
+    let mut new_matched = vec![false; new.len()];
+    let mut unmatched_old = vec![];
+    /* Identical components first, so that reordering is not reported. */
+    for o in old {
+        if let Some(j) = new
+            .iter()
+            .enumerate()
+            .position(|(j, n)| !new_matched[j] && n.base == o.base && same_transform(o, n))
+        {
+            new_matched[j] = true;
+        } else {
+            unmatched_old.push(*o);
+        }
+    }
+    for o in unmatched_old {
+        if let Some(j) = new
+            .iter()
+            .enumerate()
+            .position(|(j, n)| !new_matched[j] && n.base == o.base)
+        {
+            new_matched[j] = true;
+            ret.push(ComponentDiff::Transformed {
+                old: o.clone(),
+                new: new[j].clone(),
+            });
+        } else {
+            ret.push(ComponentDiff::Removed(o.clone()));
+        }
+    }
+    for (j, m) in new_matched.into_iter().enumerate() {
+        if !m {
+            ret.push(ComponentDiff::Added(new[j].clone()));
+        }
+    }
+    ret
+}
+
+fn diff_anchors(old: &[Anchor], new: &[Anchor]) -> Vec<AnchorDiff> {
+    let mut ret = vec![];
+    for o in old {
+        match new.iter().find(|n| n.name == o.name) {
+            None => ret.push(AnchorDiff::Removed(o.clone())),
+            Some(n) if n.x != o.x || n.y != o.y => ret.push(AnchorDiff::Moved {
+                old: o.clone(),
+                new: n.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    for n in new {
+        if !old.iter().any(|o| o.name == n.name) {
+            ret.push(AnchorDiff::Added(n.clone()));
+        }
+    }
+    ret
+}
+
+fn diff_metadata(old: &Glif, new: &Glif) -> Vec<MetadataDiff> {
+    let mut ret = vec![];
+    let mut push = |field: &'static str, old: String, new: String| {
+        if old != new {
+            ret.push(MetadataDiff { field, old, new });
+        }
+    };
+    push("name", old.name.clone(), new.name.clone());
+    let unicodes = |g: &Glif| {
+        g.unicode
+            .iter()
+            .map(|u| u.hex().to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    push("unicode", unicodes(old), unicodes(new));
+    let width = |g: &Glif| g.advance.map(|a| a.width.to_string()).unwrap_or_default();
+    push("advance width", width(old), width(new));
+    let height = |g: &Glif| g.advance.map(|a| a.height.to_string()).unwrap_or_default();
+    push("advance height", height(old), height(new));
+    let image = |g: &Glif| {
+        g.image
+            .as_ref()
+            .and_then(|i| i.file_name.clone())
+            .unwrap_or_default()
+    };
+    push("image", image(old), image(new));
+    push(
+        "guidelines",
+        old.guidelines.len().to_string(),
+        new.guidelines.len().to_string(),
+    );
+    let lib_keys = |g: &Glif| g.lib.keys().cloned().collect::<Vec<_>>().join(", ");
+    push("lib keys", lib_keys(old), lib_keys(new));
+    ret
+}
+
+/// A glyph that differs between two glyph directories.
+#[derive(Debug, Clone)]
+pub struct GlyphChange {
+    pub name: String,
+    pub old: Option<Glif>,
+    pub new: Option<Glif>,
+    pub diff: GlifDiff,
+}
+
+/// Compares the `.glif` files of two glyph directories (e.g. `glyphs/` of two UFOs) using their
+/// `contents.plist`. Returns every glyph whose diff is not empty.
+pub fn diff_glyph_dirs(
+    old_dir: &Path,
+    new_dir: &Path,
+) -> Result<Vec<GlyphChange>, Box<dyn std::error::Error>> {
+    fn read_dir(dir: &Path) -> Result<IndexMap<String, Glif>, Box<dyn std::error::Error>> {
+        let contents = ufo::Contents::from_path(&dir.join("contents.plist"), false)
+            .map_err(|err| format!("couldn't read {}/contents.plist: {err}", dir.display()))?;
+        let mut ret = IndexMap::default();
+        for (name, filename) in contents.glyphs().iter() {
+            let path = dir.join(filename);
+            let s = std::fs::read_to_string(&path)
+                .map_err(|err| format!("Couldn't read {}: {}", path.display(), err))?;
+            let glif = Glif::from_str(&s)
+                .map_err(|err| format!("couldn't parse {}: {}", path.display(), err))?;
+            ret.insert(name.clone(), glif);
+        }
+        Ok(ret)
+    }
+    let mut old = read_dir(old_dir)?;
+    let mut new = read_dir(new_dir)?;
+    let mut ret = vec![];
+    for (name, o) in old.drain(..) {
+        let n = new.shift_remove(&name);
+        let diff = GlifDiff::new_optional(Some(&o), n.as_ref());
+        if !diff.is_empty() {
+            ret.push(GlyphChange {
+                name,
+                old: Some(o),
+                new: n,
+                diff,
+            });
+        }
+    }
+    for (name, n) in new.drain(..) {
+        ret.push(GlyphChange {
+            name,
+            diff: GlifDiff::new_optional(None, Some(&n)),
+            old: None,
+            new: Some(n),
+        });
+    }
+    Ok(ret)
+}
+
+#[test]
+fn test_glif_diff() {
+    let old = Glif::from_str(super::_LOWERCASE_B_GLIF).unwrap();
+    assert!(GlifDiff::new(&old, &old).is_empty());
+
+    let mut new = old.clone();
+    let Some(OutlineEntry::Contour(ref mut c)) = new.outline.as_mut().unwrap().contours.get_mut(1)
+    else {
+        unreachable!()
+    };
+    c.point[4].x += 10.0;
+    let mut inserted = c.point[4].clone();
+    inserted.y -= 100.0;
+    c.point.insert(5, inserted);
+    new.anchors.pop();
+    let diff = GlifDiff::new(&old, &new);
+    assert_eq!(diff.point_counts(), (1, 0, 1));
+    assert_eq!(diff.anchors.len(), 1);
+    assert!(matches!(diff.anchors[0], AnchorDiff::Removed(_)));
+    assert!(diff.components.is_empty());
+    assert!(diff.metadata.is_empty());
+
+    let mut retyped = old.clone();
+    let Some(OutlineEntry::Contour(ref mut c)) =
+        retyped.outline.as_mut().unwrap().contours.get_mut(0)
+    else {
+        unreachable!()
+    };
+    let i = c
+        .point
+        .iter()
+        .position(|p| p.type_ == PointKind::Line)
+        .unwrap();
+    c.point[i].type_ = PointKind::Curve;
+    let diff = GlifDiff::new(&old, &retyped);
+    assert_eq!(diff.point_counts(), (0, 0, 1));
+    let ContourDiff::Changed { ref points, .. } = diff.contours[0] else {
+        unreachable!()
+    };
+    assert!(matches!(
+        points[..],
+        [PointDiff::Retyped {
+            from: PointKind::Line,
+            to: PointKind::Curve,
+            ..
+        }]
+    ));
+
+    let diff = GlifDiff::new_optional(None, Some(&old));
+    assert_eq!(diff.contours.len(), 2);
+    assert!(diff
+        .contours
+        .iter()
+        .all(|c| matches!(c, ContourDiff::Added { .. })));
+}
@@ -284,7 +284,15 @@ impl FontInfo {
         let fontinfo = ufo::FontInfo::from_path(&path)
             .map_err(|err| format!("couldn't read fontinfo.plist {}: {}", path.display(), err))?;
         let ret: Self = Self::new();
-        *ret.source.borrow_mut() = fontinfo.clone();
+        ret.load_source(fontinfo);
+        ret.modified.set(false);
+        *ret.last_saved.borrow_mut() = None;
+        ret.path.set(path).unwrap();
+        Ok(ret)
+    }
+
+    fn load_source(&self, fontinfo: ufo::FontInfo) {
+        *self.source.borrow_mut() = fontinfo.clone();
         let ufo::FontInfo {
             family_name,
             style_name,
@@ -305,19 +313,16 @@ impl FontInfo {
             note,
             ..
         } = fontinfo;
-        ret.modified.set(false);
-        *ret.last_saved.borrow_mut() = None;
-        ret.path.set(path).unwrap();
-        *ret.family_name.borrow_mut() = family_name;
-        *ret.style_name.borrow_mut() = style_name;
-        *ret.style_map_family_name.borrow_mut() = style_map_family_name;
-        *ret.style_map_style_name.borrow_mut() = style_map_style_name;
-        *ret.copyright.borrow_mut() = copyright;
-        *ret.trademark.borrow_mut() = trademark;
+        *self.family_name.borrow_mut() = family_name;
+        *self.style_name.borrow_mut() = style_name;
+        *self.style_map_family_name.borrow_mut() = style_map_family_name;
+        *self.style_map_style_name.borrow_mut() = style_map_style_name;
+        *self.copyright.borrow_mut() = copyright;
+        *self.trademark.borrow_mut() = trademark;
         if let Some(note) = note {
-            *ret.note.borrow_mut() = note;
+            *self.note.borrow_mut() = note;
         }
-        *ret.guidelines.borrow_mut() = guidelines;
+        *self.guidelines.borrow_mut() = guidelines;
 
         macro_rules! set_cell {
             ($field:expr, $val:expr) => {{
@@ -326,16 +331,58 @@ impl FontInfo {
                 }
             }};
         }
-        set_cell!(ret.version_major, version_major);
-        set_cell!(ret.version_minor, version_minor);
-        set_cell!(ret.year, year);
-        set_cell!(ret.units_per_em, units_per_em);
-        set_cell!(ret.ascender, ascender);
-        set_cell!(ret.descender, descender);
-        set_cell!(ret.x_height, x_height);
-        set_cell!(ret.cap_height, cap_height);
-        set_cell!(ret.italic_angle, italic_angle);
-        Ok(ret)
+        set_cell!(self.version_major, version_major);
+        set_cell!(self.version_minor, version_minor);
+        set_cell!(self.year, year);
+        set_cell!(self.units_per_em, units_per_em);
+        set_cell!(self.ascender, ascender);
+        set_cell!(self.descender, descender);
+        set_cell!(self.x_height, x_height);
+        set_cell!(self.cap_height, cap_height);
+        set_cell!(self.italic_angle, italic_angle);
+    }
+
+    /// Replaces all of `fontinfo.plist` with `source`, e.g. after editing it in the font info
+    /// window. The properties that mirror its fields are set as well, so that anything bound to
+    /// them is updated.
+    pub fn set_source(&self, source: ufo::FontInfo) {
+        if *self.source.borrow() == source {
+            return;
+        }
+        let new = Self::new();
+        new.load_source(source.clone());
+        for pspec in new.list_properties().iter() {
+            let name = pspec.name();
+            if name == Self::MODIFIED || !pspec.flags().contains(glib::ParamFlags::WRITABLE) {
+                continue;
+            }
+            self.set_property_from_value(name, &new.property_value(name));
+        }
+        *self.source.borrow_mut() = source;
+        *self.guidelines.borrow_mut() = new.guidelines.borrow().clone();
+        self.set_property(Self::MODIFIED, true);
+    }
+
+    /// Undoable [`FontInfo::set_source`]. The returned action has to be redone once to take
+    /// effect.
+    pub fn set_source_action(&self, source: ufo::FontInfo) -> crate::app::Action {
+        let fontinfo = self.clone();
+        let other = Rc::new(RefCell::new(source));
+        let cl = Box::new(move || {
+            let prev = fontinfo.source.borrow().clone();
+            let next = std::mem::replace(&mut *other.borrow_mut(), prev);
+            fontinfo.set_source(next);
+        });
+        crate::app::Action {
+            stamp: crate::app::EventStamp {
+                t: std::any::TypeId::of::<Self>(),
+                property: "source",
+                id: Box::new([]),
+            },
+            compress: false,
+            redo: cl.clone(),
+            undo: cl,
+        }
     }
 
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
@@ -366,6 +413,7 @@ impl_modified!(FontInfo);
 
 mod layer {
     use super::*;
+    use crate::glyphs::GlyphKind;
 
     #[derive(Debug)]
     pub struct LayerInner {
@@ -528,9 +576,14 @@ mod layer {
             if !self.modified.get() {
                 return Ok(());
             }
+            /* Layers created since the project was opened have no directory yet. */
+            let create = !self.path.borrow().exists();
+            if create {
+                std::fs::create_dir_all(&*self.path.borrow())?;
+            }
             self.contents_plist
                 .borrow_mut()
-                .save(None, false)
+                .save(None, create)
                 .map_err(|err| {
                     format!(
                         "Saving contents.plist of layer {} failed: {err}",
@@ -552,6 +605,12 @@ mod layer {
             Ok(())
         }
 
+        /// A file name for new glyph `name` that clashes with no other file of the layer. See
+        /// [`ufo::Contents::new_filename`].
+        pub fn new_filename(&self, name: &str) -> String {
+            self.contents_plist.borrow().new_filename(name)
+        }
+
         pub fn new_glyph(
             &self,
             name: String,
@@ -575,6 +634,77 @@ mod layer {
             Ok(())
         }
 
+        /// Renames glyph `old` to `new`. The glyph keeps its file name. Components that refer
+        /// to it are renamed with [`Layer::rename_components`].
+        pub fn rename_glyph(
+            &self,
+            old: &str,
+            new: String,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            if old == new {
+                return Ok(());
+            }
+            let metadata = {
+                let mut glyphs = self.glyphs.borrow_mut();
+                if glyphs.contains_key(&new) {
+                    return Err(format!(
+                        "Glyph `{new}` already exists in layer {layer}.",
+                        layer = self.name.borrow()
+                    )
+                    .into());
+                }
+                let glyph = glyphs.shift_remove(old).ok_or_else(|| {
+                    format!(
+                        "Glyph `{old}` does not exist in layer {layer}.",
+                        layer = self.name.borrow()
+                    )
+                })?;
+                self.contents_plist.borrow_mut().rename(old, new.clone());
+                let metadata = glyph.borrow().metadata.clone();
+                {
+                    let mut kinds = metadata.kinds.borrow_mut();
+                    if kinds.0 == GlyphKind::Component(old.to_string()) {
+                        kinds.0 = GlyphKind::Component(new.clone());
+                    }
+                }
+                glyphs.insert(new.clone(), glyph);
+                metadata
+            };
+            /* Set outside of the borrow, since modification notifications may read the glyphs. */
+            metadata.set_property(GlyphMetadata::NAME, new);
+            metadata.set_property(GlyphMetadata::MODIFIED, true);
+            self.set_property(Self::MODIFIED, true);
+            Ok(())
+        }
+
+        /// Makes the components that refer to glyph `old` refer to `new`, and returns the names
+        /// of the glyphs that changed.
+        pub fn rename_components(&self, old: &str, new: &str) -> Vec<String> {
+            let mut modified = vec![];
+            for g in self.glyphs.borrow().values() {
+                let mut g = g.borrow_mut();
+                let mut changed = false;
+                for c in g.components.iter_mut().filter(|c| c.base_name == old) {
+                    c.base_name = new.to_string();
+                    changed = true;
+                }
+                if changed {
+                    modified.push((g.name().to_string(), g.metadata.clone()));
+                }
+            }
+            if modified.is_empty() {
+                return vec![];
+            }
+            self.set_property(Self::MODIFIED, true);
+            modified
+                .into_iter()
+                .map(|(name, metadata)| {
+                    metadata.set_property(GlyphMetadata::MODIFIED, true);
+                    name
+                })
+                .collect()
+        }
+
         pub fn path(&'_ self) -> FieldRef<'_, PathBuf> {
             self.path.borrow().into()
         }
@@ -0,0 +1,244 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Bit numbers of the OpenType flag fields that `fontinfo.plist` stores as lists of bit numbers,
+//! with their descriptions from the OpenType specification.
+
+/// `head` table `flags`.
+pub const HEAD_FLAGS: &[(u32, &str)] = &[
+    (0, "Baseline for font at y=0"),
+    (1, "Left sidebearing point at x=0"),
+    (2, "Instructions may depend on point size"),
+    (3, "Force ppem to integer values"),
+    (4, "Instructions may alter advance width"),
+    (5, "Laid out vertically (Apple)"),
+    (6, "Reserved (Apple)"),
+    (7, "Requires layout for linguistic rendering (Apple)"),
+    (8, "Has default metamorphosis effects (Apple)"),
+    (9, "Contains strong right-to-left glyphs (Apple)"),
+    (10, "Contains Indic-style rearrangement effects (Apple)"),
+    (11, "Font data is lossless"),
+    (12, "Font converted"),
+    (13, "Font optimized for ClearType"),
+    (14, "Last resort font"),
+];
+
+/// OS/2 table `fsSelection`. Bits 0 (italic), 5 (bold) and 6 (regular) are not allowed in
+/// `fontinfo.plist`, since they are derived from `styleMapStyleName`.
+pub const OS2_SELECTION: &[(u32, &str)] = &[
+    (1, "Underscore"),
+    (2, "Negative"),
+    (3, "Outlined"),
+    (4, "Strikeout"),
+    (7, "Use typo metrics"),
+    (8, "WWS"),
+    (9, "Oblique"),
+];
+
+/// OS/2 table `fsType`. Bits 1, 2 and 3 are mutually exclusive usage permissions.
+pub const OS2_TYPE: &[(u32, &str)] = &[
+    (1, "Restricted license embedding"),
+    (2, "Preview & print embedding"),
+    (3, "Editable embedding"),
+    (8, "No subsetting"),
+    (9, "Bitmap embedding only"),
+];
+
+/// gasp range record `rangeGaspBehavior`.
+pub const GASP_BEHAVIOR: &[(u32, &str)] = &[
+    (0, "Grid-fit"),
+    (1, "Grayscale"),
+    (2, "Symmetric grid-fit"),
+    (3, "Symmetric smoothing"),
+];
+
+/// OS/2 table `ulUnicodeRange1` to `ulUnicodeRange4`.
+pub const OS2_UNICODE_RANGES: &[(u32, &str)] = &[
+    (0, "Basic Latin"),
+    (1, "Latin-1 Supplement"),
+    (2, "Latin Extended-A"),
+    (3, "Latin Extended-B"),
+    (4, "IPA Extensions"),
+    (5, "Spacing Modifier Letters"),
+    (6, "Combining Diacritical Marks"),
+    (7, "Greek and Coptic"),
+    (8, "Coptic"),
+    (9, "Cyrillic"),
+    (10, "Armenian"),
+    (11, "Hebrew"),
+    (12, "Vai"),
+    (13, "Arabic"),
+    (14, "NKo"),
+    (15, "Devanagari"),
+    (16, "Bengali"),
+    (17, "Gurmukhi"),
+    (18, "Gujarati"),
+    (19, "Oriya"),
+    (20, "Tamil"),
+    (21, "Telugu"),
+    (22, "Kannada"),
+    (23, "Malayalam"),
+    (24, "Thai"),
+    (25, "Lao"),
+    (26, "Georgian"),
+    (27, "Balinese"),
+    (28, "Hangul Jamo"),
+    (29, "Latin Extended Additional"),
+    (30, "Greek Extended"),
+    (31, "General Punctuation"),
+    (32, "Superscripts And Subscripts"),
+    (33, "Currency Symbols"),
+    (34, "Combining Diacritical Marks For Symbols"),
+    (35, "Letterlike Symbols"),
+    (36, "Number Forms"),
+    (37, "Arrows"),
+    (38, "Mathematical Operators"),
+    (39, "Miscellaneous Technical"),
+    (40, "Control Pictures"),
+    (41, "Optical Character Recognition"),
+    (42, "Enclosed Alphanumerics"),
+    (43, "Box Drawing"),
+    (44, "Block Elements"),
+    (45, "Geometric Shapes"),
+    (46, "Miscellaneous Symbols"),
+    (47, "Dingbats"),
+    (48, "CJK Symbols And Punctuation"),
+    (49, "Hiragana"),
+    (50, "Katakana"),
+    (51, "Bopomofo"),
+    (52, "Hangul Compatibility Jamo"),
+    (53, "Phags-pa"),
+    (54, "Enclosed CJK Letters And Months"),
+    (55, "CJK Compatibility"),
+    (56, "Hangul Syllables"),
+    (57, "Non-Plane 0"),
+    (58, "Phoenician"),
+    (59, "CJK Unified Ideographs"),
+    (60, "Private Use Area (plane 0)"),
+    (61, "CJK Strokes"),
+    (62, "Alphabetic Presentation Forms"),
+    (63, "Arabic Presentation Forms-A"),
+    (64, "Combining Half Marks"),
+    (65, "Vertical Forms"),
+    (66, "Small Form Variants"),
+    (67, "Arabic Presentation Forms-B"),
+    (68, "Halfwidth And Fullwidth Forms"),
+    (69, "Specials"),
+    (70, "Tibetan"),
+    (71, "Syriac"),
+    (72, "Thaana"),
+    (73, "Sinhala"),
+    (74, "Myanmar"),
+    (75, "Ethiopic"),
+    (76, "Cherokee"),
+    (77, "Unified Canadian Aboriginal Syllabics"),
+    (78, "Ogham"),
+    (79, "Runic"),
+    (80, "Khmer"),
+    (81, "Mongolian"),
+    (82, "Braille Patterns"),
+    (83, "Yi Syllables"),
+    (84, "Tagalog, Hanunoo, Buhid, Tagbanwa"),
+    (85, "Old Italic"),
+    (86, "Gothic"),
+    (87, "Deseret"),
+    (88, "Byzantine Musical Symbols"),
+    (89, "Mathematical Alphanumeric Symbols"),
+    (90, "Private Use (plane 15 and 16)"),
+    (91, "Variation Selectors"),
+    (92, "Tags"),
+    (93, "Limbu"),
+    (94, "Tai Le"),
+    (95, "New Tai Lue"),
+    (96, "Buginese"),
+    (97, "Glagolitic"),
+    (98, "Tifinagh"),
+    (99, "Yijing Hexagram Symbols"),
+    (100, "Syloti Nagri"),
+    (101, "Linear B Syllabary"),
+    (102, "Ancient Greek Numbers"),
+    (103, "Ugaritic"),
+    (104, "Old Persian"),
+    (105, "Shavian"),
+    (106, "Osmanya"),
+    (107, "Cypriot Syllabary"),
+    (108, "Kharoshthi"),
+    (109, "Tai Xuan Jing Symbols"),
+    (110, "Cuneiform"),
+    (111, "Counting Rod Numerals"),
+    (112, "Sundanese"),
+    (113, "Lepcha"),
+    (114, "Ol Chiki"),
+    (115, "Saurashtra"),
+    (116, "Kayah Li"),
+    (117, "Rejang"),
+    (118, "Cham"),
+    (119, "Ancient Symbols"),
+    (120, "Phaistos Disc"),
+    (121, "Carian, Lycian, Lydian"),
+    (122, "Domino Tiles, Mahjong Tiles"),
+];
+
+/// OS/2 table `ulCodePageRange1` and `ulCodePageRange2`.
+pub const OS2_CODE_PAGE_RANGES: &[(u32, &str)] = &[
+    (0, "1252 Latin 1"),
+    (1, "1250 Latin 2: Eastern Europe"),
+    (2, "1251 Cyrillic"),
+    (3, "1253 Greek"),
+    (4, "1254 Turkish"),
+    (5, "1255 Hebrew"),
+    (6, "1256 Arabic"),
+    (7, "1257 Windows Baltic"),
+    (8, "1258 Vietnamese"),
+    (16, "874 Thai"),
+    (17, "932 JIS/Japan"),
+    (18, "936 Chinese: Simplified chars—PRC and Singapore"),
+    (19, "949 Korean Wansung"),
+    (20, "950 Chinese: Traditional chars—Taiwan and Hong Kong"),
+    (21, "1361 Korean Johab"),
+    (29, "Macintosh Character Set (US Roman)"),
+    (30, "OEM Character Set"),
+    (31, "Symbol Character Set"),
+    (48, "869 IBM Greek"),
+    (49, "866 MS-DOS Russian"),
+    (50, "865 MS-DOS Nordic"),
+    (51, "864 Arabic"),
+    (52, "863 MS-DOS Canadian French"),
+    (53, "862 Hebrew"),
+    (54, "861 MS-DOS Icelandic"),
+    (55, "860 MS-DOS Portuguese"),
+    (56, "857 IBM Turkish"),
+    (57, "855 IBM Cyrillic; primarily Russian"),
+    (58, "852 Latin 2"),
+    (59, "775 MS-DOS Baltic"),
+    (60, "737 Greek; former 437 G"),
+    (61, "708 Arabic; ASMO 708"),
+    (62, "850 WE/Latin 1"),
+    (63, "437 US"),
+];
+
+/// Returns the bit numbers of `bits` that are not listed in `table`.
+pub fn invalid_bits(bits: &[u32], table: &[(u32, &str)]) -> Vec<u32> {
+    bits.iter()
+        .copied()
+        .filter(|b| !table.iter().any(|(t, _)| t == b))
+        .collect()
+}
@@ -0,0 +1,365 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Vertical metrics of a font: the hhea, OS/2 typo and OS/2 win values that decide line
+//! spacing and clipping on different platforms.
+//!
+//! Recommended values are computed from the generic ascender and descender and the extents of
+//! the glyphs, following common strategies, and existing values can be checked for glyphs that
+//! get clipped and for line heights that differ between platforms.
+
+use super::{constants, FontInfo};
+
+/// fsSelection bit that makes applications use the typo values for line spacing.
+const USE_TYPO_METRICS: u32 = 7;
+
+/// Highest and lowest points of a font's glyphs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Extents {
+    pub y_max: f64,
+    pub y_max_glyph: Option<String>,
+    pub y_min: f64,
+    pub y_min_glyph: Option<String>,
+    /// The name, lowest and highest point of every glyph with an outline.
+    pub glyphs: Vec<(String, f64, f64)>,
+}
+
+impl Extents {
+    pub fn new(glyphs: Vec<(String, f64, f64)>) -> Self {
+        let mut ret = Self {
+            glyphs,
+            ..Self::default()
+        };
+        for (name, y_min, y_max) in ret.glyphs.iter() {
+            if *y_max > ret.y_max {
+                ret.y_max = *y_max;
+                ret.y_max_glyph = Some(name.clone());
+            }
+            if *y_min < ret.y_min {
+                ret.y_min = *y_min;
+                ret.y_min_glyph = Some(name.clone());
+            }
+        }
+        ret
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// All ascenders and descenders at the extents of the glyphs, no line gaps.
+    BoundingBox,
+    /// hhea and win at the extents of the glyphs, typo values at the ascender and descender with
+    /// a line gap that makes up the same line height.
+    Webfont,
+    /// Typo and hhea values span exactly one em, with a line gap of 20% of the em. win values at
+    /// the extents of the glyphs.
+    EmBox,
+}
+
+impl Strategy {
+    pub const ALL: [Self; 3] = [Self::BoundingBox, Self::Webfont, Self::EmBox];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::BoundingBox => "Font bounding box",
+            Self::Webfont => "Webfont",
+            Self::EmBox => "Em box",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::BoundingBox => {
+                "All ascenders and descenders at the highest and lowest glyph, without line gaps. Safe from clipping, with the same line height everywhere, but loose."
+            }
+            Self::Webfont => {
+                "hhea and win values at the highest and lowest glyph, typo values at the ascender and descender with a line gap that adds up to the same line height."
+            }
+            Self::EmBox => {
+                "Typo and hhea values span exactly one em, with a line gap of 20% of the em, and win values at the highest and lowest glyph."
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VerticalMetrics {
+    pub hhea_ascender: i64,
+    pub hhea_descender: i64,
+    pub hhea_line_gap: i64,
+    pub typo_ascender: i64,
+    pub typo_descender: i64,
+    pub typo_line_gap: i64,
+    pub win_ascent: u64,
+    pub win_descent: u64,
+    pub use_typo_metrics: bool,
+}
+
+impl VerticalMetrics {
+    /// The values of `info`, and the keys of those that are undefined. Undefined values are
+    /// filled in like font compilers do.
+    pub fn from_fontinfo(info: &FontInfo, extents: &Extents) -> (Self, Vec<&'static str>) {
+        let mut undefined = vec![];
+        let mut get = |value: Option<i64>, key: &'static str, default: i64| {
+            value.unwrap_or_else(|| {
+                undefined.push(key);
+                default
+            })
+        };
+        let upm = info.units_per_em.unwrap_or(constants::UNITS_PER_EM).round() as i64;
+        let ascender = info.ascender.unwrap_or(constants::ASCENDER).round() as i64;
+        let descender = info.descender.unwrap_or(constants::DESCENDER).round() as i64;
+        let typo_ascender = get(
+            info.open_type_os2_typo_ascender,
+            "openTypeOS2TypoAscender",
+            ascender,
+        );
+        let typo_descender = get(
+            info.open_type_os2_typo_descender,
+            "openTypeOS2TypoDescender",
+            descender,
+        );
+        let typo_line_gap = get(
+            info.open_type_os2_typo_line_gap,
+            "openTypeOS2TypoLineGap",
+            (upm as f64 * 1.2).round() as i64 - ascender + descender,
+        );
+        let hhea_ascender = get(
+            info.open_type_hhea_ascender.map(|v| v.round() as i64),
+            "openTypeHheaAscender",
+            ascender + typo_line_gap,
+        );
+        let hhea_descender = get(
+            info.open_type_hhea_descender.map(|v| v.round() as i64),
+            "openTypeHheaDescender",
+            descender,
+        );
+        let hhea_line_gap = get(
+            info.open_type_hhea_line_gap.map(|v| v.round() as i64),
+            "openTypeHheaLineGap",
+            0,
+        );
+        let win_ascent = get(
+            info.open_type_os2_win_ascent.map(|v| v as i64),
+            "openTypeOS2WinAscent",
+            extents.y_max.ceil() as i64,
+        );
+        let win_descent = get(
+            info.open_type_os2_win_descent.map(|v| v as i64),
+            "openTypeOS2WinDescent",
+            (-extents.y_min).ceil() as i64,
+        );
+        (
+            Self {
+                hhea_ascender,
+                hhea_descender,
+                hhea_line_gap,
+                typo_ascender,
+                typo_descender,
+                typo_line_gap,
+                win_ascent: win_ascent.max(0) as u64,
+                win_descent: win_descent.max(0) as u64,
+                use_typo_metrics: info
+                    .open_type_os2_selection
+                    .as_ref()
+                    .map(|s| s.contains(&USE_TYPO_METRICS))
+                    .unwrap_or(false),
+            },
+            undefined,
+        )
+    }
+
+    /// Recommended values for `info` following `strategy`.
+    pub fn recommend(strategy: Strategy, info: &FontInfo, extents: &Extents) -> Self {
+        let upm = info.units_per_em.unwrap_or(constants::UNITS_PER_EM).round() as i64;
+        let ascender = info.ascender.unwrap_or(constants::ASCENDER).round() as i64;
+        let descender = info.descender.unwrap_or(constants::DESCENDER).round() as i64;
+        let win_ascent = extents.y_max.ceil().max(0.0) as i64;
+        let win_descent = (-extents.y_min).ceil().max(0.0) as i64;
+        let (typo_ascender, typo_descender, typo_line_gap) = match strategy {
+            Strategy::BoundingBox => (win_ascent, -win_descent, 0),
+            Strategy::Webfont => (
+                ascender,
+                descender,
+                (win_ascent + win_descent - ascender + descender).max(0),
+            ),
+            Strategy::EmBox => {
+                let em_ascender = if ascender - descender > 0 {
+                    (upm as f64 * ascender as f64 / (ascender - descender) as f64).round() as i64
+                } else {
+                    upm
+                };
+                (
+                    em_ascender,
+                    em_ascender - upm,
+                    (upm as f64 * 0.2).round() as i64,
+                )
+            }
+        };
+        let (hhea_ascender, hhea_descender, hhea_line_gap) = match strategy {
+            Strategy::BoundingBox | Strategy::Webfont => (win_ascent, -win_descent, 0),
+            Strategy::EmBox => (typo_ascender, typo_descender, typo_line_gap),
+        };
+        Self {
+            hhea_ascender,
+            hhea_descender,
+            hhea_line_gap,
+            typo_ascender,
+            typo_descender,
+            typo_line_gap,
+            win_ascent: win_ascent as u64,
+            win_descent: win_descent as u64,
+            use_typo_metrics: true,
+        }
+    }
+
+    pub fn hhea_line_height(&self) -> i64 {
+        self.hhea_ascender - self.hhea_descender + self.hhea_line_gap
+    }
+
+    pub fn typo_line_height(&self) -> i64 {
+        self.typo_ascender - self.typo_descender + self.typo_line_gap
+    }
+
+    pub fn win_line_height(&self) -> i64 {
+        (self.win_ascent + self.win_descent) as i64
+    }
+
+    /// Problems with these values: clipped glyphs, line heights that differ between platforms,
+    /// and values with the wrong sign.
+    pub fn check(&self, extents: &Extents) -> Vec<String> {
+        const LISTED: usize = 10;
+
+        let mut ret = vec![];
+        let clipped = extents
+            .glyphs
+            .iter()
+            .filter(|(_, y_min, y_max)| {
+                *y_max > self.win_ascent as f64 || *y_min < -(self.win_descent as f64)
+            })
+            .map(|(name, _, _)| name.as_str())
+            .collect::<Vec<&str>>();
+        if !clipped.is_empty() {
+            ret.push(format!(
+                "{} glyph(s) are clipped by winAscent and winDescent on Windows: {}{}",
+                clipped.len(),
+                clipped[..clipped.len().min(LISTED)].join(", "),
+                if clipped.len() > LISTED { ", …" } else { "." }
+            ));
+        }
+        let (windows, windows_height) = if self.use_typo_metrics {
+            ("typo", self.typo_line_height())
+        } else {
+            ("win", self.win_line_height())
+        };
+        if self.hhea_line_height() != windows_height {
+            ret.push(format!(
+                "Line height differs between macOS (hhea: {}) and Windows ({}: {}).",
+                self.hhea_line_height(),
+                windows,
+                windows_height
+            ));
+        }
+        if self.hhea_line_gap < 0 || self.typo_line_gap < 0 {
+            ret.push("Line gaps must not be negative.".to_string());
+        }
+        if self.hhea_ascender < 0 || self.typo_ascender < 0 {
+            ret.push("Ascenders must not be negative.".to_string());
+        }
+        if self.hhea_descender > 0 || self.typo_descender > 0 {
+            ret.push("Descenders must not be positive.".to_string());
+        }
+        if !self.use_typo_metrics {
+            ret.push(
+                "USE_TYPO_METRICS is not set, so Windows applications use the win values for line spacing."
+                    .to_string(),
+            );
+        }
+        ret
+    }
+
+    /// Sets these values in `info`.
+    pub fn apply(&self, info: &mut FontInfo) {
+        info.open_type_hhea_ascender = Some(self.hhea_ascender as f64);
+        info.open_type_hhea_descender = Some(self.hhea_descender as f64);
+        info.open_type_hhea_line_gap = Some(self.hhea_line_gap as f64);
+        info.open_type_os2_typo_ascender = Some(self.typo_ascender);
+        info.open_type_os2_typo_descender = Some(self.typo_descender);
+        info.open_type_os2_typo_line_gap = Some(self.typo_line_gap);
+        info.open_type_os2_win_ascent = Some(self.win_ascent);
+        info.open_type_os2_win_descent = Some(self.win_descent);
+        let selection = info.open_type_os2_selection.get_or_insert_with(Vec::new);
+        selection.retain(|b| *b != USE_TYPO_METRICS);
+        if self.use_typo_metrics {
+            selection.push(USE_TYPO_METRICS);
+            selection.sort_unstable();
+        }
+    }
+}
+
+#[test]
+fn test_vertical_metrics() {
+    let info = FontInfo {
+        units_per_em: Some(1000.0),
+        ascender: Some(750.0),
+        descender: Some(-250.0),
+        ..FontInfo::default()
+    };
+    let extents = Extents::new(vec![
+        ("a".to_string(), -10.0, 500.0),
+        ("Aring".to_string(), 0.0, 940.2),
+        ("g".to_string(), -260.0, 500.0),
+    ]);
+    assert_eq!(extents.y_max_glyph.as_deref(), Some("Aring"));
+    assert_eq!(extents.y_min_glyph.as_deref(), Some("g"));
+
+    let (current, undefined) = VerticalMetrics::from_fontinfo(&info, &extents);
+    assert_eq!(undefined.len(), 8);
+    assert_eq!(current.typo_line_gap, 200);
+    assert_eq!(current.hhea_ascender, 950);
+    assert_eq!(current.win_ascent, 941);
+    assert_eq!(current.win_descent, 260);
+
+    for strategy in Strategy::ALL {
+        let m = VerticalMetrics::recommend(strategy, &info, &extents);
+        assert_eq!(m.hhea_line_height(), m.typo_line_height(), "{strategy:?}");
+        assert_eq!(m.check(&extents), Vec::<String>::new(), "{strategy:?}");
+    }
+    let m = VerticalMetrics::recommend(Strategy::Webfont, &info, &extents);
+    assert_eq!((m.typo_ascender, m.typo_descender), (750, -250));
+    assert_eq!(m.typo_line_gap, 201);
+    let m = VerticalMetrics::recommend(Strategy::EmBox, &info, &extents);
+    assert_eq!(m.typo_ascender - m.typo_descender, 1000);
+
+    let clipping = VerticalMetrics {
+        win_descent: 250,
+        ..m
+    };
+    let problems = clipping.check(&extents);
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].ends_with(": g."), "{problems:?}");
+
+    let mut info = info;
+    info.open_type_os2_selection = Some(vec![8]);
+    m.apply(&mut info);
+    assert_eq!(info.open_type_os2_selection, Some(vec![7, 8]));
+    assert_eq!(VerticalMetrics::from_fontinfo(&info, &extents), (m, vec![]));
+}
@@ -0,0 +1,362 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Character sets to check the coverage of a font against.
+//!
+//! Built-in definitions are given as codepoint ranges. The Adobe Latin sets follow the
+//! encoded characters of Adobe's Latin 1 to 5 definitions, each of which includes the previous
+//! one; their unencoded glyphs, such as small capitals and alternates, are out of scope. User
+//! supplied lists are read from the `charsets` directory of the configuration directory, one
+//! `.txt` file per set, with the syntax of [`Charset::parse`].
+//!
+//! <https://github.com/adobe-type-tools/adobe-latin-charsets>
+
+use crate::glyphs::GlyphKind;
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
+type Ranges = &'static [(char, char)];
+
+const ASCII: Ranges = &[(' ', '~')];
+
+const LATIN_1_SUPPLEMENT: Ranges = &[('\u{A0}', '\u{FF}')];
+
+/// Characters of Windows-1252 and Mac OS Roman outside of Latin-1.
+const WESTERN_EXTRA: Ranges = &[
+    ('\u{0131}', '\u{0131}'),
+    ('\u{0141}', '\u{0142}'),
+    ('\u{0152}', '\u{0153}'),
+    ('\u{0160}', '\u{0161}'),
+    ('\u{0178}', '\u{0178}'),
+    ('\u{017D}', '\u{017E}'),
+    ('\u{0192}', '\u{0192}'),
+    ('\u{02C6}', '\u{02C7}'),
+    ('\u{02D8}', '\u{02DD}'),
+    ('\u{03A9}', '\u{03A9}'),
+    ('\u{03C0}', '\u{03C0}'),
+    ('\u{2013}', '\u{2014}'),
+    ('\u{2018}', '\u{201A}'),
+    ('\u{201C}', '\u{201E}'),
+    ('\u{2020}', '\u{2022}'),
+    ('\u{2026}', '\u{2026}'),
+    ('\u{2030}', '\u{2030}'),
+    ('\u{2039}', '\u{203A}'),
+    ('\u{2044}', '\u{2044}'),
+    ('\u{20AC}', '\u{20AC}'),
+    ('\u{2122}', '\u{2122}'),
+    ('\u{2202}', '\u{2202}'),
+    ('\u{2206}', '\u{2206}'),
+    ('\u{220F}', '\u{220F}'),
+    ('\u{2211}', '\u{2212}'),
+    ('\u{221A}', '\u{221A}'),
+    ('\u{221E}', '\u{221E}'),
+    ('\u{222B}', '\u{222B}'),
+    ('\u{2248}', '\u{2248}'),
+    ('\u{2260}', '\u{2260}'),
+    ('\u{2264}', '\u{2265}'),
+    ('\u{25CA}', '\u{25CA}'),
+    ('\u{FB01}', '\u{FB02}'),
+];
+
+/// Central European letters.
+const ADOBE_LATIN_2_EXTRA: Ranges = &[
+    ('\u{0102}', '\u{0107}'),
+    ('\u{010C}', '\u{0111}'),
+    ('\u{0118}', '\u{011B}'),
+    ('\u{0139}', '\u{013A}'),
+    ('\u{013D}', '\u{013E}'),
+    ('\u{0143}', '\u{0144}'),
+    ('\u{0147}', '\u{0148}'),
+    ('\u{0150}', '\u{0151}'),
+    ('\u{0154}', '\u{0155}'),
+    ('\u{0158}', '\u{015B}'),
+    ('\u{015E}', '\u{015F}'),
+    ('\u{0162}', '\u{0165}'),
+    ('\u{016E}', '\u{0171}'),
+    ('\u{0179}', '\u{017C}'),
+    ('\u{0218}', '\u{021B}'),
+];
+
+/// The rest of Latin Extended-A for Baltic, Turkish, Esperanto, Maltese and Sámi, Welsh,
+/// Nordic and German letters, fractions and currency signs.
+const ADOBE_LATIN_3_EXTRA: Ranges = &[
+    ('\u{0100}', '\u{017F}'),
+    ('\u{01FA}', '\u{01FF}'),
+    ('\u{0237}', '\u{0237}'),
+    ('\u{02BC}', '\u{02BC}'),
+    ('\u{02C9}', '\u{02C9}'),
+    ('\u{1E80}', '\u{1E85}'),
+    ('\u{1E9E}', '\u{1E9E}'),
+    ('\u{1EF2}', '\u{1EF3}'),
+    ('\u{20A1}', '\u{20A1}'),
+    ('\u{20A4}', '\u{20A4}'),
+    ('\u{20A6}', '\u{20A6}'),
+    ('\u{20A9}', '\u{20A9}'),
+    ('\u{20AB}', '\u{20AB}'),
+    ('\u{20B1}', '\u{20B1}'),
+    ('\u{20B4}', '\u{20B4}'),
+    ('\u{20B8}', '\u{20BA}'),
+    ('\u{20BD}', '\u{20BD}'),
+    ('\u{2113}', '\u{2113}'),
+    ('\u{2116}', '\u{2116}'),
+    ('\u{212E}', '\u{212E}'),
+    ('\u{2153}', '\u{2154}'),
+    ('\u{215B}', '\u{215E}'),
+];
+
+/// Pinyin, Pan-African, Sámi, Livonian and transliteration letters, and combining accents.
+const ADOBE_LATIN_4_EXTRA: Ranges = &[
+    ('\u{0181}', '\u{0181}'),
+    ('\u{0186}', '\u{0186}'),
+    ('\u{018A}', '\u{018A}'),
+    ('\u{018E}', '\u{018E}'),
+    ('\u{0190}', '\u{0191}'),
+    ('\u{0193}', '\u{0194}'),
+    ('\u{0196}', '\u{0196}'),
+    ('\u{0198}', '\u{0199}'),
+    ('\u{019D}', '\u{019D}'),
+    ('\u{019F}', '\u{019F}'),
+    ('\u{01B2}', '\u{01B4}'),
+    ('\u{01B7}', '\u{01B7}'),
+    ('\u{01CD}', '\u{01DD}'),
+    ('\u{01E4}', '\u{01EF}'),
+    ('\u{022A}', '\u{022D}'),
+    ('\u{0230}', '\u{0233}'),
+    ('\u{0253}', '\u{0254}'),
+    ('\u{0257}', '\u{0257}'),
+    ('\u{025B}', '\u{025B}'),
+    ('\u{0260}', '\u{0260}'),
+    ('\u{0263}', '\u{0263}'),
+    ('\u{0269}', '\u{0269}'),
+    ('\u{0272}', '\u{0272}'),
+    ('\u{0275}', '\u{0275}'),
+    ('\u{028B}', '\u{028B}'),
+    ('\u{0292}', '\u{0292}'),
+    ('\u{02BB}', '\u{02BB}'),
+    ('\u{0300}', '\u{0304}'),
+    ('\u{0306}', '\u{030C}'),
+    ('\u{0312}', '\u{0312}'),
+    ('\u{0323}', '\u{0323}'),
+    ('\u{0326}', '\u{0328}'),
+    ('\u{0331}', '\u{0331}'),
+    ('\u{1E0C}', '\u{1E0D}'),
+    ('\u{1E24}', '\u{1E25}'),
+    ('\u{1E36}', '\u{1E37}'),
+    ('\u{1E42}', '\u{1E47}'),
+    ('\u{1E5A}', '\u{1E5B}'),
+    ('\u{1E62}', '\u{1E63}'),
+    ('\u{1E6C}', '\u{1E6D}'),
+    ('\u{1E92}', '\u{1E93}'),
+    ('\u{1EBC}', '\u{1EBD}'),
+    ('\u{1EF8}', '\u{1EF9}'),
+];
+
+/// Vietnamese.
+const ADOBE_LATIN_5_EXTRA: Ranges = &[
+    ('\u{01A0}', '\u{01A1}'),
+    ('\u{01AF}', '\u{01B0}'),
+    ('\u{0309}', '\u{0309}'),
+    ('\u{031B}', '\u{031B}'),
+    ('\u{1EA0}', '\u{1EF9}'),
+];
+
+const GOOGLE_FONTS_LATIN_CORE_EXTRA: Ranges = &[
+    ('\u{0237}', '\u{0237}'),
+    ('\u{02BC}', '\u{02BC}'),
+    ('\u{02C9}', '\u{02C9}'),
+    ('\u{0300}', '\u{0304}'),
+    ('\u{0306}', '\u{0308}'),
+    ('\u{030A}', '\u{030C}'),
+    ('\u{0312}', '\u{0312}'),
+    ('\u{0326}', '\u{0328}'),
+    ('\u{0335}', '\u{0335}'),
+    ('\u{2009}', '\u{2009}'),
+    ('\u{2074}', '\u{2074}'),
+    ('\u{2215}', '\u{2215}'),
+];
+
+const GREEK: Ranges = &[
+    ('\u{037E}', '\u{037E}'),
+    ('\u{0384}', '\u{038A}'),
+    ('\u{038C}', '\u{038C}'),
+    ('\u{038E}', '\u{03A1}'),
+    ('\u{03A3}', '\u{03CE}'),
+];
+
+const CYRILLIC: Ranges = &[
+    ('\u{0400}', '\u{045F}'),
+    ('\u{0490}', '\u{0491}'),
+    ('\u{2116}', '\u{2116}'),
+];
+
+/// A named set of characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Charset {
+    pub name: String,
+    pub chars: BTreeSet<char>,
+}
+
+/// The characters of a [`Charset`] that a font has and does not have.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    pub covered: Vec<char>,
+    pub missing: Vec<char>,
+}
+
+impl Coverage {
+    /// Covered characters as a percentage of the character set.
+    pub fn percentage(&self) -> f64 {
+        let total = self.covered.len() + self.missing.len();
+        if total == 0 {
+            return 100.0;
+        }
+        100.0 * self.covered.len() as f64 / total as f64
+    }
+}
+
+impl Charset {
+    fn from_ranges(name: &str, ranges: &[Ranges]) -> Self {
+        Self {
+            name: name.to_string(),
+            chars: ranges
+                .iter()
+                .flat_map(|r| r.iter())
+                .flat_map(|&(start, end)| start..=end)
+                .collect(),
+        }
+    }
+
+    /// The built-in character sets.
+    pub fn builtin() -> Vec<Self> {
+        let latin_1 = [ASCII, LATIN_1_SUPPLEMENT];
+        let adobe_latin_1 = [ASCII, LATIN_1_SUPPLEMENT, WESTERN_EXTRA];
+        let adobe_latin_2 = [&adobe_latin_1[..], &[ADOBE_LATIN_2_EXTRA]].concat();
+        let adobe_latin_3 = [&adobe_latin_2[..], &[ADOBE_LATIN_3_EXTRA]].concat();
+        let adobe_latin_4 = [&adobe_latin_3[..], &[ADOBE_LATIN_4_EXTRA]].concat();
+        let adobe_latin_5 = [&adobe_latin_4[..], &[ADOBE_LATIN_5_EXTRA]].concat();
+        vec![
+            Self::from_ranges("Latin-1", &latin_1),
+            Self::from_ranges("Adobe Latin 1", &adobe_latin_1),
+            Self::from_ranges("Adobe Latin 2", &adobe_latin_2),
+            Self::from_ranges("Adobe Latin 3", &adobe_latin_3),
+            Self::from_ranges("Adobe Latin 4", &adobe_latin_4),
+            Self::from_ranges("Adobe Latin 5", &adobe_latin_5),
+            Self::from_ranges(
+                "Google Fonts Latin Core",
+                &[
+                    ASCII,
+                    LATIN_1_SUPPLEMENT,
+                    WESTERN_EXTRA,
+                    GOOGLE_FONTS_LATIN_CORE_EXTRA,
+                ],
+            ),
+            Self::from_ranges("Greek", &[GREEK]),
+            Self::from_ranges("Cyrillic", &[CYRILLIC]),
+        ]
+    }
+
+    /// Parses a character list. Each line holds a character, a codepoint such as `U+00E9` or a
+    /// range such as `a:z` or `u+0300:u+036F`, like the "Add unicode ranges" dialog. Empty
+    /// lines and lines starting with `#` are ignored.
+    pub fn parse(name: &str, text: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut chars = BTreeSet::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let kinds = GlyphKind::from_range(line)
+                .ok_or_else(|| format!("Line {}: invalid character or range {:?}.", i + 1, line))?;
+            chars.extend(kinds.into_iter().filter_map(|k| match k {
+                GlyphKind::Char(c) => Some(c),
+                GlyphKind::Component(_) => None,
+            }));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            chars,
+        })
+    }
+
+    /// Reads a character list file; its name is the file name without the extension.
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let text = std::fs::read_to_string(path)?;
+        Self::parse(&name, &text).map_err(|err| format!("{}: {}", path.display(), err).into())
+    }
+
+    /// The character lists in the `charsets` configuration directory. Files that cannot be read
+    /// are reported on standard error and skipped.
+    pub fn user_defined() -> Vec<Self> {
+        let Ok(xdg_dirs) = xdg::BaseDirectories::with_prefix("gerb") else {
+            return vec![];
+        };
+        let mut paths = xdg_dirs
+            .list_config_files("charsets")
+            .into_iter()
+            .filter(|p| p.extension().map(|e| e == "txt").unwrap_or(false))
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths
+            .into_iter()
+            .filter_map(|p| match Self::from_file(&p) {
+                Ok(c) => Some(c),
+                Err(err) => {
+                    eprintln!("Could not load character set: {}", err);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Splits the character set into the characters in `font` and the rest.
+    pub fn coverage(&self, font: &HashSet<char>) -> Coverage {
+        let (covered, missing) = self.chars.iter().partition(|c| font.contains(c));
+        Coverage { covered, missing }
+    }
+}
+
+#[test]
+fn test_charsets() {
+    let builtin = Charset::builtin();
+    assert_eq!(builtin[0].chars.len(), 95 + 96);
+    /* The Adobe Latin sets are cumulative. */
+    for w in builtin[1..6].windows(2) {
+        assert!(w[0].chars.is_subset(&w[1].chars), "{}", w[1].name);
+        assert!(w[0].chars.len() < w[1].chars.len(), "{}", w[1].name);
+    }
+    assert_eq!(builtin[5].name, "Adobe Latin 5");
+    assert!(builtin[5].chars.contains(&'ệ'));
+    assert!(!builtin[4].chars.contains(&'ệ'));
+
+    let user = Charset::parse("user", "# vowels\na\ne\n\nU+00E9\nx:z\n").unwrap();
+    assert_eq!(user.chars.iter().collect::<String>(), "aexyzé".to_string());
+    assert!(Charset::parse("bad", "ab").is_err());
+
+    let font = "aeyz".chars().collect::<HashSet<char>>();
+    let coverage = user.coverage(&font);
+    assert_eq!(coverage.covered, vec!['a', 'e', 'y', 'z']);
+    assert_eq!(coverage.missing, vec!['x', 'é']);
+    assert!((coverage.percentage() - 100.0 * 4.0 / 6.0).abs() < 1e-9);
+}
@@ -19,7 +19,10 @@
  * along with gerb. If not, see <http://www.gnu.org/licenses/>.
  */
 
+pub mod agl;
+pub mod charsets;
 pub mod names;
+pub mod properties;
 
 pub mod blocks {
     use std::cmp::Ordering;
@@ -0,0 +1,250 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! General category, script and decomposition of characters.
+//!
+//! The tables are generated by `unicode.py` in this directory from the Unicode Character
+//! Database.
+
+use std::cmp::Ordering;
+
+#[rustfmt::skip]
+mod tables;
+
+pub use tables::UNICODE_VERSION;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeneralCategory {
+    UppercaseLetter,
+    LowercaseLetter,
+    TitlecaseLetter,
+    ModifierLetter,
+    OtherLetter,
+    NonspacingMark,
+    SpacingMark,
+    EnclosingMark,
+    DecimalNumber,
+    LetterNumber,
+    OtherNumber,
+    ConnectorPunctuation,
+    DashPunctuation,
+    OpenPunctuation,
+    ClosePunctuation,
+    InitialPunctuation,
+    FinalPunctuation,
+    OtherPunctuation,
+    MathSymbol,
+    CurrencySymbol,
+    ModifierSymbol,
+    OtherSymbol,
+    SpaceSeparator,
+    LineSeparator,
+    ParagraphSeparator,
+    Control,
+    Format,
+    Surrogate,
+    PrivateUse,
+    Unassigned,
+}
+
+impl GeneralCategory {
+    /// The two letter abbreviation used in the Unicode Character Database, e.g. `Lu`.
+    pub const fn abbreviation(self) -> &'static str {
+        use GeneralCategory::*;
+        match self {
+            UppercaseLetter => "Lu",
+            LowercaseLetter => "Ll",
+            TitlecaseLetter => "Lt",
+            ModifierLetter => "Lm",
+            OtherLetter => "Lo",
+            NonspacingMark => "Mn",
+            SpacingMark => "Mc",
+            EnclosingMark => "Me",
+            DecimalNumber => "Nd",
+            LetterNumber => "Nl",
+            OtherNumber => "No",
+            ConnectorPunctuation => "Pc",
+            DashPunctuation => "Pd",
+            OpenPunctuation => "Ps",
+            ClosePunctuation => "Pe",
+            InitialPunctuation => "Pi",
+            FinalPunctuation => "Pf",
+            OtherPunctuation => "Po",
+            MathSymbol => "Sm",
+            CurrencySymbol => "Sc",
+            ModifierSymbol => "Sk",
+            OtherSymbol => "So",
+            SpaceSeparator => "Zs",
+            LineSeparator => "Zl",
+            ParagraphSeparator => "Zp",
+            Control => "Cc",
+            Format => "Cf",
+            Surrogate => "Cs",
+            PrivateUse => "Co",
+            Unassigned => "Cn",
+        }
+    }
+
+    pub const fn is_letter(self) -> bool {
+        use GeneralCategory::*;
+        matches!(
+            self,
+            UppercaseLetter | LowercaseLetter | TitlecaseLetter | ModifierLetter | OtherLetter
+        )
+    }
+
+    pub const fn is_mark(self) -> bool {
+        use GeneralCategory::*;
+        matches!(self, NonspacingMark | SpacingMark | EnclosingMark)
+    }
+}
+
+impl std::fmt::Display for GeneralCategory {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        /* Split the variant name into words: "UppercaseLetter" -> "Uppercase letter" */
+        let name = format!("{:?}", self);
+        for (i, c) in name.chars().enumerate() {
+            if i > 0 && c.is_ascii_uppercase() {
+                write!(fmt, " {}", c.to_ascii_lowercase())?;
+            } else {
+                write!(fmt, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A decomposition mapping. `tag` is the formatting tag of compatibility decompositions, e.g.
+/// `compat` or `super`, and `None` for canonical decompositions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decomposition {
+    pub tag: Option<&'static str>,
+    pub chars: Vec<char>,
+}
+
+fn find_range<T: Copy>(table: &[(u32, u32, T)], cp: u32) -> Option<T> {
+    table
+        .binary_search_by(|&(lo, hi, _)| {
+            if cp < lo {
+                Ordering::Greater
+            } else if cp > hi {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|idx| table[idx].2)
+}
+
+pub trait CharProperties {
+    fn general_category(self) -> GeneralCategory;
+    /// The script name, e.g. `Latin`, `Common` or `Inherited`. Unassigned code points have the
+    /// `Unknown` script.
+    fn script(self) -> &'static str;
+    fn decomposition(self) -> Option<Decomposition>;
+}
+
+/* Hangul syllables decompose algorithmically, see section 3.12 of the Unicode standard. */
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = 21 * T_COUNT;
+const S_COUNT: u32 = 19 * N_COUNT;
+
+impl CharProperties for char {
+    fn general_category(self) -> GeneralCategory {
+        find_range(tables::GENERAL_CATEGORIES, self as u32).unwrap_or(GeneralCategory::Unassigned)
+    }
+
+    fn script(self) -> &'static str {
+        find_range(tables::SCRIPTS, self as u32).unwrap_or("Unknown")
+    }
+
+    fn decomposition(self) -> Option<Decomposition> {
+        let cp = self as u32;
+        if (S_BASE..S_BASE + S_COUNT).contains(&cp) {
+            let s = cp - S_BASE;
+            let mut chars = vec![L_BASE + s / N_COUNT, V_BASE + (s % N_COUNT) / T_COUNT];
+            if s % T_COUNT != 0 {
+                chars.push(T_BASE + s % T_COUNT);
+            }
+            return Some(Decomposition {
+                tag: None,
+                chars: chars.into_iter().filter_map(char::from_u32).collect(),
+            });
+        }
+        let idx = tables::DECOMPOSITIONS
+            .binary_search_by_key(&cp, |&(c, _, _)| c)
+            .ok()?;
+        let (_, tag, chars) = tables::DECOMPOSITIONS[idx];
+        Some(Decomposition {
+            tag,
+            chars: chars.iter().copied().filter_map(char::from_u32).collect(),
+        })
+    }
+}
+
+#[test]
+fn test_char_properties() {
+    assert_eq!('A'.general_category(), GeneralCategory::UppercaseLetter);
+    assert_eq!(
+        '\u{0301}'.general_category(),
+        GeneralCategory::NonspacingMark
+    );
+    assert_eq!('\u{E000}'.general_category(), GeneralCategory::PrivateUse);
+    assert_eq!('\u{0378}'.general_category(), GeneralCategory::Unassigned);
+    assert_eq!(
+        GeneralCategory::UppercaseLetter.to_string(),
+        "Uppercase letter"
+    );
+
+    assert_eq!('a'.script(), "Latin");
+    assert_eq!('ж'.script(), "Cyrillic");
+    assert_eq!('1'.script(), "Common");
+    assert_eq!('\u{0301}'.script(), "Inherited");
+    assert_eq!('\u{1400}'.script(), "Canadian Aboriginal");
+
+    assert_eq!(
+        'á'.decomposition(),
+        Some(Decomposition {
+            tag: None,
+            chars: vec!['a', '\u{0301}']
+        })
+    );
+    assert_eq!(
+        '²'.decomposition(),
+        Some(Decomposition {
+            tag: Some("super"),
+            chars: vec!['2']
+        })
+    );
+    assert_eq!(
+        '한'.decomposition(),
+        Some(Decomposition {
+            tag: None,
+            chars: vec!['\u{1112}', '\u{1161}', '\u{11AB}']
+        })
+    );
+    assert_eq!('a'.decomposition(), None);
+}
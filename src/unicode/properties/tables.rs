@@ -0,0 +1,10022 @@
+// NOTE: The following code was generated by "src/unicode/properties/unicode.py", do not edit
+// directly
+
+use super::GeneralCategory::{self, *};
+
+pub const UNICODE_VERSION: (u64, u64, u64) = (14, 0, 0);
+
+pub const GENERAL_CATEGORIES: &[(u32, u32, GeneralCategory)] = &[
+    (0x0, 0x1F, Control),
+    (0x20, 0x20, SpaceSeparator),
+    (0x21, 0x23, OtherPunctuation),
+    (0x24, 0x24, CurrencySymbol),
+    (0x25, 0x27, OtherPunctuation),
+    (0x28, 0x28, OpenPunctuation),
+    (0x29, 0x29, ClosePunctuation),
+    (0x2A, 0x2A, OtherPunctuation),
+    (0x2B, 0x2B, MathSymbol),
+    (0x2C, 0x2C, OtherPunctuation),
+    (0x2D, 0x2D, DashPunctuation),
+    (0x2E, 0x2F, OtherPunctuation),
+    (0x30, 0x39, DecimalNumber),
+    (0x3A, 0x3B, OtherPunctuation),
+    (0x3C, 0x3E, MathSymbol),
+    (0x3F, 0x40, OtherPunctuation),
+    (0x41, 0x5A, UppercaseLetter),
+    (0x5B, 0x5B, OpenPunctuation),
+    (0x5C, 0x5C, OtherPunctuation),
+    (0x5D, 0x5D, ClosePunctuation),
+    (0x5E, 0x5E, ModifierSymbol),
+    (0x5F, 0x5F, ConnectorPunctuation),
+    (0x60, 0x60, ModifierSymbol),
+    (0x61, 0x7A, LowercaseLetter),
+    (0x7B, 0x7B, OpenPunctuation),
+    (0x7C, 0x7C, MathSymbol),
+    (0x7D, 0x7D, ClosePunctuation),
+    (0x7E, 0x7E, MathSymbol),
+    (0x7F, 0x9F, Control),
+    (0xA0, 0xA0, SpaceSeparator),
+    (0xA1, 0xA1, OtherPunctuation),
+    (0xA2, 0xA5, CurrencySymbol),
+    (0xA6, 0xA6, OtherSymbol),
+    (0xA7, 0xA7, OtherPunctuation),
+    (0xA8, 0xA8, ModifierSymbol),
+    (0xA9, 0xA9, OtherSymbol),
+    (0xAA, 0xAA, OtherLetter),
+    (0xAB, 0xAB, InitialPunctuation),
+    (0xAC, 0xAC, MathSymbol),
+    (0xAD, 0xAD, Format),
+    (0xAE, 0xAE, OtherSymbol),
+    (0xAF, 0xAF, ModifierSymbol),
+    (0xB0, 0xB0, OtherSymbol),
+    (0xB1, 0xB1, MathSymbol),
+    (0xB2, 0xB3, OtherNumber),
+    (0xB4, 0xB4, ModifierSymbol),
+    (0xB5, 0xB5, LowercaseLetter),
+    (0xB6, 0xB7, OtherPunctuation),
+    (0xB8, 0xB8, ModifierSymbol),
+    (0xB9, 0xB9, OtherNumber),
+    (0xBA, 0xBA, OtherLetter),
+    (0xBB, 0xBB, FinalPunctuation),
+    (0xBC, 0xBE, OtherNumber),
+    (0xBF, 0xBF, OtherPunctuation),
+    (0xC0, 0xD6, UppercaseLetter),
+    (0xD7, 0xD7, MathSymbol),
+    (0xD8, 0xDE, UppercaseLetter),
+    (0xDF, 0xF6, LowercaseLetter),
+    (0xF7, 0xF7, MathSymbol),
+    (0xF8, 0xFF, LowercaseLetter),
+    (0x100, 0x100, UppercaseLetter),
+    (0x101, 0x101, LowercaseLetter),
+    (0x102, 0x102, UppercaseLetter),
+    (0x103, 0x103, LowercaseLetter),
+    (0x104, 0x104, UppercaseLetter),
+    (0x105, 0x105, LowercaseLetter),
+    (0x106, 0x106, UppercaseLetter),
+    (0x107, 0x107, LowercaseLetter),
+    (0x108, 0x108, UppercaseLetter),
+    (0x109, 0x109, LowercaseLetter),
+    (0x10A, 0x10A, UppercaseLetter),
+    (0x10B, 0x10B, LowercaseLetter),
+    (0x10C, 0x10C, UppercaseLetter),
+    (0x10D, 0x10D, LowercaseLetter),
+    (0x10E, 0x10E, UppercaseLetter),
+    (0x10F, 0x10F, LowercaseLetter),
+    (0x110, 0x110, UppercaseLetter),
+    (0x111, 0x111, LowercaseLetter),
+    (0x112, 0x112, UppercaseLetter),
+    (0x113, 0x113, LowercaseLetter),
+    (0x114, 0x114, UppercaseLetter),
+    (0x115, 0x115, LowercaseLetter),
+    (0x116, 0x116, UppercaseLetter),
+    (0x117, 0x117, LowercaseLetter),
+    (0x118, 0x118, UppercaseLetter),
+    (0x119, 0x119, LowercaseLetter),
+    (0x11A, 0x11A, UppercaseLetter),
+    (0x11B, 0x11B, LowercaseLetter),
+    (0x11C, 0x11C, UppercaseLetter),
+    (0x11D, 0x11D, LowercaseLetter),
+    (0x11E, 0x11E, UppercaseLetter),
+    (0x11F, 0x11F, LowercaseLetter),
+    (0x120, 0x120, UppercaseLetter),
+    (0x121, 0x121, LowercaseLetter),
+    (0x122, 0x122, UppercaseLetter),
+    (0x123, 0x123, LowercaseLetter),
+    (0x124, 0x124, UppercaseLetter),
+    (0x125, 0x125, LowercaseLetter),
+    (0x126, 0x126, UppercaseLetter),
+    (0x127, 0x127, LowercaseLetter),
+    (0x128, 0x128, UppercaseLetter),
+    (0x129, 0x129, LowercaseLetter),
+    (0x12A, 0x12A, UppercaseLetter),
+    (0x12B, 0x12B, LowercaseLetter),
+    (0x12C, 0x12C, UppercaseLetter),
+    (0x12D, 0x12D, LowercaseLetter),
+    (0x12E, 0x12E, UppercaseLetter),
+    (0x12F, 0x12F, LowercaseLetter),
+    (0x130, 0x130, UppercaseLetter),
+    (0x131, 0x131, LowercaseLetter),
+    (0x132, 0x132, UppercaseLetter),
+    (0x133, 0x133, LowercaseLetter),
+    (0x134, 0x134, UppercaseLetter),
+    (0x135, 0x135, LowercaseLetter),
+    (0x136, 0x136, UppercaseLetter),
+    (0x137, 0x138, LowercaseLetter),
+    (0x139, 0x139, UppercaseLetter),
+    (0x13A, 0x13A, LowercaseLetter),
+    (0x13B, 0x13B, UppercaseLetter),
+    (0x13C, 0x13C, LowercaseLetter),
+    (0x13D, 0x13D, UppercaseLetter),
+    (0x13E, 0x13E, LowercaseLetter),
+    (0x13F, 0x13F, UppercaseLetter),
+    (0x140, 0x140, LowercaseLetter),
+    (0x141, 0x141, UppercaseLetter),
+    (0x142, 0x142, LowercaseLetter),
+    (0x143, 0x143, UppercaseLetter),
+    (0x144, 0x144, LowercaseLetter),
+    (0x145, 0x145, UppercaseLetter),
+    (0x146, 0x146, LowercaseLetter),
+    (0x147, 0x147, UppercaseLetter),
+    (0x148, 0x149, LowercaseLetter),
+    (0x14A, 0x14A, UppercaseLetter),
+    (0x14B, 0x14B, LowercaseLetter),
+    (0x14C, 0x14C, UppercaseLetter),
+    (0x14D, 0x14D, LowercaseLetter),
+    (0x14E, 0x14E, UppercaseLetter),
+    (0x14F, 0x14F, LowercaseLetter),
+    (0x150, 0x150, UppercaseLetter),
+    (0x151, 0x151, LowercaseLetter),
+    (0x152, 0x152, UppercaseLetter),
+    (0x153, 0x153, LowercaseLetter),
+    (0x154, 0x154, UppercaseLetter),
+    (0x155, 0x155, LowercaseLetter),
+    (0x156, 0x156, UppercaseLetter),
+    (0x157, 0x157, LowercaseLetter),
+    (0x158, 0x158, UppercaseLetter),
+    (0x159, 0x159, LowercaseLetter),
+    (0x15A, 0x15A, UppercaseLetter),
+    (0x15B, 0x15B, LowercaseLetter),
+    (0x15C, 0x15C, UppercaseLetter),
+    (0x15D, 0x15D, LowercaseLetter),
+    (0x15E, 0x15E, UppercaseLetter),
+    (0x15F, 0x15F, LowercaseLetter),
+    (0x160, 0x160, UppercaseLetter),
+    (0x161, 0x161, LowercaseLetter),
+    (0x162, 0x162, UppercaseLetter),
+    (0x163, 0x163, LowercaseLetter),
+    (0x164, 0x164, UppercaseLetter),
+    (0x165, 0x165, LowercaseLetter),
+    (0x166, 0x166, UppercaseLetter),
+    (0x167, 0x167, LowercaseLetter),
+    (0x168, 0x168, UppercaseLetter),
+    (0x169, 0x169, LowercaseLetter),
+    (0x16A, 0x16A, UppercaseLetter),
+    (0x16B, 0x16B, LowercaseLetter),
+    (0x16C, 0x16C, UppercaseLetter),
+    (0x16D, 0x16D, LowercaseLetter),
+    (0x16E, 0x16E, UppercaseLetter),
+    (0x16F, 0x16F, LowercaseLetter),
+    (0x170, 0x170, UppercaseLetter),
+    (0x171, 0x171, LowercaseLetter),
+    (0x172, 0x172, UppercaseLetter),
+    (0x173, 0x173, LowercaseLetter),
+    (0x174, 0x174, UppercaseLetter),
+    (0x175, 0x175, LowercaseLetter),
+    (0x176, 0x176, UppercaseLetter),
+    (0x177, 0x177, LowercaseLetter),
+    (0x178, 0x179, UppercaseLetter),
+    (0x17A, 0x17A, LowercaseLetter),
+    (0x17B, 0x17B, UppercaseLetter),
+    (0x17C, 0x17C, LowercaseLetter),
+    (0x17D, 0x17D, UppercaseLetter),
+    (0x17E, 0x180, LowercaseLetter),
+    (0x181, 0x182, UppercaseLetter),
+    (0x183, 0x183, LowercaseLetter),
+    (0x184, 0x184, UppercaseLetter),
+    (0x185, 0x185, LowercaseLetter),
+    (0x186, 0x187, UppercaseLetter),
+    (0x188, 0x188, LowercaseLetter),
+    (0x189, 0x18B, UppercaseLetter),
+    (0x18C, 0x18D, LowercaseLetter),
+    (0x18E, 0x191, UppercaseLetter),
+    (0x192, 0x192, LowercaseLetter),
+    (0x193, 0x194, UppercaseLetter),
+    (0x195, 0x195, LowercaseLetter),
+    (0x196, 0x198, UppercaseLetter),
+    (0x199, 0x19B, LowercaseLetter),
+    (0x19C, 0x19D, UppercaseLetter),
+    (0x19E, 0x19E, LowercaseLetter),
+    (0x19F, 0x1A0, UppercaseLetter),
+    (0x1A1, 0x1A1, LowercaseLetter),
+    (0x1A2, 0x1A2, UppercaseLetter),
+    (0x1A3, 0x1A3, LowercaseLetter),
+    (0x1A4, 0x1A4, UppercaseLetter),
+    (0x1A5, 0x1A5, LowercaseLetter),
+    (0x1A6, 0x1A7, UppercaseLetter),
+    (0x1A8, 0x1A8, LowercaseLetter),
+    (0x1A9, 0x1A9, UppercaseLetter),
+    (0x1AA, 0x1AB, LowercaseLetter),
+    (0x1AC, 0x1AC, UppercaseLetter),
+    (0x1AD, 0x1AD, LowercaseLetter),
+    (0x1AE, 0x1AF, UppercaseLetter),
+    (0x1B0, 0x1B0, LowercaseLetter),
+    (0x1B1, 0x1B3, UppercaseLetter),
+    (0x1B4, 0x1B4, LowercaseLetter),
+    (0x1B5, 0x1B5, UppercaseLetter),
+    (0x1B6, 0x1B6, LowercaseLetter),
+    (0x1B7, 0x1B8, UppercaseLetter),
+    (0x1B9, 0x1BA, LowercaseLetter),
+    (0x1BB, 0x1BB, OtherLetter),
+    (0x1BC, 0x1BC, UppercaseLetter),
+    (0x1BD, 0x1BF, LowercaseLetter),
+    (0x1C0, 0x1C3, OtherLetter),
+    (0x1C4, 0x1C4, UppercaseLetter),
+    (0x1C5, 0x1C5, TitlecaseLetter),
+    (0x1C6, 0x1C6, LowercaseLetter),
+    (0x1C7, 0x1C7, UppercaseLetter),
+    (0x1C8, 0x1C8, TitlecaseLetter),
+    (0x1C9, 0x1C9, LowercaseLetter),
+    (0x1CA, 0x1CA, UppercaseLetter),
+    (0x1CB, 0x1CB, TitlecaseLetter),
+    (0x1CC, 0x1CC, LowercaseLetter),
+    (0x1CD, 0x1CD, UppercaseLetter),
+    (0x1CE, 0x1CE, LowercaseLetter),
+    (0x1CF, 0x1CF, UppercaseLetter),
+    (0x1D0, 0x1D0, LowercaseLetter),
+    (0x1D1, 0x1D1, UppercaseLetter),
+    (0x1D2, 0x1D2, LowercaseLetter),
+    (0x1D3, 0x1D3, UppercaseLetter),
+    (0x1D4, 0x1D4, LowercaseLetter),
+    (0x1D5, 0x1D5, UppercaseLetter),
+    (0x1D6, 0x1D6, LowercaseLetter),
+    (0x1D7, 0x1D7, UppercaseLetter),
+    (0x1D8, 0x1D8, LowercaseLetter),
+    (0x1D9, 0x1D9, UppercaseLetter),
+    (0x1DA, 0x1DA, LowercaseLetter),
+    (0x1DB, 0x1DB, UppercaseLetter),
+    (0x1DC, 0x1DD, LowercaseLetter),
+    (0x1DE, 0x1DE, UppercaseLetter),
+    (0x1DF, 0x1DF, LowercaseLetter),
+    (0x1E0, 0x1E0, UppercaseLetter),
+    (0x1E1, 0x1E1, LowercaseLetter),
+    (0x1E2, 0x1E2, UppercaseLetter),
+    (0x1E3, 0x1E3, LowercaseLetter),
+    (0x1E4, 0x1E4, UppercaseLetter),
+    (0x1E5, 0x1E5, LowercaseLetter),
+    (0x1E6, 0x1E6, UppercaseLetter),
+    (0x1E7, 0x1E7, LowercaseLetter),
+    (0x1E8, 0x1E8, UppercaseLetter),
+    (0x1E9, 0x1E9, LowercaseLetter),
+    (0x1EA, 0x1EA, UppercaseLetter),
+    (0x1EB, 0x1EB, LowercaseLetter),
+    (0x1EC, 0x1EC, UppercaseLetter),
+    (0x1ED, 0x1ED, LowercaseLetter),
+    (0x1EE, 0x1EE, UppercaseLetter),
+    (0x1EF, 0x1F0, LowercaseLetter),
+    (0x1F1, 0x1F1, UppercaseLetter),
+    (0x1F2, 0x1F2, TitlecaseLetter),
+    (0x1F3, 0x1F3, LowercaseLetter),
+    (0x1F4, 0x1F4, UppercaseLetter),
+    (0x1F5, 0x1F5, LowercaseLetter),
+    (0x1F6, 0x1F8, UppercaseLetter),
+    (0x1F9, 0x1F9, LowercaseLetter),
+    (0x1FA, 0x1FA, UppercaseLetter),
+    (0x1FB, 0x1FB, LowercaseLetter),
+    (0x1FC, 0x1FC, UppercaseLetter),
+    (0x1FD, 0x1FD, LowercaseLetter),
+    (0x1FE, 0x1FE, UppercaseLetter),
+    (0x1FF, 0x1FF, LowercaseLetter),
+    (0x200, 0x200, UppercaseLetter),
+    (0x201, 0x201, LowercaseLetter),
+    (0x202, 0x202, UppercaseLetter),
+    (0x203, 0x203, LowercaseLetter),
+    (0x204, 0x204, UppercaseLetter),
+    (0x205, 0x205, LowercaseLetter),
+    (0x206, 0x206, UppercaseLetter),
+    (0x207, 0x207, LowercaseLetter),
+    (0x208, 0x208, UppercaseLetter),
+    (0x209, 0x209, LowercaseLetter),
+    (0x20A, 0x20A, UppercaseLetter),
+    (0x20B, 0x20B, LowercaseLetter),
+    (0x20C, 0x20C, UppercaseLetter),
+    (0x20D, 0x20D, LowercaseLetter),
+    (0x20E, 0x20E, UppercaseLetter),
+    (0x20F, 0x20F, LowercaseLetter),
+    (0x210, 0x210, UppercaseLetter),
+    (0x211, 0x211, LowercaseLetter),
+    (0x212, 0x212, UppercaseLetter),
+    (0x213, 0x213, LowercaseLetter),
+    (0x214, 0x214, UppercaseLetter),
+    (0x215, 0x215, LowercaseLetter),
+    (0x216, 0x216, UppercaseLetter),
+    (0x217, 0x217, LowercaseLetter),
+    (0x218, 0x218, UppercaseLetter),
+    (0x219, 0x219, LowercaseLetter),
+    (0x21A, 0x21A, UppercaseLetter),
+    (0x21B, 0x21B, LowercaseLetter),
+    (0x21C, 0x21C, UppercaseLetter),
+    (0x21D, 0x21D, LowercaseLetter),
+    (0x21E, 0x21E, UppercaseLetter),
+    (0x21F, 0x21F, LowercaseLetter),
+    (0x220, 0x220, UppercaseLetter),
+    (0x221, 0x221, LowercaseLetter),
+    (0x222, 0x222, UppercaseLetter),
+    (0x223, 0x223, LowercaseLetter),
+    (0x224, 0x224, UppercaseLetter),
+    (0x225, 0x225, LowercaseLetter),
+    (0x226, 0x226, UppercaseLetter),
+    (0x227, 0x227, LowercaseLetter),
+    (0x228, 0x228, UppercaseLetter),
+    (0x229, 0x229, LowercaseLetter),
+    (0x22A, 0x22A, UppercaseLetter),
+    (0x22B, 0x22B, LowercaseLetter),
+    (0x22C, 0x22C, UppercaseLetter),
+    (0x22D, 0x22D, LowercaseLetter),
+    (0x22E, 0x22E, UppercaseLetter),
+    (0x22F, 0x22F, LowercaseLetter),
+    (0x230, 0x230, UppercaseLetter),
+    (0x231, 0x231, LowercaseLetter),
+    (0x232, 0x232, UppercaseLetter),
+    (0x233, 0x239, LowercaseLetter),
+    (0x23A, 0x23B, UppercaseLetter),
+    (0x23C, 0x23C, LowercaseLetter),
+    (0x23D, 0x23E, UppercaseLetter),
+    (0x23F, 0x240, LowercaseLetter),
+    (0x241, 0x241, UppercaseLetter),
+    (0x242, 0x242, LowercaseLetter),
+    (0x243, 0x246, UppercaseLetter),
+    (0x247, 0x247, LowercaseLetter),
+    (0x248, 0x248, UppercaseLetter),
+    (0x249, 0x249, LowercaseLetter),
+    (0x24A, 0x24A, UppercaseLetter),
+    (0x24B, 0x24B, LowercaseLetter),
+    (0x24C, 0x24C, UppercaseLetter),
+    (0x24D, 0x24D, LowercaseLetter),
+    (0x24E, 0x24E, UppercaseLetter),
+    (0x24F, 0x293, LowercaseLetter),
+    (0x294, 0x294, OtherLetter),
+    (0x295, 0x2AF, LowercaseLetter),
+    (0x2B0, 0x2C1, ModifierLetter),
+    (0x2C2, 0x2C5, ModifierSymbol),
+    (0x2C6, 0x2D1, ModifierLetter),
+    (0x2D2, 0x2DF, ModifierSymbol),
+    (0x2E0, 0x2E4, ModifierLetter),
+    (0x2E5, 0x2EB, ModifierSymbol),
+    (0x2EC, 0x2EC, ModifierLetter),
+    (0x2ED, 0x2ED, ModifierSymbol),
+    (0x2EE, 0x2EE, ModifierLetter),
+    (0x2EF, 0x2FF, ModifierSymbol),
+    (0x300, 0x36F, NonspacingMark),
+    (0x370, 0x370, UppercaseLetter),
+    (0x371, 0x371, LowercaseLetter),
+    (0x372, 0x372, UppercaseLetter),
+    (0x373, 0x373, LowercaseLetter),
+    (0x374, 0x374, ModifierLetter),
+    (0x375, 0x375, ModifierSymbol),
+    (0x376, 0x376, UppercaseLetter),
+    (0x377, 0x377, LowercaseLetter),
+    (0x37A, 0x37A, ModifierLetter),
+    (0x37B, 0x37D, LowercaseLetter),
+    (0x37E, 0x37E, OtherPunctuation),
+    (0x37F, 0x37F, UppercaseLetter),
+    (0x384, 0x385, ModifierSymbol),
+    (0x386, 0x386, UppercaseLetter),
+    (0x387, 0x387, OtherPunctuation),
+    (0x388, 0x38A, UppercaseLetter),
+    (0x38C, 0x38C, UppercaseLetter),
+    (0x38E, 0x38F, UppercaseLetter),
+    (0x390, 0x390, LowercaseLetter),
+    (0x391, 0x3A1, UppercaseLetter),
+    (0x3A3, 0x3AB, UppercaseLetter),
+    (0x3AC, 0x3CE, LowercaseLetter),
+    (0x3CF, 0x3CF, UppercaseLetter),
+    (0x3D0, 0x3D1, LowercaseLetter),
+    (0x3D2, 0x3D4, UppercaseLetter),
+    (0x3D5, 0x3D7, LowercaseLetter),
+    (0x3D8, 0x3D8, UppercaseLetter),
+    (0x3D9, 0x3D9, LowercaseLetter),
+    (0x3DA, 0x3DA, UppercaseLetter),
+    (0x3DB, 0x3DB, LowercaseLetter),
+    (0x3DC, 0x3DC, UppercaseLetter),
+    (0x3DD, 0x3DD, LowercaseLetter),
+    (0x3DE, 0x3DE, UppercaseLetter),
+    (0x3DF, 0x3DF, LowercaseLetter),
+    (0x3E0, 0x3E0, UppercaseLetter),
+    (0x3E1, 0x3E1, LowercaseLetter),
+    (0x3E2, 0x3E2, UppercaseLetter),
+    (0x3E3, 0x3E3, LowercaseLetter),
+    (0x3E4, 0x3E4, UppercaseLetter),
+    (0x3E5, 0x3E5, LowercaseLetter),
+    (0x3E6, 0x3E6, UppercaseLetter),
+    (0x3E7, 0x3E7, LowercaseLetter),
+    (0x3E8, 0x3E8, UppercaseLetter),
+    (0x3E9, 0x3E9, LowercaseLetter),
+    (0x3EA, 0x3EA, UppercaseLetter),
+    (0x3EB, 0x3EB, LowercaseLetter),
+    (0x3EC, 0x3EC, UppercaseLetter),
+    (0x3ED, 0x3ED, LowercaseLetter),
+    (0x3EE, 0x3EE, UppercaseLetter),
+    (0x3EF, 0x3F3, LowercaseLetter),
+    (0x3F4, 0x3F4, UppercaseLetter),
+    (0x3F5, 0x3F5, LowercaseLetter),
+    (0x3F6, 0x3F6, MathSymbol),
+    (0x3F7, 0x3F7, UppercaseLetter),
+    (0x3F8, 0x3F8, LowercaseLetter),
+    (0x3F9, 0x3FA, UppercaseLetter),
+    (0x3FB, 0x3FC, LowercaseLetter),
+    (0x3FD, 0x42F, UppercaseLetter),
+    (0x430, 0x45F, LowercaseLetter),
+    (0x460, 0x460, UppercaseLetter),
+    (0x461, 0x461, LowercaseLetter),
+    (0x462, 0x462, UppercaseLetter),
+    (0x463, 0x463, LowercaseLetter),
+    (0x464, 0x464, UppercaseLetter),
+    (0x465, 0x465, LowercaseLetter),
+    (0x466, 0x466, UppercaseLetter),
+    (0x467, 0x467, LowercaseLetter),
+    (0x468, 0x468, UppercaseLetter),
+    (0x469, 0x469, LowercaseLetter),
+    (0x46A, 0x46A, UppercaseLetter),
+    (0x46B, 0x46B, LowercaseLetter),
+    (0x46C, 0x46C, UppercaseLetter),
+    (0x46D, 0x46D, LowercaseLetter),
+    (0x46E, 0x46E, UppercaseLetter),
+    (0x46F, 0x46F, LowercaseLetter),
+    (0x470, 0x470, UppercaseLetter),
+    (0x471, 0x471, LowercaseLetter),
+    (0x472, 0x472, UppercaseLetter),
+    (0x473, 0x473, LowercaseLetter),
+    (0x474, 0x474, UppercaseLetter),
+    (0x475, 0x475, LowercaseLetter),
+    (0x476, 0x476, UppercaseLetter),
+    (0x477, 0x477, LowercaseLetter),
+    (0x478, 0x478, UppercaseLetter),
+    (0x479, 0x479, LowercaseLetter),
+    (0x47A, 0x47A, UppercaseLetter),
+    (0x47B, 0x47B, LowercaseLetter),
+    (0x47C, 0x47C, UppercaseLetter),
+    (0x47D, 0x47D, LowercaseLetter),
+    (0x47E, 0x47E, UppercaseLetter),
+    (0x47F, 0x47F, LowercaseLetter),
+    (0x480, 0x480, UppercaseLetter),
+    (0x481, 0x481, LowercaseLetter),
+    (0x482, 0x482, OtherSymbol),
+    (0x483, 0x487, NonspacingMark),
+    (0x488, 0x489, EnclosingMark),
+    (0x48A, 0x48A, UppercaseLetter),
+    (0x48B, 0x48B, LowercaseLetter),
+    (0x48C, 0x48C, UppercaseLetter),
+    (0x48D, 0x48D, LowercaseLetter),
+    (0x48E, 0x48E, UppercaseLetter),
+    (0x48F, 0x48F, LowercaseLetter),
+    (0x490, 0x490, UppercaseLetter),
+    (0x491, 0x491, LowercaseLetter),
+    (0x492, 0x492, UppercaseLetter),
+    (0x493, 0x493, LowercaseLetter),
+    (0x494, 0x494, UppercaseLetter),
+    (0x495, 0x495, LowercaseLetter),
+    (0x496, 0x496, UppercaseLetter),
+    (0x497, 0x497, LowercaseLetter),
+    (0x498, 0x498, UppercaseLetter),
+    (0x499, 0x499, LowercaseLetter),
+    (0x49A, 0x49A, UppercaseLetter),
+    (0x49B, 0x49B, LowercaseLetter),
+    (0x49C, 0x49C, UppercaseLetter),
+    (0x49D, 0x49D, LowercaseLetter),
+    (0x49E, 0x49E, UppercaseLetter),
+    (0x49F, 0x49F, LowercaseLetter),
+    (0x4A0, 0x4A0, UppercaseLetter),
+    (0x4A1, 0x4A1, LowercaseLetter),
+    (0x4A2, 0x4A2, UppercaseLetter),
+    (0x4A3, 0x4A3, LowercaseLetter),
+    (0x4A4, 0x4A4, UppercaseLetter),
+    (0x4A5, 0x4A5, LowercaseLetter),
+    (0x4A6, 0x4A6, UppercaseLetter),
+    (0x4A7, 0x4A7, LowercaseLetter),
+    (0x4A8, 0x4A8, UppercaseLetter),
+    (0x4A9, 0x4A9, LowercaseLetter),
+    (0x4AA, 0x4AA, UppercaseLetter),
+    (0x4AB, 0x4AB, LowercaseLetter),
+    (0x4AC, 0x4AC, UppercaseLetter),
+    (0x4AD, 0x4AD, LowercaseLetter),
+    (0x4AE, 0x4AE, UppercaseLetter),
+    (0x4AF, 0x4AF, LowercaseLetter),
+    (0x4B0, 0x4B0, UppercaseLetter),
+    (0x4B1, 0x4B1, LowercaseLetter),
+    (0x4B2, 0x4B2, UppercaseLetter),
+    (0x4B3, 0x4B3, LowercaseLetter),
+    (0x4B4, 0x4B4, UppercaseLetter),
+    (0x4B5, 0x4B5, LowercaseLetter),
+    (0x4B6, 0x4B6, UppercaseLetter),
+    (0x4B7, 0x4B7, LowercaseLetter),
+    (0x4B8, 0x4B8, UppercaseLetter),
+    (0x4B9, 0x4B9, LowercaseLetter),
+    (0x4BA, 0x4BA, UppercaseLetter),
+    (0x4BB, 0x4BB, LowercaseLetter),
+    (0x4BC, 0x4BC, UppercaseLetter),
+    (0x4BD, 0x4BD, LowercaseLetter),
+    (0x4BE, 0x4BE, UppercaseLetter),
+    (0x4BF, 0x4BF, LowercaseLetter),
+    (0x4C0, 0x4C1, UppercaseLetter),
+    (0x4C2, 0x4C2, LowercaseLetter),
+    (0x4C3, 0x4C3, UppercaseLetter),
+    (0x4C4, 0x4C4, LowercaseLetter),
+    (0x4C5, 0x4C5, UppercaseLetter),
+    (0x4C6, 0x4C6, LowercaseLetter),
+    (0x4C7, 0x4C7, UppercaseLetter),
+    (0x4C8, 0x4C8, LowercaseLetter),
+    (0x4C9, 0x4C9, UppercaseLetter),
+    (0x4CA, 0x4CA, LowercaseLetter),
+    (0x4CB, 0x4CB, UppercaseLetter),
+    (0x4CC, 0x4CC, LowercaseLetter),
+    (0x4CD, 0x4CD, UppercaseLetter),
+    (0x4CE, 0x4CF, LowercaseLetter),
+    (0x4D0, 0x4D0, UppercaseLetter),
+    (0x4D1, 0x4D1, LowercaseLetter),
+    (0x4D2, 0x4D2, UppercaseLetter),
+    (0x4D3, 0x4D3, LowercaseLetter),
+    (0x4D4, 0x4D4, UppercaseLetter),
+    (0x4D5, 0x4D5, LowercaseLetter),
+    (0x4D6, 0x4D6, UppercaseLetter),
+    (0x4D7, 0x4D7, LowercaseLetter),
+    (0x4D8, 0x4D8, UppercaseLetter),
+    (0x4D9, 0x4D9, LowercaseLetter),
+    (0x4DA, 0x4DA, UppercaseLetter),
+    (0x4DB, 0x4DB, LowercaseLetter),
+    (0x4DC, 0x4DC, UppercaseLetter),
+    (0x4DD, 0x4DD, LowercaseLetter),
+    (0x4DE, 0x4DE, UppercaseLetter),
+    (0x4DF, 0x4DF, LowercaseLetter),
+    (0x4E0, 0x4E0, UppercaseLetter),
+    (0x4E1, 0x4E1, LowercaseLetter),
+    (0x4E2, 0x4E2, UppercaseLetter),
+    (0x4E3, 0x4E3, LowercaseLetter),
+    (0x4E4, 0x4E4, UppercaseLetter),
+    (0x4E5, 0x4E5, LowercaseLetter),
+    (0x4E6, 0x4E6, UppercaseLetter),
+    (0x4E7, 0x4E7, LowercaseLetter),
+    (0x4E8, 0x4E8, UppercaseLetter),
+    (0x4E9, 0x4E9, LowercaseLetter),
+    (0x4EA, 0x4EA, UppercaseLetter),
+    (0x4EB, 0x4EB, LowercaseLetter),
+    (0x4EC, 0x4EC, UppercaseLetter),
+    (0x4ED, 0x4ED, LowercaseLetter),
+    (0x4EE, 0x4EE, UppercaseLetter),
+    (0x4EF, 0x4EF, LowercaseLetter),
+    (0x4F0, 0x4F0, UppercaseLetter),
+    (0x4F1, 0x4F1, LowercaseLetter),
+    (0x4F2, 0x4F2, UppercaseLetter),
+    (0x4F3, 0x4F3, LowercaseLetter),
+    (0x4F4, 0x4F4, UppercaseLetter),
+    (0x4F5, 0x4F5, LowercaseLetter),
+    (0x4F6, 0x4F6, UppercaseLetter),
+    (0x4F7, 0x4F7, LowercaseLetter),
+    (0x4F8, 0x4F8, UppercaseLetter),
+    (0x4F9, 0x4F9, LowercaseLetter),
+    (0x4FA, 0x4FA, UppercaseLetter),
+    (0x4FB, 0x4FB, LowercaseLetter),
+    (0x4FC, 0x4FC, UppercaseLetter),
+    (0x4FD, 0x4FD, LowercaseLetter),
+    (0x4FE, 0x4FE, UppercaseLetter),
+    (0x4FF, 0x4FF, LowercaseLetter),
+    (0x500, 0x500, UppercaseLetter),
+    (0x501, 0x501, LowercaseLetter),
+    (0x502, 0x502, UppercaseLetter),
+    (0x503, 0x503, LowercaseLetter),
+    (0x504, 0x504, UppercaseLetter),
+    (0x505, 0x505, LowercaseLetter),
+    (0x506, 0x506, UppercaseLetter),
+    (0x507, 0x507, LowercaseLetter),
+    (0x508, 0x508, UppercaseLetter),
+    (0x509, 0x509, LowercaseLetter),
+    (0x50A, 0x50A, UppercaseLetter),
+    (0x50B, 0x50B, LowercaseLetter),
+    (0x50C, 0x50C, UppercaseLetter),
+    (0x50D, 0x50D, LowercaseLetter),
+    (0x50E, 0x50E, UppercaseLetter),
+    (0x50F, 0x50F, LowercaseLetter),
+    (0x510, 0x510, UppercaseLetter),
+    (0x511, 0x511, LowercaseLetter),
+    (0x512, 0x512, UppercaseLetter),
+    (0x513, 0x513, LowercaseLetter),
+    (0x514, 0x514, UppercaseLetter),
+    (0x515, 0x515, LowercaseLetter),
+    (0x516, 0x516, UppercaseLetter),
+    (0x517, 0x517, LowercaseLetter),
+    (0x518, 0x518, UppercaseLetter),
+    (0x519, 0x519, LowercaseLetter),
+    (0x51A, 0x51A, UppercaseLetter),
+    (0x51B, 0x51B, LowercaseLetter),
+    (0x51C, 0x51C, UppercaseLetter),
+    (0x51D, 0x51D, LowercaseLetter),
+    (0x51E, 0x51E, UppercaseLetter),
+    (0x51F, 0x51F, LowercaseLetter),
+    (0x520, 0x520, UppercaseLetter),
+    (0x521, 0x521, LowercaseLetter),
+    (0x522, 0x522, UppercaseLetter),
+    (0x523, 0x523, LowercaseLetter),
+    (0x524, 0x524, UppercaseLetter),
+    (0x525, 0x525, LowercaseLetter),
+    (0x526, 0x526, UppercaseLetter),
+    (0x527, 0x527, LowercaseLetter),
+    (0x528, 0x528, UppercaseLetter),
+    (0x529, 0x529, LowercaseLetter),
+    (0x52A, 0x52A, UppercaseLetter),
+    (0x52B, 0x52B, LowercaseLetter),
+    (0x52C, 0x52C, UppercaseLetter),
+    (0x52D, 0x52D, LowercaseLetter),
+    (0x52E, 0x52E, UppercaseLetter),
+    (0x52F, 0x52F, LowercaseLetter),
+    (0x531, 0x556, UppercaseLetter),
+    (0x559, 0x559, ModifierLetter),
+    (0x55A, 0x55F, OtherPunctuation),
+    (0x560, 0x588, LowercaseLetter),
+    (0x589, 0x589, OtherPunctuation),
+    (0x58A, 0x58A, DashPunctuation),
+    (0x58D, 0x58E, OtherSymbol),
+    (0x58F, 0x58F, CurrencySymbol),
+    (0x591, 0x5BD, NonspacingMark),
+    (0x5BE, 0x5BE, DashPunctuation),
+    (0x5BF, 0x5BF, NonspacingMark),
+    (0x5C0, 0x5C0, OtherPunctuation),
+    (0x5C1, 0x5C2, NonspacingMark),
+    (0x5C3, 0x5C3, OtherPunctuation),
+    (0x5C4, 0x5C5, NonspacingMark),
+    (0x5C6, 0x5C6, OtherPunctuation),
+    (0x5C7, 0x5C7, NonspacingMark),
+    (0x5D0, 0x5EA, OtherLetter),
+    (0x5EF, 0x5F2, OtherLetter),
+    (0x5F3, 0x5F4, OtherPunctuation),
+    (0x600, 0x605, Format),
+    (0x606, 0x608, MathSymbol),
+    (0x609, 0x60A, OtherPunctuation),
+    (0x60B, 0x60B, CurrencySymbol),
+    (0x60C, 0x60D, OtherPunctuation),
+    (0x60E, 0x60F, OtherSymbol),
+    (0x610, 0x61A, NonspacingMark),
+    (0x61B, 0x61B, OtherPunctuation),
+    (0x61C, 0x61C, Format),
+    (0x61D, 0x61F, OtherPunctuation),
+    (0x620, 0x63F, OtherLetter),
+    (0x640, 0x640, ModifierLetter),
+    (0x641, 0x64A, OtherLetter),
+    (0x64B, 0x65F, NonspacingMark),
+    (0x660, 0x669, DecimalNumber),
+    (0x66A, 0x66D, OtherPunctuation),
+    (0x66E, 0x66F, OtherLetter),
+    (0x670, 0x670, NonspacingMark),
+    (0x671, 0x6D3, OtherLetter),
+    (0x6D4, 0x6D4, OtherPunctuation),
+    (0x6D5, 0x6D5, OtherLetter),
+    (0x6D6, 0x6DC, NonspacingMark),
+    (0x6DD, 0x6DD, Format),
+    (0x6DE, 0x6DE, OtherSymbol),
+    (0x6DF, 0x6E4, NonspacingMark),
+    (0x6E5, 0x6E6, ModifierLetter),
+    (0x6E7, 0x6E8, NonspacingMark),
+    (0x6E9, 0x6E9, OtherSymbol),
+    (0x6EA, 0x6ED, NonspacingMark),
+    (0x6EE, 0x6EF, OtherLetter),
+    (0x6F0, 0x6F9, DecimalNumber),
+    (0x6FA, 0x6FC, OtherLetter),
+    (0x6FD, 0x6FE, OtherSymbol),
+    (0x6FF, 0x6FF, OtherLetter),
+    (0x700, 0x70D, OtherPunctuation),
+    (0x70F, 0x70F, Format),
+    (0x710, 0x710, OtherLetter),
+    (0x711, 0x711, NonspacingMark),
+    (0x712, 0x72F, OtherLetter),
+    (0x730, 0x74A, NonspacingMark),
+    (0x74D, 0x7A5, OtherLetter),
+    (0x7A6, 0x7B0, NonspacingMark),
+    (0x7B1, 0x7B1, OtherLetter),
+    (0x7C0, 0x7C9, DecimalNumber),
+    (0x7CA, 0x7EA, OtherLetter),
+    (0x7EB, 0x7F3, NonspacingMark),
+    (0x7F4, 0x7F5, ModifierLetter),
+    (0x7F6, 0x7F6, OtherSymbol),
+    (0x7F7, 0x7F9, OtherPunctuation),
+    (0x7FA, 0x7FA, ModifierLetter),
+    (0x7FD, 0x7FD, NonspacingMark),
+    (0x7FE, 0x7FF, CurrencySymbol),
+    (0x800, 0x815, OtherLetter),
+    (0x816, 0x819, NonspacingMark),
+    (0x81A, 0x81A, ModifierLetter),
+    (0x81B, 0x823, NonspacingMark),
+    (0x824, 0x824, ModifierLetter),
+    (0x825, 0x827, NonspacingMark),
+    (0x828, 0x828, ModifierLetter),
+    (0x829, 0x82D, NonspacingMark),
+    (0x830, 0x83E, OtherPunctuation),
+    (0x840, 0x858, OtherLetter),
+    (0x859, 0x85B, NonspacingMark),
+    (0x85E, 0x85E, OtherPunctuation),
+    (0x860, 0x86A, OtherLetter),
+    (0x870, 0x887, OtherLetter),
+    (0x888, 0x888, ModifierSymbol),
+    (0x889, 0x88E, OtherLetter),
+    (0x890, 0x891, Format),
+    (0x898, 0x89F, NonspacingMark),
+    (0x8A0, 0x8C8, OtherLetter),
+    (0x8C9, 0x8C9, ModifierLetter),
+    (0x8CA, 0x8E1, NonspacingMark),
+    (0x8E2, 0x8E2, Format),
+    (0x8E3, 0x902, NonspacingMark),
+    (0x903, 0x903, SpacingMark),
+    (0x904, 0x939, OtherLetter),
+    (0x93A, 0x93A, NonspacingMark),
+    (0x93B, 0x93B, SpacingMark),
+    (0x93C, 0x93C, NonspacingMark),
+    (0x93D, 0x93D, OtherLetter),
+    (0x93E, 0x940, SpacingMark),
+    (0x941, 0x948, NonspacingMark),
+    (0x949, 0x94C, SpacingMark),
+    (0x94D, 0x94D, NonspacingMark),
+    (0x94E, 0x94F, SpacingMark),
+    (0x950, 0x950, OtherLetter),
+    (0x951, 0x957, NonspacingMark),
+    (0x958, 0x961, OtherLetter),
+    (0x962, 0x963, NonspacingMark),
+    (0x964, 0x965, OtherPunctuation),
+    (0x966, 0x96F, DecimalNumber),
+    (0x970, 0x970, OtherPunctuation),
+    (0x971, 0x971, ModifierLetter),
+    (0x972, 0x980, OtherLetter),
+    (0x981, 0x981, NonspacingMark),
+    (0x982, 0x983, SpacingMark),
+    (0x985, 0x98C, OtherLetter),
+    (0x98F, 0x990, OtherLetter),
+    (0x993, 0x9A8, OtherLetter),
+    (0x9AA, 0x9B0, OtherLetter),
+    (0x9B2, 0x9B2, OtherLetter),
+    (0x9B6, 0x9B9, OtherLetter),
+    (0x9BC, 0x9BC, NonspacingMark),
+    (0x9BD, 0x9BD, OtherLetter),
+    (0x9BE, 0x9C0, SpacingMark),
+    (0x9C1, 0x9C4, NonspacingMark),
+    (0x9C7, 0x9C8, SpacingMark),
+    (0x9CB, 0x9CC, SpacingMark),
+    (0x9CD, 0x9CD, NonspacingMark),
+    (0x9CE, 0x9CE, OtherLetter),
+    (0x9D7, 0x9D7, SpacingMark),
+    (0x9DC, 0x9DD, OtherLetter),
+    (0x9DF, 0x9E1, OtherLetter),
+    (0x9E2, 0x9E3, NonspacingMark),
+    (0x9E6, 0x9EF, DecimalNumber),
+    (0x9F0, 0x9F1, OtherLetter),
+    (0x9F2, 0x9F3, CurrencySymbol),
+    (0x9F4, 0x9F9, OtherNumber),
+    (0x9FA, 0x9FA, OtherSymbol),
+    (0x9FB, 0x9FB, CurrencySymbol),
+    (0x9FC, 0x9FC, OtherLetter),
+    (0x9FD, 0x9FD, OtherPunctuation),
+    (0x9FE, 0x9FE, NonspacingMark),
+    (0xA01, 0xA02, NonspacingMark),
+    (0xA03, 0xA03, SpacingMark),
+    (0xA05, 0xA0A, OtherLetter),
+    (0xA0F, 0xA10, OtherLetter),
+    (0xA13, 0xA28, OtherLetter),
+    (0xA2A, 0xA30, OtherLetter),
+    (0xA32, 0xA33, OtherLetter),
+    (0xA35, 0xA36, OtherLetter),
+    (0xA38, 0xA39, OtherLetter),
+    (0xA3C, 0xA3C, NonspacingMark),
+    (0xA3E, 0xA40, SpacingMark),
+    (0xA41, 0xA42, NonspacingMark),
+    (0xA47, 0xA48, NonspacingMark),
+    (0xA4B, 0xA4D, NonspacingMark),
+    (0xA51, 0xA51, NonspacingMark),
+    (0xA59, 0xA5C, OtherLetter),
+    (0xA5E, 0xA5E, OtherLetter),
+    (0xA66, 0xA6F, DecimalNumber),
+    (0xA70, 0xA71, NonspacingMark),
+    (0xA72, 0xA74, OtherLetter),
+    (0xA75, 0xA75, NonspacingMark),
+    (0xA76, 0xA76, OtherPunctuation),
+    (0xA81, 0xA82, NonspacingMark),
+    (0xA83, 0xA83, SpacingMark),
+    (0xA85, 0xA8D, OtherLetter),
+    (0xA8F, 0xA91, OtherLetter),
+    (0xA93, 0xAA8, OtherLetter),
+    (0xAAA, 0xAB0, OtherLetter),
+    (0xAB2, 0xAB3, OtherLetter),
+    (0xAB5, 0xAB9, OtherLetter),
+    (0xABC, 0xABC, NonspacingMark),
+    (0xABD, 0xABD, OtherLetter),
+    (0xABE, 0xAC0, SpacingMark),
+    (0xAC1, 0xAC5, NonspacingMark),
+    (0xAC7, 0xAC8, NonspacingMark),
+    (0xAC9, 0xAC9, SpacingMark),
+    (0xACB, 0xACC, SpacingMark),
+    (0xACD, 0xACD, NonspacingMark),
+    (0xAD0, 0xAD0, OtherLetter),
+    (0xAE0, 0xAE1, OtherLetter),
+    (0xAE2, 0xAE3, NonspacingMark),
+    (0xAE6, 0xAEF, DecimalNumber),
+    (0xAF0, 0xAF0, OtherPunctuation),
+    (0xAF1, 0xAF1, CurrencySymbol),
+    (0xAF9, 0xAF9, OtherLetter),
+    (0xAFA, 0xAFF, NonspacingMark),
+    (0xB01, 0xB01, NonspacingMark),
+    (0xB02, 0xB03, SpacingMark),
+    (0xB05, 0xB0C, OtherLetter),
+    (0xB0F, 0xB10, OtherLetter),
+    (0xB13, 0xB28, OtherLetter),
+    (0xB2A, 0xB30, OtherLetter),
+    (0xB32, 0xB33, OtherLetter),
+    (0xB35, 0xB39, OtherLetter),
+    (0xB3C, 0xB3C, NonspacingMark),
+    (0xB3D, 0xB3D, OtherLetter),
+    (0xB3E, 0xB3E, SpacingMark),
+    (0xB3F, 0xB3F, NonspacingMark),
+    (0xB40, 0xB40, SpacingMark),
+    (0xB41, 0xB44, NonspacingMark),
+    (0xB47, 0xB48, SpacingMark),
+    (0xB4B, 0xB4C, SpacingMark),
+    (0xB4D, 0xB4D, NonspacingMark),
+    (0xB55, 0xB56, NonspacingMark),
+    (0xB57, 0xB57, SpacingMark),
+    (0xB5C, 0xB5D, OtherLetter),
+    (0xB5F, 0xB61, OtherLetter),
+    (0xB62, 0xB63, NonspacingMark),
+    (0xB66, 0xB6F, DecimalNumber),
+    (0xB70, 0xB70, OtherSymbol),
+    (0xB71, 0xB71, OtherLetter),
+    (0xB72, 0xB77, OtherNumber),
+    (0xB82, 0xB82, NonspacingMark),
+    (0xB83, 0xB83, OtherLetter),
+    (0xB85, 0xB8A, OtherLetter),
+    (0xB8E, 0xB90, OtherLetter),
+    (0xB92, 0xB95, OtherLetter),
+    (0xB99, 0xB9A, OtherLetter),
+    (0xB9C, 0xB9C, OtherLetter),
+    (0xB9E, 0xB9F, OtherLetter),
+    (0xBA3, 0xBA4, OtherLetter),
+    (0xBA8, 0xBAA, OtherLetter),
+    (0xBAE, 0xBB9, OtherLetter),
+    (0xBBE, 0xBBF, SpacingMark),
+    (0xBC0, 0xBC0, NonspacingMark),
+    (0xBC1, 0xBC2, SpacingMark),
+    (0xBC6, 0xBC8, SpacingMark),
+    (0xBCA, 0xBCC, SpacingMark),
+    (0xBCD, 0xBCD, NonspacingMark),
+    (0xBD0, 0xBD0, OtherLetter),
+    (0xBD7, 0xBD7, SpacingMark),
+    (0xBE6, 0xBEF, DecimalNumber),
+    (0xBF0, 0xBF2, OtherNumber),
+    (0xBF3, 0xBF8, OtherSymbol),
+    (0xBF9, 0xBF9, CurrencySymbol),
+    (0xBFA, 0xBFA, OtherSymbol),
+    (0xC00, 0xC00, NonspacingMark),
+    (0xC01, 0xC03, SpacingMark),
+    (0xC04, 0xC04, NonspacingMark),
+    (0xC05, 0xC0C, OtherLetter),
+    (0xC0E, 0xC10, OtherLetter),
+    (0xC12, 0xC28, OtherLetter),
+    (0xC2A, 0xC39, OtherLetter),
+    (0xC3C, 0xC3C, NonspacingMark),
+    (0xC3D, 0xC3D, OtherLetter),
+    (0xC3E, 0xC40, NonspacingMark),
+    (0xC41, 0xC44, SpacingMark),
+    (0xC46, 0xC48, NonspacingMark),
+    (0xC4A, 0xC4D, NonspacingMark),
+    (0xC55, 0xC56, NonspacingMark),
+    (0xC58, 0xC5A, OtherLetter),
+    (0xC5D, 0xC5D, OtherLetter),
+    (0xC60, 0xC61, OtherLetter),
+    (0xC62, 0xC63, NonspacingMark),
+    (0xC66, 0xC6F, DecimalNumber),
+    (0xC77, 0xC77, OtherPunctuation),
+    (0xC78, 0xC7E, OtherNumber),
+    (0xC7F, 0xC7F, OtherSymbol),
+    (0xC80, 0xC80, OtherLetter),
+    (0xC81, 0xC81, NonspacingMark),
+    (0xC82, 0xC83, SpacingMark),
+    (0xC84, 0xC84, OtherPunctuation),
+    (0xC85, 0xC8C, OtherLetter),
+    (0xC8E, 0xC90, OtherLetter),
+    (0xC92, 0xCA8, OtherLetter),
+    (0xCAA, 0xCB3, OtherLetter),
+    (0xCB5, 0xCB9, OtherLetter),
+    (0xCBC, 0xCBC, NonspacingMark),
+    (0xCBD, 0xCBD, OtherLetter),
+    (0xCBE, 0xCBE, SpacingMark),
+    (0xCBF, 0xCBF, NonspacingMark),
+    (0xCC0, 0xCC4, SpacingMark),
+    (0xCC6, 0xCC6, NonspacingMark),
+    (0xCC7, 0xCC8, SpacingMark),
+    (0xCCA, 0xCCB, SpacingMark),
+    (0xCCC, 0xCCD, NonspacingMark),
+    (0xCD5, 0xCD6, SpacingMark),
+    (0xCDD, 0xCDE, OtherLetter),
+    (0xCE0, 0xCE1, OtherLetter),
+    (0xCE2, 0xCE3, NonspacingMark),
+    (0xCE6, 0xCEF, DecimalNumber),
+    (0xCF1, 0xCF2, OtherLetter),
+    (0xD00, 0xD01, NonspacingMark),
+    (0xD02, 0xD03, SpacingMark),
+    (0xD04, 0xD0C, OtherLetter),
+    (0xD0E, 0xD10, OtherLetter),
+    (0xD12, 0xD3A, OtherLetter),
+    (0xD3B, 0xD3C, NonspacingMark),
+    (0xD3D, 0xD3D, OtherLetter),
+    (0xD3E, 0xD40, SpacingMark),
+    (0xD41, 0xD44, NonspacingMark),
+    (0xD46, 0xD48, SpacingMark),
+    (0xD4A, 0xD4C, SpacingMark),
+    (0xD4D, 0xD4D, NonspacingMark),
+    (0xD4E, 0xD4E, OtherLetter),
+    (0xD4F, 0xD4F, OtherSymbol),
+    (0xD54, 0xD56, OtherLetter),
+    (0xD57, 0xD57, SpacingMark),
+    (0xD58, 0xD5E, OtherNumber),
+    (0xD5F, 0xD61, OtherLetter),
+    (0xD62, 0xD63, NonspacingMark),
+    (0xD66, 0xD6F, DecimalNumber),
+    (0xD70, 0xD78, OtherNumber),
+    (0xD79, 0xD79, OtherSymbol),
+    (0xD7A, 0xD7F, OtherLetter),
+    (0xD81, 0xD81, NonspacingMark),
+    (0xD82, 0xD83, SpacingMark),
+    (0xD85, 0xD96, OtherLetter),
+    (0xD9A, 0xDB1, OtherLetter),
+    (0xDB3, 0xDBB, OtherLetter),
+    (0xDBD, 0xDBD, OtherLetter),
+    (0xDC0, 0xDC6, OtherLetter),
+    (0xDCA, 0xDCA, NonspacingMark),
+    (0xDCF, 0xDD1, SpacingMark),
+    (0xDD2, 0xDD4, NonspacingMark),
+    (0xDD6, 0xDD6, NonspacingMark),
+    (0xDD8, 0xDDF, SpacingMark),
+    (0xDE6, 0xDEF, DecimalNumber),
+    (0xDF2, 0xDF3, SpacingMark),
+    (0xDF4, 0xDF4, OtherPunctuation),
+    (0xE01, 0xE30, OtherLetter),
+    (0xE31, 0xE31, NonspacingMark),
+    (0xE32, 0xE33, OtherLetter),
+    (0xE34, 0xE3A, NonspacingMark),
+    (0xE3F, 0xE3F, CurrencySymbol),
+    (0xE40, 0xE45, OtherLetter),
+    (0xE46, 0xE46, ModifierLetter),
+    (0xE47, 0xE4E, NonspacingMark),
+    (0xE4F, 0xE4F, OtherPunctuation),
+    (0xE50, 0xE59, DecimalNumber),
+    (0xE5A, 0xE5B, OtherPunctuation),
+    (0xE81, 0xE82, OtherLetter),
+    (0xE84, 0xE84, OtherLetter),
+    (0xE86, 0xE8A, OtherLetter),
+    (0xE8C, 0xEA3, OtherLetter),
+    (0xEA5, 0xEA5, OtherLetter),
+    (0xEA7, 0xEB0, OtherLetter),
+    (0xEB1, 0xEB1, NonspacingMark),
+    (0xEB2, 0xEB3, OtherLetter),
+    (0xEB4, 0xEBC, NonspacingMark),
+    (0xEBD, 0xEBD, OtherLetter),
+    (0xEC0, 0xEC4, OtherLetter),
+    (0xEC6, 0xEC6, ModifierLetter),
+    (0xEC8, 0xECD, NonspacingMark),
+    (0xED0, 0xED9, DecimalNumber),
+    (0xEDC, 0xEDF, OtherLetter),
+    (0xF00, 0xF00, OtherLetter),
+    (0xF01, 0xF03, OtherSymbol),
+    (0xF04, 0xF12, OtherPunctuation),
+    (0xF13, 0xF13, OtherSymbol),
+    (0xF14, 0xF14, OtherPunctuation),
+    (0xF15, 0xF17, OtherSymbol),
+    (0xF18, 0xF19, NonspacingMark),
+    (0xF1A, 0xF1F, OtherSymbol),
+    (0xF20, 0xF29, DecimalNumber),
+    (0xF2A, 0xF33, OtherNumber),
+    (0xF34, 0xF34, OtherSymbol),
+    (0xF35, 0xF35, NonspacingMark),
+    (0xF36, 0xF36, OtherSymbol),
+    (0xF37, 0xF37, NonspacingMark),
+    (0xF38, 0xF38, OtherSymbol),
+    (0xF39, 0xF39, NonspacingMark),
+    (0xF3A, 0xF3A, OpenPunctuation),
+    (0xF3B, 0xF3B, ClosePunctuation),
+    (0xF3C, 0xF3C, OpenPunctuation),
+    (0xF3D, 0xF3D, ClosePunctuation),
+    (0xF3E, 0xF3F, SpacingMark),
+    (0xF40, 0xF47, OtherLetter),
+    (0xF49, 0xF6C, OtherLetter),
+    (0xF71, 0xF7E, NonspacingMark),
+    (0xF7F, 0xF7F, SpacingMark),
+    (0xF80, 0xF84, NonspacingMark),
+    (0xF85, 0xF85, OtherPunctuation),
+    (0xF86, 0xF87, NonspacingMark),
+    (0xF88, 0xF8C, OtherLetter),
+    (0xF8D, 0xF97, NonspacingMark),
+    (0xF99, 0xFBC, NonspacingMark),
+    (0xFBE, 0xFC5, OtherSymbol),
+    (0xFC6, 0xFC6, NonspacingMark),
+    (0xFC7, 0xFCC, OtherSymbol),
+    (0xFCE, 0xFCF, OtherSymbol),
+    (0xFD0, 0xFD4, OtherPunctuation),
+    (0xFD5, 0xFD8, OtherSymbol),
+    (0xFD9, 0xFDA, OtherPunctuation),
+    (0x1000, 0x102A, OtherLetter),
+    (0x102B, 0x102C, SpacingMark),
+    (0x102D, 0x1030, NonspacingMark),
+    (0x1031, 0x1031, SpacingMark),
+    (0x1032, 0x1037, NonspacingMark),
+    (0x1038, 0x1038, SpacingMark),
+    (0x1039, 0x103A, NonspacingMark),
+    (0x103B, 0x103C, SpacingMark),
+    (0x103D, 0x103E, NonspacingMark),
+    (0x103F, 0x103F, OtherLetter),
+    (0x1040, 0x1049, DecimalNumber),
+    (0x104A, 0x104F, OtherPunctuation),
+    (0x1050, 0x1055, OtherLetter),
+    (0x1056, 0x1057, SpacingMark),
+    (0x1058, 0x1059, NonspacingMark),
+    (0x105A, 0x105D, OtherLetter),
+    (0x105E, 0x1060, NonspacingMark),
+    (0x1061, 0x1061, OtherLetter),
+    (0x1062, 0x1064, SpacingMark),
+    (0x1065, 0x1066, OtherLetter),
+    (0x1067, 0x106D, SpacingMark),
+    (0x106E, 0x1070, OtherLetter),
+    (0x1071, 0x1074, NonspacingMark),
+    (0x1075, 0x1081, OtherLetter),
+    (0x1082, 0x1082, NonspacingMark),
+    (0x1083, 0x1084, SpacingMark),
+    (0x1085, 0x1086, NonspacingMark),
+    (0x1087, 0x108C, SpacingMark),
+    (0x108D, 0x108D, NonspacingMark),
+    (0x108E, 0x108E, OtherLetter),
+    (0x108F, 0x108F, SpacingMark),
+    (0x1090, 0x1099, DecimalNumber),
+    (0x109A, 0x109C, SpacingMark),
+    (0x109D, 0x109D, NonspacingMark),
+    (0x109E, 0x109F, OtherSymbol),
+    (0x10A0, 0x10C5, UppercaseLetter),
+    (0x10C7, 0x10C7, UppercaseLetter),
+    (0x10CD, 0x10CD, UppercaseLetter),
+    (0x10D0, 0x10FA, LowercaseLetter),
+    (0x10FB, 0x10FB, OtherPunctuation),
+    (0x10FC, 0x10FC, ModifierLetter),
+    (0x10FD, 0x10FF, LowercaseLetter),
+    (0x1100, 0x1248, OtherLetter),
+    (0x124A, 0x124D, OtherLetter),
+    (0x1250, 0x1256, OtherLetter),
+    (0x1258, 0x1258, OtherLetter),
+    (0x125A, 0x125D, OtherLetter),
+    (0x1260, 0x1288, OtherLetter),
+    (0x128A, 0x128D, OtherLetter),
+    (0x1290, 0x12B0, OtherLetter),
+    (0x12B2, 0x12B5, OtherLetter),
+    (0x12B8, 0x12BE, OtherLetter),
+    (0x12C0, 0x12C0, OtherLetter),
+    (0x12C2, 0x12C5, OtherLetter),
+    (0x12C8, 0x12D6, OtherLetter),
+    (0x12D8, 0x1310, OtherLetter),
+    (0x1312, 0x1315, OtherLetter),
+    (0x1318, 0x135A, OtherLetter),
+    (0x135D, 0x135F, NonspacingMark),
+    (0x1360, 0x1368, OtherPunctuation),
+    (0x1369, 0x137C, OtherNumber),
+    (0x1380, 0x138F, OtherLetter),
+    (0x1390, 0x1399, OtherSymbol),
+    (0x13A0, 0x13F5, UppercaseLetter),
+    (0x13F8, 0x13FD, LowercaseLetter),
+    (0x1400, 0x1400, DashPunctuation),
+    (0x1401, 0x166C, OtherLetter),
+    (0x166D, 0x166D, OtherSymbol),
+    (0x166E, 0x166E, OtherPunctuation),
+    (0x166F, 0x167F, OtherLetter),
+    (0x1680, 0x1680, SpaceSeparator),
+    (0x1681, 0x169A, OtherLetter),
+    (0x169B, 0x169B, OpenPunctuation),
+    (0x169C, 0x169C, ClosePunctuation),
+    (0x16A0, 0x16EA, OtherLetter),
+    (0x16EB, 0x16ED, OtherPunctuation),
+    (0x16EE, 0x16F0, LetterNumber),
+    (0x16F1, 0x16F8, OtherLetter),
+    (0x1700, 0x1711, OtherLetter),
+    (0x1712, 0x1714, NonspacingMark),
+    (0x1715, 0x1715, SpacingMark),
+    (0x171F, 0x1731, OtherLetter),
+    (0x1732, 0x1733, NonspacingMark),
+    (0x1734, 0x1734, SpacingMark),
+    (0x1735, 0x1736, OtherPunctuation),
+    (0x1740, 0x1751, OtherLetter),
+    (0x1752, 0x1753, NonspacingMark),
+    (0x1760, 0x176C, OtherLetter),
+    (0x176E, 0x1770, OtherLetter),
+    (0x1772, 0x1773, NonspacingMark),
+    (0x1780, 0x17B3, OtherLetter),
+    (0x17B4, 0x17B5, NonspacingMark),
+    (0x17B6, 0x17B6, SpacingMark),
+    (0x17B7, 0x17BD, NonspacingMark),
+    (0x17BE, 0x17C5, SpacingMark),
+    (0x17C6, 0x17C6, NonspacingMark),
+    (0x17C7, 0x17C8, SpacingMark),
+    (0x17C9, 0x17D3, NonspacingMark),
+    (0x17D4, 0x17D6, OtherPunctuation),
+    (0x17D7, 0x17D7, ModifierLetter),
+    (0x17D8, 0x17DA, OtherPunctuation),
+    (0x17DB, 0x17DB, CurrencySymbol),
+    (0x17DC, 0x17DC, OtherLetter),
+    (0x17DD, 0x17DD, NonspacingMark),
+    (0x17E0, 0x17E9, DecimalNumber),
+    (0x17F0, 0x17F9, OtherNumber),
+    (0x1800, 0x1805, OtherPunctuation),
+    (0x1806, 0x1806, DashPunctuation),
+    (0x1807, 0x180A, OtherPunctuation),
+    (0x180B, 0x180D, NonspacingMark),
+    (0x180E, 0x180E, Format),
+    (0x180F, 0x180F, NonspacingMark),
+    (0x1810, 0x1819, DecimalNumber),
+    (0x1820, 0x1842, OtherLetter),
+    (0x1843, 0x1843, ModifierLetter),
+    (0x1844, 0x1878, OtherLetter),
+    (0x1880, 0x1884, OtherLetter),
+    (0x1885, 0x1886, NonspacingMark),
+    (0x1887, 0x18A8, OtherLetter),
+    (0x18A9, 0x18A9, NonspacingMark),
+    (0x18AA, 0x18AA, OtherLetter),
+    (0x18B0, 0x18F5, OtherLetter),
+    (0x1900, 0x191E, OtherLetter),
+    (0x1920, 0x1922, NonspacingMark),
+    (0x1923, 0x1926, SpacingMark),
+    (0x1927, 0x1928, NonspacingMark),
+    (0x1929, 0x192B, SpacingMark),
+    (0x1930, 0x1931, SpacingMark),
+    (0x1932, 0x1932, NonspacingMark),
+    (0x1933, 0x1938, SpacingMark),
+    (0x1939, 0x193B, NonspacingMark),
+    (0x1940, 0x1940, OtherSymbol),
+    (0x1944, 0x1945, OtherPunctuation),
+    (0x1946, 0x194F, DecimalNumber),
+    (0x1950, 0x196D, OtherLetter),
+    (0x1970, 0x1974, OtherLetter),
+    (0x1980, 0x19AB, OtherLetter),
+    (0x19B0, 0x19C9, OtherLetter),
+    (0x19D0, 0x19D9, DecimalNumber),
+    (0x19DA, 0x19DA, OtherNumber),
+    (0x19DE, 0x19FF, OtherSymbol),
+    (0x1A00, 0x1A16, OtherLetter),
+    (0x1A17, 0x1A18, NonspacingMark),
+    (0x1A19, 0x1A1A, SpacingMark),
+    (0x1A1B, 0x1A1B, NonspacingMark),
+    (0x1A1E, 0x1A1F, OtherPunctuation),
+    (0x1A20, 0x1A54, OtherLetter),
+    (0x1A55, 0x1A55, SpacingMark),
+    (0x1A56, 0x1A56, NonspacingMark),
+    (0x1A57, 0x1A57, SpacingMark),
+    (0x1A58, 0x1A5E, NonspacingMark),
+    (0x1A60, 0x1A60, NonspacingMark),
+    (0x1A61, 0x1A61, SpacingMark),
+    (0x1A62, 0x1A62, NonspacingMark),
+    (0x1A63, 0x1A64, SpacingMark),
+    (0x1A65, 0x1A6C, NonspacingMark),
+    (0x1A6D, 0x1A72, SpacingMark),
+    (0x1A73, 0x1A7C, NonspacingMark),
+    (0x1A7F, 0x1A7F, NonspacingMark),
+    (0x1A80, 0x1A89, DecimalNumber),
+    (0x1A90, 0x1A99, DecimalNumber),
+    (0x1AA0, 0x1AA6, OtherPunctuation),
+    (0x1AA7, 0x1AA7, ModifierLetter),
+    (0x1AA8, 0x1AAD, OtherPunctuation),
+    (0x1AB0, 0x1ABD, NonspacingMark),
+    (0x1ABE, 0x1ABE, EnclosingMark),
+    (0x1ABF, 0x1ACE, NonspacingMark),
+    (0x1B00, 0x1B03, NonspacingMark),
+    (0x1B04, 0x1B04, SpacingMark),
+    (0x1B05, 0x1B33, OtherLetter),
+    (0x1B34, 0x1B34, NonspacingMark),
+    (0x1B35, 0x1B35, SpacingMark),
+    (0x1B36, 0x1B3A, NonspacingMark),
+    (0x1B3B, 0x1B3B, SpacingMark),
+    (0x1B3C, 0x1B3C, NonspacingMark),
+    (0x1B3D, 0x1B41, SpacingMark),
+    (0x1B42, 0x1B42, NonspacingMark),
+    (0x1B43, 0x1B44, SpacingMark),
+    (0x1B45, 0x1B4C, OtherLetter),
+    (0x1B50, 0x1B59, DecimalNumber),
+    (0x1B5A, 0x1B60, OtherPunctuation),
+    (0x1B61, 0x1B6A, OtherSymbol),
+    (0x1B6B, 0x1B73, NonspacingMark),
+    (0x1B74, 0x1B7C, OtherSymbol),
+    (0x1B7D, 0x1B7E, OtherPunctuation),
+    (0x1B80, 0x1B81, NonspacingMark),
+    (0x1B82, 0x1B82, SpacingMark),
+    (0x1B83, 0x1BA0, OtherLetter),
+    (0x1BA1, 0x1BA1, SpacingMark),
+    (0x1BA2, 0x1BA5, NonspacingMark),
+    (0x1BA6, 0x1BA7, SpacingMark),
+    (0x1BA8, 0x1BA9, NonspacingMark),
+    (0x1BAA, 0x1BAA, SpacingMark),
+    (0x1BAB, 0x1BAD, NonspacingMark),
+    (0x1BAE, 0x1BAF, OtherLetter),
+    (0x1BB0, 0x1BB9, DecimalNumber),
+    (0x1BBA, 0x1BE5, OtherLetter),
+    (0x1BE6, 0x1BE6, NonspacingMark),
+    (0x1BE7, 0x1BE7, SpacingMark),
+    (0x1BE8, 0x1BE9, NonspacingMark),
+    (0x1BEA, 0x1BEC, SpacingMark),
+    (0x1BED, 0x1BED, NonspacingMark),
+    (0x1BEE, 0x1BEE, SpacingMark),
+    (0x1BEF, 0x1BF1, NonspacingMark),
+    (0x1BF2, 0x1BF3, SpacingMark),
+    (0x1BFC, 0x1BFF, OtherPunctuation),
+    (0x1C00, 0x1C23, OtherLetter),
+    (0x1C24, 0x1C2B, SpacingMark),
+    (0x1C2C, 0x1C33, NonspacingMark),
+    (0x1C34, 0x1C35, SpacingMark),
+    (0x1C36, 0x1C37, NonspacingMark),
+    (0x1C3B, 0x1C3F, OtherPunctuation),
+    (0x1C40, 0x1C49, DecimalNumber),
+    (0x1C4D, 0x1C4F, OtherLetter),
+    (0x1C50, 0x1C59, DecimalNumber),
+    (0x1C5A, 0x1C77, OtherLetter),
+    (0x1C78, 0x1C7D, ModifierLetter),
+    (0x1C7E, 0x1C7F, OtherPunctuation),
+    (0x1C80, 0x1C88, LowercaseLetter),
+    (0x1C90, 0x1CBA, UppercaseLetter),
+    (0x1CBD, 0x1CBF, UppercaseLetter),
+    (0x1CC0, 0x1CC7, OtherPunctuation),
+    (0x1CD0, 0x1CD2, NonspacingMark),
+    (0x1CD3, 0x1CD3, OtherPunctuation),
+    (0x1CD4, 0x1CE0, NonspacingMark),
+    (0x1CE1, 0x1CE1, SpacingMark),
+    (0x1CE2, 0x1CE8, NonspacingMark),
+    (0x1CE9, 0x1CEC, OtherLetter),
+    (0x1CED, 0x1CED, NonspacingMark),
+    (0x1CEE, 0x1CF3, OtherLetter),
+    (0x1CF4, 0x1CF4, NonspacingMark),
+    (0x1CF5, 0x1CF6, OtherLetter),
+    (0x1CF7, 0x1CF7, SpacingMark),
+    (0x1CF8, 0x1CF9, NonspacingMark),
+    (0x1CFA, 0x1CFA, OtherLetter),
+    (0x1D00, 0x1D2B, LowercaseLetter),
+    (0x1D2C, 0x1D6A, ModifierLetter),
+    (0x1D6B, 0x1D77, LowercaseLetter),
+    (0x1D78, 0x1D78, ModifierLetter),
+    (0x1D79, 0x1D9A, LowercaseLetter),
+    (0x1D9B, 0x1DBF, ModifierLetter),
+    (0x1DC0, 0x1DFF, NonspacingMark),
+    (0x1E00, 0x1E00, UppercaseLetter),
+    (0x1E01, 0x1E01, LowercaseLetter),
+    (0x1E02, 0x1E02, UppercaseLetter),
+    (0x1E03, 0x1E03, LowercaseLetter),
+    (0x1E04, 0x1E04, UppercaseLetter),
+    (0x1E05, 0x1E05, LowercaseLetter),
+    (0x1E06, 0x1E06, UppercaseLetter),
+    (0x1E07, 0x1E07, LowercaseLetter),
+    (0x1E08, 0x1E08, UppercaseLetter),
+    (0x1E09, 0x1E09, LowercaseLetter),
+    (0x1E0A, 0x1E0A, UppercaseLetter),
+    (0x1E0B, 0x1E0B, LowercaseLetter),
+    (0x1E0C, 0x1E0C, UppercaseLetter),
+    (0x1E0D, 0x1E0D, LowercaseLetter),
+    (0x1E0E, 0x1E0E, UppercaseLetter),
+    (0x1E0F, 0x1E0F, LowercaseLetter),
+    (0x1E10, 0x1E10, UppercaseLetter),
+    (0x1E11, 0x1E11, LowercaseLetter),
+    (0x1E12, 0x1E12, UppercaseLetter),
+    (0x1E13, 0x1E13, LowercaseLetter),
+    (0x1E14, 0x1E14, UppercaseLetter),
+    (0x1E15, 0x1E15, LowercaseLetter),
+    (0x1E16, 0x1E16, UppercaseLetter),
+    (0x1E17, 0x1E17, LowercaseLetter),
+    (0x1E18, 0x1E18, UppercaseLetter),
+    (0x1E19, 0x1E19, LowercaseLetter),
+    (0x1E1A, 0x1E1A, UppercaseLetter),
+    (0x1E1B, 0x1E1B, LowercaseLetter),
+    (0x1E1C, 0x1E1C, UppercaseLetter),
+    (0x1E1D, 0x1E1D, LowercaseLetter),
+    (0x1E1E, 0x1E1E, UppercaseLetter),
+    (0x1E1F, 0x1E1F, LowercaseLetter),
+    (0x1E20, 0x1E20, UppercaseLetter),
+    (0x1E21, 0x1E21, LowercaseLetter),
+    (0x1E22, 0x1E22, UppercaseLetter),
+    (0x1E23, 0x1E23, LowercaseLetter),
+    (0x1E24, 0x1E24, UppercaseLetter),
+    (0x1E25, 0x1E25, LowercaseLetter),
+    (0x1E26, 0x1E26, UppercaseLetter),
+    (0x1E27, 0x1E27, LowercaseLetter),
+    (0x1E28, 0x1E28, UppercaseLetter),
+    (0x1E29, 0x1E29, LowercaseLetter),
+    (0x1E2A, 0x1E2A, UppercaseLetter),
+    (0x1E2B, 0x1E2B, LowercaseLetter),
+    (0x1E2C, 0x1E2C, UppercaseLetter),
+    (0x1E2D, 0x1E2D, LowercaseLetter),
+    (0x1E2E, 0x1E2E, UppercaseLetter),
+    (0x1E2F, 0x1E2F, LowercaseLetter),
+    (0x1E30, 0x1E30, UppercaseLetter),
+    (0x1E31, 0x1E31, LowercaseLetter),
+    (0x1E32, 0x1E32, UppercaseLetter),
+    (0x1E33, 0x1E33, LowercaseLetter),
+    (0x1E34, 0x1E34, UppercaseLetter),
+    (0x1E35, 0x1E35, LowercaseLetter),
+    (0x1E36, 0x1E36, UppercaseLetter),
+    (0x1E37, 0x1E37, LowercaseLetter),
+    (0x1E38, 0x1E38, UppercaseLetter),
+    (0x1E39, 0x1E39, LowercaseLetter),
+    (0x1E3A, 0x1E3A, UppercaseLetter),
+    (0x1E3B, 0x1E3B, LowercaseLetter),
+    (0x1E3C, 0x1E3C, UppercaseLetter),
+    (0x1E3D, 0x1E3D, LowercaseLetter),
+    (0x1E3E, 0x1E3E, UppercaseLetter),
+    (0x1E3F, 0x1E3F, LowercaseLetter),
+    (0x1E40, 0x1E40, UppercaseLetter),
+    (0x1E41, 0x1E41, LowercaseLetter),
+    (0x1E42, 0x1E42, UppercaseLetter),
+    (0x1E43, 0x1E43, LowercaseLetter),
+    (0x1E44, 0x1E44, UppercaseLetter),
+    (0x1E45, 0x1E45, LowercaseLetter),
+    (0x1E46, 0x1E46, UppercaseLetter),
+    (0x1E47, 0x1E47, LowercaseLetter),
+    (0x1E48, 0x1E48, UppercaseLetter),
+    (0x1E49, 0x1E49, LowercaseLetter),
+    (0x1E4A, 0x1E4A, UppercaseLetter),
+    (0x1E4B, 0x1E4B, LowercaseLetter),
+    (0x1E4C, 0x1E4C, UppercaseLetter),
+    (0x1E4D, 0x1E4D, LowercaseLetter),
+    (0x1E4E, 0x1E4E, UppercaseLetter),
+    (0x1E4F, 0x1E4F, LowercaseLetter),
+    (0x1E50, 0x1E50, UppercaseLetter),
+    (0x1E51, 0x1E51, LowercaseLetter),
+    (0x1E52, 0x1E52, UppercaseLetter),
+    (0x1E53, 0x1E53, LowercaseLetter),
+    (0x1E54, 0x1E54, UppercaseLetter),
+    (0x1E55, 0x1E55, LowercaseLetter),
+    (0x1E56, 0x1E56, UppercaseLetter),
+    (0x1E57, 0x1E57, LowercaseLetter),
+    (0x1E58, 0x1E58, UppercaseLetter),
+    (0x1E59, 0x1E59, LowercaseLetter),
+    (0x1E5A, 0x1E5A, UppercaseLetter),
+    (0x1E5B, 0x1E5B, LowercaseLetter),
+    (0x1E5C, 0x1E5C, UppercaseLetter),
+    (0x1E5D, 0x1E5D, LowercaseLetter),
+    (0x1E5E, 0x1E5E, UppercaseLetter),
+    (0x1E5F, 0x1E5F, LowercaseLetter),
+    (0x1E60, 0x1E60, UppercaseLetter),
+    (0x1E61, 0x1E61, LowercaseLetter),
+    (0x1E62, 0x1E62, UppercaseLetter),
+    (0x1E63, 0x1E63, LowercaseLetter),
+    (0x1E64, 0x1E64, UppercaseLetter),
+    (0x1E65, 0x1E65, LowercaseLetter),
+    (0x1E66, 0x1E66, UppercaseLetter),
+    (0x1E67, 0x1E67, LowercaseLetter),
+    (0x1E68, 0x1E68, UppercaseLetter),
+    (0x1E69, 0x1E69, LowercaseLetter),
+    (0x1E6A, 0x1E6A, UppercaseLetter),
+    (0x1E6B, 0x1E6B, LowercaseLetter),
+    (0x1E6C, 0x1E6C, UppercaseLetter),
+    (0x1E6D, 0x1E6D, LowercaseLetter),
+    (0x1E6E, 0x1E6E, UppercaseLetter),
+    (0x1E6F, 0x1E6F, LowercaseLetter),
+    (0x1E70, 0x1E70, UppercaseLetter),
+    (0x1E71, 0x1E71, LowercaseLetter),
+    (0x1E72, 0x1E72, UppercaseLetter),
+    (0x1E73, 0x1E73, LowercaseLetter),
+    (0x1E74, 0x1E74, UppercaseLetter),
+    (0x1E75, 0x1E75, LowercaseLetter),
+    (0x1E76, 0x1E76, UppercaseLetter),
+    (0x1E77, 0x1E77, LowercaseLetter),
+    (0x1E78, 0x1E78, UppercaseLetter),
+    (0x1E79, 0x1E79, LowercaseLetter),
+    (0x1E7A, 0x1E7A, UppercaseLetter),
+    (0x1E7B, 0x1E7B, LowercaseLetter),
+    (0x1E7C, 0x1E7C, UppercaseLetter),
+    (0x1E7D, 0x1E7D, LowercaseLetter),
+    (0x1E7E, 0x1E7E, UppercaseLetter),
+    (0x1E7F, 0x1E7F, LowercaseLetter),
+    (0x1E80, 0x1E80, UppercaseLetter),
+    (0x1E81, 0x1E81, LowercaseLetter),
+    (0x1E82, 0x1E82, UppercaseLetter),
+    (0x1E83, 0x1E83, LowercaseLetter),
+    (0x1E84, 0x1E84, UppercaseLetter),
+    (0x1E85, 0x1E85, LowercaseLetter),
+    (0x1E86, 0x1E86, UppercaseLetter),
+    (0x1E87, 0x1E87, LowercaseLetter),
+    (0x1E88, 0x1E88, UppercaseLetter),
+    (0x1E89, 0x1E89, LowercaseLetter),
+    (0x1E8A, 0x1E8A, UppercaseLetter),
+    (0x1E8B, 0x1E8B, LowercaseLetter),
+    (0x1E8C, 0x1E8C, UppercaseLetter),
+    (0x1E8D, 0x1E8D, LowercaseLetter),
+    (0x1E8E, 0x1E8E, UppercaseLetter),
+    (0x1E8F, 0x1E8F, LowercaseLetter),
+    (0x1E90, 0x1E90, UppercaseLetter),
+    (0x1E91, 0x1E91, LowercaseLetter),
+    (0x1E92, 0x1E92, UppercaseLetter),
+    (0x1E93, 0x1E93, LowercaseLetter),
+    (0x1E94, 0x1E94, UppercaseLetter),
+    (0x1E95, 0x1E9D, LowercaseLetter),
+    (0x1E9E, 0x1E9E, UppercaseLetter),
+    (0x1E9F, 0x1E9F, LowercaseLetter),
+    (0x1EA0, 0x1EA0, UppercaseLetter),
+    (0x1EA1, 0x1EA1, LowercaseLetter),
+    (0x1EA2, 0x1EA2, UppercaseLetter),
+    (0x1EA3, 0x1EA3, LowercaseLetter),
+    (0x1EA4, 0x1EA4, UppercaseLetter),
+    (0x1EA5, 0x1EA5, LowercaseLetter),
+    (0x1EA6, 0x1EA6, UppercaseLetter),
+    (0x1EA7, 0x1EA7, LowercaseLetter),
+    (0x1EA8, 0x1EA8, UppercaseLetter),
+    (0x1EA9, 0x1EA9, LowercaseLetter),
+    (0x1EAA, 0x1EAA, UppercaseLetter),
+    (0x1EAB, 0x1EAB, LowercaseLetter),
+    (0x1EAC, 0x1EAC, UppercaseLetter),
+    (0x1EAD, 0x1EAD, LowercaseLetter),
+    (0x1EAE, 0x1EAE, UppercaseLetter),
+    (0x1EAF, 0x1EAF, LowercaseLetter),
+    (0x1EB0, 0x1EB0, UppercaseLetter),
+    (0x1EB1, 0x1EB1, LowercaseLetter),
+    (0x1EB2, 0x1EB2, UppercaseLetter),
+    (0x1EB3, 0x1EB3, LowercaseLetter),
+    (0x1EB4, 0x1EB4, UppercaseLetter),
+    (0x1EB5, 0x1EB5, LowercaseLetter),
+    (0x1EB6, 0x1EB6, UppercaseLetter),
+    (0x1EB7, 0x1EB7, LowercaseLetter),
+    (0x1EB8, 0x1EB8, UppercaseLetter),
+    (0x1EB9, 0x1EB9, LowercaseLetter),
+    (0x1EBA, 0x1EBA, UppercaseLetter),
+    (0x1EBB, 0x1EBB, LowercaseLetter),
+    (0x1EBC, 0x1EBC, UppercaseLetter),
+    (0x1EBD, 0x1EBD, LowercaseLetter),
+    (0x1EBE, 0x1EBE, UppercaseLetter),
+    (0x1EBF, 0x1EBF, LowercaseLetter),
+    (0x1EC0, 0x1EC0, UppercaseLetter),
+    (0x1EC1, 0x1EC1, LowercaseLetter),
+    (0x1EC2, 0x1EC2, UppercaseLetter),
+    (0x1EC3, 0x1EC3, LowercaseLetter),
+    (0x1EC4, 0x1EC4, UppercaseLetter),
+    (0x1EC5, 0x1EC5, LowercaseLetter),
+    (0x1EC6, 0x1EC6, UppercaseLetter),
+    (0x1EC7, 0x1EC7, LowercaseLetter),
+    (0x1EC8, 0x1EC8, UppercaseLetter),
+    (0x1EC9, 0x1EC9, LowercaseLetter),
+    (0x1ECA, 0x1ECA, UppercaseLetter),
+    (0x1ECB, 0x1ECB, LowercaseLetter),
+    (0x1ECC, 0x1ECC, UppercaseLetter),
+    (0x1ECD, 0x1ECD, LowercaseLetter),
+    (0x1ECE, 0x1ECE, UppercaseLetter),
+    (0x1ECF, 0x1ECF, LowercaseLetter),
+    (0x1ED0, 0x1ED0, UppercaseLetter),
+    (0x1ED1, 0x1ED1, LowercaseLetter),
+    (0x1ED2, 0x1ED2, UppercaseLetter),
+    (0x1ED3, 0x1ED3, LowercaseLetter),
+    (0x1ED4, 0x1ED4, UppercaseLetter),
+    (0x1ED5, 0x1ED5, LowercaseLetter),
+    (0x1ED6, 0x1ED6, UppercaseLetter),
+    (0x1ED7, 0x1ED7, LowercaseLetter),
+    (0x1ED8, 0x1ED8, UppercaseLetter),
+    (0x1ED9, 0x1ED9, LowercaseLetter),
+    (0x1EDA, 0x1EDA, UppercaseLetter),
+    (0x1EDB, 0x1EDB, LowercaseLetter),
+    (0x1EDC, 0x1EDC, UppercaseLetter),
+    (0x1EDD, 0x1EDD, LowercaseLetter),
+    (0x1EDE, 0x1EDE, UppercaseLetter),
+    (0x1EDF, 0x1EDF, LowercaseLetter),
+    (0x1EE0, 0x1EE0, UppercaseLetter),
+    (0x1EE1, 0x1EE1, LowercaseLetter),
+    (0x1EE2, 0x1EE2, UppercaseLetter),
+    (0x1EE3, 0x1EE3, LowercaseLetter),
+    (0x1EE4, 0x1EE4, UppercaseLetter),
+    (0x1EE5, 0x1EE5, LowercaseLetter),
+    (0x1EE6, 0x1EE6, UppercaseLetter),
+    (0x1EE7, 0x1EE7, LowercaseLetter),
+    (0x1EE8, 0x1EE8, UppercaseLetter),
+    (0x1EE9, 0x1EE9, LowercaseLetter),
+    (0x1EEA, 0x1EEA, UppercaseLetter),
+    (0x1EEB, 0x1EEB, LowercaseLetter),
+    (0x1EEC, 0x1EEC, UppercaseLetter),
+    (0x1EED, 0x1EED, LowercaseLetter),
+    (0x1EEE, 0x1EEE, UppercaseLetter),
+    (0x1EEF, 0x1EEF, LowercaseLetter),
+    (0x1EF0, 0x1EF0, UppercaseLetter),
+    (0x1EF1, 0x1EF1, LowercaseLetter),
+    (0x1EF2, 0x1EF2, UppercaseLetter),
+    (0x1EF3, 0x1EF3, LowercaseLetter),
+    (0x1EF4, 0x1EF4, UppercaseLetter),
+    (0x1EF5, 0x1EF5, LowercaseLetter),
+    (0x1EF6, 0x1EF6, UppercaseLetter),
+    (0x1EF7, 0x1EF7, LowercaseLetter),
+    (0x1EF8, 0x1EF8, UppercaseLetter),
+    (0x1EF9, 0x1EF9, LowercaseLetter),
+    (0x1EFA, 0x1EFA, UppercaseLetter),
+    (0x1EFB, 0x1EFB, LowercaseLetter),
+    (0x1EFC, 0x1EFC, UppercaseLetter),
+    (0x1EFD, 0x1EFD, LowercaseLetter),
+    (0x1EFE, 0x1EFE, UppercaseLetter),
+    (0x1EFF, 0x1F07, LowercaseLetter),
+    (0x1F08, 0x1F0F, UppercaseLetter),
+    (0x1F10, 0x1F15, LowercaseLetter),
+    (0x1F18, 0x1F1D, UppercaseLetter),
+    (0x1F20, 0x1F27, LowercaseLetter),
+    (0x1F28, 0x1F2F, UppercaseLetter),
+    (0x1F30, 0x1F37, LowercaseLetter),
+    (0x1F38, 0x1F3F, UppercaseLetter),
+    (0x1F40, 0x1F45, LowercaseLetter),
+    (0x1F48, 0x1F4D, UppercaseLetter),
+    (0x1F50, 0x1F57, LowercaseLetter),
+    (0x1F59, 0x1F59, UppercaseLetter),
+    (0x1F5B, 0x1F5B, UppercaseLetter),
+    (0x1F5D, 0x1F5D, UppercaseLetter),
+    (0x1F5F, 0x1F5F, UppercaseLetter),
+    (0x1F60, 0x1F67, LowercaseLetter),
+    (0x1F68, 0x1F6F, UppercaseLetter),
+    (0x1F70, 0x1F7D, LowercaseLetter),
+    (0x1F80, 0x1F87, LowercaseLetter),
+    (0x1F88, 0x1F8F, TitlecaseLetter),
+    (0x1F90, 0x1F97, LowercaseLetter),
+    (0x1F98, 0x1F9F, TitlecaseLetter),
+    (0x1FA0, 0x1FA7, LowercaseLetter),
+    (0x1FA8, 0x1FAF, TitlecaseLetter),
+    (0x1FB0, 0x1FB4, LowercaseLetter),
+    (0x1FB6, 0x1FB7, LowercaseLetter),
+    (0x1FB8, 0x1FBB, UppercaseLetter),
+    (0x1FBC, 0x1FBC, TitlecaseLetter),
+    (0x1FBD, 0x1FBD, ModifierSymbol),
+    (0x1FBE, 0x1FBE, LowercaseLetter),
+    (0x1FBF, 0x1FC1, ModifierSymbol),
+    (0x1FC2, 0x1FC4, LowercaseLetter),
+    (0x1FC6, 0x1FC7, LowercaseLetter),
+    (0x1FC8, 0x1FCB, UppercaseLetter),
+    (0x1FCC, 0x1FCC, TitlecaseLetter),
+    (0x1FCD, 0x1FCF, ModifierSymbol),
+    (0x1FD0, 0x1FD3, LowercaseLetter),
+    (0x1FD6, 0x1FD7, LowercaseLetter),
+    (0x1FD8, 0x1FDB, UppercaseLetter),
+    (0x1FDD, 0x1FDF, ModifierSymbol),
+    (0x1FE0, 0x1FE7, LowercaseLetter),
+    (0x1FE8, 0x1FEC, UppercaseLetter),
+    (0x1FED, 0x1FEF, ModifierSymbol),
+    (0x1FF2, 0x1FF4, LowercaseLetter),
+    (0x1FF6, 0x1FF7, LowercaseLetter),
+    (0x1FF8, 0x1FFB, UppercaseLetter),
+    (0x1FFC, 0x1FFC, TitlecaseLetter),
+    (0x1FFD, 0x1FFE, ModifierSymbol),
+    (0x2000, 0x200A, SpaceSeparator),
+    (0x200B, 0x200F, Format),
+    (0x2010, 0x2015, DashPunctuation),
+    (0x2016, 0x2017, OtherPunctuation),
+    (0x2018, 0x2018, InitialPunctuation),
+    (0x2019, 0x2019, FinalPunctuation),
+    (0x201A, 0x201A, OpenPunctuation),
+    (0x201B, 0x201C, InitialPunctuation),
+    (0x201D, 0x201D, FinalPunctuation),
+    (0x201E, 0x201E, OpenPunctuation),
+    (0x201F, 0x201F, InitialPunctuation),
+    (0x2020, 0x2027, OtherPunctuation),
+    (0x2028, 0x2028, LineSeparator),
+    (0x2029, 0x2029, ParagraphSeparator),
+    (0x202A, 0x202E, Format),
+    (0x202F, 0x202F, SpaceSeparator),
+    (0x2030, 0x2038, OtherPunctuation),
+    (0x2039, 0x2039, InitialPunctuation),
+    (0x203A, 0x203A, FinalPunctuation),
+    (0x203B, 0x203E, OtherPunctuation),
+    (0x203F, 0x2040, ConnectorPunctuation),
+    (0x2041, 0x2043, OtherPunctuation),
+    (0x2044, 0x2044, MathSymbol),
+    (0x2045, 0x2045, OpenPunctuation),
+    (0x2046, 0x2046, ClosePunctuation),
+    (0x2047, 0x2051, OtherPunctuation),
+    (0x2052, 0x2052, MathSymbol),
+    (0x2053, 0x2053, OtherPunctuation),
+    (0x2054, 0x2054, ConnectorPunctuation),
+    (0x2055, 0x205E, OtherPunctuation),
+    (0x205F, 0x205F, SpaceSeparator),
+    (0x2060, 0x2064, Format),
+    (0x2066, 0x206F, Format),
+    (0x2070, 0x2070, OtherNumber),
+    (0x2071, 0x2071, ModifierLetter),
+    (0x2074, 0x2079, OtherNumber),
+    (0x207A, 0x207C, MathSymbol),
+    (0x207D, 0x207D, OpenPunctuation),
+    (0x207E, 0x207E, ClosePunctuation),
+    (0x207F, 0x207F, ModifierLetter),
+    (0x2080, 0x2089, OtherNumber),
+    (0x208A, 0x208C, MathSymbol),
+    (0x208D, 0x208D, OpenPunctuation),
+    (0x208E, 0x208E, ClosePunctuation),
+    (0x2090, 0x209C, ModifierLetter),
+    (0x20A0, 0x20C0, CurrencySymbol),
+    (0x20D0, 0x20DC, NonspacingMark),
+    (0x20DD, 0x20E0, EnclosingMark),
+    (0x20E1, 0x20E1, NonspacingMark),
+    (0x20E2, 0x20E4, EnclosingMark),
+    (0x20E5, 0x20F0, NonspacingMark),
+    (0x2100, 0x2101, OtherSymbol),
+    (0x2102, 0x2102, UppercaseLetter),
+    (0x2103, 0x2106, OtherSymbol),
+    (0x2107, 0x2107, UppercaseLetter),
+    (0x2108, 0x2109, OtherSymbol),
+    (0x210A, 0x210A, LowercaseLetter),
+    (0x210B, 0x210D, UppercaseLetter),
+    (0x210E, 0x210F, LowercaseLetter),
+    (0x2110, 0x2112, UppercaseLetter),
+    (0x2113, 0x2113, LowercaseLetter),
+    (0x2114, 0x2114, OtherSymbol),
+    (0x2115, 0x2115, UppercaseLetter),
+    (0x2116, 0x2117, OtherSymbol),
+    (0x2118, 0x2118, MathSymbol),
+    (0x2119, 0x211D, UppercaseLetter),
+    (0x211E, 0x2123, OtherSymbol),
+    (0x2124, 0x2124, UppercaseLetter),
+    (0x2125, 0x2125, OtherSymbol),
+    (0x2126, 0x2126, UppercaseLetter),
+    (0x2127, 0x2127, OtherSymbol),
+    (0x2128, 0x2128, UppercaseLetter),
+    (0x2129, 0x2129, OtherSymbol),
+    (0x212A, 0x212D, UppercaseLetter),
+    (0x212E, 0x212E, OtherSymbol),
+    (0x212F, 0x212F, LowercaseLetter),
+    (0x2130, 0x2133, UppercaseLetter),
+    (0x2134, 0x2134, LowercaseLetter),
+    (0x2135, 0x2138, OtherLetter),
+    (0x2139, 0x2139, LowercaseLetter),
+    (0x213A, 0x213B, OtherSymbol),
+    (0x213C, 0x213D, LowercaseLetter),
+    (0x213E, 0x213F, UppercaseLetter),
+    (0x2140, 0x2144, MathSymbol),
+    (0x2145, 0x2145, UppercaseLetter),
+    (0x2146, 0x2149, LowercaseLetter),
+    (0x214A, 0x214A, OtherSymbol),
+    (0x214B, 0x214B, MathSymbol),
+    (0x214C, 0x214D, OtherSymbol),
+    (0x214E, 0x214E, LowercaseLetter),
+    (0x214F, 0x214F, OtherSymbol),
+    (0x2150, 0x215F, OtherNumber),
+    (0x2160, 0x2182, LetterNumber),
+    (0x2183, 0x2183, UppercaseLetter),
+    (0x2184, 0x2184, LowercaseLetter),
+    (0x2185, 0x2188, LetterNumber),
+    (0x2189, 0x2189, OtherNumber),
+    (0x218A, 0x218B, OtherSymbol),
+    (0x2190, 0x2194, MathSymbol),
+    (0x2195, 0x2199, OtherSymbol),
+    (0x219A, 0x219B, MathSymbol),
+    (0x219C, 0x219F, OtherSymbol),
+    (0x21A0, 0x21A0, MathSymbol),
+    (0x21A1, 0x21A2, OtherSymbol),
+    (0x21A3, 0x21A3, MathSymbol),
+    (0x21A4, 0x21A5, OtherSymbol),
+    (0x21A6, 0x21A6, MathSymbol),
+    (0x21A7, 0x21AD, OtherSymbol),
+    (0x21AE, 0x21AE, MathSymbol),
+    (0x21AF, 0x21CD, OtherSymbol),
+    (0x21CE, 0x21CF, MathSymbol),
+    (0x21D0, 0x21D1, OtherSymbol),
+    (0x21D2, 0x21D2, MathSymbol),
+    (0x21D3, 0x21D3, OtherSymbol),
+    (0x21D4, 0x21D4, MathSymbol),
+    (0x21D5, 0x21F3, OtherSymbol),
+    (0x21F4, 0x22FF, MathSymbol),
+    (0x2300, 0x2307, OtherSymbol),
+    (0x2308, 0x2308, OpenPunctuation),
+    (0x2309, 0x2309, ClosePunctuation),
+    (0x230A, 0x230A, OpenPunctuation),
+    (0x230B, 0x230B, ClosePunctuation),
+    (0x230C, 0x231F, OtherSymbol),
+    (0x2320, 0x2321, MathSymbol),
+    (0x2322, 0x2328, OtherSymbol),
+    (0x2329, 0x2329, OpenPunctuation),
+    (0x232A, 0x232A, ClosePunctuation),
+    (0x232B, 0x237B, OtherSymbol),
+    (0x237C, 0x237C, MathSymbol),
+    (0x237D, 0x239A, OtherSymbol),
+    (0x239B, 0x23B3, MathSymbol),
+    (0x23B4, 0x23DB, OtherSymbol),
+    (0x23DC, 0x23E1, MathSymbol),
+    (0x23E2, 0x2426, OtherSymbol),
+    (0x2440, 0x244A, OtherSymbol),
+    (0x2460, 0x249B, OtherNumber),
+    (0x249C, 0x24E9, OtherSymbol),
+    (0x24EA, 0x24FF, OtherNumber),
+    (0x2500, 0x25B6, OtherSymbol),
+    (0x25B7, 0x25B7, MathSymbol),
+    (0x25B8, 0x25C0, OtherSymbol),
+    (0x25C1, 0x25C1, MathSymbol),
+    (0x25C2, 0x25F7, OtherSymbol),
+    (0x25F8, 0x25FF, MathSymbol),
+    (0x2600, 0x266E, OtherSymbol),
+    (0x266F, 0x266F, MathSymbol),
+    (0x2670, 0x2767, OtherSymbol),
+    (0x2768, 0x2768, OpenPunctuation),
+    (0x2769, 0x2769, ClosePunctuation),
+    (0x276A, 0x276A, OpenPunctuation),
+    (0x276B, 0x276B, ClosePunctuation),
+    (0x276C, 0x276C, OpenPunctuation),
+    (0x276D, 0x276D, ClosePunctuation),
+    (0x276E, 0x276E, OpenPunctuation),
+    (0x276F, 0x276F, ClosePunctuation),
+    (0x2770, 0x2770, OpenPunctuation),
+    (0x2771, 0x2771, ClosePunctuation),
+    (0x2772, 0x2772, OpenPunctuation),
+    (0x2773, 0x2773, ClosePunctuation),
+    (0x2774, 0x2774, OpenPunctuation),
+    (0x2775, 0x2775, ClosePunctuation),
+    (0x2776, 0x2793, OtherNumber),
+    (0x2794, 0x27BF, OtherSymbol),
+    (0x27C0, 0x27C4, MathSymbol),
+    (0x27C5, 0x27C5, OpenPunctuation),
+    (0x27C6, 0x27C6, ClosePunctuation),
+    (0x27C7, 0x27E5, MathSymbol),
+    (0x27E6, 0x27E6, OpenPunctuation),
+    (0x27E7, 0x27E7, ClosePunctuation),
+    (0x27E8, 0x27E8, OpenPunctuation),
+    (0x27E9, 0x27E9, ClosePunctuation),
+    (0x27EA, 0x27EA, OpenPunctuation),
+    (0x27EB, 0x27EB, ClosePunctuation),
+    (0x27EC, 0x27EC, OpenPunctuation),
+    (0x27ED, 0x27ED, ClosePunctuation),
+    (0x27EE, 0x27EE, OpenPunctuation),
+    (0x27EF, 0x27EF, ClosePunctuation),
+    (0x27F0, 0x27FF, MathSymbol),
+    (0x2800, 0x28FF, OtherSymbol),
+    (0x2900, 0x2982, MathSymbol),
+    (0x2983, 0x2983, OpenPunctuation),
+    (0x2984, 0x2984, ClosePunctuation),
+    (0x2985, 0x2985, OpenPunctuation),
+    (0x2986, 0x2986, ClosePunctuation),
+    (0x2987, 0x2987, OpenPunctuation),
+    (0x2988, 0x2988, ClosePunctuation),
+    (0x2989, 0x2989, OpenPunctuation),
+    (0x298A, 0x298A, ClosePunctuation),
+    (0x298B, 0x298B, OpenPunctuation),
+    (0x298C, 0x298C, ClosePunctuation),
+    (0x298D, 0x298D, OpenPunctuation),
+    (0x298E, 0x298E, ClosePunctuation),
+    (0x298F, 0x298F, OpenPunctuation),
+    (0x2990, 0x2990, ClosePunctuation),
+    (0x2991, 0x2991, OpenPunctuation),
+    (0x2992, 0x2992, ClosePunctuation),
+    (0x2993, 0x2993, OpenPunctuation),
+    (0x2994, 0x2994, ClosePunctuation),
+    (0x2995, 0x2995, OpenPunctuation),
+    (0x2996, 0x2996, ClosePunctuation),
+    (0x2997, 0x2997, OpenPunctuation),
+    (0x2998, 0x2998, ClosePunctuation),
+    (0x2999, 0x29D7, MathSymbol),
+    (0x29D8, 0x29D8, OpenPunctuation),
+    (0x29D9, 0x29D9, ClosePunctuation),
+    (0x29DA, 0x29DA, OpenPunctuation),
+    (0x29DB, 0x29DB, ClosePunctuation),
+    (0x29DC, 0x29FB, MathSymbol),
+    (0x29FC, 0x29FC, OpenPunctuation),
+    (0x29FD, 0x29FD, ClosePunctuation),
+    (0x29FE, 0x2AFF, MathSymbol),
+    (0x2B00, 0x2B2F, OtherSymbol),
+    (0x2B30, 0x2B44, MathSymbol),
+    (0x2B45, 0x2B46, OtherSymbol),
+    (0x2B47, 0x2B4C, MathSymbol),
+    (0x2B4D, 0x2B73, OtherSymbol),
+    (0x2B76, 0x2B95, OtherSymbol),
+    (0x2B97, 0x2BFF, OtherSymbol),
+    (0x2C00, 0x2C2F, UppercaseLetter),
+    (0x2C30, 0x2C5F, LowercaseLetter),
+    (0x2C60, 0x2C60, UppercaseLetter),
+    (0x2C61, 0x2C61, LowercaseLetter),
+    (0x2C62, 0x2C64, UppercaseLetter),
+    (0x2C65, 0x2C66, LowercaseLetter),
+    (0x2C67, 0x2C67, UppercaseLetter),
+    (0x2C68, 0x2C68, LowercaseLetter),
+    (0x2C69, 0x2C69, UppercaseLetter),
+    (0x2C6A, 0x2C6A, LowercaseLetter),
+    (0x2C6B, 0x2C6B, UppercaseLetter),
+    (0x2C6C, 0x2C6C, LowercaseLetter),
+    (0x2C6D, 0x2C70, UppercaseLetter),
+    (0x2C71, 0x2C71, LowercaseLetter),
+    (0x2C72, 0x2C72, UppercaseLetter),
+    (0x2C73, 0x2C74, LowercaseLetter),
+    (0x2C75, 0x2C75, UppercaseLetter),
+    (0x2C76, 0x2C7B, LowercaseLetter),
+    (0x2C7C, 0x2C7D, ModifierLetter),
+    (0x2C7E, 0x2C80, UppercaseLetter),
+    (0x2C81, 0x2C81, LowercaseLetter),
+    (0x2C82, 0x2C82, UppercaseLetter),
+    (0x2C83, 0x2C83, LowercaseLetter),
+    (0x2C84, 0x2C84, UppercaseLetter),
+    (0x2C85, 0x2C85, LowercaseLetter),
+    (0x2C86, 0x2C86, UppercaseLetter),
+    (0x2C87, 0x2C87, LowercaseLetter),
+    (0x2C88, 0x2C88, UppercaseLetter),
+    (0x2C89, 0x2C89, LowercaseLetter),
+    (0x2C8A, 0x2C8A, UppercaseLetter),
+    (0x2C8B, 0x2C8B, LowercaseLetter),
+    (0x2C8C, 0x2C8C, UppercaseLetter),
+    (0x2C8D, 0x2C8D, LowercaseLetter),
+    (0x2C8E, 0x2C8E, UppercaseLetter),
+    (0x2C8F, 0x2C8F, LowercaseLetter),
+    (0x2C90, 0x2C90, UppercaseLetter),
+    (0x2C91, 0x2C91, LowercaseLetter),
+    (0x2C92, 0x2C92, UppercaseLetter),
+    (0x2C93, 0x2C93, LowercaseLetter),
+    (0x2C94, 0x2C94, UppercaseLetter),
+    (0x2C95, 0x2C95, LowercaseLetter),
+    (0x2C96, 0x2C96, UppercaseLetter),
+    (0x2C97, 0x2C97, LowercaseLetter),
+    (0x2C98, 0x2C98, UppercaseLetter),
+    (0x2C99, 0x2C99, LowercaseLetter),
+    (0x2C9A, 0x2C9A, UppercaseLetter),
+    (0x2C9B, 0x2C9B, LowercaseLetter),
+    (0x2C9C, 0x2C9C, UppercaseLetter),
+    (0x2C9D, 0x2C9D, LowercaseLetter),
+    (0x2C9E, 0x2C9E, UppercaseLetter),
+    (0x2C9F, 0x2C9F, LowercaseLetter),
+    (0x2CA0, 0x2CA0, UppercaseLetter),
+    (0x2CA1, 0x2CA1, LowercaseLetter),
+    (0x2CA2, 0x2CA2, UppercaseLetter),
+    (0x2CA3, 0x2CA3, LowercaseLetter),
+    (0x2CA4, 0x2CA4, UppercaseLetter),
+    (0x2CA5, 0x2CA5, LowercaseLetter),
+    (0x2CA6, 0x2CA6, UppercaseLetter),
+    (0x2CA7, 0x2CA7, LowercaseLetter),
+    (0x2CA8, 0x2CA8, UppercaseLetter),
+    (0x2CA9, 0x2CA9, LowercaseLetter),
+    (0x2CAA, 0x2CAA, UppercaseLetter),
+    (0x2CAB, 0x2CAB, LowercaseLetter),
+    (0x2CAC, 0x2CAC, UppercaseLetter),
+    (0x2CAD, 0x2CAD, LowercaseLetter),
+    (0x2CAE, 0x2CAE, UppercaseLetter),
+    (0x2CAF, 0x2CAF, LowercaseLetter),
+    (0x2CB0, 0x2CB0, UppercaseLetter),
+    (0x2CB1, 0x2CB1, LowercaseLetter),
+    (0x2CB2, 0x2CB2, UppercaseLetter),
+    (0x2CB3, 0x2CB3, LowercaseLetter),
+    (0x2CB4, 0x2CB4, UppercaseLetter),
+    (0x2CB5, 0x2CB5, LowercaseLetter),
+    (0x2CB6, 0x2CB6, UppercaseLetter),
+    (0x2CB7, 0x2CB7, LowercaseLetter),
+    (0x2CB8, 0x2CB8, UppercaseLetter),
+    (0x2CB9, 0x2CB9, LowercaseLetter),
+    (0x2CBA, 0x2CBA, UppercaseLetter),
+    (0x2CBB, 0x2CBB, LowercaseLetter),
+    (0x2CBC, 0x2CBC, UppercaseLetter),
+    (0x2CBD, 0x2CBD, LowercaseLetter),
+    (0x2CBE, 0x2CBE, UppercaseLetter),
+    (0x2CBF, 0x2CBF, LowercaseLetter),
+    (0x2CC0, 0x2CC0, UppercaseLetter),
+    (0x2CC1, 0x2CC1, LowercaseLetter),
+    (0x2CC2, 0x2CC2, UppercaseLetter),
+    (0x2CC3, 0x2CC3, LowercaseLetter),
+    (0x2CC4, 0x2CC4, UppercaseLetter),
+    (0x2CC5, 0x2CC5, LowercaseLetter),
+    (0x2CC6, 0x2CC6, UppercaseLetter),
+    (0x2CC7, 0x2CC7, LowercaseLetter),
+    (0x2CC8, 0x2CC8, UppercaseLetter),
+    (0x2CC9, 0x2CC9, LowercaseLetter),
+    (0x2CCA, 0x2CCA, UppercaseLetter),
+    (0x2CCB, 0x2CCB, LowercaseLetter),
+    (0x2CCC, 0x2CCC, UppercaseLetter),
+    (0x2CCD, 0x2CCD, LowercaseLetter),
+    (0x2CCE, 0x2CCE, UppercaseLetter),
+    (0x2CCF, 0x2CCF, LowercaseLetter),
+    (0x2CD0, 0x2CD0, UppercaseLetter),
+    (0x2CD1, 0x2CD1, LowercaseLetter),
+    (0x2CD2, 0x2CD2, UppercaseLetter),
+    (0x2CD3, 0x2CD3, LowercaseLetter),
+    (0x2CD4, 0x2CD4, UppercaseLetter),
+    (0x2CD5, 0x2CD5, LowercaseLetter),
+    (0x2CD6, 0x2CD6, UppercaseLetter),
+    (0x2CD7, 0x2CD7, LowercaseLetter),
+    (0x2CD8, 0x2CD8, UppercaseLetter),
+    (0x2CD9, 0x2CD9, LowercaseLetter),
+    (0x2CDA, 0x2CDA, UppercaseLetter),
+    (0x2CDB, 0x2CDB, LowercaseLetter),
+    (0x2CDC, 0x2CDC, UppercaseLetter),
+    (0x2CDD, 0x2CDD, LowercaseLetter),
+    (0x2CDE, 0x2CDE, UppercaseLetter),
+    (0x2CDF, 0x2CDF, LowercaseLetter),
+    (0x2CE0, 0x2CE0, UppercaseLetter),
+    (0x2CE1, 0x2CE1, LowercaseLetter),
+    (0x2CE2, 0x2CE2, UppercaseLetter),
+    (0x2CE3, 0x2CE4, LowercaseLetter),
+    (0x2CE5, 0x2CEA, OtherSymbol),
+    (0x2CEB, 0x2CEB, UppercaseLetter),
+    (0x2CEC, 0x2CEC, LowercaseLetter),
+    (0x2CED, 0x2CED, UppercaseLetter),
+    (0x2CEE, 0x2CEE, LowercaseLetter),
+    (0x2CEF, 0x2CF1, NonspacingMark),
+    (0x2CF2, 0x2CF2, UppercaseLetter),
+    (0x2CF3, 0x2CF3, LowercaseLetter),
+    (0x2CF9, 0x2CFC, OtherPunctuation),
+    (0x2CFD, 0x2CFD, OtherNumber),
+    (0x2CFE, 0x2CFF, OtherPunctuation),
+    (0x2D00, 0x2D25, LowercaseLetter),
+    (0x2D27, 0x2D27, LowercaseLetter),
+    (0x2D2D, 0x2D2D, LowercaseLetter),
+    (0x2D30, 0x2D67, OtherLetter),
+    (0x2D6F, 0x2D6F, ModifierLetter),
+    (0x2D70, 0x2D70, OtherPunctuation),
+    (0x2D7F, 0x2D7F, NonspacingMark),
+    (0x2D80, 0x2D96, OtherLetter),
+    (0x2DA0, 0x2DA6, OtherLetter),
+    (0x2DA8, 0x2DAE, OtherLetter),
+    (0x2DB0, 0x2DB6, OtherLetter),
+    (0x2DB8, 0x2DBE, OtherLetter),
+    (0x2DC0, 0x2DC6, OtherLetter),
+    (0x2DC8, 0x2DCE, OtherLetter),
+    (0x2DD0, 0x2DD6, OtherLetter),
+    (0x2DD8, 0x2DDE, OtherLetter),
+    (0x2DE0, 0x2DFF, NonspacingMark),
+    (0x2E00, 0x2E01, OtherPunctuation),
+    (0x2E02, 0x2E02, InitialPunctuation),
+    (0x2E03, 0x2E03, FinalPunctuation),
+    (0x2E04, 0x2E04, InitialPunctuation),
+    (0x2E05, 0x2E05, FinalPunctuation),
+    (0x2E06, 0x2E08, OtherPunctuation),
+    (0x2E09, 0x2E09, InitialPunctuation),
+    (0x2E0A, 0x2E0A, FinalPunctuation),
+    (0x2E0B, 0x2E0B, OtherPunctuation),
+    (0x2E0C, 0x2E0C, InitialPunctuation),
+    (0x2E0D, 0x2E0D, FinalPunctuation),
+    (0x2E0E, 0x2E16, OtherPunctuation),
+    (0x2E17, 0x2E17, DashPunctuation),
+    (0x2E18, 0x2E19, OtherPunctuation),
+    (0x2E1A, 0x2E1A, DashPunctuation),
+    (0x2E1B, 0x2E1B, OtherPunctuation),
+    (0x2E1C, 0x2E1C, InitialPunctuation),
+    (0x2E1D, 0x2E1D, FinalPunctuation),
+    (0x2E1E, 0x2E1F, OtherPunctuation),
+    (0x2E20, 0x2E20, InitialPunctuation),
+    (0x2E21, 0x2E21, FinalPunctuation),
+    (0x2E22, 0x2E22, OpenPunctuation),
+    (0x2E23, 0x2E23, ClosePunctuation),
+    (0x2E24, 0x2E24, OpenPunctuation),
+    (0x2E25, 0x2E25, ClosePunctuation),
+    (0x2E26, 0x2E26, OpenPunctuation),
+    (0x2E27, 0x2E27, ClosePunctuation),
+    (0x2E28, 0x2E28, OpenPunctuation),
+    (0x2E29, 0x2E29, ClosePunctuation),
+    (0x2E2A, 0x2E2E, OtherPunctuation),
+    (0x2E2F, 0x2E2F, ModifierLetter),
+    (0x2E30, 0x2E39, OtherPunctuation),
+    (0x2E3A, 0x2E3B, DashPunctuation),
+    (0x2E3C, 0x2E3F, OtherPunctuation),
+    (0x2E40, 0x2E40, DashPunctuation),
+    (0x2E41, 0x2E41, OtherPunctuation),
+    (0x2E42, 0x2E42, OpenPunctuation),
+    (0x2E43, 0x2E4F, OtherPunctuation),
+    (0x2E50, 0x2E51, OtherSymbol),
+    (0x2E52, 0x2E54, OtherPunctuation),
+    (0x2E55, 0x2E55, OpenPunctuation),
+    (0x2E56, 0x2E56, ClosePunctuation),
+    (0x2E57, 0x2E57, OpenPunctuation),
+    (0x2E58, 0x2E58, ClosePunctuation),
+    (0x2E59, 0x2E59, OpenPunctuation),
+    (0x2E5A, 0x2E5A, ClosePunctuation),
+    (0x2E5B, 0x2E5B, OpenPunctuation),
+    (0x2E5C, 0x2E5C, ClosePunctuation),
+    (0x2E5D, 0x2E5D, DashPunctuation),
+    (0x2E80, 0x2E99, OtherSymbol),
+    (0x2E9B, 0x2EF3, OtherSymbol),
+    (0x2F00, 0x2FD5, OtherSymbol),
+    (0x2FF0, 0x2FFB, OtherSymbol),
+    (0x3000, 0x3000, SpaceSeparator),
+    (0x3001, 0x3003, OtherPunctuation),
+    (0x3004, 0x3004, OtherSymbol),
+    (0x3005, 0x3005, ModifierLetter),
+    (0x3006, 0x3006, OtherLetter),
+    (0x3007, 0x3007, LetterNumber),
+    (0x3008, 0x3008, OpenPunctuation),
+    (0x3009, 0x3009, ClosePunctuation),
+    (0x300A, 0x300A, OpenPunctuation),
+    (0x300B, 0x300B, ClosePunctuation),
+    (0x300C, 0x300C, OpenPunctuation),
+    (0x300D, 0x300D, ClosePunctuation),
+    (0x300E, 0x300E, OpenPunctuation),
+    (0x300F, 0x300F, ClosePunctuation),
+    (0x3010, 0x3010, OpenPunctuation),
+    (0x3011, 0x3011, ClosePunctuation),
+    (0x3012, 0x3013, OtherSymbol),
+    (0x3014, 0x3014, OpenPunctuation),
+    (0x3015, 0x3015, ClosePunctuation),
+    (0x3016, 0x3016, OpenPunctuation),
+    (0x3017, 0x3017, ClosePunctuation),
+    (0x3018, 0x3018, OpenPunctuation),
+    (0x3019, 0x3019, ClosePunctuation),
+    (0x301A, 0x301A, OpenPunctuation),
+    (0x301B, 0x301B, ClosePunctuation),
+    (0x301C, 0x301C, DashPunctuation),
+    (0x301D, 0x301D, OpenPunctuation),
+    (0x301E, 0x301F, ClosePunctuation),
+    (0x3020, 0x3020, OtherSymbol),
+    (0x3021, 0x3029, LetterNumber),
+    (0x302A, 0x302D, NonspacingMark),
+    (0x302E, 0x302F, SpacingMark),
+    (0x3030, 0x3030, DashPunctuation),
+    (0x3031, 0x3035, ModifierLetter),
+    (0x3036, 0x3037, OtherSymbol),
+    (0x3038, 0x303A, LetterNumber),
+    (0x303B, 0x303B, ModifierLetter),
+    (0x303C, 0x303C, OtherLetter),
+    (0x303D, 0x303D, OtherPunctuation),
+    (0x303E, 0x303F, OtherSymbol),
+    (0x3041, 0x3096, OtherLetter),
+    (0x3099, 0x309A, NonspacingMark),
+    (0x309B, 0x309C, ModifierSymbol),
+    (0x309D, 0x309E, ModifierLetter),
+    (0x309F, 0x309F, OtherLetter),
+    (0x30A0, 0x30A0, DashPunctuation),
+    (0x30A1, 0x30FA, OtherLetter),
+    (0x30FB, 0x30FB, OtherPunctuation),
+    (0x30FC, 0x30FE, ModifierLetter),
+    (0x30FF, 0x30FF, OtherLetter),
+    (0x3105, 0x312F, OtherLetter),
+    (0x3131, 0x318E, OtherLetter),
+    (0x3190, 0x3191, OtherSymbol),
+    (0x3192, 0x3195, OtherNumber),
+    (0x3196, 0x319F, OtherSymbol),
+    (0x31A0, 0x31BF, OtherLetter),
+    (0x31C0, 0x31E3, OtherSymbol),
+    (0x31F0, 0x31FF, OtherLetter),
+    (0x3200, 0x321E, OtherSymbol),
+    (0x3220, 0x3229, OtherNumber),
+    (0x322A, 0x3247, OtherSymbol),
+    (0x3248, 0x324F, OtherNumber),
+    (0x3250, 0x3250, OtherSymbol),
+    (0x3251, 0x325F, OtherNumber),
+    (0x3260, 0x327F, OtherSymbol),
+    (0x3280, 0x3289, OtherNumber),
+    (0x328A, 0x32B0, OtherSymbol),
+    (0x32B1, 0x32BF, OtherNumber),
+    (0x32C0, 0x33FF, OtherSymbol),
+    (0x3400, 0x4DBF, OtherLetter),
+    (0x4DC0, 0x4DFF, OtherSymbol),
+    (0x4E00, 0xA014, OtherLetter),
+    (0xA015, 0xA015, ModifierLetter),
+    (0xA016, 0xA48C, OtherLetter),
+    (0xA490, 0xA4C6, OtherSymbol),
+    (0xA4D0, 0xA4F7, OtherLetter),
+    (0xA4F8, 0xA4FD, ModifierLetter),
+    (0xA4FE, 0xA4FF, OtherPunctuation),
+    (0xA500, 0xA60B, OtherLetter),
+    (0xA60C, 0xA60C, ModifierLetter),
+    (0xA60D, 0xA60F, OtherPunctuation),
+    (0xA610, 0xA61F, OtherLetter),
+    (0xA620, 0xA629, DecimalNumber),
+    (0xA62A, 0xA62B, OtherLetter),
+    (0xA640, 0xA640, UppercaseLetter),
+    (0xA641, 0xA641, LowercaseLetter),
+    (0xA642, 0xA642, UppercaseLetter),
+    (0xA643, 0xA643, LowercaseLetter),
+    (0xA644, 0xA644, UppercaseLetter),
+    (0xA645, 0xA645, LowercaseLetter),
+    (0xA646, 0xA646, UppercaseLetter),
+    (0xA647, 0xA647, LowercaseLetter),
+    (0xA648, 0xA648, UppercaseLetter),
+    (0xA649, 0xA649, LowercaseLetter),
+    (0xA64A, 0xA64A, UppercaseLetter),
+    (0xA64B, 0xA64B, LowercaseLetter),
+    (0xA64C, 0xA64C, UppercaseLetter),
+    (0xA64D, 0xA64D, LowercaseLetter),
+    (0xA64E, 0xA64E, UppercaseLetter),
+    (0xA64F, 0xA64F, LowercaseLetter),
+    (0xA650, 0xA650, UppercaseLetter),
+    (0xA651, 0xA651, LowercaseLetter),
+    (0xA652, 0xA652, UppercaseLetter),
+    (0xA653, 0xA653, LowercaseLetter),
+    (0xA654, 0xA654, UppercaseLetter),
+    (0xA655, 0xA655, LowercaseLetter),
+    (0xA656, 0xA656, UppercaseLetter),
+    (0xA657, 0xA657, LowercaseLetter),
+    (0xA658, 0xA658, UppercaseLetter),
+    (0xA659, 0xA659, LowercaseLetter),
+    (0xA65A, 0xA65A, UppercaseLetter),
+    (0xA65B, 0xA65B, LowercaseLetter),
+    (0xA65C, 0xA65C, UppercaseLetter),
+    (0xA65D, 0xA65D, LowercaseLetter),
+    (0xA65E, 0xA65E, UppercaseLetter),
+    (0xA65F, 0xA65F, LowercaseLetter),
+    (0xA660, 0xA660, UppercaseLetter),
+    (0xA661, 0xA661, LowercaseLetter),
+    (0xA662, 0xA662, UppercaseLetter),
+    (0xA663, 0xA663, LowercaseLetter),
+    (0xA664, 0xA664, UppercaseLetter),
+    (0xA665, 0xA665, LowercaseLetter),
+    (0xA666, 0xA666, UppercaseLetter),
+    (0xA667, 0xA667, LowercaseLetter),
+    (0xA668, 0xA668, UppercaseLetter),
+    (0xA669, 0xA669, LowercaseLetter),
+    (0xA66A, 0xA66A, UppercaseLetter),
+    (0xA66B, 0xA66B, LowercaseLetter),
+    (0xA66C, 0xA66C, UppercaseLetter),
+    (0xA66D, 0xA66D, LowercaseLetter),
+    (0xA66E, 0xA66E, OtherLetter),
+    (0xA66F, 0xA66F, NonspacingMark),
+    (0xA670, 0xA672, EnclosingMark),
+    (0xA673, 0xA673, OtherPunctuation),
+    (0xA674, 0xA67D, NonspacingMark),
+    (0xA67E, 0xA67E, OtherPunctuation),
+    (0xA67F, 0xA67F, ModifierLetter),
+    (0xA680, 0xA680, UppercaseLetter),
+    (0xA681, 0xA681, LowercaseLetter),
+    (0xA682, 0xA682, UppercaseLetter),
+    (0xA683, 0xA683, LowercaseLetter),
+    (0xA684, 0xA684, UppercaseLetter),
+    (0xA685, 0xA685, LowercaseLetter),
+    (0xA686, 0xA686, UppercaseLetter),
+    (0xA687, 0xA687, LowercaseLetter),
+    (0xA688, 0xA688, UppercaseLetter),
+    (0xA689, 0xA689, LowercaseLetter),
+    (0xA68A, 0xA68A, UppercaseLetter),
+    (0xA68B, 0xA68B, LowercaseLetter),
+    (0xA68C, 0xA68C, UppercaseLetter),
+    (0xA68D, 0xA68D, LowercaseLetter),
+    (0xA68E, 0xA68E, UppercaseLetter),
+    (0xA68F, 0xA68F, LowercaseLetter),
+    (0xA690, 0xA690, UppercaseLetter),
+    (0xA691, 0xA691, LowercaseLetter),
+    (0xA692, 0xA692, UppercaseLetter),
+    (0xA693, 0xA693, LowercaseLetter),
+    (0xA694, 0xA694, UppercaseLetter),
+    (0xA695, 0xA695, LowercaseLetter),
+    (0xA696, 0xA696, UppercaseLetter),
+    (0xA697, 0xA697, LowercaseLetter),
+    (0xA698, 0xA698, UppercaseLetter),
+    (0xA699, 0xA699, LowercaseLetter),
+    (0xA69A, 0xA69A, UppercaseLetter),
+    (0xA69B, 0xA69B, LowercaseLetter),
+    (0xA69C, 0xA69D, ModifierLetter),
+    (0xA69E, 0xA69F, NonspacingMark),
+    (0xA6A0, 0xA6E5, OtherLetter),
+    (0xA6E6, 0xA6EF, LetterNumber),
+    (0xA6F0, 0xA6F1, NonspacingMark),
+    (0xA6F2, 0xA6F7, OtherPunctuation),
+    (0xA700, 0xA716, ModifierSymbol),
+    (0xA717, 0xA71F, ModifierLetter),
+    (0xA720, 0xA721, ModifierSymbol),
+    (0xA722, 0xA722, UppercaseLetter),
+    (0xA723, 0xA723, LowercaseLetter),
+    (0xA724, 0xA724, UppercaseLetter),
+    (0xA725, 0xA725, LowercaseLetter),
+    (0xA726, 0xA726, UppercaseLetter),
+    (0xA727, 0xA727, LowercaseLetter),
+    (0xA728, 0xA728, UppercaseLetter),
+    (0xA729, 0xA729, LowercaseLetter),
+    (0xA72A, 0xA72A, UppercaseLetter),
+    (0xA72B, 0xA72B, LowercaseLetter),
+    (0xA72C, 0xA72C, UppercaseLetter),
+    (0xA72D, 0xA72D, LowercaseLetter),
+    (0xA72E, 0xA72E, UppercaseLetter),
+    (0xA72F, 0xA731, LowercaseLetter),
+    (0xA732, 0xA732, UppercaseLetter),
+    (0xA733, 0xA733, LowercaseLetter),
+    (0xA734, 0xA734, UppercaseLetter),
+    (0xA735, 0xA735, LowercaseLetter),
+    (0xA736, 0xA736, UppercaseLetter),
+    (0xA737, 0xA737, LowercaseLetter),
+    (0xA738, 0xA738, UppercaseLetter),
+    (0xA739, 0xA739, LowercaseLetter),
+    (0xA73A, 0xA73A, UppercaseLetter),
+    (0xA73B, 0xA73B, LowercaseLetter),
+    (0xA73C, 0xA73C, UppercaseLetter),
+    (0xA73D, 0xA73D, LowercaseLetter),
+    (0xA73E, 0xA73E, UppercaseLetter),
+    (0xA73F, 0xA73F, LowercaseLetter),
+    (0xA740, 0xA740, UppercaseLetter),
+    (0xA741, 0xA741, LowercaseLetter),
+    (0xA742, 0xA742, UppercaseLetter),
+    (0xA743, 0xA743, LowercaseLetter),
+    (0xA744, 0xA744, UppercaseLetter),
+    (0xA745, 0xA745, LowercaseLetter),
+    (0xA746, 0xA746, UppercaseLetter),
+    (0xA747, 0xA747, LowercaseLetter),
+    (0xA748, 0xA748, UppercaseLetter),
+    (0xA749, 0xA749, LowercaseLetter),
+    (0xA74A, 0xA74A, UppercaseLetter),
+    (0xA74B, 0xA74B, LowercaseLetter),
+    (0xA74C, 0xA74C, UppercaseLetter),
+    (0xA74D, 0xA74D, LowercaseLetter),
+    (0xA74E, 0xA74E, UppercaseLetter),
+    (0xA74F, 0xA74F, LowercaseLetter),
+    (0xA750, 0xA750, UppercaseLetter),
+    (0xA751, 0xA751, LowercaseLetter),
+    (0xA752, 0xA752, UppercaseLetter),
+    (0xA753, 0xA753, LowercaseLetter),
+    (0xA754, 0xA754, UppercaseLetter),
+    (0xA755, 0xA755, LowercaseLetter),
+    (0xA756, 0xA756, UppercaseLetter),
+    (0xA757, 0xA757, LowercaseLetter),
+    (0xA758, 0xA758, UppercaseLetter),
+    (0xA759, 0xA759, LowercaseLetter),
+    (0xA75A, 0xA75A, UppercaseLetter),
+    (0xA75B, 0xA75B, LowercaseLetter),
+    (0xA75C, 0xA75C, UppercaseLetter),
+    (0xA75D, 0xA75D, LowercaseLetter),
+    (0xA75E, 0xA75E, UppercaseLetter),
+    (0xA75F, 0xA75F, LowercaseLetter),
+    (0xA760, 0xA760, UppercaseLetter),
+    (0xA761, 0xA761, LowercaseLetter),
+    (0xA762, 0xA762, UppercaseLetter),
+    (0xA763, 0xA763, LowercaseLetter),
+    (0xA764, 0xA764, UppercaseLetter),
+    (0xA765, 0xA765, LowercaseLetter),
+    (0xA766, 0xA766, UppercaseLetter),
+    (0xA767, 0xA767, LowercaseLetter),
+    (0xA768, 0xA768, UppercaseLetter),
+    (0xA769, 0xA769, LowercaseLetter),
+    (0xA76A, 0xA76A, UppercaseLetter),
+    (0xA76B, 0xA76B, LowercaseLetter),
+    (0xA76C, 0xA76C, UppercaseLetter),
+    (0xA76D, 0xA76D, LowercaseLetter),
+    (0xA76E, 0xA76E, UppercaseLetter),
+    (0xA76F, 0xA76F, LowercaseLetter),
+    (0xA770, 0xA770, ModifierLetter),
+    (0xA771, 0xA778, LowercaseLetter),
+    (0xA779, 0xA779, UppercaseLetter),
+    (0xA77A, 0xA77A, LowercaseLetter),
+    (0xA77B, 0xA77B, UppercaseLetter),
+    (0xA77C, 0xA77C, LowercaseLetter),
+    (0xA77D, 0xA77E, UppercaseLetter),
+    (0xA77F, 0xA77F, LowercaseLetter),
+    (0xA780, 0xA780, UppercaseLetter),
+    (0xA781, 0xA781, LowercaseLetter),
+    (0xA782, 0xA782, UppercaseLetter),
+    (0xA783, 0xA783, LowercaseLetter),
+    (0xA784, 0xA784, UppercaseLetter),
+    (0xA785, 0xA785, LowercaseLetter),
+    (0xA786, 0xA786, UppercaseLetter),
+    (0xA787, 0xA787, LowercaseLetter),
+    (0xA788, 0xA788, ModifierLetter),
+    (0xA789, 0xA78A, ModifierSymbol),
+    (0xA78B, 0xA78B, UppercaseLetter),
+    (0xA78C, 0xA78C, LowercaseLetter),
+    (0xA78D, 0xA78D, UppercaseLetter),
+    (0xA78E, 0xA78E, LowercaseLetter),
+    (0xA78F, 0xA78F, OtherLetter),
+    (0xA790, 0xA790, UppercaseLetter),
+    (0xA791, 0xA791, LowercaseLetter),
+    (0xA792, 0xA792, UppercaseLetter),
+    (0xA793, 0xA795, LowercaseLetter),
+    (0xA796, 0xA796, UppercaseLetter),
+    (0xA797, 0xA797, LowercaseLetter),
+    (0xA798, 0xA798, UppercaseLetter),
+    (0xA799, 0xA799, LowercaseLetter),
+    (0xA79A, 0xA79A, UppercaseLetter),
+    (0xA79B, 0xA79B, LowercaseLetter),
+    (0xA79C, 0xA79C, UppercaseLetter),
+    (0xA79D, 0xA79D, LowercaseLetter),
+    (0xA79E, 0xA79E, UppercaseLetter),
+    (0xA79F, 0xA79F, LowercaseLetter),
+    (0xA7A0, 0xA7A0, UppercaseLetter),
+    (0xA7A1, 0xA7A1, LowercaseLetter),
+    (0xA7A2, 0xA7A2, UppercaseLetter),
+    (0xA7A3, 0xA7A3, LowercaseLetter),
+    (0xA7A4, 0xA7A4, UppercaseLetter),
+    (0xA7A5, 0xA7A5, LowercaseLetter),
+    (0xA7A6, 0xA7A6, UppercaseLetter),
+    (0xA7A7, 0xA7A7, LowercaseLetter),
+    (0xA7A8, 0xA7A8, UppercaseLetter),
+    (0xA7A9, 0xA7A9, LowercaseLetter),
+    (0xA7AA, 0xA7AE, UppercaseLetter),
+    (0xA7AF, 0xA7AF, LowercaseLetter),
+    (0xA7B0, 0xA7B4, UppercaseLetter),
+    (0xA7B5, 0xA7B5, LowercaseLetter),
+    (0xA7B6, 0xA7B6, UppercaseLetter),
+    (0xA7B7, 0xA7B7, LowercaseLetter),
+    (0xA7B8, 0xA7B8, UppercaseLetter),
+    (0xA7B9, 0xA7B9, LowercaseLetter),
+    (0xA7BA, 0xA7BA, UppercaseLetter),
+    (0xA7BB, 0xA7BB, LowercaseLetter),
+    (0xA7BC, 0xA7BC, UppercaseLetter),
+    (0xA7BD, 0xA7BD, LowercaseLetter),
+    (0xA7BE, 0xA7BE, UppercaseLetter),
+    (0xA7BF, 0xA7BF, LowercaseLetter),
+    (0xA7C0, 0xA7C0, UppercaseLetter),
+    (0xA7C1, 0xA7C1, LowercaseLetter),
+    (0xA7C2, 0xA7C2, UppercaseLetter),
+    (0xA7C3, 0xA7C3, LowercaseLetter),
+    (0xA7C4, 0xA7C7, UppercaseLetter),
+    (0xA7C8, 0xA7C8, LowercaseLetter),
+    (0xA7C9, 0xA7C9, UppercaseLetter),
+    (0xA7CA, 0xA7CA, LowercaseLetter),
+    (0xA7D0, 0xA7D0, UppercaseLetter),
+    (0xA7D1, 0xA7D1, LowercaseLetter),
+    (0xA7D3, 0xA7D3, LowercaseLetter),
+    (0xA7D5, 0xA7D5, LowercaseLetter),
+    (0xA7D6, 0xA7D6, UppercaseLetter),
+    (0xA7D7, 0xA7D7, LowercaseLetter),
+    (0xA7D8, 0xA7D8, UppercaseLetter),
+    (0xA7D9, 0xA7D9, LowercaseLetter),
+    (0xA7F2, 0xA7F4, ModifierLetter),
+    (0xA7F5, 0xA7F5, UppercaseLetter),
+    (0xA7F6, 0xA7F6, LowercaseLetter),
+    (0xA7F7, 0xA7F7, OtherLetter),
+    (0xA7F8, 0xA7F9, ModifierLetter),
+    (0xA7FA, 0xA7FA, LowercaseLetter),
+    (0xA7FB, 0xA801, OtherLetter),
+    (0xA802, 0xA802, NonspacingMark),
+    (0xA803, 0xA805, OtherLetter),
+    (0xA806, 0xA806, NonspacingMark),
+    (0xA807, 0xA80A, OtherLetter),
+    (0xA80B, 0xA80B, NonspacingMark),
+    (0xA80C, 0xA822, OtherLetter),
+    (0xA823, 0xA824, SpacingMark),
+    (0xA825, 0xA826, NonspacingMark),
+    (0xA827, 0xA827, SpacingMark),
+    (0xA828, 0xA82B, OtherSymbol),
+    (0xA82C, 0xA82C, NonspacingMark),
+    (0xA830, 0xA835, OtherNumber),
+    (0xA836, 0xA837, OtherSymbol),
+    (0xA838, 0xA838, CurrencySymbol),
+    (0xA839, 0xA839, OtherSymbol),
+    (0xA840, 0xA873, OtherLetter),
+    (0xA874, 0xA877, OtherPunctuation),
+    (0xA880, 0xA881, SpacingMark),
+    (0xA882, 0xA8B3, OtherLetter),
+    (0xA8B4, 0xA8C3, SpacingMark),
+    (0xA8C4, 0xA8C5, NonspacingMark),
+    (0xA8CE, 0xA8CF, OtherPunctuation),
+    (0xA8D0, 0xA8D9, DecimalNumber),
+    (0xA8E0, 0xA8F1, NonspacingMark),
+    (0xA8F2, 0xA8F7, OtherLetter),
+    (0xA8F8, 0xA8FA, OtherPunctuation),
+    (0xA8FB, 0xA8FB, OtherLetter),
+    (0xA8FC, 0xA8FC, OtherPunctuation),
+    (0xA8FD, 0xA8FE, OtherLetter),
+    (0xA8FF, 0xA8FF, NonspacingMark),
+    (0xA900, 0xA909, DecimalNumber),
+    (0xA90A, 0xA925, OtherLetter),
+    (0xA926, 0xA92D, NonspacingMark),
+    (0xA92E, 0xA92F, OtherPunctuation),
+    (0xA930, 0xA946, OtherLetter),
+    (0xA947, 0xA951, NonspacingMark),
+    (0xA952, 0xA953, SpacingMark),
+    (0xA95F, 0xA95F, OtherPunctuation),
+    (0xA960, 0xA97C, OtherLetter),
+    (0xA980, 0xA982, NonspacingMark),
+    (0xA983, 0xA983, SpacingMark),
+    (0xA984, 0xA9B2, OtherLetter),
+    (0xA9B3, 0xA9B3, NonspacingMark),
+    (0xA9B4, 0xA9B5, SpacingMark),
+    (0xA9B6, 0xA9B9, NonspacingMark),
+    (0xA9BA, 0xA9BB, SpacingMark),
+    (0xA9BC, 0xA9BD, NonspacingMark),
+    (0xA9BE, 0xA9C0, SpacingMark),
+    (0xA9C1, 0xA9CD, OtherPunctuation),
+    (0xA9CF, 0xA9CF, ModifierLetter),
+    (0xA9D0, 0xA9D9, DecimalNumber),
+    (0xA9DE, 0xA9DF, OtherPunctuation),
+    (0xA9E0, 0xA9E4, OtherLetter),
+    (0xA9E5, 0xA9E5, NonspacingMark),
+    (0xA9E6, 0xA9E6, ModifierLetter),
+    (0xA9E7, 0xA9EF, OtherLetter),
+    (0xA9F0, 0xA9F9, DecimalNumber),
+    (0xA9FA, 0xA9FE, OtherLetter),
+    (0xAA00, 0xAA28, OtherLetter),
+    (0xAA29, 0xAA2E, NonspacingMark),
+    (0xAA2F, 0xAA30, SpacingMark),
+    (0xAA31, 0xAA32, NonspacingMark),
+    (0xAA33, 0xAA34, SpacingMark),
+    (0xAA35, 0xAA36, NonspacingMark),
+    (0xAA40, 0xAA42, OtherLetter),
+    (0xAA43, 0xAA43, NonspacingMark),
+    (0xAA44, 0xAA4B, OtherLetter),
+    (0xAA4C, 0xAA4C, NonspacingMark),
+    (0xAA4D, 0xAA4D, SpacingMark),
+    (0xAA50, 0xAA59, DecimalNumber),
+    (0xAA5C, 0xAA5F, OtherPunctuation),
+    (0xAA60, 0xAA6F, OtherLetter),
+    (0xAA70, 0xAA70, ModifierLetter),
+    (0xAA71, 0xAA76, OtherLetter),
+    (0xAA77, 0xAA79, OtherSymbol),
+    (0xAA7A, 0xAA7A, OtherLetter),
+    (0xAA7B, 0xAA7B, SpacingMark),
+    (0xAA7C, 0xAA7C, NonspacingMark),
+    (0xAA7D, 0xAA7D, SpacingMark),
+    (0xAA7E, 0xAAAF, OtherLetter),
+    (0xAAB0, 0xAAB0, NonspacingMark),
+    (0xAAB1, 0xAAB1, OtherLetter),
+    (0xAAB2, 0xAAB4, NonspacingMark),
+    (0xAAB5, 0xAAB6, OtherLetter),
+    (0xAAB7, 0xAAB8, NonspacingMark),
+    (0xAAB9, 0xAABD, OtherLetter),
+    (0xAABE, 0xAABF, NonspacingMark),
+    (0xAAC0, 0xAAC0, OtherLetter),
+    (0xAAC1, 0xAAC1, NonspacingMark),
+    (0xAAC2, 0xAAC2, OtherLetter),
+    (0xAADB, 0xAADC, OtherLetter),
+    (0xAADD, 0xAADD, ModifierLetter),
+    (0xAADE, 0xAADF, OtherPunctuation),
+    (0xAAE0, 0xAAEA, OtherLetter),
+    (0xAAEB, 0xAAEB, SpacingMark),
+    (0xAAEC, 0xAAED, NonspacingMark),
+    (0xAAEE, 0xAAEF, SpacingMark),
+    (0xAAF0, 0xAAF1, OtherPunctuation),
+    (0xAAF2, 0xAAF2, OtherLetter),
+    (0xAAF3, 0xAAF4, ModifierLetter),
+    (0xAAF5, 0xAAF5, SpacingMark),
+    (0xAAF6, 0xAAF6, NonspacingMark),
+    (0xAB01, 0xAB06, OtherLetter),
+    (0xAB09, 0xAB0E, OtherLetter),
+    (0xAB11, 0xAB16, OtherLetter),
+    (0xAB20, 0xAB26, OtherLetter),
+    (0xAB28, 0xAB2E, OtherLetter),
+    (0xAB30, 0xAB5A, LowercaseLetter),
+    (0xAB5B, 0xAB5B, ModifierSymbol),
+    (0xAB5C, 0xAB5F, ModifierLetter),
+    (0xAB60, 0xAB68, LowercaseLetter),
+    (0xAB69, 0xAB69, ModifierLetter),
+    (0xAB6A, 0xAB6B, ModifierSymbol),
+    (0xAB70, 0xABBF, LowercaseLetter),
+    (0xABC0, 0xABE2, OtherLetter),
+    (0xABE3, 0xABE4, SpacingMark),
+    (0xABE5, 0xABE5, NonspacingMark),
+    (0xABE6, 0xABE7, SpacingMark),
+    (0xABE8, 0xABE8, NonspacingMark),
+    (0xABE9, 0xABEA, SpacingMark),
+    (0xABEB, 0xABEB, OtherPunctuation),
+    (0xABEC, 0xABEC, SpacingMark),
+    (0xABED, 0xABED, NonspacingMark),
+    (0xABF0, 0xABF9, DecimalNumber),
+    (0xAC00, 0xD7A3, OtherLetter),
+    (0xD7B0, 0xD7C6, OtherLetter),
+    (0xD7CB, 0xD7FB, OtherLetter),
+    (0xD800, 0xDFFF, Surrogate),
+    (0xE000, 0xF8FF, PrivateUse),
+    (0xF900, 0xFA6D, OtherLetter),
+    (0xFA70, 0xFAD9, OtherLetter),
+    (0xFB00, 0xFB06, LowercaseLetter),
+    (0xFB13, 0xFB17, LowercaseLetter),
+    (0xFB1D, 0xFB1D, OtherLetter),
+    (0xFB1E, 0xFB1E, NonspacingMark),
+    (0xFB1F, 0xFB28, OtherLetter),
+    (0xFB29, 0xFB29, MathSymbol),
+    (0xFB2A, 0xFB36, OtherLetter),
+    (0xFB38, 0xFB3C, OtherLetter),
+    (0xFB3E, 0xFB3E, OtherLetter),
+    (0xFB40, 0xFB41, OtherLetter),
+    (0xFB43, 0xFB44, OtherLetter),
+    (0xFB46, 0xFBB1, OtherLetter),
+    (0xFBB2, 0xFBC2, ModifierSymbol),
+    (0xFBD3, 0xFD3D, OtherLetter),
+    (0xFD3E, 0xFD3E, ClosePunctuation),
+    (0xFD3F, 0xFD3F, OpenPunctuation),
+    (0xFD40, 0xFD4F, OtherSymbol),
+    (0xFD50, 0xFD8F, OtherLetter),
+    (0xFD92, 0xFDC7, OtherLetter),
+    (0xFDCF, 0xFDCF, OtherSymbol),
+    (0xFDF0, 0xFDFB, OtherLetter),
+    (0xFDFC, 0xFDFC, CurrencySymbol),
+    (0xFDFD, 0xFDFF, OtherSymbol),
+    (0xFE00, 0xFE0F, NonspacingMark),
+    (0xFE10, 0xFE16, OtherPunctuation),
+    (0xFE17, 0xFE17, OpenPunctuation),
+    (0xFE18, 0xFE18, ClosePunctuation),
+    (0xFE19, 0xFE19, OtherPunctuation),
+    (0xFE20, 0xFE2F, NonspacingMark),
+    (0xFE30, 0xFE30, OtherPunctuation),
+    (0xFE31, 0xFE32, DashPunctuation),
+    (0xFE33, 0xFE34, ConnectorPunctuation),
+    (0xFE35, 0xFE35, OpenPunctuation),
+    (0xFE36, 0xFE36, ClosePunctuation),
+    (0xFE37, 0xFE37, OpenPunctuation),
+    (0xFE38, 0xFE38, ClosePunctuation),
+    (0xFE39, 0xFE39, OpenPunctuation),
+    (0xFE3A, 0xFE3A, ClosePunctuation),
+    (0xFE3B, 0xFE3B, OpenPunctuation),
+    (0xFE3C, 0xFE3C, ClosePunctuation),
+    (0xFE3D, 0xFE3D, OpenPunctuation),
+    (0xFE3E, 0xFE3E, ClosePunctuation),
+    (0xFE3F, 0xFE3F, OpenPunctuation),
+    (0xFE40, 0xFE40, ClosePunctuation),
+    (0xFE41, 0xFE41, OpenPunctuation),
+    (0xFE42, 0xFE42, ClosePunctuation),
+    (0xFE43, 0xFE43, OpenPunctuation),
+    (0xFE44, 0xFE44, ClosePunctuation),
+    (0xFE45, 0xFE46, OtherPunctuation),
+    (0xFE47, 0xFE47, OpenPunctuation),
+    (0xFE48, 0xFE48, ClosePunctuation),
+    (0xFE49, 0xFE4C, OtherPunctuation),
+    (0xFE4D, 0xFE4F, ConnectorPunctuation),
+    (0xFE50, 0xFE52, OtherPunctuation),
+    (0xFE54, 0xFE57, OtherPunctuation),
+    (0xFE58, 0xFE58, DashPunctuation),
+    (0xFE59, 0xFE59, OpenPunctuation),
+    (0xFE5A, 0xFE5A, ClosePunctuation),
+    (0xFE5B, 0xFE5B, OpenPunctuation),
+    (0xFE5C, 0xFE5C, ClosePunctuation),
+    (0xFE5D, 0xFE5D, OpenPunctuation),
+    (0xFE5E, 0xFE5E, ClosePunctuation),
+    (0xFE5F, 0xFE61, OtherPunctuation),
+    (0xFE62, 0xFE62, MathSymbol),
+    (0xFE63, 0xFE63, DashPunctuation),
+    (0xFE64, 0xFE66, MathSymbol),
+    (0xFE68, 0xFE68, OtherPunctuation),
+    (0xFE69, 0xFE69, CurrencySymbol),
+    (0xFE6A, 0xFE6B, OtherPunctuation),
+    (0xFE70, 0xFE74, OtherLetter),
+    (0xFE76, 0xFEFC, OtherLetter),
+    (0xFEFF, 0xFEFF, Format),
+    (0xFF01, 0xFF03, OtherPunctuation),
+    (0xFF04, 0xFF04, CurrencySymbol),
+    (0xFF05, 0xFF07, OtherPunctuation),
+    (0xFF08, 0xFF08, OpenPunctuation),
+    (0xFF09, 0xFF09, ClosePunctuation),
+    (0xFF0A, 0xFF0A, OtherPunctuation),
+    (0xFF0B, 0xFF0B, MathSymbol),
+    (0xFF0C, 0xFF0C, OtherPunctuation),
+    (0xFF0D, 0xFF0D, DashPunctuation),
+    (0xFF0E, 0xFF0F, OtherPunctuation),
+    (0xFF10, 0xFF19, DecimalNumber),
+    (0xFF1A, 0xFF1B, OtherPunctuation),
+    (0xFF1C, 0xFF1E, MathSymbol),
+    (0xFF1F, 0xFF20, OtherPunctuation),
+    (0xFF21, 0xFF3A, UppercaseLetter),
+    (0xFF3B, 0xFF3B, OpenPunctuation),
+    (0xFF3C, 0xFF3C, OtherPunctuation),
+    (0xFF3D, 0xFF3D, ClosePunctuation),
+    (0xFF3E, 0xFF3E, ModifierSymbol),
+    (0xFF3F, 0xFF3F, ConnectorPunctuation),
+    (0xFF40, 0xFF40, ModifierSymbol),
+    (0xFF41, 0xFF5A, LowercaseLetter),
+    (0xFF5B, 0xFF5B, OpenPunctuation),
+    (0xFF5C, 0xFF5C, MathSymbol),
+    (0xFF5D, 0xFF5D, ClosePunctuation),
+    (0xFF5E, 0xFF5E, MathSymbol),
+    (0xFF5F, 0xFF5F, OpenPunctuation),
+    (0xFF60, 0xFF60, ClosePunctuation),
+    (0xFF61, 0xFF61, OtherPunctuation),
+    (0xFF62, 0xFF62, OpenPunctuation),
+    (0xFF63, 0xFF63, ClosePunctuation),
+    (0xFF64, 0xFF65, OtherPunctuation),
+    (0xFF66, 0xFF6F, OtherLetter),
+    (0xFF70, 0xFF70, ModifierLetter),
+    (0xFF71, 0xFF9D, OtherLetter),
+    (0xFF9E, 0xFF9F, ModifierLetter),
+    (0xFFA0, 0xFFBE, OtherLetter),
+    (0xFFC2, 0xFFC7, OtherLetter),
+    (0xFFCA, 0xFFCF, OtherLetter),
+    (0xFFD2, 0xFFD7, OtherLetter),
+    (0xFFDA, 0xFFDC, OtherLetter),
+    (0xFFE0, 0xFFE1, CurrencySymbol),
+    (0xFFE2, 0xFFE2, MathSymbol),
+    (0xFFE3, 0xFFE3, ModifierSymbol),
+    (0xFFE4, 0xFFE4, OtherSymbol),
+    (0xFFE5, 0xFFE6, CurrencySymbol),
+    (0xFFE8, 0xFFE8, OtherSymbol),
+    (0xFFE9, 0xFFEC, MathSymbol),
+    (0xFFED, 0xFFEE, OtherSymbol),
+    (0xFFF9, 0xFFFB, Format),
+    (0xFFFC, 0xFFFD, OtherSymbol),
+    (0x10000, 0x1000B, OtherLetter),
+    (0x1000D, 0x10026, OtherLetter),
+    (0x10028, 0x1003A, OtherLetter),
+    (0x1003C, 0x1003D, OtherLetter),
+    (0x1003F, 0x1004D, OtherLetter),
+    (0x10050, 0x1005D, OtherLetter),
+    (0x10080, 0x100FA, OtherLetter),
+    (0x10100, 0x10102, OtherPunctuation),
+    (0x10107, 0x10133, OtherNumber),
+    (0x10137, 0x1013F, OtherSymbol),
+    (0x10140, 0x10174, LetterNumber),
+    (0x10175, 0x10178, OtherNumber),
+    (0x10179, 0x10189, OtherSymbol),
+    (0x1018A, 0x1018B, OtherNumber),
+    (0x1018C, 0x1018E, OtherSymbol),
+    (0x10190, 0x1019C, OtherSymbol),
+    (0x101A0, 0x101A0, OtherSymbol),
+    (0x101D0, 0x101FC, OtherSymbol),
+    (0x101FD, 0x101FD, NonspacingMark),
+    (0x10280, 0x1029C, OtherLetter),
+    (0x102A0, 0x102D0, OtherLetter),
+    (0x102E0, 0x102E0, NonspacingMark),
+    (0x102E1, 0x102FB, OtherNumber),
+    (0x10300, 0x1031F, OtherLetter),
+    (0x10320, 0x10323, OtherNumber),
+    (0x1032D, 0x10340, OtherLetter),
+    (0x10341, 0x10341, LetterNumber),
+    (0x10342, 0x10349, OtherLetter),
+    (0x1034A, 0x1034A, LetterNumber),
+    (0x10350, 0x10375, OtherLetter),
+    (0x10376, 0x1037A, NonspacingMark),
+    (0x10380, 0x1039D, OtherLetter),
+    (0x1039F, 0x1039F, OtherPunctuation),
+    (0x103A0, 0x103C3, OtherLetter),
+    (0x103C8, 0x103CF, OtherLetter),
+    (0x103D0, 0x103D0, OtherPunctuation),
+    (0x103D1, 0x103D5, LetterNumber),
+    (0x10400, 0x10427, UppercaseLetter),
+    (0x10428, 0x1044F, LowercaseLetter),
+    (0x10450, 0x1049D, OtherLetter),
+    (0x104A0, 0x104A9, DecimalNumber),
+    (0x104B0, 0x104D3, UppercaseLetter),
+    (0x104D8, 0x104FB, LowercaseLetter),
+    (0x10500, 0x10527, OtherLetter),
+    (0x10530, 0x10563, OtherLetter),
+    (0x1056F, 0x1056F, OtherPunctuation),
+    (0x10570, 0x1057A, UppercaseLetter),
+    (0x1057C, 0x1058A, UppercaseLetter),
+    (0x1058C, 0x10592, UppercaseLetter),
+    (0x10594, 0x10595, UppercaseLetter),
+    (0x10597, 0x105A1, LowercaseLetter),
+    (0x105A3, 0x105B1, LowercaseLetter),
+    (0x105B3, 0x105B9, LowercaseLetter),
+    (0x105BB, 0x105BC, LowercaseLetter),
+    (0x10600, 0x10736, OtherLetter),
+    (0x10740, 0x10755, OtherLetter),
+    (0x10760, 0x10767, OtherLetter),
+    (0x10780, 0x10785, ModifierLetter),
+    (0x10787, 0x107B0, ModifierLetter),
+    (0x107B2, 0x107BA, ModifierLetter),
+    (0x10800, 0x10805, OtherLetter),
+    (0x10808, 0x10808, OtherLetter),
+    (0x1080A, 0x10835, OtherLetter),
+    (0x10837, 0x10838, OtherLetter),
+    (0x1083C, 0x1083C, OtherLetter),
+    (0x1083F, 0x10855, OtherLetter),
+    (0x10857, 0x10857, OtherPunctuation),
+    (0x10858, 0x1085F, OtherNumber),
+    (0x10860, 0x10876, OtherLetter),
+    (0x10877, 0x10878, OtherSymbol),
+    (0x10879, 0x1087F, OtherNumber),
+    (0x10880, 0x1089E, OtherLetter),
+    (0x108A7, 0x108AF, OtherNumber),
+    (0x108E0, 0x108F2, OtherLetter),
+    (0x108F4, 0x108F5, OtherLetter),
+    (0x108FB, 0x108FF, OtherNumber),
+    (0x10900, 0x10915, OtherLetter),
+    (0x10916, 0x1091B, OtherNumber),
+    (0x1091F, 0x1091F, OtherPunctuation),
+    (0x10920, 0x10939, OtherLetter),
+    (0x1093F, 0x1093F, OtherPunctuation),
+    (0x10980, 0x109B7, OtherLetter),
+    (0x109BC, 0x109BD, OtherNumber),
+    (0x109BE, 0x109BF, OtherLetter),
+    (0x109C0, 0x109CF, OtherNumber),
+    (0x109D2, 0x109FF, OtherNumber),
+    (0x10A00, 0x10A00, OtherLetter),
+    (0x10A01, 0x10A03, NonspacingMark),
+    (0x10A05, 0x10A06, NonspacingMark),
+    (0x10A0C, 0x10A0F, NonspacingMark),
+    (0x10A10, 0x10A13, OtherLetter),
+    (0x10A15, 0x10A17, OtherLetter),
+    (0x10A19, 0x10A35, OtherLetter),
+    (0x10A38, 0x10A3A, NonspacingMark),
+    (0x10A3F, 0x10A3F, NonspacingMark),
+    (0x10A40, 0x10A48, OtherNumber),
+    (0x10A50, 0x10A58, OtherPunctuation),
+    (0x10A60, 0x10A7C, OtherLetter),
+    (0x10A7D, 0x10A7E, OtherNumber),
+    (0x10A7F, 0x10A7F, OtherPunctuation),
+    (0x10A80, 0x10A9C, OtherLetter),
+    (0x10A9D, 0x10A9F, OtherNumber),
+    (0x10AC0, 0x10AC7, OtherLetter),
+    (0x10AC8, 0x10AC8, OtherSymbol),
+    (0x10AC9, 0x10AE4, OtherLetter),
+    (0x10AE5, 0x10AE6, NonspacingMark),
+    (0x10AEB, 0x10AEF, OtherNumber),
+    (0x10AF0, 0x10AF6, OtherPunctuation),
+    (0x10B00, 0x10B35, OtherLetter),
+    (0x10B39, 0x10B3F, OtherPunctuation),
+    (0x10B40, 0x10B55, OtherLetter),
+    (0x10B58, 0x10B5F, OtherNumber),
+    (0x10B60, 0x10B72, OtherLetter),
+    (0x10B78, 0x10B7F, OtherNumber),
+    (0x10B80, 0x10B91, OtherLetter),
+    (0x10B99, 0x10B9C, OtherPunctuation),
+    (0x10BA9, 0x10BAF, OtherNumber),
+    (0x10C00, 0x10C48, OtherLetter),
+    (0x10C80, 0x10CB2, UppercaseLetter),
+    (0x10CC0, 0x10CF2, LowercaseLetter),
+    (0x10CFA, 0x10CFF, OtherNumber),
+    (0x10D00, 0x10D23, OtherLetter),
+    (0x10D24, 0x10D27, NonspacingMark),
+    (0x10D30, 0x10D39, DecimalNumber),
+    (0x10E60, 0x10E7E, OtherNumber),
+    (0x10E80, 0x10EA9, OtherLetter),
+    (0x10EAB, 0x10EAC, NonspacingMark),
+    (0x10EAD, 0x10EAD, DashPunctuation),
+    (0x10EB0, 0x10EB1, OtherLetter),
+    (0x10F00, 0x10F1C, OtherLetter),
+    (0x10F1D, 0x10F26, OtherNumber),
+    (0x10F27, 0x10F27, OtherLetter),
+    (0x10F30, 0x10F45, OtherLetter),
+    (0x10F46, 0x10F50, NonspacingMark),
+    (0x10F51, 0x10F54, OtherNumber),
+    (0x10F55, 0x10F59, OtherPunctuation),
+    (0x10F70, 0x10F81, OtherLetter),
+    (0x10F82, 0x10F85, NonspacingMark),
+    (0x10F86, 0x10F89, OtherPunctuation),
+    (0x10FB0, 0x10FC4, OtherLetter),
+    (0x10FC5, 0x10FCB, OtherNumber),
+    (0x10FE0, 0x10FF6, OtherLetter),
+    (0x11000, 0x11000, SpacingMark),
+    (0x11001, 0x11001, NonspacingMark),
+    (0x11002, 0x11002, SpacingMark),
+    (0x11003, 0x11037, OtherLetter),
+    (0x11038, 0x11046, NonspacingMark),
+    (0x11047, 0x1104D, OtherPunctuation),
+    (0x11052, 0x11065, OtherNumber),
+    (0x11066, 0x1106F, DecimalNumber),
+    (0x11070, 0x11070, NonspacingMark),
+    (0x11071, 0x11072, OtherLetter),
+    (0x11073, 0x11074, NonspacingMark),
+    (0x11075, 0x11075, OtherLetter),
+    (0x1107F, 0x11081, NonspacingMark),
+    (0x11082, 0x11082, SpacingMark),
+    (0x11083, 0x110AF, OtherLetter),
+    (0x110B0, 0x110B2, SpacingMark),
+    (0x110B3, 0x110B6, NonspacingMark),
+    (0x110B7, 0x110B8, SpacingMark),
+    (0x110B9, 0x110BA, NonspacingMark),
+    (0x110BB, 0x110BC, OtherPunctuation),
+    (0x110BD, 0x110BD, Format),
+    (0x110BE, 0x110C1, OtherPunctuation),
+    (0x110C2, 0x110C2, NonspacingMark),
+    (0x110CD, 0x110CD, Format),
+    (0x110D0, 0x110E8, OtherLetter),
+    (0x110F0, 0x110F9, DecimalNumber),
+    (0x11100, 0x11102, NonspacingMark),
+    (0x11103, 0x11126, OtherLetter),
+    (0x11127, 0x1112B, NonspacingMark),
+    (0x1112C, 0x1112C, SpacingMark),
+    (0x1112D, 0x11134, NonspacingMark),
+    (0x11136, 0x1113F, DecimalNumber),
+    (0x11140, 0x11143, OtherPunctuation),
+    (0x11144, 0x11144, OtherLetter),
+    (0x11145, 0x11146, SpacingMark),
+    (0x11147, 0x11147, OtherLetter),
+    (0x11150, 0x11172, OtherLetter),
+    (0x11173, 0x11173, NonspacingMark),
+    (0x11174, 0x11175, OtherPunctuation),
+    (0x11176, 0x11176, OtherLetter),
+    (0x11180, 0x11181, NonspacingMark),
+    (0x11182, 0x11182, SpacingMark),
+    (0x11183, 0x111B2, OtherLetter),
+    (0x111B3, 0x111B5, SpacingMark),
+    (0x111B6, 0x111BE, NonspacingMark),
+    (0x111BF, 0x111C0, SpacingMark),
+    (0x111C1, 0x111C4, OtherLetter),
+    (0x111C5, 0x111C8, OtherPunctuation),
+    (0x111C9, 0x111CC, NonspacingMark),
+    (0x111CD, 0x111CD, OtherPunctuation),
+    (0x111CE, 0x111CE, SpacingMark),
+    (0x111CF, 0x111CF, NonspacingMark),
+    (0x111D0, 0x111D9, DecimalNumber),
+    (0x111DA, 0x111DA, OtherLetter),
+    (0x111DB, 0x111DB, OtherPunctuation),
+    (0x111DC, 0x111DC, OtherLetter),
+    (0x111DD, 0x111DF, OtherPunctuation),
+    (0x111E1, 0x111F4, OtherNumber),
+    (0x11200, 0x11211, OtherLetter),
+    (0x11213, 0x1122B, OtherLetter),
+    (0x1122C, 0x1122E, SpacingMark),
+    (0x1122F, 0x11231, NonspacingMark),
+    (0x11232, 0x11233, SpacingMark),
+    (0x11234, 0x11234, NonspacingMark),
+    (0x11235, 0x11235, SpacingMark),
+    (0x11236, 0x11237, NonspacingMark),
+    (0x11238, 0x1123D, OtherPunctuation),
+    (0x1123E, 0x1123E, NonspacingMark),
+    (0x11280, 0x11286, OtherLetter),
+    (0x11288, 0x11288, OtherLetter),
+    (0x1128A, 0x1128D, OtherLetter),
+    (0x1128F, 0x1129D, OtherLetter),
+    (0x1129F, 0x112A8, OtherLetter),
+    (0x112A9, 0x112A9, OtherPunctuation),
+    (0x112B0, 0x112DE, OtherLetter),
+    (0x112DF, 0x112DF, NonspacingMark),
+    (0x112E0, 0x112E2, SpacingMark),
+    (0x112E3, 0x112EA, NonspacingMark),
+    (0x112F0, 0x112F9, DecimalNumber),
+    (0x11300, 0x11301, NonspacingMark),
+    (0x11302, 0x11303, SpacingMark),
+    (0x11305, 0x1130C, OtherLetter),
+    (0x1130F, 0x11310, OtherLetter),
+    (0x11313, 0x11328, OtherLetter),
+    (0x1132A, 0x11330, OtherLetter),
+    (0x11332, 0x11333, OtherLetter),
+    (0x11335, 0x11339, OtherLetter),
+    (0x1133B, 0x1133C, NonspacingMark),
+    (0x1133D, 0x1133D, OtherLetter),
+    (0x1133E, 0x1133F, SpacingMark),
+    (0x11340, 0x11340, NonspacingMark),
+    (0x11341, 0x11344, SpacingMark),
+    (0x11347, 0x11348, SpacingMark),
+    (0x1134B, 0x1134D, SpacingMark),
+    (0x11350, 0x11350, OtherLetter),
+    (0x11357, 0x11357, SpacingMark),
+    (0x1135D, 0x11361, OtherLetter),
+    (0x11362, 0x11363, SpacingMark),
+    (0x11366, 0x1136C, NonspacingMark),
+    (0x11370, 0x11374, NonspacingMark),
+    (0x11400, 0x11434, OtherLetter),
+    (0x11435, 0x11437, SpacingMark),
+    (0x11438, 0x1143F, NonspacingMark),
+    (0x11440, 0x11441, SpacingMark),
+    (0x11442, 0x11444, NonspacingMark),
+    (0x11445, 0x11445, SpacingMark),
+    (0x11446, 0x11446, NonspacingMark),
+    (0x11447, 0x1144A, OtherLetter),
+    (0x1144B, 0x1144F, OtherPunctuation),
+    (0x11450, 0x11459, DecimalNumber),
+    (0x1145A, 0x1145B, OtherPunctuation),
+    (0x1145D, 0x1145D, OtherPunctuation),
+    (0x1145E, 0x1145E, NonspacingMark),
+    (0x1145F, 0x11461, OtherLetter),
+    (0x11480, 0x114AF, OtherLetter),
+    (0x114B0, 0x114B2, SpacingMark),
+    (0x114B3, 0x114B8, NonspacingMark),
+    (0x114B9, 0x114B9, SpacingMark),
+    (0x114BA, 0x114BA, NonspacingMark),
+    (0x114BB, 0x114BE, SpacingMark),
+    (0x114BF, 0x114C0, NonspacingMark),
+    (0x114C1, 0x114C1, SpacingMark),
+    (0x114C2, 0x114C3, NonspacingMark),
+    (0x114C4, 0x114C5, OtherLetter),
+    (0x114C6, 0x114C6, OtherPunctuation),
+    (0x114C7, 0x114C7, OtherLetter),
+    (0x114D0, 0x114D9, DecimalNumber),
+    (0x11580, 0x115AE, OtherLetter),
+    (0x115AF, 0x115B1, SpacingMark),
+    (0x115B2, 0x115B5, NonspacingMark),
+    (0x115B8, 0x115BB, SpacingMark),
+    (0x115BC, 0x115BD, NonspacingMark),
+    (0x115BE, 0x115BE, SpacingMark),
+    (0x115BF, 0x115C0, NonspacingMark),
+    (0x115C1, 0x115D7, OtherPunctuation),
+    (0x115D8, 0x115DB, OtherLetter),
+    (0x115DC, 0x115DD, NonspacingMark),
+    (0x11600, 0x1162F, OtherLetter),
+    (0x11630, 0x11632, SpacingMark),
+    (0x11633, 0x1163A, NonspacingMark),
+    (0x1163B, 0x1163C, SpacingMark),
+    (0x1163D, 0x1163D, NonspacingMark),
+    (0x1163E, 0x1163E, SpacingMark),
+    (0x1163F, 0x11640, NonspacingMark),
+    (0x11641, 0x11643, OtherPunctuation),
+    (0x11644, 0x11644, OtherLetter),
+    (0x11650, 0x11659, DecimalNumber),
+    (0x11660, 0x1166C, OtherPunctuation),
+    (0x11680, 0x116AA, OtherLetter),
+    (0x116AB, 0x116AB, NonspacingMark),
+    (0x116AC, 0x116AC, SpacingMark),
+    (0x116AD, 0x116AD, NonspacingMark),
+    (0x116AE, 0x116AF, SpacingMark),
+    (0x116B0, 0x116B5, NonspacingMark),
+    (0x116B6, 0x116B6, SpacingMark),
+    (0x116B7, 0x116B7, NonspacingMark),
+    (0x116B8, 0x116B8, OtherLetter),
+    (0x116B9, 0x116B9, OtherPunctuation),
+    (0x116C0, 0x116C9, DecimalNumber),
+    (0x11700, 0x1171A, OtherLetter),
+    (0x1171D, 0x1171F, NonspacingMark),
+    (0x11720, 0x11721, SpacingMark),
+    (0x11722, 0x11725, NonspacingMark),
+    (0x11726, 0x11726, SpacingMark),
+    (0x11727, 0x1172B, NonspacingMark),
+    (0x11730, 0x11739, DecimalNumber),
+    (0x1173A, 0x1173B, OtherNumber),
+    (0x1173C, 0x1173E, OtherPunctuation),
+    (0x1173F, 0x1173F, OtherSymbol),
+    (0x11740, 0x11746, OtherLetter),
+    (0x11800, 0x1182B, OtherLetter),
+    (0x1182C, 0x1182E, SpacingMark),
+    (0x1182F, 0x11837, NonspacingMark),
+    (0x11838, 0x11838, SpacingMark),
+    (0x11839, 0x1183A, NonspacingMark),
+    (0x1183B, 0x1183B, OtherPunctuation),
+    (0x118A0, 0x118BF, UppercaseLetter),
+    (0x118C0, 0x118DF, LowercaseLetter),
+    (0x118E0, 0x118E9, DecimalNumber),
+    (0x118EA, 0x118F2, OtherNumber),
+    (0x118FF, 0x11906, OtherLetter),
+    (0x11909, 0x11909, OtherLetter),
+    (0x1190C, 0x11913, OtherLetter),
+    (0x11915, 0x11916, OtherLetter),
+    (0x11918, 0x1192F, OtherLetter),
+    (0x11930, 0x11935, SpacingMark),
+    (0x11937, 0x11938, SpacingMark),
+    (0x1193B, 0x1193C, NonspacingMark),
+    (0x1193D, 0x1193D, SpacingMark),
+    (0x1193E, 0x1193E, NonspacingMark),
+    (0x1193F, 0x1193F, OtherLetter),
+    (0x11940, 0x11940, SpacingMark),
+    (0x11941, 0x11941, OtherLetter),
+    (0x11942, 0x11942, SpacingMark),
+    (0x11943, 0x11943, NonspacingMark),
+    (0x11944, 0x11946, OtherPunctuation),
+    (0x11950, 0x11959, DecimalNumber),
+    (0x119A0, 0x119A7, OtherLetter),
+    (0x119AA, 0x119D0, OtherLetter),
+    (0x119D1, 0x119D3, SpacingMark),
+    (0x119D4, 0x119D7, NonspacingMark),
+    (0x119DA, 0x119DB, NonspacingMark),
+    (0x119DC, 0x119DF, SpacingMark),
+    (0x119E0, 0x119E0, NonspacingMark),
+    (0x119E1, 0x119E1, OtherLetter),
+    (0x119E2, 0x119E2, OtherPunctuation),
+    (0x119E3, 0x119E3, OtherLetter),
+    (0x119E4, 0x119E4, SpacingMark),
+    (0x11A00, 0x11A00, OtherLetter),
+    (0x11A01, 0x11A0A, NonspacingMark),
+    (0x11A0B, 0x11A32, OtherLetter),
+    (0x11A33, 0x11A38, NonspacingMark),
+    (0x11A39, 0x11A39, SpacingMark),
+    (0x11A3A, 0x11A3A, OtherLetter),
+    (0x11A3B, 0x11A3E, NonspacingMark),
+    (0x11A3F, 0x11A46, OtherPunctuation),
+    (0x11A47, 0x11A47, NonspacingMark),
+    (0x11A50, 0x11A50, OtherLetter),
+    (0x11A51, 0x11A56, NonspacingMark),
+    (0x11A57, 0x11A58, SpacingMark),
+    (0x11A59, 0x11A5B, NonspacingMark),
+    (0x11A5C, 0x11A89, OtherLetter),
+    (0x11A8A, 0x11A96, NonspacingMark),
+    (0x11A97, 0x11A97, SpacingMark),
+    (0x11A98, 0x11A99, NonspacingMark),
+    (0x11A9A, 0x11A9C, OtherPunctuation),
+    (0x11A9D, 0x11A9D, OtherLetter),
+    (0x11A9E, 0x11AA2, OtherPunctuation),
+    (0x11AB0, 0x11AF8, OtherLetter),
+    (0x11C00, 0x11C08, OtherLetter),
+    (0x11C0A, 0x11C2E, OtherLetter),
+    (0x11C2F, 0x11C2F, SpacingMark),
+    (0x11C30, 0x11C36, NonspacingMark),
+    (0x11C38, 0x11C3D, NonspacingMark),
+    (0x11C3E, 0x11C3E, SpacingMark),
+    (0x11C3F, 0x11C3F, NonspacingMark),
+    (0x11C40, 0x11C40, OtherLetter),
+    (0x11C41, 0x11C45, OtherPunctuation),
+    (0x11C50, 0x11C59, DecimalNumber),
+    (0x11C5A, 0x11C6C, OtherNumber),
+    (0x11C70, 0x11C71, OtherPunctuation),
+    (0x11C72, 0x11C8F, OtherLetter),
+    (0x11C92, 0x11CA7, NonspacingMark),
+    (0x11CA9, 0x11CA9, SpacingMark),
+    (0x11CAA, 0x11CB0, NonspacingMark),
+    (0x11CB1, 0x11CB1, SpacingMark),
+    (0x11CB2, 0x11CB3, NonspacingMark),
+    (0x11CB4, 0x11CB4, SpacingMark),
+    (0x11CB5, 0x11CB6, NonspacingMark),
+    (0x11D00, 0x11D06, OtherLetter),
+    (0x11D08, 0x11D09, OtherLetter),
+    (0x11D0B, 0x11D30, OtherLetter),
+    (0x11D31, 0x11D36, NonspacingMark),
+    (0x11D3A, 0x11D3A, NonspacingMark),
+    (0x11D3C, 0x11D3D, NonspacingMark),
+    (0x11D3F, 0x11D45, NonspacingMark),
+    (0x11D46, 0x11D46, OtherLetter),
+    (0x11D47, 0x11D47, NonspacingMark),
+    (0x11D50, 0x11D59, DecimalNumber),
+    (0x11D60, 0x11D65, OtherLetter),
+    (0x11D67, 0x11D68, OtherLetter),
+    (0x11D6A, 0x11D89, OtherLetter),
+    (0x11D8A, 0x11D8E, SpacingMark),
+    (0x11D90, 0x11D91, NonspacingMark),
+    (0x11D93, 0x11D94, SpacingMark),
+    (0x11D95, 0x11D95, NonspacingMark),
+    (0x11D96, 0x11D96, SpacingMark),
+    (0x11D97, 0x11D97, NonspacingMark),
+    (0x11D98, 0x11D98, OtherLetter),
+    (0x11DA0, 0x11DA9, DecimalNumber),
+    (0x11EE0, 0x11EF2, OtherLetter),
+    (0x11EF3, 0x11EF4, NonspacingMark),
+    (0x11EF5, 0x11EF6, SpacingMark),
+    (0x11EF7, 0x11EF8, OtherPunctuation),
+    (0x11FB0, 0x11FB0, OtherLetter),
+    (0x11FC0, 0x11FD4, OtherNumber),
+    (0x11FD5, 0x11FDC, OtherSymbol),
+    (0x11FDD, 0x11FE0, CurrencySymbol),
+    (0x11FE1, 0x11FF1, OtherSymbol),
+    (0x11FFF, 0x11FFF, OtherPunctuation),
+    (0x12000, 0x12399, OtherLetter),
+    (0x12400, 0x1246E, LetterNumber),
+    (0x12470, 0x12474, OtherPunctuation),
+    (0x12480, 0x12543, OtherLetter),
+    (0x12F90, 0x12FF0, OtherLetter),
+    (0x12FF1, 0x12FF2, OtherPunctuation),
+    (0x13000, 0x1342E, OtherLetter),
+    (0x13430, 0x13438, Format),
+    (0x14400, 0x14646, OtherLetter),
+    (0x16800, 0x16A38, OtherLetter),
+    (0x16A40, 0x16A5E, OtherLetter),
+    (0x16A60, 0x16A69, DecimalNumber),
+    (0x16A6E, 0x16A6F, OtherPunctuation),
+    (0x16A70, 0x16ABE, OtherLetter),
+    (0x16AC0, 0x16AC9, DecimalNumber),
+    (0x16AD0, 0x16AED, OtherLetter),
+    (0x16AF0, 0x16AF4, NonspacingMark),
+    (0x16AF5, 0x16AF5, OtherPunctuation),
+    (0x16B00, 0x16B2F, OtherLetter),
+    (0x16B30, 0x16B36, NonspacingMark),
+    (0x16B37, 0x16B3B, OtherPunctuation),
+    (0x16B3C, 0x16B3F, OtherSymbol),
+    (0x16B40, 0x16B43, ModifierLetter),
+    (0x16B44, 0x16B44, OtherPunctuation),
+    (0x16B45, 0x16B45, OtherSymbol),
+    (0x16B50, 0x16B59, DecimalNumber),
+    (0x16B5B, 0x16B61, OtherNumber),
+    (0x16B63, 0x16B77, OtherLetter),
+    (0x16B7D, 0x16B8F, OtherLetter),
+    (0x16E40, 0x16E5F, UppercaseLetter),
+    (0x16E60, 0x16E7F, LowercaseLetter),
+    (0x16E80, 0x16E96, OtherNumber),
+    (0x16E97, 0x16E9A, OtherPunctuation),
+    (0x16F00, 0x16F4A, OtherLetter),
+    (0x16F4F, 0x16F4F, NonspacingMark),
+    (0x16F50, 0x16F50, OtherLetter),
+    (0x16F51, 0x16F87, SpacingMark),
+    (0x16F8F, 0x16F92, NonspacingMark),
+    (0x16F93, 0x16F9F, ModifierLetter),
+    (0x16FE0, 0x16FE1, ModifierLetter),
+    (0x16FE2, 0x16FE2, OtherPunctuation),
+    (0x16FE3, 0x16FE3, ModifierLetter),
+    (0x16FE4, 0x16FE4, NonspacingMark),
+    (0x16FF0, 0x16FF1, SpacingMark),
+    (0x17000, 0x187F7, OtherLetter),
+    (0x18800, 0x18CD5, OtherLetter),
+    (0x18D00, 0x18D08, OtherLetter),
+    (0x1AFF0, 0x1AFF3, ModifierLetter),
+    (0x1AFF5, 0x1AFFB, ModifierLetter),
+    (0x1AFFD, 0x1AFFE, ModifierLetter),
+    (0x1B000, 0x1B122, OtherLetter),
+    (0x1B150, 0x1B152, OtherLetter),
+    (0x1B164, 0x1B167, OtherLetter),
+    (0x1B170, 0x1B2FB, OtherLetter),
+    (0x1BC00, 0x1BC6A, OtherLetter),
+    (0x1BC70, 0x1BC7C, OtherLetter),
+    (0x1BC80, 0x1BC88, OtherLetter),
+    (0x1BC90, 0x1BC99, OtherLetter),
+    (0x1BC9C, 0x1BC9C, OtherSymbol),
+    (0x1BC9D, 0x1BC9E, NonspacingMark),
+    (0x1BC9F, 0x1BC9F, OtherPunctuation),
+    (0x1BCA0, 0x1BCA3, Format),
+    (0x1CF00, 0x1CF2D, NonspacingMark),
+    (0x1CF30, 0x1CF46, NonspacingMark),
+    (0x1CF50, 0x1CFC3, OtherSymbol),
+    (0x1D000, 0x1D0F5, OtherSymbol),
+    (0x1D100, 0x1D126, OtherSymbol),
+    (0x1D129, 0x1D164, OtherSymbol),
+    (0x1D165, 0x1D166, SpacingMark),
+    (0x1D167, 0x1D169, NonspacingMark),
+    (0x1D16A, 0x1D16C, OtherSymbol),
+    (0x1D16D, 0x1D172, SpacingMark),
+    (0x1D173, 0x1D17A, Format),
+    (0x1D17B, 0x1D182, NonspacingMark),
+    (0x1D183, 0x1D184, OtherSymbol),
+    (0x1D185, 0x1D18B, NonspacingMark),
+    (0x1D18C, 0x1D1A9, OtherSymbol),
+    (0x1D1AA, 0x1D1AD, NonspacingMark),
+    (0x1D1AE, 0x1D1EA, OtherSymbol),
+    (0x1D200, 0x1D241, OtherSymbol),
+    (0x1D242, 0x1D244, NonspacingMark),
+    (0x1D245, 0x1D245, OtherSymbol),
+    (0x1D2E0, 0x1D2F3, OtherNumber),
+    (0x1D300, 0x1D356, OtherSymbol),
+    (0x1D360, 0x1D378, OtherNumber),
+    (0x1D400, 0x1D419, UppercaseLetter),
+    (0x1D41A, 0x1D433, LowercaseLetter),
+    (0x1D434, 0x1D44D, UppercaseLetter),
+    (0x1D44E, 0x1D454, LowercaseLetter),
+    (0x1D456, 0x1D467, LowercaseLetter),
+    (0x1D468, 0x1D481, UppercaseLetter),
+    (0x1D482, 0x1D49B, LowercaseLetter),
+    (0x1D49C, 0x1D49C, UppercaseLetter),
+    (0x1D49E, 0x1D49F, UppercaseLetter),
+    (0x1D4A2, 0x1D4A2, UppercaseLetter),
+    (0x1D4A5, 0x1D4A6, UppercaseLetter),
+    (0x1D4A9, 0x1D4AC, UppercaseLetter),
+    (0x1D4AE, 0x1D4B5, UppercaseLetter),
+    (0x1D4B6, 0x1D4B9, LowercaseLetter),
+    (0x1D4BB, 0x1D4BB, LowercaseLetter),
+    (0x1D4BD, 0x1D4C3, LowercaseLetter),
+    (0x1D4C5, 0x1D4CF, LowercaseLetter),
+    (0x1D4D0, 0x1D4E9, UppercaseLetter),
+    (0x1D4EA, 0x1D503, LowercaseLetter),
+    (0x1D504, 0x1D505, UppercaseLetter),
+    (0x1D507, 0x1D50A, UppercaseLetter),
+    (0x1D50D, 0x1D514, UppercaseLetter),
+    (0x1D516, 0x1D51C, UppercaseLetter),
+    (0x1D51E, 0x1D537, LowercaseLetter),
+    (0x1D538, 0x1D539, UppercaseLetter),
+    (0x1D53B, 0x1D53E, UppercaseLetter),
+    (0x1D540, 0x1D544, UppercaseLetter),
+    (0x1D546, 0x1D546, UppercaseLetter),
+    (0x1D54A, 0x1D550, UppercaseLetter),
+    (0x1D552, 0x1D56B, LowercaseLetter),
+    (0x1D56C, 0x1D585, UppercaseLetter),
+    (0x1D586, 0x1D59F, LowercaseLetter),
+    (0x1D5A0, 0x1D5B9, UppercaseLetter),
+    (0x1D5BA, 0x1D5D3, LowercaseLetter),
+    (0x1D5D4, 0x1D5ED, UppercaseLetter),
+    (0x1D5EE, 0x1D607, LowercaseLetter),
+    (0x1D608, 0x1D621, UppercaseLetter),
+    (0x1D622, 0x1D63B, LowercaseLetter),
+    (0x1D63C, 0x1D655, UppercaseLetter),
+    (0x1D656, 0x1D66F, LowercaseLetter),
+    (0x1D670, 0x1D689, UppercaseLetter),
+    (0x1D68A, 0x1D6A5, LowercaseLetter),
+    (0x1D6A8, 0x1D6C0, UppercaseLetter),
+    (0x1D6C1, 0x1D6C1, MathSymbol),
+    (0x1D6C2, 0x1D6DA, LowercaseLetter),
+    (0x1D6DB, 0x1D6DB, MathSymbol),
+    (0x1D6DC, 0x1D6E1, LowercaseLetter),
+    (0x1D6E2, 0x1D6FA, UppercaseLetter),
+    (0x1D6FB, 0x1D6FB, MathSymbol),
+    (0x1D6FC, 0x1D714, LowercaseLetter),
+    (0x1D715, 0x1D715, MathSymbol),
+    (0x1D716, 0x1D71B, LowercaseLetter),
+    (0x1D71C, 0x1D734, UppercaseLetter),
+    (0x1D735, 0x1D735, MathSymbol),
+    (0x1D736, 0x1D74E, LowercaseLetter),
+    (0x1D74F, 0x1D74F, MathSymbol),
+    (0x1D750, 0x1D755, LowercaseLetter),
+    (0x1D756, 0x1D76E, UppercaseLetter),
+    (0x1D76F, 0x1D76F, MathSymbol),
+    (0x1D770, 0x1D788, LowercaseLetter),
+    (0x1D789, 0x1D789, MathSymbol),
+    (0x1D78A, 0x1D78F, LowercaseLetter),
+    (0x1D790, 0x1D7A8, UppercaseLetter),
+    (0x1D7A9, 0x1D7A9, MathSymbol),
+    (0x1D7AA, 0x1D7C2, LowercaseLetter),
+    (0x1D7C3, 0x1D7C3, MathSymbol),
+    (0x1D7C4, 0x1D7C9, LowercaseLetter),
+    (0x1D7CA, 0x1D7CA, UppercaseLetter),
+    (0x1D7CB, 0x1D7CB, LowercaseLetter),
+    (0x1D7CE, 0x1D7FF, DecimalNumber),
+    (0x1D800, 0x1D9FF, OtherSymbol),
+    (0x1DA00, 0x1DA36, NonspacingMark),
+    (0x1DA37, 0x1DA3A, OtherSymbol),
+    (0x1DA3B, 0x1DA6C, NonspacingMark),
+    (0x1DA6D, 0x1DA74, OtherSymbol),
+    (0x1DA75, 0x1DA75, NonspacingMark),
+    (0x1DA76, 0x1DA83, OtherSymbol),
+    (0x1DA84, 0x1DA84, NonspacingMark),
+    (0x1DA85, 0x1DA86, OtherSymbol),
+    (0x1DA87, 0x1DA8B, OtherPunctuation),
+    (0x1DA9B, 0x1DA9F, NonspacingMark),
+    (0x1DAA1, 0x1DAAF, NonspacingMark),
+    (0x1DF00, 0x1DF09, LowercaseLetter),
+    (0x1DF0A, 0x1DF0A, OtherLetter),
+    (0x1DF0B, 0x1DF1E, LowercaseLetter),
+    (0x1E000, 0x1E006, NonspacingMark),
+    (0x1E008, 0x1E018, NonspacingMark),
+    (0x1E01B, 0x1E021, NonspacingMark),
+    (0x1E023, 0x1E024, NonspacingMark),
+    (0x1E026, 0x1E02A, NonspacingMark),
+    (0x1E100, 0x1E12C, OtherLetter),
+    (0x1E130, 0x1E136, NonspacingMark),
+    (0x1E137, 0x1E13D, ModifierLetter),
+    (0x1E140, 0x1E149, DecimalNumber),
+    (0x1E14E, 0x1E14E, OtherLetter),
+    (0x1E14F, 0x1E14F, OtherSymbol),
+    (0x1E290, 0x1E2AD, OtherLetter),
+    (0x1E2AE, 0x1E2AE, NonspacingMark),
+    (0x1E2C0, 0x1E2EB, OtherLetter),
+    (0x1E2EC, 0x1E2EF, NonspacingMark),
+    (0x1E2F0, 0x1E2F9, DecimalNumber),
+    (0x1E2FF, 0x1E2FF, CurrencySymbol),
+    (0x1E7E0, 0x1E7E6, OtherLetter),
+    (0x1E7E8, 0x1E7EB, OtherLetter),
+    (0x1E7ED, 0x1E7EE, OtherLetter),
+    (0x1E7F0, 0x1E7FE, OtherLetter),
+    (0x1E800, 0x1E8C4, OtherLetter),
+    (0x1E8C7, 0x1E8CF, OtherNumber),
+    (0x1E8D0, 0x1E8D6, NonspacingMark),
+    (0x1E900, 0x1E921, UppercaseLetter),
+    (0x1E922, 0x1E943, LowercaseLetter),
+    (0x1E944, 0x1E94A, NonspacingMark),
+    (0x1E94B, 0x1E94B, ModifierLetter),
+    (0x1E950, 0x1E959, DecimalNumber),
+    (0x1E95E, 0x1E95F, OtherPunctuation),
+    (0x1EC71, 0x1ECAB, OtherNumber),
+    (0x1ECAC, 0x1ECAC, OtherSymbol),
+    (0x1ECAD, 0x1ECAF, OtherNumber),
+    (0x1ECB0, 0x1ECB0, CurrencySymbol),
+    (0x1ECB1, 0x1ECB4, OtherNumber),
+    (0x1ED01, 0x1ED2D, OtherNumber),
+    (0x1ED2E, 0x1ED2E, OtherSymbol),
+    (0x1ED2F, 0x1ED3D, OtherNumber),
+    (0x1EE00, 0x1EE03, OtherLetter),
+    (0x1EE05, 0x1EE1F, OtherLetter),
+    (0x1EE21, 0x1EE22, OtherLetter),
+    (0x1EE24, 0x1EE24, OtherLetter),
+    (0x1EE27, 0x1EE27, OtherLetter),
+    (0x1EE29, 0x1EE32, OtherLetter),
+    (0x1EE34, 0x1EE37, OtherLetter),
+    (0x1EE39, 0x1EE39, OtherLetter),
+    (0x1EE3B, 0x1EE3B, OtherLetter),
+    (0x1EE42, 0x1EE42, OtherLetter),
+    (0x1EE47, 0x1EE47, OtherLetter),
+    (0x1EE49, 0x1EE49, OtherLetter),
+    (0x1EE4B, 0x1EE4B, OtherLetter),
+    (0x1EE4D, 0x1EE4F, OtherLetter),
+    (0x1EE51, 0x1EE52, OtherLetter),
+    (0x1EE54, 0x1EE54, OtherLetter),
+    (0x1EE57, 0x1EE57, OtherLetter),
+    (0x1EE59, 0x1EE59, OtherLetter),
+    (0x1EE5B, 0x1EE5B, OtherLetter),
+    (0x1EE5D, 0x1EE5D, OtherLetter),
+    (0x1EE5F, 0x1EE5F, OtherLetter),
+    (0x1EE61, 0x1EE62, OtherLetter),
+    (0x1EE64, 0x1EE64, OtherLetter),
+    (0x1EE67, 0x1EE6A, OtherLetter),
+    (0x1EE6C, 0x1EE72, OtherLetter),
+    (0x1EE74, 0x1EE77, OtherLetter),
+    (0x1EE79, 0x1EE7C, OtherLetter),
+    (0x1EE7E, 0x1EE7E, OtherLetter),
+    (0x1EE80, 0x1EE89, OtherLetter),
+    (0x1EE8B, 0x1EE9B, OtherLetter),
+    (0x1EEA1, 0x1EEA3, OtherLetter),
+    (0x1EEA5, 0x1EEA9, OtherLetter),
+    (0x1EEAB, 0x1EEBB, OtherLetter),
+    (0x1EEF0, 0x1EEF1, MathSymbol),
+    (0x1F000, 0x1F02B, OtherSymbol),
+    (0x1F030, 0x1F093, OtherSymbol),
+    (0x1F0A0, 0x1F0AE, OtherSymbol),
+    (0x1F0B1, 0x1F0BF, OtherSymbol),
+    (0x1F0C1, 0x1F0CF, OtherSymbol),
+    (0x1F0D1, 0x1F0F5, OtherSymbol),
+    (0x1F100, 0x1F10C, OtherNumber),
+    (0x1F10D, 0x1F1AD, OtherSymbol),
+    (0x1F1E6, 0x1F202, OtherSymbol),
+    (0x1F210, 0x1F23B, OtherSymbol),
+    (0x1F240, 0x1F248, OtherSymbol),
+    (0x1F250, 0x1F251, OtherSymbol),
+    (0x1F260, 0x1F265, OtherSymbol),
+    (0x1F300, 0x1F3FA, OtherSymbol),
+    (0x1F3FB, 0x1F3FF, ModifierSymbol),
+    (0x1F400, 0x1F6D7, OtherSymbol),
+    (0x1F6DD, 0x1F6EC, OtherSymbol),
+    (0x1F6F0, 0x1F6FC, OtherSymbol),
+    (0x1F700, 0x1F773, OtherSymbol),
+    (0x1F780, 0x1F7D8, OtherSymbol),
+    (0x1F7E0, 0x1F7EB, OtherSymbol),
+    (0x1F7F0, 0x1F7F0, OtherSymbol),
+    (0x1F800, 0x1F80B, OtherSymbol),
+    (0x1F810, 0x1F847, OtherSymbol),
+    (0x1F850, 0x1F859, OtherSymbol),
+    (0x1F860, 0x1F887, OtherSymbol),
+    (0x1F890, 0x1F8AD, OtherSymbol),
+    (0x1F8B0, 0x1F8B1, OtherSymbol),
+    (0x1F900, 0x1FA53, OtherSymbol),
+    (0x1FA60, 0x1FA6D, OtherSymbol),
+    (0x1FA70, 0x1FA74, OtherSymbol),
+    (0x1FA78, 0x1FA7C, OtherSymbol),
+    (0x1FA80, 0x1FA86, OtherSymbol),
+    (0x1FA90, 0x1FAAC, OtherSymbol),
+    (0x1FAB0, 0x1FABA, OtherSymbol),
+    (0x1FAC0, 0x1FAC5, OtherSymbol),
+    (0x1FAD0, 0x1FAD9, OtherSymbol),
+    (0x1FAE0, 0x1FAE7, OtherSymbol),
+    (0x1FAF0, 0x1FAF6, OtherSymbol),
+    (0x1FB00, 0x1FB92, OtherSymbol),
+    (0x1FB94, 0x1FBCA, OtherSymbol),
+    (0x1FBF0, 0x1FBF9, DecimalNumber),
+    (0x20000, 0x2A6DF, OtherLetter),
+    (0x2A700, 0x2B738, OtherLetter),
+    (0x2B740, 0x2B81D, OtherLetter),
+    (0x2B820, 0x2CEA1, OtherLetter),
+    (0x2CEB0, 0x2EBE0, OtherLetter),
+    (0x2F800, 0x2FA1D, OtherLetter),
+    (0x30000, 0x3134A, OtherLetter),
+    (0xE0001, 0xE0001, Format),
+    (0xE0020, 0xE007F, Format),
+    (0xE0100, 0xE01EF, NonspacingMark),
+    (0xF0000, 0xFFFFD, PrivateUse),
+    (0x100000, 0x10FFFD, PrivateUse),
+];
+
+pub const SCRIPTS: &[(u32, u32, &str)] = &[
+    (0x0, 0x40, "Common"),
+    (0x41, 0x5A, "Latin"),
+    (0x5B, 0x60, "Common"),
+    (0x61, 0x7A, "Latin"),
+    (0x7B, 0xA9, "Common"),
+    (0xAA, 0xAA, "Latin"),
+    (0xAB, 0xB9, "Common"),
+    (0xBA, 0xBA, "Latin"),
+    (0xBB, 0xBF, "Common"),
+    (0xC0, 0xD6, "Latin"),
+    (0xD7, 0xD7, "Common"),
+    (0xD8, 0xF6, "Latin"),
+    (0xF7, 0xF7, "Common"),
+    (0xF8, 0x2B8, "Latin"),
+    (0x2B9, 0x2DF, "Common"),
+    (0x2E0, 0x2E4, "Latin"),
+    (0x2E5, 0x2E9, "Common"),
+    (0x2EA, 0x2EB, "Bopomofo"),
+    (0x2EC, 0x2FF, "Common"),
+    (0x300, 0x36F, "Inherited"),
+    (0x370, 0x373, "Greek"),
+    (0x374, 0x374, "Common"),
+    (0x375, 0x377, "Greek"),
+    (0x37A, 0x37D, "Greek"),
+    (0x37E, 0x37E, "Common"),
+    (0x37F, 0x37F, "Greek"),
+    (0x384, 0x384, "Greek"),
+    (0x385, 0x385, "Common"),
+    (0x386, 0x386, "Greek"),
+    (0x387, 0x387, "Common"),
+    (0x388, 0x38A, "Greek"),
+    (0x38C, 0x38C, "Greek"),
+    (0x38E, 0x3A1, "Greek"),
+    (0x3A3, 0x3E1, "Greek"),
+    (0x3E2, 0x3EF, "Coptic"),
+    (0x3F0, 0x3FF, "Greek"),
+    (0x400, 0x484, "Cyrillic"),
+    (0x485, 0x486, "Inherited"),
+    (0x487, 0x52F, "Cyrillic"),
+    (0x531, 0x556, "Armenian"),
+    (0x559, 0x58A, "Armenian"),
+    (0x58D, 0x58F, "Armenian"),
+    (0x591, 0x5C7, "Hebrew"),
+    (0x5D0, 0x5EA, "Hebrew"),
+    (0x5EF, 0x5F4, "Hebrew"),
+    (0x600, 0x604, "Arabic"),
+    (0x605, 0x605, "Common"),
+    (0x606, 0x60B, "Arabic"),
+    (0x60C, 0x60C, "Common"),
+    (0x60D, 0x61A, "Arabic"),
+    (0x61B, 0x61B, "Common"),
+    (0x61C, 0x61E, "Arabic"),
+    (0x61F, 0x61F, "Common"),
+    (0x620, 0x63F, "Arabic"),
+    (0x640, 0x640, "Common"),
+    (0x641, 0x64A, "Arabic"),
+    (0x64B, 0x655, "Inherited"),
+    (0x656, 0x66F, "Arabic"),
+    (0x670, 0x670, "Inherited"),
+    (0x671, 0x6DC, "Arabic"),
+    (0x6DD, 0x6DD, "Common"),
+    (0x6DE, 0x6FF, "Arabic"),
+    (0x700, 0x70D, "Syriac"),
+    (0x70F, 0x74A, "Syriac"),
+    (0x74D, 0x74F, "Syriac"),
+    (0x750, 0x77F, "Arabic"),
+    (0x780, 0x7B1, "Thaana"),
+    (0x7C0, 0x7FA, "Nko"),
+    (0x7FD, 0x7FF, "Nko"),
+    (0x800, 0x82D, "Samaritan"),
+    (0x830, 0x83E, "Samaritan"),
+    (0x840, 0x85B, "Mandaic"),
+    (0x85E, 0x85E, "Mandaic"),
+    (0x860, 0x86A, "Syriac"),
+    (0x870, 0x88E, "Arabic"),
+    (0x890, 0x891, "Arabic"),
+    (0x898, 0x8E1, "Arabic"),
+    (0x8E2, 0x8E2, "Common"),
+    (0x8E3, 0x8FF, "Arabic"),
+    (0x900, 0x950, "Devanagari"),
+    (0x951, 0x954, "Inherited"),
+    (0x955, 0x963, "Devanagari"),
+    (0x964, 0x965, "Common"),
+    (0x966, 0x97F, "Devanagari"),
+    (0x980, 0x983, "Bengali"),
+    (0x985, 0x98C, "Bengali"),
+    (0x98F, 0x990, "Bengali"),
+    (0x993, 0x9A8, "Bengali"),
+    (0x9AA, 0x9B0, "Bengali"),
+    (0x9B2, 0x9B2, "Bengali"),
+    (0x9B6, 0x9B9, "Bengali"),
+    (0x9BC, 0x9C4, "Bengali"),
+    (0x9C7, 0x9C8, "Bengali"),
+    (0x9CB, 0x9CE, "Bengali"),
+    (0x9D7, 0x9D7, "Bengali"),
+    (0x9DC, 0x9DD, "Bengali"),
+    (0x9DF, 0x9E3, "Bengali"),
+    (0x9E6, 0x9FE, "Bengali"),
+    (0xA01, 0xA03, "Gurmukhi"),
+    (0xA05, 0xA0A, "Gurmukhi"),
+    (0xA0F, 0xA10, "Gurmukhi"),
+    (0xA13, 0xA28, "Gurmukhi"),
+    (0xA2A, 0xA30, "Gurmukhi"),
+    (0xA32, 0xA33, "Gurmukhi"),
+    (0xA35, 0xA36, "Gurmukhi"),
+    (0xA38, 0xA39, "Gurmukhi"),
+    (0xA3C, 0xA3C, "Gurmukhi"),
+    (0xA3E, 0xA42, "Gurmukhi"),
+    (0xA47, 0xA48, "Gurmukhi"),
+    (0xA4B, 0xA4D, "Gurmukhi"),
+    (0xA51, 0xA51, "Gurmukhi"),
+    (0xA59, 0xA5C, "Gurmukhi"),
+    (0xA5E, 0xA5E, "Gurmukhi"),
+    (0xA66, 0xA76, "Gurmukhi"),
+    (0xA81, 0xA83, "Gujarati"),
+    (0xA85, 0xA8D, "Gujarati"),
+    (0xA8F, 0xA91, "Gujarati"),
+    (0xA93, 0xAA8, "Gujarati"),
+    (0xAAA, 0xAB0, "Gujarati"),
+    (0xAB2, 0xAB3, "Gujarati"),
+    (0xAB5, 0xAB9, "Gujarati"),
+    (0xABC, 0xAC5, "Gujarati"),
+    (0xAC7, 0xAC9, "Gujarati"),
+    (0xACB, 0xACD, "Gujarati"),
+    (0xAD0, 0xAD0, "Gujarati"),
+    (0xAE0, 0xAE3, "Gujarati"),
+    (0xAE6, 0xAF1, "Gujarati"),
+    (0xAF9, 0xAFF, "Gujarati"),
+    (0xB01, 0xB03, "Oriya"),
+    (0xB05, 0xB0C, "Oriya"),
+    (0xB0F, 0xB10, "Oriya"),
+    (0xB13, 0xB28, "Oriya"),
+    (0xB2A, 0xB30, "Oriya"),
+    (0xB32, 0xB33, "Oriya"),
+    (0xB35, 0xB39, "Oriya"),
+    (0xB3C, 0xB44, "Oriya"),
+    (0xB47, 0xB48, "Oriya"),
+    (0xB4B, 0xB4D, "Oriya"),
+    (0xB55, 0xB57, "Oriya"),
+    (0xB5C, 0xB5D, "Oriya"),
+    (0xB5F, 0xB63, "Oriya"),
+    (0xB66, 0xB77, "Oriya"),
+    (0xB82, 0xB83, "Tamil"),
+    (0xB85, 0xB8A, "Tamil"),
+    (0xB8E, 0xB90, "Tamil"),
+    (0xB92, 0xB95, "Tamil"),
+    (0xB99, 0xB9A, "Tamil"),
+    (0xB9C, 0xB9C, "Tamil"),
+    (0xB9E, 0xB9F, "Tamil"),
+    (0xBA3, 0xBA4, "Tamil"),
+    (0xBA8, 0xBAA, "Tamil"),
+    (0xBAE, 0xBB9, "Tamil"),
+    (0xBBE, 0xBC2, "Tamil"),
+    (0xBC6, 0xBC8, "Tamil"),
+    (0xBCA, 0xBCD, "Tamil"),
+    (0xBD0, 0xBD0, "Tamil"),
+    (0xBD7, 0xBD7, "Tamil"),
+    (0xBE6, 0xBFA, "Tamil"),
+    (0xC00, 0xC0C, "Telugu"),
+    (0xC0E, 0xC10, "Telugu"),
+    (0xC12, 0xC28, "Telugu"),
+    (0xC2A, 0xC39, "Telugu"),
+    (0xC3C, 0xC44, "Telugu"),
+    (0xC46, 0xC48, "Telugu"),
+    (0xC4A, 0xC4D, "Telugu"),
+    (0xC55, 0xC56, "Telugu"),
+    (0xC58, 0xC5A, "Telugu"),
+    (0xC5D, 0xC5D, "Telugu"),
+    (0xC60, 0xC63, "Telugu"),
+    (0xC66, 0xC6F, "Telugu"),
+    (0xC77, 0xC7F, "Telugu"),
+    (0xC80, 0xC8C, "Kannada"),
+    (0xC8E, 0xC90, "Kannada"),
+    (0xC92, 0xCA8, "Kannada"),
+    (0xCAA, 0xCB3, "Kannada"),
+    (0xCB5, 0xCB9, "Kannada"),
+    (0xCBC, 0xCC4, "Kannada"),
+    (0xCC6, 0xCC8, "Kannada"),
+    (0xCCA, 0xCCD, "Kannada"),
+    (0xCD5, 0xCD6, "Kannada"),
+    (0xCDD, 0xCDE, "Kannada"),
+    (0xCE0, 0xCE3, "Kannada"),
+    (0xCE6, 0xCEF, "Kannada"),
+    (0xCF1, 0xCF2, "Kannada"),
+    (0xD00, 0xD0C, "Malayalam"),
+    (0xD0E, 0xD10, "Malayalam"),
+    (0xD12, 0xD44, "Malayalam"),
+    (0xD46, 0xD48, "Malayalam"),
+    (0xD4A, 0xD4F, "Malayalam"),
+    (0xD54, 0xD63, "Malayalam"),
+    (0xD66, 0xD7F, "Malayalam"),
+    (0xD81, 0xD83, "Sinhala"),
+    (0xD85, 0xD96, "Sinhala"),
+    (0xD9A, 0xDB1, "Sinhala"),
+    (0xDB3, 0xDBB, "Sinhala"),
+    (0xDBD, 0xDBD, "Sinhala"),
+    (0xDC0, 0xDC6, "Sinhala"),
+    (0xDCA, 0xDCA, "Sinhala"),
+    (0xDCF, 0xDD4, "Sinhala"),
+    (0xDD6, 0xDD6, "Sinhala"),
+    (0xDD8, 0xDDF, "Sinhala"),
+    (0xDE6, 0xDEF, "Sinhala"),
+    (0xDF2, 0xDF4, "Sinhala"),
+    (0xE01, 0xE3A, "Thai"),
+    (0xE3F, 0xE3F, "Common"),
+    (0xE40, 0xE5B, "Thai"),
+    (0xE81, 0xE82, "Lao"),
+    (0xE84, 0xE84, "Lao"),
+    (0xE86, 0xE8A, "Lao"),
+    (0xE8C, 0xEA3, "Lao"),
+    (0xEA5, 0xEA5, "Lao"),
+    (0xEA7, 0xEBD, "Lao"),
+    (0xEC0, 0xEC4, "Lao"),
+    (0xEC6, 0xEC6, "Lao"),
+    (0xEC8, 0xECD, "Lao"),
+    (0xED0, 0xED9, "Lao"),
+    (0xEDC, 0xEDF, "Lao"),
+    (0xF00, 0xF47, "Tibetan"),
+    (0xF49, 0xF6C, "Tibetan"),
+    (0xF71, 0xF97, "Tibetan"),
+    (0xF99, 0xFBC, "Tibetan"),
+    (0xFBE, 0xFCC, "Tibetan"),
+    (0xFCE, 0xFD4, "Tibetan"),
+    (0xFD5, 0xFD8, "Common"),
+    (0xFD9, 0xFDA, "Tibetan"),
+    (0x1000, 0x109F, "Myanmar"),
+    (0x10A0, 0x10C5, "Georgian"),
+    (0x10C7, 0x10C7, "Georgian"),
+    (0x10CD, 0x10CD, "Georgian"),
+    (0x10D0, 0x10FA, "Georgian"),
+    (0x10FB, 0x10FB, "Common"),
+    (0x10FC, 0x10FF, "Georgian"),
+    (0x1100, 0x11FF, "Hangul"),
+    (0x1200, 0x1248, "Ethiopic"),
+    (0x124A, 0x124D, "Ethiopic"),
+    (0x1250, 0x1256, "Ethiopic"),
+    (0x1258, 0x1258, "Ethiopic"),
+    (0x125A, 0x125D, "Ethiopic"),
+    (0x1260, 0x1288, "Ethiopic"),
+    (0x128A, 0x128D, "Ethiopic"),
+    (0x1290, 0x12B0, "Ethiopic"),
+    (0x12B2, 0x12B5, "Ethiopic"),
+    (0x12B8, 0x12BE, "Ethiopic"),
+    (0x12C0, 0x12C0, "Ethiopic"),
+    (0x12C2, 0x12C5, "Ethiopic"),
+    (0x12C8, 0x12D6, "Ethiopic"),
+    (0x12D8, 0x1310, "Ethiopic"),
+    (0x1312, 0x1315, "Ethiopic"),
+    (0x1318, 0x135A, "Ethiopic"),
+    (0x135D, 0x137C, "Ethiopic"),
+    (0x1380, 0x1399, "Ethiopic"),
+    (0x13A0, 0x13F5, "Cherokee"),
+    (0x13F8, 0x13FD, "Cherokee"),
+    (0x1400, 0x167F, "Canadian Aboriginal"),
+    (0x1680, 0x169C, "Ogham"),
+    (0x16A0, 0x16EA, "Runic"),
+    (0x16EB, 0x16ED, "Common"),
+    (0x16EE, 0x16F8, "Runic"),
+    (0x1700, 0x1715, "Tagalog"),
+    (0x171F, 0x171F, "Tagalog"),
+    (0x1720, 0x1734, "Hanunoo"),
+    (0x1735, 0x1736, "Common"),
+    (0x1740, 0x1753, "Buhid"),
+    (0x1760, 0x176C, "Tagbanwa"),
+    (0x176E, 0x1770, "Tagbanwa"),
+    (0x1772, 0x1773, "Tagbanwa"),
+    (0x1780, 0x17DD, "Khmer"),
+    (0x17E0, 0x17E9, "Khmer"),
+    (0x17F0, 0x17F9, "Khmer"),
+    (0x1800, 0x1801, "Mongolian"),
+    (0x1802, 0x1803, "Common"),
+    (0x1804, 0x1804, "Mongolian"),
+    (0x1805, 0x1805, "Common"),
+    (0x1806, 0x1819, "Mongolian"),
+    (0x1820, 0x1878, "Mongolian"),
+    (0x1880, 0x18AA, "Mongolian"),
+    (0x18B0, 0x18F5, "Canadian Aboriginal"),
+    (0x1900, 0x191E, "Limbu"),
+    (0x1920, 0x192B, "Limbu"),
+    (0x1930, 0x193B, "Limbu"),
+    (0x1940, 0x1940, "Limbu"),
+    (0x1944, 0x194F, "Limbu"),
+    (0x1950, 0x196D, "Tai Le"),
+    (0x1970, 0x1974, "Tai Le"),
+    (0x1980, 0x19AB, "New Tai Lue"),
+    (0x19B0, 0x19C9, "New Tai Lue"),
+    (0x19D0, 0x19DA, "New Tai Lue"),
+    (0x19DE, 0x19DF, "New Tai Lue"),
+    (0x19E0, 0x19FF, "Khmer"),
+    (0x1A00, 0x1A1B, "Buginese"),
+    (0x1A1E, 0x1A1F, "Buginese"),
+    (0x1A20, 0x1A5E, "Tai Tham"),
+    (0x1A60, 0x1A7C, "Tai Tham"),
+    (0x1A7F, 0x1A89, "Tai Tham"),
+    (0x1A90, 0x1A99, "Tai Tham"),
+    (0x1AA0, 0x1AAD, "Tai Tham"),
+    (0x1AB0, 0x1ACE, "Inherited"),
+    (0x1B00, 0x1B4C, "Balinese"),
+    (0x1B50, 0x1B7E, "Balinese"),
+    (0x1B80, 0x1BBF, "Sundanese"),
+    (0x1BC0, 0x1BF3, "Batak"),
+    (0x1BFC, 0x1BFF, "Batak"),
+    (0x1C00, 0x1C37, "Lepcha"),
+    (0x1C3B, 0x1C49, "Lepcha"),
+    (0x1C4D, 0x1C4F, "Lepcha"),
+    (0x1C50, 0x1C7F, "Ol Chiki"),
+    (0x1C80, 0x1C88, "Cyrillic"),
+    (0x1C90, 0x1CBA, "Georgian"),
+    (0x1CBD, 0x1CBF, "Georgian"),
+    (0x1CC0, 0x1CC7, "Sundanese"),
+    (0x1CD0, 0x1CD2, "Inherited"),
+    (0x1CD3, 0x1CD3, "Common"),
+    (0x1CD4, 0x1CE0, "Inherited"),
+    (0x1CE1, 0x1CE1, "Common"),
+    (0x1CE2, 0x1CE8, "Inherited"),
+    (0x1CE9, 0x1CEC, "Common"),
+    (0x1CED, 0x1CED, "Inherited"),
+    (0x1CEE, 0x1CF3, "Common"),
+    (0x1CF4, 0x1CF4, "Inherited"),
+    (0x1CF5, 0x1CF7, "Common"),
+    (0x1CF8, 0x1CF9, "Inherited"),
+    (0x1CFA, 0x1CFA, "Common"),
+    (0x1D00, 0x1D25, "Latin"),
+    (0x1D26, 0x1D2A, "Greek"),
+    (0x1D2B, 0x1D2B, "Cyrillic"),
+    (0x1D2C, 0x1D5C, "Latin"),
+    (0x1D5D, 0x1D61, "Greek"),
+    (0x1D62, 0x1D65, "Latin"),
+    (0x1D66, 0x1D6A, "Greek"),
+    (0x1D6B, 0x1D77, "Latin"),
+    (0x1D78, 0x1D78, "Cyrillic"),
+    (0x1D79, 0x1DBE, "Latin"),
+    (0x1DBF, 0x1DBF, "Greek"),
+    (0x1DC0, 0x1DFF, "Inherited"),
+    (0x1E00, 0x1EFF, "Latin"),
+    (0x1F00, 0x1F15, "Greek"),
+    (0x1F18, 0x1F1D, "Greek"),
+    (0x1F20, 0x1F45, "Greek"),
+    (0x1F48, 0x1F4D, "Greek"),
+    (0x1F50, 0x1F57, "Greek"),
+    (0x1F59, 0x1F59, "Greek"),
+    (0x1F5B, 0x1F5B, "Greek"),
+    (0x1F5D, 0x1F5D, "Greek"),
+    (0x1F5F, 0x1F7D, "Greek"),
+    (0x1F80, 0x1FB4, "Greek"),
+    (0x1FB6, 0x1FC4, "Greek"),
+    (0x1FC6, 0x1FD3, "Greek"),
+    (0x1FD6, 0x1FDB, "Greek"),
+    (0x1FDD, 0x1FEF, "Greek"),
+    (0x1FF2, 0x1FF4, "Greek"),
+    (0x1FF6, 0x1FFE, "Greek"),
+    (0x2000, 0x200B, "Common"),
+    (0x200C, 0x200D, "Inherited"),
+    (0x200E, 0x2064, "Common"),
+    (0x2066, 0x2070, "Common"),
+    (0x2071, 0x2071, "Latin"),
+    (0x2074, 0x207E, "Common"),
+    (0x207F, 0x207F, "Latin"),
+    (0x2080, 0x208E, "Common"),
+    (0x2090, 0x209C, "Latin"),
+    (0x20A0, 0x20C0, "Common"),
+    (0x20D0, 0x20F0, "Inherited"),
+    (0x2100, 0x2125, "Common"),
+    (0x2126, 0x2126, "Greek"),
+    (0x2127, 0x2129, "Common"),
+    (0x212A, 0x212B, "Latin"),
+    (0x212C, 0x2131, "Common"),
+    (0x2132, 0x2132, "Latin"),
+    (0x2133, 0x214D, "Common"),
+    (0x214E, 0x214E, "Latin"),
+    (0x214F, 0x215F, "Common"),
+    (0x2160, 0x2188, "Latin"),
+    (0x2189, 0x218B, "Common"),
+    (0x2190, 0x2426, "Common"),
+    (0x2440, 0x244A, "Common"),
+    (0x2460, 0x27FF, "Common"),
+    (0x2800, 0x28FF, "Braille"),
+    (0x2900, 0x2B73, "Common"),
+    (0x2B76, 0x2B95, "Common"),
+    (0x2B97, 0x2BFF, "Common"),
+    (0x2C00, 0x2C5F, "Glagolitic"),
+    (0x2C60, 0x2C7F, "Latin"),
+    (0x2C80, 0x2CF3, "Coptic"),
+    (0x2CF9, 0x2CFF, "Coptic"),
+    (0x2D00, 0x2D25, "Georgian"),
+    (0x2D27, 0x2D27, "Georgian"),
+    (0x2D2D, 0x2D2D, "Georgian"),
+    (0x2D30, 0x2D67, "Tifinagh"),
+    (0x2D6F, 0x2D70, "Tifinagh"),
+    (0x2D7F, 0x2D7F, "Tifinagh"),
+    (0x2D80, 0x2D96, "Ethiopic"),
+    (0x2DA0, 0x2DA6, "Ethiopic"),
+    (0x2DA8, 0x2DAE, "Ethiopic"),
+    (0x2DB0, 0x2DB6, "Ethiopic"),
+    (0x2DB8, 0x2DBE, "Ethiopic"),
+    (0x2DC0, 0x2DC6, "Ethiopic"),
+    (0x2DC8, 0x2DCE, "Ethiopic"),
+    (0x2DD0, 0x2DD6, "Ethiopic"),
+    (0x2DD8, 0x2DDE, "Ethiopic"),
+    (0x2DE0, 0x2DFF, "Cyrillic"),
+    (0x2E00, 0x2E5D, "Common"),
+    (0x2E80, 0x2E99, "Han"),
+    (0x2E9B, 0x2EF3, "Han"),
+    (0x2F00, 0x2FD5, "Han"),
+    (0x2FF0, 0x2FFB, "Common"),
+    (0x3000, 0x3004, "Common"),
+    (0x3005, 0x3005, "Han"),
+    (0x3006, 0x3006, "Common"),
+    (0x3007, 0x3007, "Han"),
+    (0x3008, 0x3020, "Common"),
+    (0x3021, 0x3029, "Han"),
+    (0x302A, 0x302D, "Inherited"),
+    (0x302E, 0x302F, "Hangul"),
+    (0x3030, 0x3037, "Common"),
+    (0x3038, 0x303B, "Han"),
+    (0x303C, 0x303F, "Common"),
+    (0x3041, 0x3096, "Hiragana"),
+    (0x3099, 0x309A, "Inherited"),
+    (0x309B, 0x309C, "Common"),
+    (0x309D, 0x309F, "Hiragana"),
+    (0x30A0, 0x30A0, "Common"),
+    (0x30A1, 0x30FA, "Katakana"),
+    (0x30FB, 0x30FC, "Common"),
+    (0x30FD, 0x30FF, "Katakana"),
+    (0x3105, 0x312F, "Bopomofo"),
+    (0x3131, 0x318E, "Hangul"),
+    (0x3190, 0x319F, "Common"),
+    (0x31A0, 0x31BF, "Bopomofo"),
+    (0x31C0, 0x31E3, "Common"),
+    (0x31F0, 0x31FF, "Katakana"),
+    (0x3200, 0x321E, "Hangul"),
+    (0x3220, 0x325F, "Common"),
+    (0x3260, 0x327E, "Hangul"),
+    (0x327F, 0x32CF, "Common"),
+    (0x32D0, 0x32FE, "Katakana"),
+    (0x32FF, 0x32FF, "Common"),
+    (0x3300, 0x3357, "Katakana"),
+    (0x3358, 0x33FF, "Common"),
+    (0x3400, 0x4DBF, "Han"),
+    (0x4DC0, 0x4DFF, "Common"),
+    (0x4E00, 0x9FFF, "Han"),
+    (0xA000, 0xA48C, "Yi"),
+    (0xA490, 0xA4C6, "Yi"),
+    (0xA4D0, 0xA4FF, "Lisu"),
+    (0xA500, 0xA62B, "Vai"),
+    (0xA640, 0xA69F, "Cyrillic"),
+    (0xA6A0, 0xA6F7, "Bamum"),
+    (0xA700, 0xA721, "Common"),
+    (0xA722, 0xA787, "Latin"),
+    (0xA788, 0xA78A, "Common"),
+    (0xA78B, 0xA7CA, "Latin"),
+    (0xA7D0, 0xA7D1, "Latin"),
+    (0xA7D3, 0xA7D3, "Latin"),
+    (0xA7D5, 0xA7D9, "Latin"),
+    (0xA7F2, 0xA7FF, "Latin"),
+    (0xA800, 0xA82C, "Syloti Nagri"),
+    (0xA830, 0xA839, "Common"),
+    (0xA840, 0xA877, "Phags Pa"),
+    (0xA880, 0xA8C5, "Saurashtra"),
+    (0xA8CE, 0xA8D9, "Saurashtra"),
+    (0xA8E0, 0xA8FF, "Devanagari"),
+    (0xA900, 0xA92D, "Kayah Li"),
+    (0xA92E, 0xA92E, "Common"),
+    (0xA92F, 0xA92F, "Kayah Li"),
+    (0xA930, 0xA953, "Rejang"),
+    (0xA95F, 0xA95F, "Rejang"),
+    (0xA960, 0xA97C, "Hangul"),
+    (0xA980, 0xA9CD, "Javanese"),
+    (0xA9CF, 0xA9CF, "Common"),
+    (0xA9D0, 0xA9D9, "Javanese"),
+    (0xA9DE, 0xA9DF, "Javanese"),
+    (0xA9E0, 0xA9FE, "Myanmar"),
+    (0xAA00, 0xAA36, "Cham"),
+    (0xAA40, 0xAA4D, "Cham"),
+    (0xAA50, 0xAA59, "Cham"),
+    (0xAA5C, 0xAA5F, "Cham"),
+    (0xAA60, 0xAA7F, "Myanmar"),
+    (0xAA80, 0xAAC2, "Tai Viet"),
+    (0xAADB, 0xAADF, "Tai Viet"),
+    (0xAAE0, 0xAAF6, "Meetei Mayek"),
+    (0xAB01, 0xAB06, "Ethiopic"),
+    (0xAB09, 0xAB0E, "Ethiopic"),
+    (0xAB11, 0xAB16, "Ethiopic"),
+    (0xAB20, 0xAB26, "Ethiopic"),
+    (0xAB28, 0xAB2E, "Ethiopic"),
+    (0xAB30, 0xAB5A, "Latin"),
+    (0xAB5B, 0xAB5B, "Common"),
+    (0xAB5C, 0xAB64, "Latin"),
+    (0xAB65, 0xAB65, "Greek"),
+    (0xAB66, 0xAB69, "Latin"),
+    (0xAB6A, 0xAB6B, "Common"),
+    (0xAB70, 0xABBF, "Cherokee"),
+    (0xABC0, 0xABED, "Meetei Mayek"),
+    (0xABF0, 0xABF9, "Meetei Mayek"),
+    (0xAC00, 0xD7A3, "Hangul"),
+    (0xD7B0, 0xD7C6, "Hangul"),
+    (0xD7CB, 0xD7FB, "Hangul"),
+    (0xF900, 0xFA6D, "Han"),
+    (0xFA70, 0xFAD9, "Han"),
+    (0xFB00, 0xFB06, "Latin"),
+    (0xFB13, 0xFB17, "Armenian"),
+    (0xFB1D, 0xFB36, "Hebrew"),
+    (0xFB38, 0xFB3C, "Hebrew"),
+    (0xFB3E, 0xFB3E, "Hebrew"),
+    (0xFB40, 0xFB41, "Hebrew"),
+    (0xFB43, 0xFB44, "Hebrew"),
+    (0xFB46, 0xFB4F, "Hebrew"),
+    (0xFB50, 0xFBC2, "Arabic"),
+    (0xFBD3, 0xFD3D, "Arabic"),
+    (0xFD3E, 0xFD3F, "Common"),
+    (0xFD40, 0xFD8F, "Arabic"),
+    (0xFD92, 0xFDC7, "Arabic"),
+    (0xFDCF, 0xFDCF, "Arabic"),
+    (0xFDF0, 0xFDFF, "Arabic"),
+    (0xFE00, 0xFE0F, "Inherited"),
+    (0xFE10, 0xFE19, "Common"),
+    (0xFE20, 0xFE2D, "Inherited"),
+    (0xFE2E, 0xFE2F, "Cyrillic"),
+    (0xFE30, 0xFE52, "Common"),
+    (0xFE54, 0xFE66, "Common"),
+    (0xFE68, 0xFE6B, "Common"),
+    (0xFE70, 0xFE74, "Arabic"),
+    (0xFE76, 0xFEFC, "Arabic"),
+    (0xFEFF, 0xFEFF, "Common"),
+    (0xFF01, 0xFF20, "Common"),
+    (0xFF21, 0xFF3A, "Latin"),
+    (0xFF3B, 0xFF40, "Common"),
+    (0xFF41, 0xFF5A, "Latin"),
+    (0xFF5B, 0xFF65, "Common"),
+    (0xFF66, 0xFF6F, "Katakana"),
+    (0xFF70, 0xFF70, "Common"),
+    (0xFF71, 0xFF9D, "Katakana"),
+    (0xFF9E, 0xFF9F, "Common"),
+    (0xFFA0, 0xFFBE, "Hangul"),
+    (0xFFC2, 0xFFC7, "Hangul"),
+    (0xFFCA, 0xFFCF, "Hangul"),
+    (0xFFD2, 0xFFD7, "Hangul"),
+    (0xFFDA, 0xFFDC, "Hangul"),
+    (0xFFE0, 0xFFE6, "Common"),
+    (0xFFE8, 0xFFEE, "Common"),
+    (0xFFF9, 0xFFFD, "Common"),
+    (0x10000, 0x1000B, "Linear B"),
+    (0x1000D, 0x10026, "Linear B"),
+    (0x10028, 0x1003A, "Linear B"),
+    (0x1003C, 0x1003D, "Linear B"),
+    (0x1003F, 0x1004D, "Linear B"),
+    (0x10050, 0x1005D, "Linear B"),
+    (0x10080, 0x100FA, "Linear B"),
+    (0x10100, 0x10102, "Common"),
+    (0x10107, 0x10133, "Common"),
+    (0x10137, 0x1013F, "Common"),
+    (0x10140, 0x1018E, "Greek"),
+    (0x10190, 0x1019C, "Common"),
+    (0x101A0, 0x101A0, "Greek"),
+    (0x101D0, 0x101FC, "Common"),
+    (0x101FD, 0x101FD, "Inherited"),
+    (0x10280, 0x1029C, "Lycian"),
+    (0x102A0, 0x102D0, "Carian"),
+    (0x102E0, 0x102E0, "Inherited"),
+    (0x102E1, 0x102FB, "Common"),
+    (0x10300, 0x10323, "Old Italic"),
+    (0x1032D, 0x1032F, "Old Italic"),
+    (0x10330, 0x1034A, "Gothic"),
+    (0x10350, 0x1037A, "Old Permic"),
+    (0x10380, 0x1039D, "Ugaritic"),
+    (0x1039F, 0x1039F, "Ugaritic"),
+    (0x103A0, 0x103C3, "Old Persian"),
+    (0x103C8, 0x103D5, "Old Persian"),
+    (0x10400, 0x1044F, "Deseret"),
+    (0x10450, 0x1047F, "Shavian"),
+    (0x10480, 0x1049D, "Osmanya"),
+    (0x104A0, 0x104A9, "Osmanya"),
+    (0x104B0, 0x104D3, "Osage"),
+    (0x104D8, 0x104FB, "Osage"),
+    (0x10500, 0x10527, "Elbasan"),
+    (0x10530, 0x10563, "Caucasian Albanian"),
+    (0x1056F, 0x1056F, "Caucasian Albanian"),
+    (0x10570, 0x1057A, "Vithkuqi"),
+    (0x1057C, 0x1058A, "Vithkuqi"),
+    (0x1058C, 0x10592, "Vithkuqi"),
+    (0x10594, 0x10595, "Vithkuqi"),
+    (0x10597, 0x105A1, "Vithkuqi"),
+    (0x105A3, 0x105B1, "Vithkuqi"),
+    (0x105B3, 0x105B9, "Vithkuqi"),
+    (0x105BB, 0x105BC, "Vithkuqi"),
+    (0x10600, 0x10736, "Linear A"),
+    (0x10740, 0x10755, "Linear A"),
+    (0x10760, 0x10767, "Linear A"),
+    (0x10780, 0x10785, "Latin"),
+    (0x10787, 0x107B0, "Latin"),
+    (0x107B2, 0x107BA, "Latin"),
+    (0x10800, 0x10805, "Cypriot"),
+    (0x10808, 0x10808, "Cypriot"),
+    (0x1080A, 0x10835, "Cypriot"),
+    (0x10837, 0x10838, "Cypriot"),
+    (0x1083C, 0x1083C, "Cypriot"),
+    (0x1083F, 0x1083F, "Cypriot"),
+    (0x10840, 0x10855, "Imperial Aramaic"),
+    (0x10857, 0x1085F, "Imperial Aramaic"),
+    (0x10860, 0x1087F, "Palmyrene"),
+    (0x10880, 0x1089E, "Nabataean"),
+    (0x108A7, 0x108AF, "Nabataean"),
+    (0x108E0, 0x108F2, "Hatran"),
+    (0x108F4, 0x108F5, "Hatran"),
+    (0x108FB, 0x108FF, "Hatran"),
+    (0x10900, 0x1091B, "Phoenician"),
+    (0x1091F, 0x1091F, "Phoenician"),
+    (0x10920, 0x10939, "Lydian"),
+    (0x1093F, 0x1093F, "Lydian"),
+    (0x10980, 0x1099F, "Meroitic Hieroglyphs"),
+    (0x109A0, 0x109B7, "Meroitic Cursive"),
+    (0x109BC, 0x109CF, "Meroitic Cursive"),
+    (0x109D2, 0x109FF, "Meroitic Cursive"),
+    (0x10A00, 0x10A03, "Kharoshthi"),
+    (0x10A05, 0x10A06, "Kharoshthi"),
+    (0x10A0C, 0x10A13, "Kharoshthi"),
+    (0x10A15, 0x10A17, "Kharoshthi"),
+    (0x10A19, 0x10A35, "Kharoshthi"),
+    (0x10A38, 0x10A3A, "Kharoshthi"),
+    (0x10A3F, 0x10A48, "Kharoshthi"),
+    (0x10A50, 0x10A58, "Kharoshthi"),
+    (0x10A60, 0x10A7F, "Old South Arabian"),
+    (0x10A80, 0x10A9F, "Old North Arabian"),
+    (0x10AC0, 0x10AE6, "Manichaean"),
+    (0x10AEB, 0x10AF6, "Manichaean"),
+    (0x10B00, 0x10B35, "Avestan"),
+    (0x10B39, 0x10B3F, "Avestan"),
+    (0x10B40, 0x10B55, "Inscriptional Parthian"),
+    (0x10B58, 0x10B5F, "Inscriptional Parthian"),
+    (0x10B60, 0x10B72, "Inscriptional Pahlavi"),
+    (0x10B78, 0x10B7F, "Inscriptional Pahlavi"),
+    (0x10B80, 0x10B91, "Psalter Pahlavi"),
+    (0x10B99, 0x10B9C, "Psalter Pahlavi"),
+    (0x10BA9, 0x10BAF, "Psalter Pahlavi"),
+    (0x10C00, 0x10C48, "Old Turkic"),
+    (0x10C80, 0x10CB2, "Old Hungarian"),
+    (0x10CC0, 0x10CF2, "Old Hungarian"),
+    (0x10CFA, 0x10CFF, "Old Hungarian"),
+    (0x10D00, 0x10D27, "Hanifi Rohingya"),
+    (0x10D30, 0x10D39, "Hanifi Rohingya"),
+    (0x10E60, 0x10E7E, "Arabic"),
+    (0x10E80, 0x10EA9, "Yezidi"),
+    (0x10EAB, 0x10EAD, "Yezidi"),
+    (0x10EB0, 0x10EB1, "Yezidi"),
+    (0x10F00, 0x10F27, "Old Sogdian"),
+    (0x10F30, 0x10F59, "Sogdian"),
+    (0x10F70, 0x10F89, "Old Uyghur"),
+    (0x10FB0, 0x10FCB, "Chorasmian"),
+    (0x10FE0, 0x10FF6, "Elymaic"),
+    (0x11000, 0x1104D, "Brahmi"),
+    (0x11052, 0x11075, "Brahmi"),
+    (0x1107F, 0x1107F, "Brahmi"),
+    (0x11080, 0x110C2, "Kaithi"),
+    (0x110CD, 0x110CD, "Kaithi"),
+    (0x110D0, 0x110E8, "Sora Sompeng"),
+    (0x110F0, 0x110F9, "Sora Sompeng"),
+    (0x11100, 0x11134, "Chakma"),
+    (0x11136, 0x11147, "Chakma"),
+    (0x11150, 0x11176, "Mahajani"),
+    (0x11180, 0x111DF, "Sharada"),
+    (0x111E1, 0x111F4, "Sinhala"),
+    (0x11200, 0x11211, "Khojki"),
+    (0x11213, 0x1123E, "Khojki"),
+    (0x11280, 0x11286, "Multani"),
+    (0x11288, 0x11288, "Multani"),
+    (0x1128A, 0x1128D, "Multani"),
+    (0x1128F, 0x1129D, "Multani"),
+    (0x1129F, 0x112A9, "Multani"),
+    (0x112B0, 0x112EA, "Khudawadi"),
+    (0x112F0, 0x112F9, "Khudawadi"),
+    (0x11300, 0x11303, "Grantha"),
+    (0x11305, 0x1130C, "Grantha"),
+    (0x1130F, 0x11310, "Grantha"),
+    (0x11313, 0x11328, "Grantha"),
+    (0x1132A, 0x11330, "Grantha"),
+    (0x11332, 0x11333, "Grantha"),
+    (0x11335, 0x11339, "Grantha"),
+    (0x1133B, 0x1133B, "Inherited"),
+    (0x1133C, 0x11344, "Grantha"),
+    (0x11347, 0x11348, "Grantha"),
+    (0x1134B, 0x1134D, "Grantha"),
+    (0x11350, 0x11350, "Grantha"),
+    (0x11357, 0x11357, "Grantha"),
+    (0x1135D, 0x11363, "Grantha"),
+    (0x11366, 0x1136C, "Grantha"),
+    (0x11370, 0x11374, "Grantha"),
+    (0x11400, 0x1145B, "Newa"),
+    (0x1145D, 0x11461, "Newa"),
+    (0x11480, 0x114C7, "Tirhuta"),
+    (0x114D0, 0x114D9, "Tirhuta"),
+    (0x11580, 0x115B5, "Siddham"),
+    (0x115B8, 0x115DD, "Siddham"),
+    (0x11600, 0x11644, "Modi"),
+    (0x11650, 0x11659, "Modi"),
+    (0x11660, 0x1166C, "Mongolian"),
+    (0x11680, 0x116B9, "Takri"),
+    (0x116C0, 0x116C9, "Takri"),
+    (0x11700, 0x1171A, "Ahom"),
+    (0x1171D, 0x1172B, "Ahom"),
+    (0x11730, 0x11746, "Ahom"),
+    (0x11800, 0x1183B, "Dogra"),
+    (0x118A0, 0x118F2, "Warang Citi"),
+    (0x118FF, 0x118FF, "Warang Citi"),
+    (0x11900, 0x11906, "Dives Akuru"),
+    (0x11909, 0x11909, "Dives Akuru"),
+    (0x1190C, 0x11913, "Dives Akuru"),
+    (0x11915, 0x11916, "Dives Akuru"),
+    (0x11918, 0x11935, "Dives Akuru"),
+    (0x11937, 0x11938, "Dives Akuru"),
+    (0x1193B, 0x11946, "Dives Akuru"),
+    (0x11950, 0x11959, "Dives Akuru"),
+    (0x119A0, 0x119A7, "Nandinagari"),
+    (0x119AA, 0x119D7, "Nandinagari"),
+    (0x119DA, 0x119E4, "Nandinagari"),
+    (0x11A00, 0x11A47, "Zanabazar Square"),
+    (0x11A50, 0x11AA2, "Soyombo"),
+    (0x11AB0, 0x11ABF, "Canadian Aboriginal"),
+    (0x11AC0, 0x11AF8, "Pau Cin Hau"),
+    (0x11C00, 0x11C08, "Bhaiksuki"),
+    (0x11C0A, 0x11C36, "Bhaiksuki"),
+    (0x11C38, 0x11C45, "Bhaiksuki"),
+    (0x11C50, 0x11C6C, "Bhaiksuki"),
+    (0x11C70, 0x11C8F, "Marchen"),
+    (0x11C92, 0x11CA7, "Marchen"),
+    (0x11CA9, 0x11CB6, "Marchen"),
+    (0x11D00, 0x11D06, "Masaram Gondi"),
+    (0x11D08, 0x11D09, "Masaram Gondi"),
+    (0x11D0B, 0x11D36, "Masaram Gondi"),
+    (0x11D3A, 0x11D3A, "Masaram Gondi"),
+    (0x11D3C, 0x11D3D, "Masaram Gondi"),
+    (0x11D3F, 0x11D47, "Masaram Gondi"),
+    (0x11D50, 0x11D59, "Masaram Gondi"),
+    (0x11D60, 0x11D65, "Gunjala Gondi"),
+    (0x11D67, 0x11D68, "Gunjala Gondi"),
+    (0x11D6A, 0x11D8E, "Gunjala Gondi"),
+    (0x11D90, 0x11D91, "Gunjala Gondi"),
+    (0x11D93, 0x11D98, "Gunjala Gondi"),
+    (0x11DA0, 0x11DA9, "Gunjala Gondi"),
+    (0x11EE0, 0x11EF8, "Makasar"),
+    (0x11FB0, 0x11FB0, "Lisu"),
+    (0x11FC0, 0x11FF1, "Tamil"),
+    (0x11FFF, 0x11FFF, "Tamil"),
+    (0x12000, 0x12399, "Cuneiform"),
+    (0x12400, 0x1246E, "Cuneiform"),
+    (0x12470, 0x12474, "Cuneiform"),
+    (0x12480, 0x12543, "Cuneiform"),
+    (0x12F90, 0x12FF2, "Cypro Minoan"),
+    (0x13000, 0x1342E, "Egyptian Hieroglyphs"),
+    (0x13430, 0x13438, "Egyptian Hieroglyphs"),
+    (0x14400, 0x14646, "Anatolian Hieroglyphs"),
+    (0x16800, 0x16A38, "Bamum"),
+    (0x16A40, 0x16A5E, "Mro"),
+    (0x16A60, 0x16A69, "Mro"),
+    (0x16A6E, 0x16A6F, "Mro"),
+    (0x16A70, 0x16ABE, "Tangsa"),
+    (0x16AC0, 0x16AC9, "Tangsa"),
+    (0x16AD0, 0x16AED, "Bassa Vah"),
+    (0x16AF0, 0x16AF5, "Bassa Vah"),
+    (0x16B00, 0x16B45, "Pahawh Hmong"),
+    (0x16B50, 0x16B59, "Pahawh Hmong"),
+    (0x16B5B, 0x16B61, "Pahawh Hmong"),
+    (0x16B63, 0x16B77, "Pahawh Hmong"),
+    (0x16B7D, 0x16B8F, "Pahawh Hmong"),
+    (0x16E40, 0x16E9A, "Medefaidrin"),
+    (0x16F00, 0x16F4A, "Miao"),
+    (0x16F4F, 0x16F87, "Miao"),
+    (0x16F8F, 0x16F9F, "Miao"),
+    (0x16FE0, 0x16FE0, "Tangut"),
+    (0x16FE1, 0x16FE1, "Nushu"),
+    (0x16FE2, 0x16FE3, "Han"),
+    (0x16FE4, 0x16FE4, "Khitan Small Script"),
+    (0x16FF0, 0x16FF1, "Han"),
+    (0x17000, 0x187F7, "Tangut"),
+    (0x18800, 0x18AFF, "Tangut"),
+    (0x18B00, 0x18CD5, "Khitan Small Script"),
+    (0x18D00, 0x18D08, "Tangut"),
+    (0x1AFF0, 0x1AFF3, "Katakana"),
+    (0x1AFF5, 0x1AFFB, "Katakana"),
+    (0x1AFFD, 0x1AFFE, "Katakana"),
+    (0x1B000, 0x1B000, "Katakana"),
+    (0x1B001, 0x1B11F, "Hiragana"),
+    (0x1B120, 0x1B122, "Katakana"),
+    (0x1B150, 0x1B152, "Hiragana"),
+    (0x1B164, 0x1B167, "Katakana"),
+    (0x1B170, 0x1B2FB, "Nushu"),
+    (0x1BC00, 0x1BC6A, "Duployan"),
+    (0x1BC70, 0x1BC7C, "Duployan"),
+    (0x1BC80, 0x1BC88, "Duployan"),
+    (0x1BC90, 0x1BC99, "Duployan"),
+    (0x1BC9C, 0x1BC9F, "Duployan"),
+    (0x1BCA0, 0x1BCA3, "Common"),
+    (0x1CF00, 0x1CF2D, "Inherited"),
+    (0x1CF30, 0x1CF46, "Inherited"),
+    (0x1CF50, 0x1CFC3, "Common"),
+    (0x1D000, 0x1D0F5, "Common"),
+    (0x1D100, 0x1D126, "Common"),
+    (0x1D129, 0x1D166, "Common"),
+    (0x1D167, 0x1D169, "Inherited"),
+    (0x1D16A, 0x1D17A, "Common"),
+    (0x1D17B, 0x1D182, "Inherited"),
+    (0x1D183, 0x1D184, "Common"),
+    (0x1D185, 0x1D18B, "Inherited"),
+    (0x1D18C, 0x1D1A9, "Common"),
+    (0x1D1AA, 0x1D1AD, "Inherited"),
+    (0x1D1AE, 0x1D1EA, "Common"),
+    (0x1D200, 0x1D245, "Greek"),
+    (0x1D2E0, 0x1D2F3, "Common"),
+    (0x1D300, 0x1D356, "Common"),
+    (0x1D360, 0x1D378, "Common"),
+    (0x1D400, 0x1D454, "Common"),
+    (0x1D456, 0x1D49C, "Common"),
+    (0x1D49E, 0x1D49F, "Common"),
+    (0x1D4A2, 0x1D4A2, "Common"),
+    (0x1D4A5, 0x1D4A6, "Common"),
+    (0x1D4A9, 0x1D4AC, "Common"),
+    (0x1D4AE, 0x1D4B9, "Common"),
+    (0x1D4BB, 0x1D4BB, "Common"),
+    (0x1D4BD, 0x1D4C3, "Common"),
+    (0x1D4C5, 0x1D505, "Common"),
+    (0x1D507, 0x1D50A, "Common"),
+    (0x1D50D, 0x1D514, "Common"),
+    (0x1D516, 0x1D51C, "Common"),
+    (0x1D51E, 0x1D539, "Common"),
+    (0x1D53B, 0x1D53E, "Common"),
+    (0x1D540, 0x1D544, "Common"),
+    (0x1D546, 0x1D546, "Common"),
+    (0x1D54A, 0x1D550, "Common"),
+    (0x1D552, 0x1D6A5, "Common"),
+    (0x1D6A8, 0x1D7CB, "Common"),
+    (0x1D7CE, 0x1D7FF, "Common"),
+    (0x1D800, 0x1DA8B, "SignWriting"),
+    (0x1DA9B, 0x1DA9F, "SignWriting"),
+    (0x1DAA1, 0x1DAAF, "SignWriting"),
+    (0x1DF00, 0x1DF1E, "Latin"),
+    (0x1E000, 0x1E006, "Glagolitic"),
+    (0x1E008, 0x1E018, "Glagolitic"),
+    (0x1E01B, 0x1E021, "Glagolitic"),
+    (0x1E023, 0x1E024, "Glagolitic"),
+    (0x1E026, 0x1E02A, "Glagolitic"),
+    (0x1E100, 0x1E12C, "Nyiakeng Puachue Hmong"),
+    (0x1E130, 0x1E13D, "Nyiakeng Puachue Hmong"),
+    (0x1E140, 0x1E149, "Nyiakeng Puachue Hmong"),
+    (0x1E14E, 0x1E14F, "Nyiakeng Puachue Hmong"),
+    (0x1E290, 0x1E2AE, "Toto"),
+    (0x1E2C0, 0x1E2F9, "Wancho"),
+    (0x1E2FF, 0x1E2FF, "Wancho"),
+    (0x1E7E0, 0x1E7E6, "Ethiopic"),
+    (0x1E7E8, 0x1E7EB, "Ethiopic"),
+    (0x1E7ED, 0x1E7EE, "Ethiopic"),
+    (0x1E7F0, 0x1E7FE, "Ethiopic"),
+    (0x1E800, 0x1E8C4, "Mende Kikakui"),
+    (0x1E8C7, 0x1E8D6, "Mende Kikakui"),
+    (0x1E900, 0x1E94B, "Adlam"),
+    (0x1E950, 0x1E959, "Adlam"),
+    (0x1E95E, 0x1E95F, "Adlam"),
+    (0x1EC71, 0x1ECB4, "Common"),
+    (0x1ED01, 0x1ED3D, "Common"),
+    (0x1EE00, 0x1EE03, "Arabic"),
+    (0x1EE05, 0x1EE1F, "Arabic"),
+    (0x1EE21, 0x1EE22, "Arabic"),
+    (0x1EE24, 0x1EE24, "Arabic"),
+    (0x1EE27, 0x1EE27, "Arabic"),
+    (0x1EE29, 0x1EE32, "Arabic"),
+    (0x1EE34, 0x1EE37, "Arabic"),
+    (0x1EE39, 0x1EE39, "Arabic"),
+    (0x1EE3B, 0x1EE3B, "Arabic"),
+    (0x1EE42, 0x1EE42, "Arabic"),
+    (0x1EE47, 0x1EE47, "Arabic"),
+    (0x1EE49, 0x1EE49, "Arabic"),
+    (0x1EE4B, 0x1EE4B, "Arabic"),
+    (0x1EE4D, 0x1EE4F, "Arabic"),
+    (0x1EE51, 0x1EE52, "Arabic"),
+    (0x1EE54, 0x1EE54, "Arabic"),
+    (0x1EE57, 0x1EE57, "Arabic"),
+    (0x1EE59, 0x1EE59, "Arabic"),
+    (0x1EE5B, 0x1EE5B, "Arabic"),
+    (0x1EE5D, 0x1EE5D, "Arabic"),
+    (0x1EE5F, 0x1EE5F, "Arabic"),
+    (0x1EE61, 0x1EE62, "Arabic"),
+    (0x1EE64, 0x1EE64, "Arabic"),
+    (0x1EE67, 0x1EE6A, "Arabic"),
+    (0x1EE6C, 0x1EE72, "Arabic"),
+    (0x1EE74, 0x1EE77, "Arabic"),
+    (0x1EE79, 0x1EE7C, "Arabic"),
+    (0x1EE7E, 0x1EE7E, "Arabic"),
+    (0x1EE80, 0x1EE89, "Arabic"),
+    (0x1EE8B, 0x1EE9B, "Arabic"),
+    (0x1EEA1, 0x1EEA3, "Arabic"),
+    (0x1EEA5, 0x1EEA9, "Arabic"),
+    (0x1EEAB, 0x1EEBB, "Arabic"),
+    (0x1EEF0, 0x1EEF1, "Arabic"),
+    (0x1F000, 0x1F02B, "Common"),
+    (0x1F030, 0x1F093, "Common"),
+    (0x1F0A0, 0x1F0AE, "Common"),
+    (0x1F0B1, 0x1F0BF, "Common"),
+    (0x1F0C1, 0x1F0CF, "Common"),
+    (0x1F0D1, 0x1F0F5, "Common"),
+    (0x1F100, 0x1F1AD, "Common"),
+    (0x1F1E6, 0x1F1FF, "Common"),
+    (0x1F200, 0x1F200, "Hiragana"),
+    (0x1F201, 0x1F202, "Common"),
+    (0x1F210, 0x1F23B, "Common"),
+    (0x1F240, 0x1F248, "Common"),
+    (0x1F250, 0x1F251, "Common"),
+    (0x1F260, 0x1F265, "Common"),
+    (0x1F300, 0x1F6D7, "Common"),
+    (0x1F6DD, 0x1F6EC, "Common"),
+    (0x1F6F0, 0x1F6FC, "Common"),
+    (0x1F700, 0x1F773, "Common"),
+    (0x1F780, 0x1F7D8, "Common"),
+    (0x1F7E0, 0x1F7EB, "Common"),
+    (0x1F7F0, 0x1F7F0, "Common"),
+    (0x1F800, 0x1F80B, "Common"),
+    (0x1F810, 0x1F847, "Common"),
+    (0x1F850, 0x1F859, "Common"),
+    (0x1F860, 0x1F887, "Common"),
+    (0x1F890, 0x1F8AD, "Common"),
+    (0x1F8B0, 0x1F8B1, "Common"),
+    (0x1F900, 0x1FA53, "Common"),
+    (0x1FA60, 0x1FA6D, "Common"),
+    (0x1FA70, 0x1FA74, "Common"),
+    (0x1FA78, 0x1FA7C, "Common"),
+    (0x1FA80, 0x1FA86, "Common"),
+    (0x1FA90, 0x1FAAC, "Common"),
+    (0x1FAB0, 0x1FABA, "Common"),
+    (0x1FAC0, 0x1FAC5, "Common"),
+    (0x1FAD0, 0x1FAD9, "Common"),
+    (0x1FAE0, 0x1FAE7, "Common"),
+    (0x1FAF0, 0x1FAF6, "Common"),
+    (0x1FB00, 0x1FB92, "Common"),
+    (0x1FB94, 0x1FBCA, "Common"),
+    (0x1FBF0, 0x1FBF9, "Common"),
+    (0x20000, 0x2A6DF, "Han"),
+    (0x2A700, 0x2B738, "Han"),
+    (0x2B740, 0x2B81D, "Han"),
+    (0x2B820, 0x2CEA1, "Han"),
+    (0x2CEB0, 0x2EBE0, "Han"),
+    (0x2F800, 0x2FA1D, "Han"),
+    (0x30000, 0x3134A, "Han"),
+    (0xE0001, 0xE0001, "Common"),
+    (0xE0020, 0xE007F, "Common"),
+    (0xE0100, 0xE01EF, "Inherited"),
+];
+
+pub const DECOMPOSITIONS: &[(u32, Option<&str>, &[u32])] = &[
+    (0xA0, Some("noBreak"), &[0x20]),
+    (0xA8, Some("compat"), &[0x20, 0x308]),
+    (0xAA, Some("super"), &[0x61]),
+    (0xAF, Some("compat"), &[0x20, 0x304]),
+    (0xB2, Some("super"), &[0x32]),
+    (0xB3, Some("super"), &[0x33]),
+    (0xB4, Some("compat"), &[0x20, 0x301]),
+    (0xB5, Some("compat"), &[0x3BC]),
+    (0xB8, Some("compat"), &[0x20, 0x327]),
+    (0xB9, Some("super"), &[0x31]),
+    (0xBA, Some("super"), &[0x6F]),
+    (0xBC, Some("fraction"), &[0x31, 0x2044, 0x34]),
+    (0xBD, Some("fraction"), &[0x31, 0x2044, 0x32]),
+    (0xBE, Some("fraction"), &[0x33, 0x2044, 0x34]),
+    (0xC0, None, &[0x41, 0x300]),
+    (0xC1, None, &[0x41, 0x301]),
+    (0xC2, None, &[0x41, 0x302]),
+    (0xC3, None, &[0x41, 0x303]),
+    (0xC4, None, &[0x41, 0x308]),
+    (0xC5, None, &[0x41, 0x30A]),
+    (0xC7, None, &[0x43, 0x327]),
+    (0xC8, None, &[0x45, 0x300]),
+    (0xC9, None, &[0x45, 0x301]),
+    (0xCA, None, &[0x45, 0x302]),
+    (0xCB, None, &[0x45, 0x308]),
+    (0xCC, None, &[0x49, 0x300]),
+    (0xCD, None, &[0x49, 0x301]),
+    (0xCE, None, &[0x49, 0x302]),
+    (0xCF, None, &[0x49, 0x308]),
+    (0xD1, None, &[0x4E, 0x303]),
+    (0xD2, None, &[0x4F, 0x300]),
+    (0xD3, None, &[0x4F, 0x301]),
+    (0xD4, None, &[0x4F, 0x302]),
+    (0xD5, None, &[0x4F, 0x303]),
+    (0xD6, None, &[0x4F, 0x308]),
+    (0xD9, None, &[0x55, 0x300]),
+    (0xDA, None, &[0x55, 0x301]),
+    (0xDB, None, &[0x55, 0x302]),
+    (0xDC, None, &[0x55, 0x308]),
+    (0xDD, None, &[0x59, 0x301]),
+    (0xE0, None, &[0x61, 0x300]),
+    (0xE1, None, &[0x61, 0x301]),
+    (0xE2, None, &[0x61, 0x302]),
+    (0xE3, None, &[0x61, 0x303]),
+    (0xE4, None, &[0x61, 0x308]),
+    (0xE5, None, &[0x61, 0x30A]),
+    (0xE7, None, &[0x63, 0x327]),
+    (0xE8, None, &[0x65, 0x300]),
+    (0xE9, None, &[0x65, 0x301]),
+    (0xEA, None, &[0x65, 0x302]),
+    (0xEB, None, &[0x65, 0x308]),
+    (0xEC, None, &[0x69, 0x300]),
+    (0xED, None, &[0x69, 0x301]),
+    (0xEE, None, &[0x69, 0x302]),
+    (0xEF, None, &[0x69, 0x308]),
+    (0xF1, None, &[0x6E, 0x303]),
+    (0xF2, None, &[0x6F, 0x300]),
+    (0xF3, None, &[0x6F, 0x301]),
+    (0xF4, None, &[0x6F, 0x302]),
+    (0xF5, None, &[0x6F, 0x303]),
+    (0xF6, None, &[0x6F, 0x308]),
+    (0xF9, None, &[0x75, 0x300]),
+    (0xFA, None, &[0x75, 0x301]),
+    (0xFB, None, &[0x75, 0x302]),
+    (0xFC, None, &[0x75, 0x308]),
+    (0xFD, None, &[0x79, 0x301]),
+    (0xFF, None, &[0x79, 0x308]),
+    (0x100, None, &[0x41, 0x304]),
+    (0x101, None, &[0x61, 0x304]),
+    (0x102, None, &[0x41, 0x306]),
+    (0x103, None, &[0x61, 0x306]),
+    (0x104, None, &[0x41, 0x328]),
+    (0x105, None, &[0x61, 0x328]),
+    (0x106, None, &[0x43, 0x301]),
+    (0x107, None, &[0x63, 0x301]),
+    (0x108, None, &[0x43, 0x302]),
+    (0x109, None, &[0x63, 0x302]),
+    (0x10A, None, &[0x43, 0x307]),
+    (0x10B, None, &[0x63, 0x307]),
+    (0x10C, None, &[0x43, 0x30C]),
+    (0x10D, None, &[0x63, 0x30C]),
+    (0x10E, None, &[0x44, 0x30C]),
+    (0x10F, None, &[0x64, 0x30C]),
+    (0x112, None, &[0x45, 0x304]),
+    (0x113, None, &[0x65, 0x304]),
+    (0x114, None, &[0x45, 0x306]),
+    (0x115, None, &[0x65, 0x306]),
+    (0x116, None, &[0x45, 0x307]),
+    (0x117, None, &[0x65, 0x307]),
+    (0x118, None, &[0x45, 0x328]),
+    (0x119, None, &[0x65, 0x328]),
+    (0x11A, None, &[0x45, 0x30C]),
+    (0x11B, None, &[0x65, 0x30C]),
+    (0x11C, None, &[0x47, 0x302]),
+    (0x11D, None, &[0x67, 0x302]),
+    (0x11E, None, &[0x47, 0x306]),
+    (0x11F, None, &[0x67, 0x306]),
+    (0x120, None, &[0x47, 0x307]),
+    (0x121, None, &[0x67, 0x307]),
+    (0x122, None, &[0x47, 0x327]),
+    (0x123, None, &[0x67, 0x327]),
+    (0x124, None, &[0x48, 0x302]),
+    (0x125, None, &[0x68, 0x302]),
+    (0x128, None, &[0x49, 0x303]),
+    (0x129, None, &[0x69, 0x303]),
+    (0x12A, None, &[0x49, 0x304]),
+    (0x12B, None, &[0x69, 0x304]),
+    (0x12C, None, &[0x49, 0x306]),
+    (0x12D, None, &[0x69, 0x306]),
+    (0x12E, None, &[0x49, 0x328]),
+    (0x12F, None, &[0x69, 0x328]),
+    (0x130, None, &[0x49, 0x307]),
+    (0x132, Some("compat"), &[0x49, 0x4A]),
+    (0x133, Some("compat"), &[0x69, 0x6A]),
+    (0x134, None, &[0x4A, 0x302]),
+    (0x135, None, &[0x6A, 0x302]),
+    (0x136, None, &[0x4B, 0x327]),
+    (0x137, None, &[0x6B, 0x327]),
+    (0x139, None, &[0x4C, 0x301]),
+    (0x13A, None, &[0x6C, 0x301]),
+    (0x13B, None, &[0x4C, 0x327]),
+    (0x13C, None, &[0x6C, 0x327]),
+    (0x13D, None, &[0x4C, 0x30C]),
+    (0x13E, None, &[0x6C, 0x30C]),
+    (0x13F, Some("compat"), &[0x4C, 0xB7]),
+    (0x140, Some("compat"), &[0x6C, 0xB7]),
+    (0x143, None, &[0x4E, 0x301]),
+    (0x144, None, &[0x6E, 0x301]),
+    (0x145, None, &[0x4E, 0x327]),
+    (0x146, None, &[0x6E, 0x327]),
+    (0x147, None, &[0x4E, 0x30C]),
+    (0x148, None, &[0x6E, 0x30C]),
+    (0x149, Some("compat"), &[0x2BC, 0x6E]),
+    (0x14C, None, &[0x4F, 0x304]),
+    (0x14D, None, &[0x6F, 0x304]),
+    (0x14E, None, &[0x4F, 0x306]),
+    (0x14F, None, &[0x6F, 0x306]),
+    (0x150, None, &[0x4F, 0x30B]),
+    (0x151, None, &[0x6F, 0x30B]),
+    (0x154, None, &[0x52, 0x301]),
+    (0x155, None, &[0x72, 0x301]),
+    (0x156, None, &[0x52, 0x327]),
+    (0x157, None, &[0x72, 0x327]),
+    (0x158, None, &[0x52, 0x30C]),
+    (0x159, None, &[0x72, 0x30C]),
+    (0x15A, None, &[0x53, 0x301]),
+    (0x15B, None, &[0x73, 0x301]),
+    (0x15C, None, &[0x53, 0x302]),
+    (0x15D, None, &[0x73, 0x302]),
+    (0x15E, None, &[0x53, 0x327]),
+    (0x15F, None, &[0x73, 0x327]),
+    (0x160, None, &[0x53, 0x30C]),
+    (0x161, None, &[0x73, 0x30C]),
+    (0x162, None, &[0x54, 0x327]),
+    (0x163, None, &[0x74, 0x327]),
+    (0x164, None, &[0x54, 0x30C]),
+    (0x165, None, &[0x74, 0x30C]),
+    (0x168, None, &[0x55, 0x303]),
+    (0x169, None, &[0x75, 0x303]),
+    (0x16A, None, &[0x55, 0x304]),
+    (0x16B, None, &[0x75, 0x304]),
+    (0x16C, None, &[0x55, 0x306]),
+    (0x16D, None, &[0x75, 0x306]),
+    (0x16E, None, &[0x55, 0x30A]),
+    (0x16F, None, &[0x75, 0x30A]),
+    (0x170, None, &[0x55, 0x30B]),
+    (0x171, None, &[0x75, 0x30B]),
+    (0x172, None, &[0x55, 0x328]),
+    (0x173, None, &[0x75, 0x328]),
+    (0x174, None, &[0x57, 0x302]),
+    (0x175, None, &[0x77, 0x302]),
+    (0x176, None, &[0x59, 0x302]),
+    (0x177, None, &[0x79, 0x302]),
+    (0x178, None, &[0x59, 0x308]),
+    (0x179, None, &[0x5A, 0x301]),
+    (0x17A, None, &[0x7A, 0x301]),
+    (0x17B, None, &[0x5A, 0x307]),
+    (0x17C, None, &[0x7A, 0x307]),
+    (0x17D, None, &[0x5A, 0x30C]),
+    (0x17E, None, &[0x7A, 0x30C]),
+    (0x17F, Some("compat"), &[0x73]),
+    (0x1A0, None, &[0x4F, 0x31B]),
+    (0x1A1, None, &[0x6F, 0x31B]),
+    (0x1AF, None, &[0x55, 0x31B]),
+    (0x1B0, None, &[0x75, 0x31B]),
+    (0x1C4, Some("compat"), &[0x44, 0x17D]),
+    (0x1C5, Some("compat"), &[0x44, 0x17E]),
+    (0x1C6, Some("compat"), &[0x64, 0x17E]),
+    (0x1C7, Some("compat"), &[0x4C, 0x4A]),
+    (0x1C8, Some("compat"), &[0x4C, 0x6A]),
+    (0x1C9, Some("compat"), &[0x6C, 0x6A]),
+    (0x1CA, Some("compat"), &[0x4E, 0x4A]),
+    (0x1CB, Some("compat"), &[0x4E, 0x6A]),
+    (0x1CC, Some("compat"), &[0x6E, 0x6A]),
+    (0x1CD, None, &[0x41, 0x30C]),
+    (0x1CE, None, &[0x61, 0x30C]),
+    (0x1CF, None, &[0x49, 0x30C]),
+    (0x1D0, None, &[0x69, 0x30C]),
+    (0x1D1, None, &[0x4F, 0x30C]),
+    (0x1D2, None, &[0x6F, 0x30C]),
+    (0x1D3, None, &[0x55, 0x30C]),
+    (0x1D4, None, &[0x75, 0x30C]),
+    (0x1D5, None, &[0xDC, 0x304]),
+    (0x1D6, None, &[0xFC, 0x304]),
+    (0x1D7, None, &[0xDC, 0x301]),
+    (0x1D8, None, &[0xFC, 0x301]),
+    (0x1D9, None, &[0xDC, 0x30C]),
+    (0x1DA, None, &[0xFC, 0x30C]),
+    (0x1DB, None, &[0xDC, 0x300]),
+    (0x1DC, None, &[0xFC, 0x300]),
+    (0x1DE, None, &[0xC4, 0x304]),
+    (0x1DF, None, &[0xE4, 0x304]),
+    (0x1E0, None, &[0x226, 0x304]),
+    (0x1E1, None, &[0x227, 0x304]),
+    (0x1E2, None, &[0xC6, 0x304]),
+    (0x1E3, None, &[0xE6, 0x304]),
+    (0x1E6, None, &[0x47, 0x30C]),
+    (0x1E7, None, &[0x67, 0x30C]),
+    (0x1E8, None, &[0x4B, 0x30C]),
+    (0x1E9, None, &[0x6B, 0x30C]),
+    (0x1EA, None, &[0x4F, 0x328]),
+    (0x1EB, None, &[0x6F, 0x328]),
+    (0x1EC, None, &[0x1EA, 0x304]),
+    (0x1ED, None, &[0x1EB, 0x304]),
+    (0x1EE, None, &[0x1B7, 0x30C]),
+    (0x1EF, None, &[0x292, 0x30C]),
+    (0x1F0, None, &[0x6A, 0x30C]),
+    (0x1F1, Some("compat"), &[0x44, 0x5A]),
+    (0x1F2, Some("compat"), &[0x44, 0x7A]),
+    (0x1F3, Some("compat"), &[0x64, 0x7A]),
+    (0x1F4, None, &[0x47, 0x301]),
+    (0x1F5, None, &[0x67, 0x301]),
+    (0x1F8, None, &[0x4E, 0x300]),
+    (0x1F9, None, &[0x6E, 0x300]),
+    (0x1FA, None, &[0xC5, 0x301]),
+    (0x1FB, None, &[0xE5, 0x301]),
+    (0x1FC, None, &[0xC6, 0x301]),
+    (0x1FD, None, &[0xE6, 0x301]),
+    (0x1FE, None, &[0xD8, 0x301]),
+    (0x1FF, None, &[0xF8, 0x301]),
+    (0x200, None, &[0x41, 0x30F]),
+    (0x201, None, &[0x61, 0x30F]),
+    (0x202, None, &[0x41, 0x311]),
+    (0x203, None, &[0x61, 0x311]),
+    (0x204, None, &[0x45, 0x30F]),
+    (0x205, None, &[0x65, 0x30F]),
+    (0x206, None, &[0x45, 0x311]),
+    (0x207, None, &[0x65, 0x311]),
+    (0x208, None, &[0x49, 0x30F]),
+    (0x209, None, &[0x69, 0x30F]),
+    (0x20A, None, &[0x49, 0x311]),
+    (0x20B, None, &[0x69, 0x311]),
+    (0x20C, None, &[0x4F, 0x30F]),
+    (0x20D, None, &[0x6F, 0x30F]),
+    (0x20E, None, &[0x4F, 0x311]),
+    (0x20F, None, &[0x6F, 0x311]),
+    (0x210, None, &[0x52, 0x30F]),
+    (0x211, None, &[0x72, 0x30F]),
+    (0x212, None, &[0x52, 0x311]),
+    (0x213, None, &[0x72, 0x311]),
+    (0x214, None, &[0x55, 0x30F]),
+    (0x215, None, &[0x75, 0x30F]),
+    (0x216, None, &[0x55, 0x311]),
+    (0x217, None, &[0x75, 0x311]),
+    (0x218, None, &[0x53, 0x326]),
+    (0x219, None, &[0x73, 0x326]),
+    (0x21A, None, &[0x54, 0x326]),
+    (0x21B, None, &[0x74, 0x326]),
+    (0x21E, None, &[0x48, 0x30C]),
+    (0x21F, None, &[0x68, 0x30C]),
+    (0x226, None, &[0x41, 0x307]),
+    (0x227, None, &[0x61, 0x307]),
+    (0x228, None, &[0x45, 0x327]),
+    (0x229, None, &[0x65, 0x327]),
+    (0x22A, None, &[0xD6, 0x304]),
+    (0x22B, None, &[0xF6, 0x304]),
+    (0x22C, None, &[0xD5, 0x304]),
+    (0x22D, None, &[0xF5, 0x304]),
+    (0x22E, None, &[0x4F, 0x307]),
+    (0x22F, None, &[0x6F, 0x307]),
+    (0x230, None, &[0x22E, 0x304]),
+    (0x231, None, &[0x22F, 0x304]),
+    (0x232, None, &[0x59, 0x304]),
+    (0x233, None, &[0x79, 0x304]),
+    (0x2B0, Some("super"), &[0x68]),
+    (0x2B1, Some("super"), &[0x266]),
+    (0x2B2, Some("super"), &[0x6A]),
+    (0x2B3, Some("super"), &[0x72]),
+    (0x2B4, Some("super"), &[0x279]),
+    (0x2B5, Some("super"), &[0x27B]),
+    (0x2B6, Some("super"), &[0x281]),
+    (0x2B7, Some("super"), &[0x77]),
+    (0x2B8, Some("super"), &[0x79]),
+    (0x2D8, Some("compat"), &[0x20, 0x306]),
+    (0x2D9, Some("compat"), &[0x20, 0x307]),
+    (0x2DA, Some("compat"), &[0x20, 0x30A]),
+    (0x2DB, Some("compat"), &[0x20, 0x328]),
+    (0x2DC, Some("compat"), &[0x20, 0x303]),
+    (0x2DD, Some("compat"), &[0x20, 0x30B]),
+    (0x2E0, Some("super"), &[0x263]),
+    (0x2E1, Some("super"), &[0x6C]),
+    (0x2E2, Some("super"), &[0x73]),
+    (0x2E3, Some("super"), &[0x78]),
+    (0x2E4, Some("super"), &[0x295]),
+    (0x340, None, &[0x300]),
+    (0x341, None, &[0x301]),
+    (0x343, None, &[0x313]),
+    (0x344, None, &[0x308, 0x301]),
+    (0x374, None, &[0x2B9]),
+    (0x37A, Some("compat"), &[0x20, 0x345]),
+    (0x37E, None, &[0x3B]),
+    (0x384, Some("compat"), &[0x20, 0x301]),
+    (0x385, None, &[0xA8, 0x301]),
+    (0x386, None, &[0x391, 0x301]),
+    (0x387, None, &[0xB7]),
+    (0x388, None, &[0x395, 0x301]),
+    (0x389, None, &[0x397, 0x301]),
+    (0x38A, None, &[0x399, 0x301]),
+    (0x38C, None, &[0x39F, 0x301]),
+    (0x38E, None, &[0x3A5, 0x301]),
+    (0x38F, None, &[0x3A9, 0x301]),
+    (0x390, None, &[0x3CA, 0x301]),
+    (0x3AA, None, &[0x399, 0x308]),
+    (0x3AB, None, &[0x3A5, 0x308]),
+    (0x3AC, None, &[0x3B1, 0x301]),
+    (0x3AD, None, &[0x3B5, 0x301]),
+    (0x3AE, None, &[0x3B7, 0x301]),
+    (0x3AF, None, &[0x3B9, 0x301]),
+    (0x3B0, None, &[0x3CB, 0x301]),
+    (0x3CA, None, &[0x3B9, 0x308]),
+    (0x3CB, None, &[0x3C5, 0x308]),
+    (0x3CC, None, &[0x3BF, 0x301]),
+    (0x3CD, None, &[0x3C5, 0x301]),
+    (0x3CE, None, &[0x3C9, 0x301]),
+    (0x3D0, Some("compat"), &[0x3B2]),
+    (0x3D1, Some("compat"), &[0x3B8]),
+    (0x3D2, Some("compat"), &[0x3A5]),
+    (0x3D3, None, &[0x3D2, 0x301]),
+    (0x3D4, None, &[0x3D2, 0x308]),
+    (0x3D5, Some("compat"), &[0x3C6]),
+    (0x3D6, Some("compat"), &[0x3C0]),
+    (0x3F0, Some("compat"), &[0x3BA]),
+    (0x3F1, Some("compat"), &[0x3C1]),
+    (0x3F2, Some("compat"), &[0x3C2]),
+    (0x3F4, Some("compat"), &[0x398]),
+    (0x3F5, Some("compat"), &[0x3B5]),
+    (0x3F9, Some("compat"), &[0x3A3]),
+    (0x400, None, &[0x415, 0x300]),
+    (0x401, None, &[0x415, 0x308]),
+    (0x403, None, &[0x413, 0x301]),
+    (0x407, None, &[0x406, 0x308]),
+    (0x40C, None, &[0x41A, 0x301]),
+    (0x40D, None, &[0x418, 0x300]),
+    (0x40E, None, &[0x423, 0x306]),
+    (0x419, None, &[0x418, 0x306]),
+    (0x439, None, &[0x438, 0x306]),
+    (0x450, None, &[0x435, 0x300]),
+    (0x451, None, &[0x435, 0x308]),
+    (0x453, None, &[0x433, 0x301]),
+    (0x457, None, &[0x456, 0x308]),
+    (0x45C, None, &[0x43A, 0x301]),
+    (0x45D, None, &[0x438, 0x300]),
+    (0x45E, None, &[0x443, 0x306]),
+    (0x476, None, &[0x474, 0x30F]),
+    (0x477, None, &[0x475, 0x30F]),
+    (0x4C1, None, &[0x416, 0x306]),
+    (0x4C2, None, &[0x436, 0x306]),
+    (0x4D0, None, &[0x410, 0x306]),
+    (0x4D1, None, &[0x430, 0x306]),
+    (0x4D2, None, &[0x410, 0x308]),
+    (0x4D3, None, &[0x430, 0x308]),
+    (0x4D6, None, &[0x415, 0x306]),
+    (0x4D7, None, &[0x435, 0x306]),
+    (0x4DA, None, &[0x4D8, 0x308]),
+    (0x4DB, None, &[0x4D9, 0x308]),
+    (0x4DC, None, &[0x416, 0x308]),
+    (0x4DD, None, &[0x436, 0x308]),
+    (0x4DE, None, &[0x417, 0x308]),
+    (0x4DF, None, &[0x437, 0x308]),
+    (0x4E2, None, &[0x418, 0x304]),
+    (0x4E3, None, &[0x438, 0x304]),
+    (0x4E4, None, &[0x418, 0x308]),
+    (0x4E5, None, &[0x438, 0x308]),
+    (0x4E6, None, &[0x41E, 0x308]),
+    (0x4E7, None, &[0x43E, 0x308]),
+    (0x4EA, None, &[0x4E8, 0x308]),
+    (0x4EB, None, &[0x4E9, 0x308]),
+    (0x4EC, None, &[0x42D, 0x308]),
+    (0x4ED, None, &[0x44D, 0x308]),
+    (0x4EE, None, &[0x423, 0x304]),
+    (0x4EF, None, &[0x443, 0x304]),
+    (0x4F0, None, &[0x423, 0x308]),
+    (0x4F1, None, &[0x443, 0x308]),
+    (0x4F2, None, &[0x423, 0x30B]),
+    (0x4F3, None, &[0x443, 0x30B]),
+    (0x4F4, None, &[0x427, 0x308]),
+    (0x4F5, None, &[0x447, 0x308]),
+    (0x4F8, None, &[0x42B, 0x308]),
+    (0x4F9, None, &[0x44B, 0x308]),
+    (0x587, Some("compat"), &[0x565, 0x582]),
+    (0x622, None, &[0x627, 0x653]),
+    (0x623, None, &[0x627, 0x654]),
+    (0x624, None, &[0x648, 0x654]),
+    (0x625, None, &[0x627, 0x655]),
+    (0x626, None, &[0x64A, 0x654]),
+    (0x675, Some("compat"), &[0x627, 0x674]),
+    (0x676, Some("compat"), &[0x648, 0x674]),
+    (0x677, Some("compat"), &[0x6C7, 0x674]),
+    (0x678, Some("compat"), &[0x64A, 0x674]),
+    (0x6C0, None, &[0x6D5, 0x654]),
+    (0x6C2, None, &[0x6C1, 0x654]),
+    (0x6D3, None, &[0x6D2, 0x654]),
+    (0x929, None, &[0x928, 0x93C]),
+    (0x931, None, &[0x930, 0x93C]),
+    (0x934, None, &[0x933, 0x93C]),
+    (0x958, None, &[0x915, 0x93C]),
+    (0x959, None, &[0x916, 0x93C]),
+    (0x95A, None, &[0x917, 0x93C]),
+    (0x95B, None, &[0x91C, 0x93C]),
+    (0x95C, None, &[0x921, 0x93C]),
+    (0x95D, None, &[0x922, 0x93C]),
+    (0x95E, None, &[0x92B, 0x93C]),
+    (0x95F, None, &[0x92F, 0x93C]),
+    (0x9CB, None, &[0x9C7, 0x9BE]),
+    (0x9CC, None, &[0x9C7, 0x9D7]),
+    (0x9DC, None, &[0x9A1, 0x9BC]),
+    (0x9DD, None, &[0x9A2, 0x9BC]),
+    (0x9DF, None, &[0x9AF, 0x9BC]),
+    (0xA33, None, &[0xA32, 0xA3C]),
+    (0xA36, None, &[0xA38, 0xA3C]),
+    (0xA59, None, &[0xA16, 0xA3C]),
+    (0xA5A, None, &[0xA17, 0xA3C]),
+    (0xA5B, None, &[0xA1C, 0xA3C]),
+    (0xA5E, None, &[0xA2B, 0xA3C]),
+    (0xB48, None, &[0xB47, 0xB56]),
+    (0xB4B, None, &[0xB47, 0xB3E]),
+    (0xB4C, None, &[0xB47, 0xB57]),
+    (0xB5C, None, &[0xB21, 0xB3C]),
+    (0xB5D, None, &[0xB22, 0xB3C]),
+    (0xB94, None, &[0xB92, 0xBD7]),
+    (0xBCA, None, &[0xBC6, 0xBBE]),
+    (0xBCB, None, &[0xBC7, 0xBBE]),
+    (0xBCC, None, &[0xBC6, 0xBD7]),
+    (0xC48, None, &[0xC46, 0xC56]),
+    (0xCC0, None, &[0xCBF, 0xCD5]),
+    (0xCC7, None, &[0xCC6, 0xCD5]),
+    (0xCC8, None, &[0xCC6, 0xCD6]),
+    (0xCCA, None, &[0xCC6, 0xCC2]),
+    (0xCCB, None, &[0xCCA, 0xCD5]),
+    (0xD4A, None, &[0xD46, 0xD3E]),
+    (0xD4B, None, &[0xD47, 0xD3E]),
+    (0xD4C, None, &[0xD46, 0xD57]),
+    (0xDDA, None, &[0xDD9, 0xDCA]),
+    (0xDDC, None, &[0xDD9, 0xDCF]),
+    (0xDDD, None, &[0xDDC, 0xDCA]),
+    (0xDDE, None, &[0xDD9, 0xDDF]),
+    (0xE33, Some("compat"), &[0xE4D, 0xE32]),
+    (0xEB3, Some("compat"), &[0xECD, 0xEB2]),
+    (0xEDC, Some("compat"), &[0xEAB, 0xE99]),
+    (0xEDD, Some("compat"), &[0xEAB, 0xEA1]),
+    (0xF0C, Some("noBreak"), &[0xF0B]),
+    (0xF43, None, &[0xF42, 0xFB7]),
+    (0xF4D, None, &[0xF4C, 0xFB7]),
+    (0xF52, None, &[0xF51, 0xFB7]),
+    (0xF57, None, &[0xF56, 0xFB7]),
+    (0xF5C, None, &[0xF5B, 0xFB7]),
+    (0xF69, None, &[0xF40, 0xFB5]),
+    (0xF73, None, &[0xF71, 0xF72]),
+    (0xF75, None, &[0xF71, 0xF74]),
+    (0xF76, None, &[0xFB2, 0xF80]),
+    (0xF77, Some("compat"), &[0xFB2, 0xF81]),
+    (0xF78, None, &[0xFB3, 0xF80]),
+    (0xF79, Some("compat"), &[0xFB3, 0xF81]),
+    (0xF81, None, &[0xF71, 0xF80]),
+    (0xF93, None, &[0xF92, 0xFB7]),
+    (0xF9D, None, &[0xF9C, 0xFB7]),
+    (0xFA2, None, &[0xFA1, 0xFB7]),
+    (0xFA7, None, &[0xFA6, 0xFB7]),
+    (0xFAC, None, &[0xFAB, 0xFB7]),
+    (0xFB9, None, &[0xF90, 0xFB5]),
+    (0x1026, None, &[0x1025, 0x102E]),
+    (0x10FC, Some("super"), &[0x10DC]),
+    (0x1B06, None, &[0x1B05, 0x1B35]),
+    (0x1B08, None, &[0x1B07, 0x1B35]),
+    (0x1B0A, None, &[0x1B09, 0x1B35]),
+    (0x1B0C, None, &[0x1B0B, 0x1B35]),
+    (0x1B0E, None, &[0x1B0D, 0x1B35]),
+    (0x1B12, None, &[0x1B11, 0x1B35]),
+    (0x1B3B, None, &[0x1B3A, 0x1B35]),
+    (0x1B3D, None, &[0x1B3C, 0x1B35]),
+    (0x1B40, None, &[0x1B3E, 0x1B35]),
+    (0x1B41, None, &[0x1B3F, 0x1B35]),
+    (0x1B43, None, &[0x1B42, 0x1B35]),
+    (0x1D2C, Some("super"), &[0x41]),
+    (0x1D2D, Some("super"), &[0xC6]),
+    (0x1D2E, Some("super"), &[0x42]),
+    (0x1D30, Some("super"), &[0x44]),
+    (0x1D31, Some("super"), &[0x45]),
+    (0x1D32, Some("super"), &[0x18E]),
+    (0x1D33, Some("super"), &[0x47]),
+    (0x1D34, Some("super"), &[0x48]),
+    (0x1D35, Some("super"), &[0x49]),
+    (0x1D36, Some("super"), &[0x4A]),
+    (0x1D37, Some("super"), &[0x4B]),
+    (0x1D38, Some("super"), &[0x4C]),
+    (0x1D39, Some("super"), &[0x4D]),
+    (0x1D3A, Some("super"), &[0x4E]),
+    (0x1D3C, Some("super"), &[0x4F]),
+    (0x1D3D, Some("super"), &[0x222]),
+    (0x1D3E, Some("super"), &[0x50]),
+    (0x1D3F, Some("super"), &[0x52]),
+    (0x1D40, Some("super"), &[0x54]),
+    (0x1D41, Some("super"), &[0x55]),
+    (0x1D42, Some("super"), &[0x57]),
+    (0x1D43, Some("super"), &[0x61]),
+    (0x1D44, Some("super"), &[0x250]),
+    (0x1D45, Some("super"), &[0x251]),
+    (0x1D46, Some("super"), &[0x1D02]),
+    (0x1D47, Some("super"), &[0x62]),
+    (0x1D48, Some("super"), &[0x64]),
+    (0x1D49, Some("super"), &[0x65]),
+    (0x1D4A, Some("super"), &[0x259]),
+    (0x1D4B, Some("super"), &[0x25B]),
+    (0x1D4C, Some("super"), &[0x25C]),
+    (0x1D4D, Some("super"), &[0x67]),
+    (0x1D4F, Some("super"), &[0x6B]),
+    (0x1D50, Some("super"), &[0x6D]),
+    (0x1D51, Some("super"), &[0x14B]),
+    (0x1D52, Some("super"), &[0x6F]),
+    (0x1D53, Some("super"), &[0x254]),
+    (0x1D54, Some("super"), &[0x1D16]),
+    (0x1D55, Some("super"), &[0x1D17]),
+    (0x1D56, Some("super"), &[0x70]),
+    (0x1D57, Some("super"), &[0x74]),
+    (0x1D58, Some("super"), &[0x75]),
+    (0x1D59, Some("super"), &[0x1D1D]),
+    (0x1D5A, Some("super"), &[0x26F]),
+    (0x1D5B, Some("super"), &[0x76]),
+    (0x1D5C, Some("super"), &[0x1D25]),
+    (0x1D5D, Some("super"), &[0x3B2]),
+    (0x1D5E, Some("super"), &[0x3B3]),
+    (0x1D5F, Some("super"), &[0x3B4]),
+    (0x1D60, Some("super"), &[0x3C6]),
+    (0x1D61, Some("super"), &[0x3C7]),
+    (0x1D62, Some("sub"), &[0x69]),
+    (0x1D63, Some("sub"), &[0x72]),
+    (0x1D64, Some("sub"), &[0x75]),
+    (0x1D65, Some("sub"), &[0x76]),
+    (0x1D66, Some("sub"), &[0x3B2]),
+    (0x1D67, Some("sub"), &[0x3B3]),
+    (0x1D68, Some("sub"), &[0x3C1]),
+    (0x1D69, Some("sub"), &[0x3C6]),
+    (0x1D6A, Some("sub"), &[0x3C7]),
+    (0x1D78, Some("super"), &[0x43D]),
+    (0x1D9B, Some("super"), &[0x252]),
+    (0x1D9C, Some("super"), &[0x63]),
+    (0x1D9D, Some("super"), &[0x255]),
+    (0x1D9E, Some("super"), &[0xF0]),
+    (0x1D9F, Some("super"), &[0x25C]),
+    (0x1DA0, Some("super"), &[0x66]),
+    (0x1DA1, Some("super"), &[0x25F]),
+    (0x1DA2, Some("super"), &[0x261]),
+    (0x1DA3, Some("super"), &[0x265]),
+    (0x1DA4, Some("super"), &[0x268]),
+    (0x1DA5, Some("super"), &[0x269]),
+    (0x1DA6, Some("super"), &[0x26A]),
+    (0x1DA7, Some("super"), &[0x1D7B]),
+    (0x1DA8, Some("super"), &[0x29D]),
+    (0x1DA9, Some("super"), &[0x26D]),
+    (0x1DAA, Some("super"), &[0x1D85]),
+    (0x1DAB, Some("super"), &[0x29F]),
+    (0x1DAC, Some("super"), &[0x271]),
+    (0x1DAD, Some("super"), &[0x270]),
+    (0x1DAE, Some("super"), &[0x272]),
+    (0x1DAF, Some("super"), &[0x273]),
+    (0x1DB0, Some("super"), &[0x274]),
+    (0x1DB1, Some("super"), &[0x275]),
+    (0x1DB2, Some("super"), &[0x278]),
+    (0x1DB3, Some("super"), &[0x282]),
+    (0x1DB4, Some("super"), &[0x283]),
+    (0x1DB5, Some("super"), &[0x1AB]),
+    (0x1DB6, Some("super"), &[0x289]),
+    (0x1DB7, Some("super"), &[0x28A]),
+    (0x1DB8, Some("super"), &[0x1D1C]),
+    (0x1DB9, Some("super"), &[0x28B]),
+    (0x1DBA, Some("super"), &[0x28C]),
+    (0x1DBB, Some("super"), &[0x7A]),
+    (0x1DBC, Some("super"), &[0x290]),
+    (0x1DBD, Some("super"), &[0x291]),
+    (0x1DBE, Some("super"), &[0x292]),
+    (0x1DBF, Some("super"), &[0x3B8]),
+    (0x1E00, None, &[0x41, 0x325]),
+    (0x1E01, None, &[0x61, 0x325]),
+    (0x1E02, None, &[0x42, 0x307]),
+    (0x1E03, None, &[0x62, 0x307]),
+    (0x1E04, None, &[0x42, 0x323]),
+    (0x1E05, None, &[0x62, 0x323]),
+    (0x1E06, None, &[0x42, 0x331]),
+    (0x1E07, None, &[0x62, 0x331]),
+    (0x1E08, None, &[0xC7, 0x301]),
+    (0x1E09, None, &[0xE7, 0x301]),
+    (0x1E0A, None, &[0x44, 0x307]),
+    (0x1E0B, None, &[0x64, 0x307]),
+    (0x1E0C, None, &[0x44, 0x323]),
+    (0x1E0D, None, &[0x64, 0x323]),
+    (0x1E0E, None, &[0x44, 0x331]),
+    (0x1E0F, None, &[0x64, 0x331]),
+    (0x1E10, None, &[0x44, 0x327]),
+    (0x1E11, None, &[0x64, 0x327]),
+    (0x1E12, None, &[0x44, 0x32D]),
+    (0x1E13, None, &[0x64, 0x32D]),
+    (0x1E14, None, &[0x112, 0x300]),
+    (0x1E15, None, &[0x113, 0x300]),
+    (0x1E16, None, &[0x112, 0x301]),
+    (0x1E17, None, &[0x113, 0x301]),
+    (0x1E18, None, &[0x45, 0x32D]),
+    (0x1E19, None, &[0x65, 0x32D]),
+    (0x1E1A, None, &[0x45, 0x330]),
+    (0x1E1B, None, &[0x65, 0x330]),
+    (0x1E1C, None, &[0x228, 0x306]),
+    (0x1E1D, None, &[0x229, 0x306]),
+    (0x1E1E, None, &[0x46, 0x307]),
+    (0x1E1F, None, &[0x66, 0x307]),
+    (0x1E20, None, &[0x47, 0x304]),
+    (0x1E21, None, &[0x67, 0x304]),
+    (0x1E22, None, &[0x48, 0x307]),
+    (0x1E23, None, &[0x68, 0x307]),
+    (0x1E24, None, &[0x48, 0x323]),
+    (0x1E25, None, &[0x68, 0x323]),
+    (0x1E26, None, &[0x48, 0x308]),
+    (0x1E27, None, &[0x68, 0x308]),
+    (0x1E28, None, &[0x48, 0x327]),
+    (0x1E29, None, &[0x68, 0x327]),
+    (0x1E2A, None, &[0x48, 0x32E]),
+    (0x1E2B, None, &[0x68, 0x32E]),
+    (0x1E2C, None, &[0x49, 0x330]),
+    (0x1E2D, None, &[0x69, 0x330]),
+    (0x1E2E, None, &[0xCF, 0x301]),
+    (0x1E2F, None, &[0xEF, 0x301]),
+    (0x1E30, None, &[0x4B, 0x301]),
+    (0x1E31, None, &[0x6B, 0x301]),
+    (0x1E32, None, &[0x4B, 0x323]),
+    (0x1E33, None, &[0x6B, 0x323]),
+    (0x1E34, None, &[0x4B, 0x331]),
+    (0x1E35, None, &[0x6B, 0x331]),
+    (0x1E36, None, &[0x4C, 0x323]),
+    (0x1E37, None, &[0x6C, 0x323]),
+    (0x1E38, None, &[0x1E36, 0x304]),
+    (0x1E39, None, &[0x1E37, 0x304]),
+    (0x1E3A, None, &[0x4C, 0x331]),
+    (0x1E3B, None, &[0x6C, 0x331]),
+    (0x1E3C, None, &[0x4C, 0x32D]),
+    (0x1E3D, None, &[0x6C, 0x32D]),
+    (0x1E3E, None, &[0x4D, 0x301]),
+    (0x1E3F, None, &[0x6D, 0x301]),
+    (0x1E40, None, &[0x4D, 0x307]),
+    (0x1E41, None, &[0x6D, 0x307]),
+    (0x1E42, None, &[0x4D, 0x323]),
+    (0x1E43, None, &[0x6D, 0x323]),
+    (0x1E44, None, &[0x4E, 0x307]),
+    (0x1E45, None, &[0x6E, 0x307]),
+    (0x1E46, None, &[0x4E, 0x323]),
+    (0x1E47, None, &[0x6E, 0x323]),
+    (0x1E48, None, &[0x4E, 0x331]),
+    (0x1E49, None, &[0x6E, 0x331]),
+    (0x1E4A, None, &[0x4E, 0x32D]),
+    (0x1E4B, None, &[0x6E, 0x32D]),
+    (0x1E4C, None, &[0xD5, 0x301]),
+    (0x1E4D, None, &[0xF5, 0x301]),
+    (0x1E4E, None, &[0xD5, 0x308]),
+    (0x1E4F, None, &[0xF5, 0x308]),
+    (0x1E50, None, &[0x14C, 0x300]),
+    (0x1E51, None, &[0x14D, 0x300]),
+    (0x1E52, None, &[0x14C, 0x301]),
+    (0x1E53, None, &[0x14D, 0x301]),
+    (0x1E54, None, &[0x50, 0x301]),
+    (0x1E55, None, &[0x70, 0x301]),
+    (0x1E56, None, &[0x50, 0x307]),
+    (0x1E57, None, &[0x70, 0x307]),
+    (0x1E58, None, &[0x52, 0x307]),
+    (0x1E59, None, &[0x72, 0x307]),
+    (0x1E5A, None, &[0x52, 0x323]),
+    (0x1E5B, None, &[0x72, 0x323]),
+    (0x1E5C, None, &[0x1E5A, 0x304]),
+    (0x1E5D, None, &[0x1E5B, 0x304]),
+    (0x1E5E, None, &[0x52, 0x331]),
+    (0x1E5F, None, &[0x72, 0x331]),
+    (0x1E60, None, &[0x53, 0x307]),
+    (0x1E61, None, &[0x73, 0x307]),
+    (0x1E62, None, &[0x53, 0x323]),
+    (0x1E63, None, &[0x73, 0x323]),
+    (0x1E64, None, &[0x15A, 0x307]),
+    (0x1E65, None, &[0x15B, 0x307]),
+    (0x1E66, None, &[0x160, 0x307]),
+    (0x1E67, None, &[0x161, 0x307]),
+    (0x1E68, None, &[0x1E62, 0x307]),
+    (0x1E69, None, &[0x1E63, 0x307]),
+    (0x1E6A, None, &[0x54, 0x307]),
+    (0x1E6B, None, &[0x74, 0x307]),
+    (0x1E6C, None, &[0x54, 0x323]),
+    (0x1E6D, None, &[0x74, 0x323]),
+    (0x1E6E, None, &[0x54, 0x331]),
+    (0x1E6F, None, &[0x74, 0x331]),
+    (0x1E70, None, &[0x54, 0x32D]),
+    (0x1E71, None, &[0x74, 0x32D]),
+    (0x1E72, None, &[0x55, 0x324]),
+    (0x1E73, None, &[0x75, 0x324]),
+    (0x1E74, None, &[0x55, 0x330]),
+    (0x1E75, None, &[0x75, 0x330]),
+    (0x1E76, None, &[0x55, 0x32D]),
+    (0x1E77, None, &[0x75, 0x32D]),
+    (0x1E78, None, &[0x168, 0x301]),
+    (0x1E79, None, &[0x169, 0x301]),
+    (0x1E7A, None, &[0x16A, 0x308]),
+    (0x1E7B, None, &[0x16B, 0x308]),
+    (0x1E7C, None, &[0x56, 0x303]),
+    (0x1E7D, None, &[0x76, 0x303]),
+    (0x1E7E, None, &[0x56, 0x323]),
+    (0x1E7F, None, &[0x76, 0x323]),
+    (0x1E80, None, &[0x57, 0x300]),
+    (0x1E81, None, &[0x77, 0x300]),
+    (0x1E82, None, &[0x57, 0x301]),
+    (0x1E83, None, &[0x77, 0x301]),
+    (0x1E84, None, &[0x57, 0x308]),
+    (0x1E85, None, &[0x77, 0x308]),
+    (0x1E86, None, &[0x57, 0x307]),
+    (0x1E87, None, &[0x77, 0x307]),
+    (0x1E88, None, &[0x57, 0x323]),
+    (0x1E89, None, &[0x77, 0x323]),
+    (0x1E8A, None, &[0x58, 0x307]),
+    (0x1E8B, None, &[0x78, 0x307]),
+    (0x1E8C, None, &[0x58, 0x308]),
+    (0x1E8D, None, &[0x78, 0x308]),
+    (0x1E8E, None, &[0x59, 0x307]),
+    (0x1E8F, None, &[0x79, 0x307]),
+    (0x1E90, None, &[0x5A, 0x302]),
+    (0x1E91, None, &[0x7A, 0x302]),
+    (0x1E92, None, &[0x5A, 0x323]),
+    (0x1E93, None, &[0x7A, 0x323]),
+    (0x1E94, None, &[0x5A, 0x331]),
+    (0x1E95, None, &[0x7A, 0x331]),
+    (0x1E96, None, &[0x68, 0x331]),
+    (0x1E97, None, &[0x74, 0x308]),
+    (0x1E98, None, &[0x77, 0x30A]),
+    (0x1E99, None, &[0x79, 0x30A]),
+    (0x1E9A, Some("compat"), &[0x61, 0x2BE]),
+    (0x1E9B, None, &[0x17F, 0x307]),
+    (0x1EA0, None, &[0x41, 0x323]),
+    (0x1EA1, None, &[0x61, 0x323]),
+    (0x1EA2, None, &[0x41, 0x309]),
+    (0x1EA3, None, &[0x61, 0x309]),
+    (0x1EA4, None, &[0xC2, 0x301]),
+    (0x1EA5, None, &[0xE2, 0x301]),
+    (0x1EA6, None, &[0xC2, 0x300]),
+    (0x1EA7, None, &[0xE2, 0x300]),
+    (0x1EA8, None, &[0xC2, 0x309]),
+    (0x1EA9, None, &[0xE2, 0x309]),
+    (0x1EAA, None, &[0xC2, 0x303]),
+    (0x1EAB, None, &[0xE2, 0x303]),
+    (0x1EAC, None, &[0x1EA0, 0x302]),
+    (0x1EAD, None, &[0x1EA1, 0x302]),
+    (0x1EAE, None, &[0x102, 0x301]),
+    (0x1EAF, None, &[0x103, 0x301]),
+    (0x1EB0, None, &[0x102, 0x300]),
+    (0x1EB1, None, &[0x103, 0x300]),
+    (0x1EB2, None, &[0x102, 0x309]),
+    (0x1EB3, None, &[0x103, 0x309]),
+    (0x1EB4, None, &[0x102, 0x303]),
+    (0x1EB5, None, &[0x103, 0x303]),
+    (0x1EB6, None, &[0x1EA0, 0x306]),
+    (0x1EB7, None, &[0x1EA1, 0x306]),
+    (0x1EB8, None, &[0x45, 0x323]),
+    (0x1EB9, None, &[0x65, 0x323]),
+    (0x1EBA, None, &[0x45, 0x309]),
+    (0x1EBB, None, &[0x65, 0x309]),
+    (0x1EBC, None, &[0x45, 0x303]),
+    (0x1EBD, None, &[0x65, 0x303]),
+    (0x1EBE, None, &[0xCA, 0x301]),
+    (0x1EBF, None, &[0xEA, 0x301]),
+    (0x1EC0, None, &[0xCA, 0x300]),
+    (0x1EC1, None, &[0xEA, 0x300]),
+    (0x1EC2, None, &[0xCA, 0x309]),
+    (0x1EC3, None, &[0xEA, 0x309]),
+    (0x1EC4, None, &[0xCA, 0x303]),
+    (0x1EC5, None, &[0xEA, 0x303]),
+    (0x1EC6, None, &[0x1EB8, 0x302]),
+    (0x1EC7, None, &[0x1EB9, 0x302]),
+    (0x1EC8, None, &[0x49, 0x309]),
+    (0x1EC9, None, &[0x69, 0x309]),
+    (0x1ECA, None, &[0x49, 0x323]),
+    (0x1ECB, None, &[0x69, 0x323]),
+    (0x1ECC, None, &[0x4F, 0x323]),
+    (0x1ECD, None, &[0x6F, 0x323]),
+    (0x1ECE, None, &[0x4F, 0x309]),
+    (0x1ECF, None, &[0x6F, 0x309]),
+    (0x1ED0, None, &[0xD4, 0x301]),
+    (0x1ED1, None, &[0xF4, 0x301]),
+    (0x1ED2, None, &[0xD4, 0x300]),
+    (0x1ED3, None, &[0xF4, 0x300]),
+    (0x1ED4, None, &[0xD4, 0x309]),
+    (0x1ED5, None, &[0xF4, 0x309]),
+    (0x1ED6, None, &[0xD4, 0x303]),
+    (0x1ED7, None, &[0xF4, 0x303]),
+    (0x1ED8, None, &[0x1ECC, 0x302]),
+    (0x1ED9, None, &[0x1ECD, 0x302]),
+    (0x1EDA, None, &[0x1A0, 0x301]),
+    (0x1EDB, None, &[0x1A1, 0x301]),
+    (0x1EDC, None, &[0x1A0, 0x300]),
+    (0x1EDD, None, &[0x1A1, 0x300]),
+    (0x1EDE, None, &[0x1A0, 0x309]),
+    (0x1EDF, None, &[0x1A1, 0x309]),
+    (0x1EE0, None, &[0x1A0, 0x303]),
+    (0x1EE1, None, &[0x1A1, 0x303]),
+    (0x1EE2, None, &[0x1A0, 0x323]),
+    (0x1EE3, None, &[0x1A1, 0x323]),
+    (0x1EE4, None, &[0x55, 0x323]),
+    (0x1EE5, None, &[0x75, 0x323]),
+    (0x1EE6, None, &[0x55, 0x309]),
+    (0x1EE7, None, &[0x75, 0x309]),
+    (0x1EE8, None, &[0x1AF, 0x301]),
+    (0x1EE9, None, &[0x1B0, 0x301]),
+    (0x1EEA, None, &[0x1AF, 0x300]),
+    (0x1EEB, None, &[0x1B0, 0x300]),
+    (0x1EEC, None, &[0x1AF, 0x309]),
+    (0x1EED, None, &[0x1B0, 0x309]),
+    (0x1EEE, None, &[0x1AF, 0x303]),
+    (0x1EEF, None, &[0x1B0, 0x303]),
+    (0x1EF0, None, &[0x1AF, 0x323]),
+    (0x1EF1, None, &[0x1B0, 0x323]),
+    (0x1EF2, None, &[0x59, 0x300]),
+    (0x1EF3, None, &[0x79, 0x300]),
+    (0x1EF4, None, &[0x59, 0x323]),
+    (0x1EF5, None, &[0x79, 0x323]),
+    (0x1EF6, None, &[0x59, 0x309]),
+    (0x1EF7, None, &[0x79, 0x309]),
+    (0x1EF8, None, &[0x59, 0x303]),
+    (0x1EF9, None, &[0x79, 0x303]),
+    (0x1F00, None, &[0x3B1, 0x313]),
+    (0x1F01, None, &[0x3B1, 0x314]),
+    (0x1F02, None, &[0x1F00, 0x300]),
+    (0x1F03, None, &[0x1F01, 0x300]),
+    (0x1F04, None, &[0x1F00, 0x301]),
+    (0x1F05, None, &[0x1F01, 0x301]),
+    (0x1F06, None, &[0x1F00, 0x342]),
+    (0x1F07, None, &[0x1F01, 0x342]),
+    (0x1F08, None, &[0x391, 0x313]),
+    (0x1F09, None, &[0x391, 0x314]),
+    (0x1F0A, None, &[0x1F08, 0x300]),
+    (0x1F0B, None, &[0x1F09, 0x300]),
+    (0x1F0C, None, &[0x1F08, 0x301]),
+    (0x1F0D, None, &[0x1F09, 0x301]),
+    (0x1F0E, None, &[0x1F08, 0x342]),
+    (0x1F0F, None, &[0x1F09, 0x342]),
+    (0x1F10, None, &[0x3B5, 0x313]),
+    (0x1F11, None, &[0x3B5, 0x314]),
+    (0x1F12, None, &[0x1F10, 0x300]),
+    (0x1F13, None, &[0x1F11, 0x300]),
+    (0x1F14, None, &[0x1F10, 0x301]),
+    (0x1F15, None, &[0x1F11, 0x301]),
+    (0x1F18, None, &[0x395, 0x313]),
+    (0x1F19, None, &[0x395, 0x314]),
+    (0x1F1A, None, &[0x1F18, 0x300]),
+    (0x1F1B, None, &[0x1F19, 0x300]),
+    (0x1F1C, None, &[0x1F18, 0x301]),
+    (0x1F1D, None, &[0x1F19, 0x301]),
+    (0x1F20, None, &[0x3B7, 0x313]),
+    (0x1F21, None, &[0x3B7, 0x314]),
+    (0x1F22, None, &[0x1F20, 0x300]),
+    (0x1F23, None, &[0x1F21, 0x300]),
+    (0x1F24, None, &[0x1F20, 0x301]),
+    (0x1F25, None, &[0x1F21, 0x301]),
+    (0x1F26, None, &[0x1F20, 0x342]),
+    (0x1F27, None, &[0x1F21, 0x342]),
+    (0x1F28, None, &[0x397, 0x313]),
+    (0x1F29, None, &[0x397, 0x314]),
+    (0x1F2A, None, &[0x1F28, 0x300]),
+    (0x1F2B, None, &[0x1F29, 0x300]),
+    (0x1F2C, None, &[0x1F28, 0x301]),
+    (0x1F2D, None, &[0x1F29, 0x301]),
+    (0x1F2E, None, &[0x1F28, 0x342]),
+    (0x1F2F, None, &[0x1F29, 0x342]),
+    (0x1F30, None, &[0x3B9, 0x313]),
+    (0x1F31, None, &[0x3B9, 0x314]),
+    (0x1F32, None, &[0x1F30, 0x300]),
+    (0x1F33, None, &[0x1F31, 0x300]),
+    (0x1F34, None, &[0x1F30, 0x301]),
+    (0x1F35, None, &[0x1F31, 0x301]),
+    (0x1F36, None, &[0x1F30, 0x342]),
+    (0x1F37, None, &[0x1F31, 0x342]),
+    (0x1F38, None, &[0x399, 0x313]),
+    (0x1F39, None, &[0x399, 0x314]),
+    (0x1F3A, None, &[0x1F38, 0x300]),
+    (0x1F3B, None, &[0x1F39, 0x300]),
+    (0x1F3C, None, &[0x1F38, 0x301]),
+    (0x1F3D, None, &[0x1F39, 0x301]),
+    (0x1F3E, None, &[0x1F38, 0x342]),
+    (0x1F3F, None, &[0x1F39, 0x342]),
+    (0x1F40, None, &[0x3BF, 0x313]),
+    (0x1F41, None, &[0x3BF, 0x314]),
+    (0x1F42, None, &[0x1F40, 0x300]),
+    (0x1F43, None, &[0x1F41, 0x300]),
+    (0x1F44, None, &[0x1F40, 0x301]),
+    (0x1F45, None, &[0x1F41, 0x301]),
+    (0x1F48, None, &[0x39F, 0x313]),
+    (0x1F49, None, &[0x39F, 0x314]),
+    (0x1F4A, None, &[0x1F48, 0x300]),
+    (0x1F4B, None, &[0x1F49, 0x300]),
+    (0x1F4C, None, &[0x1F48, 0x301]),
+    (0x1F4D, None, &[0x1F49, 0x301]),
+    (0x1F50, None, &[0x3C5, 0x313]),
+    (0x1F51, None, &[0x3C5, 0x314]),
+    (0x1F52, None, &[0x1F50, 0x300]),
+    (0x1F53, None, &[0x1F51, 0x300]),
+    (0x1F54, None, &[0x1F50, 0x301]),
+    (0x1F55, None, &[0x1F51, 0x301]),
+    (0x1F56, None, &[0x1F50, 0x342]),
+    (0x1F57, None, &[0x1F51, 0x342]),
+    (0x1F59, None, &[0x3A5, 0x314]),
+    (0x1F5B, None, &[0x1F59, 0x300]),
+    (0x1F5D, None, &[0x1F59, 0x301]),
+    (0x1F5F, None, &[0x1F59, 0x342]),
+    (0x1F60, None, &[0x3C9, 0x313]),
+    (0x1F61, None, &[0x3C9, 0x314]),
+    (0x1F62, None, &[0x1F60, 0x300]),
+    (0x1F63, None, &[0x1F61, 0x300]),
+    (0x1F64, None, &[0x1F60, 0x301]),
+    (0x1F65, None, &[0x1F61, 0x301]),
+    (0x1F66, None, &[0x1F60, 0x342]),
+    (0x1F67, None, &[0x1F61, 0x342]),
+    (0x1F68, None, &[0x3A9, 0x313]),
+    (0x1F69, None, &[0x3A9, 0x314]),
+    (0x1F6A, None, &[0x1F68, 0x300]),
+    (0x1F6B, None, &[0x1F69, 0x300]),
+    (0x1F6C, None, &[0x1F68, 0x301]),
+    (0x1F6D, None, &[0x1F69, 0x301]),
+    (0x1F6E, None, &[0x1F68, 0x342]),
+    (0x1F6F, None, &[0x1F69, 0x342]),
+    (0x1F70, None, &[0x3B1, 0x300]),
+    (0x1F71, None, &[0x3AC]),
+    (0x1F72, None, &[0x3B5, 0x300]),
+    (0x1F73, None, &[0x3AD]),
+    (0x1F74, None, &[0x3B7, 0x300]),
+    (0x1F75, None, &[0x3AE]),
+    (0x1F76, None, &[0x3B9, 0x300]),
+    (0x1F77, None, &[0x3AF]),
+    (0x1F78, None, &[0x3BF, 0x300]),
+    (0x1F79, None, &[0x3CC]),
+    (0x1F7A, None, &[0x3C5, 0x300]),
+    (0x1F7B, None, &[0x3CD]),
+    (0x1F7C, None, &[0x3C9, 0x300]),
+    (0x1F7D, None, &[0x3CE]),
+    (0x1F80, None, &[0x1F00, 0x345]),
+    (0x1F81, None, &[0x1F01, 0x345]),
+    (0x1F82, None, &[0x1F02, 0x345]),
+    (0x1F83, None, &[0x1F03, 0x345]),
+    (0x1F84, None, &[0x1F04, 0x345]),
+    (0x1F85, None, &[0x1F05, 0x345]),
+    (0x1F86, None, &[0x1F06, 0x345]),
+    (0x1F87, None, &[0x1F07, 0x345]),
+    (0x1F88, None, &[0x1F08, 0x345]),
+    (0x1F89, None, &[0x1F09, 0x345]),
+    (0x1F8A, None, &[0x1F0A, 0x345]),
+    (0x1F8B, None, &[0x1F0B, 0x345]),
+    (0x1F8C, None, &[0x1F0C, 0x345]),
+    (0x1F8D, None, &[0x1F0D, 0x345]),
+    (0x1F8E, None, &[0x1F0E, 0x345]),
+    (0x1F8F, None, &[0x1F0F, 0x345]),
+    (0x1F90, None, &[0x1F20, 0x345]),
+    (0x1F91, None, &[0x1F21, 0x345]),
+    (0x1F92, None, &[0x1F22, 0x345]),
+    (0x1F93, None, &[0x1F23, 0x345]),
+    (0x1F94, None, &[0x1F24, 0x345]),
+    (0x1F95, None, &[0x1F25, 0x345]),
+    (0x1F96, None, &[0x1F26, 0x345]),
+    (0x1F97, None, &[0x1F27, 0x345]),
+    (0x1F98, None, &[0x1F28, 0x345]),
+    (0x1F99, None, &[0x1F29, 0x345]),
+    (0x1F9A, None, &[0x1F2A, 0x345]),
+    (0x1F9B, None, &[0x1F2B, 0x345]),
+    (0x1F9C, None, &[0x1F2C, 0x345]),
+    (0x1F9D, None, &[0x1F2D, 0x345]),
+    (0x1F9E, None, &[0x1F2E, 0x345]),
+    (0x1F9F, None, &[0x1F2F, 0x345]),
+    (0x1FA0, None, &[0x1F60, 0x345]),
+    (0x1FA1, None, &[0x1F61, 0x345]),
+    (0x1FA2, None, &[0x1F62, 0x345]),
+    (0x1FA3, None, &[0x1F63, 0x345]),
+    (0x1FA4, None, &[0x1F64, 0x345]),
+    (0x1FA5, None, &[0x1F65, 0x345]),
+    (0x1FA6, None, &[0x1F66, 0x345]),
+    (0x1FA7, None, &[0x1F67, 0x345]),
+    (0x1FA8, None, &[0x1F68, 0x345]),
+    (0x1FA9, None, &[0x1F69, 0x345]),
+    (0x1FAA, None, &[0x1F6A, 0x345]),
+    (0x1FAB, None, &[0x1F6B, 0x345]),
+    (0x1FAC, None, &[0x1F6C, 0x345]),
+    (0x1FAD, None, &[0x1F6D, 0x345]),
+    (0x1FAE, None, &[0x1F6E, 0x345]),
+    (0x1FAF, None, &[0x1F6F, 0x345]),
+    (0x1FB0, None, &[0x3B1, 0x306]),
+    (0x1FB1, None, &[0x3B1, 0x304]),
+    (0x1FB2, None, &[0x1F70, 0x345]),
+    (0x1FB3, None, &[0x3B1, 0x345]),
+    (0x1FB4, None, &[0x3AC, 0x345]),
+    (0x1FB6, None, &[0x3B1, 0x342]),
+    (0x1FB7, None, &[0x1FB6, 0x345]),
+    (0x1FB8, None, &[0x391, 0x306]),
+    (0x1FB9, None, &[0x391, 0x304]),
+    (0x1FBA, None, &[0x391, 0x300]),
+    (0x1FBB, None, &[0x386]),
+    (0x1FBC, None, &[0x391, 0x345]),
+    (0x1FBD, Some("compat"), &[0x20, 0x313]),
+    (0x1FBE, None, &[0x3B9]),
+    (0x1FBF, Some("compat"), &[0x20, 0x313]),
+    (0x1FC0, Some("compat"), &[0x20, 0x342]),
+    (0x1FC1, None, &[0xA8, 0x342]),
+    (0x1FC2, None, &[0x1F74, 0x345]),
+    (0x1FC3, None, &[0x3B7, 0x345]),
+    (0x1FC4, None, &[0x3AE, 0x345]),
+    (0x1FC6, None, &[0x3B7, 0x342]),
+    (0x1FC7, None, &[0x1FC6, 0x345]),
+    (0x1FC8, None, &[0x395, 0x300]),
+    (0x1FC9, None, &[0x388]),
+    (0x1FCA, None, &[0x397, 0x300]),
+    (0x1FCB, None, &[0x389]),
+    (0x1FCC, None, &[0x397, 0x345]),
+    (0x1FCD, None, &[0x1FBF, 0x300]),
+    (0x1FCE, None, &[0x1FBF, 0x301]),
+    (0x1FCF, None, &[0x1FBF, 0x342]),
+    (0x1FD0, None, &[0x3B9, 0x306]),
+    (0x1FD1, None, &[0x3B9, 0x304]),
+    (0x1FD2, None, &[0x3CA, 0x300]),
+    (0x1FD3, None, &[0x390]),
+    (0x1FD6, None, &[0x3B9, 0x342]),
+    (0x1FD7, None, &[0x3CA, 0x342]),
+    (0x1FD8, None, &[0x399, 0x306]),
+    (0x1FD9, None, &[0x399, 0x304]),
+    (0x1FDA, None, &[0x399, 0x300]),
+    (0x1FDB, None, &[0x38A]),
+    (0x1FDD, None, &[0x1FFE, 0x300]),
+    (0x1FDE, None, &[0x1FFE, 0x301]),
+    (0x1FDF, None, &[0x1FFE, 0x342]),
+    (0x1FE0, None, &[0x3C5, 0x306]),
+    (0x1FE1, None, &[0x3C5, 0x304]),
+    (0x1FE2, None, &[0x3CB, 0x300]),
+    (0x1FE3, None, &[0x3B0]),
+    (0x1FE4, None, &[0x3C1, 0x313]),
+    (0x1FE5, None, &[0x3C1, 0x314]),
+    (0x1FE6, None, &[0x3C5, 0x342]),
+    (0x1FE7, None, &[0x3CB, 0x342]),
+    (0x1FE8, None, &[0x3A5, 0x306]),
+    (0x1FE9, None, &[0x3A5, 0x304]),
+    (0x1FEA, None, &[0x3A5, 0x300]),
+    (0x1FEB, None, &[0x38E]),
+    (0x1FEC, None, &[0x3A1, 0x314]),
+    (0x1FED, None, &[0xA8, 0x300]),
+    (0x1FEE, None, &[0x385]),
+    (0x1FEF, None, &[0x60]),
+    (0x1FF2, None, &[0x1F7C, 0x345]),
+    (0x1FF3, None, &[0x3C9, 0x345]),
+    (0x1FF4, None, &[0x3CE, 0x345]),
+    (0x1FF6, None, &[0x3C9, 0x342]),
+    (0x1FF7, None, &[0x1FF6, 0x345]),
+    (0x1FF8, None, &[0x39F, 0x300]),
+    (0x1FF9, None, &[0x38C]),
+    (0x1FFA, None, &[0x3A9, 0x300]),
+    (0x1FFB, None, &[0x38F]),
+    (0x1FFC, None, &[0x3A9, 0x345]),
+    (0x1FFD, None, &[0xB4]),
+    (0x1FFE, Some("compat"), &[0x20, 0x314]),
+    (0x2000, None, &[0x2002]),
+    (0x2001, None, &[0x2003]),
+    (0x2002, Some("compat"), &[0x20]),
+    (0x2003, Some("compat"), &[0x20]),
+    (0x2004, Some("compat"), &[0x20]),
+    (0x2005, Some("compat"), &[0x20]),
+    (0x2006, Some("compat"), &[0x20]),
+    (0x2007, Some("noBreak"), &[0x20]),
+    (0x2008, Some("compat"), &[0x20]),
+    (0x2009, Some("compat"), &[0x20]),
+    (0x200A, Some("compat"), &[0x20]),
+    (0x2011, Some("noBreak"), &[0x2010]),
+    (0x2017, Some("compat"), &[0x20, 0x333]),
+    (0x2024, Some("compat"), &[0x2E]),
+    (0x2025, Some("compat"), &[0x2E, 0x2E]),
+    (0x2026, Some("compat"), &[0x2E, 0x2E, 0x2E]),
+    (0x202F, Some("noBreak"), &[0x20]),
+    (0x2033, Some("compat"), &[0x2032, 0x2032]),
+    (0x2034, Some("compat"), &[0x2032, 0x2032, 0x2032]),
+    (0x2036, Some("compat"), &[0x2035, 0x2035]),
+    (0x2037, Some("compat"), &[0x2035, 0x2035, 0x2035]),
+    (0x203C, Some("compat"), &[0x21, 0x21]),
+    (0x203E, Some("compat"), &[0x20, 0x305]),
+    (0x2047, Some("compat"), &[0x3F, 0x3F]),
+    (0x2048, Some("compat"), &[0x3F, 0x21]),
+    (0x2049, Some("compat"), &[0x21, 0x3F]),
+    (0x2057, Some("compat"), &[0x2032, 0x2032, 0x2032, 0x2032]),
+    (0x205F, Some("compat"), &[0x20]),
+    (0x2070, Some("super"), &[0x30]),
+    (0x2071, Some("super"), &[0x69]),
+    (0x2074, Some("super"), &[0x34]),
+    (0x2075, Some("super"), &[0x35]),
+    (0x2076, Some("super"), &[0x36]),
+    (0x2077, Some("super"), &[0x37]),
+    (0x2078, Some("super"), &[0x38]),
+    (0x2079, Some("super"), &[0x39]),
+    (0x207A, Some("super"), &[0x2B]),
+    (0x207B, Some("super"), &[0x2212]),
+    (0x207C, Some("super"), &[0x3D]),
+    (0x207D, Some("super"), &[0x28]),
+    (0x207E, Some("super"), &[0x29]),
+    (0x207F, Some("super"), &[0x6E]),
+    (0x2080, Some("sub"), &[0x30]),
+    (0x2081, Some("sub"), &[0x31]),
+    (0x2082, Some("sub"), &[0x32]),
+    (0x2083, Some("sub"), &[0x33]),
+    (0x2084, Some("sub"), &[0x34]),
+    (0x2085, Some("sub"), &[0x35]),
+    (0x2086, Some("sub"), &[0x36]),
+    (0x2087, Some("sub"), &[0x37]),
+    (0x2088, Some("sub"), &[0x38]),
+    (0x2089, Some("sub"), &[0x39]),
+    (0x208A, Some("sub"), &[0x2B]),
+    (0x208B, Some("sub"), &[0x2212]),
+    (0x208C, Some("sub"), &[0x3D]),
+    (0x208D, Some("sub"), &[0x28]),
+    (0x208E, Some("sub"), &[0x29]),
+    (0x2090, Some("sub"), &[0x61]),
+    (0x2091, Some("sub"), &[0x65]),
+    (0x2092, Some("sub"), &[0x6F]),
+    (0x2093, Some("sub"), &[0x78]),
+    (0x2094, Some("sub"), &[0x259]),
+    (0x2095, Some("sub"), &[0x68]),
+    (0x2096, Some("sub"), &[0x6B]),
+    (0x2097, Some("sub"), &[0x6C]),
+    (0x2098, Some("sub"), &[0x6D]),
+    (0x2099, Some("sub"), &[0x6E]),
+    (0x209A, Some("sub"), &[0x70]),
+    (0x209B, Some("sub"), &[0x73]),
+    (0x209C, Some("sub"), &[0x74]),
+    (0x20A8, Some("compat"), &[0x52, 0x73]),
+    (0x2100, Some("compat"), &[0x61, 0x2F, 0x63]),
+    (0x2101, Some("compat"), &[0x61, 0x2F, 0x73]),
+    (0x2102, Some("font"), &[0x43]),
+    (0x2103, Some("compat"), &[0xB0, 0x43]),
+    (0x2105, Some("compat"), &[0x63, 0x2F, 0x6F]),
+    (0x2106, Some("compat"), &[0x63, 0x2F, 0x75]),
+    (0x2107, Some("compat"), &[0x190]),
+    (0x2109, Some("compat"), &[0xB0, 0x46]),
+    (0x210A, Some("font"), &[0x67]),
+    (0x210B, Some("font"), &[0x48]),
+    (0x210C, Some("font"), &[0x48]),
+    (0x210D, Some("font"), &[0x48]),
+    (0x210E, Some("font"), &[0x68]),
+    (0x210F, Some("font"), &[0x127]),
+    (0x2110, Some("font"), &[0x49]),
+    (0x2111, Some("font"), &[0x49]),
+    (0x2112, Some("font"), &[0x4C]),
+    (0x2113, Some("font"), &[0x6C]),
+    (0x2115, Some("font"), &[0x4E]),
+    (0x2116, Some("compat"), &[0x4E, 0x6F]),
+    (0x2119, Some("font"), &[0x50]),
+    (0x211A, Some("font"), &[0x51]),
+    (0x211B, Some("font"), &[0x52]),
+    (0x211C, Some("font"), &[0x52]),
+    (0x211D, Some("font"), &[0x52]),
+    (0x2120, Some("super"), &[0x53, 0x4D]),
+    (0x2121, Some("compat"), &[0x54, 0x45, 0x4C]),
+    (0x2122, Some("super"), &[0x54, 0x4D]),
+    (0x2124, Some("font"), &[0x5A]),
+    (0x2126, None, &[0x3A9]),
+    (0x2128, Some("font"), &[0x5A]),
+    (0x212A, None, &[0x4B]),
+    (0x212B, None, &[0xC5]),
+    (0x212C, Some("font"), &[0x42]),
+    (0x212D, Some("font"), &[0x43]),
+    (0x212F, Some("font"), &[0x65]),
+    (0x2130, Some("font"), &[0x45]),
+    (0x2131, Some("font"), &[0x46]),
+    (0x2133, Some("font"), &[0x4D]),
+    (0x2134, Some("font"), &[0x6F]),
+    (0x2135, Some("compat"), &[0x5D0]),
+    (0x2136, Some("compat"), &[0x5D1]),
+    (0x2137, Some("compat"), &[0x5D2]),
+    (0x2138, Some("compat"), &[0x5D3]),
+    (0x2139, Some("font"), &[0x69]),
+    (0x213B, Some("compat"), &[0x46, 0x41, 0x58]),
+    (0x213C, Some("font"), &[0x3C0]),
+    (0x213D, Some("font"), &[0x3B3]),
+    (0x213E, Some("font"), &[0x393]),
+    (0x213F, Some("font"), &[0x3A0]),
+    (0x2140, Some("font"), &[0x2211]),
+    (0x2145, Some("font"), &[0x44]),
+    (0x2146, Some("font"), &[0x64]),
+    (0x2147, Some("font"), &[0x65]),
+    (0x2148, Some("font"), &[0x69]),
+    (0x2149, Some("font"), &[0x6A]),
+    (0x2150, Some("fraction"), &[0x31, 0x2044, 0x37]),
+    (0x2151, Some("fraction"), &[0x31, 0x2044, 0x39]),
+    (0x2152, Some("fraction"), &[0x31, 0x2044, 0x31, 0x30]),
+    (0x2153, Some("fraction"), &[0x31, 0x2044, 0x33]),
+    (0x2154, Some("fraction"), &[0x32, 0x2044, 0x33]),
+    (0x2155, Some("fraction"), &[0x31, 0x2044, 0x35]),
+    (0x2156, Some("fraction"), &[0x32, 0x2044, 0x35]),
+    (0x2157, Some("fraction"), &[0x33, 0x2044, 0x35]),
+    (0x2158, Some("fraction"), &[0x34, 0x2044, 0x35]),
+    (0x2159, Some("fraction"), &[0x31, 0x2044, 0x36]),
+    (0x215A, Some("fraction"), &[0x35, 0x2044, 0x36]),
+    (0x215B, Some("fraction"), &[0x31, 0x2044, 0x38]),
+    (0x215C, Some("fraction"), &[0x33, 0x2044, 0x38]),
+    (0x215D, Some("fraction"), &[0x35, 0x2044, 0x38]),
+    (0x215E, Some("fraction"), &[0x37, 0x2044, 0x38]),
+    (0x215F, Some("fraction"), &[0x31, 0x2044]),
+    (0x2160, Some("compat"), &[0x49]),
+    (0x2161, Some("compat"), &[0x49, 0x49]),
+    (0x2162, Some("compat"), &[0x49, 0x49, 0x49]),
+    (0x2163, Some("compat"), &[0x49, 0x56]),
+    (0x2164, Some("compat"), &[0x56]),
+    (0x2165, Some("compat"), &[0x56, 0x49]),
+    (0x2166, Some("compat"), &[0x56, 0x49, 0x49]),
+    (0x2167, Some("compat"), &[0x56, 0x49, 0x49, 0x49]),
+    (0x2168, Some("compat"), &[0x49, 0x58]),
+    (0x2169, Some("compat"), &[0x58]),
+    (0x216A, Some("compat"), &[0x58, 0x49]),
+    (0x216B, Some("compat"), &[0x58, 0x49, 0x49]),
+    (0x216C, Some("compat"), &[0x4C]),
+    (0x216D, Some("compat"), &[0x43]),
+    (0x216E, Some("compat"), &[0x44]),
+    (0x216F, Some("compat"), &[0x4D]),
+    (0x2170, Some("compat"), &[0x69]),
+    (0x2171, Some("compat"), &[0x69, 0x69]),
+    (0x2172, Some("compat"), &[0x69, 0x69, 0x69]),
+    (0x2173, Some("compat"), &[0x69, 0x76]),
+    (0x2174, Some("compat"), &[0x76]),
+    (0x2175, Some("compat"), &[0x76, 0x69]),
+    (0x2176, Some("compat"), &[0x76, 0x69, 0x69]),
+    (0x2177, Some("compat"), &[0x76, 0x69, 0x69, 0x69]),
+    (0x2178, Some("compat"), &[0x69, 0x78]),
+    (0x2179, Some("compat"), &[0x78]),
+    (0x217A, Some("compat"), &[0x78, 0x69]),
+    (0x217B, Some("compat"), &[0x78, 0x69, 0x69]),
+    (0x217C, Some("compat"), &[0x6C]),
+    (0x217D, Some("compat"), &[0x63]),
+    (0x217E, Some("compat"), &[0x64]),
+    (0x217F, Some("compat"), &[0x6D]),
+    (0x2189, Some("fraction"), &[0x30, 0x2044, 0x33]),
+    (0x219A, None, &[0x2190, 0x338]),
+    (0x219B, None, &[0x2192, 0x338]),
+    (0x21AE, None, &[0x2194, 0x338]),
+    (0x21CD, None, &[0x21D0, 0x338]),
+    (0x21CE, None, &[0x21D4, 0x338]),
+    (0x21CF, None, &[0x21D2, 0x338]),
+    (0x2204, None, &[0x2203, 0x338]),
+    (0x2209, None, &[0x2208, 0x338]),
+    (0x220C, None, &[0x220B, 0x338]),
+    (0x2224, None, &[0x2223, 0x338]),
+    (0x2226, None, &[0x2225, 0x338]),
+    (0x222C, Some("compat"), &[0x222B, 0x222B]),
+    (0x222D, Some("compat"), &[0x222B, 0x222B, 0x222B]),
+    (0x222F, Some("compat"), &[0x222E, 0x222E]),
+    (0x2230, Some("compat"), &[0x222E, 0x222E, 0x222E]),
+    (0x2241, None, &[0x223C, 0x338]),
+    (0x2244, None, &[0x2243, 0x338]),
+    (0x2247, None, &[0x2245, 0x338]),
+    (0x2249, None, &[0x2248, 0x338]),
+    (0x2260, None, &[0x3D, 0x338]),
+    (0x2262, None, &[0x2261, 0x338]),
+    (0x226D, None, &[0x224D, 0x338]),
+    (0x226E, None, &[0x3C, 0x338]),
+    (0x226F, None, &[0x3E, 0x338]),
+    (0x2270, None, &[0x2264, 0x338]),
+    (0x2271, None, &[0x2265, 0x338]),
+    (0x2274, None, &[0x2272, 0x338]),
+    (0x2275, None, &[0x2273, 0x338]),
+    (0x2278, None, &[0x2276, 0x338]),
+    (0x2279, None, &[0x2277, 0x338]),
+    (0x2280, None, &[0x227A, 0x338]),
+    (0x2281, None, &[0x227B, 0x338]),
+    (0x2284, None, &[0x2282, 0x338]),
+    (0x2285, None, &[0x2283, 0x338]),
+    (0x2288, None, &[0x2286, 0x338]),
+    (0x2289, None, &[0x2287, 0x338]),
+    (0x22AC, None, &[0x22A2, 0x338]),
+    (0x22AD, None, &[0x22A8, 0x338]),
+    (0x22AE, None, &[0x22A9, 0x338]),
+    (0x22AF, None, &[0x22AB, 0x338]),
+    (0x22E0, None, &[0x227C, 0x338]),
+    (0x22E1, None, &[0x227D, 0x338]),
+    (0x22E2, None, &[0x2291, 0x338]),
+    (0x22E3, None, &[0x2292, 0x338]),
+    (0x22EA, None, &[0x22B2, 0x338]),
+    (0x22EB, None, &[0x22B3, 0x338]),
+    (0x22EC, None, &[0x22B4, 0x338]),
+    (0x22ED, None, &[0x22B5, 0x338]),
+    (0x2329, None, &[0x3008]),
+    (0x232A, None, &[0x3009]),
+    (0x2460, Some("circle"), &[0x31]),
+    (0x2461, Some("circle"), &[0x32]),
+    (0x2462, Some("circle"), &[0x33]),
+    (0x2463, Some("circle"), &[0x34]),
+    (0x2464, Some("circle"), &[0x35]),
+    (0x2465, Some("circle"), &[0x36]),
+    (0x2466, Some("circle"), &[0x37]),
+    (0x2467, Some("circle"), &[0x38]),
+    (0x2468, Some("circle"), &[0x39]),
+    (0x2469, Some("circle"), &[0x31, 0x30]),
+    (0x246A, Some("circle"), &[0x31, 0x31]),
+    (0x246B, Some("circle"), &[0x31, 0x32]),
+    (0x246C, Some("circle"), &[0x31, 0x33]),
+    (0x246D, Some("circle"), &[0x31, 0x34]),
+    (0x246E, Some("circle"), &[0x31, 0x35]),
+    (0x246F, Some("circle"), &[0x31, 0x36]),
+    (0x2470, Some("circle"), &[0x31, 0x37]),
+    (0x2471, Some("circle"), &[0x31, 0x38]),
+    (0x2472, Some("circle"), &[0x31, 0x39]),
+    (0x2473, Some("circle"), &[0x32, 0x30]),
+    (0x2474, Some("compat"), &[0x28, 0x31, 0x29]),
+    (0x2475, Some("compat"), &[0x28, 0x32, 0x29]),
+    (0x2476, Some("compat"), &[0x28, 0x33, 0x29]),
+    (0x2477, Some("compat"), &[0x28, 0x34, 0x29]),
+    (0x2478, Some("compat"), &[0x28, 0x35, 0x29]),
+    (0x2479, Some("compat"), &[0x28, 0x36, 0x29]),
+    (0x247A, Some("compat"), &[0x28, 0x37, 0x29]),
+    (0x247B, Some("compat"), &[0x28, 0x38, 0x29]),
+    (0x247C, Some("compat"), &[0x28, 0x39, 0x29]),
+    (0x247D, Some("compat"), &[0x28, 0x31, 0x30, 0x29]),
+    (0x247E, Some("compat"), &[0x28, 0x31, 0x31, 0x29]),
+    (0x247F, Some("compat"), &[0x28, 0x31, 0x32, 0x29]),
+    (0x2480, Some("compat"), &[0x28, 0x31, 0x33, 0x29]),
+    (0x2481, Some("compat"), &[0x28, 0x31, 0x34, 0x29]),
+    (0x2482, Some("compat"), &[0x28, 0x31, 0x35, 0x29]),
+    (0x2483, Some("compat"), &[0x28, 0x31, 0x36, 0x29]),
+    (0x2484, Some("compat"), &[0x28, 0x31, 0x37, 0x29]),
+    (0x2485, Some("compat"), &[0x28, 0x31, 0x38, 0x29]),
+    (0x2486, Some("compat"), &[0x28, 0x31, 0x39, 0x29]),
+    (0x2487, Some("compat"), &[0x28, 0x32, 0x30, 0x29]),
+    (0x2488, Some("compat"), &[0x31, 0x2E]),
+    (0x2489, Some("compat"), &[0x32, 0x2E]),
+    (0x248A, Some("compat"), &[0x33, 0x2E]),
+    (0x248B, Some("compat"), &[0x34, 0x2E]),
+    (0x248C, Some("compat"), &[0x35, 0x2E]),
+    (0x248D, Some("compat"), &[0x36, 0x2E]),
+    (0x248E, Some("compat"), &[0x37, 0x2E]),
+    (0x248F, Some("compat"), &[0x38, 0x2E]),
+    (0x2490, Some("compat"), &[0x39, 0x2E]),
+    (0x2491, Some("compat"), &[0x31, 0x30, 0x2E]),
+    (0x2492, Some("compat"), &[0x31, 0x31, 0x2E]),
+    (0x2493, Some("compat"), &[0x31, 0x32, 0x2E]),
+    (0x2494, Some("compat"), &[0x31, 0x33, 0x2E]),
+    (0x2495, Some("compat"), &[0x31, 0x34, 0x2E]),
+    (0x2496, Some("compat"), &[0x31, 0x35, 0x2E]),
+    (0x2497, Some("compat"), &[0x31, 0x36, 0x2E]),
+    (0x2498, Some("compat"), &[0x31, 0x37, 0x2E]),
+    (0x2499, Some("compat"), &[0x31, 0x38, 0x2E]),
+    (0x249A, Some("compat"), &[0x31, 0x39, 0x2E]),
+    (0x249B, Some("compat"), &[0x32, 0x30, 0x2E]),
+    (0x249C, Some("compat"), &[0x28, 0x61, 0x29]),
+    (0x249D, Some("compat"), &[0x28, 0x62, 0x29]),
+    (0x249E, Some("compat"), &[0x28, 0x63, 0x29]),
+    (0x249F, Some("compat"), &[0x28, 0x64, 0x29]),
+    (0x24A0, Some("compat"), &[0x28, 0x65, 0x29]),
+    (0x24A1, Some("compat"), &[0x28, 0x66, 0x29]),
+    (0x24A2, Some("compat"), &[0x28, 0x67, 0x29]),
+    (0x24A3, Some("compat"), &[0x28, 0x68, 0x29]),
+    (0x24A4, Some("compat"), &[0x28, 0x69, 0x29]),
+    (0x24A5, Some("compat"), &[0x28, 0x6A, 0x29]),
+    (0x24A6, Some("compat"), &[0x28, 0x6B, 0x29]),
+    (0x24A7, Some("compat"), &[0x28, 0x6C, 0x29]),
+    (0x24A8, Some("compat"), &[0x28, 0x6D, 0x29]),
+    (0x24A9, Some("compat"), &[0x28, 0x6E, 0x29]),
+    (0x24AA, Some("compat"), &[0x28, 0x6F, 0x29]),
+    (0x24AB, Some("compat"), &[0x28, 0x70, 0x29]),
+    (0x24AC, Some("compat"), &[0x28, 0x71, 0x29]),
+    (0x24AD, Some("compat"), &[0x28, 0x72, 0x29]),
+    (0x24AE, Some("compat"), &[0x28, 0x73, 0x29]),
+    (0x24AF, Some("compat"), &[0x28, 0x74, 0x29]),
+    (0x24B0, Some("compat"), &[0x28, 0x75, 0x29]),
+    (0x24B1, Some("compat"), &[0x28, 0x76, 0x29]),
+    (0x24B2, Some("compat"), &[0x28, 0x77, 0x29]),
+    (0x24B3, Some("compat"), &[0x28, 0x78, 0x29]),
+    (0x24B4, Some("compat"), &[0x28, 0x79, 0x29]),
+    (0x24B5, Some("compat"), &[0x28, 0x7A, 0x29]),
+    (0x24B6, Some("circle"), &[0x41]),
+    (0x24B7, Some("circle"), &[0x42]),
+    (0x24B8, Some("circle"), &[0x43]),
+    (0x24B9, Some("circle"), &[0x44]),
+    (0x24BA, Some("circle"), &[0x45]),
+    (0x24BB, Some("circle"), &[0x46]),
+    (0x24BC, Some("circle"), &[0x47]),
+    (0x24BD, Some("circle"), &[0x48]),
+    (0x24BE, Some("circle"), &[0x49]),
+    (0x24BF, Some("circle"), &[0x4A]),
+    (0x24C0, Some("circle"), &[0x4B]),
+    (0x24C1, Some("circle"), &[0x4C]),
+    (0x24C2, Some("circle"), &[0x4D]),
+    (0x24C3, Some("circle"), &[0x4E]),
+    (0x24C4, Some("circle"), &[0x4F]),
+    (0x24C5, Some("circle"), &[0x50]),
+    (0x24C6, Some("circle"), &[0x51]),
+    (0x24C7, Some("circle"), &[0x52]),
+    (0x24C8, Some("circle"), &[0x53]),
+    (0x24C9, Some("circle"), &[0x54]),
+    (0x24CA, Some("circle"), &[0x55]),
+    (0x24CB, Some("circle"), &[0x56]),
+    (0x24CC, Some("circle"), &[0x57]),
+    (0x24CD, Some("circle"), &[0x58]),
+    (0x24CE, Some("circle"), &[0x59]),
+    (0x24CF, Some("circle"), &[0x5A]),
+    (0x24D0, Some("circle"), &[0x61]),
+    (0x24D1, Some("circle"), &[0x62]),
+    (0x24D2, Some("circle"), &[0x63]),
+    (0x24D3, Some("circle"), &[0x64]),
+    (0x24D4, Some("circle"), &[0x65]),
+    (0x24D5, Some("circle"), &[0x66]),
+    (0x24D6, Some("circle"), &[0x67]),
+    (0x24D7, Some("circle"), &[0x68]),
+    (0x24D8, Some("circle"), &[0x69]),
+    (0x24D9, Some("circle"), &[0x6A]),
+    (0x24DA, Some("circle"), &[0x6B]),
+    (0x24DB, Some("circle"), &[0x6C]),
+    (0x24DC, Some("circle"), &[0x6D]),
+    (0x24DD, Some("circle"), &[0x6E]),
+    (0x24DE, Some("circle"), &[0x6F]),
+    (0x24DF, Some("circle"), &[0x70]),
+    (0x24E0, Some("circle"), &[0x71]),
+    (0x24E1, Some("circle"), &[0x72]),
+    (0x24E2, Some("circle"), &[0x73]),
+    (0x24E3, Some("circle"), &[0x74]),
+    (0x24E4, Some("circle"), &[0x75]),
+    (0x24E5, Some("circle"), &[0x76]),
+    (0x24E6, Some("circle"), &[0x77]),
+    (0x24E7, Some("circle"), &[0x78]),
+    (0x24E8, Some("circle"), &[0x79]),
+    (0x24E9, Some("circle"), &[0x7A]),
+    (0x24EA, Some("circle"), &[0x30]),
+    (0x2A0C, Some("compat"), &[0x222B, 0x222B, 0x222B, 0x222B]),
+    (0x2A74, Some("compat"), &[0x3A, 0x3A, 0x3D]),
+    (0x2A75, Some("compat"), &[0x3D, 0x3D]),
+    (0x2A76, Some("compat"), &[0x3D, 0x3D, 0x3D]),
+    (0x2ADC, None, &[0x2ADD, 0x338]),
+    (0x2C7C, Some("sub"), &[0x6A]),
+    (0x2C7D, Some("super"), &[0x56]),
+    (0x2D6F, Some("super"), &[0x2D61]),
+    (0x2E9F, Some("compat"), &[0x6BCD]),
+    (0x2EF3, Some("compat"), &[0x9F9F]),
+    (0x2F00, Some("compat"), &[0x4E00]),
+    (0x2F01, Some("compat"), &[0x4E28]),
+    (0x2F02, Some("compat"), &[0x4E36]),
+    (0x2F03, Some("compat"), &[0x4E3F]),
+    (0x2F04, Some("compat"), &[0x4E59]),
+    (0x2F05, Some("compat"), &[0x4E85]),
+    (0x2F06, Some("compat"), &[0x4E8C]),
+    (0x2F07, Some("compat"), &[0x4EA0]),
+    (0x2F08, Some("compat"), &[0x4EBA]),
+    (0x2F09, Some("compat"), &[0x513F]),
+    (0x2F0A, Some("compat"), &[0x5165]),
+    (0x2F0B, Some("compat"), &[0x516B]),
+    (0x2F0C, Some("compat"), &[0x5182]),
+    (0x2F0D, Some("compat"), &[0x5196]),
+    (0x2F0E, Some("compat"), &[0x51AB]),
+    (0x2F0F, Some("compat"), &[0x51E0]),
+    (0x2F10, Some("compat"), &[0x51F5]),
+    (0x2F11, Some("compat"), &[0x5200]),
+    (0x2F12, Some("compat"), &[0x529B]),
+    (0x2F13, Some("compat"), &[0x52F9]),
+    (0x2F14, Some("compat"), &[0x5315]),
+    (0x2F15, Some("compat"), &[0x531A]),
+    (0x2F16, Some("compat"), &[0x5338]),
+    (0x2F17, Some("compat"), &[0x5341]),
+    (0x2F18, Some("compat"), &[0x535C]),
+    (0x2F19, Some("compat"), &[0x5369]),
+    (0x2F1A, Some("compat"), &[0x5382]),
+    (0x2F1B, Some("compat"), &[0x53B6]),
+    (0x2F1C, Some("compat"), &[0x53C8]),
+    (0x2F1D, Some("compat"), &[0x53E3]),
+    (0x2F1E, Some("compat"), &[0x56D7]),
+    (0x2F1F, Some("compat"), &[0x571F]),
+    (0x2F20, Some("compat"), &[0x58EB]),
+    (0x2F21, Some("compat"), &[0x5902]),
+    (0x2F22, Some("compat"), &[0x590A]),
+    (0x2F23, Some("compat"), &[0x5915]),
+    (0x2F24, Some("compat"), &[0x5927]),
+    (0x2F25, Some("compat"), &[0x5973]),
+    (0x2F26, Some("compat"), &[0x5B50]),
+    (0x2F27, Some("compat"), &[0x5B80]),
+    (0x2F28, Some("compat"), &[0x5BF8]),
+    (0x2F29, Some("compat"), &[0x5C0F]),
+    (0x2F2A, Some("compat"), &[0x5C22]),
+    (0x2F2B, Some("compat"), &[0x5C38]),
+    (0x2F2C, Some("compat"), &[0x5C6E]),
+    (0x2F2D, Some("compat"), &[0x5C71]),
+    (0x2F2E, Some("compat"), &[0x5DDB]),
+    (0x2F2F, Some("compat"), &[0x5DE5]),
+    (0x2F30, Some("compat"), &[0x5DF1]),
+    (0x2F31, Some("compat"), &[0x5DFE]),
+    (0x2F32, Some("compat"), &[0x5E72]),
+    (0x2F33, Some("compat"), &[0x5E7A]),
+    (0x2F34, Some("compat"), &[0x5E7F]),
+    (0x2F35, Some("compat"), &[0x5EF4]),
+    (0x2F36, Some("compat"), &[0x5EFE]),
+    (0x2F37, Some("compat"), &[0x5F0B]),
+    (0x2F38, Some("compat"), &[0x5F13]),
+    (0x2F39, Some("compat"), &[0x5F50]),
+    (0x2F3A, Some("compat"), &[0x5F61]),
+    (0x2F3B, Some("compat"), &[0x5F73]),
+    (0x2F3C, Some("compat"), &[0x5FC3]),
+    (0x2F3D, Some("compat"), &[0x6208]),
+    (0x2F3E, Some("compat"), &[0x6236]),
+    (0x2F3F, Some("compat"), &[0x624B]),
+    (0x2F40, Some("compat"), &[0x652F]),
+    (0x2F41, Some("compat"), &[0x6534]),
+    (0x2F42, Some("compat"), &[0x6587]),
+    (0x2F43, Some("compat"), &[0x6597]),
+    (0x2F44, Some("compat"), &[0x65A4]),
+    (0x2F45, Some("compat"), &[0x65B9]),
+    (0x2F46, Some("compat"), &[0x65E0]),
+    (0x2F47, Some("compat"), &[0x65E5]),
+    (0x2F48, Some("compat"), &[0x66F0]),
+    (0x2F49, Some("compat"), &[0x6708]),
+    (0x2F4A, Some("compat"), &[0x6728]),
+    (0x2F4B, Some("compat"), &[0x6B20]),
+    (0x2F4C, Some("compat"), &[0x6B62]),
+    (0x2F4D, Some("compat"), &[0x6B79]),
+    (0x2F4E, Some("compat"), &[0x6BB3]),
+    (0x2F4F, Some("compat"), &[0x6BCB]),
+    (0x2F50, Some("compat"), &[0x6BD4]),
+    (0x2F51, Some("compat"), &[0x6BDB]),
+    (0x2F52, Some("compat"), &[0x6C0F]),
+    (0x2F53, Some("compat"), &[0x6C14]),
+    (0x2F54, Some("compat"), &[0x6C34]),
+    (0x2F55, Some("compat"), &[0x706B]),
+    (0x2F56, Some("compat"), &[0x722A]),
+    (0x2F57, Some("compat"), &[0x7236]),
+    (0x2F58, Some("compat"), &[0x723B]),
+    (0x2F59, Some("compat"), &[0x723F]),
+    (0x2F5A, Some("compat"), &[0x7247]),
+    (0x2F5B, Some("compat"), &[0x7259]),
+    (0x2F5C, Some("compat"), &[0x725B]),
+    (0x2F5D, Some("compat"), &[0x72AC]),
+    (0x2F5E, Some("compat"), &[0x7384]),
+    (0x2F5F, Some("compat"), &[0x7389]),
+    (0x2F60, Some("compat"), &[0x74DC]),
+    (0x2F61, Some("compat"), &[0x74E6]),
+    (0x2F62, Some("compat"), &[0x7518]),
+    (0x2F63, Some("compat"), &[0x751F]),
+    (0x2F64, Some("compat"), &[0x7528]),
+    (0x2F65, Some("compat"), &[0x7530]),
+    (0x2F66, Some("compat"), &[0x758B]),
+    (0x2F67, Some("compat"), &[0x7592]),
+    (0x2F68, Some("compat"), &[0x7676]),
+    (0x2F69, Some("compat"), &[0x767D]),
+    (0x2F6A, Some("compat"), &[0x76AE]),
+    (0x2F6B, Some("compat"), &[0x76BF]),
+    (0x2F6C, Some("compat"), &[0x76EE]),
+    (0x2F6D, Some("compat"), &[0x77DB]),
+    (0x2F6E, Some("compat"), &[0x77E2]),
+    (0x2F6F, Some("compat"), &[0x77F3]),
+    (0x2F70, Some("compat"), &[0x793A]),
+    (0x2F71, Some("compat"), &[0x79B8]),
+    (0x2F72, Some("compat"), &[0x79BE]),
+    (0x2F73, Some("compat"), &[0x7A74]),
+    (0x2F74, Some("compat"), &[0x7ACB]),
+    (0x2F75, Some("compat"), &[0x7AF9]),
+    (0x2F76, Some("compat"), &[0x7C73]),
+    (0x2F77, Some("compat"), &[0x7CF8]),
+    (0x2F78, Some("compat"), &[0x7F36]),
+    (0x2F79, Some("compat"), &[0x7F51]),
+    (0x2F7A, Some("compat"), &[0x7F8A]),
+    (0x2F7B, Some("compat"), &[0x7FBD]),
+    (0x2F7C, Some("compat"), &[0x8001]),
+    (0x2F7D, Some("compat"), &[0x800C]),
+    (0x2F7E, Some("compat"), &[0x8012]),
+    (0x2F7F, Some("compat"), &[0x8033]),
+    (0x2F80, Some("compat"), &[0x807F]),
+    (0x2F81, Some("compat"), &[0x8089]),
+    (0x2F82, Some("compat"), &[0x81E3]),
+    (0x2F83, Some("compat"), &[0x81EA]),
+    (0x2F84, Some("compat"), &[0x81F3]),
+    (0x2F85, Some("compat"), &[0x81FC]),
+    (0x2F86, Some("compat"), &[0x820C]),
+    (0x2F87, Some("compat"), &[0x821B]),
+    (0x2F88, Some("compat"), &[0x821F]),
+    (0x2F89, Some("compat"), &[0x826E]),
+    (0x2F8A, Some("compat"), &[0x8272]),
+    (0x2F8B, Some("compat"), &[0x8278]),
+    (0x2F8C, Some("compat"), &[0x864D]),
+    (0x2F8D, Some("compat"), &[0x866B]),
+    (0x2F8E, Some("compat"), &[0x8840]),
+    (0x2F8F, Some("compat"), &[0x884C]),
+    (0x2F90, Some("compat"), &[0x8863]),
+    (0x2F91, Some("compat"), &[0x897E]),
+    (0x2F92, Some("compat"), &[0x898B]),
+    (0x2F93, Some("compat"), &[0x89D2]),
+    (0x2F94, Some("compat"), &[0x8A00]),
+    (0x2F95, Some("compat"), &[0x8C37]),
+    (0x2F96, Some("compat"), &[0x8C46]),
+    (0x2F97, Some("compat"), &[0x8C55]),
+    (0x2F98, Some("compat"), &[0x8C78]),
+    (0x2F99, Some("compat"), &[0x8C9D]),
+    (0x2F9A, Some("compat"), &[0x8D64]),
+    (0x2F9B, Some("compat"), &[0x8D70]),
+    (0x2F9C, Some("compat"), &[0x8DB3]),
+    (0x2F9D, Some("compat"), &[0x8EAB]),
+    (0x2F9E, Some("compat"), &[0x8ECA]),
+    (0x2F9F, Some("compat"), &[0x8F9B]),
+    (0x2FA0, Some("compat"), &[0x8FB0]),
+    (0x2FA1, Some("compat"), &[0x8FB5]),
+    (0x2FA2, Some("compat"), &[0x9091]),
+    (0x2FA3, Some("compat"), &[0x9149]),
+    (0x2FA4, Some("compat"), &[0x91C6]),
+    (0x2FA5, Some("compat"), &[0x91CC]),
+    (0x2FA6, Some("compat"), &[0x91D1]),
+    (0x2FA7, Some("compat"), &[0x9577]),
+    (0x2FA8, Some("compat"), &[0x9580]),
+    (0x2FA9, Some("compat"), &[0x961C]),
+    (0x2FAA, Some("compat"), &[0x96B6]),
+    (0x2FAB, Some("compat"), &[0x96B9]),
+    (0x2FAC, Some("compat"), &[0x96E8]),
+    (0x2FAD, Some("compat"), &[0x9751]),
+    (0x2FAE, Some("compat"), &[0x975E]),
+    (0x2FAF, Some("compat"), &[0x9762]),
+    (0x2FB0, Some("compat"), &[0x9769]),
+    (0x2FB1, Some("compat"), &[0x97CB]),
+    (0x2FB2, Some("compat"), &[0x97ED]),
+    (0x2FB3, Some("compat"), &[0x97F3]),
+    (0x2FB4, Some("compat"), &[0x9801]),
+    (0x2FB5, Some("compat"), &[0x98A8]),
+    (0x2FB6, Some("compat"), &[0x98DB]),
+    (0x2FB7, Some("compat"), &[0x98DF]),
+    (0x2FB8, Some("compat"), &[0x9996]),
+    (0x2FB9, Some("compat"), &[0x9999]),
+    (0x2FBA, Some("compat"), &[0x99AC]),
+    (0x2FBB, Some("compat"), &[0x9AA8]),
+    (0x2FBC, Some("compat"), &[0x9AD8]),
+    (0x2FBD, Some("compat"), &[0x9ADF]),
+    (0x2FBE, Some("compat"), &[0x9B25]),
+    (0x2FBF, Some("compat"), &[0x9B2F]),
+    (0x2FC0, Some("compat"), &[0x9B32]),
+    (0x2FC1, Some("compat"), &[0x9B3C]),
+    (0x2FC2, Some("compat"), &[0x9B5A]),
+    (0x2FC3, Some("compat"), &[0x9CE5]),
+    (0x2FC4, Some("compat"), &[0x9E75]),
+    (0x2FC5, Some("compat"), &[0x9E7F]),
+    (0x2FC6, Some("compat"), &[0x9EA5]),
+    (0x2FC7, Some("compat"), &[0x9EBB]),
+    (0x2FC8, Some("compat"), &[0x9EC3]),
+    (0x2FC9, Some("compat"), &[0x9ECD]),
+    (0x2FCA, Some("compat"), &[0x9ED1]),
+    (0x2FCB, Some("compat"), &[0x9EF9]),
+    (0x2FCC, Some("compat"), &[0x9EFD]),
+    (0x2FCD, Some("compat"), &[0x9F0E]),
+    (0x2FCE, Some("compat"), &[0x9F13]),
+    (0x2FCF, Some("compat"), &[0x9F20]),
+    (0x2FD0, Some("compat"), &[0x9F3B]),
+    (0x2FD1, Some("compat"), &[0x9F4A]),
+    (0x2FD2, Some("compat"), &[0x9F52]),
+    (0x2FD3, Some("compat"), &[0x9F8D]),
+    (0x2FD4, Some("compat"), &[0x9F9C]),
+    (0x2FD5, Some("compat"), &[0x9FA0]),
+    (0x3000, Some("wide"), &[0x20]),
+    (0x3036, Some("compat"), &[0x3012]),
+    (0x3038, Some("compat"), &[0x5341]),
+    (0x3039, Some("compat"), &[0x5344]),
+    (0x303A, Some("compat"), &[0x5345]),
+    (0x304C, None, &[0x304B, 0x3099]),
+    (0x304E, None, &[0x304D, 0x3099]),
+    (0x3050, None, &[0x304F, 0x3099]),
+    (0x3052, None, &[0x3051, 0x3099]),
+    (0x3054, None, &[0x3053, 0x3099]),
+    (0x3056, None, &[0x3055, 0x3099]),
+    (0x3058, None, &[0x3057, 0x3099]),
+    (0x305A, None, &[0x3059, 0x3099]),
+    (0x305C, None, &[0x305B, 0x3099]),
+    (0x305E, None, &[0x305D, 0x3099]),
+    (0x3060, None, &[0x305F, 0x3099]),
+    (0x3062, None, &[0x3061, 0x3099]),
+    (0x3065, None, &[0x3064, 0x3099]),
+    (0x3067, None, &[0x3066, 0x3099]),
+    (0x3069, None, &[0x3068, 0x3099]),
+    (0x3070, None, &[0x306F, 0x3099]),
+    (0x3071, None, &[0x306F, 0x309A]),
+    (0x3073, None, &[0x3072, 0x3099]),
+    (0x3074, None, &[0x3072, 0x309A]),
+    (0x3076, None, &[0x3075, 0x3099]),
+    (0x3077, None, &[0x3075, 0x309A]),
+    (0x3079, None, &[0x3078, 0x3099]),
+    (0x307A, None, &[0x3078, 0x309A]),
+    (0x307C, None, &[0x307B, 0x3099]),
+    (0x307D, None, &[0x307B, 0x309A]),
+    (0x3094, None, &[0x3046, 0x3099]),
+    (0x309B, Some("compat"), &[0x20, 0x3099]),
+    (0x309C, Some("compat"), &[0x20, 0x309A]),
+    (0x309E, None, &[0x309D, 0x3099]),
+    (0x309F, Some("vertical"), &[0x3088, 0x308A]),
+    (0x30AC, None, &[0x30AB, 0x3099]),
+    (0x30AE, None, &[0x30AD, 0x3099]),
+    (0x30B0, None, &[0x30AF, 0x3099]),
+    (0x30B2, None, &[0x30B1, 0x3099]),
+    (0x30B4, None, &[0x30B3, 0x3099]),
+    (0x30B6, None, &[0x30B5, 0x3099]),
+    (0x30B8, None, &[0x30B7, 0x3099]),
+    (0x30BA, None, &[0x30B9, 0x3099]),
+    (0x30BC, None, &[0x30BB, 0x3099]),
+    (0x30BE, None, &[0x30BD, 0x3099]),
+    (0x30C0, None, &[0x30BF, 0x3099]),
+    (0x30C2, None, &[0x30C1, 0x3099]),
+    (0x30C5, None, &[0x30C4, 0x3099]),
+    (0x30C7, None, &[0x30C6, 0x3099]),
+    (0x30C9, None, &[0x30C8, 0x3099]),
+    (0x30D0, None, &[0x30CF, 0x3099]),
+    (0x30D1, None, &[0x30CF, 0x309A]),
+    (0x30D3, None, &[0x30D2, 0x3099]),
+    (0x30D4, None, &[0x30D2, 0x309A]),
+    (0x30D6, None, &[0x30D5, 0x3099]),
+    (0x30D7, None, &[0x30D5, 0x309A]),
+    (0x30D9, None, &[0x30D8, 0x3099]),
+    (0x30DA, None, &[0x30D8, 0x309A]),
+    (0x30DC, None, &[0x30DB, 0x3099]),
+    (0x30DD, None, &[0x30DB, 0x309A]),
+    (0x30F4, None, &[0x30A6, 0x3099]),
+    (0x30F7, None, &[0x30EF, 0x3099]),
+    (0x30F8, None, &[0x30F0, 0x3099]),
+    (0x30F9, None, &[0x30F1, 0x3099]),
+    (0x30FA, None, &[0x30F2, 0x3099]),
+    (0x30FE, None, &[0x30FD, 0x3099]),
+    (0x30FF, Some("vertical"), &[0x30B3, 0x30C8]),
+    (0x3131, Some("compat"), &[0x1100]),
+    (0x3132, Some("compat"), &[0x1101]),
+    (0x3133, Some("compat"), &[0x11AA]),
+    (0x3134, Some("compat"), &[0x1102]),
+    (0x3135, Some("compat"), &[0x11AC]),
+    (0x3136, Some("compat"), &[0x11AD]),
+    (0x3137, Some("compat"), &[0x1103]),
+    (0x3138, Some("compat"), &[0x1104]),
+    (0x3139, Some("compat"), &[0x1105]),
+    (0x313A, Some("compat"), &[0x11B0]),
+    (0x313B, Some("compat"), &[0x11B1]),
+    (0x313C, Some("compat"), &[0x11B2]),
+    (0x313D, Some("compat"), &[0x11B3]),
+    (0x313E, Some("compat"), &[0x11B4]),
+    (0x313F, Some("compat"), &[0x11B5]),
+    (0x3140, Some("compat"), &[0x111A]),
+    (0x3141, Some("compat"), &[0x1106]),
+    (0x3142, Some("compat"), &[0x1107]),
+    (0x3143, Some("compat"), &[0x1108]),
+    (0x3144, Some("compat"), &[0x1121]),
+    (0x3145, Some("compat"), &[0x1109]),
+    (0x3146, Some("compat"), &[0x110A]),
+    (0x3147, Some("compat"), &[0x110B]),
+    (0x3148, Some("compat"), &[0x110C]),
+    (0x3149, Some("compat"), &[0x110D]),
+    (0x314A, Some("compat"), &[0x110E]),
+    (0x314B, Some("compat"), &[0x110F]),
+    (0x314C, Some("compat"), &[0x1110]),
+    (0x314D, Some("compat"), &[0x1111]),
+    (0x314E, Some("compat"), &[0x1112]),
+    (0x314F, Some("compat"), &[0x1161]),
+    (0x3150, Some("compat"), &[0x1162]),
+    (0x3151, Some("compat"), &[0x1163]),
+    (0x3152, Some("compat"), &[0x1164]),
+    (0x3153, Some("compat"), &[0x1165]),
+    (0x3154, Some("compat"), &[0x1166]),
+    (0x3155, Some("compat"), &[0x1167]),
+    (0x3156, Some("compat"), &[0x1168]),
+    (0x3157, Some("compat"), &[0x1169]),
+    (0x3158, Some("compat"), &[0x116A]),
+    (0x3159, Some("compat"), &[0x116B]),
+    (0x315A, Some("compat"), &[0x116C]),
+    (0x315B, Some("compat"), &[0x116D]),
+    (0x315C, Some("compat"), &[0x116E]),
+    (0x315D, Some("compat"), &[0x116F]),
+    (0x315E, Some("compat"), &[0x1170]),
+    (0x315F, Some("compat"), &[0x1171]),
+    (0x3160, Some("compat"), &[0x1172]),
+    (0x3161, Some("compat"), &[0x1173]),
+    (0x3162, Some("compat"), &[0x1174]),
+    (0x3163, Some("compat"), &[0x1175]),
+    (0x3164, Some("compat"), &[0x1160]),
+    (0x3165, Some("compat"), &[0x1114]),
+    (0x3166, Some("compat"), &[0x1115]),
+    (0x3167, Some("compat"), &[0x11C7]),
+    (0x3168, Some("compat"), &[0x11C8]),
+    (0x3169, Some("compat"), &[0x11CC]),
+    (0x316A, Some("compat"), &[0x11CE]),
+    (0x316B, Some("compat"), &[0x11D3]),
+    (0x316C, Some("compat"), &[0x11D7]),
+    (0x316D, Some("compat"), &[0x11D9]),
+    (0x316E, Some("compat"), &[0x111C]),
+    (0x316F, Some("compat"), &[0x11DD]),
+    (0x3170, Some("compat"), &[0x11DF]),
+    (0x3171, Some("compat"), &[0x111D]),
+    (0x3172, Some("compat"), &[0x111E]),
+    (0x3173, Some("compat"), &[0x1120]),
+    (0x3174, Some("compat"), &[0x1122]),
+    (0x3175, Some("compat"), &[0x1123]),
+    (0x3176, Some("compat"), &[0x1127]),
+    (0x3177, Some("compat"), &[0x1129]),
+    (0x3178, Some("compat"), &[0x112B]),
+    (0x3179, Some("compat"), &[0x112C]),
+    (0x317A, Some("compat"), &[0x112D]),
+    (0x317B, Some("compat"), &[0x112E]),
+    (0x317C, Some("compat"), &[0x112F]),
+    (0x317D, Some("compat"), &[0x1132]),
+    (0x317E, Some("compat"), &[0x1136]),
+    (0x317F, Some("compat"), &[0x1140]),
+    (0x3180, Some("compat"), &[0x1147]),
+    (0x3181, Some("compat"), &[0x114C]),
+    (0x3182, Some("compat"), &[0x11F1]),
+    (0x3183, Some("compat"), &[0x11F2]),
+    (0x3184, Some("compat"), &[0x1157]),
+    (0x3185, Some("compat"), &[0x1158]),
+    (0x3186, Some("compat"), &[0x1159]),
+    (0x3187, Some("compat"), &[0x1184]),
+    (0x3188, Some("compat"), &[0x1185]),
+    (0x3189, Some("compat"), &[0x1188]),
+    (0x318A, Some("compat"), &[0x1191]),
+    (0x318B, Some("compat"), &[0x1192]),
+    (0x318C, Some("compat"), &[0x1194]),
+    (0x318D, Some("compat"), &[0x119E]),
+    (0x318E, Some("compat"), &[0x11A1]),
+    (0x3192, Some("super"), &[0x4E00]),
+    (0x3193, Some("super"), &[0x4E8C]),
+    (0x3194, Some("super"), &[0x4E09]),
+    (0x3195, Some("super"), &[0x56DB]),
+    (0x3196, Some("super"), &[0x4E0A]),
+    (0x3197, Some("super"), &[0x4E2D]),
+    (0x3198, Some("super"), &[0x4E0B]),
+    (0x3199, Some("super"), &[0x7532]),
+    (0x319A, Some("super"), &[0x4E59]),
+    (0x319B, Some("super"), &[0x4E19]),
+    (0x319C, Some("super"), &[0x4E01]),
+    (0x319D, Some("super"), &[0x5929]),
+    (0x319E, Some("super"), &[0x5730]),
+    (0x319F, Some("super"), &[0x4EBA]),
+    (0x3200, Some("compat"), &[0x28, 0x1100, 0x29]),
+    (0x3201, Some("compat"), &[0x28, 0x1102, 0x29]),
+    (0x3202, Some("compat"), &[0x28, 0x1103, 0x29]),
+    (0x3203, Some("compat"), &[0x28, 0x1105, 0x29]),
+    (0x3204, Some("compat"), &[0x28, 0x1106, 0x29]),
+    (0x3205, Some("compat"), &[0x28, 0x1107, 0x29]),
+    (0x3206, Some("compat"), &[0x28, 0x1109, 0x29]),
+    (0x3207, Some("compat"), &[0x28, 0x110B, 0x29]),
+    (0x3208, Some("compat"), &[0x28, 0x110C, 0x29]),
+    (0x3209, Some("compat"), &[0x28, 0x110E, 0x29]),
+    (0x320A, Some("compat"), &[0x28, 0x110F, 0x29]),
+    (0x320B, Some("compat"), &[0x28, 0x1110, 0x29]),
+    (0x320C, Some("compat"), &[0x28, 0x1111, 0x29]),
+    (0x320D, Some("compat"), &[0x28, 0x1112, 0x29]),
+    (0x320E, Some("compat"), &[0x28, 0x1100, 0x1161, 0x29]),
+    (0x320F, Some("compat"), &[0x28, 0x1102, 0x1161, 0x29]),
+    (0x3210, Some("compat"), &[0x28, 0x1103, 0x1161, 0x29]),
+    (0x3211, Some("compat"), &[0x28, 0x1105, 0x1161, 0x29]),
+    (0x3212, Some("compat"), &[0x28, 0x1106, 0x1161, 0x29]),
+    (0x3213, Some("compat"), &[0x28, 0x1107, 0x1161, 0x29]),
+    (0x3214, Some("compat"), &[0x28, 0x1109, 0x1161, 0x29]),
+    (0x3215, Some("compat"), &[0x28, 0x110B, 0x1161, 0x29]),
+    (0x3216, Some("compat"), &[0x28, 0x110C, 0x1161, 0x29]),
+    (0x3217, Some("compat"), &[0x28, 0x110E, 0x1161, 0x29]),
+    (0x3218, Some("compat"), &[0x28, 0x110F, 0x1161, 0x29]),
+    (0x3219, Some("compat"), &[0x28, 0x1110, 0x1161, 0x29]),
+    (0x321A, Some("compat"), &[0x28, 0x1111, 0x1161, 0x29]),
+    (0x321B, Some("compat"), &[0x28, 0x1112, 0x1161, 0x29]),
+    (0x321C, Some("compat"), &[0x28, 0x110C, 0x116E, 0x29]),
+    (0x321D, Some("compat"), &[0x28, 0x110B, 0x1169, 0x110C, 0x1165, 0x11AB, 0x29]),
+    (0x321E, Some("compat"), &[0x28, 0x110B, 0x1169, 0x1112, 0x116E, 0x29]),
+    (0x3220, Some("compat"), &[0x28, 0x4E00, 0x29]),
+    (0x3221, Some("compat"), &[0x28, 0x4E8C, 0x29]),
+    (0x3222, Some("compat"), &[0x28, 0x4E09, 0x29]),
+    (0x3223, Some("compat"), &[0x28, 0x56DB, 0x29]),
+    (0x3224, Some("compat"), &[0x28, 0x4E94, 0x29]),
+    (0x3225, Some("compat"), &[0x28, 0x516D, 0x29]),
+    (0x3226, Some("compat"), &[0x28, 0x4E03, 0x29]),
+    (0x3227, Some("compat"), &[0x28, 0x516B, 0x29]),
+    (0x3228, Some("compat"), &[0x28, 0x4E5D, 0x29]),
+    (0x3229, Some("compat"), &[0x28, 0x5341, 0x29]),
+    (0x322A, Some("compat"), &[0x28, 0x6708, 0x29]),
+    (0x322B, Some("compat"), &[0x28, 0x706B, 0x29]),
+    (0x322C, Some("compat"), &[0x28, 0x6C34, 0x29]),
+    (0x322D, Some("compat"), &[0x28, 0x6728, 0x29]),
+    (0x322E, Some("compat"), &[0x28, 0x91D1, 0x29]),
+    (0x322F, Some("compat"), &[0x28, 0x571F, 0x29]),
+    (0x3230, Some("compat"), &[0x28, 0x65E5, 0x29]),
+    (0x3231, Some("compat"), &[0x28, 0x682A, 0x29]),
+    (0x3232, Some("compat"), &[0x28, 0x6709, 0x29]),
+    (0x3233, Some("compat"), &[0x28, 0x793E, 0x29]),
+    (0x3234, Some("compat"), &[0x28, 0x540D, 0x29]),
+    (0x3235, Some("compat"), &[0x28, 0x7279, 0x29]),
+    (0x3236, Some("compat"), &[0x28, 0x8CA1, 0x29]),
+    (0x3237, Some("compat"), &[0x28, 0x795D, 0x29]),
+    (0x3238, Some("compat"), &[0x28, 0x52B4, 0x29]),
+    (0x3239, Some("compat"), &[0x28, 0x4EE3, 0x29]),
+    (0x323A, Some("compat"), &[0x28, 0x547C, 0x29]),
+    (0x323B, Some("compat"), &[0x28, 0x5B66, 0x29]),
+    (0x323C, Some("compat"), &[0x28, 0x76E3, 0x29]),
+    (0x323D, Some("compat"), &[0x28, 0x4F01, 0x29]),
+    (0x323E, Some("compat"), &[0x28, 0x8CC7, 0x29]),
+    (0x323F, Some("compat"), &[0x28, 0x5354, 0x29]),
+    (0x3240, Some("compat"), &[0x28, 0x796D, 0x29]),
+    (0x3241, Some("compat"), &[0x28, 0x4F11, 0x29]),
+    (0x3242, Some("compat"), &[0x28, 0x81EA, 0x29]),
+    (0x3243, Some("compat"), &[0x28, 0x81F3, 0x29]),
+    (0x3244, Some("circle"), &[0x554F]),
+    (0x3245, Some("circle"), &[0x5E7C]),
+    (0x3246, Some("circle"), &[0x6587]),
+    (0x3247, Some("circle"), &[0x7B8F]),
+    (0x3250, Some("square"), &[0x50, 0x54, 0x45]),
+    (0x3251, Some("circle"), &[0x32, 0x31]),
+    (0x3252, Some("circle"), &[0x32, 0x32]),
+    (0x3253, Some("circle"), &[0x32, 0x33]),
+    (0x3254, Some("circle"), &[0x32, 0x34]),
+    (0x3255, Some("circle"), &[0x32, 0x35]),
+    (0x3256, Some("circle"), &[0x32, 0x36]),
+    (0x3257, Some("circle"), &[0x32, 0x37]),
+    (0x3258, Some("circle"), &[0x32, 0x38]),
+    (0x3259, Some("circle"), &[0x32, 0x39]),
+    (0x325A, Some("circle"), &[0x33, 0x30]),
+    (0x325B, Some("circle"), &[0x33, 0x31]),
+    (0x325C, Some("circle"), &[0x33, 0x32]),
+    (0x325D, Some("circle"), &[0x33, 0x33]),
+    (0x325E, Some("circle"), &[0x33, 0x34]),
+    (0x325F, Some("circle"), &[0x33, 0x35]),
+    (0x3260, Some("circle"), &[0x1100]),
+    (0x3261, Some("circle"), &[0x1102]),
+    (0x3262, Some("circle"), &[0x1103]),
+    (0x3263, Some("circle"), &[0x1105]),
+    (0x3264, Some("circle"), &[0x1106]),
+    (0x3265, Some("circle"), &[0x1107]),
+    (0x3266, Some("circle"), &[0x1109]),
+    (0x3267, Some("circle"), &[0x110B]),
+    (0x3268, Some("circle"), &[0x110C]),
+    (0x3269, Some("circle"), &[0x110E]),
+    (0x326A, Some("circle"), &[0x110F]),
+    (0x326B, Some("circle"), &[0x1110]),
+    (0x326C, Some("circle"), &[0x1111]),
+    (0x326D, Some("circle"), &[0x1112]),
+    (0x326E, Some("circle"), &[0x1100, 0x1161]),
+    (0x326F, Some("circle"), &[0x1102, 0x1161]),
+    (0x3270, Some("circle"), &[0x1103, 0x1161]),
+    (0x3271, Some("circle"), &[0x1105, 0x1161]),
+    (0x3272, Some("circle"), &[0x1106, 0x1161]),
+    (0x3273, Some("circle"), &[0x1107, 0x1161]),
+    (0x3274, Some("circle"), &[0x1109, 0x1161]),
+    (0x3275, Some("circle"), &[0x110B, 0x1161]),
+    (0x3276, Some("circle"), &[0x110C, 0x1161]),
+    (0x3277, Some("circle"), &[0x110E, 0x1161]),
+    (0x3278, Some("circle"), &[0x110F, 0x1161]),
+    (0x3279, Some("circle"), &[0x1110, 0x1161]),
+    (0x327A, Some("circle"), &[0x1111, 0x1161]),
+    (0x327B, Some("circle"), &[0x1112, 0x1161]),
+    (0x327C, Some("circle"), &[0x110E, 0x1161, 0x11B7, 0x1100, 0x1169]),
+    (0x327D, Some("circle"), &[0x110C, 0x116E, 0x110B, 0x1174]),
+    (0x327E, Some("circle"), &[0x110B, 0x116E]),
+    (0x3280, Some("circle"), &[0x4E00]),
+    (0x3281, Some("circle"), &[0x4E8C]),
+    (0x3282, Some("circle"), &[0x4E09]),
+    (0x3283, Some("circle"), &[0x56DB]),
+    (0x3284, Some("circle"), &[0x4E94]),
+    (0x3285, Some("circle"), &[0x516D]),
+    (0x3286, Some("circle"), &[0x4E03]),
+    (0x3287, Some("circle"), &[0x516B]),
+    (0x3288, Some("circle"), &[0x4E5D]),
+    (0x3289, Some("circle"), &[0x5341]),
+    (0x328A, Some("circle"), &[0x6708]),
+    (0x328B, Some("circle"), &[0x706B]),
+    (0x328C, Some("circle"), &[0x6C34]),
+    (0x328D, Some("circle"), &[0x6728]),
+    (0x328E, Some("circle"), &[0x91D1]),
+    (0x328F, Some("circle"), &[0x571F]),
+    (0x3290, Some("circle"), &[0x65E5]),
+    (0x3291, Some("circle"), &[0x682A]),
+    (0x3292, Some("circle"), &[0x6709]),
+    (0x3293, Some("circle"), &[0x793E]),
+    (0x3294, Some("circle"), &[0x540D]),
+    (0x3295, Some("circle"), &[0x7279]),
+    (0x3296, Some("circle"), &[0x8CA1]),
+    (0x3297, Some("circle"), &[0x795D]),
+    (0x3298, Some("circle"), &[0x52B4]),
+    (0x3299, Some("circle"), &[0x79D8]),
+    (0x329A, Some("circle"), &[0x7537]),
+    (0x329B, Some("circle"), &[0x5973]),
+    (0x329C, Some("circle"), &[0x9069]),
+    (0x329D, Some("circle"), &[0x512A]),
+    (0x329E, Some("circle"), &[0x5370]),
+    (0x329F, Some("circle"), &[0x6CE8]),
+    (0x32A0, Some("circle"), &[0x9805]),
+    (0x32A1, Some("circle"), &[0x4F11]),
+    (0x32A2, Some("circle"), &[0x5199]),
+    (0x32A3, Some("circle"), &[0x6B63]),
+    (0x32A4, Some("circle"), &[0x4E0A]),
+    (0x32A5, Some("circle"), &[0x4E2D]),
+    (0x32A6, Some("circle"), &[0x4E0B]),
+    (0x32A7, Some("circle"), &[0x5DE6]),
+    (0x32A8, Some("circle"), &[0x53F3]),
+    (0x32A9, Some("circle"), &[0x533B]),
+    (0x32AA, Some("circle"), &[0x5B97]),
+    (0x32AB, Some("circle"), &[0x5B66]),
+    (0x32AC, Some("circle"), &[0x76E3]),
+    (0x32AD, Some("circle"), &[0x4F01]),
+    (0x32AE, Some("circle"), &[0x8CC7]),
+    (0x32AF, Some("circle"), &[0x5354]),
+    (0x32B0, Some("circle"), &[0x591C]),
+    (0x32B1, Some("circle"), &[0x33, 0x36]),
+    (0x32B2, Some("circle"), &[0x33, 0x37]),
+    (0x32B3, Some("circle"), &[0x33, 0x38]),
+    (0x32B4, Some("circle"), &[0x33, 0x39]),
+    (0x32B5, Some("circle"), &[0x34, 0x30]),
+    (0x32B6, Some("circle"), &[0x34, 0x31]),
+    (0x32B7, Some("circle"), &[0x34, 0x32]),
+    (0x32B8, Some("circle"), &[0x34, 0x33]),
+    (0x32B9, Some("circle"), &[0x34, 0x34]),
+    (0x32BA, Some("circle"), &[0x34, 0x35]),
+    (0x32BB, Some("circle"), &[0x34, 0x36]),
+    (0x32BC, Some("circle"), &[0x34, 0x37]),
+    (0x32BD, Some("circle"), &[0x34, 0x38]),
+    (0x32BE, Some("circle"), &[0x34, 0x39]),
+    (0x32BF, Some("circle"), &[0x35, 0x30]),
+    (0x32C0, Some("compat"), &[0x31, 0x6708]),
+    (0x32C1, Some("compat"), &[0x32, 0x6708]),
+    (0x32C2, Some("compat"), &[0x33, 0x6708]),
+    (0x32C3, Some("compat"), &[0x34, 0x6708]),
+    (0x32C4, Some("compat"), &[0x35, 0x6708]),
+    (0x32C5, Some("compat"), &[0x36, 0x6708]),
+    (0x32C6, Some("compat"), &[0x37, 0x6708]),
+    (0x32C7, Some("compat"), &[0x38, 0x6708]),
+    (0x32C8, Some("compat"), &[0x39, 0x6708]),
+    (0x32C9, Some("compat"), &[0x31, 0x30, 0x6708]),
+    (0x32CA, Some("compat"), &[0x31, 0x31, 0x6708]),
+    (0x32CB, Some("compat"), &[0x31, 0x32, 0x6708]),
+    (0x32CC, Some("square"), &[0x48, 0x67]),
+    (0x32CD, Some("square"), &[0x65, 0x72, 0x67]),
+    (0x32CE, Some("square"), &[0x65, 0x56]),
+    (0x32CF, Some("square"), &[0x4C, 0x54, 0x44]),
+    (0x32D0, Some("circle"), &[0x30A2]),
+    (0x32D1, Some("circle"), &[0x30A4]),
+    (0x32D2, Some("circle"), &[0x30A6]),
+    (0x32D3, Some("circle"), &[0x30A8]),
+    (0x32D4, Some("circle"), &[0x30AA]),
+    (0x32D5, Some("circle"), &[0x30AB]),
+    (0x32D6, Some("circle"), &[0x30AD]),
+    (0x32D7, Some("circle"), &[0x30AF]),
+    (0x32D8, Some("circle"), &[0x30B1]),
+    (0x32D9, Some("circle"), &[0x30B3]),
+    (0x32DA, Some("circle"), &[0x30B5]),
+    (0x32DB, Some("circle"), &[0x30B7]),
+    (0x32DC, Some("circle"), &[0x30B9]),
+    (0x32DD, Some("circle"), &[0x30BB]),
+    (0x32DE, Some("circle"), &[0x30BD]),
+    (0x32DF, Some("circle"), &[0x30BF]),
+    (0x32E0, Some("circle"), &[0x30C1]),
+    (0x32E1, Some("circle"), &[0x30C4]),
+    (0x32E2, Some("circle"), &[0x30C6]),
+    (0x32E3, Some("circle"), &[0x30C8]),
+    (0x32E4, Some("circle"), &[0x30CA]),
+    (0x32E5, Some("circle"), &[0x30CB]),
+    (0x32E6, Some("circle"), &[0x30CC]),
+    (0x32E7, Some("circle"), &[0x30CD]),
+    (0x32E8, Some("circle"), &[0x30CE]),
+    (0x32E9, Some("circle"), &[0x30CF]),
+    (0x32EA, Some("circle"), &[0x30D2]),
+    (0x32EB, Some("circle"), &[0x30D5]),
+    (0x32EC, Some("circle"), &[0x30D8]),
+    (0x32ED, Some("circle"), &[0x30DB]),
+    (0x32EE, Some("circle"), &[0x30DE]),
+    (0x32EF, Some("circle"), &[0x30DF]),
+    (0x32F0, Some("circle"), &[0x30E0]),
+    (0x32F1, Some("circle"), &[0x30E1]),
+    (0x32F2, Some("circle"), &[0x30E2]),
+    (0x32F3, Some("circle"), &[0x30E4]),
+    (0x32F4, Some("circle"), &[0x30E6]),
+    (0x32F5, Some("circle"), &[0x30E8]),
+    (0x32F6, Some("circle"), &[0x30E9]),
+    (0x32F7, Some("circle"), &[0x30EA]),
+    (0x32F8, Some("circle"), &[0x30EB]),
+    (0x32F9, Some("circle"), &[0x30EC]),
+    (0x32FA, Some("circle"), &[0x30ED]),
+    (0x32FB, Some("circle"), &[0x30EF]),
+    (0x32FC, Some("circle"), &[0x30F0]),
+    (0x32FD, Some("circle"), &[0x30F1]),
+    (0x32FE, Some("circle"), &[0x30F2]),
+    (0x32FF, Some("square"), &[0x4EE4, 0x548C]),
+    (0x3300, Some("square"), &[0x30A2, 0x30D1, 0x30FC, 0x30C8]),
+    (0x3301, Some("square"), &[0x30A2, 0x30EB, 0x30D5, 0x30A1]),
+    (0x3302, Some("square"), &[0x30A2, 0x30F3, 0x30DA, 0x30A2]),
+    (0x3303, Some("square"), &[0x30A2, 0x30FC, 0x30EB]),
+    (0x3304, Some("square"), &[0x30A4, 0x30CB, 0x30F3, 0x30B0]),
+    (0x3305, Some("square"), &[0x30A4, 0x30F3, 0x30C1]),
+    (0x3306, Some("square"), &[0x30A6, 0x30A9, 0x30F3]),
+    (0x3307, Some("square"), &[0x30A8, 0x30B9, 0x30AF, 0x30FC, 0x30C9]),
+    (0x3308, Some("square"), &[0x30A8, 0x30FC, 0x30AB, 0x30FC]),
+    (0x3309, Some("square"), &[0x30AA, 0x30F3, 0x30B9]),
+    (0x330A, Some("square"), &[0x30AA, 0x30FC, 0x30E0]),
+    (0x330B, Some("square"), &[0x30AB, 0x30A4, 0x30EA]),
+    (0x330C, Some("square"), &[0x30AB, 0x30E9, 0x30C3, 0x30C8]),
+    (0x330D, Some("square"), &[0x30AB, 0x30ED, 0x30EA, 0x30FC]),
+    (0x330E, Some("square"), &[0x30AC, 0x30ED, 0x30F3]),
+    (0x330F, Some("square"), &[0x30AC, 0x30F3, 0x30DE]),
+    (0x3310, Some("square"), &[0x30AE, 0x30AC]),
+    (0x3311, Some("square"), &[0x30AE, 0x30CB, 0x30FC]),
+    (0x3312, Some("square"), &[0x30AD, 0x30E5, 0x30EA, 0x30FC]),
+    (0x3313, Some("square"), &[0x30AE, 0x30EB, 0x30C0, 0x30FC]),
+    (0x3314, Some("square"), &[0x30AD, 0x30ED]),
+    (0x3315, Some("square"), &[0x30AD, 0x30ED, 0x30B0, 0x30E9, 0x30E0]),
+    (0x3316, Some("square"), &[0x30AD, 0x30ED, 0x30E1, 0x30FC, 0x30C8, 0x30EB]),
+    (0x3317, Some("square"), &[0x30AD, 0x30ED, 0x30EF, 0x30C3, 0x30C8]),
+    (0x3318, Some("square"), &[0x30B0, 0x30E9, 0x30E0]),
+    (0x3319, Some("square"), &[0x30B0, 0x30E9, 0x30E0, 0x30C8, 0x30F3]),
+    (0x331A, Some("square"), &[0x30AF, 0x30EB, 0x30BC, 0x30A4, 0x30ED]),
+    (0x331B, Some("square"), &[0x30AF, 0x30ED, 0x30FC, 0x30CD]),
+    (0x331C, Some("square"), &[0x30B1, 0x30FC, 0x30B9]),
+    (0x331D, Some("square"), &[0x30B3, 0x30EB, 0x30CA]),
+    (0x331E, Some("square"), &[0x30B3, 0x30FC, 0x30DD]),
+    (0x331F, Some("square"), &[0x30B5, 0x30A4, 0x30AF, 0x30EB]),
+    (0x3320, Some("square"), &[0x30B5, 0x30F3, 0x30C1, 0x30FC, 0x30E0]),
+    (0x3321, Some("square"), &[0x30B7, 0x30EA, 0x30F3, 0x30B0]),
+    (0x3322, Some("square"), &[0x30BB, 0x30F3, 0x30C1]),
+    (0x3323, Some("square"), &[0x30BB, 0x30F3, 0x30C8]),
+    (0x3324, Some("square"), &[0x30C0, 0x30FC, 0x30B9]),
+    (0x3325, Some("square"), &[0x30C7, 0x30B7]),
+    (0x3326, Some("square"), &[0x30C9, 0x30EB]),
+    (0x3327, Some("square"), &[0x30C8, 0x30F3]),
+    (0x3328, Some("square"), &[0x30CA, 0x30CE]),
+    (0x3329, Some("square"), &[0x30CE, 0x30C3, 0x30C8]),
+    (0x332A, Some("square"), &[0x30CF, 0x30A4, 0x30C4]),
+    (0x332B, Some("square"), &[0x30D1, 0x30FC, 0x30BB, 0x30F3, 0x30C8]),
+    (0x332C, Some("square"), &[0x30D1, 0x30FC, 0x30C4]),
+    (0x332D, Some("square"), &[0x30D0, 0x30FC, 0x30EC, 0x30EB]),
+    (0x332E, Some("square"), &[0x30D4, 0x30A2, 0x30B9, 0x30C8, 0x30EB]),
+    (0x332F, Some("square"), &[0x30D4, 0x30AF, 0x30EB]),
+    (0x3330, Some("square"), &[0x30D4, 0x30B3]),
+    (0x3331, Some("square"), &[0x30D3, 0x30EB]),
+    (0x3332, Some("square"), &[0x30D5, 0x30A1, 0x30E9, 0x30C3, 0x30C9]),
+    (0x3333, Some("square"), &[0x30D5, 0x30A3, 0x30FC, 0x30C8]),
+    (0x3334, Some("square"), &[0x30D6, 0x30C3, 0x30B7, 0x30A7, 0x30EB]),
+    (0x3335, Some("square"), &[0x30D5, 0x30E9, 0x30F3]),
+    (0x3336, Some("square"), &[0x30D8, 0x30AF, 0x30BF, 0x30FC, 0x30EB]),
+    (0x3337, Some("square"), &[0x30DA, 0x30BD]),
+    (0x3338, Some("square"), &[0x30DA, 0x30CB, 0x30D2]),
+    (0x3339, Some("square"), &[0x30D8, 0x30EB, 0x30C4]),
+    (0x333A, Some("square"), &[0x30DA, 0x30F3, 0x30B9]),
+    (0x333B, Some("square"), &[0x30DA, 0x30FC, 0x30B8]),
+    (0x333C, Some("square"), &[0x30D9, 0x30FC, 0x30BF]),
+    (0x333D, Some("square"), &[0x30DD, 0x30A4, 0x30F3, 0x30C8]),
+    (0x333E, Some("square"), &[0x30DC, 0x30EB, 0x30C8]),
+    (0x333F, Some("square"), &[0x30DB, 0x30F3]),
+    (0x3340, Some("square"), &[0x30DD, 0x30F3, 0x30C9]),
+    (0x3341, Some("square"), &[0x30DB, 0x30FC, 0x30EB]),
+    (0x3342, Some("square"), &[0x30DB, 0x30FC, 0x30F3]),
+    (0x3343, Some("square"), &[0x30DE, 0x30A4, 0x30AF, 0x30ED]),
+    (0x3344, Some("square"), &[0x30DE, 0x30A4, 0x30EB]),
+    (0x3345, Some("square"), &[0x30DE, 0x30C3, 0x30CF]),
+    (0x3346, Some("square"), &[0x30DE, 0x30EB, 0x30AF]),
+    (0x3347, Some("square"), &[0x30DE, 0x30F3, 0x30B7, 0x30E7, 0x30F3]),
+    (0x3348, Some("square"), &[0x30DF, 0x30AF, 0x30ED, 0x30F3]),
+    (0x3349, Some("square"), &[0x30DF, 0x30EA]),
+    (0x334A, Some("square"), &[0x30DF, 0x30EA, 0x30D0, 0x30FC, 0x30EB]),
+    (0x334B, Some("square"), &[0x30E1, 0x30AC]),
+    (0x334C, Some("square"), &[0x30E1, 0x30AC, 0x30C8, 0x30F3]),
+    (0x334D, Some("square"), &[0x30E1, 0x30FC, 0x30C8, 0x30EB]),
+    (0x334E, Some("square"), &[0x30E4, 0x30FC, 0x30C9]),
+    (0x334F, Some("square"), &[0x30E4, 0x30FC, 0x30EB]),
+    (0x3350, Some("square"), &[0x30E6, 0x30A2, 0x30F3]),
+    (0x3351, Some("square"), &[0x30EA, 0x30C3, 0x30C8, 0x30EB]),
+    (0x3352, Some("square"), &[0x30EA, 0x30E9]),
+    (0x3353, Some("square"), &[0x30EB, 0x30D4, 0x30FC]),
+    (0x3354, Some("square"), &[0x30EB, 0x30FC, 0x30D6, 0x30EB]),
+    (0x3355, Some("square"), &[0x30EC, 0x30E0]),
+    (0x3356, Some("square"), &[0x30EC, 0x30F3, 0x30C8, 0x30B2, 0x30F3]),
+    (0x3357, Some("square"), &[0x30EF, 0x30C3, 0x30C8]),
+    (0x3358, Some("compat"), &[0x30, 0x70B9]),
+    (0x3359, Some("compat"), &[0x31, 0x70B9]),
+    (0x335A, Some("compat"), &[0x32, 0x70B9]),
+    (0x335B, Some("compat"), &[0x33, 0x70B9]),
+    (0x335C, Some("compat"), &[0x34, 0x70B9]),
+    (0x335D, Some("compat"), &[0x35, 0x70B9]),
+    (0x335E, Some("compat"), &[0x36, 0x70B9]),
+    (0x335F, Some("compat"), &[0x37, 0x70B9]),
+    (0x3360, Some("compat"), &[0x38, 0x70B9]),
+    (0x3361, Some("compat"), &[0x39, 0x70B9]),
+    (0x3362, Some("compat"), &[0x31, 0x30, 0x70B9]),
+    (0x3363, Some("compat"), &[0x31, 0x31, 0x70B9]),
+    (0x3364, Some("compat"), &[0x31, 0x32, 0x70B9]),
+    (0x3365, Some("compat"), &[0x31, 0x33, 0x70B9]),
+    (0x3366, Some("compat"), &[0x31, 0x34, 0x70B9]),
+    (0x3367, Some("compat"), &[0x31, 0x35, 0x70B9]),
+    (0x3368, Some("compat"), &[0x31, 0x36, 0x70B9]),
+    (0x3369, Some("compat"), &[0x31, 0x37, 0x70B9]),
+    (0x336A, Some("compat"), &[0x31, 0x38, 0x70B9]),
+    (0x336B, Some("compat"), &[0x31, 0x39, 0x70B9]),
+    (0x336C, Some("compat"), &[0x32, 0x30, 0x70B9]),
+    (0x336D, Some("compat"), &[0x32, 0x31, 0x70B9]),
+    (0x336E, Some("compat"), &[0x32, 0x32, 0x70B9]),
+    (0x336F, Some("compat"), &[0x32, 0x33, 0x70B9]),
+    (0x3370, Some("compat"), &[0x32, 0x34, 0x70B9]),
+    (0x3371, Some("square"), &[0x68, 0x50, 0x61]),
+    (0x3372, Some("square"), &[0x64, 0x61]),
+    (0x3373, Some("square"), &[0x41, 0x55]),
+    (0x3374, Some("square"), &[0x62, 0x61, 0x72]),
+    (0x3375, Some("square"), &[0x6F, 0x56]),
+    (0x3376, Some("square"), &[0x70, 0x63]),
+    (0x3377, Some("square"), &[0x64, 0x6D]),
+    (0x3378, Some("square"), &[0x64, 0x6D, 0xB2]),
+    (0x3379, Some("square"), &[0x64, 0x6D, 0xB3]),
+    (0x337A, Some("square"), &[0x49, 0x55]),
+    (0x337B, Some("square"), &[0x5E73, 0x6210]),
+    (0x337C, Some("square"), &[0x662D, 0x548C]),
+    (0x337D, Some("square"), &[0x5927, 0x6B63]),
+    (0x337E, Some("square"), &[0x660E, 0x6CBB]),
+    (0x337F, Some("square"), &[0x682A, 0x5F0F, 0x4F1A, 0x793E]),
+    (0x3380, Some("square"), &[0x70, 0x41]),
+    (0x3381, Some("square"), &[0x6E, 0x41]),
+    (0x3382, Some("square"), &[0x3BC, 0x41]),
+    (0x3383, Some("square"), &[0x6D, 0x41]),
+    (0x3384, Some("square"), &[0x6B, 0x41]),
+    (0x3385, Some("square"), &[0x4B, 0x42]),
+    (0x3386, Some("square"), &[0x4D, 0x42]),
+    (0x3387, Some("square"), &[0x47, 0x42]),
+    (0x3388, Some("square"), &[0x63, 0x61, 0x6C]),
+    (0x3389, Some("square"), &[0x6B, 0x63, 0x61, 0x6C]),
+    (0x338A, Some("square"), &[0x70, 0x46]),
+    (0x338B, Some("square"), &[0x6E, 0x46]),
+    (0x338C, Some("square"), &[0x3BC, 0x46]),
+    (0x338D, Some("square"), &[0x3BC, 0x67]),
+    (0x338E, Some("square"), &[0x6D, 0x67]),
+    (0x338F, Some("square"), &[0x6B, 0x67]),
+    (0x3390, Some("square"), &[0x48, 0x7A]),
+    (0x3391, Some("square"), &[0x6B, 0x48, 0x7A]),
+    (0x3392, Some("square"), &[0x4D, 0x48, 0x7A]),
+    (0x3393, Some("square"), &[0x47, 0x48, 0x7A]),
+    (0x3394, Some("square"), &[0x54, 0x48, 0x7A]),
+    (0x3395, Some("square"), &[0x3BC, 0x2113]),
+    (0x3396, Some("square"), &[0x6D, 0x2113]),
+    (0x3397, Some("square"), &[0x64, 0x2113]),
+    (0x3398, Some("square"), &[0x6B, 0x2113]),
+    (0x3399, Some("square"), &[0x66, 0x6D]),
+    (0x339A, Some("square"), &[0x6E, 0x6D]),
+    (0x339B, Some("square"), &[0x3BC, 0x6D]),
+    (0x339C, Some("square"), &[0x6D, 0x6D]),
+    (0x339D, Some("square"), &[0x63, 0x6D]),
+    (0x339E, Some("square"), &[0x6B, 0x6D]),
+    (0x339F, Some("square"), &[0x6D, 0x6D, 0xB2]),
+    (0x33A0, Some("square"), &[0x63, 0x6D, 0xB2]),
+    (0x33A1, Some("square"), &[0x6D, 0xB2]),
+    (0x33A2, Some("square"), &[0x6B, 0x6D, 0xB2]),
+    (0x33A3, Some("square"), &[0x6D, 0x6D, 0xB3]),
+    (0x33A4, Some("square"), &[0x63, 0x6D, 0xB3]),
+    (0x33A5, Some("square"), &[0x6D, 0xB3]),
+    (0x33A6, Some("square"), &[0x6B, 0x6D, 0xB3]),
+    (0x33A7, Some("square"), &[0x6D, 0x2215, 0x73]),
+    (0x33A8, Some("square"), &[0x6D, 0x2215, 0x73, 0xB2]),
+    (0x33A9, Some("square"), &[0x50, 0x61]),
+    (0x33AA, Some("square"), &[0x6B, 0x50, 0x61]),
+    (0x33AB, Some("square"), &[0x4D, 0x50, 0x61]),
+    (0x33AC, Some("square"), &[0x47, 0x50, 0x61]),
+    (0x33AD, Some("square"), &[0x72, 0x61, 0x64]),
+    (0x33AE, Some("square"), &[0x72, 0x61, 0x64, 0x2215, 0x73]),
+    (0x33AF, Some("square"), &[0x72, 0x61, 0x64, 0x2215, 0x73, 0xB2]),
+    (0x33B0, Some("square"), &[0x70, 0x73]),
+    (0x33B1, Some("square"), &[0x6E, 0x73]),
+    (0x33B2, Some("square"), &[0x3BC, 0x73]),
+    (0x33B3, Some("square"), &[0x6D, 0x73]),
+    (0x33B4, Some("square"), &[0x70, 0x56]),
+    (0x33B5, Some("square"), &[0x6E, 0x56]),
+    (0x33B6, Some("square"), &[0x3BC, 0x56]),
+    (0x33B7, Some("square"), &[0x6D, 0x56]),
+    (0x33B8, Some("square"), &[0x6B, 0x56]),
+    (0x33B9, Some("square"), &[0x4D, 0x56]),
+    (0x33BA, Some("square"), &[0x70, 0x57]),
+    (0x33BB, Some("square"), &[0x6E, 0x57]),
+    (0x33BC, Some("square"), &[0x3BC, 0x57]),
+    (0x33BD, Some("square"), &[0x6D, 0x57]),
+    (0x33BE, Some("square"), &[0x6B, 0x57]),
+    (0x33BF, Some("square"), &[0x4D, 0x57]),
+    (0x33C0, Some("square"), &[0x6B, 0x3A9]),
+    (0x33C1, Some("square"), &[0x4D, 0x3A9]),
+    (0x33C2, Some("square"), &[0x61, 0x2E, 0x6D, 0x2E]),
+    (0x33C3, Some("square"), &[0x42, 0x71]),
+    (0x33C4, Some("square"), &[0x63, 0x63]),
+    (0x33C5, Some("square"), &[0x63, 0x64]),
+    (0x33C6, Some("square"), &[0x43, 0x2215, 0x6B, 0x67]),
+    (0x33C7, Some("square"), &[0x43, 0x6F, 0x2E]),
+    (0x33C8, Some("square"), &[0x64, 0x42]),
+    (0x33C9, Some("square"), &[0x47, 0x79]),
+    (0x33CA, Some("square"), &[0x68, 0x61]),
+    (0x33CB, Some("square"), &[0x48, 0x50]),
+    (0x33CC, Some("square"), &[0x69, 0x6E]),
+    (0x33CD, Some("square"), &[0x4B, 0x4B]),
+    (0x33CE, Some("square"), &[0x4B, 0x4D]),
+    (0x33CF, Some("square"), &[0x6B, 0x74]),
+    (0x33D0, Some("square"), &[0x6C, 0x6D]),
+    (0x33D1, Some("square"), &[0x6C, 0x6E]),
+    (0x33D2, Some("square"), &[0x6C, 0x6F, 0x67]),
+    (0x33D3, Some("square"), &[0x6C, 0x78]),
+    (0x33D4, Some("square"), &[0x6D, 0x62]),
+    (0x33D5, Some("square"), &[0x6D, 0x69, 0x6C]),
+    (0x33D6, Some("square"), &[0x6D, 0x6F, 0x6C]),
+    (0x33D7, Some("square"), &[0x50, 0x48]),
+    (0x33D8, Some("square"), &[0x70, 0x2E, 0x6D, 0x2E]),
+    (0x33D9, Some("square"), &[0x50, 0x50, 0x4D]),
+    (0x33DA, Some("square"), &[0x50, 0x52]),
+    (0x33DB, Some("square"), &[0x73, 0x72]),
+    (0x33DC, Some("square"), &[0x53, 0x76]),
+    (0x33DD, Some("square"), &[0x57, 0x62]),
+    (0x33DE, Some("square"), &[0x56, 0x2215, 0x6D]),
+    (0x33DF, Some("square"), &[0x41, 0x2215, 0x6D]),
+    (0x33E0, Some("compat"), &[0x31, 0x65E5]),
+    (0x33E1, Some("compat"), &[0x32, 0x65E5]),
+    (0x33E2, Some("compat"), &[0x33, 0x65E5]),
+    (0x33E3, Some("compat"), &[0x34, 0x65E5]),
+    (0x33E4, Some("compat"), &[0x35, 0x65E5]),
+    (0x33E5, Some("compat"), &[0x36, 0x65E5]),
+    (0x33E6, Some("compat"), &[0x37, 0x65E5]),
+    (0x33E7, Some("compat"), &[0x38, 0x65E5]),
+    (0x33E8, Some("compat"), &[0x39, 0x65E5]),
+    (0x33E9, Some("compat"), &[0x31, 0x30, 0x65E5]),
+    (0x33EA, Some("compat"), &[0x31, 0x31, 0x65E5]),
+    (0x33EB, Some("compat"), &[0x31, 0x32, 0x65E5]),
+    (0x33EC, Some("compat"), &[0x31, 0x33, 0x65E5]),
+    (0x33ED, Some("compat"), &[0x31, 0x34, 0x65E5]),
+    (0x33EE, Some("compat"), &[0x31, 0x35, 0x65E5]),
+    (0x33EF, Some("compat"), &[0x31, 0x36, 0x65E5]),
+    (0x33F0, Some("compat"), &[0x31, 0x37, 0x65E5]),
+    (0x33F1, Some("compat"), &[0x31, 0x38, 0x65E5]),
+    (0x33F2, Some("compat"), &[0x31, 0x39, 0x65E5]),
+    (0x33F3, Some("compat"), &[0x32, 0x30, 0x65E5]),
+    (0x33F4, Some("compat"), &[0x32, 0x31, 0x65E5]),
+    (0x33F5, Some("compat"), &[0x32, 0x32, 0x65E5]),
+    (0x33F6, Some("compat"), &[0x32, 0x33, 0x65E5]),
+    (0x33F7, Some("compat"), &[0x32, 0x34, 0x65E5]),
+    (0x33F8, Some("compat"), &[0x32, 0x35, 0x65E5]),
+    (0x33F9, Some("compat"), &[0x32, 0x36, 0x65E5]),
+    (0x33FA, Some("compat"), &[0x32, 0x37, 0x65E5]),
+    (0x33FB, Some("compat"), &[0x32, 0x38, 0x65E5]),
+    (0x33FC, Some("compat"), &[0x32, 0x39, 0x65E5]),
+    (0x33FD, Some("compat"), &[0x33, 0x30, 0x65E5]),
+    (0x33FE, Some("compat"), &[0x33, 0x31, 0x65E5]),
+    (0x33FF, Some("square"), &[0x67, 0x61, 0x6C]),
+    (0xA69C, Some("super"), &[0x44A]),
+    (0xA69D, Some("super"), &[0x44C]),
+    (0xA770, Some("super"), &[0xA76F]),
+    (0xA7F2, Some("super"), &[0x43]),
+    (0xA7F3, Some("super"), &[0x46]),
+    (0xA7F4, Some("super"), &[0x51]),
+    (0xA7F8, Some("super"), &[0x126]),
+    (0xA7F9, Some("super"), &[0x153]),
+    (0xAB5C, Some("super"), &[0xA727]),
+    (0xAB5D, Some("super"), &[0xAB37]),
+    (0xAB5E, Some("super"), &[0x26B]),
+    (0xAB5F, Some("super"), &[0xAB52]),
+    (0xAB69, Some("super"), &[0x28D]),
+    (0xF900, None, &[0x8C48]),
+    (0xF901, None, &[0x66F4]),
+    (0xF902, None, &[0x8ECA]),
+    (0xF903, None, &[0x8CC8]),
+    (0xF904, None, &[0x6ED1]),
+    (0xF905, None, &[0x4E32]),
+    (0xF906, None, &[0x53E5]),
+    (0xF907, None, &[0x9F9C]),
+    (0xF908, None, &[0x9F9C]),
+    (0xF909, None, &[0x5951]),
+    (0xF90A, None, &[0x91D1]),
+    (0xF90B, None, &[0x5587]),
+    (0xF90C, None, &[0x5948]),
+    (0xF90D, None, &[0x61F6]),
+    (0xF90E, None, &[0x7669]),
+    (0xF90F, None, &[0x7F85]),
+    (0xF910, None, &[0x863F]),
+    (0xF911, None, &[0x87BA]),
+    (0xF912, None, &[0x88F8]),
+    (0xF913, None, &[0x908F]),
+    (0xF914, None, &[0x6A02]),
+    (0xF915, None, &[0x6D1B]),
+    (0xF916, None, &[0x70D9]),
+    (0xF917, None, &[0x73DE]),
+    (0xF918, None, &[0x843D]),
+    (0xF919, None, &[0x916A]),
+    (0xF91A, None, &[0x99F1]),
+    (0xF91B, None, &[0x4E82]),
+    (0xF91C, None, &[0x5375]),
+    (0xF91D, None, &[0x6B04]),
+    (0xF91E, None, &[0x721B]),
+    (0xF91F, None, &[0x862D]),
+    (0xF920, None, &[0x9E1E]),
+    (0xF921, None, &[0x5D50]),
+    (0xF922, None, &[0x6FEB]),
+    (0xF923, None, &[0x85CD]),
+    (0xF924, None, &[0x8964]),
+    (0xF925, None, &[0x62C9]),
+    (0xF926, None, &[0x81D8]),
+    (0xF927, None, &[0x881F]),
+    (0xF928, None, &[0x5ECA]),
+    (0xF929, None, &[0x6717]),
+    (0xF92A, None, &[0x6D6A]),
+    (0xF92B, None, &[0x72FC]),
+    (0xF92C, None, &[0x90CE]),
+    (0xF92D, None, &[0x4F86]),
+    (0xF92E, None, &[0x51B7]),
+    (0xF92F, None, &[0x52DE]),
+    (0xF930, None, &[0x64C4]),
+    (0xF931, None, &[0x6AD3]),
+    (0xF932, None, &[0x7210]),
+    (0xF933, None, &[0x76E7]),
+    (0xF934, None, &[0x8001]),
+    (0xF935, None, &[0x8606]),
+    (0xF936, None, &[0x865C]),
+    (0xF937, None, &[0x8DEF]),
+    (0xF938, None, &[0x9732]),
+    (0xF939, None, &[0x9B6F]),
+    (0xF93A, None, &[0x9DFA]),
+    (0xF93B, None, &[0x788C]),
+    (0xF93C, None, &[0x797F]),
+    (0xF93D, None, &[0x7DA0]),
+    (0xF93E, None, &[0x83C9]),
+    (0xF93F, None, &[0x9304]),
+    (0xF940, None, &[0x9E7F]),
+    (0xF941, None, &[0x8AD6]),
+    (0xF942, None, &[0x58DF]),
+    (0xF943, None, &[0x5F04]),
+    (0xF944, None, &[0x7C60]),
+    (0xF945, None, &[0x807E]),
+    (0xF946, None, &[0x7262]),
+    (0xF947, None, &[0x78CA]),
+    (0xF948, None, &[0x8CC2]),
+    (0xF949, None, &[0x96F7]),
+    (0xF94A, None, &[0x58D8]),
+    (0xF94B, None, &[0x5C62]),
+    (0xF94C, None, &[0x6A13]),
+    (0xF94D, None, &[0x6DDA]),
+    (0xF94E, None, &[0x6F0F]),
+    (0xF94F, None, &[0x7D2F]),
+    (0xF950, None, &[0x7E37]),
+    (0xF951, None, &[0x964B]),
+    (0xF952, None, &[0x52D2]),
+    (0xF953, None, &[0x808B]),
+    (0xF954, None, &[0x51DC]),
+    (0xF955, None, &[0x51CC]),
+    (0xF956, None, &[0x7A1C]),
+    (0xF957, None, &[0x7DBE]),
+    (0xF958, None, &[0x83F1]),
+    (0xF959, None, &[0x9675]),
+    (0xF95A, None, &[0x8B80]),
+    (0xF95B, None, &[0x62CF]),
+    (0xF95C, None, &[0x6A02]),
+    (0xF95D, None, &[0x8AFE]),
+    (0xF95E, None, &[0x4E39]),
+    (0xF95F, None, &[0x5BE7]),
+    (0xF960, None, &[0x6012]),
+    (0xF961, None, &[0x7387]),
+    (0xF962, None, &[0x7570]),
+    (0xF963, None, &[0x5317]),
+    (0xF964, None, &[0x78FB]),
+    (0xF965, None, &[0x4FBF]),
+    (0xF966, None, &[0x5FA9]),
+    (0xF967, None, &[0x4E0D]),
+    (0xF968, None, &[0x6CCC]),
+    (0xF969, None, &[0x6578]),
+    (0xF96A, None, &[0x7D22]),
+    (0xF96B, None, &[0x53C3]),
+    (0xF96C, None, &[0x585E]),
+    (0xF96D, None, &[0x7701]),
+    (0xF96E, None, &[0x8449]),
+    (0xF96F, None, &[0x8AAA]),
+    (0xF970, None, &[0x6BBA]),
+    (0xF971, None, &[0x8FB0]),
+    (0xF972, None, &[0x6C88]),
+    (0xF973, None, &[0x62FE]),
+    (0xF974, None, &[0x82E5]),
+    (0xF975, None, &[0x63A0]),
+    (0xF976, None, &[0x7565]),
+    (0xF977, None, &[0x4EAE]),
+    (0xF978, None, &[0x5169]),
+    (0xF979, None, &[0x51C9]),
+    (0xF97A, None, &[0x6881]),
+    (0xF97B, None, &[0x7CE7]),
+    (0xF97C, None, &[0x826F]),
+    (0xF97D, None, &[0x8AD2]),
+    (0xF97E, None, &[0x91CF]),
+    (0xF97F, None, &[0x52F5]),
+    (0xF980, None, &[0x5442]),
+    (0xF981, None, &[0x5973]),
+    (0xF982, None, &[0x5EEC]),
+    (0xF983, None, &[0x65C5]),
+    (0xF984, None, &[0x6FFE]),
+    (0xF985, None, &[0x792A]),
+    (0xF986, None, &[0x95AD]),
+    (0xF987, None, &[0x9A6A]),
+    (0xF988, None, &[0x9E97]),
+    (0xF989, None, &[0x9ECE]),
+    (0xF98A, None, &[0x529B]),
+    (0xF98B, None, &[0x66C6]),
+    (0xF98C, None, &[0x6B77]),
+    (0xF98D, None, &[0x8F62]),
+    (0xF98E, None, &[0x5E74]),
+    (0xF98F, None, &[0x6190]),
+    (0xF990, None, &[0x6200]),
+    (0xF991, None, &[0x649A]),
+    (0xF992, None, &[0x6F23]),
+    (0xF993, None, &[0x7149]),
+    (0xF994, None, &[0x7489]),
+    (0xF995, None, &[0x79CA]),
+    (0xF996, None, &[0x7DF4]),
+    (0xF997, None, &[0x806F]),
+    (0xF998, None, &[0x8F26]),
+    (0xF999, None, &[0x84EE]),
+    (0xF99A, None, &[0x9023]),
+    (0xF99B, None, &[0x934A]),
+    (0xF99C, None, &[0x5217]),
+    (0xF99D, None, &[0x52A3]),
+    (0xF99E, None, &[0x54BD]),
+    (0xF99F, None, &[0x70C8]),
+    (0xF9A0, None, &[0x88C2]),
+    (0xF9A1, None, &[0x8AAA]),
+    (0xF9A2, None, &[0x5EC9]),
+    (0xF9A3, None, &[0x5FF5]),
+    (0xF9A4, None, &[0x637B]),
+    (0xF9A5, None, &[0x6BAE]),
+    (0xF9A6, None, &[0x7C3E]),
+    (0xF9A7, None, &[0x7375]),
+    (0xF9A8, None, &[0x4EE4]),
+    (0xF9A9, None, &[0x56F9]),
+    (0xF9AA, None, &[0x5BE7]),
+    (0xF9AB, None, &[0x5DBA]),
+    (0xF9AC, None, &[0x601C]),
+    (0xF9AD, None, &[0x73B2]),
+    (0xF9AE, None, &[0x7469]),
+    (0xF9AF, None, &[0x7F9A]),
+    (0xF9B0, None, &[0x8046]),
+    (0xF9B1, None, &[0x9234]),
+    (0xF9B2, None, &[0x96F6]),
+    (0xF9B3, None, &[0x9748]),
+    (0xF9B4, None, &[0x9818]),
+    (0xF9B5, None, &[0x4F8B]),
+    (0xF9B6, None, &[0x79AE]),
+    (0xF9B7, None, &[0x91B4]),
+    (0xF9B8, None, &[0x96B8]),
+    (0xF9B9, None, &[0x60E1]),
+    (0xF9BA, None, &[0x4E86]),
+    (0xF9BB, None, &[0x50DA]),
+    (0xF9BC, None, &[0x5BEE]),
+    (0xF9BD, None, &[0x5C3F]),
+    (0xF9BE, None, &[0x6599]),
+    (0xF9BF, None, &[0x6A02]),
+    (0xF9C0, None, &[0x71CE]),
+    (0xF9C1, None, &[0x7642]),
+    (0xF9C2, None, &[0x84FC]),
+    (0xF9C3, None, &[0x907C]),
+    (0xF9C4, None, &[0x9F8D]),
+    (0xF9C5, None, &[0x6688]),
+    (0xF9C6, None, &[0x962E]),
+    (0xF9C7, None, &[0x5289]),
+    (0xF9C8, None, &[0x677B]),
+    (0xF9C9, None, &[0x67F3]),
+    (0xF9CA, None, &[0x6D41]),
+    (0xF9CB, None, &[0x6E9C]),
+    (0xF9CC, None, &[0x7409]),
+    (0xF9CD, None, &[0x7559]),
+    (0xF9CE, None, &[0x786B]),
+    (0xF9CF, None, &[0x7D10]),
+    (0xF9D0, None, &[0x985E]),
+    (0xF9D1, None, &[0x516D]),
+    (0xF9D2, None, &[0x622E]),
+    (0xF9D3, None, &[0x9678]),
+    (0xF9D4, None, &[0x502B]),
+    (0xF9D5, None, &[0x5D19]),
+    (0xF9D6, None, &[0x6DEA]),
+    (0xF9D7, None, &[0x8F2A]),
+    (0xF9D8, None, &[0x5F8B]),
+    (0xF9D9, None, &[0x6144]),
+    (0xF9DA, None, &[0x6817]),
+    (0xF9DB, None, &[0x7387]),
+    (0xF9DC, None, &[0x9686]),
+    (0xF9DD, None, &[0x5229]),
+    (0xF9DE, None, &[0x540F]),
+    (0xF9DF, None, &[0x5C65]),
+    (0xF9E0, None, &[0x6613]),
+    (0xF9E1, None, &[0x674E]),
+    (0xF9E2, None, &[0x68A8]),
+    (0xF9E3, None, &[0x6CE5]),
+    (0xF9E4, None, &[0x7406]),
+    (0xF9E5, None, &[0x75E2]),
+    (0xF9E6, None, &[0x7F79]),
+    (0xF9E7, None, &[0x88CF]),
+    (0xF9E8, None, &[0x88E1]),
+    (0xF9E9, None, &[0x91CC]),
+    (0xF9EA, None, &[0x96E2]),
+    (0xF9EB, None, &[0x533F]),
+    (0xF9EC, None, &[0x6EBA]),
+    (0xF9ED, None, &[0x541D]),
+    (0xF9EE, None, &[0x71D0]),
+    (0xF9EF, None, &[0x7498]),
+    (0xF9F0, None, &[0x85FA]),
+    (0xF9F1, None, &[0x96A3]),
+    (0xF9F2, None, &[0x9C57]),
+    (0xF9F3, None, &[0x9E9F]),
+    (0xF9F4, None, &[0x6797]),
+    (0xF9F5, None, &[0x6DCB]),
+    (0xF9F6, None, &[0x81E8]),
+    (0xF9F7, None, &[0x7ACB]),
+    (0xF9F8, None, &[0x7B20]),
+    (0xF9F9, None, &[0x7C92]),
+    (0xF9FA, None, &[0x72C0]),
+    (0xF9FB, None, &[0x7099]),
+    (0xF9FC, None, &[0x8B58]),
+    (0xF9FD, None, &[0x4EC0]),
+    (0xF9FE, None, &[0x8336]),
+    (0xF9FF, None, &[0x523A]),
+    (0xFA00, None, &[0x5207]),
+    (0xFA01, None, &[0x5EA6]),
+    (0xFA02, None, &[0x62D3]),
+    (0xFA03, None, &[0x7CD6]),
+    (0xFA04, None, &[0x5B85]),
+    (0xFA05, None, &[0x6D1E]),
+    (0xFA06, None, &[0x66B4]),
+    (0xFA07, None, &[0x8F3B]),
+    (0xFA08, None, &[0x884C]),
+    (0xFA09, None, &[0x964D]),
+    (0xFA0A, None, &[0x898B]),
+    (0xFA0B, None, &[0x5ED3]),
+    (0xFA0C, None, &[0x5140]),
+    (0xFA0D, None, &[0x55C0]),
+    (0xFA10, None, &[0x585A]),
+    (0xFA12, None, &[0x6674]),
+    (0xFA15, None, &[0x51DE]),
+    (0xFA16, None, &[0x732A]),
+    (0xFA17, None, &[0x76CA]),
+    (0xFA18, None, &[0x793C]),
+    (0xFA19, None, &[0x795E]),
+    (0xFA1A, None, &[0x7965]),
+    (0xFA1B, None, &[0x798F]),
+    (0xFA1C, None, &[0x9756]),
+    (0xFA1D, None, &[0x7CBE]),
+    (0xFA1E, None, &[0x7FBD]),
+    (0xFA20, None, &[0x8612]),
+    (0xFA22, None, &[0x8AF8]),
+    (0xFA25, None, &[0x9038]),
+    (0xFA26, None, &[0x90FD]),
+    (0xFA2A, None, &[0x98EF]),
+    (0xFA2B, None, &[0x98FC]),
+    (0xFA2C, None, &[0x9928]),
+    (0xFA2D, None, &[0x9DB4]),
+    (0xFA2E, None, &[0x90DE]),
+    (0xFA2F, None, &[0x96B7]),
+    (0xFA30, None, &[0x4FAE]),
+    (0xFA31, None, &[0x50E7]),
+    (0xFA32, None, &[0x514D]),
+    (0xFA33, None, &[0x52C9]),
+    (0xFA34, None, &[0x52E4]),
+    (0xFA35, None, &[0x5351]),
+    (0xFA36, None, &[0x559D]),
+    (0xFA37, None, &[0x5606]),
+    (0xFA38, None, &[0x5668]),
+    (0xFA39, None, &[0x5840]),
+    (0xFA3A, None, &[0x58A8]),
+    (0xFA3B, None, &[0x5C64]),
+    (0xFA3C, None, &[0x5C6E]),
+    (0xFA3D, None, &[0x6094]),
+    (0xFA3E, None, &[0x6168]),
+    (0xFA3F, None, &[0x618E]),
+    (0xFA40, None, &[0x61F2]),
+    (0xFA41, None, &[0x654F]),
+    (0xFA42, None, &[0x65E2]),
+    (0xFA43, None, &[0x6691]),
+    (0xFA44, None, &[0x6885]),
+    (0xFA45, None, &[0x6D77]),
+    (0xFA46, None, &[0x6E1A]),
+    (0xFA47, None, &[0x6F22]),
+    (0xFA48, None, &[0x716E]),
+    (0xFA49, None, &[0x722B]),
+    (0xFA4A, None, &[0x7422]),
+    (0xFA4B, None, &[0x7891]),
+    (0xFA4C, None, &[0x793E]),
+    (0xFA4D, None, &[0x7949]),
+    (0xFA4E, None, &[0x7948]),
+    (0xFA4F, None, &[0x7950]),
+    (0xFA50, None, &[0x7956]),
+    (0xFA51, None, &[0x795D]),
+    (0xFA52, None, &[0x798D]),
+    (0xFA53, None, &[0x798E]),
+    (0xFA54, None, &[0x7A40]),
+    (0xFA55, None, &[0x7A81]),
+    (0xFA56, None, &[0x7BC0]),
+    (0xFA57, None, &[0x7DF4]),
+    (0xFA58, None, &[0x7E09]),
+    (0xFA59, None, &[0x7E41]),
+    (0xFA5A, None, &[0x7F72]),
+    (0xFA5B, None, &[0x8005]),
+    (0xFA5C, None, &[0x81ED]),
+    (0xFA5D, None, &[0x8279]),
+    (0xFA5E, None, &[0x8279]),
+    (0xFA5F, None, &[0x8457]),
+    (0xFA60, None, &[0x8910]),
+    (0xFA61, None, &[0x8996]),
+    (0xFA62, None, &[0x8B01]),
+    (0xFA63, None, &[0x8B39]),
+    (0xFA64, None, &[0x8CD3]),
+    (0xFA65, None, &[0x8D08]),
+    (0xFA66, None, &[0x8FB6]),
+    (0xFA67, None, &[0x9038]),
+    (0xFA68, None, &[0x96E3]),
+    (0xFA69, None, &[0x97FF]),
+    (0xFA6A, None, &[0x983B]),
+    (0xFA6B, None, &[0x6075]),
+    (0xFA6C, None, &[0x242EE]),
+    (0xFA6D, None, &[0x8218]),
+    (0xFA70, None, &[0x4E26]),
+    (0xFA71, None, &[0x51B5]),
+    (0xFA72, None, &[0x5168]),
+    (0xFA73, None, &[0x4F80]),
+    (0xFA74, None, &[0x5145]),
+    (0xFA75, None, &[0x5180]),
+    (0xFA76, None, &[0x52C7]),
+    (0xFA77, None, &[0x52FA]),
+    (0xFA78, None, &[0x559D]),
+    (0xFA79, None, &[0x5555]),
+    (0xFA7A, None, &[0x5599]),
+    (0xFA7B, None, &[0x55E2]),
+    (0xFA7C, None, &[0x585A]),
+    (0xFA7D, None, &[0x58B3]),
+    (0xFA7E, None, &[0x5944]),
+    (0xFA7F, None, &[0x5954]),
+    (0xFA80, None, &[0x5A62]),
+    (0xFA81, None, &[0x5B28]),
+    (0xFA82, None, &[0x5ED2]),
+    (0xFA83, None, &[0x5ED9]),
+    (0xFA84, None, &[0x5F69]),
+    (0xFA85, None, &[0x5FAD]),
+    (0xFA86, None, &[0x60D8]),
+    (0xFA87, None, &[0x614E]),
+    (0xFA88, None, &[0x6108]),
+    (0xFA89, None, &[0x618E]),
+    (0xFA8A, None, &[0x6160]),
+    (0xFA8B, None, &[0x61F2]),
+    (0xFA8C, None, &[0x6234]),
+    (0xFA8D, None, &[0x63C4]),
+    (0xFA8E, None, &[0x641C]),
+    (0xFA8F, None, &[0x6452]),
+    (0xFA90, None, &[0x6556]),
+    (0xFA91, None, &[0x6674]),
+    (0xFA92, None, &[0x6717]),
+    (0xFA93, None, &[0x671B]),
+    (0xFA94, None, &[0x6756]),
+    (0xFA95, None, &[0x6B79]),
+    (0xFA96, None, &[0x6BBA]),
+    (0xFA97, None, &[0x6D41]),
+    (0xFA98, None, &[0x6EDB]),
+    (0xFA99, None, &[0x6ECB]),
+    (0xFA9A, None, &[0x6F22]),
+    (0xFA9B, None, &[0x701E]),
+    (0xFA9C, None, &[0x716E]),
+    (0xFA9D, None, &[0x77A7]),
+    (0xFA9E, None, &[0x7235]),
+    (0xFA9F, None, &[0x72AF]),
+    (0xFAA0, None, &[0x732A]),
+    (0xFAA1, None, &[0x7471]),
+    (0xFAA2, None, &[0x7506]),
+    (0xFAA3, None, &[0x753B]),
+    (0xFAA4, None, &[0x761D]),
+    (0xFAA5, None, &[0x761F]),
+    (0xFAA6, None, &[0x76CA]),
+    (0xFAA7, None, &[0x76DB]),
+    (0xFAA8, None, &[0x76F4]),
+    (0xFAA9, None, &[0x774A]),
+    (0xFAAA, None, &[0x7740]),
+    (0xFAAB, None, &[0x78CC]),
+    (0xFAAC, None, &[0x7AB1]),
+    (0xFAAD, None, &[0x7BC0]),
+    (0xFAAE, None, &[0x7C7B]),
+    (0xFAAF, None, &[0x7D5B]),
+    (0xFAB0, None, &[0x7DF4]),
+    (0xFAB1, None, &[0x7F3E]),
+    (0xFAB2, None, &[0x8005]),
+    (0xFAB3, None, &[0x8352]),
+    (0xFAB4, None, &[0x83EF]),
+    (0xFAB5, None, &[0x8779]),
+    (0xFAB6, None, &[0x8941]),
+    (0xFAB7, None, &[0x8986]),
+    (0xFAB8, None, &[0x8996]),
+    (0xFAB9, None, &[0x8ABF]),
+    (0xFABA, None, &[0x8AF8]),
+    (0xFABB, None, &[0x8ACB]),
+    (0xFABC, None, &[0x8B01]),
+    (0xFABD, None, &[0x8AFE]),
+    (0xFABE, None, &[0x8AED]),
+    (0xFABF, None, &[0x8B39]),
+    (0xFAC0, None, &[0x8B8A]),
+    (0xFAC1, None, &[0x8D08]),
+    (0xFAC2, None, &[0x8F38]),
+    (0xFAC3, None, &[0x9072]),
+    (0xFAC4, None, &[0x9199]),
+    (0xFAC5, None, &[0x9276]),
+    (0xFAC6, None, &[0x967C]),
+    (0xFAC7, None, &[0x96E3]),
+    (0xFAC8, None, &[0x9756]),
+    (0xFAC9, None, &[0x97DB]),
+    (0xFACA, None, &[0x97FF]),
+    (0xFACB, None, &[0x980B]),
+    (0xFACC, None, &[0x983B]),
+    (0xFACD, None, &[0x9B12]),
+    (0xFACE, None, &[0x9F9C]),
+    (0xFACF, None, &[0x2284A]),
+    (0xFAD0, None, &[0x22844]),
+    (0xFAD1, None, &[0x233D5]),
+    (0xFAD2, None, &[0x3B9D]),
+    (0xFAD3, None, &[0x4018]),
+    (0xFAD4, None, &[0x4039]),
+    (0xFAD5, None, &[0x25249]),
+    (0xFAD6, None, &[0x25CD0]),
+    (0xFAD7, None, &[0x27ED3]),
+    (0xFAD8, None, &[0x9F43]),
+    (0xFAD9, None, &[0x9F8E]),
+    (0xFB00, Some("compat"), &[0x66, 0x66]),
+    (0xFB01, Some("compat"), &[0x66, 0x69]),
+    (0xFB02, Some("compat"), &[0x66, 0x6C]),
+    (0xFB03, Some("compat"), &[0x66, 0x66, 0x69]),
+    (0xFB04, Some("compat"), &[0x66, 0x66, 0x6C]),
+    (0xFB05, Some("compat"), &[0x17F, 0x74]),
+    (0xFB06, Some("compat"), &[0x73, 0x74]),
+    (0xFB13, Some("compat"), &[0x574, 0x576]),
+    (0xFB14, Some("compat"), &[0x574, 0x565]),
+    (0xFB15, Some("compat"), &[0x574, 0x56B]),
+    (0xFB16, Some("compat"), &[0x57E, 0x576]),
+    (0xFB17, Some("compat"), &[0x574, 0x56D]),
+    (0xFB1D, None, &[0x5D9, 0x5B4]),
+    (0xFB1F, None, &[0x5F2, 0x5B7]),
+    (0xFB20, Some("font"), &[0x5E2]),
+    (0xFB21, Some("font"), &[0x5D0]),
+    (0xFB22, Some("font"), &[0x5D3]),
+    (0xFB23, Some("font"), &[0x5D4]),
+    (0xFB24, Some("font"), &[0x5DB]),
+    (0xFB25, Some("font"), &[0x5DC]),
+    (0xFB26, Some("font"), &[0x5DD]),
+    (0xFB27, Some("font"), &[0x5E8]),
+    (0xFB28, Some("font"), &[0x5EA]),
+    (0xFB29, Some("font"), &[0x2B]),
+    (0xFB2A, None, &[0x5E9, 0x5C1]),
+    (0xFB2B, None, &[0x5E9, 0x5C2]),
+    (0xFB2C, None, &[0xFB49, 0x5C1]),
+    (0xFB2D, None, &[0xFB49, 0x5C2]),
+    (0xFB2E, None, &[0x5D0, 0x5B7]),
+    (0xFB2F, None, &[0x5D0, 0x5B8]),
+    (0xFB30, None, &[0x5D0, 0x5BC]),
+    (0xFB31, None, &[0x5D1, 0x5BC]),
+    (0xFB32, None, &[0x5D2, 0x5BC]),
+    (0xFB33, None, &[0x5D3, 0x5BC]),
+    (0xFB34, None, &[0x5D4, 0x5BC]),
+    (0xFB35, None, &[0x5D5, 0x5BC]),
+    (0xFB36, None, &[0x5D6, 0x5BC]),
+    (0xFB38, None, &[0x5D8, 0x5BC]),
+    (0xFB39, None, &[0x5D9, 0x5BC]),
+    (0xFB3A, None, &[0x5DA, 0x5BC]),
+    (0xFB3B, None, &[0x5DB, 0x5BC]),
+    (0xFB3C, None, &[0x5DC, 0x5BC]),
+    (0xFB3E, None, &[0x5DE, 0x5BC]),
+    (0xFB40, None, &[0x5E0, 0x5BC]),
+    (0xFB41, None, &[0x5E1, 0x5BC]),
+    (0xFB43, None, &[0x5E3, 0x5BC]),
+    (0xFB44, None, &[0x5E4, 0x5BC]),
+    (0xFB46, None, &[0x5E6, 0x5BC]),
+    (0xFB47, None, &[0x5E7, 0x5BC]),
+    (0xFB48, None, &[0x5E8, 0x5BC]),
+    (0xFB49, None, &[0x5E9, 0x5BC]),
+    (0xFB4A, None, &[0x5EA, 0x5BC]),
+    (0xFB4B, None, &[0x5D5, 0x5B9]),
+    (0xFB4C, None, &[0x5D1, 0x5BF]),
+    (0xFB4D, None, &[0x5DB, 0x5BF]),
+    (0xFB4E, None, &[0x5E4, 0x5BF]),
+    (0xFB4F, Some("compat"), &[0x5D0, 0x5DC]),
+    (0xFB50, Some("isolated"), &[0x671]),
+    (0xFB51, Some("final"), &[0x671]),
+    (0xFB52, Some("isolated"), &[0x67B]),
+    (0xFB53, Some("final"), &[0x67B]),
+    (0xFB54, Some("initial"), &[0x67B]),
+    (0xFB55, Some("medial"), &[0x67B]),
+    (0xFB56, Some("isolated"), &[0x67E]),
+    (0xFB57, Some("final"), &[0x67E]),
+    (0xFB58, Some("initial"), &[0x67E]),
+    (0xFB59, Some("medial"), &[0x67E]),
+    (0xFB5A, Some("isolated"), &[0x680]),
+    (0xFB5B, Some("final"), &[0x680]),
+    (0xFB5C, Some("initial"), &[0x680]),
+    (0xFB5D, Some("medial"), &[0x680]),
+    (0xFB5E, Some("isolated"), &[0x67A]),
+    (0xFB5F, Some("final"), &[0x67A]),
+    (0xFB60, Some("initial"), &[0x67A]),
+    (0xFB61, Some("medial"), &[0x67A]),
+    (0xFB62, Some("isolated"), &[0x67F]),
+    (0xFB63, Some("final"), &[0x67F]),
+    (0xFB64, Some("initial"), &[0x67F]),
+    (0xFB65, Some("medial"), &[0x67F]),
+    (0xFB66, Some("isolated"), &[0x679]),
+    (0xFB67, Some("final"), &[0x679]),
+    (0xFB68, Some("initial"), &[0x679]),
+    (0xFB69, Some("medial"), &[0x679]),
+    (0xFB6A, Some("isolated"), &[0x6A4]),
+    (0xFB6B, Some("final"), &[0x6A4]),
+    (0xFB6C, Some("initial"), &[0x6A4]),
+    (0xFB6D, Some("medial"), &[0x6A4]),
+    (0xFB6E, Some("isolated"), &[0x6A6]),
+    (0xFB6F, Some("final"), &[0x6A6]),
+    (0xFB70, Some("initial"), &[0x6A6]),
+    (0xFB71, Some("medial"), &[0x6A6]),
+    (0xFB72, Some("isolated"), &[0x684]),
+    (0xFB73, Some("final"), &[0x684]),
+    (0xFB74, Some("initial"), &[0x684]),
+    (0xFB75, Some("medial"), &[0x684]),
+    (0xFB76, Some("isolated"), &[0x683]),
+    (0xFB77, Some("final"), &[0x683]),
+    (0xFB78, Some("initial"), &[0x683]),
+    (0xFB79, Some("medial"), &[0x683]),
+    (0xFB7A, Some("isolated"), &[0x686]),
+    (0xFB7B, Some("final"), &[0x686]),
+    (0xFB7C, Some("initial"), &[0x686]),
+    (0xFB7D, Some("medial"), &[0x686]),
+    (0xFB7E, Some("isolated"), &[0x687]),
+    (0xFB7F, Some("final"), &[0x687]),
+    (0xFB80, Some("initial"), &[0x687]),
+    (0xFB81, Some("medial"), &[0x687]),
+    (0xFB82, Some("isolated"), &[0x68D]),
+    (0xFB83, Some("final"), &[0x68D]),
+    (0xFB84, Some("isolated"), &[0x68C]),
+    (0xFB85, Some("final"), &[0x68C]),
+    (0xFB86, Some("isolated"), &[0x68E]),
+    (0xFB87, Some("final"), &[0x68E]),
+    (0xFB88, Some("isolated"), &[0x688]),
+    (0xFB89, Some("final"), &[0x688]),
+    (0xFB8A, Some("isolated"), &[0x698]),
+    (0xFB8B, Some("final"), &[0x698]),
+    (0xFB8C, Some("isolated"), &[0x691]),
+    (0xFB8D, Some("final"), &[0x691]),
+    (0xFB8E, Some("isolated"), &[0x6A9]),
+    (0xFB8F, Some("final"), &[0x6A9]),
+    (0xFB90, Some("initial"), &[0x6A9]),
+    (0xFB91, Some("medial"), &[0x6A9]),
+    (0xFB92, Some("isolated"), &[0x6AF]),
+    (0xFB93, Some("final"), &[0x6AF]),
+    (0xFB94, Some("initial"), &[0x6AF]),
+    (0xFB95, Some("medial"), &[0x6AF]),
+    (0xFB96, Some("isolated"), &[0x6B3]),
+    (0xFB97, Some("final"), &[0x6B3]),
+    (0xFB98, Some("initial"), &[0x6B3]),
+    (0xFB99, Some("medial"), &[0x6B3]),
+    (0xFB9A, Some("isolated"), &[0x6B1]),
+    (0xFB9B, Some("final"), &[0x6B1]),
+    (0xFB9C, Some("initial"), &[0x6B1]),
+    (0xFB9D, Some("medial"), &[0x6B1]),
+    (0xFB9E, Some("isolated"), &[0x6BA]),
+    (0xFB9F, Some("final"), &[0x6BA]),
+    (0xFBA0, Some("isolated"), &[0x6BB]),
+    (0xFBA1, Some("final"), &[0x6BB]),
+    (0xFBA2, Some("initial"), &[0x6BB]),
+    (0xFBA3, Some("medial"), &[0x6BB]),
+    (0xFBA4, Some("isolated"), &[0x6C0]),
+    (0xFBA5, Some("final"), &[0x6C0]),
+    (0xFBA6, Some("isolated"), &[0x6C1]),
+    (0xFBA7, Some("final"), &[0x6C1]),
+    (0xFBA8, Some("initial"), &[0x6C1]),
+    (0xFBA9, Some("medial"), &[0x6C1]),
+    (0xFBAA, Some("isolated"), &[0x6BE]),
+    (0xFBAB, Some("final"), &[0x6BE]),
+    (0xFBAC, Some("initial"), &[0x6BE]),
+    (0xFBAD, Some("medial"), &[0x6BE]),
+    (0xFBAE, Some("isolated"), &[0x6D2]),
+    (0xFBAF, Some("final"), &[0x6D2]),
+    (0xFBB0, Some("isolated"), &[0x6D3]),
+    (0xFBB1, Some("final"), &[0x6D3]),
+    (0xFBD3, Some("isolated"), &[0x6AD]),
+    (0xFBD4, Some("final"), &[0x6AD]),
+    (0xFBD5, Some("initial"), &[0x6AD]),
+    (0xFBD6, Some("medial"), &[0x6AD]),
+    (0xFBD7, Some("isolated"), &[0x6C7]),
+    (0xFBD8, Some("final"), &[0x6C7]),
+    (0xFBD9, Some("isolated"), &[0x6C6]),
+    (0xFBDA, Some("final"), &[0x6C6]),
+    (0xFBDB, Some("isolated"), &[0x6C8]),
+    (0xFBDC, Some("final"), &[0x6C8]),
+    (0xFBDD, Some("isolated"), &[0x677]),
+    (0xFBDE, Some("isolated"), &[0x6CB]),
+    (0xFBDF, Some("final"), &[0x6CB]),
+    (0xFBE0, Some("isolated"), &[0x6C5]),
+    (0xFBE1, Some("final"), &[0x6C5]),
+    (0xFBE2, Some("isolated"), &[0x6C9]),
+    (0xFBE3, Some("final"), &[0x6C9]),
+    (0xFBE4, Some("isolated"), &[0x6D0]),
+    (0xFBE5, Some("final"), &[0x6D0]),
+    (0xFBE6, Some("initial"), &[0x6D0]),
+    (0xFBE7, Some("medial"), &[0x6D0]),
+    (0xFBE8, Some("initial"), &[0x649]),
+    (0xFBE9, Some("medial"), &[0x649]),
+    (0xFBEA, Some("isolated"), &[0x626, 0x627]),
+    (0xFBEB, Some("final"), &[0x626, 0x627]),
+    (0xFBEC, Some("isolated"), &[0x626, 0x6D5]),
+    (0xFBED, Some("final"), &[0x626, 0x6D5]),
+    (0xFBEE, Some("isolated"), &[0x626, 0x648]),
+    (0xFBEF, Some("final"), &[0x626, 0x648]),
+    (0xFBF0, Some("isolated"), &[0x626, 0x6C7]),
+    (0xFBF1, Some("final"), &[0x626, 0x6C7]),
+    (0xFBF2, Some("isolated"), &[0x626, 0x6C6]),
+    (0xFBF3, Some("final"), &[0x626, 0x6C6]),
+    (0xFBF4, Some("isolated"), &[0x626, 0x6C8]),
+    (0xFBF5, Some("final"), &[0x626, 0x6C8]),
+    (0xFBF6, Some("isolated"), &[0x626, 0x6D0]),
+    (0xFBF7, Some("final"), &[0x626, 0x6D0]),
+    (0xFBF8, Some("initial"), &[0x626, 0x6D0]),
+    (0xFBF9, Some("isolated"), &[0x626, 0x649]),
+    (0xFBFA, Some("final"), &[0x626, 0x649]),
+    (0xFBFB, Some("initial"), &[0x626, 0x649]),
+    (0xFBFC, Some("isolated"), &[0x6CC]),
+    (0xFBFD, Some("final"), &[0x6CC]),
+    (0xFBFE, Some("initial"), &[0x6CC]),
+    (0xFBFF, Some("medial"), &[0x6CC]),
+    (0xFC00, Some("isolated"), &[0x626, 0x62C]),
+    (0xFC01, Some("isolated"), &[0x626, 0x62D]),
+    (0xFC02, Some("isolated"), &[0x626, 0x645]),
+    (0xFC03, Some("isolated"), &[0x626, 0x649]),
+    (0xFC04, Some("isolated"), &[0x626, 0x64A]),
+    (0xFC05, Some("isolated"), &[0x628, 0x62C]),
+    (0xFC06, Some("isolated"), &[0x628, 0x62D]),
+    (0xFC07, Some("isolated"), &[0x628, 0x62E]),
+    (0xFC08, Some("isolated"), &[0x628, 0x645]),
+    (0xFC09, Some("isolated"), &[0x628, 0x649]),
+    (0xFC0A, Some("isolated"), &[0x628, 0x64A]),
+    (0xFC0B, Some("isolated"), &[0x62A, 0x62C]),
+    (0xFC0C, Some("isolated"), &[0x62A, 0x62D]),
+    (0xFC0D, Some("isolated"), &[0x62A, 0x62E]),
+    (0xFC0E, Some("isolated"), &[0x62A, 0x645]),
+    (0xFC0F, Some("isolated"), &[0x62A, 0x649]),
+    (0xFC10, Some("isolated"), &[0x62A, 0x64A]),
+    (0xFC11, Some("isolated"), &[0x62B, 0x62C]),
+    (0xFC12, Some("isolated"), &[0x62B, 0x645]),
+    (0xFC13, Some("isolated"), &[0x62B, 0x649]),
+    (0xFC14, Some("isolated"), &[0x62B, 0x64A]),
+    (0xFC15, Some("isolated"), &[0x62C, 0x62D]),
+    (0xFC16, Some("isolated"), &[0x62C, 0x645]),
+    (0xFC17, Some("isolated"), &[0x62D, 0x62C]),
+    (0xFC18, Some("isolated"), &[0x62D, 0x645]),
+    (0xFC19, Some("isolated"), &[0x62E, 0x62C]),
+    (0xFC1A, Some("isolated"), &[0x62E, 0x62D]),
+    (0xFC1B, Some("isolated"), &[0x62E, 0x645]),
+    (0xFC1C, Some("isolated"), &[0x633, 0x62C]),
+    (0xFC1D, Some("isolated"), &[0x633, 0x62D]),
+    (0xFC1E, Some("isolated"), &[0x633, 0x62E]),
+    (0xFC1F, Some("isolated"), &[0x633, 0x645]),
+    (0xFC20, Some("isolated"), &[0x635, 0x62D]),
+    (0xFC21, Some("isolated"), &[0x635, 0x645]),
+    (0xFC22, Some("isolated"), &[0x636, 0x62C]),
+    (0xFC23, Some("isolated"), &[0x636, 0x62D]),
+    (0xFC24, Some("isolated"), &[0x636, 0x62E]),
+    (0xFC25, Some("isolated"), &[0x636, 0x645]),
+    (0xFC26, Some("isolated"), &[0x637, 0x62D]),
+    (0xFC27, Some("isolated"), &[0x637, 0x645]),
+    (0xFC28, Some("isolated"), &[0x638, 0x645]),
+    (0xFC29, Some("isolated"), &[0x639, 0x62C]),
+    (0xFC2A, Some("isolated"), &[0x639, 0x645]),
+    (0xFC2B, Some("isolated"), &[0x63A, 0x62C]),
+    (0xFC2C, Some("isolated"), &[0x63A, 0x645]),
+    (0xFC2D, Some("isolated"), &[0x641, 0x62C]),
+    (0xFC2E, Some("isolated"), &[0x641, 0x62D]),
+    (0xFC2F, Some("isolated"), &[0x641, 0x62E]),
+    (0xFC30, Some("isolated"), &[0x641, 0x645]),
+    (0xFC31, Some("isolated"), &[0x641, 0x649]),
+    (0xFC32, Some("isolated"), &[0x641, 0x64A]),
+    (0xFC33, Some("isolated"), &[0x642, 0x62D]),
+    (0xFC34, Some("isolated"), &[0x642, 0x645]),
+    (0xFC35, Some("isolated"), &[0x642, 0x649]),
+    (0xFC36, Some("isolated"), &[0x642, 0x64A]),
+    (0xFC37, Some("isolated"), &[0x643, 0x627]),
+    (0xFC38, Some("isolated"), &[0x643, 0x62C]),
+    (0xFC39, Some("isolated"), &[0x643, 0x62D]),
+    (0xFC3A, Some("isolated"), &[0x643, 0x62E]),
+    (0xFC3B, Some("isolated"), &[0x643, 0x644]),
+    (0xFC3C, Some("isolated"), &[0x643, 0x645]),
+    (0xFC3D, Some("isolated"), &[0x643, 0x649]),
+    (0xFC3E, Some("isolated"), &[0x643, 0x64A]),
+    (0xFC3F, Some("isolated"), &[0x644, 0x62C]),
+    (0xFC40, Some("isolated"), &[0x644, 0x62D]),
+    (0xFC41, Some("isolated"), &[0x644, 0x62E]),
+    (0xFC42, Some("isolated"), &[0x644, 0x645]),
+    (0xFC43, Some("isolated"), &[0x644, 0x649]),
+    (0xFC44, Some("isolated"), &[0x644, 0x64A]),
+    (0xFC45, Some("isolated"), &[0x645, 0x62C]),
+    (0xFC46, Some("isolated"), &[0x645, 0x62D]),
+    (0xFC47, Some("isolated"), &[0x645, 0x62E]),
+    (0xFC48, Some("isolated"), &[0x645, 0x645]),
+    (0xFC49, Some("isolated"), &[0x645, 0x649]),
+    (0xFC4A, Some("isolated"), &[0x645, 0x64A]),
+    (0xFC4B, Some("isolated"), &[0x646, 0x62C]),
+    (0xFC4C, Some("isolated"), &[0x646, 0x62D]),
+    (0xFC4D, Some("isolated"), &[0x646, 0x62E]),
+    (0xFC4E, Some("isolated"), &[0x646, 0x645]),
+    (0xFC4F, Some("isolated"), &[0x646, 0x649]),
+    (0xFC50, Some("isolated"), &[0x646, 0x64A]),
+    (0xFC51, Some("isolated"), &[0x647, 0x62C]),
+    (0xFC52, Some("isolated"), &[0x647, 0x645]),
+    (0xFC53, Some("isolated"), &[0x647, 0x649]),
+    (0xFC54, Some("isolated"), &[0x647, 0x64A]),
+    (0xFC55, Some("isolated"), &[0x64A, 0x62C]),
+    (0xFC56, Some("isolated"), &[0x64A, 0x62D]),
+    (0xFC57, Some("isolated"), &[0x64A, 0x62E]),
+    (0xFC58, Some("isolated"), &[0x64A, 0x645]),
+    (0xFC59, Some("isolated"), &[0x64A, 0x649]),
+    (0xFC5A, Some("isolated"), &[0x64A, 0x64A]),
+    (0xFC5B, Some("isolated"), &[0x630, 0x670]),
+    (0xFC5C, Some("isolated"), &[0x631, 0x670]),
+    (0xFC5D, Some("isolated"), &[0x649, 0x670]),
+    (0xFC5E, Some("isolated"), &[0x20, 0x64C, 0x651]),
+    (0xFC5F, Some("isolated"), &[0x20, 0x64D, 0x651]),
+    (0xFC60, Some("isolated"), &[0x20, 0x64E, 0x651]),
+    (0xFC61, Some("isolated"), &[0x20, 0x64F, 0x651]),
+    (0xFC62, Some("isolated"), &[0x20, 0x650, 0x651]),
+    (0xFC63, Some("isolated"), &[0x20, 0x651, 0x670]),
+    (0xFC64, Some("final"), &[0x626, 0x631]),
+    (0xFC65, Some("final"), &[0x626, 0x632]),
+    (0xFC66, Some("final"), &[0x626, 0x645]),
+    (0xFC67, Some("final"), &[0x626, 0x646]),
+    (0xFC68, Some("final"), &[0x626, 0x649]),
+    (0xFC69, Some("final"), &[0x626, 0x64A]),
+    (0xFC6A, Some("final"), &[0x628, 0x631]),
+    (0xFC6B, Some("final"), &[0x628, 0x632]),
+    (0xFC6C, Some("final"), &[0x628, 0x645]),
+    (0xFC6D, Some("final"), &[0x628, 0x646]),
+    (0xFC6E, Some("final"), &[0x628, 0x649]),
+    (0xFC6F, Some("final"), &[0x628, 0x64A]),
+    (0xFC70, Some("final"), &[0x62A, 0x631]),
+    (0xFC71, Some("final"), &[0x62A, 0x632]),
+    (0xFC72, Some("final"), &[0x62A, 0x645]),
+    (0xFC73, Some("final"), &[0x62A, 0x646]),
+    (0xFC74, Some("final"), &[0x62A, 0x649]),
+    (0xFC75, Some("final"), &[0x62A, 0x64A]),
+    (0xFC76, Some("final"), &[0x62B, 0x631]),
+    (0xFC77, Some("final"), &[0x62B, 0x632]),
+    (0xFC78, Some("final"), &[0x62B, 0x645]),
+    (0xFC79, Some("final"), &[0x62B, 0x646]),
+    (0xFC7A, Some("final"), &[0x62B, 0x649]),
+    (0xFC7B, Some("final"), &[0x62B, 0x64A]),
+    (0xFC7C, Some("final"), &[0x641, 0x649]),
+    (0xFC7D, Some("final"), &[0x641, 0x64A]),
+    (0xFC7E, Some("final"), &[0x642, 0x649]),
+    (0xFC7F, Some("final"), &[0x642, 0x64A]),
+    (0xFC80, Some("final"), &[0x643, 0x627]),
+    (0xFC81, Some("final"), &[0x643, 0x644]),
+    (0xFC82, Some("final"), &[0x643, 0x645]),
+    (0xFC83, Some("final"), &[0x643, 0x649]),
+    (0xFC84, Some("final"), &[0x643, 0x64A]),
+    (0xFC85, Some("final"), &[0x644, 0x645]),
+    (0xFC86, Some("final"), &[0x644, 0x649]),
+    (0xFC87, Some("final"), &[0x644, 0x64A]),
+    (0xFC88, Some("final"), &[0x645, 0x627]),
+    (0xFC89, Some("final"), &[0x645, 0x645]),
+    (0xFC8A, Some("final"), &[0x646, 0x631]),
+    (0xFC8B, Some("final"), &[0x646, 0x632]),
+    (0xFC8C, Some("final"), &[0x646, 0x645]),
+    (0xFC8D, Some("final"), &[0x646, 0x646]),
+    (0xFC8E, Some("final"), &[0x646, 0x649]),
+    (0xFC8F, Some("final"), &[0x646, 0x64A]),
+    (0xFC90, Some("final"), &[0x649, 0x670]),
+    (0xFC91, Some("final"), &[0x64A, 0x631]),
+    (0xFC92, Some("final"), &[0x64A, 0x632]),
+    (0xFC93, Some("final"), &[0x64A, 0x645]),
+    (0xFC94, Some("final"), &[0x64A, 0x646]),
+    (0xFC95, Some("final"), &[0x64A, 0x649]),
+    (0xFC96, Some("final"), &[0x64A, 0x64A]),
+    (0xFC97, Some("initial"), &[0x626, 0x62C]),
+    (0xFC98, Some("initial"), &[0x626, 0x62D]),
+    (0xFC99, Some("initial"), &[0x626, 0x62E]),
+    (0xFC9A, Some("initial"), &[0x626, 0x645]),
+    (0xFC9B, Some("initial"), &[0x626, 0x647]),
+    (0xFC9C, Some("initial"), &[0x628, 0x62C]),
+    (0xFC9D, Some("initial"), &[0x628, 0x62D]),
+    (0xFC9E, Some("initial"), &[0x628, 0x62E]),
+    (0xFC9F, Some("initial"), &[0x628, 0x645]),
+    (0xFCA0, Some("initial"), &[0x628, 0x647]),
+    (0xFCA1, Some("initial"), &[0x62A, 0x62C]),
+    (0xFCA2, Some("initial"), &[0x62A, 0x62D]),
+    (0xFCA3, Some("initial"), &[0x62A, 0x62E]),
+    (0xFCA4, Some("initial"), &[0x62A, 0x645]),
+    (0xFCA5, Some("initial"), &[0x62A, 0x647]),
+    (0xFCA6, Some("initial"), &[0x62B, 0x645]),
+    (0xFCA7, Some("initial"), &[0x62C, 0x62D]),
+    (0xFCA8, Some("initial"), &[0x62C, 0x645]),
+    (0xFCA9, Some("initial"), &[0x62D, 0x62C]),
+    (0xFCAA, Some("initial"), &[0x62D, 0x645]),
+    (0xFCAB, Some("initial"), &[0x62E, 0x62C]),
+    (0xFCAC, Some("initial"), &[0x62E, 0x645]),
+    (0xFCAD, Some("initial"), &[0x633, 0x62C]),
+    (0xFCAE, Some("initial"), &[0x633, 0x62D]),
+    (0xFCAF, Some("initial"), &[0x633, 0x62E]),
+    (0xFCB0, Some("initial"), &[0x633, 0x645]),
+    (0xFCB1, Some("initial"), &[0x635, 0x62D]),
+    (0xFCB2, Some("initial"), &[0x635, 0x62E]),
+    (0xFCB3, Some("initial"), &[0x635, 0x645]),
+    (0xFCB4, Some("initial"), &[0x636, 0x62C]),
+    (0xFCB5, Some("initial"), &[0x636, 0x62D]),
+    (0xFCB6, Some("initial"), &[0x636, 0x62E]),
+    (0xFCB7, Some("initial"), &[0x636, 0x645]),
+    (0xFCB8, Some("initial"), &[0x637, 0x62D]),
+    (0xFCB9, Some("initial"), &[0x638, 0x645]),
+    (0xFCBA, Some("initial"), &[0x639, 0x62C]),
+    (0xFCBB, Some("initial"), &[0x639, 0x645]),
+    (0xFCBC, Some("initial"), &[0x63A, 0x62C]),
+    (0xFCBD, Some("initial"), &[0x63A, 0x645]),
+    (0xFCBE, Some("initial"), &[0x641, 0x62C]),
+    (0xFCBF, Some("initial"), &[0x641, 0x62D]),
+    (0xFCC0, Some("initial"), &[0x641, 0x62E]),
+    (0xFCC1, Some("initial"), &[0x641, 0x645]),
+    (0xFCC2, Some("initial"), &[0x642, 0x62D]),
+    (0xFCC3, Some("initial"), &[0x642, 0x645]),
+    (0xFCC4, Some("initial"), &[0x643, 0x62C]),
+    (0xFCC5, Some("initial"), &[0x643, 0x62D]),
+    (0xFCC6, Some("initial"), &[0x643, 0x62E]),
+    (0xFCC7, Some("initial"), &[0x643, 0x644]),
+    (0xFCC8, Some("initial"), &[0x643, 0x645]),
+    (0xFCC9, Some("initial"), &[0x644, 0x62C]),
+    (0xFCCA, Some("initial"), &[0x644, 0x62D]),
+    (0xFCCB, Some("initial"), &[0x644, 0x62E]),
+    (0xFCCC, Some("initial"), &[0x644, 0x645]),
+    (0xFCCD, Some("initial"), &[0x644, 0x647]),
+    (0xFCCE, Some("initial"), &[0x645, 0x62C]),
+    (0xFCCF, Some("initial"), &[0x645, 0x62D]),
+    (0xFCD0, Some("initial"), &[0x645, 0x62E]),
+    (0xFCD1, Some("initial"), &[0x645, 0x645]),
+    (0xFCD2, Some("initial"), &[0x646, 0x62C]),
+    (0xFCD3, Some("initial"), &[0x646, 0x62D]),
+    (0xFCD4, Some("initial"), &[0x646, 0x62E]),
+    (0xFCD5, Some("initial"), &[0x646, 0x645]),
+    (0xFCD6, Some("initial"), &[0x646, 0x647]),
+    (0xFCD7, Some("initial"), &[0x647, 0x62C]),
+    (0xFCD8, Some("initial"), &[0x647, 0x645]),
+    (0xFCD9, Some("initial"), &[0x647, 0x670]),
+    (0xFCDA, Some("initial"), &[0x64A, 0x62C]),
+    (0xFCDB, Some("initial"), &[0x64A, 0x62D]),
+    (0xFCDC, Some("initial"), &[0x64A, 0x62E]),
+    (0xFCDD, Some("initial"), &[0x64A, 0x645]),
+    (0xFCDE, Some("initial"), &[0x64A, 0x647]),
+    (0xFCDF, Some("medial"), &[0x626, 0x645]),
+    (0xFCE0, Some("medial"), &[0x626, 0x647]),
+    (0xFCE1, Some("medial"), &[0x628, 0x645]),
+    (0xFCE2, Some("medial"), &[0x628, 0x647]),
+    (0xFCE3, Some("medial"), &[0x62A, 0x645]),
+    (0xFCE4, Some("medial"), &[0x62A, 0x647]),
+    (0xFCE5, Some("medial"), &[0x62B, 0x645]),
+    (0xFCE6, Some("medial"), &[0x62B, 0x647]),
+    (0xFCE7, Some("medial"), &[0x633, 0x645]),
+    (0xFCE8, Some("medial"), &[0x633, 0x647]),
+    (0xFCE9, Some("medial"), &[0x634, 0x645]),
+    (0xFCEA, Some("medial"), &[0x634, 0x647]),
+    (0xFCEB, Some("medial"), &[0x643, 0x644]),
+    (0xFCEC, Some("medial"), &[0x643, 0x645]),
+    (0xFCED, Some("medial"), &[0x644, 0x645]),
+    (0xFCEE, Some("medial"), &[0x646, 0x645]),
+    (0xFCEF, Some("medial"), &[0x646, 0x647]),
+    (0xFCF0, Some("medial"), &[0x64A, 0x645]),
+    (0xFCF1, Some("medial"), &[0x64A, 0x647]),
+    (0xFCF2, Some("medial"), &[0x640, 0x64E, 0x651]),
+    (0xFCF3, Some("medial"), &[0x640, 0x64F, 0x651]),
+    (0xFCF4, Some("medial"), &[0x640, 0x650, 0x651]),
+    (0xFCF5, Some("isolated"), &[0x637, 0x649]),
+    (0xFCF6, Some("isolated"), &[0x637, 0x64A]),
+    (0xFCF7, Some("isolated"), &[0x639, 0x649]),
+    (0xFCF8, Some("isolated"), &[0x639, 0x64A]),
+    (0xFCF9, Some("isolated"), &[0x63A, 0x649]),
+    (0xFCFA, Some("isolated"), &[0x63A, 0x64A]),
+    (0xFCFB, Some("isolated"), &[0x633, 0x649]),
+    (0xFCFC, Some("isolated"), &[0x633, 0x64A]),
+    (0xFCFD, Some("isolated"), &[0x634, 0x649]),
+    (0xFCFE, Some("isolated"), &[0x634, 0x64A]),
+    (0xFCFF, Some("isolated"), &[0x62D, 0x649]),
+    (0xFD00, Some("isolated"), &[0x62D, 0x64A]),
+    (0xFD01, Some("isolated"), &[0x62C, 0x649]),
+    (0xFD02, Some("isolated"), &[0x62C, 0x64A]),
+    (0xFD03, Some("isolated"), &[0x62E, 0x649]),
+    (0xFD04, Some("isolated"), &[0x62E, 0x64A]),
+    (0xFD05, Some("isolated"), &[0x635, 0x649]),
+    (0xFD06, Some("isolated"), &[0x635, 0x64A]),
+    (0xFD07, Some("isolated"), &[0x636, 0x649]),
+    (0xFD08, Some("isolated"), &[0x636, 0x64A]),
+    (0xFD09, Some("isolated"), &[0x634, 0x62C]),
+    (0xFD0A, Some("isolated"), &[0x634, 0x62D]),
+    (0xFD0B, Some("isolated"), &[0x634, 0x62E]),
+    (0xFD0C, Some("isolated"), &[0x634, 0x645]),
+    (0xFD0D, Some("isolated"), &[0x634, 0x631]),
+    (0xFD0E, Some("isolated"), &[0x633, 0x631]),
+    (0xFD0F, Some("isolated"), &[0x635, 0x631]),
+    (0xFD10, Some("isolated"), &[0x636, 0x631]),
+    (0xFD11, Some("final"), &[0x637, 0x649]),
+    (0xFD12, Some("final"), &[0x637, 0x64A]),
+    (0xFD13, Some("final"), &[0x639, 0x649]),
+    (0xFD14, Some("final"), &[0x639, 0x64A]),
+    (0xFD15, Some("final"), &[0x63A, 0x649]),
+    (0xFD16, Some("final"), &[0x63A, 0x64A]),
+    (0xFD17, Some("final"), &[0x633, 0x649]),
+    (0xFD18, Some("final"), &[0x633, 0x64A]),
+    (0xFD19, Some("final"), &[0x634, 0x649]),
+    (0xFD1A, Some("final"), &[0x634, 0x64A]),
+    (0xFD1B, Some("final"), &[0x62D, 0x649]),
+    (0xFD1C, Some("final"), &[0x62D, 0x64A]),
+    (0xFD1D, Some("final"), &[0x62C, 0x649]),
+    (0xFD1E, Some("final"), &[0x62C, 0x64A]),
+    (0xFD1F, Some("final"), &[0x62E, 0x649]),
+    (0xFD20, Some("final"), &[0x62E, 0x64A]),
+    (0xFD21, Some("final"), &[0x635, 0x649]),
+    (0xFD22, Some("final"), &[0x635, 0x64A]),
+    (0xFD23, Some("final"), &[0x636, 0x649]),
+    (0xFD24, Some("final"), &[0x636, 0x64A]),
+    (0xFD25, Some("final"), &[0x634, 0x62C]),
+    (0xFD26, Some("final"), &[0x634, 0x62D]),
+    (0xFD27, Some("final"), &[0x634, 0x62E]),
+    (0xFD28, Some("final"), &[0x634, 0x645]),
+    (0xFD29, Some("final"), &[0x634, 0x631]),
+    (0xFD2A, Some("final"), &[0x633, 0x631]),
+    (0xFD2B, Some("final"), &[0x635, 0x631]),
+    (0xFD2C, Some("final"), &[0x636, 0x631]),
+    (0xFD2D, Some("initial"), &[0x634, 0x62C]),
+    (0xFD2E, Some("initial"), &[0x634, 0x62D]),
+    (0xFD2F, Some("initial"), &[0x634, 0x62E]),
+    (0xFD30, Some("initial"), &[0x634, 0x645]),
+    (0xFD31, Some("initial"), &[0x633, 0x647]),
+    (0xFD32, Some("initial"), &[0x634, 0x647]),
+    (0xFD33, Some("initial"), &[0x637, 0x645]),
+    (0xFD34, Some("medial"), &[0x633, 0x62C]),
+    (0xFD35, Some("medial"), &[0x633, 0x62D]),
+    (0xFD36, Some("medial"), &[0x633, 0x62E]),
+    (0xFD37, Some("medial"), &[0x634, 0x62C]),
+    (0xFD38, Some("medial"), &[0x634, 0x62D]),
+    (0xFD39, Some("medial"), &[0x634, 0x62E]),
+    (0xFD3A, Some("medial"), &[0x637, 0x645]),
+    (0xFD3B, Some("medial"), &[0x638, 0x645]),
+    (0xFD3C, Some("final"), &[0x627, 0x64B]),
+    (0xFD3D, Some("isolated"), &[0x627, 0x64B]),
+    (0xFD50, Some("initial"), &[0x62A, 0x62C, 0x645]),
+    (0xFD51, Some("final"), &[0x62A, 0x62D, 0x62C]),
+    (0xFD52, Some("initial"), &[0x62A, 0x62D, 0x62C]),
+    (0xFD53, Some("initial"), &[0x62A, 0x62D, 0x645]),
+    (0xFD54, Some("initial"), &[0x62A, 0x62E, 0x645]),
+    (0xFD55, Some("initial"), &[0x62A, 0x645, 0x62C]),
+    (0xFD56, Some("initial"), &[0x62A, 0x645, 0x62D]),
+    (0xFD57, Some("initial"), &[0x62A, 0x645, 0x62E]),
+    (0xFD58, Some("final"), &[0x62C, 0x645, 0x62D]),
+    (0xFD59, Some("initial"), &[0x62C, 0x645, 0x62D]),
+    (0xFD5A, Some("final"), &[0x62D, 0x645, 0x64A]),
+    (0xFD5B, Some("final"), &[0x62D, 0x645, 0x649]),
+    (0xFD5C, Some("initial"), &[0x633, 0x62D, 0x62C]),
+    (0xFD5D, Some("initial"), &[0x633, 0x62C, 0x62D]),
+    (0xFD5E, Some("final"), &[0x633, 0x62C, 0x649]),
+    (0xFD5F, Some("final"), &[0x633, 0x645, 0x62D]),
+    (0xFD60, Some("initial"), &[0x633, 0x645, 0x62D]),
+    (0xFD61, Some("initial"), &[0x633, 0x645, 0x62C]),
+    (0xFD62, Some("final"), &[0x633, 0x645, 0x645]),
+    (0xFD63, Some("initial"), &[0x633, 0x645, 0x645]),
+    (0xFD64, Some("final"), &[0x635, 0x62D, 0x62D]),
+    (0xFD65, Some("initial"), &[0x635, 0x62D, 0x62D]),
+    (0xFD66, Some("final"), &[0x635, 0x645, 0x645]),
+    (0xFD67, Some("final"), &[0x634, 0x62D, 0x645]),
+    (0xFD68, Some("initial"), &[0x634, 0x62D, 0x645]),
+    (0xFD69, Some("final"), &[0x634, 0x62C, 0x64A]),
+    (0xFD6A, Some("final"), &[0x634, 0x645, 0x62E]),
+    (0xFD6B, Some("initial"), &[0x634, 0x645, 0x62E]),
+    (0xFD6C, Some("final"), &[0x634, 0x645, 0x645]),
+    (0xFD6D, Some("initial"), &[0x634, 0x645, 0x645]),
+    (0xFD6E, Some("final"), &[0x636, 0x62D, 0x649]),
+    (0xFD6F, Some("final"), &[0x636, 0x62E, 0x645]),
+    (0xFD70, Some("initial"), &[0x636, 0x62E, 0x645]),
+    (0xFD71, Some("final"), &[0x637, 0x645, 0x62D]),
+    (0xFD72, Some("initial"), &[0x637, 0x645, 0x62D]),
+    (0xFD73, Some("initial"), &[0x637, 0x645, 0x645]),
+    (0xFD74, Some("final"), &[0x637, 0x645, 0x64A]),
+    (0xFD75, Some("final"), &[0x639, 0x62C, 0x645]),
+    (0xFD76, Some("final"), &[0x639, 0x645, 0x645]),
+    (0xFD77, Some("initial"), &[0x639, 0x645, 0x645]),
+    (0xFD78, Some("final"), &[0x639, 0x645, 0x649]),
+    (0xFD79, Some("final"), &[0x63A, 0x645, 0x645]),
+    (0xFD7A, Some("final"), &[0x63A, 0x645, 0x64A]),
+    (0xFD7B, Some("final"), &[0x63A, 0x645, 0x649]),
+    (0xFD7C, Some("final"), &[0x641, 0x62E, 0x645]),
+    (0xFD7D, Some("initial"), &[0x641, 0x62E, 0x645]),
+    (0xFD7E, Some("final"), &[0x642, 0x645, 0x62D]),
+    (0xFD7F, Some("final"), &[0x642, 0x645, 0x645]),
+    (0xFD80, Some("final"), &[0x644, 0x62D, 0x645]),
+    (0xFD81, Some("final"), &[0x644, 0x62D, 0x64A]),
+    (0xFD82, Some("final"), &[0x644, 0x62D, 0x649]),
+    (0xFD83, Some("initial"), &[0x644, 0x62C, 0x62C]),
+    (0xFD84, Some("final"), &[0x644, 0x62C, 0x62C]),
+    (0xFD85, Some("final"), &[0x644, 0x62E, 0x645]),
+    (0xFD86, Some("initial"), &[0x644, 0x62E, 0x645]),
+    (0xFD87, Some("final"), &[0x644, 0x645, 0x62D]),
+    (0xFD88, Some("initial"), &[0x644, 0x645, 0x62D]),
+    (0xFD89, Some("initial"), &[0x645, 0x62D, 0x62C]),
+    (0xFD8A, Some("initial"), &[0x645, 0x62D, 0x645]),
+    (0xFD8B, Some("final"), &[0x645, 0x62D, 0x64A]),
+    (0xFD8C, Some("initial"), &[0x645, 0x62C, 0x62D]),
+    (0xFD8D, Some("initial"), &[0x645, 0x62C, 0x645]),
+    (0xFD8E, Some("initial"), &[0x645, 0x62E, 0x62C]),
+    (0xFD8F, Some("initial"), &[0x645, 0x62E, 0x645]),
+    (0xFD92, Some("initial"), &[0x645, 0x62C, 0x62E]),
+    (0xFD93, Some("initial"), &[0x647, 0x645, 0x62C]),
+    (0xFD94, Some("initial"), &[0x647, 0x645, 0x645]),
+    (0xFD95, Some("initial"), &[0x646, 0x62D, 0x645]),
+    (0xFD96, Some("final"), &[0x646, 0x62D, 0x649]),
+    (0xFD97, Some("final"), &[0x646, 0x62C, 0x645]),
+    (0xFD98, Some("initial"), &[0x646, 0x62C, 0x645]),
+    (0xFD99, Some("final"), &[0x646, 0x62C, 0x649]),
+    (0xFD9A, Some("final"), &[0x646, 0x645, 0x64A]),
+    (0xFD9B, Some("final"), &[0x646, 0x645, 0x649]),
+    (0xFD9C, Some("final"), &[0x64A, 0x645, 0x645]),
+    (0xFD9D, Some("initial"), &[0x64A, 0x645, 0x645]),
+    (0xFD9E, Some("final"), &[0x628, 0x62E, 0x64A]),
+    (0xFD9F, Some("final"), &[0x62A, 0x62C, 0x64A]),
+    (0xFDA0, Some("final"), &[0x62A, 0x62C, 0x649]),
+    (0xFDA1, Some("final"), &[0x62A, 0x62E, 0x64A]),
+    (0xFDA2, Some("final"), &[0x62A, 0x62E, 0x649]),
+    (0xFDA3, Some("final"), &[0x62A, 0x645, 0x64A]),
+    (0xFDA4, Some("final"), &[0x62A, 0x645, 0x649]),
+    (0xFDA5, Some("final"), &[0x62C, 0x645, 0x64A]),
+    (0xFDA6, Some("final"), &[0x62C, 0x62D, 0x649]),
+    (0xFDA7, Some("final"), &[0x62C, 0x645, 0x649]),
+    (0xFDA8, Some("final"), &[0x633, 0x62E, 0x649]),
+    (0xFDA9, Some("final"), &[0x635, 0x62D, 0x64A]),
+    (0xFDAA, Some("final"), &[0x634, 0x62D, 0x64A]),
+    (0xFDAB, Some("final"), &[0x636, 0x62D, 0x64A]),
+    (0xFDAC, Some("final"), &[0x644, 0x62C, 0x64A]),
+    (0xFDAD, Some("final"), &[0x644, 0x645, 0x64A]),
+    (0xFDAE, Some("final"), &[0x64A, 0x62D, 0x64A]),
+    (0xFDAF, Some("final"), &[0x64A, 0x62C, 0x64A]),
+    (0xFDB0, Some("final"), &[0x64A, 0x645, 0x64A]),
+    (0xFDB1, Some("final"), &[0x645, 0x645, 0x64A]),
+    (0xFDB2, Some("final"), &[0x642, 0x645, 0x64A]),
+    (0xFDB3, Some("final"), &[0x646, 0x62D, 0x64A]),
+    (0xFDB4, Some("initial"), &[0x642, 0x645, 0x62D]),
+    (0xFDB5, Some("initial"), &[0x644, 0x62D, 0x645]),
+    (0xFDB6, Some("final"), &[0x639, 0x645, 0x64A]),
+    (0xFDB7, Some("final"), &[0x643, 0x645, 0x64A]),
+    (0xFDB8, Some("initial"), &[0x646, 0x62C, 0x62D]),
+    (0xFDB9, Some("final"), &[0x645, 0x62E, 0x64A]),
+    (0xFDBA, Some("initial"), &[0x644, 0x62C, 0x645]),
+    (0xFDBB, Some("final"), &[0x643, 0x645, 0x645]),
+    (0xFDBC, Some("final"), &[0x644, 0x62C, 0x645]),
+    (0xFDBD, Some("final"), &[0x646, 0x62C, 0x62D]),
+    (0xFDBE, Some("final"), &[0x62C, 0x62D, 0x64A]),
+    (0xFDBF, Some("final"), &[0x62D, 0x62C, 0x64A]),
+    (0xFDC0, Some("final"), &[0x645, 0x62C, 0x64A]),
+    (0xFDC1, Some("final"), &[0x641, 0x645, 0x64A]),
+    (0xFDC2, Some("final"), &[0x628, 0x62D, 0x64A]),
+    (0xFDC3, Some("initial"), &[0x643, 0x645, 0x645]),
+    (0xFDC4, Some("initial"), &[0x639, 0x62C, 0x645]),
+    (0xFDC5, Some("initial"), &[0x635, 0x645, 0x645]),
+    (0xFDC6, Some("final"), &[0x633, 0x62E, 0x64A]),
+    (0xFDC7, Some("final"), &[0x646, 0x62C, 0x64A]),
+    (0xFDF0, Some("isolated"), &[0x635, 0x644, 0x6D2]),
+    (0xFDF1, Some("isolated"), &[0x642, 0x644, 0x6D2]),
+    (0xFDF2, Some("isolated"), &[0x627, 0x644, 0x644, 0x647]),
+    (0xFDF3, Some("isolated"), &[0x627, 0x643, 0x628, 0x631]),
+    (0xFDF4, Some("isolated"), &[0x645, 0x62D, 0x645, 0x62F]),
+    (0xFDF5, Some("isolated"), &[0x635, 0x644, 0x639, 0x645]),
+    (0xFDF6, Some("isolated"), &[0x631, 0x633, 0x648, 0x644]),
+    (0xFDF7, Some("isolated"), &[0x639, 0x644, 0x64A, 0x647]),
+    (0xFDF8, Some("isolated"), &[0x648, 0x633, 0x644, 0x645]),
+    (0xFDF9, Some("isolated"), &[0x635, 0x644, 0x649]),
+    (0xFDFA, Some("isolated"), &[0x635, 0x644, 0x649, 0x20, 0x627, 0x644, 0x644, 0x647, 0x20, 0x639, 0x644, 0x64A, 0x647, 0x20, 0x648, 0x633, 0x644, 0x645]),
+    (0xFDFB, Some("isolated"), &[0x62C, 0x644, 0x20, 0x62C, 0x644, 0x627, 0x644, 0x647]),
+    (0xFDFC, Some("isolated"), &[0x631, 0x6CC, 0x627, 0x644]),
+    (0xFE10, Some("vertical"), &[0x2C]),
+    (0xFE11, Some("vertical"), &[0x3001]),
+    (0xFE12, Some("vertical"), &[0x3002]),
+    (0xFE13, Some("vertical"), &[0x3A]),
+    (0xFE14, Some("vertical"), &[0x3B]),
+    (0xFE15, Some("vertical"), &[0x21]),
+    (0xFE16, Some("vertical"), &[0x3F]),
+    (0xFE17, Some("vertical"), &[0x3016]),
+    (0xFE18, Some("vertical"), &[0x3017]),
+    (0xFE19, Some("vertical"), &[0x2026]),
+    (0xFE30, Some("vertical"), &[0x2025]),
+    (0xFE31, Some("vertical"), &[0x2014]),
+    (0xFE32, Some("vertical"), &[0x2013]),
+    (0xFE33, Some("vertical"), &[0x5F]),
+    (0xFE34, Some("vertical"), &[0x5F]),
+    (0xFE35, Some("vertical"), &[0x28]),
+    (0xFE36, Some("vertical"), &[0x29]),
+    (0xFE37, Some("vertical"), &[0x7B]),
+    (0xFE38, Some("vertical"), &[0x7D]),
+    (0xFE39, Some("vertical"), &[0x3014]),
+    (0xFE3A, Some("vertical"), &[0x3015]),
+    (0xFE3B, Some("vertical"), &[0x3010]),
+    (0xFE3C, Some("vertical"), &[0x3011]),
+    (0xFE3D, Some("vertical"), &[0x300A]),
+    (0xFE3E, Some("vertical"), &[0x300B]),
+    (0xFE3F, Some("vertical"), &[0x3008]),
+    (0xFE40, Some("vertical"), &[0x3009]),
+    (0xFE41, Some("vertical"), &[0x300C]),
+    (0xFE42, Some("vertical"), &[0x300D]),
+    (0xFE43, Some("vertical"), &[0x300E]),
+    (0xFE44, Some("vertical"), &[0x300F]),
+    (0xFE47, Some("vertical"), &[0x5B]),
+    (0xFE48, Some("vertical"), &[0x5D]),
+    (0xFE49, Some("compat"), &[0x203E]),
+    (0xFE4A, Some("compat"), &[0x203E]),
+    (0xFE4B, Some("compat"), &[0x203E]),
+    (0xFE4C, Some("compat"), &[0x203E]),
+    (0xFE4D, Some("compat"), &[0x5F]),
+    (0xFE4E, Some("compat"), &[0x5F]),
+    (0xFE4F, Some("compat"), &[0x5F]),
+    (0xFE50, Some("small"), &[0x2C]),
+    (0xFE51, Some("small"), &[0x3001]),
+    (0xFE52, Some("small"), &[0x2E]),
+    (0xFE54, Some("small"), &[0x3B]),
+    (0xFE55, Some("small"), &[0x3A]),
+    (0xFE56, Some("small"), &[0x3F]),
+    (0xFE57, Some("small"), &[0x21]),
+    (0xFE58, Some("small"), &[0x2014]),
+    (0xFE59, Some("small"), &[0x28]),
+    (0xFE5A, Some("small"), &[0x29]),
+    (0xFE5B, Some("small"), &[0x7B]),
+    (0xFE5C, Some("small"), &[0x7D]),
+    (0xFE5D, Some("small"), &[0x3014]),
+    (0xFE5E, Some("small"), &[0x3015]),
+    (0xFE5F, Some("small"), &[0x23]),
+    (0xFE60, Some("small"), &[0x26]),
+    (0xFE61, Some("small"), &[0x2A]),
+    (0xFE62, Some("small"), &[0x2B]),
+    (0xFE63, Some("small"), &[0x2D]),
+    (0xFE64, Some("small"), &[0x3C]),
+    (0xFE65, Some("small"), &[0x3E]),
+    (0xFE66, Some("small"), &[0x3D]),
+    (0xFE68, Some("small"), &[0x5C]),
+    (0xFE69, Some("small"), &[0x24]),
+    (0xFE6A, Some("small"), &[0x25]),
+    (0xFE6B, Some("small"), &[0x40]),
+    (0xFE70, Some("isolated"), &[0x20, 0x64B]),
+    (0xFE71, Some("medial"), &[0x640, 0x64B]),
+    (0xFE72, Some("isolated"), &[0x20, 0x64C]),
+    (0xFE74, Some("isolated"), &[0x20, 0x64D]),
+    (0xFE76, Some("isolated"), &[0x20, 0x64E]),
+    (0xFE77, Some("medial"), &[0x640, 0x64E]),
+    (0xFE78, Some("isolated"), &[0x20, 0x64F]),
+    (0xFE79, Some("medial"), &[0x640, 0x64F]),
+    (0xFE7A, Some("isolated"), &[0x20, 0x650]),
+    (0xFE7B, Some("medial"), &[0x640, 0x650]),
+    (0xFE7C, Some("isolated"), &[0x20, 0x651]),
+    (0xFE7D, Some("medial"), &[0x640, 0x651]),
+    (0xFE7E, Some("isolated"), &[0x20, 0x652]),
+    (0xFE7F, Some("medial"), &[0x640, 0x652]),
+    (0xFE80, Some("isolated"), &[0x621]),
+    (0xFE81, Some("isolated"), &[0x622]),
+    (0xFE82, Some("final"), &[0x622]),
+    (0xFE83, Some("isolated"), &[0x623]),
+    (0xFE84, Some("final"), &[0x623]),
+    (0xFE85, Some("isolated"), &[0x624]),
+    (0xFE86, Some("final"), &[0x624]),
+    (0xFE87, Some("isolated"), &[0x625]),
+    (0xFE88, Some("final"), &[0x625]),
+    (0xFE89, Some("isolated"), &[0x626]),
+    (0xFE8A, Some("final"), &[0x626]),
+    (0xFE8B, Some("initial"), &[0x626]),
+    (0xFE8C, Some("medial"), &[0x626]),
+    (0xFE8D, Some("isolated"), &[0x627]),
+    (0xFE8E, Some("final"), &[0x627]),
+    (0xFE8F, Some("isolated"), &[0x628]),
+    (0xFE90, Some("final"), &[0x628]),
+    (0xFE91, Some("initial"), &[0x628]),
+    (0xFE92, Some("medial"), &[0x628]),
+    (0xFE93, Some("isolated"), &[0x629]),
+    (0xFE94, Some("final"), &[0x629]),
+    (0xFE95, Some("isolated"), &[0x62A]),
+    (0xFE96, Some("final"), &[0x62A]),
+    (0xFE97, Some("initial"), &[0x62A]),
+    (0xFE98, Some("medial"), &[0x62A]),
+    (0xFE99, Some("isolated"), &[0x62B]),
+    (0xFE9A, Some("final"), &[0x62B]),
+    (0xFE9B, Some("initial"), &[0x62B]),
+    (0xFE9C, Some("medial"), &[0x62B]),
+    (0xFE9D, Some("isolated"), &[0x62C]),
+    (0xFE9E, Some("final"), &[0x62C]),
+    (0xFE9F, Some("initial"), &[0x62C]),
+    (0xFEA0, Some("medial"), &[0x62C]),
+    (0xFEA1, Some("isolated"), &[0x62D]),
+    (0xFEA2, Some("final"), &[0x62D]),
+    (0xFEA3, Some("initial"), &[0x62D]),
+    (0xFEA4, Some("medial"), &[0x62D]),
+    (0xFEA5, Some("isolated"), &[0x62E]),
+    (0xFEA6, Some("final"), &[0x62E]),
+    (0xFEA7, Some("initial"), &[0x62E]),
+    (0xFEA8, Some("medial"), &[0x62E]),
+    (0xFEA9, Some("isolated"), &[0x62F]),
+    (0xFEAA, Some("final"), &[0x62F]),
+    (0xFEAB, Some("isolated"), &[0x630]),
+    (0xFEAC, Some("final"), &[0x630]),
+    (0xFEAD, Some("isolated"), &[0x631]),
+    (0xFEAE, Some("final"), &[0x631]),
+    (0xFEAF, Some("isolated"), &[0x632]),
+    (0xFEB0, Some("final"), &[0x632]),
+    (0xFEB1, Some("isolated"), &[0x633]),
+    (0xFEB2, Some("final"), &[0x633]),
+    (0xFEB3, Some("initial"), &[0x633]),
+    (0xFEB4, Some("medial"), &[0x633]),
+    (0xFEB5, Some("isolated"), &[0x634]),
+    (0xFEB6, Some("final"), &[0x634]),
+    (0xFEB7, Some("initial"), &[0x634]),
+    (0xFEB8, Some("medial"), &[0x634]),
+    (0xFEB9, Some("isolated"), &[0x635]),
+    (0xFEBA, Some("final"), &[0x635]),
+    (0xFEBB, Some("initial"), &[0x635]),
+    (0xFEBC, Some("medial"), &[0x635]),
+    (0xFEBD, Some("isolated"), &[0x636]),
+    (0xFEBE, Some("final"), &[0x636]),
+    (0xFEBF, Some("initial"), &[0x636]),
+    (0xFEC0, Some("medial"), &[0x636]),
+    (0xFEC1, Some("isolated"), &[0x637]),
+    (0xFEC2, Some("final"), &[0x637]),
+    (0xFEC3, Some("initial"), &[0x637]),
+    (0xFEC4, Some("medial"), &[0x637]),
+    (0xFEC5, Some("isolated"), &[0x638]),
+    (0xFEC6, Some("final"), &[0x638]),
+    (0xFEC7, Some("initial"), &[0x638]),
+    (0xFEC8, Some("medial"), &[0x638]),
+    (0xFEC9, Some("isolated"), &[0x639]),
+    (0xFECA, Some("final"), &[0x639]),
+    (0xFECB, Some("initial"), &[0x639]),
+    (0xFECC, Some("medial"), &[0x639]),
+    (0xFECD, Some("isolated"), &[0x63A]),
+    (0xFECE, Some("final"), &[0x63A]),
+    (0xFECF, Some("initial"), &[0x63A]),
+    (0xFED0, Some("medial"), &[0x63A]),
+    (0xFED1, Some("isolated"), &[0x641]),
+    (0xFED2, Some("final"), &[0x641]),
+    (0xFED3, Some("initial"), &[0x641]),
+    (0xFED4, Some("medial"), &[0x641]),
+    (0xFED5, Some("isolated"), &[0x642]),
+    (0xFED6, Some("final"), &[0x642]),
+    (0xFED7, Some("initial"), &[0x642]),
+    (0xFED8, Some("medial"), &[0x642]),
+    (0xFED9, Some("isolated"), &[0x643]),
+    (0xFEDA, Some("final"), &[0x643]),
+    (0xFEDB, Some("initial"), &[0x643]),
+    (0xFEDC, Some("medial"), &[0x643]),
+    (0xFEDD, Some("isolated"), &[0x644]),
+    (0xFEDE, Some("final"), &[0x644]),
+    (0xFEDF, Some("initial"), &[0x644]),
+    (0xFEE0, Some("medial"), &[0x644]),
+    (0xFEE1, Some("isolated"), &[0x645]),
+    (0xFEE2, Some("final"), &[0x645]),
+    (0xFEE3, Some("initial"), &[0x645]),
+    (0xFEE4, Some("medial"), &[0x645]),
+    (0xFEE5, Some("isolated"), &[0x646]),
+    (0xFEE6, Some("final"), &[0x646]),
+    (0xFEE7, Some("initial"), &[0x646]),
+    (0xFEE8, Some("medial"), &[0x646]),
+    (0xFEE9, Some("isolated"), &[0x647]),
+    (0xFEEA, Some("final"), &[0x647]),
+    (0xFEEB, Some("initial"), &[0x647]),
+    (0xFEEC, Some("medial"), &[0x647]),
+    (0xFEED, Some("isolated"), &[0x648]),
+    (0xFEEE, Some("final"), &[0x648]),
+    (0xFEEF, Some("isolated"), &[0x649]),
+    (0xFEF0, Some("final"), &[0x649]),
+    (0xFEF1, Some("isolated"), &[0x64A]),
+    (0xFEF2, Some("final"), &[0x64A]),
+    (0xFEF3, Some("initial"), &[0x64A]),
+    (0xFEF4, Some("medial"), &[0x64A]),
+    (0xFEF5, Some("isolated"), &[0x644, 0x622]),
+    (0xFEF6, Some("final"), &[0x644, 0x622]),
+    (0xFEF7, Some("isolated"), &[0x644, 0x623]),
+    (0xFEF8, Some("final"), &[0x644, 0x623]),
+    (0xFEF9, Some("isolated"), &[0x644, 0x625]),
+    (0xFEFA, Some("final"), &[0x644, 0x625]),
+    (0xFEFB, Some("isolated"), &[0x644, 0x627]),
+    (0xFEFC, Some("final"), &[0x644, 0x627]),
+    (0xFF01, Some("wide"), &[0x21]),
+    (0xFF02, Some("wide"), &[0x22]),
+    (0xFF03, Some("wide"), &[0x23]),
+    (0xFF04, Some("wide"), &[0x24]),
+    (0xFF05, Some("wide"), &[0x25]),
+    (0xFF06, Some("wide"), &[0x26]),
+    (0xFF07, Some("wide"), &[0x27]),
+    (0xFF08, Some("wide"), &[0x28]),
+    (0xFF09, Some("wide"), &[0x29]),
+    (0xFF0A, Some("wide"), &[0x2A]),
+    (0xFF0B, Some("wide"), &[0x2B]),
+    (0xFF0C, Some("wide"), &[0x2C]),
+    (0xFF0D, Some("wide"), &[0x2D]),
+    (0xFF0E, Some("wide"), &[0x2E]),
+    (0xFF0F, Some("wide"), &[0x2F]),
+    (0xFF10, Some("wide"), &[0x30]),
+    (0xFF11, Some("wide"), &[0x31]),
+    (0xFF12, Some("wide"), &[0x32]),
+    (0xFF13, Some("wide"), &[0x33]),
+    (0xFF14, Some("wide"), &[0x34]),
+    (0xFF15, Some("wide"), &[0x35]),
+    (0xFF16, Some("wide"), &[0x36]),
+    (0xFF17, Some("wide"), &[0x37]),
+    (0xFF18, Some("wide"), &[0x38]),
+    (0xFF19, Some("wide"), &[0x39]),
+    (0xFF1A, Some("wide"), &[0x3A]),
+    (0xFF1B, Some("wide"), &[0x3B]),
+    (0xFF1C, Some("wide"), &[0x3C]),
+    (0xFF1D, Some("wide"), &[0x3D]),
+    (0xFF1E, Some("wide"), &[0x3E]),
+    (0xFF1F, Some("wide"), &[0x3F]),
+    (0xFF20, Some("wide"), &[0x40]),
+    (0xFF21, Some("wide"), &[0x41]),
+    (0xFF22, Some("wide"), &[0x42]),
+    (0xFF23, Some("wide"), &[0x43]),
+    (0xFF24, Some("wide"), &[0x44]),
+    (0xFF25, Some("wide"), &[0x45]),
+    (0xFF26, Some("wide"), &[0x46]),
+    (0xFF27, Some("wide"), &[0x47]),
+    (0xFF28, Some("wide"), &[0x48]),
+    (0xFF29, Some("wide"), &[0x49]),
+    (0xFF2A, Some("wide"), &[0x4A]),
+    (0xFF2B, Some("wide"), &[0x4B]),
+    (0xFF2C, Some("wide"), &[0x4C]),
+    (0xFF2D, Some("wide"), &[0x4D]),
+    (0xFF2E, Some("wide"), &[0x4E]),
+    (0xFF2F, Some("wide"), &[0x4F]),
+    (0xFF30, Some("wide"), &[0x50]),
+    (0xFF31, Some("wide"), &[0x51]),
+    (0xFF32, Some("wide"), &[0x52]),
+    (0xFF33, Some("wide"), &[0x53]),
+    (0xFF34, Some("wide"), &[0x54]),
+    (0xFF35, Some("wide"), &[0x55]),
+    (0xFF36, Some("wide"), &[0x56]),
+    (0xFF37, Some("wide"), &[0x57]),
+    (0xFF38, Some("wide"), &[0x58]),
+    (0xFF39, Some("wide"), &[0x59]),
+    (0xFF3A, Some("wide"), &[0x5A]),
+    (0xFF3B, Some("wide"), &[0x5B]),
+    (0xFF3C, Some("wide"), &[0x5C]),
+    (0xFF3D, Some("wide"), &[0x5D]),
+    (0xFF3E, Some("wide"), &[0x5E]),
+    (0xFF3F, Some("wide"), &[0x5F]),
+    (0xFF40, Some("wide"), &[0x60]),
+    (0xFF41, Some("wide"), &[0x61]),
+    (0xFF42, Some("wide"), &[0x62]),
+    (0xFF43, Some("wide"), &[0x63]),
+    (0xFF44, Some("wide"), &[0x64]),
+    (0xFF45, Some("wide"), &[0x65]),
+    (0xFF46, Some("wide"), &[0x66]),
+    (0xFF47, Some("wide"), &[0x67]),
+    (0xFF48, Some("wide"), &[0x68]),
+    (0xFF49, Some("wide"), &[0x69]),
+    (0xFF4A, Some("wide"), &[0x6A]),
+    (0xFF4B, Some("wide"), &[0x6B]),
+    (0xFF4C, Some("wide"), &[0x6C]),
+    (0xFF4D, Some("wide"), &[0x6D]),
+    (0xFF4E, Some("wide"), &[0x6E]),
+    (0xFF4F, Some("wide"), &[0x6F]),
+    (0xFF50, Some("wide"), &[0x70]),
+    (0xFF51, Some("wide"), &[0x71]),
+    (0xFF52, Some("wide"), &[0x72]),
+    (0xFF53, Some("wide"), &[0x73]),
+    (0xFF54, Some("wide"), &[0x74]),
+    (0xFF55, Some("wide"), &[0x75]),
+    (0xFF56, Some("wide"), &[0x76]),
+    (0xFF57, Some("wide"), &[0x77]),
+    (0xFF58, Some("wide"), &[0x78]),
+    (0xFF59, Some("wide"), &[0x79]),
+    (0xFF5A, Some("wide"), &[0x7A]),
+    (0xFF5B, Some("wide"), &[0x7B]),
+    (0xFF5C, Some("wide"), &[0x7C]),
+    (0xFF5D, Some("wide"), &[0x7D]),
+    (0xFF5E, Some("wide"), &[0x7E]),
+    (0xFF5F, Some("wide"), &[0x2985]),
+    (0xFF60, Some("wide"), &[0x2986]),
+    (0xFF61, Some("narrow"), &[0x3002]),
+    (0xFF62, Some("narrow"), &[0x300C]),
+    (0xFF63, Some("narrow"), &[0x300D]),
+    (0xFF64, Some("narrow"), &[0x3001]),
+    (0xFF65, Some("narrow"), &[0x30FB]),
+    (0xFF66, Some("narrow"), &[0x30F2]),
+    (0xFF67, Some("narrow"), &[0x30A1]),
+    (0xFF68, Some("narrow"), &[0x30A3]),
+    (0xFF69, Some("narrow"), &[0x30A5]),
+    (0xFF6A, Some("narrow"), &[0x30A7]),
+    (0xFF6B, Some("narrow"), &[0x30A9]),
+    (0xFF6C, Some("narrow"), &[0x30E3]),
+    (0xFF6D, Some("narrow"), &[0x30E5]),
+    (0xFF6E, Some("narrow"), &[0x30E7]),
+    (0xFF6F, Some("narrow"), &[0x30C3]),
+    (0xFF70, Some("narrow"), &[0x30FC]),
+    (0xFF71, Some("narrow"), &[0x30A2]),
+    (0xFF72, Some("narrow"), &[0x30A4]),
+    (0xFF73, Some("narrow"), &[0x30A6]),
+    (0xFF74, Some("narrow"), &[0x30A8]),
+    (0xFF75, Some("narrow"), &[0x30AA]),
+    (0xFF76, Some("narrow"), &[0x30AB]),
+    (0xFF77, Some("narrow"), &[0x30AD]),
+    (0xFF78, Some("narrow"), &[0x30AF]),
+    (0xFF79, Some("narrow"), &[0x30B1]),
+    (0xFF7A, Some("narrow"), &[0x30B3]),
+    (0xFF7B, Some("narrow"), &[0x30B5]),
+    (0xFF7C, Some("narrow"), &[0x30B7]),
+    (0xFF7D, Some("narrow"), &[0x30B9]),
+    (0xFF7E, Some("narrow"), &[0x30BB]),
+    (0xFF7F, Some("narrow"), &[0x30BD]),
+    (0xFF80, Some("narrow"), &[0x30BF]),
+    (0xFF81, Some("narrow"), &[0x30C1]),
+    (0xFF82, Some("narrow"), &[0x30C4]),
+    (0xFF83, Some("narrow"), &[0x30C6]),
+    (0xFF84, Some("narrow"), &[0x30C8]),
+    (0xFF85, Some("narrow"), &[0x30CA]),
+    (0xFF86, Some("narrow"), &[0x30CB]),
+    (0xFF87, Some("narrow"), &[0x30CC]),
+    (0xFF88, Some("narrow"), &[0x30CD]),
+    (0xFF89, Some("narrow"), &[0x30CE]),
+    (0xFF8A, Some("narrow"), &[0x30CF]),
+    (0xFF8B, Some("narrow"), &[0x30D2]),
+    (0xFF8C, Some("narrow"), &[0x30D5]),
+    (0xFF8D, Some("narrow"), &[0x30D8]),
+    (0xFF8E, Some("narrow"), &[0x30DB]),
+    (0xFF8F, Some("narrow"), &[0x30DE]),
+    (0xFF90, Some("narrow"), &[0x30DF]),
+    (0xFF91, Some("narrow"), &[0x30E0]),
+    (0xFF92, Some("narrow"), &[0x30E1]),
+    (0xFF93, Some("narrow"), &[0x30E2]),
+    (0xFF94, Some("narrow"), &[0x30E4]),
+    (0xFF95, Some("narrow"), &[0x30E6]),
+    (0xFF96, Some("narrow"), &[0x30E8]),
+    (0xFF97, Some("narrow"), &[0x30E9]),
+    (0xFF98, Some("narrow"), &[0x30EA]),
+    (0xFF99, Some("narrow"), &[0x30EB]),
+    (0xFF9A, Some("narrow"), &[0x30EC]),
+    (0xFF9B, Some("narrow"), &[0x30ED]),
+    (0xFF9C, Some("narrow"), &[0x30EF]),
+    (0xFF9D, Some("narrow"), &[0x30F3]),
+    (0xFF9E, Some("narrow"), &[0x3099]),
+    (0xFF9F, Some("narrow"), &[0x309A]),
+    (0xFFA0, Some("narrow"), &[0x3164]),
+    (0xFFA1, Some("narrow"), &[0x3131]),
+    (0xFFA2, Some("narrow"), &[0x3132]),
+    (0xFFA3, Some("narrow"), &[0x3133]),
+    (0xFFA4, Some("narrow"), &[0x3134]),
+    (0xFFA5, Some("narrow"), &[0x3135]),
+    (0xFFA6, Some("narrow"), &[0x3136]),
+    (0xFFA7, Some("narrow"), &[0x3137]),
+    (0xFFA8, Some("narrow"), &[0x3138]),
+    (0xFFA9, Some("narrow"), &[0x3139]),
+    (0xFFAA, Some("narrow"), &[0x313A]),
+    (0xFFAB, Some("narrow"), &[0x313B]),
+    (0xFFAC, Some("narrow"), &[0x313C]),
+    (0xFFAD, Some("narrow"), &[0x313D]),
+    (0xFFAE, Some("narrow"), &[0x313E]),
+    (0xFFAF, Some("narrow"), &[0x313F]),
+    (0xFFB0, Some("narrow"), &[0x3140]),
+    (0xFFB1, Some("narrow"), &[0x3141]),
+    (0xFFB2, Some("narrow"), &[0x3142]),
+    (0xFFB3, Some("narrow"), &[0x3143]),
+    (0xFFB4, Some("narrow"), &[0x3144]),
+    (0xFFB5, Some("narrow"), &[0x3145]),
+    (0xFFB6, Some("narrow"), &[0x3146]),
+    (0xFFB7, Some("narrow"), &[0x3147]),
+    (0xFFB8, Some("narrow"), &[0x3148]),
+    (0xFFB9, Some("narrow"), &[0x3149]),
+    (0xFFBA, Some("narrow"), &[0x314A]),
+    (0xFFBB, Some("narrow"), &[0x314B]),
+    (0xFFBC, Some("narrow"), &[0x314C]),
+    (0xFFBD, Some("narrow"), &[0x314D]),
+    (0xFFBE, Some("narrow"), &[0x314E]),
+    (0xFFC2, Some("narrow"), &[0x314F]),
+    (0xFFC3, Some("narrow"), &[0x3150]),
+    (0xFFC4, Some("narrow"), &[0x3151]),
+    (0xFFC5, Some("narrow"), &[0x3152]),
+    (0xFFC6, Some("narrow"), &[0x3153]),
+    (0xFFC7, Some("narrow"), &[0x3154]),
+    (0xFFCA, Some("narrow"), &[0x3155]),
+    (0xFFCB, Some("narrow"), &[0x3156]),
+    (0xFFCC, Some("narrow"), &[0x3157]),
+    (0xFFCD, Some("narrow"), &[0x3158]),
+    (0xFFCE, Some("narrow"), &[0x3159]),
+    (0xFFCF, Some("narrow"), &[0x315A]),
+    (0xFFD2, Some("narrow"), &[0x315B]),
+    (0xFFD3, Some("narrow"), &[0x315C]),
+    (0xFFD4, Some("narrow"), &[0x315D]),
+    (0xFFD5, Some("narrow"), &[0x315E]),
+    (0xFFD6, Some("narrow"), &[0x315F]),
+    (0xFFD7, Some("narrow"), &[0x3160]),
+    (0xFFDA, Some("narrow"), &[0x3161]),
+    (0xFFDB, Some("narrow"), &[0x3162]),
+    (0xFFDC, Some("narrow"), &[0x3163]),
+    (0xFFE0, Some("wide"), &[0xA2]),
+    (0xFFE1, Some("wide"), &[0xA3]),
+    (0xFFE2, Some("wide"), &[0xAC]),
+    (0xFFE3, Some("wide"), &[0xAF]),
+    (0xFFE4, Some("wide"), &[0xA6]),
+    (0xFFE5, Some("wide"), &[0xA5]),
+    (0xFFE6, Some("wide"), &[0x20A9]),
+    (0xFFE8, Some("narrow"), &[0x2502]),
+    (0xFFE9, Some("narrow"), &[0x2190]),
+    (0xFFEA, Some("narrow"), &[0x2191]),
+    (0xFFEB, Some("narrow"), &[0x2192]),
+    (0xFFEC, Some("narrow"), &[0x2193]),
+    (0xFFED, Some("narrow"), &[0x25A0]),
+    (0xFFEE, Some("narrow"), &[0x25CB]),
+    (0x10781, Some("super"), &[0x2D0]),
+    (0x10782, Some("super"), &[0x2D1]),
+    (0x10783, Some("super"), &[0xE6]),
+    (0x10784, Some("super"), &[0x299]),
+    (0x10785, Some("super"), &[0x253]),
+    (0x10787, Some("super"), &[0x2A3]),
+    (0x10788, Some("super"), &[0xAB66]),
+    (0x10789, Some("super"), &[0x2A5]),
+    (0x1078A, Some("super"), &[0x2A4]),
+    (0x1078B, Some("super"), &[0x256]),
+    (0x1078C, Some("super"), &[0x257]),
+    (0x1078D, Some("super"), &[0x1D91]),
+    (0x1078E, Some("super"), &[0x258]),
+    (0x1078F, Some("super"), &[0x25E]),
+    (0x10790, Some("super"), &[0x2A9]),
+    (0x10791, Some("super"), &[0x264]),
+    (0x10792, Some("super"), &[0x262]),
+    (0x10793, Some("super"), &[0x260]),
+    (0x10794, Some("super"), &[0x29B]),
+    (0x10795, Some("super"), &[0x127]),
+    (0x10796, Some("super"), &[0x29C]),
+    (0x10797, Some("super"), &[0x267]),
+    (0x10798, Some("super"), &[0x284]),
+    (0x10799, Some("super"), &[0x2AA]),
+    (0x1079A, Some("super"), &[0x2AB]),
+    (0x1079B, Some("super"), &[0x26C]),
+    (0x1079C, Some("super"), &[0x1DF04]),
+    (0x1079D, Some("super"), &[0xA78E]),
+    (0x1079E, Some("super"), &[0x26E]),
+    (0x1079F, Some("super"), &[0x1DF05]),
+    (0x107A0, Some("super"), &[0x28E]),
+    (0x107A1, Some("super"), &[0x1DF06]),
+    (0x107A2, Some("super"), &[0xF8]),
+    (0x107A3, Some("super"), &[0x276]),
+    (0x107A4, Some("super"), &[0x277]),
+    (0x107A5, Some("super"), &[0x71]),
+    (0x107A6, Some("super"), &[0x27A]),
+    (0x107A7, Some("super"), &[0x1DF08]),
+    (0x107A8, Some("super"), &[0x27D]),
+    (0x107A9, Some("super"), &[0x27E]),
+    (0x107AA, Some("super"), &[0x280]),
+    (0x107AB, Some("super"), &[0x2A8]),
+    (0x107AC, Some("super"), &[0x2A6]),
+    (0x107AD, Some("super"), &[0xAB67]),
+    (0x107AE, Some("super"), &[0x2A7]),
+    (0x107AF, Some("super"), &[0x288]),
+    (0x107B0, Some("super"), &[0x2C71]),
+    (0x107B2, Some("super"), &[0x28F]),
+    (0x107B3, Some("super"), &[0x2A1]),
+    (0x107B4, Some("super"), &[0x2A2]),
+    (0x107B5, Some("super"), &[0x298]),
+    (0x107B6, Some("super"), &[0x1C0]),
+    (0x107B7, Some("super"), &[0x1C1]),
+    (0x107B8, Some("super"), &[0x1C2]),
+    (0x107B9, Some("super"), &[0x1DF0A]),
+    (0x107BA, Some("super"), &[0x1DF1E]),
+    (0x1109A, None, &[0x11099, 0x110BA]),
+    (0x1109C, None, &[0x1109B, 0x110BA]),
+    (0x110AB, None, &[0x110A5, 0x110BA]),
+    (0x1112E, None, &[0x11131, 0x11127]),
+    (0x1112F, None, &[0x11132, 0x11127]),
+    (0x1134B, None, &[0x11347, 0x1133E]),
+    (0x1134C, None, &[0x11347, 0x11357]),
+    (0x114BB, None, &[0x114B9, 0x114BA]),
+    (0x114BC, None, &[0x114B9, 0x114B0]),
+    (0x114BE, None, &[0x114B9, 0x114BD]),
+    (0x115BA, None, &[0x115B8, 0x115AF]),
+    (0x115BB, None, &[0x115B9, 0x115AF]),
+    (0x11938, None, &[0x11935, 0x11930]),
+    (0x1D15E, None, &[0x1D157, 0x1D165]),
+    (0x1D15F, None, &[0x1D158, 0x1D165]),
+    (0x1D160, None, &[0x1D15F, 0x1D16E]),
+    (0x1D161, None, &[0x1D15F, 0x1D16F]),
+    (0x1D162, None, &[0x1D15F, 0x1D170]),
+    (0x1D163, None, &[0x1D15F, 0x1D171]),
+    (0x1D164, None, &[0x1D15F, 0x1D172]),
+    (0x1D1BB, None, &[0x1D1B9, 0x1D165]),
+    (0x1D1BC, None, &[0x1D1BA, 0x1D165]),
+    (0x1D1BD, None, &[0x1D1BB, 0x1D16E]),
+    (0x1D1BE, None, &[0x1D1BC, 0x1D16E]),
+    (0x1D1BF, None, &[0x1D1BB, 0x1D16F]),
+    (0x1D1C0, None, &[0x1D1BC, 0x1D16F]),
+    (0x1D400, Some("font"), &[0x41]),
+    (0x1D401, Some("font"), &[0x42]),
+    (0x1D402, Some("font"), &[0x43]),
+    (0x1D403, Some("font"), &[0x44]),
+    (0x1D404, Some("font"), &[0x45]),
+    (0x1D405, Some("font"), &[0x46]),
+    (0x1D406, Some("font"), &[0x47]),
+    (0x1D407, Some("font"), &[0x48]),
+    (0x1D408, Some("font"), &[0x49]),
+    (0x1D409, Some("font"), &[0x4A]),
+    (0x1D40A, Some("font"), &[0x4B]),
+    (0x1D40B, Some("font"), &[0x4C]),
+    (0x1D40C, Some("font"), &[0x4D]),
+    (0x1D40D, Some("font"), &[0x4E]),
+    (0x1D40E, Some("font"), &[0x4F]),
+    (0x1D40F, Some("font"), &[0x50]),
+    (0x1D410, Some("font"), &[0x51]),
+    (0x1D411, Some("font"), &[0x52]),
+    (0x1D412, Some("font"), &[0x53]),
+    (0x1D413, Some("font"), &[0x54]),
+    (0x1D414, Some("font"), &[0x55]),
+    (0x1D415, Some("font"), &[0x56]),
+    (0x1D416, Some("font"), &[0x57]),
+    (0x1D417, Some("font"), &[0x58]),
+    (0x1D418, Some("font"), &[0x59]),
+    (0x1D419, Some("font"), &[0x5A]),
+    (0x1D41A, Some("font"), &[0x61]),
+    (0x1D41B, Some("font"), &[0x62]),
+    (0x1D41C, Some("font"), &[0x63]),
+    (0x1D41D, Some("font"), &[0x64]),
+    (0x1D41E, Some("font"), &[0x65]),
+    (0x1D41F, Some("font"), &[0x66]),
+    (0x1D420, Some("font"), &[0x67]),
+    (0x1D421, Some("font"), &[0x68]),
+    (0x1D422, Some("font"), &[0x69]),
+    (0x1D423, Some("font"), &[0x6A]),
+    (0x1D424, Some("font"), &[0x6B]),
+    (0x1D425, Some("font"), &[0x6C]),
+    (0x1D426, Some("font"), &[0x6D]),
+    (0x1D427, Some("font"), &[0x6E]),
+    (0x1D428, Some("font"), &[0x6F]),
+    (0x1D429, Some("font"), &[0x70]),
+    (0x1D42A, Some("font"), &[0x71]),
+    (0x1D42B, Some("font"), &[0x72]),
+    (0x1D42C, Some("font"), &[0x73]),
+    (0x1D42D, Some("font"), &[0x74]),
+    (0x1D42E, Some("font"), &[0x75]),
+    (0x1D42F, Some("font"), &[0x76]),
+    (0x1D430, Some("font"), &[0x77]),
+    (0x1D431, Some("font"), &[0x78]),
+    (0x1D432, Some("font"), &[0x79]),
+    (0x1D433, Some("font"), &[0x7A]),
+    (0x1D434, Some("font"), &[0x41]),
+    (0x1D435, Some("font"), &[0x42]),
+    (0x1D436, Some("font"), &[0x43]),
+    (0x1D437, Some("font"), &[0x44]),
+    (0x1D438, Some("font"), &[0x45]),
+    (0x1D439, Some("font"), &[0x46]),
+    (0x1D43A, Some("font"), &[0x47]),
+    (0x1D43B, Some("font"), &[0x48]),
+    (0x1D43C, Some("font"), &[0x49]),
+    (0x1D43D, Some("font"), &[0x4A]),
+    (0x1D43E, Some("font"), &[0x4B]),
+    (0x1D43F, Some("font"), &[0x4C]),
+    (0x1D440, Some("font"), &[0x4D]),
+    (0x1D441, Some("font"), &[0x4E]),
+    (0x1D442, Some("font"), &[0x4F]),
+    (0x1D443, Some("font"), &[0x50]),
+    (0x1D444, Some("font"), &[0x51]),
+    (0x1D445, Some("font"), &[0x52]),
+    (0x1D446, Some("font"), &[0x53]),
+    (0x1D447, Some("font"), &[0x54]),
+    (0x1D448, Some("font"), &[0x55]),
+    (0x1D449, Some("font"), &[0x56]),
+    (0x1D44A, Some("font"), &[0x57]),
+    (0x1D44B, Some("font"), &[0x58]),
+    (0x1D44C, Some("font"), &[0x59]),
+    (0x1D44D, Some("font"), &[0x5A]),
+    (0x1D44E, Some("font"), &[0x61]),
+    (0x1D44F, Some("font"), &[0x62]),
+    (0x1D450, Some("font"), &[0x63]),
+    (0x1D451, Some("font"), &[0x64]),
+    (0x1D452, Some("font"), &[0x65]),
+    (0x1D453, Some("font"), &[0x66]),
+    (0x1D454, Some("font"), &[0x67]),
+    (0x1D456, Some("font"), &[0x69]),
+    (0x1D457, Some("font"), &[0x6A]),
+    (0x1D458, Some("font"), &[0x6B]),
+    (0x1D459, Some("font"), &[0x6C]),
+    (0x1D45A, Some("font"), &[0x6D]),
+    (0x1D45B, Some("font"), &[0x6E]),
+    (0x1D45C, Some("font"), &[0x6F]),
+    (0x1D45D, Some("font"), &[0x70]),
+    (0x1D45E, Some("font"), &[0x71]),
+    (0x1D45F, Some("font"), &[0x72]),
+    (0x1D460, Some("font"), &[0x73]),
+    (0x1D461, Some("font"), &[0x74]),
+    (0x1D462, Some("font"), &[0x75]),
+    (0x1D463, Some("font"), &[0x76]),
+    (0x1D464, Some("font"), &[0x77]),
+    (0x1D465, Some("font"), &[0x78]),
+    (0x1D466, Some("font"), &[0x79]),
+    (0x1D467, Some("font"), &[0x7A]),
+    (0x1D468, Some("font"), &[0x41]),
+    (0x1D469, Some("font"), &[0x42]),
+    (0x1D46A, Some("font"), &[0x43]),
+    (0x1D46B, Some("font"), &[0x44]),
+    (0x1D46C, Some("font"), &[0x45]),
+    (0x1D46D, Some("font"), &[0x46]),
+    (0x1D46E, Some("font"), &[0x47]),
+    (0x1D46F, Some("font"), &[0x48]),
+    (0x1D470, Some("font"), &[0x49]),
+    (0x1D471, Some("font"), &[0x4A]),
+    (0x1D472, Some("font"), &[0x4B]),
+    (0x1D473, Some("font"), &[0x4C]),
+    (0x1D474, Some("font"), &[0x4D]),
+    (0x1D475, Some("font"), &[0x4E]),
+    (0x1D476, Some("font"), &[0x4F]),
+    (0x1D477, Some("font"), &[0x50]),
+    (0x1D478, Some("font"), &[0x51]),
+    (0x1D479, Some("font"), &[0x52]),
+    (0x1D47A, Some("font"), &[0x53]),
+    (0x1D47B, Some("font"), &[0x54]),
+    (0x1D47C, Some("font"), &[0x55]),
+    (0x1D47D, Some("font"), &[0x56]),
+    (0x1D47E, Some("font"), &[0x57]),
+    (0x1D47F, Some("font"), &[0x58]),
+    (0x1D480, Some("font"), &[0x59]),
+    (0x1D481, Some("font"), &[0x5A]),
+    (0x1D482, Some("font"), &[0x61]),
+    (0x1D483, Some("font"), &[0x62]),
+    (0x1D484, Some("font"), &[0x63]),
+    (0x1D485, Some("font"), &[0x64]),
+    (0x1D486, Some("font"), &[0x65]),
+    (0x1D487, Some("font"), &[0x66]),
+    (0x1D488, Some("font"), &[0x67]),
+    (0x1D489, Some("font"), &[0x68]),
+    (0x1D48A, Some("font"), &[0x69]),
+    (0x1D48B, Some("font"), &[0x6A]),
+    (0x1D48C, Some("font"), &[0x6B]),
+    (0x1D48D, Some("font"), &[0x6C]),
+    (0x1D48E, Some("font"), &[0x6D]),
+    (0x1D48F, Some("font"), &[0x6E]),
+    (0x1D490, Some("font"), &[0x6F]),
+    (0x1D491, Some("font"), &[0x70]),
+    (0x1D492, Some("font"), &[0x71]),
+    (0x1D493, Some("font"), &[0x72]),
+    (0x1D494, Some("font"), &[0x73]),
+    (0x1D495, Some("font"), &[0x74]),
+    (0x1D496, Some("font"), &[0x75]),
+    (0x1D497, Some("font"), &[0x76]),
+    (0x1D498, Some("font"), &[0x77]),
+    (0x1D499, Some("font"), &[0x78]),
+    (0x1D49A, Some("font"), &[0x79]),
+    (0x1D49B, Some("font"), &[0x7A]),
+    (0x1D49C, Some("font"), &[0x41]),
+    (0x1D49E, Some("font"), &[0x43]),
+    (0x1D49F, Some("font"), &[0x44]),
+    (0x1D4A2, Some("font"), &[0x47]),
+    (0x1D4A5, Some("font"), &[0x4A]),
+    (0x1D4A6, Some("font"), &[0x4B]),
+    (0x1D4A9, Some("font"), &[0x4E]),
+    (0x1D4AA, Some("font"), &[0x4F]),
+    (0x1D4AB, Some("font"), &[0x50]),
+    (0x1D4AC, Some("font"), &[0x51]),
+    (0x1D4AE, Some("font"), &[0x53]),
+    (0x1D4AF, Some("font"), &[0x54]),
+    (0x1D4B0, Some("font"), &[0x55]),
+    (0x1D4B1, Some("font"), &[0x56]),
+    (0x1D4B2, Some("font"), &[0x57]),
+    (0x1D4B3, Some("font"), &[0x58]),
+    (0x1D4B4, Some("font"), &[0x59]),
+    (0x1D4B5, Some("font"), &[0x5A]),
+    (0x1D4B6, Some("font"), &[0x61]),
+    (0x1D4B7, Some("font"), &[0x62]),
+    (0x1D4B8, Some("font"), &[0x63]),
+    (0x1D4B9, Some("font"), &[0x64]),
+    (0x1D4BB, Some("font"), &[0x66]),
+    (0x1D4BD, Some("font"), &[0x68]),
+    (0x1D4BE, Some("font"), &[0x69]),
+    (0x1D4BF, Some("font"), &[0x6A]),
+    (0x1D4C0, Some("font"), &[0x6B]),
+    (0x1D4C1, Some("font"), &[0x6C]),
+    (0x1D4C2, Some("font"), &[0x6D]),
+    (0x1D4C3, Some("font"), &[0x6E]),
+    (0x1D4C5, Some("font"), &[0x70]),
+    (0x1D4C6, Some("font"), &[0x71]),
+    (0x1D4C7, Some("font"), &[0x72]),
+    (0x1D4C8, Some("font"), &[0x73]),
+    (0x1D4C9, Some("font"), &[0x74]),
+    (0x1D4CA, Some("font"), &[0x75]),
+    (0x1D4CB, Some("font"), &[0x76]),
+    (0x1D4CC, Some("font"), &[0x77]),
+    (0x1D4CD, Some("font"), &[0x78]),
+    (0x1D4CE, Some("font"), &[0x79]),
+    (0x1D4CF, Some("font"), &[0x7A]),
+    (0x1D4D0, Some("font"), &[0x41]),
+    (0x1D4D1, Some("font"), &[0x42]),
+    (0x1D4D2, Some("font"), &[0x43]),
+    (0x1D4D3, Some("font"), &[0x44]),
+    (0x1D4D4, Some("font"), &[0x45]),
+    (0x1D4D5, Some("font"), &[0x46]),
+    (0x1D4D6, Some("font"), &[0x47]),
+    (0x1D4D7, Some("font"), &[0x48]),
+    (0x1D4D8, Some("font"), &[0x49]),
+    (0x1D4D9, Some("font"), &[0x4A]),
+    (0x1D4DA, Some("font"), &[0x4B]),
+    (0x1D4DB, Some("font"), &[0x4C]),
+    (0x1D4DC, Some("font"), &[0x4D]),
+    (0x1D4DD, Some("font"), &[0x4E]),
+    (0x1D4DE, Some("font"), &[0x4F]),
+    (0x1D4DF, Some("font"), &[0x50]),
+    (0x1D4E0, Some("font"), &[0x51]),
+    (0x1D4E1, Some("font"), &[0x52]),
+    (0x1D4E2, Some("font"), &[0x53]),
+    (0x1D4E3, Some("font"), &[0x54]),
+    (0x1D4E4, Some("font"), &[0x55]),
+    (0x1D4E5, Some("font"), &[0x56]),
+    (0x1D4E6, Some("font"), &[0x57]),
+    (0x1D4E7, Some("font"), &[0x58]),
+    (0x1D4E8, Some("font"), &[0x59]),
+    (0x1D4E9, Some("font"), &[0x5A]),
+    (0x1D4EA, Some("font"), &[0x61]),
+    (0x1D4EB, Some("font"), &[0x62]),
+    (0x1D4EC, Some("font"), &[0x63]),
+    (0x1D4ED, Some("font"), &[0x64]),
+    (0x1D4EE, Some("font"), &[0x65]),
+    (0x1D4EF, Some("font"), &[0x66]),
+    (0x1D4F0, Some("font"), &[0x67]),
+    (0x1D4F1, Some("font"), &[0x68]),
+    (0x1D4F2, Some("font"), &[0x69]),
+    (0x1D4F3, Some("font"), &[0x6A]),
+    (0x1D4F4, Some("font"), &[0x6B]),
+    (0x1D4F5, Some("font"), &[0x6C]),
+    (0x1D4F6, Some("font"), &[0x6D]),
+    (0x1D4F7, Some("font"), &[0x6E]),
+    (0x1D4F8, Some("font"), &[0x6F]),
+    (0x1D4F9, Some("font"), &[0x70]),
+    (0x1D4FA, Some("font"), &[0x71]),
+    (0x1D4FB, Some("font"), &[0x72]),
+    (0x1D4FC, Some("font"), &[0x73]),
+    (0x1D4FD, Some("font"), &[0x74]),
+    (0x1D4FE, Some("font"), &[0x75]),
+    (0x1D4FF, Some("font"), &[0x76]),
+    (0x1D500, Some("font"), &[0x77]),
+    (0x1D501, Some("font"), &[0x78]),
+    (0x1D502, Some("font"), &[0x79]),
+    (0x1D503, Some("font"), &[0x7A]),
+    (0x1D504, Some("font"), &[0x41]),
+    (0x1D505, Some("font"), &[0x42]),
+    (0x1D507, Some("font"), &[0x44]),
+    (0x1D508, Some("font"), &[0x45]),
+    (0x1D509, Some("font"), &[0x46]),
+    (0x1D50A, Some("font"), &[0x47]),
+    (0x1D50D, Some("font"), &[0x4A]),
+    (0x1D50E, Some("font"), &[0x4B]),
+    (0x1D50F, Some("font"), &[0x4C]),
+    (0x1D510, Some("font"), &[0x4D]),
+    (0x1D511, Some("font"), &[0x4E]),
+    (0x1D512, Some("font"), &[0x4F]),
+    (0x1D513, Some("font"), &[0x50]),
+    (0x1D514, Some("font"), &[0x51]),
+    (0x1D516, Some("font"), &[0x53]),
+    (0x1D517, Some("font"), &[0x54]),
+    (0x1D518, Some("font"), &[0x55]),
+    (0x1D519, Some("font"), &[0x56]),
+    (0x1D51A, Some("font"), &[0x57]),
+    (0x1D51B, Some("font"), &[0x58]),
+    (0x1D51C, Some("font"), &[0x59]),
+    (0x1D51E, Some("font"), &[0x61]),
+    (0x1D51F, Some("font"), &[0x62]),
+    (0x1D520, Some("font"), &[0x63]),
+    (0x1D521, Some("font"), &[0x64]),
+    (0x1D522, Some("font"), &[0x65]),
+    (0x1D523, Some("font"), &[0x66]),
+    (0x1D524, Some("font"), &[0x67]),
+    (0x1D525, Some("font"), &[0x68]),
+    (0x1D526, Some("font"), &[0x69]),
+    (0x1D527, Some("font"), &[0x6A]),
+    (0x1D528, Some("font"), &[0x6B]),
+    (0x1D529, Some("font"), &[0x6C]),
+    (0x1D52A, Some("font"), &[0x6D]),
+    (0x1D52B, Some("font"), &[0x6E]),
+    (0x1D52C, Some("font"), &[0x6F]),
+    (0x1D52D, Some("font"), &[0x70]),
+    (0x1D52E, Some("font"), &[0x71]),
+    (0x1D52F, Some("font"), &[0x72]),
+    (0x1D530, Some("font"), &[0x73]),
+    (0x1D531, Some("font"), &[0x74]),
+    (0x1D532, Some("font"), &[0x75]),
+    (0x1D533, Some("font"), &[0x76]),
+    (0x1D534, Some("font"), &[0x77]),
+    (0x1D535, Some("font"), &[0x78]),
+    (0x1D536, Some("font"), &[0x79]),
+    (0x1D537, Some("font"), &[0x7A]),
+    (0x1D538, Some("font"), &[0x41]),
+    (0x1D539, Some("font"), &[0x42]),
+    (0x1D53B, Some("font"), &[0x44]),
+    (0x1D53C, Some("font"), &[0x45]),
+    (0x1D53D, Some("font"), &[0x46]),
+    (0x1D53E, Some("font"), &[0x47]),
+    (0x1D540, Some("font"), &[0x49]),
+    (0x1D541, Some("font"), &[0x4A]),
+    (0x1D542, Some("font"), &[0x4B]),
+    (0x1D543, Some("font"), &[0x4C]),
+    (0x1D544, Some("font"), &[0x4D]),
+    (0x1D546, Some("font"), &[0x4F]),
+    (0x1D54A, Some("font"), &[0x53]),
+    (0x1D54B, Some("font"), &[0x54]),
+    (0x1D54C, Some("font"), &[0x55]),
+    (0x1D54D, Some("font"), &[0x56]),
+    (0x1D54E, Some("font"), &[0x57]),
+    (0x1D54F, Some("font"), &[0x58]),
+    (0x1D550, Some("font"), &[0x59]),
+    (0x1D552, Some("font"), &[0x61]),
+    (0x1D553, Some("font"), &[0x62]),
+    (0x1D554, Some("font"), &[0x63]),
+    (0x1D555, Some("font"), &[0x64]),
+    (0x1D556, Some("font"), &[0x65]),
+    (0x1D557, Some("font"), &[0x66]),
+    (0x1D558, Some("font"), &[0x67]),
+    (0x1D559, Some("font"), &[0x68]),
+    (0x1D55A, Some("font"), &[0x69]),
+    (0x1D55B, Some("font"), &[0x6A]),
+    (0x1D55C, Some("font"), &[0x6B]),
+    (0x1D55D, Some("font"), &[0x6C]),
+    (0x1D55E, Some("font"), &[0x6D]),
+    (0x1D55F, Some("font"), &[0x6E]),
+    (0x1D560, Some("font"), &[0x6F]),
+    (0x1D561, Some("font"), &[0x70]),
+    (0x1D562, Some("font"), &[0x71]),
+    (0x1D563, Some("font"), &[0x72]),
+    (0x1D564, Some("font"), &[0x73]),
+    (0x1D565, Some("font"), &[0x74]),
+    (0x1D566, Some("font"), &[0x75]),
+    (0x1D567, Some("font"), &[0x76]),
+    (0x1D568, Some("font"), &[0x77]),
+    (0x1D569, Some("font"), &[0x78]),
+    (0x1D56A, Some("font"), &[0x79]),
+    (0x1D56B, Some("font"), &[0x7A]),
+    (0x1D56C, Some("font"), &[0x41]),
+    (0x1D56D, Some("font"), &[0x42]),
+    (0x1D56E, Some("font"), &[0x43]),
+    (0x1D56F, Some("font"), &[0x44]),
+    (0x1D570, Some("font"), &[0x45]),
+    (0x1D571, Some("font"), &[0x46]),
+    (0x1D572, Some("font"), &[0x47]),
+    (0x1D573, Some("font"), &[0x48]),
+    (0x1D574, Some("font"), &[0x49]),
+    (0x1D575, Some("font"), &[0x4A]),
+    (0x1D576, Some("font"), &[0x4B]),
+    (0x1D577, Some("font"), &[0x4C]),
+    (0x1D578, Some("font"), &[0x4D]),
+    (0x1D579, Some("font"), &[0x4E]),
+    (0x1D57A, Some("font"), &[0x4F]),
+    (0x1D57B, Some("font"), &[0x50]),
+    (0x1D57C, Some("font"), &[0x51]),
+    (0x1D57D, Some("font"), &[0x52]),
+    (0x1D57E, Some("font"), &[0x53]),
+    (0x1D57F, Some("font"), &[0x54]),
+    (0x1D580, Some("font"), &[0x55]),
+    (0x1D581, Some("font"), &[0x56]),
+    (0x1D582, Some("font"), &[0x57]),
+    (0x1D583, Some("font"), &[0x58]),
+    (0x1D584, Some("font"), &[0x59]),
+    (0x1D585, Some("font"), &[0x5A]),
+    (0x1D586, Some("font"), &[0x61]),
+    (0x1D587, Some("font"), &[0x62]),
+    (0x1D588, Some("font"), &[0x63]),
+    (0x1D589, Some("font"), &[0x64]),
+    (0x1D58A, Some("font"), &[0x65]),
+    (0x1D58B, Some("font"), &[0x66]),
+    (0x1D58C, Some("font"), &[0x67]),
+    (0x1D58D, Some("font"), &[0x68]),
+    (0x1D58E, Some("font"), &[0x69]),
+    (0x1D58F, Some("font"), &[0x6A]),
+    (0x1D590, Some("font"), &[0x6B]),
+    (0x1D591, Some("font"), &[0x6C]),
+    (0x1D592, Some("font"), &[0x6D]),
+    (0x1D593, Some("font"), &[0x6E]),
+    (0x1D594, Some("font"), &[0x6F]),
+    (0x1D595, Some("font"), &[0x70]),
+    (0x1D596, Some("font"), &[0x71]),
+    (0x1D597, Some("font"), &[0x72]),
+    (0x1D598, Some("font"), &[0x73]),
+    (0x1D599, Some("font"), &[0x74]),
+    (0x1D59A, Some("font"), &[0x75]),
+    (0x1D59B, Some("font"), &[0x76]),
+    (0x1D59C, Some("font"), &[0x77]),
+    (0x1D59D, Some("font"), &[0x78]),
+    (0x1D59E, Some("font"), &[0x79]),
+    (0x1D59F, Some("font"), &[0x7A]),
+    (0x1D5A0, Some("font"), &[0x41]),
+    (0x1D5A1, Some("font"), &[0x42]),
+    (0x1D5A2, Some("font"), &[0x43]),
+    (0x1D5A3, Some("font"), &[0x44]),
+    (0x1D5A4, Some("font"), &[0x45]),
+    (0x1D5A5, Some("font"), &[0x46]),
+    (0x1D5A6, Some("font"), &[0x47]),
+    (0x1D5A7, Some("font"), &[0x48]),
+    (0x1D5A8, Some("font"), &[0x49]),
+    (0x1D5A9, Some("font"), &[0x4A]),
+    (0x1D5AA, Some("font"), &[0x4B]),
+    (0x1D5AB, Some("font"), &[0x4C]),
+    (0x1D5AC, Some("font"), &[0x4D]),
+    (0x1D5AD, Some("font"), &[0x4E]),
+    (0x1D5AE, Some("font"), &[0x4F]),
+    (0x1D5AF, Some("font"), &[0x50]),
+    (0x1D5B0, Some("font"), &[0x51]),
+    (0x1D5B1, Some("font"), &[0x52]),
+    (0x1D5B2, Some("font"), &[0x53]),
+    (0x1D5B3, Some("font"), &[0x54]),
+    (0x1D5B4, Some("font"), &[0x55]),
+    (0x1D5B5, Some("font"), &[0x56]),
+    (0x1D5B6, Some("font"), &[0x57]),
+    (0x1D5B7, Some("font"), &[0x58]),
+    (0x1D5B8, Some("font"), &[0x59]),
+    (0x1D5B9, Some("font"), &[0x5A]),
+    (0x1D5BA, Some("font"), &[0x61]),
+    (0x1D5BB, Some("font"), &[0x62]),
+    (0x1D5BC, Some("font"), &[0x63]),
+    (0x1D5BD, Some("font"), &[0x64]),
+    (0x1D5BE, Some("font"), &[0x65]),
+    (0x1D5BF, Some("font"), &[0x66]),
+    (0x1D5C0, Some("font"), &[0x67]),
+    (0x1D5C1, Some("font"), &[0x68]),
+    (0x1D5C2, Some("font"), &[0x69]),
+    (0x1D5C3, Some("font"), &[0x6A]),
+    (0x1D5C4, Some("font"), &[0x6B]),
+    (0x1D5C5, Some("font"), &[0x6C]),
+    (0x1D5C6, Some("font"), &[0x6D]),
+    (0x1D5C7, Some("font"), &[0x6E]),
+    (0x1D5C8, Some("font"), &[0x6F]),
+    (0x1D5C9, Some("font"), &[0x70]),
+    (0x1D5CA, Some("font"), &[0x71]),
+    (0x1D5CB, Some("font"), &[0x72]),
+    (0x1D5CC, Some("font"), &[0x73]),
+    (0x1D5CD, Some("font"), &[0x74]),
+    (0x1D5CE, Some("font"), &[0x75]),
+    (0x1D5CF, Some("font"), &[0x76]),
+    (0x1D5D0, Some("font"), &[0x77]),
+    (0x1D5D1, Some("font"), &[0x78]),
+    (0x1D5D2, Some("font"), &[0x79]),
+    (0x1D5D3, Some("font"), &[0x7A]),
+    (0x1D5D4, Some("font"), &[0x41]),
+    (0x1D5D5, Some("font"), &[0x42]),
+    (0x1D5D6, Some("font"), &[0x43]),
+    (0x1D5D7, Some("font"), &[0x44]),
+    (0x1D5D8, Some("font"), &[0x45]),
+    (0x1D5D9, Some("font"), &[0x46]),
+    (0x1D5DA, Some("font"), &[0x47]),
+    (0x1D5DB, Some("font"), &[0x48]),
+    (0x1D5DC, Some("font"), &[0x49]),
+    (0x1D5DD, Some("font"), &[0x4A]),
+    (0x1D5DE, Some("font"), &[0x4B]),
+    (0x1D5DF, Some("font"), &[0x4C]),
+    (0x1D5E0, Some("font"), &[0x4D]),
+    (0x1D5E1, Some("font"), &[0x4E]),
+    (0x1D5E2, Some("font"), &[0x4F]),
+    (0x1D5E3, Some("font"), &[0x50]),
+    (0x1D5E4, Some("font"), &[0x51]),
+    (0x1D5E5, Some("font"), &[0x52]),
+    (0x1D5E6, Some("font"), &[0x53]),
+    (0x1D5E7, Some("font"), &[0x54]),
+    (0x1D5E8, Some("font"), &[0x55]),
+    (0x1D5E9, Some("font"), &[0x56]),
+    (0x1D5EA, Some("font"), &[0x57]),
+    (0x1D5EB, Some("font"), &[0x58]),
+    (0x1D5EC, Some("font"), &[0x59]),
+    (0x1D5ED, Some("font"), &[0x5A]),
+    (0x1D5EE, Some("font"), &[0x61]),
+    (0x1D5EF, Some("font"), &[0x62]),
+    (0x1D5F0, Some("font"), &[0x63]),
+    (0x1D5F1, Some("font"), &[0x64]),
+    (0x1D5F2, Some("font"), &[0x65]),
+    (0x1D5F3, Some("font"), &[0x66]),
+    (0x1D5F4, Some("font"), &[0x67]),
+    (0x1D5F5, Some("font"), &[0x68]),
+    (0x1D5F6, Some("font"), &[0x69]),
+    (0x1D5F7, Some("font"), &[0x6A]),
+    (0x1D5F8, Some("font"), &[0x6B]),
+    (0x1D5F9, Some("font"), &[0x6C]),
+    (0x1D5FA, Some("font"), &[0x6D]),
+    (0x1D5FB, Some("font"), &[0x6E]),
+    (0x1D5FC, Some("font"), &[0x6F]),
+    (0x1D5FD, Some("font"), &[0x70]),
+    (0x1D5FE, Some("font"), &[0x71]),
+    (0x1D5FF, Some("font"), &[0x72]),
+    (0x1D600, Some("font"), &[0x73]),
+    (0x1D601, Some("font"), &[0x74]),
+    (0x1D602, Some("font"), &[0x75]),
+    (0x1D603, Some("font"), &[0x76]),
+    (0x1D604, Some("font"), &[0x77]),
+    (0x1D605, Some("font"), &[0x78]),
+    (0x1D606, Some("font"), &[0x79]),
+    (0x1D607, Some("font"), &[0x7A]),
+    (0x1D608, Some("font"), &[0x41]),
+    (0x1D609, Some("font"), &[0x42]),
+    (0x1D60A, Some("font"), &[0x43]),
+    (0x1D60B, Some("font"), &[0x44]),
+    (0x1D60C, Some("font"), &[0x45]),
+    (0x1D60D, Some("font"), &[0x46]),
+    (0x1D60E, Some("font"), &[0x47]),
+    (0x1D60F, Some("font"), &[0x48]),
+    (0x1D610, Some("font"), &[0x49]),
+    (0x1D611, Some("font"), &[0x4A]),
+    (0x1D612, Some("font"), &[0x4B]),
+    (0x1D613, Some("font"), &[0x4C]),
+    (0x1D614, Some("font"), &[0x4D]),
+    (0x1D615, Some("font"), &[0x4E]),
+    (0x1D616, Some("font"), &[0x4F]),
+    (0x1D617, Some("font"), &[0x50]),
+    (0x1D618, Some("font"), &[0x51]),
+    (0x1D619, Some("font"), &[0x52]),
+    (0x1D61A, Some("font"), &[0x53]),
+    (0x1D61B, Some("font"), &[0x54]),
+    (0x1D61C, Some("font"), &[0x55]),
+    (0x1D61D, Some("font"), &[0x56]),
+    (0x1D61E, Some("font"), &[0x57]),
+    (0x1D61F, Some("font"), &[0x58]),
+    (0x1D620, Some("font"), &[0x59]),
+    (0x1D621, Some("font"), &[0x5A]),
+    (0x1D622, Some("font"), &[0x61]),
+    (0x1D623, Some("font"), &[0x62]),
+    (0x1D624, Some("font"), &[0x63]),
+    (0x1D625, Some("font"), &[0x64]),
+    (0x1D626, Some("font"), &[0x65]),
+    (0x1D627, Some("font"), &[0x66]),
+    (0x1D628, Some("font"), &[0x67]),
+    (0x1D629, Some("font"), &[0x68]),
+    (0x1D62A, Some("font"), &[0x69]),
+    (0x1D62B, Some("font"), &[0x6A]),
+    (0x1D62C, Some("font"), &[0x6B]),
+    (0x1D62D, Some("font"), &[0x6C]),
+    (0x1D62E, Some("font"), &[0x6D]),
+    (0x1D62F, Some("font"), &[0x6E]),
+    (0x1D630, Some("font"), &[0x6F]),
+    (0x1D631, Some("font"), &[0x70]),
+    (0x1D632, Some("font"), &[0x71]),
+    (0x1D633, Some("font"), &[0x72]),
+    (0x1D634, Some("font"), &[0x73]),
+    (0x1D635, Some("font"), &[0x74]),
+    (0x1D636, Some("font"), &[0x75]),
+    (0x1D637, Some("font"), &[0x76]),
+    (0x1D638, Some("font"), &[0x77]),
+    (0x1D639, Some("font"), &[0x78]),
+    (0x1D63A, Some("font"), &[0x79]),
+    (0x1D63B, Some("font"), &[0x7A]),
+    (0x1D63C, Some("font"), &[0x41]),
+    (0x1D63D, Some("font"), &[0x42]),
+    (0x1D63E, Some("font"), &[0x43]),
+    (0x1D63F, Some("font"), &[0x44]),
+    (0x1D640, Some("font"), &[0x45]),
+    (0x1D641, Some("font"), &[0x46]),
+    (0x1D642, Some("font"), &[0x47]),
+    (0x1D643, Some("font"), &[0x48]),
+    (0x1D644, Some("font"), &[0x49]),
+    (0x1D645, Some("font"), &[0x4A]),
+    (0x1D646, Some("font"), &[0x4B]),
+    (0x1D647, Some("font"), &[0x4C]),
+    (0x1D648, Some("font"), &[0x4D]),
+    (0x1D649, Some("font"), &[0x4E]),
+    (0x1D64A, Some("font"), &[0x4F]),
+    (0x1D64B, Some("font"), &[0x50]),
+    (0x1D64C, Some("font"), &[0x51]),
+    (0x1D64D, Some("font"), &[0x52]),
+    (0x1D64E, Some("font"), &[0x53]),
+    (0x1D64F, Some("font"), &[0x54]),
+    (0x1D650, Some("font"), &[0x55]),
+    (0x1D651, Some("font"), &[0x56]),
+    (0x1D652, Some("font"), &[0x57]),
+    (0x1D653, Some("font"), &[0x58]),
+    (0x1D654, Some("font"), &[0x59]),
+    (0x1D655, Some("font"), &[0x5A]),
+    (0x1D656, Some("font"), &[0x61]),
+    (0x1D657, Some("font"), &[0x62]),
+    (0x1D658, Some("font"), &[0x63]),
+    (0x1D659, Some("font"), &[0x64]),
+    (0x1D65A, Some("font"), &[0x65]),
+    (0x1D65B, Some("font"), &[0x66]),
+    (0x1D65C, Some("font"), &[0x67]),
+    (0x1D65D, Some("font"), &[0x68]),
+    (0x1D65E, Some("font"), &[0x69]),
+    (0x1D65F, Some("font"), &[0x6A]),
+    (0x1D660, Some("font"), &[0x6B]),
+    (0x1D661, Some("font"), &[0x6C]),
+    (0x1D662, Some("font"), &[0x6D]),
+    (0x1D663, Some("font"), &[0x6E]),
+    (0x1D664, Some("font"), &[0x6F]),
+    (0x1D665, Some("font"), &[0x70]),
+    (0x1D666, Some("font"), &[0x71]),
+    (0x1D667, Some("font"), &[0x72]),
+    (0x1D668, Some("font"), &[0x73]),
+    (0x1D669, Some("font"), &[0x74]),
+    (0x1D66A, Some("font"), &[0x75]),
+    (0x1D66B, Some("font"), &[0x76]),
+    (0x1D66C, Some("font"), &[0x77]),
+    (0x1D66D, Some("font"), &[0x78]),
+    (0x1D66E, Some("font"), &[0x79]),
+    (0x1D66F, Some("font"), &[0x7A]),
+    (0x1D670, Some("font"), &[0x41]),
+    (0x1D671, Some("font"), &[0x42]),
+    (0x1D672, Some("font"), &[0x43]),
+    (0x1D673, Some("font"), &[0x44]),
+    (0x1D674, Some("font"), &[0x45]),
+    (0x1D675, Some("font"), &[0x46]),
+    (0x1D676, Some("font"), &[0x47]),
+    (0x1D677, Some("font"), &[0x48]),
+    (0x1D678, Some("font"), &[0x49]),
+    (0x1D679, Some("font"), &[0x4A]),
+    (0x1D67A, Some("font"), &[0x4B]),
+    (0x1D67B, Some("font"), &[0x4C]),
+    (0x1D67C, Some("font"), &[0x4D]),
+    (0x1D67D, Some("font"), &[0x4E]),
+    (0x1D67E, Some("font"), &[0x4F]),
+    (0x1D67F, Some("font"), &[0x50]),
+    (0x1D680, Some("font"), &[0x51]),
+    (0x1D681, Some("font"), &[0x52]),
+    (0x1D682, Some("font"), &[0x53]),
+    (0x1D683, Some("font"), &[0x54]),
+    (0x1D684, Some("font"), &[0x55]),
+    (0x1D685, Some("font"), &[0x56]),
+    (0x1D686, Some("font"), &[0x57]),
+    (0x1D687, Some("font"), &[0x58]),
+    (0x1D688, Some("font"), &[0x59]),
+    (0x1D689, Some("font"), &[0x5A]),
+    (0x1D68A, Some("font"), &[0x61]),
+    (0x1D68B, Some("font"), &[0x62]),
+    (0x1D68C, Some("font"), &[0x63]),
+    (0x1D68D, Some("font"), &[0x64]),
+    (0x1D68E, Some("font"), &[0x65]),
+    (0x1D68F, Some("font"), &[0x66]),
+    (0x1D690, Some("font"), &[0x67]),
+    (0x1D691, Some("font"), &[0x68]),
+    (0x1D692, Some("font"), &[0x69]),
+    (0x1D693, Some("font"), &[0x6A]),
+    (0x1D694, Some("font"), &[0x6B]),
+    (0x1D695, Some("font"), &[0x6C]),
+    (0x1D696, Some("font"), &[0x6D]),
+    (0x1D697, Some("font"), &[0x6E]),
+    (0x1D698, Some("font"), &[0x6F]),
+    (0x1D699, Some("font"), &[0x70]),
+    (0x1D69A, Some("font"), &[0x71]),
+    (0x1D69B, Some("font"), &[0x72]),
+    (0x1D69C, Some("font"), &[0x73]),
+    (0x1D69D, Some("font"), &[0x74]),
+    (0x1D69E, Some("font"), &[0x75]),
+    (0x1D69F, Some("font"), &[0x76]),
+    (0x1D6A0, Some("font"), &[0x77]),
+    (0x1D6A1, Some("font"), &[0x78]),
+    (0x1D6A2, Some("font"), &[0x79]),
+    (0x1D6A3, Some("font"), &[0x7A]),
+    (0x1D6A4, Some("font"), &[0x131]),
+    (0x1D6A5, Some("font"), &[0x237]),
+    (0x1D6A8, Some("font"), &[0x391]),
+    (0x1D6A9, Some("font"), &[0x392]),
+    (0x1D6AA, Some("font"), &[0x393]),
+    (0x1D6AB, Some("font"), &[0x394]),
+    (0x1D6AC, Some("font"), &[0x395]),
+    (0x1D6AD, Some("font"), &[0x396]),
+    (0x1D6AE, Some("font"), &[0x397]),
+    (0x1D6AF, Some("font"), &[0x398]),
+    (0x1D6B0, Some("font"), &[0x399]),
+    (0x1D6B1, Some("font"), &[0x39A]),
+    (0x1D6B2, Some("font"), &[0x39B]),
+    (0x1D6B3, Some("font"), &[0x39C]),
+    (0x1D6B4, Some("font"), &[0x39D]),
+    (0x1D6B5, Some("font"), &[0x39E]),
+    (0x1D6B6, Some("font"), &[0x39F]),
+    (0x1D6B7, Some("font"), &[0x3A0]),
+    (0x1D6B8, Some("font"), &[0x3A1]),
+    (0x1D6B9, Some("font"), &[0x3F4]),
+    (0x1D6BA, Some("font"), &[0x3A3]),
+    (0x1D6BB, Some("font"), &[0x3A4]),
+    (0x1D6BC, Some("font"), &[0x3A5]),
+    (0x1D6BD, Some("font"), &[0x3A6]),
+    (0x1D6BE, Some("font"), &[0x3A7]),
+    (0x1D6BF, Some("font"), &[0x3A8]),
+    (0x1D6C0, Some("font"), &[0x3A9]),
+    (0x1D6C1, Some("font"), &[0x2207]),
+    (0x1D6C2, Some("font"), &[0x3B1]),
+    (0x1D6C3, Some("font"), &[0x3B2]),
+    (0x1D6C4, Some("font"), &[0x3B3]),
+    (0x1D6C5, Some("font"), &[0x3B4]),
+    (0x1D6C6, Some("font"), &[0x3B5]),
+    (0x1D6C7, Some("font"), &[0x3B6]),
+    (0x1D6C8, Some("font"), &[0x3B7]),
+    (0x1D6C9, Some("font"), &[0x3B8]),
+    (0x1D6CA, Some("font"), &[0x3B9]),
+    (0x1D6CB, Some("font"), &[0x3BA]),
+    (0x1D6CC, Some("font"), &[0x3BB]),
+    (0x1D6CD, Some("font"), &[0x3BC]),
+    (0x1D6CE, Some("font"), &[0x3BD]),
+    (0x1D6CF, Some("font"), &[0x3BE]),
+    (0x1D6D0, Some("font"), &[0x3BF]),
+    (0x1D6D1, Some("font"), &[0x3C0]),
+    (0x1D6D2, Some("font"), &[0x3C1]),
+    (0x1D6D3, Some("font"), &[0x3C2]),
+    (0x1D6D4, Some("font"), &[0x3C3]),
+    (0x1D6D5, Some("font"), &[0x3C4]),
+    (0x1D6D6, Some("font"), &[0x3C5]),
+    (0x1D6D7, Some("font"), &[0x3C6]),
+    (0x1D6D8, Some("font"), &[0x3C7]),
+    (0x1D6D9, Some("font"), &[0x3C8]),
+    (0x1D6DA, Some("font"), &[0x3C9]),
+    (0x1D6DB, Some("font"), &[0x2202]),
+    (0x1D6DC, Some("font"), &[0x3F5]),
+    (0x1D6DD, Some("font"), &[0x3D1]),
+    (0x1D6DE, Some("font"), &[0x3F0]),
+    (0x1D6DF, Some("font"), &[0x3D5]),
+    (0x1D6E0, Some("font"), &[0x3F1]),
+    (0x1D6E1, Some("font"), &[0x3D6]),
+    (0x1D6E2, Some("font"), &[0x391]),
+    (0x1D6E3, Some("font"), &[0x392]),
+    (0x1D6E4, Some("font"), &[0x393]),
+    (0x1D6E5, Some("font"), &[0x394]),
+    (0x1D6E6, Some("font"), &[0x395]),
+    (0x1D6E7, Some("font"), &[0x396]),
+    (0x1D6E8, Some("font"), &[0x397]),
+    (0x1D6E9, Some("font"), &[0x398]),
+    (0x1D6EA, Some("font"), &[0x399]),
+    (0x1D6EB, Some("font"), &[0x39A]),
+    (0x1D6EC, Some("font"), &[0x39B]),
+    (0x1D6ED, Some("font"), &[0x39C]),
+    (0x1D6EE, Some("font"), &[0x39D]),
+    (0x1D6EF, Some("font"), &[0x39E]),
+    (0x1D6F0, Some("font"), &[0x39F]),
+    (0x1D6F1, Some("font"), &[0x3A0]),
+    (0x1D6F2, Some("font"), &[0x3A1]),
+    (0x1D6F3, Some("font"), &[0x3F4]),
+    (0x1D6F4, Some("font"), &[0x3A3]),
+    (0x1D6F5, Some("font"), &[0x3A4]),
+    (0x1D6F6, Some("font"), &[0x3A5]),
+    (0x1D6F7, Some("font"), &[0x3A6]),
+    (0x1D6F8, Some("font"), &[0x3A7]),
+    (0x1D6F9, Some("font"), &[0x3A8]),
+    (0x1D6FA, Some("font"), &[0x3A9]),
+    (0x1D6FB, Some("font"), &[0x2207]),
+    (0x1D6FC, Some("font"), &[0x3B1]),
+    (0x1D6FD, Some("font"), &[0x3B2]),
+    (0x1D6FE, Some("font"), &[0x3B3]),
+    (0x1D6FF, Some("font"), &[0x3B4]),
+    (0x1D700, Some("font"), &[0x3B5]),
+    (0x1D701, Some("font"), &[0x3B6]),
+    (0x1D702, Some("font"), &[0x3B7]),
+    (0x1D703, Some("font"), &[0x3B8]),
+    (0x1D704, Some("font"), &[0x3B9]),
+    (0x1D705, Some("font"), &[0x3BA]),
+    (0x1D706, Some("font"), &[0x3BB]),
+    (0x1D707, Some("font"), &[0x3BC]),
+    (0x1D708, Some("font"), &[0x3BD]),
+    (0x1D709, Some("font"), &[0x3BE]),
+    (0x1D70A, Some("font"), &[0x3BF]),
+    (0x1D70B, Some("font"), &[0x3C0]),
+    (0x1D70C, Some("font"), &[0x3C1]),
+    (0x1D70D, Some("font"), &[0x3C2]),
+    (0x1D70E, Some("font"), &[0x3C3]),
+    (0x1D70F, Some("font"), &[0x3C4]),
+    (0x1D710, Some("font"), &[0x3C5]),
+    (0x1D711, Some("font"), &[0x3C6]),
+    (0x1D712, Some("font"), &[0x3C7]),
+    (0x1D713, Some("font"), &[0x3C8]),
+    (0x1D714, Some("font"), &[0x3C9]),
+    (0x1D715, Some("font"), &[0x2202]),
+    (0x1D716, Some("font"), &[0x3F5]),
+    (0x1D717, Some("font"), &[0x3D1]),
+    (0x1D718, Some("font"), &[0x3F0]),
+    (0x1D719, Some("font"), &[0x3D5]),
+    (0x1D71A, Some("font"), &[0x3F1]),
+    (0x1D71B, Some("font"), &[0x3D6]),
+    (0x1D71C, Some("font"), &[0x391]),
+    (0x1D71D, Some("font"), &[0x392]),
+    (0x1D71E, Some("font"), &[0x393]),
+    (0x1D71F, Some("font"), &[0x394]),
+    (0x1D720, Some("font"), &[0x395]),
+    (0x1D721, Some("font"), &[0x396]),
+    (0x1D722, Some("font"), &[0x397]),
+    (0x1D723, Some("font"), &[0x398]),
+    (0x1D724, Some("font"), &[0x399]),
+    (0x1D725, Some("font"), &[0x39A]),
+    (0x1D726, Some("font"), &[0x39B]),
+    (0x1D727, Some("font"), &[0x39C]),
+    (0x1D728, Some("font"), &[0x39D]),
+    (0x1D729, Some("font"), &[0x39E]),
+    (0x1D72A, Some("font"), &[0x39F]),
+    (0x1D72B, Some("font"), &[0x3A0]),
+    (0x1D72C, Some("font"), &[0x3A1]),
+    (0x1D72D, Some("font"), &[0x3F4]),
+    (0x1D72E, Some("font"), &[0x3A3]),
+    (0x1D72F, Some("font"), &[0x3A4]),
+    (0x1D730, Some("font"), &[0x3A5]),
+    (0x1D731, Some("font"), &[0x3A6]),
+    (0x1D732, Some("font"), &[0x3A7]),
+    (0x1D733, Some("font"), &[0x3A8]),
+    (0x1D734, Some("font"), &[0x3A9]),
+    (0x1D735, Some("font"), &[0x2207]),
+    (0x1D736, Some("font"), &[0x3B1]),
+    (0x1D737, Some("font"), &[0x3B2]),
+    (0x1D738, Some("font"), &[0x3B3]),
+    (0x1D739, Some("font"), &[0x3B4]),
+    (0x1D73A, Some("font"), &[0x3B5]),
+    (0x1D73B, Some("font"), &[0x3B6]),
+    (0x1D73C, Some("font"), &[0x3B7]),
+    (0x1D73D, Some("font"), &[0x3B8]),
+    (0x1D73E, Some("font"), &[0x3B9]),
+    (0x1D73F, Some("font"), &[0x3BA]),
+    (0x1D740, Some("font"), &[0x3BB]),
+    (0x1D741, Some("font"), &[0x3BC]),
+    (0x1D742, Some("font"), &[0x3BD]),
+    (0x1D743, Some("font"), &[0x3BE]),
+    (0x1D744, Some("font"), &[0x3BF]),
+    (0x1D745, Some("font"), &[0x3C0]),
+    (0x1D746, Some("font"), &[0x3C1]),
+    (0x1D747, Some("font"), &[0x3C2]),
+    (0x1D748, Some("font"), &[0x3C3]),
+    (0x1D749, Some("font"), &[0x3C4]),
+    (0x1D74A, Some("font"), &[0x3C5]),
+    (0x1D74B, Some("font"), &[0x3C6]),
+    (0x1D74C, Some("font"), &[0x3C7]),
+    (0x1D74D, Some("font"), &[0x3C8]),
+    (0x1D74E, Some("font"), &[0x3C9]),
+    (0x1D74F, Some("font"), &[0x2202]),
+    (0x1D750, Some("font"), &[0x3F5]),
+    (0x1D751, Some("font"), &[0x3D1]),
+    (0x1D752, Some("font"), &[0x3F0]),
+    (0x1D753, Some("font"), &[0x3D5]),
+    (0x1D754, Some("font"), &[0x3F1]),
+    (0x1D755, Some("font"), &[0x3D6]),
+    (0x1D756, Some("font"), &[0x391]),
+    (0x1D757, Some("font"), &[0x392]),
+    (0x1D758, Some("font"), &[0x393]),
+    (0x1D759, Some("font"), &[0x394]),
+    (0x1D75A, Some("font"), &[0x395]),
+    (0x1D75B, Some("font"), &[0x396]),
+    (0x1D75C, Some("font"), &[0x397]),
+    (0x1D75D, Some("font"), &[0x398]),
+    (0x1D75E, Some("font"), &[0x399]),
+    (0x1D75F, Some("font"), &[0x39A]),
+    (0x1D760, Some("font"), &[0x39B]),
+    (0x1D761, Some("font"), &[0x39C]),
+    (0x1D762, Some("font"), &[0x39D]),
+    (0x1D763, Some("font"), &[0x39E]),
+    (0x1D764, Some("font"), &[0x39F]),
+    (0x1D765, Some("font"), &[0x3A0]),
+    (0x1D766, Some("font"), &[0x3A1]),
+    (0x1D767, Some("font"), &[0x3F4]),
+    (0x1D768, Some("font"), &[0x3A3]),
+    (0x1D769, Some("font"), &[0x3A4]),
+    (0x1D76A, Some("font"), &[0x3A5]),
+    (0x1D76B, Some("font"), &[0x3A6]),
+    (0x1D76C, Some("font"), &[0x3A7]),
+    (0x1D76D, Some("font"), &[0x3A8]),
+    (0x1D76E, Some("font"), &[0x3A9]),
+    (0x1D76F, Some("font"), &[0x2207]),
+    (0x1D770, Some("font"), &[0x3B1]),
+    (0x1D771, Some("font"), &[0x3B2]),
+    (0x1D772, Some("font"), &[0x3B3]),
+    (0x1D773, Some("font"), &[0x3B4]),
+    (0x1D774, Some("font"), &[0x3B5]),
+    (0x1D775, Some("font"), &[0x3B6]),
+    (0x1D776, Some("font"), &[0x3B7]),
+    (0x1D777, Some("font"), &[0x3B8]),
+    (0x1D778, Some("font"), &[0x3B9]),
+    (0x1D779, Some("font"), &[0x3BA]),
+    (0x1D77A, Some("font"), &[0x3BB]),
+    (0x1D77B, Some("font"), &[0x3BC]),
+    (0x1D77C, Some("font"), &[0x3BD]),
+    (0x1D77D, Some("font"), &[0x3BE]),
+    (0x1D77E, Some("font"), &[0x3BF]),
+    (0x1D77F, Some("font"), &[0x3C0]),
+    (0x1D780, Some("font"), &[0x3C1]),
+    (0x1D781, Some("font"), &[0x3C2]),
+    (0x1D782, Some("font"), &[0x3C3]),
+    (0x1D783, Some("font"), &[0x3C4]),
+    (0x1D784, Some("font"), &[0x3C5]),
+    (0x1D785, Some("font"), &[0x3C6]),
+    (0x1D786, Some("font"), &[0x3C7]),
+    (0x1D787, Some("font"), &[0x3C8]),
+    (0x1D788, Some("font"), &[0x3C9]),
+    (0x1D789, Some("font"), &[0x2202]),
+    (0x1D78A, Some("font"), &[0x3F5]),
+    (0x1D78B, Some("font"), &[0x3D1]),
+    (0x1D78C, Some("font"), &[0x3F0]),
+    (0x1D78D, Some("font"), &[0x3D5]),
+    (0x1D78E, Some("font"), &[0x3F1]),
+    (0x1D78F, Some("font"), &[0x3D6]),
+    (0x1D790, Some("font"), &[0x391]),
+    (0x1D791, Some("font"), &[0x392]),
+    (0x1D792, Some("font"), &[0x393]),
+    (0x1D793, Some("font"), &[0x394]),
+    (0x1D794, Some("font"), &[0x395]),
+    (0x1D795, Some("font"), &[0x396]),
+    (0x1D796, Some("font"), &[0x397]),
+    (0x1D797, Some("font"), &[0x398]),
+    (0x1D798, Some("font"), &[0x399]),
+    (0x1D799, Some("font"), &[0x39A]),
+    (0x1D79A, Some("font"), &[0x39B]),
+    (0x1D79B, Some("font"), &[0x39C]),
+    (0x1D79C, Some("font"), &[0x39D]),
+    (0x1D79D, Some("font"), &[0x39E]),
+    (0x1D79E, Some("font"), &[0x39F]),
+    (0x1D79F, Some("font"), &[0x3A0]),
+    (0x1D7A0, Some("font"), &[0x3A1]),
+    (0x1D7A1, Some("font"), &[0x3F4]),
+    (0x1D7A2, Some("font"), &[0x3A3]),
+    (0x1D7A3, Some("font"), &[0x3A4]),
+    (0x1D7A4, Some("font"), &[0x3A5]),
+    (0x1D7A5, Some("font"), &[0x3A6]),
+    (0x1D7A6, Some("font"), &[0x3A7]),
+    (0x1D7A7, Some("font"), &[0x3A8]),
+    (0x1D7A8, Some("font"), &[0x3A9]),
+    (0x1D7A9, Some("font"), &[0x2207]),
+    (0x1D7AA, Some("font"), &[0x3B1]),
+    (0x1D7AB, Some("font"), &[0x3B2]),
+    (0x1D7AC, Some("font"), &[0x3B3]),
+    (0x1D7AD, Some("font"), &[0x3B4]),
+    (0x1D7AE, Some("font"), &[0x3B5]),
+    (0x1D7AF, Some("font"), &[0x3B6]),
+    (0x1D7B0, Some("font"), &[0x3B7]),
+    (0x1D7B1, Some("font"), &[0x3B8]),
+    (0x1D7B2, Some("font"), &[0x3B9]),
+    (0x1D7B3, Some("font"), &[0x3BA]),
+    (0x1D7B4, Some("font"), &[0x3BB]),
+    (0x1D7B5, Some("font"), &[0x3BC]),
+    (0x1D7B6, Some("font"), &[0x3BD]),
+    (0x1D7B7, Some("font"), &[0x3BE]),
+    (0x1D7B8, Some("font"), &[0x3BF]),
+    (0x1D7B9, Some("font"), &[0x3C0]),
+    (0x1D7BA, Some("font"), &[0x3C1]),
+    (0x1D7BB, Some("font"), &[0x3C2]),
+    (0x1D7BC, Some("font"), &[0x3C3]),
+    (0x1D7BD, Some("font"), &[0x3C4]),
+    (0x1D7BE, Some("font"), &[0x3C5]),
+    (0x1D7BF, Some("font"), &[0x3C6]),
+    (0x1D7C0, Some("font"), &[0x3C7]),
+    (0x1D7C1, Some("font"), &[0x3C8]),
+    (0x1D7C2, Some("font"), &[0x3C9]),
+    (0x1D7C3, Some("font"), &[0x2202]),
+    (0x1D7C4, Some("font"), &[0x3F5]),
+    (0x1D7C5, Some("font"), &[0x3D1]),
+    (0x1D7C6, Some("font"), &[0x3F0]),
+    (0x1D7C7, Some("font"), &[0x3D5]),
+    (0x1D7C8, Some("font"), &[0x3F1]),
+    (0x1D7C9, Some("font"), &[0x3D6]),
+    (0x1D7CA, Some("font"), &[0x3DC]),
+    (0x1D7CB, Some("font"), &[0x3DD]),
+    (0x1D7CE, Some("font"), &[0x30]),
+    (0x1D7CF, Some("font"), &[0x31]),
+    (0x1D7D0, Some("font"), &[0x32]),
+    (0x1D7D1, Some("font"), &[0x33]),
+    (0x1D7D2, Some("font"), &[0x34]),
+    (0x1D7D3, Some("font"), &[0x35]),
+    (0x1D7D4, Some("font"), &[0x36]),
+    (0x1D7D5, Some("font"), &[0x37]),
+    (0x1D7D6, Some("font"), &[0x38]),
+    (0x1D7D7, Some("font"), &[0x39]),
+    (0x1D7D8, Some("font"), &[0x30]),
+    (0x1D7D9, Some("font"), &[0x31]),
+    (0x1D7DA, Some("font"), &[0x32]),
+    (0x1D7DB, Some("font"), &[0x33]),
+    (0x1D7DC, Some("font"), &[0x34]),
+    (0x1D7DD, Some("font"), &[0x35]),
+    (0x1D7DE, Some("font"), &[0x36]),
+    (0x1D7DF, Some("font"), &[0x37]),
+    (0x1D7E0, Some("font"), &[0x38]),
+    (0x1D7E1, Some("font"), &[0x39]),
+    (0x1D7E2, Some("font"), &[0x30]),
+    (0x1D7E3, Some("font"), &[0x31]),
+    (0x1D7E4, Some("font"), &[0x32]),
+    (0x1D7E5, Some("font"), &[0x33]),
+    (0x1D7E6, Some("font"), &[0x34]),
+    (0x1D7E7, Some("font"), &[0x35]),
+    (0x1D7E8, Some("font"), &[0x36]),
+    (0x1D7E9, Some("font"), &[0x37]),
+    (0x1D7EA, Some("font"), &[0x38]),
+    (0x1D7EB, Some("font"), &[0x39]),
+    (0x1D7EC, Some("font"), &[0x30]),
+    (0x1D7ED, Some("font"), &[0x31]),
+    (0x1D7EE, Some("font"), &[0x32]),
+    (0x1D7EF, Some("font"), &[0x33]),
+    (0x1D7F0, Some("font"), &[0x34]),
+    (0x1D7F1, Some("font"), &[0x35]),
+    (0x1D7F2, Some("font"), &[0x36]),
+    (0x1D7F3, Some("font"), &[0x37]),
+    (0x1D7F4, Some("font"), &[0x38]),
+    (0x1D7F5, Some("font"), &[0x39]),
+    (0x1D7F6, Some("font"), &[0x30]),
+    (0x1D7F7, Some("font"), &[0x31]),
+    (0x1D7F8, Some("font"), &[0x32]),
+    (0x1D7F9, Some("font"), &[0x33]),
+    (0x1D7FA, Some("font"), &[0x34]),
+    (0x1D7FB, Some("font"), &[0x35]),
+    (0x1D7FC, Some("font"), &[0x36]),
+    (0x1D7FD, Some("font"), &[0x37]),
+    (0x1D7FE, Some("font"), &[0x38]),
+    (0x1D7FF, Some("font"), &[0x39]),
+    (0x1EE00, Some("font"), &[0x627]),
+    (0x1EE01, Some("font"), &[0x628]),
+    (0x1EE02, Some("font"), &[0x62C]),
+    (0x1EE03, Some("font"), &[0x62F]),
+    (0x1EE05, Some("font"), &[0x648]),
+    (0x1EE06, Some("font"), &[0x632]),
+    (0x1EE07, Some("font"), &[0x62D]),
+    (0x1EE08, Some("font"), &[0x637]),
+    (0x1EE09, Some("font"), &[0x64A]),
+    (0x1EE0A, Some("font"), &[0x643]),
+    (0x1EE0B, Some("font"), &[0x644]),
+    (0x1EE0C, Some("font"), &[0x645]),
+    (0x1EE0D, Some("font"), &[0x646]),
+    (0x1EE0E, Some("font"), &[0x633]),
+    (0x1EE0F, Some("font"), &[0x639]),
+    (0x1EE10, Some("font"), &[0x641]),
+    (0x1EE11, Some("font"), &[0x635]),
+    (0x1EE12, Some("font"), &[0x642]),
+    (0x1EE13, Some("font"), &[0x631]),
+    (0x1EE14, Some("font"), &[0x634]),
+    (0x1EE15, Some("font"), &[0x62A]),
+    (0x1EE16, Some("font"), &[0x62B]),
+    (0x1EE17, Some("font"), &[0x62E]),
+    (0x1EE18, Some("font"), &[0x630]),
+    (0x1EE19, Some("font"), &[0x636]),
+    (0x1EE1A, Some("font"), &[0x638]),
+    (0x1EE1B, Some("font"), &[0x63A]),
+    (0x1EE1C, Some("font"), &[0x66E]),
+    (0x1EE1D, Some("font"), &[0x6BA]),
+    (0x1EE1E, Some("font"), &[0x6A1]),
+    (0x1EE1F, Some("font"), &[0x66F]),
+    (0x1EE21, Some("font"), &[0x628]),
+    (0x1EE22, Some("font"), &[0x62C]),
+    (0x1EE24, Some("font"), &[0x647]),
+    (0x1EE27, Some("font"), &[0x62D]),
+    (0x1EE29, Some("font"), &[0x64A]),
+    (0x1EE2A, Some("font"), &[0x643]),
+    (0x1EE2B, Some("font"), &[0x644]),
+    (0x1EE2C, Some("font"), &[0x645]),
+    (0x1EE2D, Some("font"), &[0x646]),
+    (0x1EE2E, Some("font"), &[0x633]),
+    (0x1EE2F, Some("font"), &[0x639]),
+    (0x1EE30, Some("font"), &[0x641]),
+    (0x1EE31, Some("font"), &[0x635]),
+    (0x1EE32, Some("font"), &[0x642]),
+    (0x1EE34, Some("font"), &[0x634]),
+    (0x1EE35, Some("font"), &[0x62A]),
+    (0x1EE36, Some("font"), &[0x62B]),
+    (0x1EE37, Some("font"), &[0x62E]),
+    (0x1EE39, Some("font"), &[0x636]),
+    (0x1EE3B, Some("font"), &[0x63A]),
+    (0x1EE42, Some("font"), &[0x62C]),
+    (0x1EE47, Some("font"), &[0x62D]),
+    (0x1EE49, Some("font"), &[0x64A]),
+    (0x1EE4B, Some("font"), &[0x644]),
+    (0x1EE4D, Some("font"), &[0x646]),
+    (0x1EE4E, Some("font"), &[0x633]),
+    (0x1EE4F, Some("font"), &[0x639]),
+    (0x1EE51, Some("font"), &[0x635]),
+    (0x1EE52, Some("font"), &[0x642]),
+    (0x1EE54, Some("font"), &[0x634]),
+    (0x1EE57, Some("font"), &[0x62E]),
+    (0x1EE59, Some("font"), &[0x636]),
+    (0x1EE5B, Some("font"), &[0x63A]),
+    (0x1EE5D, Some("font"), &[0x6BA]),
+    (0x1EE5F, Some("font"), &[0x66F]),
+    (0x1EE61, Some("font"), &[0x628]),
+    (0x1EE62, Some("font"), &[0x62C]),
+    (0x1EE64, Some("font"), &[0x647]),
+    (0x1EE67, Some("font"), &[0x62D]),
+    (0x1EE68, Some("font"), &[0x637]),
+    (0x1EE69, Some("font"), &[0x64A]),
+    (0x1EE6A, Some("font"), &[0x643]),
+    (0x1EE6C, Some("font"), &[0x645]),
+    (0x1EE6D, Some("font"), &[0x646]),
+    (0x1EE6E, Some("font"), &[0x633]),
+    (0x1EE6F, Some("font"), &[0x639]),
+    (0x1EE70, Some("font"), &[0x641]),
+    (0x1EE71, Some("font"), &[0x635]),
+    (0x1EE72, Some("font"), &[0x642]),
+    (0x1EE74, Some("font"), &[0x634]),
+    (0x1EE75, Some("font"), &[0x62A]),
+    (0x1EE76, Some("font"), &[0x62B]),
+    (0x1EE77, Some("font"), &[0x62E]),
+    (0x1EE79, Some("font"), &[0x636]),
+    (0x1EE7A, Some("font"), &[0x638]),
+    (0x1EE7B, Some("font"), &[0x63A]),
+    (0x1EE7C, Some("font"), &[0x66E]),
+    (0x1EE7E, Some("font"), &[0x6A1]),
+    (0x1EE80, Some("font"), &[0x627]),
+    (0x1EE81, Some("font"), &[0x628]),
+    (0x1EE82, Some("font"), &[0x62C]),
+    (0x1EE83, Some("font"), &[0x62F]),
+    (0x1EE84, Some("font"), &[0x647]),
+    (0x1EE85, Some("font"), &[0x648]),
+    (0x1EE86, Some("font"), &[0x632]),
+    (0x1EE87, Some("font"), &[0x62D]),
+    (0x1EE88, Some("font"), &[0x637]),
+    (0x1EE89, Some("font"), &[0x64A]),
+    (0x1EE8B, Some("font"), &[0x644]),
+    (0x1EE8C, Some("font"), &[0x645]),
+    (0x1EE8D, Some("font"), &[0x646]),
+    (0x1EE8E, Some("font"), &[0x633]),
+    (0x1EE8F, Some("font"), &[0x639]),
+    (0x1EE90, Some("font"), &[0x641]),
+    (0x1EE91, Some("font"), &[0x635]),
+    (0x1EE92, Some("font"), &[0x642]),
+    (0x1EE93, Some("font"), &[0x631]),
+    (0x1EE94, Some("font"), &[0x634]),
+    (0x1EE95, Some("font"), &[0x62A]),
+    (0x1EE96, Some("font"), &[0x62B]),
+    (0x1EE97, Some("font"), &[0x62E]),
+    (0x1EE98, Some("font"), &[0x630]),
+    (0x1EE99, Some("font"), &[0x636]),
+    (0x1EE9A, Some("font"), &[0x638]),
+    (0x1EE9B, Some("font"), &[0x63A]),
+    (0x1EEA1, Some("font"), &[0x628]),
+    (0x1EEA2, Some("font"), &[0x62C]),
+    (0x1EEA3, Some("font"), &[0x62F]),
+    (0x1EEA5, Some("font"), &[0x648]),
+    (0x1EEA6, Some("font"), &[0x632]),
+    (0x1EEA7, Some("font"), &[0x62D]),
+    (0x1EEA8, Some("font"), &[0x637]),
+    (0x1EEA9, Some("font"), &[0x64A]),
+    (0x1EEAB, Some("font"), &[0x644]),
+    (0x1EEAC, Some("font"), &[0x645]),
+    (0x1EEAD, Some("font"), &[0x646]),
+    (0x1EEAE, Some("font"), &[0x633]),
+    (0x1EEAF, Some("font"), &[0x639]),
+    (0x1EEB0, Some("font"), &[0x641]),
+    (0x1EEB1, Some("font"), &[0x635]),
+    (0x1EEB2, Some("font"), &[0x642]),
+    (0x1EEB3, Some("font"), &[0x631]),
+    (0x1EEB4, Some("font"), &[0x634]),
+    (0x1EEB5, Some("font"), &[0x62A]),
+    (0x1EEB6, Some("font"), &[0x62B]),
+    (0x1EEB7, Some("font"), &[0x62E]),
+    (0x1EEB8, Some("font"), &[0x630]),
+    (0x1EEB9, Some("font"), &[0x636]),
+    (0x1EEBA, Some("font"), &[0x638]),
+    (0x1EEBB, Some("font"), &[0x63A]),
+    (0x1F100, Some("compat"), &[0x30, 0x2E]),
+    (0x1F101, Some("compat"), &[0x30, 0x2C]),
+    (0x1F102, Some("compat"), &[0x31, 0x2C]),
+    (0x1F103, Some("compat"), &[0x32, 0x2C]),
+    (0x1F104, Some("compat"), &[0x33, 0x2C]),
+    (0x1F105, Some("compat"), &[0x34, 0x2C]),
+    (0x1F106, Some("compat"), &[0x35, 0x2C]),
+    (0x1F107, Some("compat"), &[0x36, 0x2C]),
+    (0x1F108, Some("compat"), &[0x37, 0x2C]),
+    (0x1F109, Some("compat"), &[0x38, 0x2C]),
+    (0x1F10A, Some("compat"), &[0x39, 0x2C]),
+    (0x1F110, Some("compat"), &[0x28, 0x41, 0x29]),
+    (0x1F111, Some("compat"), &[0x28, 0x42, 0x29]),
+    (0x1F112, Some("compat"), &[0x28, 0x43, 0x29]),
+    (0x1F113, Some("compat"), &[0x28, 0x44, 0x29]),
+    (0x1F114, Some("compat"), &[0x28, 0x45, 0x29]),
+    (0x1F115, Some("compat"), &[0x28, 0x46, 0x29]),
+    (0x1F116, Some("compat"), &[0x28, 0x47, 0x29]),
+    (0x1F117, Some("compat"), &[0x28, 0x48, 0x29]),
+    (0x1F118, Some("compat"), &[0x28, 0x49, 0x29]),
+    (0x1F119, Some("compat"), &[0x28, 0x4A, 0x29]),
+    (0x1F11A, Some("compat"), &[0x28, 0x4B, 0x29]),
+    (0x1F11B, Some("compat"), &[0x28, 0x4C, 0x29]),
+    (0x1F11C, Some("compat"), &[0x28, 0x4D, 0x29]),
+    (0x1F11D, Some("compat"), &[0x28, 0x4E, 0x29]),
+    (0x1F11E, Some("compat"), &[0x28, 0x4F, 0x29]),
+    (0x1F11F, Some("compat"), &[0x28, 0x50, 0x29]),
+    (0x1F120, Some("compat"), &[0x28, 0x51, 0x29]),
+    (0x1F121, Some("compat"), &[0x28, 0x52, 0x29]),
+    (0x1F122, Some("compat"), &[0x28, 0x53, 0x29]),
+    (0x1F123, Some("compat"), &[0x28, 0x54, 0x29]),
+    (0x1F124, Some("compat"), &[0x28, 0x55, 0x29]),
+    (0x1F125, Some("compat"), &[0x28, 0x56, 0x29]),
+    (0x1F126, Some("compat"), &[0x28, 0x57, 0x29]),
+    (0x1F127, Some("compat"), &[0x28, 0x58, 0x29]),
+    (0x1F128, Some("compat"), &[0x28, 0x59, 0x29]),
+    (0x1F129, Some("compat"), &[0x28, 0x5A, 0x29]),
+    (0x1F12A, Some("compat"), &[0x3014, 0x53, 0x3015]),
+    (0x1F12B, Some("circle"), &[0x43]),
+    (0x1F12C, Some("circle"), &[0x52]),
+    (0x1F12D, Some("circle"), &[0x43, 0x44]),
+    (0x1F12E, Some("circle"), &[0x57, 0x5A]),
+    (0x1F130, Some("square"), &[0x41]),
+    (0x1F131, Some("square"), &[0x42]),
+    (0x1F132, Some("square"), &[0x43]),
+    (0x1F133, Some("square"), &[0x44]),
+    (0x1F134, Some("square"), &[0x45]),
+    (0x1F135, Some("square"), &[0x46]),
+    (0x1F136, Some("square"), &[0x47]),
+    (0x1F137, Some("square"), &[0x48]),
+    (0x1F138, Some("square"), &[0x49]),
+    (0x1F139, Some("square"), &[0x4A]),
+    (0x1F13A, Some("square"), &[0x4B]),
+    (0x1F13B, Some("square"), &[0x4C]),
+    (0x1F13C, Some("square"), &[0x4D]),
+    (0x1F13D, Some("square"), &[0x4E]),
+    (0x1F13E, Some("square"), &[0x4F]),
+    (0x1F13F, Some("square"), &[0x50]),
+    (0x1F140, Some("square"), &[0x51]),
+    (0x1F141, Some("square"), &[0x52]),
+    (0x1F142, Some("square"), &[0x53]),
+    (0x1F143, Some("square"), &[0x54]),
+    (0x1F144, Some("square"), &[0x55]),
+    (0x1F145, Some("square"), &[0x56]),
+    (0x1F146, Some("square"), &[0x57]),
+    (0x1F147, Some("square"), &[0x58]),
+    (0x1F148, Some("square"), &[0x59]),
+    (0x1F149, Some("square"), &[0x5A]),
+    (0x1F14A, Some("square"), &[0x48, 0x56]),
+    (0x1F14B, Some("square"), &[0x4D, 0x56]),
+    (0x1F14C, Some("square"), &[0x53, 0x44]),
+    (0x1F14D, Some("square"), &[0x53, 0x53]),
+    (0x1F14E, Some("square"), &[0x50, 0x50, 0x56]),
+    (0x1F14F, Some("square"), &[0x57, 0x43]),
+    (0x1F16A, Some("super"), &[0x4D, 0x43]),
+    (0x1F16B, Some("super"), &[0x4D, 0x44]),
+    (0x1F16C, Some("super"), &[0x4D, 0x52]),
+    (0x1F190, Some("square"), &[0x44, 0x4A]),
+    (0x1F200, Some("square"), &[0x307B, 0x304B]),
+    (0x1F201, Some("square"), &[0x30B3, 0x30B3]),
+    (0x1F202, Some("square"), &[0x30B5]),
+    (0x1F210, Some("square"), &[0x624B]),
+    (0x1F211, Some("square"), &[0x5B57]),
+    (0x1F212, Some("square"), &[0x53CC]),
+    (0x1F213, Some("square"), &[0x30C7]),
+    (0x1F214, Some("square"), &[0x4E8C]),
+    (0x1F215, Some("square"), &[0x591A]),
+    (0x1F216, Some("square"), &[0x89E3]),
+    (0x1F217, Some("square"), &[0x5929]),
+    (0x1F218, Some("square"), &[0x4EA4]),
+    (0x1F219, Some("square"), &[0x6620]),
+    (0x1F21A, Some("square"), &[0x7121]),
+    (0x1F21B, Some("square"), &[0x6599]),
+    (0x1F21C, Some("square"), &[0x524D]),
+    (0x1F21D, Some("square"), &[0x5F8C]),
+    (0x1F21E, Some("square"), &[0x518D]),
+    (0x1F21F, Some("square"), &[0x65B0]),
+    (0x1F220, Some("square"), &[0x521D]),
+    (0x1F221, Some("square"), &[0x7D42]),
+    (0x1F222, Some("square"), &[0x751F]),
+    (0x1F223, Some("square"), &[0x8CA9]),
+    (0x1F224, Some("square"), &[0x58F0]),
+    (0x1F225, Some("square"), &[0x5439]),
+    (0x1F226, Some("square"), &[0x6F14]),
+    (0x1F227, Some("square"), &[0x6295]),
+    (0x1F228, Some("square"), &[0x6355]),
+    (0x1F229, Some("square"), &[0x4E00]),
+    (0x1F22A, Some("square"), &[0x4E09]),
+    (0x1F22B, Some("square"), &[0x904A]),
+    (0x1F22C, Some("square"), &[0x5DE6]),
+    (0x1F22D, Some("square"), &[0x4E2D]),
+    (0x1F22E, Some("square"), &[0x53F3]),
+    (0x1F22F, Some("square"), &[0x6307]),
+    (0x1F230, Some("square"), &[0x8D70]),
+    (0x1F231, Some("square"), &[0x6253]),
+    (0x1F232, Some("square"), &[0x7981]),
+    (0x1F233, Some("square"), &[0x7A7A]),
+    (0x1F234, Some("square"), &[0x5408]),
+    (0x1F235, Some("square"), &[0x6E80]),
+    (0x1F236, Some("square"), &[0x6709]),
+    (0x1F237, Some("square"), &[0x6708]),
+    (0x1F238, Some("square"), &[0x7533]),
+    (0x1F239, Some("square"), &[0x5272]),
+    (0x1F23A, Some("square"), &[0x55B6]),
+    (0x1F23B, Some("square"), &[0x914D]),
+    (0x1F240, Some("compat"), &[0x3014, 0x672C, 0x3015]),
+    (0x1F241, Some("compat"), &[0x3014, 0x4E09, 0x3015]),
+    (0x1F242, Some("compat"), &[0x3014, 0x4E8C, 0x3015]),
+    (0x1F243, Some("compat"), &[0x3014, 0x5B89, 0x3015]),
+    (0x1F244, Some("compat"), &[0x3014, 0x70B9, 0x3015]),
+    (0x1F245, Some("compat"), &[0x3014, 0x6253, 0x3015]),
+    (0x1F246, Some("compat"), &[0x3014, 0x76D7, 0x3015]),
+    (0x1F247, Some("compat"), &[0x3014, 0x52DD, 0x3015]),
+    (0x1F248, Some("compat"), &[0x3014, 0x6557, 0x3015]),
+    (0x1F250, Some("circle"), &[0x5F97]),
+    (0x1F251, Some("circle"), &[0x53EF]),
+    (0x1FBF0, Some("font"), &[0x30]),
+    (0x1FBF1, Some("font"), &[0x31]),
+    (0x1FBF2, Some("font"), &[0x32]),
+    (0x1FBF3, Some("font"), &[0x33]),
+    (0x1FBF4, Some("font"), &[0x34]),
+    (0x1FBF5, Some("font"), &[0x35]),
+    (0x1FBF6, Some("font"), &[0x36]),
+    (0x1FBF7, Some("font"), &[0x37]),
+    (0x1FBF8, Some("font"), &[0x38]),
+    (0x1FBF9, Some("font"), &[0x39]),
+    (0x2F800, None, &[0x4E3D]),
+    (0x2F801, None, &[0x4E38]),
+    (0x2F802, None, &[0x4E41]),
+    (0x2F803, None, &[0x20122]),
+    (0x2F804, None, &[0x4F60]),
+    (0x2F805, None, &[0x4FAE]),
+    (0x2F806, None, &[0x4FBB]),
+    (0x2F807, None, &[0x5002]),
+    (0x2F808, None, &[0x507A]),
+    (0x2F809, None, &[0x5099]),
+    (0x2F80A, None, &[0x50E7]),
+    (0x2F80B, None, &[0x50CF]),
+    (0x2F80C, None, &[0x349E]),
+    (0x2F80D, None, &[0x2063A]),
+    (0x2F80E, None, &[0x514D]),
+    (0x2F80F, None, &[0x5154]),
+    (0x2F810, None, &[0x5164]),
+    (0x2F811, None, &[0x5177]),
+    (0x2F812, None, &[0x2051C]),
+    (0x2F813, None, &[0x34B9]),
+    (0x2F814, None, &[0x5167]),
+    (0x2F815, None, &[0x518D]),
+    (0x2F816, None, &[0x2054B]),
+    (0x2F817, None, &[0x5197]),
+    (0x2F818, None, &[0x51A4]),
+    (0x2F819, None, &[0x4ECC]),
+    (0x2F81A, None, &[0x51AC]),
+    (0x2F81B, None, &[0x51B5]),
+    (0x2F81C, None, &[0x291DF]),
+    (0x2F81D, None, &[0x51F5]),
+    (0x2F81E, None, &[0x5203]),
+    (0x2F81F, None, &[0x34DF]),
+    (0x2F820, None, &[0x523B]),
+    (0x2F821, None, &[0x5246]),
+    (0x2F822, None, &[0x5272]),
+    (0x2F823, None, &[0x5277]),
+    (0x2F824, None, &[0x3515]),
+    (0x2F825, None, &[0x52C7]),
+    (0x2F826, None, &[0x52C9]),
+    (0x2F827, None, &[0x52E4]),
+    (0x2F828, None, &[0x52FA]),
+    (0x2F829, None, &[0x5305]),
+    (0x2F82A, None, &[0x5306]),
+    (0x2F82B, None, &[0x5317]),
+    (0x2F82C, None, &[0x5349]),
+    (0x2F82D, None, &[0x5351]),
+    (0x2F82E, None, &[0x535A]),
+    (0x2F82F, None, &[0x5373]),
+    (0x2F830, None, &[0x537D]),
+    (0x2F831, None, &[0x537F]),
+    (0x2F832, None, &[0x537F]),
+    (0x2F833, None, &[0x537F]),
+    (0x2F834, None, &[0x20A2C]),
+    (0x2F835, None, &[0x7070]),
+    (0x2F836, None, &[0x53CA]),
+    (0x2F837, None, &[0x53DF]),
+    (0x2F838, None, &[0x20B63]),
+    (0x2F839, None, &[0x53EB]),
+    (0x2F83A, None, &[0x53F1]),
+    (0x2F83B, None, &[0x5406]),
+    (0x2F83C, None, &[0x549E]),
+    (0x2F83D, None, &[0x5438]),
+    (0x2F83E, None, &[0x5448]),
+    (0x2F83F, None, &[0x5468]),
+    (0x2F840, None, &[0x54A2]),
+    (0x2F841, None, &[0x54F6]),
+    (0x2F842, None, &[0x5510]),
+    (0x2F843, None, &[0x5553]),
+    (0x2F844, None, &[0x5563]),
+    (0x2F845, None, &[0x5584]),
+    (0x2F846, None, &[0x5584]),
+    (0x2F847, None, &[0x5599]),
+    (0x2F848, None, &[0x55AB]),
+    (0x2F849, None, &[0x55B3]),
+    (0x2F84A, None, &[0x55C2]),
+    (0x2F84B, None, &[0x5716]),
+    (0x2F84C, None, &[0x5606]),
+    (0x2F84D, None, &[0x5717]),
+    (0x2F84E, None, &[0x5651]),
+    (0x2F84F, None, &[0x5674]),
+    (0x2F850, None, &[0x5207]),
+    (0x2F851, None, &[0x58EE]),
+    (0x2F852, None, &[0x57CE]),
+    (0x2F853, None, &[0x57F4]),
+    (0x2F854, None, &[0x580D]),
+    (0x2F855, None, &[0x578B]),
+    (0x2F856, None, &[0x5832]),
+    (0x2F857, None, &[0x5831]),
+    (0x2F858, None, &[0x58AC]),
+    (0x2F859, None, &[0x214E4]),
+    (0x2F85A, None, &[0x58F2]),
+    (0x2F85B, None, &[0x58F7]),
+    (0x2F85C, None, &[0x5906]),
+    (0x2F85D, None, &[0x591A]),
+    (0x2F85E, None, &[0x5922]),
+    (0x2F85F, None, &[0x5962]),
+    (0x2F860, None, &[0x216A8]),
+    (0x2F861, None, &[0x216EA]),
+    (0x2F862, None, &[0x59EC]),
+    (0x2F863, None, &[0x5A1B]),
+    (0x2F864, None, &[0x5A27]),
+    (0x2F865, None, &[0x59D8]),
+    (0x2F866, None, &[0x5A66]),
+    (0x2F867, None, &[0x36EE]),
+    (0x2F868, None, &[0x36FC]),
+    (0x2F869, None, &[0x5B08]),
+    (0x2F86A, None, &[0x5B3E]),
+    (0x2F86B, None, &[0x5B3E]),
+    (0x2F86C, None, &[0x219C8]),
+    (0x2F86D, None, &[0x5BC3]),
+    (0x2F86E, None, &[0x5BD8]),
+    (0x2F86F, None, &[0x5BE7]),
+    (0x2F870, None, &[0x5BF3]),
+    (0x2F871, None, &[0x21B18]),
+    (0x2F872, None, &[0x5BFF]),
+    (0x2F873, None, &[0x5C06]),
+    (0x2F874, None, &[0x5F53]),
+    (0x2F875, None, &[0x5C22]),
+    (0x2F876, None, &[0x3781]),
+    (0x2F877, None, &[0x5C60]),
+    (0x2F878, None, &[0x5C6E]),
+    (0x2F879, None, &[0x5CC0]),
+    (0x2F87A, None, &[0x5C8D]),
+    (0x2F87B, None, &[0x21DE4]),
+    (0x2F87C, None, &[0x5D43]),
+    (0x2F87D, None, &[0x21DE6]),
+    (0x2F87E, None, &[0x5D6E]),
+    (0x2F87F, None, &[0x5D6B]),
+    (0x2F880, None, &[0x5D7C]),
+    (0x2F881, None, &[0x5DE1]),
+    (0x2F882, None, &[0x5DE2]),
+    (0x2F883, None, &[0x382F]),
+    (0x2F884, None, &[0x5DFD]),
+    (0x2F885, None, &[0x5E28]),
+    (0x2F886, None, &[0x5E3D]),
+    (0x2F887, None, &[0x5E69]),
+    (0x2F888, None, &[0x3862]),
+    (0x2F889, None, &[0x22183]),
+    (0x2F88A, None, &[0x387C]),
+    (0x2F88B, None, &[0x5EB0]),
+    (0x2F88C, None, &[0x5EB3]),
+    (0x2F88D, None, &[0x5EB6]),
+    (0x2F88E, None, &[0x5ECA]),
+    (0x2F88F, None, &[0x2A392]),
+    (0x2F890, None, &[0x5EFE]),
+    (0x2F891, None, &[0x22331]),
+    (0x2F892, None, &[0x22331]),
+    (0x2F893, None, &[0x8201]),
+    (0x2F894, None, &[0x5F22]),
+    (0x2F895, None, &[0x5F22]),
+    (0x2F896, None, &[0x38C7]),
+    (0x2F897, None, &[0x232B8]),
+    (0x2F898, None, &[0x261DA]),
+    (0x2F899, None, &[0x5F62]),
+    (0x2F89A, None, &[0x5F6B]),
+    (0x2F89B, None, &[0x38E3]),
+    (0x2F89C, None, &[0x5F9A]),
+    (0x2F89D, None, &[0x5FCD]),
+    (0x2F89E, None, &[0x5FD7]),
+    (0x2F89F, None, &[0x5FF9]),
+    (0x2F8A0, None, &[0x6081]),
+    (0x2F8A1, None, &[0x393A]),
+    (0x2F8A2, None, &[0x391C]),
+    (0x2F8A3, None, &[0x6094]),
+    (0x2F8A4, None, &[0x226D4]),
+    (0x2F8A5, None, &[0x60C7]),
+    (0x2F8A6, None, &[0x6148]),
+    (0x2F8A7, None, &[0x614C]),
+    (0x2F8A8, None, &[0x614E]),
+    (0x2F8A9, None, &[0x614C]),
+    (0x2F8AA, None, &[0x617A]),
+    (0x2F8AB, None, &[0x618E]),
+    (0x2F8AC, None, &[0x61B2]),
+    (0x2F8AD, None, &[0x61A4]),
+    (0x2F8AE, None, &[0x61AF]),
+    (0x2F8AF, None, &[0x61DE]),
+    (0x2F8B0, None, &[0x61F2]),
+    (0x2F8B1, None, &[0x61F6]),
+    (0x2F8B2, None, &[0x6210]),
+    (0x2F8B3, None, &[0x621B]),
+    (0x2F8B4, None, &[0x625D]),
+    (0x2F8B5, None, &[0x62B1]),
+    (0x2F8B6, None, &[0x62D4]),
+    (0x2F8B7, None, &[0x6350]),
+    (0x2F8B8, None, &[0x22B0C]),
+    (0x2F8B9, None, &[0x633D]),
+    (0x2F8BA, None, &[0x62FC]),
+    (0x2F8BB, None, &[0x6368]),
+    (0x2F8BC, None, &[0x6383]),
+    (0x2F8BD, None, &[0x63E4]),
+    (0x2F8BE, None, &[0x22BF1]),
+    (0x2F8BF, None, &[0x6422]),
+    (0x2F8C0, None, &[0x63C5]),
+    (0x2F8C1, None, &[0x63A9]),
+    (0x2F8C2, None, &[0x3A2E]),
+    (0x2F8C3, None, &[0x6469]),
+    (0x2F8C4, None, &[0x647E]),
+    (0x2F8C5, None, &[0x649D]),
+    (0x2F8C6, None, &[0x6477]),
+    (0x2F8C7, None, &[0x3A6C]),
+    (0x2F8C8, None, &[0x654F]),
+    (0x2F8C9, None, &[0x656C]),
+    (0x2F8CA, None, &[0x2300A]),
+    (0x2F8CB, None, &[0x65E3]),
+    (0x2F8CC, None, &[0x66F8]),
+    (0x2F8CD, None, &[0x6649]),
+    (0x2F8CE, None, &[0x3B19]),
+    (0x2F8CF, None, &[0x6691]),
+    (0x2F8D0, None, &[0x3B08]),
+    (0x2F8D1, None, &[0x3AE4]),
+    (0x2F8D2, None, &[0x5192]),
+    (0x2F8D3, None, &[0x5195]),
+    (0x2F8D4, None, &[0x6700]),
+    (0x2F8D5, None, &[0x669C]),
+    (0x2F8D6, None, &[0x80AD]),
+    (0x2F8D7, None, &[0x43D9]),
+    (0x2F8D8, None, &[0x6717]),
+    (0x2F8D9, None, &[0x671B]),
+    (0x2F8DA, None, &[0x6721]),
+    (0x2F8DB, None, &[0x675E]),
+    (0x2F8DC, None, &[0x6753]),
+    (0x2F8DD, None, &[0x233C3]),
+    (0x2F8DE, None, &[0x3B49]),
+    (0x2F8DF, None, &[0x67FA]),
+    (0x2F8E0, None, &[0x6785]),
+    (0x2F8E1, None, &[0x6852]),
+    (0x2F8E2, None, &[0x6885]),
+    (0x2F8E3, None, &[0x2346D]),
+    (0x2F8E4, None, &[0x688E]),
+    (0x2F8E5, None, &[0x681F]),
+    (0x2F8E6, None, &[0x6914]),
+    (0x2F8E7, None, &[0x3B9D]),
+    (0x2F8E8, None, &[0x6942]),
+    (0x2F8E9, None, &[0x69A3]),
+    (0x2F8EA, None, &[0x69EA]),
+    (0x2F8EB, None, &[0x6AA8]),
+    (0x2F8EC, None, &[0x236A3]),
+    (0x2F8ED, None, &[0x6ADB]),
+    (0x2F8EE, None, &[0x3C18]),
+    (0x2F8EF, None, &[0x6B21]),
+    (0x2F8F0, None, &[0x238A7]),
+    (0x2F8F1, None, &[0x6B54]),
+    (0x2F8F2, None, &[0x3C4E]),
+    (0x2F8F3, None, &[0x6B72]),
+    (0x2F8F4, None, &[0x6B9F]),
+    (0x2F8F5, None, &[0x6BBA]),
+    (0x2F8F6, None, &[0x6BBB]),
+    (0x2F8F7, None, &[0x23A8D]),
+    (0x2F8F8, None, &[0x21D0B]),
+    (0x2F8F9, None, &[0x23AFA]),
+    (0x2F8FA, None, &[0x6C4E]),
+    (0x2F8FB, None, &[0x23CBC]),
+    (0x2F8FC, None, &[0x6CBF]),
+    (0x2F8FD, None, &[0x6CCD]),
+    (0x2F8FE, None, &[0x6C67]),
+    (0x2F8FF, None, &[0x6D16]),
+    (0x2F900, None, &[0x6D3E]),
+    (0x2F901, None, &[0x6D77]),
+    (0x2F902, None, &[0x6D41]),
+    (0x2F903, None, &[0x6D69]),
+    (0x2F904, None, &[0x6D78]),
+    (0x2F905, None, &[0x6D85]),
+    (0x2F906, None, &[0x23D1E]),
+    (0x2F907, None, &[0x6D34]),
+    (0x2F908, None, &[0x6E2F]),
+    (0x2F909, None, &[0x6E6E]),
+    (0x2F90A, None, &[0x3D33]),
+    (0x2F90B, None, &[0x6ECB]),
+    (0x2F90C, None, &[0x6EC7]),
+    (0x2F90D, None, &[0x23ED1]),
+    (0x2F90E, None, &[0x6DF9]),
+    (0x2F90F, None, &[0x6F6E]),
+    (0x2F910, None, &[0x23F5E]),
+    (0x2F911, None, &[0x23F8E]),
+    (0x2F912, None, &[0x6FC6]),
+    (0x2F913, None, &[0x7039]),
+    (0x2F914, None, &[0x701E]),
+    (0x2F915, None, &[0x701B]),
+    (0x2F916, None, &[0x3D96]),
+    (0x2F917, None, &[0x704A]),
+    (0x2F918, None, &[0x707D]),
+    (0x2F919, None, &[0x7077]),
+    (0x2F91A, None, &[0x70AD]),
+    (0x2F91B, None, &[0x20525]),
+    (0x2F91C, None, &[0x7145]),
+    (0x2F91D, None, &[0x24263]),
+    (0x2F91E, None, &[0x719C]),
+    (0x2F91F, None, &[0x243AB]),
+    (0x2F920, None, &[0x7228]),
+    (0x2F921, None, &[0x7235]),
+    (0x2F922, None, &[0x7250]),
+    (0x2F923, None, &[0x24608]),
+    (0x2F924, None, &[0x7280]),
+    (0x2F925, None, &[0x7295]),
+    (0x2F926, None, &[0x24735]),
+    (0x2F927, None, &[0x24814]),
+    (0x2F928, None, &[0x737A]),
+    (0x2F929, None, &[0x738B]),
+    (0x2F92A, None, &[0x3EAC]),
+    (0x2F92B, None, &[0x73A5]),
+    (0x2F92C, None, &[0x3EB8]),
+    (0x2F92D, None, &[0x3EB8]),
+    (0x2F92E, None, &[0x7447]),
+    (0x2F92F, None, &[0x745C]),
+    (0x2F930, None, &[0x7471]),
+    (0x2F931, None, &[0x7485]),
+    (0x2F932, None, &[0x74CA]),
+    (0x2F933, None, &[0x3F1B]),
+    (0x2F934, None, &[0x7524]),
+    (0x2F935, None, &[0x24C36]),
+    (0x2F936, None, &[0x753E]),
+    (0x2F937, None, &[0x24C92]),
+    (0x2F938, None, &[0x7570]),
+    (0x2F939, None, &[0x2219F]),
+    (0x2F93A, None, &[0x7610]),
+    (0x2F93B, None, &[0x24FA1]),
+    (0x2F93C, None, &[0x24FB8]),
+    (0x2F93D, None, &[0x25044]),
+    (0x2F93E, None, &[0x3FFC]),
+    (0x2F93F, None, &[0x4008]),
+    (0x2F940, None, &[0x76F4]),
+    (0x2F941, None, &[0x250F3]),
+    (0x2F942, None, &[0x250F2]),
+    (0x2F943, None, &[0x25119]),
+    (0x2F944, None, &[0x25133]),
+    (0x2F945, None, &[0x771E]),
+    (0x2F946, None, &[0x771F]),
+    (0x2F947, None, &[0x771F]),
+    (0x2F948, None, &[0x774A]),
+    (0x2F949, None, &[0x4039]),
+    (0x2F94A, None, &[0x778B]),
+    (0x2F94B, None, &[0x4046]),
+    (0x2F94C, None, &[0x4096]),
+    (0x2F94D, None, &[0x2541D]),
+    (0x2F94E, None, &[0x784E]),
+    (0x2F94F, None, &[0x788C]),
+    (0x2F950, None, &[0x78CC]),
+    (0x2F951, None, &[0x40E3]),
+    (0x2F952, None, &[0x25626]),
+    (0x2F953, None, &[0x7956]),
+    (0x2F954, None, &[0x2569A]),
+    (0x2F955, None, &[0x256C5]),
+    (0x2F956, None, &[0x798F]),
+    (0x2F957, None, &[0x79EB]),
+    (0x2F958, None, &[0x412F]),
+    (0x2F959, None, &[0x7A40]),
+    (0x2F95A, None, &[0x7A4A]),
+    (0x2F95B, None, &[0x7A4F]),
+    (0x2F95C, None, &[0x2597C]),
+    (0x2F95D, None, &[0x25AA7]),
+    (0x2F95E, None, &[0x25AA7]),
+    (0x2F95F, None, &[0x7AEE]),
+    (0x2F960, None, &[0x4202]),
+    (0x2F961, None, &[0x25BAB]),
+    (0x2F962, None, &[0x7BC6]),
+    (0x2F963, None, &[0x7BC9]),
+    (0x2F964, None, &[0x4227]),
+    (0x2F965, None, &[0x25C80]),
+    (0x2F966, None, &[0x7CD2]),
+    (0x2F967, None, &[0x42A0]),
+    (0x2F968, None, &[0x7CE8]),
+    (0x2F969, None, &[0x7CE3]),
+    (0x2F96A, None, &[0x7D00]),
+    (0x2F96B, None, &[0x25F86]),
+    (0x2F96C, None, &[0x7D63]),
+    (0x2F96D, None, &[0x4301]),
+    (0x2F96E, None, &[0x7DC7]),
+    (0x2F96F, None, &[0x7E02]),
+    (0x2F970, None, &[0x7E45]),
+    (0x2F971, None, &[0x4334]),
+    (0x2F972, None, &[0x26228]),
+    (0x2F973, None, &[0x26247]),
+    (0x2F974, None, &[0x4359]),
+    (0x2F975, None, &[0x262D9]),
+    (0x2F976, None, &[0x7F7A]),
+    (0x2F977, None, &[0x2633E]),
+    (0x2F978, None, &[0x7F95]),
+    (0x2F979, None, &[0x7FFA]),
+    (0x2F97A, None, &[0x8005]),
+    (0x2F97B, None, &[0x264DA]),
+    (0x2F97C, None, &[0x26523]),
+    (0x2F97D, None, &[0x8060]),
+    (0x2F97E, None, &[0x265A8]),
+    (0x2F97F, None, &[0x8070]),
+    (0x2F980, None, &[0x2335F]),
+    (0x2F981, None, &[0x43D5]),
+    (0x2F982, None, &[0x80B2]),
+    (0x2F983, None, &[0x8103]),
+    (0x2F984, None, &[0x440B]),
+    (0x2F985, None, &[0x813E]),
+    (0x2F986, None, &[0x5AB5]),
+    (0x2F987, None, &[0x267A7]),
+    (0x2F988, None, &[0x267B5]),
+    (0x2F989, None, &[0x23393]),
+    (0x2F98A, None, &[0x2339C]),
+    (0x2F98B, None, &[0x8201]),
+    (0x2F98C, None, &[0x8204]),
+    (0x2F98D, None, &[0x8F9E]),
+    (0x2F98E, None, &[0x446B]),
+    (0x2F98F, None, &[0x8291]),
+    (0x2F990, None, &[0x828B]),
+    (0x2F991, None, &[0x829D]),
+    (0x2F992, None, &[0x52B3]),
+    (0x2F993, None, &[0x82B1]),
+    (0x2F994, None, &[0x82B3]),
+    (0x2F995, None, &[0x82BD]),
+    (0x2F996, None, &[0x82E6]),
+    (0x2F997, None, &[0x26B3C]),
+    (0x2F998, None, &[0x82E5]),
+    (0x2F999, None, &[0x831D]),
+    (0x2F99A, None, &[0x8363]),
+    (0x2F99B, None, &[0x83AD]),
+    (0x2F99C, None, &[0x8323]),
+    (0x2F99D, None, &[0x83BD]),
+    (0x2F99E, None, &[0x83E7]),
+    (0x2F99F, None, &[0x8457]),
+    (0x2F9A0, None, &[0x8353]),
+    (0x2F9A1, None, &[0x83CA]),
+    (0x2F9A2, None, &[0x83CC]),
+    (0x2F9A3, None, &[0x83DC]),
+    (0x2F9A4, None, &[0x26C36]),
+    (0x2F9A5, None, &[0x26D6B]),
+    (0x2F9A6, None, &[0x26CD5]),
+    (0x2F9A7, None, &[0x452B]),
+    (0x2F9A8, None, &[0x84F1]),
+    (0x2F9A9, None, &[0x84F3]),
+    (0x2F9AA, None, &[0x8516]),
+    (0x2F9AB, None, &[0x273CA]),
+    (0x2F9AC, None, &[0x8564]),
+    (0x2F9AD, None, &[0x26F2C]),
+    (0x2F9AE, None, &[0x455D]),
+    (0x2F9AF, None, &[0x4561]),
+    (0x2F9B0, None, &[0x26FB1]),
+    (0x2F9B1, None, &[0x270D2]),
+    (0x2F9B2, None, &[0x456B]),
+    (0x2F9B3, None, &[0x8650]),
+    (0x2F9B4, None, &[0x865C]),
+    (0x2F9B5, None, &[0x8667]),
+    (0x2F9B6, None, &[0x8669]),
+    (0x2F9B7, None, &[0x86A9]),
+    (0x2F9B8, None, &[0x8688]),
+    (0x2F9B9, None, &[0x870E]),
+    (0x2F9BA, None, &[0x86E2]),
+    (0x2F9BB, None, &[0x8779]),
+    (0x2F9BC, None, &[0x8728]),
+    (0x2F9BD, None, &[0x876B]),
+    (0x2F9BE, None, &[0x8786]),
+    (0x2F9BF, None, &[0x45D7]),
+    (0x2F9C0, None, &[0x87E1]),
+    (0x2F9C1, None, &[0x8801]),
+    (0x2F9C2, None, &[0x45F9]),
+    (0x2F9C3, None, &[0x8860]),
+    (0x2F9C4, None, &[0x8863]),
+    (0x2F9C5, None, &[0x27667]),
+    (0x2F9C6, None, &[0x88D7]),
+    (0x2F9C7, None, &[0x88DE]),
+    (0x2F9C8, None, &[0x4635]),
+    (0x2F9C9, None, &[0x88FA]),
+    (0x2F9CA, None, &[0x34BB]),
+    (0x2F9CB, None, &[0x278AE]),
+    (0x2F9CC, None, &[0x27966]),
+    (0x2F9CD, None, &[0x46BE]),
+    (0x2F9CE, None, &[0x46C7]),
+    (0x2F9CF, None, &[0x8AA0]),
+    (0x2F9D0, None, &[0x8AED]),
+    (0x2F9D1, None, &[0x8B8A]),
+    (0x2F9D2, None, &[0x8C55]),
+    (0x2F9D3, None, &[0x27CA8]),
+    (0x2F9D4, None, &[0x8CAB]),
+    (0x2F9D5, None, &[0x8CC1]),
+    (0x2F9D6, None, &[0x8D1B]),
+    (0x2F9D7, None, &[0x8D77]),
+    (0x2F9D8, None, &[0x27F2F]),
+    (0x2F9D9, None, &[0x20804]),
+    (0x2F9DA, None, &[0x8DCB]),
+    (0x2F9DB, None, &[0x8DBC]),
+    (0x2F9DC, None, &[0x8DF0]),
+    (0x2F9DD, None, &[0x208DE]),
+    (0x2F9DE, None, &[0x8ED4]),
+    (0x2F9DF, None, &[0x8F38]),
+    (0x2F9E0, None, &[0x285D2]),
+    (0x2F9E1, None, &[0x285ED]),
+    (0x2F9E2, None, &[0x9094]),
+    (0x2F9E3, None, &[0x90F1]),
+    (0x2F9E4, None, &[0x9111]),
+    (0x2F9E5, None, &[0x2872E]),
+    (0x2F9E6, None, &[0x911B]),
+    (0x2F9E7, None, &[0x9238]),
+    (0x2F9E8, None, &[0x92D7]),
+    (0x2F9E9, None, &[0x92D8]),
+    (0x2F9EA, None, &[0x927C]),
+    (0x2F9EB, None, &[0x93F9]),
+    (0x2F9EC, None, &[0x9415]),
+    (0x2F9ED, None, &[0x28BFA]),
+    (0x2F9EE, None, &[0x958B]),
+    (0x2F9EF, None, &[0x4995]),
+    (0x2F9F0, None, &[0x95B7]),
+    (0x2F9F1, None, &[0x28D77]),
+    (0x2F9F2, None, &[0x49E6]),
+    (0x2F9F3, None, &[0x96C3]),
+    (0x2F9F4, None, &[0x5DB2]),
+    (0x2F9F5, None, &[0x9723]),
+    (0x2F9F6, None, &[0x29145]),
+    (0x2F9F7, None, &[0x2921A]),
+    (0x2F9F8, None, &[0x4A6E]),
+    (0x2F9F9, None, &[0x4A76]),
+    (0x2F9FA, None, &[0x97E0]),
+    (0x2F9FB, None, &[0x2940A]),
+    (0x2F9FC, None, &[0x4AB2]),
+    (0x2F9FD, None, &[0x29496]),
+    (0x2F9FE, None, &[0x980B]),
+    (0x2F9FF, None, &[0x980B]),
+    (0x2FA00, None, &[0x9829]),
+    (0x2FA01, None, &[0x295B6]),
+    (0x2FA02, None, &[0x98E2]),
+    (0x2FA03, None, &[0x4B33]),
+    (0x2FA04, None, &[0x9929]),
+    (0x2FA05, None, &[0x99A7]),
+    (0x2FA06, None, &[0x99C2]),
+    (0x2FA07, None, &[0x99FE]),
+    (0x2FA08, None, &[0x4BCE]),
+    (0x2FA09, None, &[0x29B30]),
+    (0x2FA0A, None, &[0x9B12]),
+    (0x2FA0B, None, &[0x9C40]),
+    (0x2FA0C, None, &[0x9CFD]),
+    (0x2FA0D, None, &[0x4CCE]),
+    (0x2FA0E, None, &[0x4CED]),
+    (0x2FA0F, None, &[0x9D67]),
+    (0x2FA10, None, &[0x2A0CE]),
+    (0x2FA11, None, &[0x4CF8]),
+    (0x2FA12, None, &[0x2A105]),
+    (0x2FA13, None, &[0x2A20E]),
+    (0x2FA14, None, &[0x2A291]),
+    (0x2FA15, None, &[0x9EBB]),
+    (0x2FA16, None, &[0x4D56]),
+    (0x2FA17, None, &[0x9EF9]),
+    (0x2FA18, None, &[0x9EFE]),
+    (0x2FA19, None, &[0x9F05]),
+    (0x2FA1A, None, &[0x9F0F]),
+    (0x2FA1B, None, &[0x9F16]),
+    (0x2FA1C, None, &[0x9F3B]),
+    (0x2FA1D, None, &[0x2A600]),
+];
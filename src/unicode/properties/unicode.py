@@ -0,0 +1,177 @@
+#!/usr/bin/env python3
+#
+# gerb
+#
+# Copyright 2022 - Manos Pitsidianakis
+#
+# This file is part of gerb.
+#
+# gerb is free software: you can redistribute it and/or modify
+# it under the terms of the GNU General Public License as published by
+# the Free Software Foundation, either version 3 of the License, or
+# (at your option) any later version.
+#
+# gerb is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+# GNU General Public License for more details.
+#
+# You should have received a copy of the GNU General Public License
+# along with gerb. If not, see <http://www.gnu.org/licenses/>.
+
+# This script uses the following Unicode tables:
+# - UnicodeData.txt
+# - Scripts.txt
+#
+# and writes tables.rs with general categories, scripts and decomposition
+# mappings. Since this should not require frequent updates, we just store
+# this out-of-line and check the tables.rs file into git.
+
+import os
+import sys
+
+UNICODE_VERSION = (14, 0, 0)
+
+UNICODE_VERSION_NUMBER = "%s.%s.%s" % UNICODE_VERSION
+
+preamble = """// NOTE: The following code was generated by "src/unicode/properties/unicode.py", do not edit
+// directly
+
+use super::GeneralCategory::{self, *};
+
+"""
+
+CATEGORIES = {
+    "Lu": "UppercaseLetter",
+    "Ll": "LowercaseLetter",
+    "Lt": "TitlecaseLetter",
+    "Lm": "ModifierLetter",
+    "Lo": "OtherLetter",
+    "Mn": "NonspacingMark",
+    "Mc": "SpacingMark",
+    "Me": "EnclosingMark",
+    "Nd": "DecimalNumber",
+    "Nl": "LetterNumber",
+    "No": "OtherNumber",
+    "Pc": "ConnectorPunctuation",
+    "Pd": "DashPunctuation",
+    "Ps": "OpenPunctuation",
+    "Pe": "ClosePunctuation",
+    "Pi": "InitialPunctuation",
+    "Pf": "FinalPunctuation",
+    "Po": "OtherPunctuation",
+    "Sm": "MathSymbol",
+    "Sc": "CurrencySymbol",
+    "Sk": "ModifierSymbol",
+    "So": "OtherSymbol",
+    "Zs": "SpaceSeparator",
+    "Zl": "LineSeparator",
+    "Zp": "ParagraphSeparator",
+    "Cc": "Control",
+    "Cf": "Format",
+    "Cs": "Surrogate",
+    "Co": "PrivateUse",
+}
+
+
+def fetch(f):
+    if not os.path.exists(os.path.basename(f)):
+        os.system(
+            "curl -O http://www.unicode.org/Public/%s/ucd/%s"
+            % (UNICODE_VERSION_NUMBER, f)
+        )
+
+    if not os.path.exists(os.path.basename(f)):
+        sys.stderr.write("cannot load %s\n" % f)
+        sys.exit(1)
+
+
+def merge_ranges(entries):
+    """Merges consecutive (codepoint, value) entries into (lo, hi, value) ranges."""
+    ranges = []
+    for cp, value in sorted(entries):
+        if ranges and ranges[-1][1] + 1 == cp and ranges[-1][2] == value:
+            ranges[-1][1] = cp
+        else:
+            ranges.append([cp, cp, value])
+    return ranges
+
+
+def load_unicode_data(f):
+    fetch(f)
+    categories = []
+    decompositions = []
+    range_start = None
+    for line in open(f):
+        fields = line.strip().split(";")
+        if len(fields) < 6:
+            continue
+        cp = int(fields[0], 16)
+        name, gc, decomp = fields[1], fields[2], fields[5]
+        if name.endswith(", First>"):
+            range_start = cp
+            continue
+        if name.endswith(", Last>"):
+            categories.extend((c, gc) for c in range(range_start, cp + 1))
+            range_start = None
+            continue
+        categories.append((cp, gc))
+        if decomp:
+            parts = decomp.split()
+            tag = None
+            if parts[0].startswith("<"):
+                tag = parts[0][1:-1]
+                parts = parts[1:]
+            decompositions.append((cp, tag, [int(p, 16) for p in parts]))
+    return merge_ranges(categories), decompositions
+
+
+def load_scripts(f):
+    fetch(f)
+    entries = []
+    for line in open(f):
+        line = line.split("#")[0].strip()
+        if not line:
+            continue
+        cps, script = [s.strip() for s in line.split(";")]
+        if ".." in cps:
+            lo, hi = [int(c, 16) for c in cps.split("..")]
+        else:
+            lo = hi = int(cps, 16)
+        entries.extend((c, script.replace("_", " ")) for c in range(lo, hi + 1))
+    return merge_ranges(entries)
+
+
+if __name__ == "__main__":
+    categories, decompositions = load_unicode_data("UnicodeData.txt")
+    scripts = load_scripts("Scripts.txt")
+    r = "tables.rs"
+    if os.path.exists(r):
+        os.remove(r)
+    with open(r, "w") as rf:
+        rf.write(preamble)
+        rf.write(
+            "pub const UNICODE_VERSION: (u64, u64, u64) = (%s, %s, %s);\n\n"
+            % UNICODE_VERSION
+        )
+        rf.write(
+            "pub const GENERAL_CATEGORIES: &[(u32, u32, GeneralCategory)] = &[\n"
+        )
+        for lo, hi, gc in categories:
+            rf.write("    (0x%X, 0x%X, %s),\n" % (lo, hi, CATEGORIES[gc]))
+        rf.write("];\n\n")
+        rf.write("pub const SCRIPTS: &[(u32, u32, &str)] = &[\n")
+        for lo, hi, script in scripts:
+            rf.write('    (0x%X, 0x%X, "%s"),\n' % (lo, hi, script))
+        rf.write("];\n\n")
+        rf.write("pub const DECOMPOSITIONS: &[(u32, Option<&str>, &[u32])] = &[\n")
+        for cp, tag, parts in decompositions:
+            rf.write(
+                "    (0x%X, %s, &[%s]),\n"
+                % (
+                    cp,
+                    'Some("%s")' % tag if tag else "None",
+                    ", ".join("0x%X" % p for p in parts),
+                )
+            )
+        rf.write("];\n")
@@ -27,6 +27,7 @@ pub mod curves;
 pub mod menu;
 pub mod points;
 pub mod property_window;
+pub mod quadratic;
 pub mod range_query;
 pub mod shortcuts;
 pub mod widgets;
@@ -74,6 +74,14 @@ impl Bezier {
         }
     }
 
+    /// A new curve with the same points, smoothness and implied end, but new point identities.
+    pub fn duplicate(&self) -> Self {
+        let ret = Self::new(self.points().iter().map(|cp| cp.position).collect());
+        ret.set_property(Self::SMOOTH, self.property::<bool>(Self::SMOOTH));
+        ret.set_property(Self::IMPLIED_END, self.property::<bool>(Self::IMPLIED_END));
+        ret
+    }
+
     pub fn reverse(&self) {
         self.set_modified();
         self.imp().points.borrow_mut().reverse();
@@ -94,6 +102,7 @@ pub struct BezierInner {
     pub emptiest_t: Cell<Option<(f64, Point, bool)>>,
     pub continuity_in: Cell<Option<Continuity>>,
     pub continuity_out: Cell<Option<Continuity>>,
+    pub implied_end: Cell<bool>,
 }
 
 impl std::fmt::Debug for BezierInner {
@@ -112,6 +121,7 @@ impl std::fmt::Debug for BezierInner {
             .field("lut entries", &self.lut.borrow().len())
             .field("incoming continuity", &self.continuity_in)
             .field("outcoming continuity", &self.continuity_out)
+            .field("implied end", &self.implied_end)
             .finish()
     }
 }
@@ -155,6 +165,13 @@ impl ObjectImpl for BezierInner {
                         true,
                         glib::ParamFlags::READWRITE | UI_EDITABLE,
                     ),
+                    glib::ParamSpecBoolean::new(
+                        Bezier::IMPLIED_END,
+                        Bezier::IMPLIED_END,
+                        Bezier::IMPLIED_END,
+                        false,
+                        glib::ParamFlags::READWRITE,
+                    ),
                 ]
             });
         PROPERTIES.as_ref()
@@ -165,6 +182,7 @@ impl ObjectImpl for BezierInner {
             Bezier::SMOOTH => self.smooth.get().to_value(),
             Bezier::CONTINUITY_IN => self.continuity_in.get().to_value(),
             Bezier::CONTINUITY_OUT => self.continuity_out.get().to_value(),
+            Bezier::IMPLIED_END => self.implied_end.get().to_value(),
             _ => unimplemented!("{}", pspec.name()),
         }
     }
@@ -197,6 +215,9 @@ impl ObjectImpl for BezierInner {
                 let new_val = value.get().unwrap();
                 self.continuity_out.set(new_val);
             }
+            Bezier::IMPLIED_END => {
+                self.implied_end.set(value.get().unwrap());
+            }
             _ => unimplemented!("{}", pspec.name()),
         }
     }
@@ -221,9 +242,15 @@ impl Default for Bezier {
 }
 
 impl Bezier {
+    /// Whether the first point of the curve is smooth, i.e. the curve continues the tangent of
+    /// the previous one. In `.glif` files this is the `smooth` attribute of that point.
     pub const SMOOTH: &str = "smooth";
     pub const CONTINUITY_IN: &str = "continuity-in";
     pub const CONTINUITY_OUT: &str = "continuity-out";
+    /// Whether the last point is a TrueType implied on-curve point. Such a point is not stored
+    /// in `.glif` files and always lies halfway between this quadratic's control point and the
+    /// next one's.
+    pub const IMPLIED_END: &str = "implied-end";
 
     pub fn new(points: Vec<Point>) -> Self {
         let ret: Self = glib::Object::new::<Self>(&[]).unwrap();
@@ -565,4 +592,264 @@ impl Bezier {
         }
         self.imp().lut.borrow_mut().clear();
     }
+
+    /// Splits the curve at `t` with de Casteljau's algorithm. The new on-curve point is smooth.
+    pub fn split(&self, t: f64) -> (Self, Self) {
+        let mut pts = self
+            .points()
+            .iter()
+            .map(|cp| cp.position)
+            .collect::<Vec<Point>>();
+        let mut left = vec![pts[0]];
+        let mut right = vec![pts[pts.len() - 1]];
+        while pts.len() > 1 {
+            pts = pts.windows(2).map(|w| w[0] + t * (w[1] - w[0])).collect();
+            left.push(pts[0]);
+            right.push(pts[pts.len() - 1]);
+        }
+        right.reverse();
+        let (left, right) = (Self::new(left), Self::new(right));
+        left.set_property(Self::SMOOTH, self.property::<bool>(Self::SMOOTH));
+        right.set_property(Self::SMOOTH, true);
+        right.set_property(Self::IMPLIED_END, self.property::<bool>(Self::IMPLIED_END));
+        (left, right)
+    }
+
+    /// The `t` of the point on the curve that is closest to `point`.
+    pub fn nearest_t(&self, point: Point) -> f64 {
+        const STEPS: usize = 100;
+        let (mut lo, mut hi) = {
+            let i = (0..=STEPS)
+                .map(|i| {
+                    let t = i as f64 / STEPS as f64;
+                    (i, self.compute(t).distance(point))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+            (
+                i.saturating_sub(1) as f64 / STEPS as f64,
+                (i + 1).min(STEPS) as f64 / STEPS as f64,
+            )
+        };
+        /* Golden section search in the neighbourhood of the closest sample. */
+        let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+        for _ in 0..30 {
+            let a = hi - ratio * (hi - lo);
+            let b = lo + ratio * (hi - lo);
+            if self.compute(a).distance(point) < self.compute(b).distance(point) {
+                hi = b;
+            } else {
+                lo = a;
+            }
+        }
+        (lo + hi) / 2.0
+    }
+
+    /// Direction of the curve at its start, ignoring handles that coincide with the start.
+    pub fn start_direction(&self) -> Point {
+        let points = self.points();
+        let start = points[0].position;
+        points
+            .iter()
+            .skip(1)
+            .map(|cp| cp.position - start)
+            .find(|d| d.norm() > f64::EPSILON)
+            .map(|d| d.unit())
+            .unwrap_or_default()
+    }
+
+    /// Direction of the curve at its end, ignoring handles that coincide with the end.
+    pub fn end_direction(&self) -> Point {
+        let points = self.points();
+        let end = points[points.len() - 1].position;
+        points
+            .iter()
+            .rev()
+            .skip(1)
+            .map(|cp| end - cp.position)
+            .find(|d| d.norm() > f64::EPSILON)
+            .map(|d| d.unit())
+            .unwrap_or_default()
+    }
+
+    /// Values of `t` strictly inside the curve where its x or y coordinate is at a local
+    /// extremum, in ascending order.
+    pub fn extrema(&self) -> Vec<f64> {
+        const EPSILON: f64 = 1e-4;
+        let points = self
+            .points()
+            .iter()
+            .map(|cp| cp.position)
+            .collect::<Vec<Point>>();
+        let mut ret = vec![];
+        for axis in [|p: Point| p.x, |p: Point| p.y] {
+            let c = points.iter().map(|&p| axis(p)).collect::<Vec<f64>>();
+            match c.len() {
+                3 => {
+                    /* B'(t) = 2(1-t)(p1-p0) + 2t(p2-p1) */
+                    let denom = c[0] - 2.0 * c[1] + c[2];
+                    if denom.abs() > f64::EPSILON {
+                        ret.push((c[0] - c[1]) / denom);
+                    }
+                }
+                4 => {
+                    /* B'(t) = a t^2 + b t + c, up to a factor of 3 */
+                    let a = -c[0] + 3.0 * c[1] - 3.0 * c[2] + c[3];
+                    let b = 2.0 * (c[0] - 2.0 * c[1] + c[2]);
+                    let k = c[1] - c[0];
+                    if a.abs() <= f64::EPSILON {
+                        if b.abs() > f64::EPSILON {
+                            ret.push(-k / b);
+                        }
+                    } else {
+                        let disc = b * b - 4.0 * a * k;
+                        if disc > 0.0 {
+                            let sq = disc.sqrt();
+                            ret.push((-b + sq) / (2.0 * a));
+                            ret.push((-b - sq) / (2.0 * a));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        ret.retain(|t| *t > EPSILON && *t < 1.0 - EPSILON);
+        ret.sort_by(f64::total_cmp);
+        ret.dedup_by(|a, b| (*a - *b).abs() < EPSILON);
+        ret
+    }
+
+    /// Points where the curve crosses the line segment from `a` to `b`, as pairs of the
+    /// curve's `t` and the position, in ascending `t`. Tangential touches may be missed.
+    pub fn line_intersections(&self, a: Point, b: Point) -> Vec<(f64, Point)> {
+        const STEPS: usize = 64;
+        let direction = b - a;
+        let length = direction.norm();
+        if length <= f64::EPSILON || self.degree().is_none() {
+            return vec![];
+        }
+        /* Signed distance of the curve point at `t` from the line. */
+        let distance = |t: f64| {
+            let p = self.compute(t) - a;
+            (direction.x * p.y - direction.y * p.x) / length
+        };
+        let mut ret = vec![];
+        let mut prev = (0.0, distance(0.0));
+        if prev.1 == 0.0 {
+            ret.push(0.0);
+        }
+        for i in 1..=STEPS {
+            let t = i as f64 / STEPS as f64;
+            let d = distance(t);
+            if d == 0.0 {
+                ret.push(t);
+            } else if prev.1 != 0.0 && d.signum() != prev.1.signum() {
+                let (mut lo, mut hi) = (prev.0, t);
+                for _ in 0..50 {
+                    let mid = (lo + hi) / 2.0;
+                    if distance(mid).signum() == prev.1.signum() {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                ret.push((lo + hi) / 2.0);
+            }
+            prev = (t, d);
+        }
+        ret.into_iter()
+            .map(|t| (t, self.compute(t)))
+            .filter(|(_, p)| {
+                let s = direction.dot(*p - a) / (length * length);
+                (-1e-9..=1.0 + 1e-9).contains(&s)
+            })
+            .collect()
+    }
+}
+
+/// Least squares fit of a cubic curve to `points`, which are in order along the curve. The fit
+/// starts and ends at the first and last point, leaving in direction `start_direction` and
+/// arriving in direction `end_direction`.
+///
+/// This is the single curve step of Philip J. Schneider's algorithm from "An Algorithm for
+/// Automatically Fitting Digitized Curves", Graphics Gems, 1990, including its
+/// reparameterization passes.
+pub fn fit_cubic(points: &[Point], start_direction: Point, end_direction: Point) -> [Point; 4] {
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let chord = first.distance(last);
+    let fallback = [
+        first,
+        first + (chord / 3.0) * start_direction,
+        last - (chord / 3.0) * end_direction,
+        last,
+    ];
+    if points.len() < 3 {
+        return fallback;
+    }
+
+    /* Chord length parameterization */
+    let mut u = vec![0.0];
+    for w in points.windows(2) {
+        u.push(u[u.len() - 1] + w[0].distance(w[1]));
+    }
+    let total = u[u.len() - 1];
+    if total <= f64::EPSILON {
+        return fallback;
+    }
+    for v in u.iter_mut() {
+        *v /= total;
+    }
+
+    let (t1, t2) = (start_direction, -1.0 * end_direction);
+    let epsilon = 1e-6 * chord;
+    let mut ret = fallback;
+    /* Each pass refits with the parameters improved by a Newton-Raphson step. */
+    for _ in 0..4 {
+        let mut c = [[0.0; 2]; 2];
+        let mut x = [0.0; 2];
+        for (p, &u) in points.iter().zip(u.iter()) {
+            let mu = 1.0 - u;
+            let (b0, b1, b2, b3) = (mu * mu * mu, 3.0 * u * mu * mu, 3.0 * u * u * mu, u * u * u);
+            let (a1, a2) = (b1 * t1, b2 * t2);
+            c[0][0] += a1.dot(a1);
+            c[0][1] += a1.dot(a2);
+            c[1][1] += a2.dot(a2);
+            let tmp = *p - ((b0 + b1) * first + (b2 + b3) * last);
+            x[0] += a1.dot(tmp);
+            x[1] += a2.dot(tmp);
+        }
+        c[1][0] = c[0][1];
+        let det = c[0][0] * c[1][1] - c[1][0] * c[0][1];
+        if det.abs() <= f64::EPSILON {
+            break;
+        }
+        let alpha1 = (x[0] * c[1][1] - x[1] * c[0][1]) / det;
+        let alpha2 = (c[0][0] * x[1] - c[1][0] * x[0]) / det;
+        if alpha1 < epsilon || alpha2 < epsilon {
+            break;
+        }
+        ret = [first, first + alpha1 * t1, last + alpha2 * t2, last];
+        let d1 = [
+            3.0 * (ret[1] - ret[0]),
+            3.0 * (ret[2] - ret[1]),
+            3.0 * (ret[3] - ret[2]),
+        ];
+        let d2 = [2.0 * (d1[1] - d1[0]), 2.0 * (d1[2] - d1[1])];
+        for (p, u) in points.iter().zip(u.iter_mut()) {
+            let mu = 1.0 - *u;
+            let q = (mu * mu * mu) * ret[0]
+                + (3.0 * mu * mu * *u) * ret[1]
+                + (3.0 * mu * *u * *u) * ret[2]
+                + (*u * *u * *u) * ret[3];
+            let q1 = (mu * mu) * d1[0] + (2.0 * mu * *u) * d1[1] + (*u * *u) * d1[2];
+            let q2 = mu * d2[0] + *u * d2[1];
+            let numerator = (q - *p).dot(q1);
+            let denominator = q1.dot(q1) + (q - *p).dot(q2);
+            if denominator.abs() > f64::EPSILON {
+                *u = (*u - numerator / denominator).clamp(0.0, 1.0);
+            }
+        }
+    }
+    ret
 }
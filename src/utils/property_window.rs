@@ -358,6 +358,24 @@ impl PropertyWindow {
                 check_dirty_on_change!(MarkColor);
                 <MarkColor>::get(app, val, obj, property, create, readwrite, flags)
             }
+            "LineCap" => {
+                use crate::glyphs::offset::LineCap;
+
+                check_dirty_on_change!(LineCap);
+                <LineCap>::get(app, val, obj, property, create, readwrite, flags)
+            }
+            "LineJoin" => {
+                use crate::glyphs::offset::LineJoin;
+
+                check_dirty_on_change!(LineJoin);
+                <LineJoin>::get(app, val, obj, property, create, readwrite, flags)
+            }
+            "CornerKind" => {
+                use crate::glyphs::corners::CornerKind;
+
+                check_dirty_on_change!(CornerKind);
+                <CornerKind>::get(app, val, obj, property, create, readwrite, flags)
+            }
             _other => gtk::Label::builder()
                 .label(&format!("{:?}", val))
                 .visible(true)
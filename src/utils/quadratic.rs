@@ -0,0 +1,194 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Conversion between cubic and quadratic Bézier curves.
+//!
+//! The cubic to quadratic direction follows the approach of the `cu2qu` library: a cubic is
+//! approximated by a quadratic spline with as few control points as possible such that the
+//! distance from the original curve stays within a tolerance. Consecutive quadratics of the
+//! spline meet at TrueType "implied" on-curve points, halfway between their control points.
+
+use super::Point;
+
+/// Largest number of quadratic segments a single cubic is split into.
+pub const MAX_SEGMENTS: usize = 16;
+
+/// Degree elevation, which is exact.
+pub fn quadratic_to_cubic([p0, p1, p2]: [Point; 3]) -> [Point; 4] {
+    [
+        p0,
+        p0 + (2.0 / 3.0) * (p1 - p0),
+        p2 + (2.0 / 3.0) * (p1 - p2),
+        p2,
+    ]
+}
+
+/// Approximates a cubic with a quadratic spline `[on, off_1, ..., off_n, on]` whose distance
+/// from the cubic is at most `tolerance`. Consecutive off-curve points have an implied on-curve
+/// point halfway between them. Returns `None` if no spline with up to [`MAX_SEGMENTS`] segments
+/// is close enough.
+pub fn cubic_to_quadratic(cubic: [Point; 4], tolerance: f64) -> Option<Vec<Point>> {
+    (1..=MAX_SEGMENTS).find_map(|n| cubic_approx_spline(cubic, n, tolerance))
+}
+
+/// Like [`cubic_to_quadratic`], but falls back to the closest spline with [`MAX_SEGMENTS`]
+/// segments instead of failing.
+pub fn cubic_to_quadratic_lossy(cubic: [Point; 4], tolerance: f64) -> Vec<Point> {
+    cubic_to_quadratic(cubic, tolerance)
+        .or_else(|| cubic_approx_spline(cubic, MAX_SEGMENTS, f64::INFINITY))
+        .unwrap_or_else(|| {
+            let [p0, _, _, p3] = cubic;
+            vec![p0, 0.5 * (p0 + p3), p3]
+        })
+}
+
+fn cubic_approx_spline(cubic: [Point; 4], n: usize, tolerance: f64) -> Option<Vec<Point>> {
+    if n == 1 {
+        return cubic_approx_quadratic(cubic, tolerance);
+    }
+    let pieces = split_cubic(cubic, n);
+    let mut next_q1 = cubic_approx_control(0.0, pieces[0]);
+    let mut q2 = cubic[0];
+    let mut d1 = Point::default();
+    let mut spline = vec![cubic[0], next_q1];
+    for i in 1..=n {
+        let [_, c1, c2, c3] = pieces[i - 1];
+        let q0 = q2;
+        let q1 = next_q1;
+        if i < n {
+            next_q1 = cubic_approx_control(i as f64 / (n - 1) as f64, pieces[i]);
+            spline.push(next_q1);
+            q2 = 0.5 * (q1 + next_q1);
+        } else {
+            q2 = c3;
+        }
+        let d0 = d1;
+        d1 = q2 - c3;
+        if d1.norm() > tolerance
+            || !cubic_farthest_fit_inside(
+                d0,
+                q0 + (2.0 / 3.0) * (q1 - q0) - c1,
+                q2 + (2.0 / 3.0) * (q1 - q2) - c2,
+                d1,
+                tolerance,
+            )
+        {
+            return None;
+        }
+    }
+    spline.push(cubic[3]);
+    Some(spline)
+}
+
+fn cubic_approx_quadratic(cubic: [Point; 4], tolerance: f64) -> Option<Vec<Point>> {
+    let [c0, c1, c2, c3] = cubic;
+    let q1 = intersect(c0, c1, c2, c3)?;
+    let d1 = c0 + (2.0 / 3.0) * (q1 - c0) - c1;
+    let d2 = c3 + (2.0 / 3.0) * (q1 - c3) - c2;
+    if !cubic_farthest_fit_inside(Point::default(), d1, d2, Point::default(), tolerance) {
+        return None;
+    }
+    Some(vec![c0, q1, c3])
+}
+
+/// Control point of the quadratic that approximates the part of the cubic around `t`.
+fn cubic_approx_control(t: f64, [p0, p1, p2, p3]: [Point; 4]) -> Point {
+    let p1 = p0 + 1.5 * (p1 - p0);
+    let p2 = p3 + 1.5 * (p2 - p3);
+    p1 + t * (p2 - p1)
+}
+
+/// Intersection of line `ab` with line `cd`.
+fn intersect(a: Point, b: Point, c: Point, d: Point) -> Option<Point> {
+    let ab = b - a;
+    let cd = d - c;
+    let p = Point { x: -ab.y, y: ab.x };
+    let denominator = p.dot(cd);
+    if denominator.abs() < f64::EPSILON {
+        return None;
+    }
+    let h = p.dot(a - c) / denominator;
+    Some(c + h * cd)
+}
+
+/// Whether the cubic with the given (difference) control points stays within `tolerance` of the
+/// origin. Recursively splits the curve in half.
+fn cubic_farthest_fit_inside(p0: Point, p1: Point, p2: Point, p3: Point, tolerance: f64) -> bool {
+    if p1.norm() <= tolerance && p2.norm() <= tolerance {
+        return true;
+    }
+    let mid = 0.125 * (p0 + 3.0 * (p1 + p2) + p3);
+    if mid.norm() > tolerance {
+        return false;
+    }
+    let deriv3 = 0.125 * (p3 + p2 - p1 - p0);
+    cubic_farthest_fit_inside(p0, 0.5 * (p0 + p1), mid - deriv3, mid, tolerance)
+        && cubic_farthest_fit_inside(mid, mid + deriv3, 0.5 * (p2 + p3), p3, tolerance)
+}
+
+/// Splits a cubic into `n` pieces of equal parameter length.
+fn split_cubic([p0, p1, p2, p3]: [Point; 4], n: usize) -> Vec<[Point; 4]> {
+    /* Polynomial coefficients: B(t) = a t³ + b t² + c t + d */
+    let c = 3.0 * (p1 - p0);
+    let b = 3.0 * (p2 - p1) - c;
+    let a = p3 - p0 - c - b;
+    let d = p0;
+    let dt = 1.0 / n as f64;
+    (0..n)
+        .map(|i| {
+            let t1 = i as f64 * dt;
+            /* Coefficients of the piece, reparametrized to [0, 1]. */
+            let a1 = (dt * dt * dt) * a;
+            let b1 = (dt * dt) * (3.0 * t1 * a + b);
+            let c1 = dt * (3.0 * t1 * t1 * a + 2.0 * t1 * b + c);
+            let d1 = (t1 * t1 * t1) * a + (t1 * t1) * b + t1 * c + d;
+            let q0 = d1;
+            let q1 = d1 + (1.0 / 3.0) * c1;
+            let q2 = q1 + (1.0 / 3.0) * (c1 + b1);
+            let q3 = d1 + c1 + b1 + a1;
+            [q0, q1, q2, q3]
+        })
+        .collect()
+}
+
+#[test]
+fn test_cubic_quadratic_conversion() {
+    let quadratic: [Point; 3] = [(0.0, 0.0).into(), (50.0, 100.0).into(), (100.0, 0.0).into()];
+    let cubic = quadratic_to_cubic(quadratic);
+    /* A degree-elevated quadratic converts back to itself. */
+    let spline = cubic_to_quadratic(cubic, 0.01).unwrap();
+    assert_eq!(spline.len(), 3);
+    assert!(spline[1].distance(quadratic[1]) < 1e-9);
+
+    /* A quarter circle needs more than one segment for a tight tolerance. */
+    let k = 0.5522847498 * 100.0;
+    let arc: [Point; 4] = [
+        (100.0, 0.0).into(),
+        (100.0, k).into(),
+        (k, 100.0).into(),
+        (0.0, 100.0).into(),
+    ];
+    let loose = cubic_to_quadratic(arc, 10.0).unwrap();
+    let tight = cubic_to_quadratic(arc, 0.1).unwrap();
+    assert!(tight.len() > loose.len());
+    assert_eq!(tight[0], arc[0]);
+    assert_eq!(tight[tight.len() - 1], arc[3]);
+}
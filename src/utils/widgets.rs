@@ -96,3 +96,53 @@ pub fn new_simple_info_dialog(
     dialog.set_use_markup(true);
     dialog
 }
+
+/// Asks the user for a number in `range`, starting from `value`. Returns `None` if the dialog
+/// was cancelled.
+///
+/// ## Usage:
+/// ```no_run
+/// # use gtk::prelude::*;
+/// # use gerb::utils::widgets::ask_number;
+/// fn doctest(window: &gtk::Window) {
+///     if let Some(tolerance) = ask_number(
+///         None,
+///         "Tolerance:",
+///         1.0,
+///         (0.0, 100.0),
+///         &window,
+///     ) {
+///         println!("{tolerance}");
+///     }
+/// }
+/// ```
+pub fn ask_number(
+    title: Option<&str>,
+    text: &str,
+    value: f64,
+    (min, max): (f64, f64),
+    window: &gtk::Window,
+) -> Option<f64> {
+    let dialog = gtk::MessageDialog::new(
+        Some(window),
+        gtk::DialogFlags::DESTROY_WITH_PARENT | gtk::DialogFlags::MODAL,
+        gtk::MessageType::Question,
+        gtk::ButtonsType::OkCancel,
+        text,
+    );
+    dialog.set_title(title.unwrap_or("Input"));
+    dialog.set_use_markup(true);
+    let spin = gtk::SpinButton::with_range(min, max, 0.1);
+    spin.set_digits(2);
+    spin.set_value(value);
+    spin.set_activates_default(true);
+    spin.set_visible(true);
+    dialog.set_default_response(gtk::ResponseType::Ok);
+    if let Ok(area) = dialog.message_area().downcast::<gtk::Box>() {
+        area.pack_start(&spin, false, false, 0);
+    }
+    let response = dialog.run();
+    let value = spin.value();
+    dialog.emit_close();
+    (response == gtk::ResponseType::Ok).then_some(value)
+}
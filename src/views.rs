@@ -21,8 +21,16 @@
 
 pub mod canvas;
 pub mod collection;
+pub mod coverage;
+pub mod font_info;
+pub mod glyph_diff;
+pub mod outline_search;
 pub mod overlay;
+pub mod transform_panel;
+pub mod unicode_inspector;
+pub mod vertical_metrics;
 
 pub use canvas::{Canvas, Transformation, UnitPoint, ViewPoint};
 pub use collection::*;
+pub use glyph_diff::GlyphDiffView;
 pub use overlay::Overlay;
@@ -26,10 +26,16 @@ use gtk::cairo::{Context, FontSlant, FontWeight};
 use once_cell::unsync::OnceCell;
 use std::collections::HashMap;
 
+use crate::glyphs::query::Query;
 use crate::glyphs::{Glyph, GlyphDrawingOptions, GlyphKind};
 use crate::prelude::*;
 use crate::unicode::blocks::*;
 
+mod layout;
+mod list;
+
+pub use layout::*;
+
 const GLYPH_BOX_WIDTH: f64 = 110.0;
 const GLYPH_BOX_HEIGHT: f64 = 140.0;
 const GLYPH_BOX_WIDTH_I32: i32 = 110;
@@ -46,8 +52,20 @@ pub struct CollectionInner {
     hide_empty: Cell<bool>,
     zoom_factor: Cell<f64>,
     filter_input: RefCell<Option<String>>,
+    filter_query: RefCell<Query>,
+    search_entry: gtk::Entry,
     widgets: RefCell<Vec<GlyphBox>>,
     title: RefCell<String>,
+    layout: Rc<RefCell<CollectionLayout>>,
+    stack: gtk::Stack,
+    list_tree: gtk::TreeView,
+    list_store: OnceCell<gtk::ListStore>,
+    list_layout_applying: Cell<bool>,
+    layout_popover: OnceCell<gtk::Popover>,
+    #[cfg(feature = "git")]
+    changed_since: RefCell<Option<String>>,
+    #[cfg(feature = "git")]
+    git_refresh_pending: Cell<bool>,
 }
 
 #[glib::object_subclass]
@@ -69,6 +87,9 @@ impl ObjectImpl for CollectionInner {
         self.flow_box.set_column_spacing(0);
         self.flow_box.set_row_spacing(0);
         self.flow_box.set_valign(gtk::Align::Start);
+        self.flow_box
+            .set_selection_mode(gtk::SelectionMode::Multiple);
+        self.flow_box.set_activate_on_single_click(false);
 
         let overlay = gtk::Overlay::builder()
             .expand(true)
@@ -102,6 +123,8 @@ impl ObjectImpl for CollectionInner {
             obj.set_property(Collection::ZOOM_FACTOR, value);
             obj.update_flow_box();
         }));
+        obj.bind_property(Collection::ZOOM_FACTOR, &zoom_scale.adjustment(), "value")
+            .build();
 
         let zoom_pop_box = gtk::Box::builder()
             .orientation(gtk::Orientation::Vertical)
@@ -141,10 +164,29 @@ impl ObjectImpl for CollectionInner {
         close_zoom_pop_box.connect_clicked(clone!(@weak zoom_pop => move |_| {
             zoom_pop.hide();
         }));
+        zoom_pop.connect_closed(clone!(@weak obj => move |_| {
+            obj.layout.borrow_mut().zoom_factor = obj.zoom_factor.get();
+            obj.save_layout();
+        }));
 
         tool_palette.add(&show_zoom_pop);
         tool_palette.set_item_homogeneous(&show_zoom_pop, false);
 
+        let show_layout_pop = gtk::ToolButton::builder()
+            .label("Layout...")
+            .tooltip_text("Grid or list mode, cell size and cell overlays")
+            .valign(gtk::Align::Center)
+            .halign(gtk::Align::Start)
+            .visible(true)
+            .build();
+        show_layout_pop.connect_clicked(clone!(@weak obj => move |button| {
+            obj.layout_popover
+                .get_or_init(|| obj.new_layout_popover(button))
+                .popup();
+        }));
+        tool_palette.add(&show_layout_pop);
+        tool_palette.set_item_homogeneous(&show_layout_pop, false);
+
         let hide_empty_button = gtk::ToggleToolButton::builder()
             .label("Hide empty glyphs")
             .valign(gtk::Align::Center)
@@ -314,16 +356,53 @@ impl ObjectImpl for CollectionInner {
                         }
                     }
                 })
+            ).add_button_cb(
+                "Check character set coverage",
+                clone!(@weak obj => move |_| {
+                    crate::views::coverage::show_coverage_window(&obj);
+                })
+            ).add_button_cb(
+                "Build composite glyphs",
+                clone!(@weak obj => move |_| {
+                    obj.build_composites_dialog();
+                })
+            ).add_button_cb(
+                "Rename glyphs to production names",
+                clone!(@weak obj => move |_| {
+                    obj.rename_glyphs_dialog();
+                })
             );
             context_menu.popup(0);
         }));
         new_glyph_box.add(&add_glyph_more);
 
-        let search_entry = gtk::Entry::builder()
-            .expand(true)
-            .visible(true)
-            .placeholder_text("Filter glyph name")
-            .build();
+        #[cfg(feature = "git")]
+        {
+            let changed_since_button = gtk::ToolButton::builder()
+                .label("Changed since...")
+                .tooltip_text("Show only glyphs changed since a commit or branch")
+                .valign(gtk::Align::Center)
+                .halign(gtk::Align::Start)
+                .visible(true)
+                .build();
+            changed_since_button.connect_clicked(clone!(@weak obj => move |_| {
+                obj.changed_since_dialog();
+            }));
+            tool_palette.add(&changed_since_button);
+            tool_palette.set_item_homogeneous(&changed_since_button, false);
+        }
+
+        let search_entry = &self.search_entry;
+        search_entry.set_expand(true);
+        search_entry.set_visible(true);
+        search_entry.set_placeholder_text(Some("Search glyphs, e.g. name:^a -is:empty"));
+        search_entry.set_tooltip_text(Some(
+            "Bare words match glyph names and characters.\n\
+             name:REGEX  u:U+0300:U+036F  uname:\"small letter\"  block:cyrillic\n\
+             mark:#ff0000|any|none  is:empty|modified|component  has:components|contours\n\
+             contours:>2  width:500..600\n\
+             Prefix a term with - to negate it.",
+        ));
 
         search_entry.connect_changed(clone!(@weak obj => move |_self| {
             let filter_input = if _self.buffer().length() == 0 {
@@ -333,8 +412,22 @@ impl ObjectImpl for CollectionInner {
             };
             let mut cur_input = obj.imp().filter_input.borrow_mut();
             if cur_input.as_ref() != filter_input.as_ref() {
+                match Query::parse(filter_input.as_deref().unwrap_or_default()) {
+                    Ok(query) => {
+                        _self.set_secondary_icon_name(None);
+                        _self.set_secondary_icon_tooltip_text(None);
+                        *obj.imp().filter_query.borrow_mut() = query;
+                    }
+                    Err(err) => {
+                        /* Keep the last valid query until the input is fixed. */
+                        _self.set_secondary_icon_name(Some("dialog-error-symbolic"));
+                        _self.set_secondary_icon_tooltip_text(Some(&err.to_string()));
+                        return;
+                    }
+                }
                 *cur_input = filter_input;
                 drop(cur_input);
+                obj.sync_saved_searches();
                 obj.update_flow_box();
                 obj.imp().flow_box.queue_draw();
             }
@@ -343,9 +436,21 @@ impl ObjectImpl for CollectionInner {
         tool_palette.add(
             &gtk::ToolItem::builder()
                 .visible(true)
-                .child(&search_entry)
+                .child(search_entry)
                 .build(),
         );
+        let save_search_button = gtk::ToolButton::builder()
+            .label("Save search...")
+            .tooltip_text("Save the current search in the filter sidebar")
+            .valign(gtk::Align::Center)
+            .halign(gtk::Align::Start)
+            .visible(true)
+            .build();
+        save_search_button.connect_clicked(clone!(@weak obj => move |_| {
+            obj.save_search_dialog();
+        }));
+        tool_palette.add(&save_search_button);
+        tool_palette.set_item_homogeneous(&save_search_button, false);
 
         self.tree.set_visible(true);
         self.tree.set_grid_lines(gtk::TreeViewGridLines::Both);
@@ -363,6 +468,13 @@ impl ObjectImpl for CollectionInner {
             cell.set_activatable(true);
             cell.connect_toggled(clone!(@weak store, @weak obj => move |_self, treepath| {
                 if let Some(iter) = store.iter(&treepath) {
+                    if let Some(name) = obj.saved_search_row(&store, &iter) {
+                        obj.toggle_saved_search(&name);
+                        return;
+                    }
+                    if store.iter_has_child(&iter) && store.value(&iter, 1).get::<String>().ok().as_deref() == Some(Collection::SAVED_SEARCHES_ROW) {
+                        return;
+                    }
                     let prev_value: bool = store.value(&iter, 0).get().unwrap();
                     let cat_value = store.value(&iter, 1);
                     let block_category: &str = cat_value.get().unwrap();
@@ -399,6 +511,37 @@ impl ObjectImpl for CollectionInner {
         self.tree.set_model(Some(&store));
         self.tree.set_headers_visible(false);
         append_text_column(&self.tree, &store);
+        self.tree.connect_button_press_event(
+            clone!(@weak obj, @weak store => @default-return Inhibit(false), move |tree, event| {
+                if event.button() != gtk::gdk::BUTTON_SECONDARY {
+                    return Inhibit(false);
+                }
+                let (x, y) = event.position();
+                let Some((Some(treepath), _, _, _)) = tree.path_at_pos(x as i32, y as i32) else {
+                    return Inhibit(false);
+                };
+                let Some(name) = store.iter(&treepath).and_then(|iter| obj.saved_search_row(&store, &iter)) else {
+                    return Inhibit(false);
+                };
+                let menu = crate::utils::menu::Menu::new()
+                    .title(Some(name.clone().into()))
+                    .add_button_cb(
+                        "Delete saved search",
+                        clone!(@weak obj => move |_| {
+                            let settings = &obj.app().runtime.settings;
+                            let mut searches = settings.saved_searches();
+                            searches.shift_remove(&name);
+                            if let Err(err) = settings.set_saved_searches(&searches) {
+                                obj.show_saved_search_error(&err.to_string());
+                            }
+                            obj.update_tree_store();
+                            obj.update_flow_box();
+                        }),
+                    );
+                menu.popup(event.time());
+                Inhibit(true)
+            }),
+        );
 
         let filter_pop_box = gtk::Box::builder()
             .orientation(gtk::Orientation::Vertical)
@@ -455,7 +598,11 @@ impl ObjectImpl for CollectionInner {
             .style_context()
             .add_class("glyphs_area_toolbar");
 
-        overlay.set_child(Some(&scrolled_window));
+        self.stack.set_visible(true);
+        self.stack.set_expand(true);
+        self.stack.add_named(&scrolled_window, "grid");
+        self.stack.add_named(&obj.setup_list(), "list");
+        overlay.set_child(Some(&self.stack));
         overlay.add_overlay(
             &gtk::Expander::builder()
                 .child(&tool_palette)
@@ -478,10 +625,7 @@ impl ObjectImpl for CollectionInner {
                 let metadata = v[1].get::<GlyphMetadata>().unwrap();
                 let glyph = metadata.glyph_ref.get().unwrap().clone();
                 {
-                    let glyph_box = GlyphBox::new(obj.app().clone(), obj.project().clone(), glyph);
-                    obj.bind_property(Collection::ZOOM_FACTOR, &glyph_box, GlyphBox::ZOOM_FACTOR)
-                        .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::DEFAULT)
-                        .build();
+                    let glyph_box = obj.new_glyph_box(glyph);
                     obj.imp().flow_box.add(&glyph_box);
                     obj.imp().widgets.borrow_mut().push(glyph_box);
                 }
@@ -582,6 +726,15 @@ impl std::ops::Deref for Collection {
     }
 }
 
+fn selected_glyphs(flow_box: &gtk::FlowBox) -> Vec<Rc<RefCell<Glyph>>> {
+    flow_box
+        .selected_children()
+        .into_iter()
+        .filter_map(|c| c.child()?.downcast::<GlyphBox>().ok())
+        .map(|b| b.imp().glyph.get().unwrap().clone())
+        .collect()
+}
+
 glib::wrapper! {
     pub struct Collection(ObjectSubclass<CollectionInner>)
         @extends gtk::Widget, gtk::Container, gtk::EventBox;
@@ -592,6 +745,12 @@ impl Collection {
     pub const CLOSEABLE: &str = Workspace::CLOSEABLE;
     pub const ZOOM_FACTOR: &str = "zoom-factor";
     pub const NEW_GLYPH: &str = "new-glyph";
+    const SAVED_SEARCHES_ROW: &str = "Saved searches";
+
+    /// Glyphs whose boxes are selected.
+    pub fn selected_glyphs(&self) -> Vec<Rc<RefCell<Glyph>>> {
+        selected_glyphs(&self.flow_box)
+    }
 
     pub fn new(app: Application, project: Project) -> Self {
         let ret: Self = glib::Object::new(&[]).expect("Failed to create Main Window");
@@ -600,6 +759,15 @@ impl Collection {
             .bind_property(FontInfo::STYLE_NAME, &ret, Self::TITLE)
             .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::DEFAULT)
             .build();
+        if let Some(layout) = app
+            .runtime
+            .settings
+            .collection_layout(&project.path.borrow())
+        {
+            *ret.layout.borrow_mut() = layout;
+        }
+        ret.imp().app.set(app).unwrap();
+        ret.imp().project.set(project.clone()).unwrap();
         let flow_box = &ret.imp().flow_box;
         let mut widgets = vec![];
         {
@@ -607,22 +775,239 @@ impl Collection {
             let mut glyphs_refs = glyphs_map.values().collect::<Vec<&Rc<RefCell<Glyph>>>>();
             glyphs_refs.sort();
             for glyph_ref in glyphs_refs {
-                let glyph_box = GlyphBox::new(app.clone(), project.clone(), glyph_ref.clone());
-                ret.bind_property(Self::ZOOM_FACTOR, &glyph_box, GlyphBox::ZOOM_FACTOR)
-                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::DEFAULT)
-                    .build();
+                let glyph_box = ret.new_glyph_box(glyph_ref.clone());
                 flow_box.add(&glyph_box);
                 widgets.push(glyph_box);
             }
         }
-        ret.imp().app.set(app).unwrap();
-        ret.imp().project.set(project).unwrap();
         *ret.imp().widgets.borrow_mut() = widgets;
-        ret.update_flow_box();
+        ret.apply_layout();
         ret.update_tree_store();
+        ret.setup_reload();
+        #[cfg(feature = "git")]
+        ret.setup_git_status();
         ret
     }
 
+    /// Keeps glyph boxes in sync with glyphs that were changed, or reloaded, added or removed
+    /// because of changes on disk.
+    fn setup_reload(&self) {
+        for signal in [Project::GLYPH_RELOADED, Project::GLYPH_CHANGED] {
+            self.project().connect_local(
+                signal,
+                false,
+                clone!(@weak self as obj => @default-return None, move |v: &[gtk::glib::Value]| {
+                    let layer = v[1].get::<crate::ufo::objects::Layer>().unwrap();
+                    if layer != obj.project().default_layer {
+                        return None;
+                    }
+                    let name = v[2].get::<String>().unwrap();
+                    let glyph = layer.glyphs().get(&name).cloned();
+                    let glyph_box = obj
+                        .widgets
+                        .borrow()
+                        .iter()
+                        .find(|w| *w.imp().glyph.get().unwrap().borrow().name() == name)
+                        .cloned();
+                    match (glyph, glyph_box) {
+                        (Some(_), Some(glyph_box)) => {
+                            glyph_box.glyph_changed();
+                            obj.update_list_row(&name);
+                        }
+                        (Some(glyph), None) => {
+                            let metadata = glyph.borrow().metadata.clone();
+                            obj.emit_by_name::<()>(Collection::NEW_GLYPH, &[&metadata]);
+                        }
+                        (None, Some(glyph_box)) => {
+                            obj.widgets.borrow_mut().retain(|w| w != &glyph_box);
+                            if let Some(child) = glyph_box.parent() {
+                                obj.flow_box.remove(&child);
+                            }
+                            obj.update_flow_box();
+                            obj.update_tree_store();
+                        }
+                        (None, None) => {}
+                    }
+                    None
+                }),
+            );
+        }
+    }
+
+    #[cfg(feature = "git")]
+    fn setup_git_status(&self) {
+        let project = self.project();
+        if !matches!(&*project.repository.borrow(), Ok(Some(_))) {
+            return;
+        }
+        project.connect_notify_local(
+            Some(Project::MODIFIED),
+            clone!(@weak self as obj => move |project, _| {
+                if !project.property::<bool>(Project::MODIFIED) {
+                    obj.schedule_git_status_update();
+                }
+            }),
+        );
+        /* Changes to the working tree or staging area made outside of this application. */
+        project.connect_local(
+            Project::FILES_CHANGED,
+            false,
+            clone!(@weak self as obj => @default-return None, move |_| {
+                obj.schedule_git_status_update();
+                None
+            }),
+        );
+        self.update_git_status();
+    }
+
+    /// Saving is followed by [`Project::FILES_CHANGED`] once the watcher sees the written files,
+    /// so coalesce them into one status update.
+    #[cfg(feature = "git")]
+    fn schedule_git_status_update(&self) {
+        if self.git_refresh_pending.replace(true) {
+            return;
+        }
+        glib::timeout_add_local_once(
+            std::time::Duration::from_millis(300),
+            clone!(@weak self as obj => move || {
+                obj.git_refresh_pending.set(false);
+                obj.update_git_status();
+            }),
+        );
+    }
+
+    #[cfg(feature = "git")]
+    pub fn update_git_status(&self) {
+        use crate::git::GlyphGitStatus;
+
+        let project = self.project();
+        let repository = project.repository.borrow();
+        let Ok(Some(repo)) = &*repository else {
+            return;
+        };
+        let statuses: HashMap<PathBuf, git2::Status> = repo
+            .changed_files()
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        let changed_since = self
+            .changed_since
+            .borrow()
+            .as_ref()
+            .and_then(|rev| repo.changed_since(rev).ok());
+        for c in self.widgets.borrow().iter() {
+            let path = c
+                .glif_path()
+                .and_then(|p| repo.relative_path(&p).map(Cow::into_owned));
+            let status = path
+                .as_ref()
+                .and_then(|p| statuses.get(p))
+                .copied()
+                .map(GlyphGitStatus::from)
+                .unwrap_or_default();
+            c.set_git_status(status);
+            c.hidden_by_git_filter.set(
+                changed_since
+                    .as_ref()
+                    .map(|set| !path.as_ref().map(|p| set.contains(p)).unwrap_or(false))
+                    .unwrap_or(false),
+            );
+        }
+        self.update_flow_box();
+    }
+
+    #[cfg(feature = "git")]
+    fn changed_since_dialog(&self) {
+        let project = self.project();
+        let branches = match &*project.repository.borrow() {
+            Ok(Some(repo)) => repo.branches().unwrap_or_default(),
+            Ok(None) | Err(_) => {
+                let dialog = crate::utils::widgets::new_simple_info_dialog(
+                    Some("Project is not in a git repository"),
+                    "Filtering by changes is only available for projects inside a git working directory.",
+                    None,
+                    self.app().window.upcast_ref(),
+                );
+                dialog.run();
+                dialog.emit_close();
+                return;
+            }
+        };
+        let dialog = gtk::Dialog::builder()
+            .attached_to(&self.app().window)
+            .application(self.app())
+            .border_width(10)
+            .destroy_with_parent(true)
+            .modal(true)
+            .title("Show glyphs changed since")
+            .build();
+        dialog.add_button("Filter", gtk::ResponseType::Accept);
+        dialog.add_button("Show all", gtk::ResponseType::Reject);
+        dialog.add_button("Cancel", gtk::ResponseType::Close);
+        let b = dialog.content_area();
+        b.pack_start(
+            &gtk::Label::builder()
+                .label("Commit, branch or any revision, e.g. HEAD~3 or main")
+                .visible(true)
+                .wrap(true)
+                .halign(gtk::Align::Start)
+                .build(),
+            true,
+            false,
+            5,
+        );
+        let combo = gtk::ComboBoxText::with_entry();
+        combo.set_visible(true);
+        combo.append_text("HEAD");
+        for branch in branches {
+            combo.append_text(&branch);
+        }
+        if let Some(entry) = combo.child().and_then(|c| c.downcast::<gtk::Entry>().ok()) {
+            entry.set_text(self.changed_since.borrow().as_deref().unwrap_or("HEAD"));
+        }
+        b.pack_start(&combo, true, false, 0);
+        loop {
+            match dialog.run() {
+                gtk::ResponseType::Accept => {
+                    let Some(revision) = combo.active_text().map(|s| s.trim().to_string()) else {
+                        continue;
+                    };
+                    if revision.is_empty() {
+                        continue;
+                    }
+                    let result = match &*project.repository.borrow() {
+                        Ok(Some(repo)) => repo.changed_since(&revision).map(|_| ()),
+                        _ => Ok(()),
+                    };
+                    if let Err(err) = result {
+                        let err_dialog = crate::utils::widgets::new_simple_error_dialog(
+                            Some("Error: invalid revision"),
+                            &err.to_string(),
+                            None,
+                            dialog.upcast_ref(),
+                        );
+                        err_dialog.run();
+                        err_dialog.emit_close();
+                        continue;
+                    }
+                    *self.changed_since.borrow_mut() = Some(revision);
+                    break;
+                }
+                gtk::ResponseType::Reject => {
+                    *self.changed_since.borrow_mut() = None;
+                    break;
+                }
+                gtk::ResponseType::Close | gtk::ResponseType::DeleteEvent => {
+                    dialog.emit_close();
+                    return;
+                }
+                _other => unreachable!("{_other:?}"),
+            }
+        }
+        dialog.emit_close();
+        self.update_git_status();
+    }
+
     fn update_tree_store(&self) {
         let tree_store = self.imp().tree_store.get().unwrap();
         let mut show_blocks = self.imp().show_blocks.borrow_mut();
@@ -671,26 +1056,483 @@ impl Collection {
                 &[(0, &true), (1, &block_name), (2, &(count as i64))],
             );
         }
+        let saved_searches = self.app().runtime.settings.saved_searches();
+        if !saved_searches.is_empty() {
+            let current = self.imp().filter_input.borrow().clone().unwrap_or_default();
+            let parent = tree_store.insert_with_values(
+                None,
+                None,
+                &[
+                    (0, &false),
+                    (1, &Self::SAVED_SEARCHES_ROW),
+                    (2, &(saved_searches.len() as i64)),
+                ],
+            );
+            let widgets = self.imp().widgets.borrow();
+            for (name, query) in saved_searches {
+                let count = Query::parse(&query)
+                    .map(|q| {
+                        widgets
+                            .iter()
+                            .filter(|c| q.matches(&c.imp().glyph.get().unwrap().borrow()))
+                            .count()
+                    })
+                    .unwrap_or(0);
+                tree_store.insert_with_values(
+                    Some(&parent),
+                    None,
+                    &[(0, &(query == current)), (1, &name), (2, &(count as i64))],
+                );
+            }
+        }
+    }
+
+    /// Returns the name of the saved search at `iter`, if it is one.
+    fn saved_search_row(&self, store: &gtk::TreeStore, iter: &gtk::TreeIter) -> Option<String> {
+        let parent = store.iter_parent(iter)?;
+        if store.value(&parent, 1).get::<String>().ok()? != Self::SAVED_SEARCHES_ROW {
+            return None;
+        }
+        store.value(iter, 1).get::<String>().ok()
+    }
+
+    /// Applies the saved search `name`, or clears the search if it is already applied.
+    fn toggle_saved_search(&self, name: &str) {
+        let Some(query) = self
+            .app()
+            .runtime
+            .settings
+            .saved_searches()
+            .get(name)
+            .cloned()
+        else {
+            return;
+        };
+        let search_entry = &self.imp().search_entry;
+        if search_entry.text().as_str() == query {
+            search_entry.set_text("");
+        } else {
+            search_entry.set_text(&query);
+        }
+    }
+
+    /// Checks the saved searches that are equal to the current search.
+    fn sync_saved_searches(&self) {
+        let tree_store = self.imp().tree_store.get().unwrap();
+        let saved_searches = self.app().runtime.settings.saved_searches();
+        let current = self.imp().filter_input.borrow().clone().unwrap_or_default();
+        tree_store.foreach(|_, _, iter| {
+            if let Some(name) = self.saved_search_row(tree_store, iter) {
+                let active = saved_searches.get(&name) == Some(&current);
+                tree_store.set_value(iter, 0, &active.to_value());
+            }
+            false
+        });
+    }
+
+    fn save_search_dialog(&self) {
+        let Some(query) = self.imp().filter_input.borrow().clone() else {
+            return;
+        };
+        let dialog = gtk::Dialog::builder()
+            .attached_to(&self.app().window)
+            .application(self.app())
+            .border_width(10)
+            .destroy_with_parent(true)
+            .modal(true)
+            .title("Save search")
+            .build();
+        dialog.add_button("Save", gtk::ResponseType::Accept);
+        dialog.add_button("Cancel", gtk::ResponseType::Close);
+        dialog.set_default_response(gtk::ResponseType::Accept);
+        let b = dialog.content_area();
+        b.pack_start(
+            &gtk::Label::builder()
+                .label(&format!(
+                    "Name for search <tt>{}</tt>:",
+                    glib::markup_escape_text(&query)
+                ))
+                .use_markup(true)
+                .visible(true)
+                .halign(gtk::Align::Start)
+                .build(),
+            true,
+            false,
+            5,
+        );
+        let entry = gtk::Entry::builder()
+            .visible(true)
+            .activates_default(true)
+            .text(&query)
+            .build();
+        b.pack_start(&entry, true, false, 0);
+        if dialog.run() == gtk::ResponseType::Accept && !entry.text().is_empty() {
+            let settings = &self.app().runtime.settings;
+            let mut searches = settings.saved_searches();
+            searches.insert(entry.text().to_string(), query);
+            if let Err(err) = settings.set_saved_searches(&searches) {
+                self.show_saved_search_error(&err.to_string());
+            }
+            self.update_tree_store();
+            self.update_flow_box();
+        }
+        dialog.emit_close();
+    }
+
+    /// Renames the selected glyphs, or all glyphs if none are selected, to their production or
+    /// `uni` names, as one undoable action.
+    fn rename_glyphs_dialog(&self) {
+        use crate::unicode::agl::{production_name, uni_name};
+
+        let selected = self.selected_glyphs();
+        let all = selected.is_empty();
+        let glyphs = if all {
+            self.imp()
+                .widgets
+                .borrow()
+                .iter()
+                .map(|c| c.imp().glyph.get().unwrap().clone())
+                .collect::<Vec<_>>()
+        } else {
+            selected
+        };
+        let dialog = gtk::Dialog::builder()
+            .attached_to(&self.app().window)
+            .application(self.app())
+            .border_width(10)
+            .destroy_with_parent(true)
+            .modal(true)
+            .title("Rename glyphs")
+            .build();
+        dialog.add_button("Rename", gtk::ResponseType::Accept);
+        dialog.add_button("Cancel", gtk::ResponseType::Close);
+        let b = dialog.content_area();
+        b.pack_start(
+            &gtk::Label::builder()
+                .label(&format!(
+                    "Rename {} glyphs mapped to characters:",
+                    if all { "all" } else { "the selected" }
+                ))
+                .visible(true)
+                .halign(gtk::Align::Start)
+                .build(),
+            false,
+            false,
+            5,
+        );
+        let production = gtk::RadioButton::with_label(
+            "Production names: AGL names such as eacute, otherwise uni0416",
+        );
+        production.set_visible(true);
+        let uni = gtk::RadioButton::with_label_from_widget(&production, "uni names, e.g. uni00E9");
+        uni.set_visible(true);
+        b.pack_start(&production, false, false, 0);
+        b.pack_start(&uni, false, false, 0);
+        let response = dialog.run();
+        let use_uni = uni.is_active();
+        dialog.emit_close();
+        if response != gtk::ResponseType::Accept {
+            return;
+        }
+        let project = self.project();
+        let mut actions = vec![];
+        let mut errors = vec![];
+        for glyph in glyphs {
+            let (name, new_name) = {
+                let glyph = glyph.borrow();
+                let GlyphKind::Char(c) = glyph.kinds().0 else {
+                    continue;
+                };
+                let new_name = if use_uni {
+                    uni_name(c)
+                } else {
+                    production_name(c)
+                };
+                (glyph.name().to_string(), new_name)
+            };
+            if name == new_name {
+                continue;
+            }
+            match project.rename_glyph_action(&name, &new_name) {
+                Ok(mut action) => {
+                    (action.redo)();
+                    actions.push(action);
+                }
+                Err(err) => errors.push(err.to_string()),
+            }
+        }
+        let renamed = actions.len();
+        if renamed > 0 {
+            let actions = Rc::new(RefCell::new(actions));
+            self.app().undo_db.borrow_mut().event(crate::app::Action {
+                stamp: crate::app::EventStamp {
+                    t: std::any::TypeId::of::<Self>(),
+                    property: "glyph-names",
+                    id: Box::new([]),
+                },
+                compress: false,
+                redo: Box::new(clone!(@strong actions => move || {
+                    for action in actions.borrow_mut().iter_mut() {
+                        (action.redo)();
+                    }
+                })),
+                undo: Box::new(move || {
+                    for action in actions.borrow_mut().iter_mut().rev() {
+                        (action.undo)();
+                    }
+                }),
+            });
+        }
+        self.imp().flow_box.queue_draw();
+        if !errors.is_empty() {
+            let dialog = crate::utils::widgets::new_simple_error_dialog(
+                Some(&format!(
+                    "Renamed {} glyphs, {} could not be renamed.",
+                    renamed,
+                    errors.len()
+                )),
+                &errors.join("\n"),
+                None,
+                self.app().window.upcast_ref(),
+            );
+            dialog.run();
+            dialog.emit_close();
+        }
+    }
+
+    /// Builds composite glyphs from canonical decompositions and an optional recipe file. New
+    /// glyphs are added to the default layer, and existing glyphs without contours get their
+    /// components replaced.
+    fn build_composites_dialog(&self) {
+        use crate::glyphs::composites::{CompositeBuilder, Recipe};
+        use crate::glyphs::Unicode;
+        use crate::unicode::charsets::Charset;
+
+        let selected = self.selected_glyphs();
+        let charsets = Charset::builtin()
+            .into_iter()
+            .chain(Charset::user_defined())
+            .collect::<Vec<_>>();
+        let dialog = gtk::Dialog::builder()
+            .attached_to(&self.app().window)
+            .application(self.app())
+            .border_width(10)
+            .destroy_with_parent(true)
+            .modal(true)
+            .title("Build composite glyphs")
+            .build();
+        dialog.add_button("Build", gtk::ResponseType::Accept);
+        dialog.add_button("Cancel", gtk::ResponseType::Close);
+        let scope = gtk::ComboBoxText::builder().visible(true).build();
+        if !selected.is_empty() {
+            scope.append(Some("selected"), "Selected glyphs");
+        }
+        scope.append(Some("all"), "All glyphs");
+        for (i, charset) in charsets.iter().enumerate() {
+            scope.append(Some(&i.to_string()), &charset.name);
+        }
+        scope.set_active(Some(0));
+        let recipe_file = gtk::FileChooserButton::builder()
+            .title("Recipe file")
+            .action(gtk::FileChooserAction::Open)
+            .visible(true)
+            .build();
+        let replace_contours = gtk::CheckButton::builder()
+            .label("Replace glyphs that have contours")
+            .visible(true)
+            .build();
+        let grid = gtk::Grid::builder()
+            .row_spacing(5)
+            .column_spacing(5)
+            .visible(true)
+            .build();
+        for (row, (label, widget)) in [
+            ("Build glyphs for", scope.upcast_ref::<gtk::Widget>()),
+            ("Recipe overrides", recipe_file.upcast_ref()),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            grid.attach(
+                &gtk::Label::builder()
+                    .label(label)
+                    .halign(gtk::Align::End)
+                    .visible(true)
+                    .build(),
+                0,
+                row as i32,
+                1,
+                1,
+            );
+            grid.attach(widget, 1, row as i32, 1, 1);
+        }
+        grid.attach(&replace_contours, 1, 2, 1, 1);
+        dialog.content_area().pack_start(&grid, false, false, 5);
+        let response = dialog.run();
+        let scope = scope
+            .active_id()
+            .map(|id| id.to_string())
+            .unwrap_or_default();
+        let recipe_file = recipe_file.filename();
+        let replace_contours = replace_contours.is_active();
+        dialog.emit_close();
+        if response != gtk::ResponseType::Accept {
+            return;
+        }
+
+        let show_error = |title: &str, msg: &str| {
+            let dialog = crate::utils::widgets::new_simple_error_dialog(
+                Some(title),
+                msg,
+                None,
+                self.app().window.upcast_ref(),
+            );
+            dialog.run();
+            dialog.emit_close();
+        };
+        let overrides = match recipe_file
+            .map(|path| -> Result<Vec<Recipe>, Box<dyn std::error::Error>> {
+                Recipe::parse_file(&std::fs::read_to_string(&path)?)
+            })
+            .transpose()
+        {
+            Ok(v) => v.unwrap_or_default(),
+            Err(err) => {
+                show_error("Error: Could not read recipe file.", &err.to_string());
+                return;
+            }
+        };
+        let project = self.project();
+        let layer = project.default_layer.clone();
+        let mut errors = vec![];
+        let mut skipped = 0;
+        let composites = {
+            let glyphs = layer.glyphs();
+            let builder = CompositeBuilder::new(&glyphs);
+            let (chars, names): (Vec<char>, Option<HashSet<String>>) = match scope.as_str() {
+                "selected" | "all" => {
+                    let targets = if scope == "selected" {
+                        selected.clone()
+                    } else {
+                        glyphs.values().cloned().collect()
+                    };
+                    let targets = targets.iter().map(|g| g.borrow()).collect::<Vec<_>>();
+                    (
+                        targets
+                            .iter()
+                            .filter_map(|g| match g.kinds().0 {
+                                GlyphKind::Char(c) => Some(c),
+                                GlyphKind::Component(_) => None,
+                            })
+                            .collect(),
+                        (scope == "selected")
+                            .then(|| targets.iter().map(|g| g.name().to_string()).collect()),
+                    )
+                }
+                i => (
+                    charsets[i.parse::<usize>().unwrap()]
+                        .chars
+                        .iter()
+                        .copied()
+                        .collect(),
+                    None,
+                ),
+            };
+            let mut recipes: IndexMap<String, Recipe> = chars
+                .into_iter()
+                .filter_map(|c| builder.recipe_for_char(c))
+                .map(|r| (r.name.clone(), r))
+                .collect();
+            for recipe in overrides {
+                if names.as_ref().map_or(true, |n| n.contains(&recipe.name)) {
+                    recipes.insert(recipe.name.clone(), recipe);
+                }
+            }
+            let mut composites = vec![];
+            for recipe in recipes.into_values() {
+                let existing = glyphs.get(&recipe.name).cloned();
+                if !replace_contours
+                    && existing
+                        .as_ref()
+                        .map_or(false, |g| !g.borrow().contours.is_empty())
+                {
+                    skipped += 1;
+                    continue;
+                }
+                match builder.build(&recipe) {
+                    Ok(composite) => composites.push((recipe, existing, composite)),
+                    Err(err) => errors.push(err.to_string()),
+                }
+            }
+            composites
+        };
+        let mut built = 0;
+        for (recipe, existing, composite) in composites {
+            if let Some(glyph) = existing {
+                let metadata = {
+                    let mut glyph = glyph.borrow_mut();
+                    glyph.contours.clear();
+                    composite.apply(&mut glyph);
+                    glyph.metadata.clone()
+                };
+                metadata.set_property(GlyphMetadata::MODIFIED, true);
+                project.glyph_changed(&glyph);
+                built += 1;
+                continue;
+            }
+            let mut glyph: Glyph = match recipe.unicode() {
+                Some(c) => GlyphKind::Char(c).into(),
+                None => GlyphKind::Component(recipe.name.clone()).into(),
+            };
+            if let Some(c) = recipe.unicode() {
+                *glyph.metadata.unicode.borrow_mut() =
+                    vec![Unicode::new(format!("{:04X}", c as u32))];
+            }
+            *glyph.metadata.name.borrow_mut() = recipe.name.clone();
+            *glyph.metadata.filename.borrow_mut() = layer.new_filename(&recipe.name);
+            composite.apply(&mut glyph);
+            let metadata = glyph.metadata.clone();
+            let glyph = Rc::new(RefCell::new(glyph));
+            metadata.glyph_ref.set(glyph.clone()).unwrap();
+            if let Err(err) = project.new_glyph(recipe.name.clone(), glyph, None) {
+                errors.push(err.to_string());
+                continue;
+            }
+            metadata.set_property(GlyphMetadata::MODIFIED, true);
+            self.emit_by_name::<()>(Collection::NEW_GLYPH, &[&metadata]);
+            built += 1;
+        }
+        if !errors.is_empty() || built == 0 {
+            show_error(
+                &format!(
+                    "Built {} composite glyphs, skipped {} glyphs with contours.",
+                    built, skipped
+                ),
+                &if errors.is_empty() {
+                    "No glyph could be built from the available bases and marks.".to_string()
+                } else {
+                    errors.join("\n")
+                },
+            );
+        }
+    }
+
+    fn show_saved_search_error(&self, msg: &str) {
+        let dialog = crate::utils::widgets::new_simple_error_dialog(
+            Some("Error: Could not save searches."),
+            msg,
+            None,
+            self.app().window.upcast_ref(),
+        );
+        dialog.run();
+        dialog.emit_close();
     }
 
     fn update_flow_box(&self) {
         let hide_empty: bool = self.imp().hide_empty.get();
         let zoom_factor: f64 = self.imp().zoom_factor.get();
         let show_blocks = self.imp().show_blocks.clone();
-        let filter_input = self.imp().filter_input.clone();
-        let filter_input_uppercase: Option<String> = filter_input
-            .borrow()
-            .as_ref()
-            .map(|s| s.to_ascii_uppercase());
-        let filter_input_char: Option<char> = filter_input.borrow().as_ref().and_then(|s| {
-            let mut iter = s.chars();
-            let first = iter.next()?;
-            if iter.next().is_some() {
-                None
-            } else {
-                Some(first)
-            }
-        });
+        let query = self.imp().filter_query.borrow().clone();
         let flow_box = &self.imp().flow_box;
         flow_box.set_filter_func(Some(Box::new(
             clone!(@weak self as _self => @default-return true, move |flowbox: &gtk::FlowBoxChild| {
@@ -702,50 +1544,225 @@ impl Collection {
                     c.set_height_request((zoom_factor * GLYPH_BOX_HEIGHT) as i32);
                     c.set_width_request((zoom_factor * GLYPH_BOX_WIDTH) as i32);
                     c.queue_draw();
-                    let show_blocks = show_blocks.borrow();
-                    let filter_input = filter_input.borrow();
-                    let glyph = c.imp().glyph.get().unwrap().borrow();
-                    if hide_empty && glyph.is_empty() {
-                        return false;
-                    }
-                    if !match glyph.kinds().0 {
-                        GlyphKind::Component(_) => *show_blocks.get("Components").unwrap_or(&true),
-                        GlyphKind::Char(c) => {
-                            *show_blocks.get("Unicode").unwrap_or(&true)
-                                && *c
-                                    .char_block()
-                                    .and_then(|idx| show_blocks.get(UNICODE_BLOCKS[idx].1))
-                                    .unwrap_or(&true)
-                        }
-                    } {
-                        return false;
-                    }
-
-                    if let (Some(f), Some(fu)) =
-                        (filter_input.as_ref(), filter_input_uppercase.as_ref())
-                    {
-                        if !(glyph.name().contains(f.as_str())
-                            && !glyph
-                                .name()
-                                .contains(fu.as_str())
-                            && !filter_input_char
-                                .as_ref()
-                                .map(|c| glyph.kinds().0 == GlyphKind::Char(*c))
-                                .unwrap_or(false))
-                        {
-                            return false;
-                        }
-                    }
-                    true
+                    is_shown(c, hide_empty, &show_blocks.borrow(), &query)
                 } else {
                     false
                 }
             }),
         )));
         flow_box.queue_draw();
+        self.update_list();
+    }
+
+    fn new_glyph_box(&self, glyph: Rc<RefCell<Glyph>>) -> GlyphBox {
+        let glyph_box = GlyphBox::new(self.app().clone(), self.project().clone(), glyph);
+        self.bind_property(Self::ZOOM_FACTOR, &glyph_box, GlyphBox::ZOOM_FACTOR)
+            .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::DEFAULT)
+            .build();
+        glyph_box.imp().layout.set(self.layout.clone()).unwrap();
+        glyph_box
+    }
+
+    /// Shows the grid or the list as in the layout, and redraws the cells.
+    fn apply_layout(&self) {
+        let layout = self.layout.borrow().clone();
+        self.set_property(Self::ZOOM_FACTOR, layout.zoom_factor);
+        let flow_box = &self.flow_box;
+        flow_box.set_min_children_per_line(layout.columns);
+        flow_box.set_max_children_per_line(if layout.columns == 0 {
+            150
+        } else {
+            layout.columns
+        });
+        self.apply_list_layout();
+        self.stack.set_visible_child_name(match layout.mode {
+            ViewMode::Grid => "grid",
+            ViewMode::List => "list",
+        });
+        self.update_flow_box();
+        for c in self.widgets.borrow().iter() {
+            c.imp().drawing_area.queue_draw();
+        }
+    }
+
+    /// Saves the layout in the settings, for the next time the project is opened.
+    fn save_layout(&self) {
+        if self.list_layout_applying.get() || self.project.get().is_none() {
+            return;
+        }
+        self.sync_list_layout();
+        let path = self.project().path.borrow().clone();
+        if let Err(err) = self
+            .app()
+            .runtime
+            .settings
+            .set_collection_layout(&path, &self.layout.borrow())
+        {
+            eprintln!("Could not save collection layout: {err}");
+        }
+    }
+
+    /// Popover with the layout options, initialized from the current layout.
+    fn new_layout_popover(&self, relative_to: &impl IsA<gtk::Widget>) -> gtk::Popover {
+        let layout = self.layout.borrow().clone();
+        let update = Rc::new(
+            clone!(@weak self as obj => move |f: &dyn Fn(&mut CollectionLayout)| {
+                f(&mut obj.layout.borrow_mut());
+                obj.apply_layout();
+                obj.save_layout();
+            }),
+        );
+        let grid = gtk::Grid::builder()
+            .row_spacing(5)
+            .column_spacing(10)
+            .margin(10)
+            .visible(true)
+            .build();
+        let label = |text: &str| {
+            gtk::Label::builder()
+                .label(text)
+                .halign(gtk::Align::Start)
+                .visible(true)
+                .build()
+        };
+        let mut row = 0;
+
+        grid.attach(&label("View"), 0, row, 1, 1);
+        let mode = gtk::ComboBoxText::builder().visible(true).build();
+        mode.append_text("Grid");
+        mode.append_text("List");
+        mode.set_active(Some(match layout.mode {
+            ViewMode::Grid => 0,
+            ViewMode::List => 1,
+        }));
+        mode.connect_changed(clone!(@strong update => move |mode| {
+            let value = if mode.active() == Some(1) {
+                ViewMode::List
+            } else {
+                ViewMode::Grid
+            };
+            update(&|l| l.mode = value);
+        }));
+        grid.attach(&mode, 1, row, 1, 1);
+        row += 1;
+
+        grid.attach(&label("Cells per row"), 0, row, 1, 1);
+        let columns = gtk::SpinButton::with_range(0.0, 150.0, 1.0);
+        columns.set_tooltip_text(Some("0 fits as many cells as the width allows"));
+        columns.set_value(f64::from(layout.columns));
+        columns.set_visible(true);
+        columns.connect_value_changed(clone!(@strong update => move |spin| {
+            let value = spin.value() as u32;
+            update(&|l| l.columns = value);
+        }));
+        grid.attach(&columns, 1, row, 1, 1);
+        row += 1;
+
+        grid.attach(&label("Cell overlays"), 0, row, 1, 1);
+        for info in CellInfo::ALL {
+            let check = gtk::CheckButton::builder()
+                .label(info.name())
+                .active(layout.shows(info))
+                .visible(true)
+                .build();
+            check.connect_toggled(clone!(@strong update => move |check| {
+                let value = check.is_active();
+                update(&|l| l.set_shows(info, value));
+            }));
+            grid.attach(&check, 1, row, 1, 1);
+            row += 1;
+        }
+
+        grid.attach(&label("Reference glyph"), 0, row, 1, 1);
+        let reference = gtk::Entry::builder()
+            .text(&layout.reference_glyph)
+            .placeholder_text("Glyph name, e.g. n")
+            .visible(true)
+            .build();
+        reference.connect_changed(clone!(@strong update => move |entry| {
+            let value = entry.text().trim().to_string();
+            update(&|l| l.reference_glyph = value.clone());
+        }));
+        grid.attach(&reference, 1, row, 1, 1);
+        row += 1;
+
+        grid.attach(&label("Badge"), 0, row, 1, 1);
+        let badge = gtk::ComboBoxText::builder().visible(true).build();
+        for b in CellBadge::ALL {
+            badge.append_text(b.name());
+        }
+        badge.set_active(
+            CellBadge::ALL
+                .iter()
+                .position(|b| *b == layout.badge)
+                .map(|i| i as u32),
+        );
+        badge.connect_changed(clone!(@strong update => move |badge| {
+            let Some(value) = badge.active().and_then(|i| CellBadge::ALL.get(i as usize).copied()) else {
+                return;
+            };
+            update(&|l| l.badge = value);
+        }));
+        grid.attach(&badge, 1, row, 1, 1);
+        row += 1;
+
+        grid.attach(&label("List columns"), 0, row, 1, 1);
+        for c in layout.list_columns.iter() {
+            let column = c.column;
+            let check = gtk::CheckButton::builder()
+                .label(column.title())
+                .active(c.visible)
+                .visible(true)
+                .build();
+            check.connect_toggled(clone!(@strong update => move |check| {
+                let value = check.is_active();
+                update(&|l| {
+                    for c in l.list_columns.iter_mut().filter(|c| c.column == column) {
+                        c.visible = value;
+                    }
+                });
+            }));
+            grid.attach(&check, 1, row, 1, 1);
+            row += 1;
+        }
+
+        gtk::Popover::builder()
+            .child(&grid)
+            .relative_to(relative_to)
+            .build()
     }
 }
 
+/// Whether `c` passes the collection's filters and search query.
+fn is_shown(
+    c: &GlyphBox,
+    hide_empty: bool,
+    show_blocks: &HashMap<&'static str, bool>,
+    query: &Query,
+) -> bool {
+    let glyph = c.imp().glyph.get().unwrap().borrow();
+    if hide_empty && glyph.is_empty() {
+        return false;
+    }
+    #[cfg(feature = "git")]
+    if c.imp().hidden_by_git_filter.get() {
+        return false;
+    }
+    if !match glyph.kinds().0 {
+        GlyphKind::Component(_) => *show_blocks.get("Components").unwrap_or(&true),
+        GlyphKind::Char(c) => {
+            *show_blocks.get("Unicode").unwrap_or(&true)
+                && *c
+                    .char_block()
+                    .and_then(|idx| show_blocks.get(UNICODE_BLOCKS[idx].1))
+                    .unwrap_or(&true)
+        }
+    } {
+        return false;
+    }
+    query.matches(&glyph)
+}
+
 #[derive(Debug, Default)]
 pub struct GlyphBoxInner {
     pub app: OnceCell<Application>,
@@ -757,6 +1774,13 @@ pub struct GlyphBoxInner {
     pub zoom_factor: Cell<f64>,
     pub show_details: Cell<bool>,
     pub drawing_area: gtk::DrawingArea,
+    pub layout: OnceCell<Rc<RefCell<CollectionLayout>>>,
+    /// Cached outline problems of the glyph, computed when first drawn after a change.
+    problems: RefCell<Option<Vec<String>>>,
+    #[cfg(feature = "git")]
+    pub git_status: Cell<crate::git::GlyphGitStatus>,
+    #[cfg(feature = "git")]
+    pub hidden_by_git_filter: Cell<bool>,
 }
 
 #[glib::object_subclass]
@@ -803,10 +1827,27 @@ impl ObjectImpl for GlyphBoxInner {
                                         w.present();
                                     }),
                                 )
+                                .add_button_cb(
+                                    "Transform",
+                                    clone!(@weak obj => move |_| {
+                                        obj.open_transform_panel();
+                                    }),
+                                )
+                                .add_button_cb(
+                                    "Unicode inspector",
+                                    clone!(@weak obj => move |_| {
+                                        obj.open_unicode_inspector();
+                                    }),
+                                )
                                 .add_button("Delete glyph")
                                 .add_button("Export SVG");
                             context_menu.popup(event.time());
                         }
+                        gtk::gdk::BUTTON_PRIMARY
+                            if event.state().contains(gtk::gdk::ModifierType::CONTROL_MASK) =>
+                        {
+                            obj.toggle_selected();
+                        }
                         gtk::gdk::BUTTON_PRIMARY => {
                             obj.emit_open_glyph_edit();
                         }
@@ -822,7 +1863,7 @@ impl ObjectImpl for GlyphBoxInner {
         self.drawing_area.connect_query_tooltip(
             clone!(@weak obj => @default-return false, move |_self, _x: i32, _y: i32, _by_keyboard: bool, tooltip| {
                 let glyph = obj.imp().glyph.get().unwrap().borrow();
-                if let GlyphKind::Char(c) = glyph.kinds().0 {
+                let mut text = if let GlyphKind::Char(c) = glyph.kinds().0 {
                     let block_name = if let Some(idx) = c.char_block() {
                         UNICODE_BLOCKS[idx].1
                     } else {
@@ -830,10 +1871,21 @@ impl ObjectImpl for GlyphBoxInner {
                     };
                     let unicode = format!("U+{:04X}", c as u32);
 
-                    tooltip.set_text(Some(&format!("Name: {}\nUnicode: {}\nBlock: {}", glyph.name(), unicode, block_name)));
+                    format!("Name: {}\nUnicode: {}\nBlock: {}", glyph.name(), unicode, block_name)
                 } else {
-                    tooltip.set_text(Some(&format!("Name: {}\nComponent", glyph.name())));
+                    format!("Name: {}\nComponent", glyph.name())
+                };
+                let info = GlyphInfo::new(&glyph);
+                for column in [ListColumn::Width, ListColumn::LeftSidebearing, ListColumn::RightSidebearing, ListColumn::Contours, ListColumn::Components] {
+                    let (value, _) = column.value(&info);
+                    if !value.is_empty() {
+                        text.push_str(&format!("\n{}: {value}", column.title()));
+                    }
+                }
+                for problem in info.problems {
+                    text.push_str(&format!("\n⚠ {problem}"));
                 }
+                tooltip.set_text(Some(&text));
                 true
             }));
         self.drawing_area.connect_draw(clone!(@weak obj => @default-return Inhibit(false), move |viewport: &gtk::DrawingArea, mut ctx: &Context| {
@@ -847,6 +1899,8 @@ impl ObjectImpl for GlyphBoxInner {
 
             let (x, y) = (0.01, 0.01);
             let glyph = obj.imp().glyph.get().unwrap().borrow();
+            let layout = obj.imp().layout.get().cloned().unwrap_or_default();
+            let layout = layout.borrow();
             let label = match glyph.kinds().0 {
                 GlyphKind::Char(c) => c.to_string(),
                 GlyphKind::Component(ref n) => n.to_string(),
@@ -855,7 +1909,7 @@ impl ObjectImpl for GlyphBoxInner {
             cr.set_line_width(1.5);
             let (point, (width, height)) = crate::utils::draw_round_rectangle(cr.push(), (x, y).into(), (zoom_factor * GLYPH_BOX_WIDTH, zoom_factor * GLYPH_BOX_HEIGHT), 1.0, 1.5);
             let glyph_width = glyph.width().unwrap_or(units_per_em) * (width * 0.8) / units_per_em;
-            if is_focused {
+            if is_focused || obj.is_selected() {
                 cr.set_source_color(colors.theme_selected_bg_color);
             } else {
                 cr.set_source_color(colors.theme_base_color);
@@ -896,6 +1950,46 @@ impl ObjectImpl for GlyphBoxInner {
                     },
                 }
             }
+            #[cfg(feature = "git")]
+            if layout.badge == CellBadge::GitStatus {
+                use crate::git::GlyphGitStatus;
+
+                // [ref:hardcoded_color_value]
+                let badge = match obj.imp().git_status.get() {
+                    GlyphGitStatus::Unchanged => None,
+                    GlyphGitStatus::Modified => Some(("M", Color::from_hex("#ff7f0e"))),
+                    GlyphGitStatus::New => Some(("N", Color::from_hex("#2ca02c"))),
+                    GlyphGitStatus::Staged => Some(("S", Color::from_hex("#1f77b4"))),
+                    GlyphGitStatus::Conflicted => Some(("C", Color::from_hex("#d62728"))),
+                };
+                if let Some((label, color)) = badge {
+                    let cr1 = cr.push();
+                    cr1.set_font_size(zoom_factor * 11.0);
+                    cr1.move_to(width / 12.0, width / 12.0 + zoom_factor * 11.0);
+                    cr1.show_text_with_bg(label, 2.0, Color::WHITE, color);
+                }
+            }
+            if layout.badge == CellBadge::Validation && obj.has_problems() {
+                // [ref:hardcoded_color_value]
+                let cr1 = cr.push();
+                cr1.set_font_size(zoom_factor * 11.0);
+                cr1.move_to(width / 12.0, width / 12.0 + zoom_factor * 11.0);
+                cr1.show_text_with_bg("!", 2.0, Color::WHITE, Color::from_hex("#d62728"));
+            }
+            if layout.shows(CellInfo::Counts) {
+                let cr1 = cr.push();
+                cr1.set_source_color(colors.theme_text_color);
+                cr1.set_font_size(zoom_factor * 9.0);
+                let mut line_y = width / 12.0 + zoom_factor * 30.0;
+                for (count, noun) in [(glyph.contours.len(), "contour"), (glyph.components.len(), "component")] {
+                    if count == 0 {
+                        continue;
+                    }
+                    cr1.move_to(width / 12.0, line_y);
+                    cr1.show_text(&format!("{count} {noun}{}", if count == 1 { "" } else { "s" })).expect("Invalid cairo surface state");
+                    line_y += zoom_factor * 11.0;
+                }
+            }
             cr.set_source_rgba(0.0, 0.0, 0.0, 0.4);
             // View height.
             let vh = f64::from(viewport.allocated_height());
@@ -907,14 +2001,29 @@ impl ObjectImpl for GlyphBoxInner {
             let sextents = cr
                 .text_extents(&label)
                 .expect("Invalid cairo surface state");
+            let mut matrix = gtk::cairo::Matrix::identity();
+            matrix.translate((width - glyph_width) / 2.0, 4.5 * vh / 8.0);
+            matrix.scale((width * 0.8) / units_per_em, -(width * 0.8) / units_per_em);
+            if layout.shows(CellInfo::Reference) && layout.reference_glyph != *glyph.name() {
+                let project = obj.imp().project.get().unwrap();
+                let reference = project.default_layer.glyphs().get(&layout.reference_glyph).cloned();
+                if let Some(reference) = reference {
+                    let options = GlyphDrawingOptions {
+                        outline: (Color::new_alpha(0, 0, 0, 0), 1.5).into(),
+                        inner_fill: Some((colors.theme_text_color.with_alpha_f64(0.15), 1.5).into()),
+                        highlight: None,
+                        matrix,
+                        units_per_em,
+                        ..Default::default()
+                    };
+                    reference.borrow().draw(cr.push(), options);
+                }
+            }
             if glyph.is_empty() {
                 cr.move_to(point.x + width / 2.0 - sextents.width / 2.0, point.y + (height / 3.0) + 20.0);
                 cr.set_source_color(colors.theme_text_color);
                 cr.show_text(&label).expect("Invalid cairo surface state");
             } else {
-                let mut matrix = gtk::cairo::Matrix::identity();
-                matrix.translate((width - glyph_width) / 2.0, 4.5 * vh / 8.0);
-                matrix.scale((width * 0.8) / units_per_em, -(width * 0.8) / units_per_em);
                 let options = GlyphDrawingOptions {
                     outline: (Color::new_alpha(0, 0, 0, 0), 1.5).into(),
                     inner_fill: Some((colors.theme_text_color.with_alpha_f64(0.6), 1.5).into()),
@@ -926,6 +2035,37 @@ impl ObjectImpl for GlyphBoxInner {
                 glyph.draw(cr.push(), options);
             }
 
+            /* Draw metrics above the label */
+
+            let show_width = layout.shows(CellInfo::AdvanceWidth);
+            let show_sidebearings = layout.shows(CellInfo::Sidebearings);
+            if show_width || show_sidebearings {
+                let cr1 = cr.push();
+                cr1.set_source_color(colors.theme_text_color);
+                cr1.set_font_size(zoom_factor * 9.0);
+                let line_y = 2.0f64.mul_add(height / 3.0, point.y) - zoom_factor * 4.0;
+                let show = |text: String, align: f64| {
+                    let extents = cr1.text_extents(&text).expect("Invalid cairo surface state");
+                    let x = align.mul_add(width - 8.0 - extents.width, 4.0);
+                    cr1.move_to(point.x + x, line_y);
+                    cr1.show_text_with_bg(&text, 1.0, colors.theme_text_color, colors.theme_base_color.with_alpha_f64(0.7));
+                };
+                let width_value = glyph.width();
+                if show_width {
+                    if let Some(w) = width_value {
+                        show(w.to_string(), 0.5);
+                    }
+                }
+                if show_sidebearings {
+                    if let Some((min, max)) = glyph.extents() {
+                        show(min.x.round().to_string(), 0.0);
+                        if let Some(w) = width_value {
+                            show((w - max.x).round().to_string(), 1.0);
+                        }
+                    }
+                }
+            }
+
             /* Draw glyph label */
 
             cr.set_line_width(2.0);
@@ -950,7 +2090,8 @@ impl ObjectImpl for GlyphBoxInner {
             cr.show_text(&label).expect("Invalid cairo surface state");
 
             let label = match glyph.kinds().0 {
-                GlyphKind::Char(c) => format!("U+{:04X}", c as u32),
+                GlyphKind::Char(c) if layout.shows(CellInfo::Unicode) => format!("U+{:04X}", c as u32),
+                GlyphKind::Char(_) => glyph.name().to_string(),
                 GlyphKind::Component(ref n) => n.to_string(),
             };
             let extents = cr
@@ -1084,6 +2225,133 @@ impl GlyphBox {
     pub const MODIFIED: &str = GlyphMetadata::MODIFIED;
     pub const MARK_COLOR: &str = GlyphMetadata::MARK_COLOR;
 
+    /// Absolute path of the glyph's `.glif` file, if it belongs to a layer.
+    pub fn glif_path(&self) -> Option<PathBuf> {
+        let glyph = self.imp().glyph.get()?.borrow();
+        let layer = glyph.metadata.layer().clone()?;
+        let path = layer.path().join(&*glyph.metadata.filename());
+        Some(path)
+    }
+
+    /// Forgets cached information about the glyph and redraws it.
+    pub fn glyph_changed(&self) {
+        self.imp().problems.borrow_mut().take();
+        self.queue_draw();
+    }
+
+    fn has_problems(&self) -> bool {
+        let mut cache = self.imp().problems.borrow_mut();
+        !cache
+            .get_or_insert_with(|| problems(&self.imp().glyph.get().unwrap().borrow()))
+            .is_empty()
+    }
+
+    #[cfg(feature = "git")]
+    pub fn set_git_status(&self, status: crate::git::GlyphGitStatus) {
+        if self.imp().git_status.replace(status) != status {
+            self.imp().drawing_area.queue_draw();
+        }
+    }
+
+    fn flow_box_child(&self) -> Option<gtk::FlowBoxChild> {
+        self.parent()?.downcast::<gtk::FlowBoxChild>().ok()
+    }
+
+    /// Whether the glyph is part of the collection's selection.
+    pub fn is_selected(&self) -> bool {
+        self.flow_box_child()
+            .map(|c| c.is_selected())
+            .unwrap_or(false)
+    }
+
+    fn flow_box(&self) -> Option<gtk::FlowBox> {
+        self.flow_box_child()?
+            .parent()?
+            .downcast::<gtk::FlowBox>()
+            .ok()
+    }
+
+    fn toggle_selected(&self) {
+        let (Some(child), Some(flow_box)) = (self.flow_box_child(), self.flow_box()) else {
+            return;
+        };
+        if child.is_selected() {
+            flow_box.unselect_child(&child);
+        } else {
+            flow_box.select_child(&child);
+        }
+        self.imp().drawing_area.queue_draw();
+    }
+
+    /// Opens a transform panel for the selected glyphs, or only this glyph if it is not
+    /// selected.
+    fn open_transform_panel(&self) {
+        use crate::glyphs::transform::{TransformOperation, TransformOrigin};
+
+        let app = self.imp().app.get().unwrap().clone();
+        let project = self.imp().project.get().unwrap().clone();
+        let glyphs = match self.flow_box() {
+            Some(flow_box) if self.is_selected() => selected_glyphs(&flow_box),
+            _ => vec![self.imp().glyph.get().unwrap().clone()],
+        };
+        let title = match glyphs.as_slice() {
+            [glyph] => format!("Transform {}", glyph.borrow().name()),
+            glyphs => format!("Transform {} glyphs", glyphs.len()),
+        };
+        let apply: Rc<dyn Fn(TransformOperation, TransformOrigin)> = Rc::new(
+            clone!(@weak app, @weak project => move |operation: TransformOperation, origin: TransformOrigin| {
+                let mut action = Glyph::transform_glyphs(
+                    glyphs.clone(),
+                    operation,
+                    origin,
+                    Rc::new(clone!(@weak project => move |glyph: &Rc<RefCell<Glyph>>| {
+                        project.glyph_changed(glyph);
+                    })),
+                );
+                (action.redo)();
+                app.undo_db.borrow_mut().event(action);
+            }),
+        );
+        let panel = crate::views::transform_panel::new_transform_panel(
+            &title,
+            app.window.upcast_ref(),
+            apply,
+        );
+        panel.present();
+    }
+
+    /// Opens a Unicode inspector for this glyph, that follows the collection's selection.
+    fn open_unicode_inspector(&self) {
+        let app = self.imp().app.get().unwrap().clone();
+        let project = self.imp().project.get().unwrap().clone();
+        let flow_box = self.flow_box();
+        let on_rename: Rc<dyn Fn()> = Rc::new(clone!(@weak self as obj => move || {
+            if let Some(flow_box) = obj.flow_box() {
+                flow_box.queue_draw();
+            }
+        }));
+        let (window, set_glyph) = crate::views::unicode_inspector::new_unicode_inspector(
+            &app,
+            &project,
+            self.imp().glyph.get().unwrap().clone(),
+            on_rename,
+        );
+        if let Some(flow_box) = flow_box {
+            let handler = flow_box.connect_selected_children_changed(move |flow_box| {
+                if let Some(glyph) = selected_glyphs(flow_box).into_iter().next() {
+                    set_glyph(glyph);
+                }
+            });
+            let handler = Cell::new(Some(handler));
+            window.connect_destroy(clone!(@weak flow_box => move |_| {
+                if let Some(handler) = handler.take() {
+                    flow_box.disconnect(handler);
+                }
+            }));
+        }
+        window.present();
+    }
+
     fn emit_open_glyph_edit(&self) {
         self.imp()
             .app
@@ -0,0 +1,397 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! How the glyph collection is laid out: grid cell size and overlays, or a table of glyphs.
+//! Layouts are saved per project in the settings file.
+
+use crate::glyphs::outline_search::ContourShape;
+use crate::glyphs::transform::control_box;
+use crate::glyphs::{Glyph, GlyphKind};
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Information drawn over the glyph of a grid cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CellInfo {
+    Unicode,
+    AdvanceWidth,
+    Sidebearings,
+    Counts,
+    Reference,
+}
+
+impl CellInfo {
+    pub const ALL: [Self; 5] = [
+        Self::Unicode,
+        Self::AdvanceWidth,
+        Self::Sidebearings,
+        Self::Counts,
+        Self::Reference,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Unicode => "Unicode value",
+            Self::AdvanceWidth => "Advance width",
+            Self::Sidebearings => "Sidebearings",
+            Self::Counts => "Contour and component counts",
+            Self::Reference => "Reference glyph in the background",
+        }
+    }
+}
+
+/// Badge in the top left corner of a grid cell.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CellBadge {
+    None,
+    #[default]
+    GitStatus,
+    Validation,
+}
+
+impl CellBadge {
+    pub const ALL: [Self; 3] = [Self::None, Self::GitStatus, Self::Validation];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::None => "No badge",
+            Self::GitStatus => "Git status",
+            Self::Validation => "Validation problems",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ViewMode {
+    #[default]
+    Grid,
+    List,
+}
+
+/// A column of the list mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ListColumn {
+    Name,
+    Character,
+    Unicode,
+    Width,
+    LeftSidebearing,
+    RightSidebearing,
+    Contours,
+    Components,
+    Anchors,
+    Problems,
+}
+
+impl ListColumn {
+    pub const ALL: [Self; 10] = [
+        Self::Name,
+        Self::Character,
+        Self::Unicode,
+        Self::Width,
+        Self::LeftSidebearing,
+        Self::RightSidebearing,
+        Self::Contours,
+        Self::Components,
+        Self::Anchors,
+        Self::Problems,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Character => "Character",
+            Self::Unicode => "Unicode",
+            Self::Width => "Width",
+            Self::LeftSidebearing => "LSB",
+            Self::RightSidebearing => "RSB",
+            Self::Contours => "Contours",
+            Self::Components => "Components",
+            Self::Anchors => "Anchors",
+            Self::Problems => "Problems",
+        }
+    }
+
+    /// Whether the column sorts by number instead of by text.
+    pub fn is_numeric(self) -> bool {
+        !matches!(
+            self,
+            Self::Name | Self::Character | Self::Unicode | Self::Problems
+        )
+    }
+
+    /// The cell text and, for numeric columns, the sort key of `info`. Missing values sort
+    /// first.
+    pub fn value(self, info: &GlyphInfo) -> (String, f64) {
+        let num = |v: Option<f64>| {
+            (
+                v.map(|v| v.to_string()).unwrap_or_default(),
+                v.unwrap_or(f64::MIN),
+            )
+        };
+        match self {
+            Self::Name => (info.name.clone(), 0.0),
+            Self::Character => (info.character.map(String::from).unwrap_or_default(), 0.0),
+            Self::Unicode => (
+                info.character
+                    .map(|c| format!("U+{:04X}", c as u32))
+                    .unwrap_or_default(),
+                0.0,
+            ),
+            Self::Width => num(info.width),
+            Self::LeftSidebearing => num(info.lsb),
+            Self::RightSidebearing => num(info.rsb),
+            Self::Contours => num(Some(info.contours as f64)),
+            Self::Components => num(Some(info.components as f64)),
+            Self::Anchors => num(Some(info.anchors as f64)),
+            Self::Problems => (info.problems.join(" "), 0.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ColumnLayout {
+    pub column: ListColumn,
+    pub visible: bool,
+    /// Width in pixels, `-1` sizes the column to its contents.
+    pub width: i32,
+}
+
+impl Default for ColumnLayout {
+    fn default() -> Self {
+        Self {
+            column: ListColumn::Name,
+            visible: true,
+            width: -1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct CollectionLayout {
+    pub mode: ViewMode,
+    pub zoom_factor: f64,
+    /// Cells per row of the grid, `0` fits as many as the width allows.
+    pub columns: u32,
+    pub overlays: Vec<CellInfo>,
+    pub badge: CellBadge,
+    /// Glyph drawn faintly behind every cell with [`CellInfo::Reference`].
+    pub reference_glyph: String,
+    /// Columns of the list mode, in display order.
+    pub list_columns: Vec<ColumnLayout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_column: Option<ListColumn>,
+    pub sort_ascending: bool,
+}
+
+impl Default for CollectionLayout {
+    fn default() -> Self {
+        Self {
+            mode: ViewMode::Grid,
+            zoom_factor: 1.0,
+            columns: 0,
+            overlays: vec![CellInfo::Unicode],
+            badge: CellBadge::GitStatus,
+            reference_glyph: String::new(),
+            list_columns: vec![],
+            sort_column: None,
+            sort_ascending: true,
+        }
+        .normalized()
+    }
+}
+
+impl CollectionLayout {
+    /// Lists every column of the list mode and every overlay exactly once, keeping the saved
+    /// order, and clamps sizes, so that layouts saved by other versions load.
+    pub fn normalized(mut self) -> Self {
+        let mut seen = vec![];
+        self.list_columns.retain(|c| {
+            let new = !seen.contains(&c.column);
+            seen.push(c.column);
+            new
+        });
+        for column in ListColumn::ALL {
+            if !seen.contains(&column) {
+                self.list_columns.push(ColumnLayout {
+                    column,
+                    ..ColumnLayout::default()
+                });
+            }
+        }
+        if !self.zoom_factor.is_finite() || self.zoom_factor <= 0.0 {
+            self.zoom_factor = 1.0;
+        }
+        self.zoom_factor = self.zoom_factor.clamp(0.1, 2.0);
+        let mut seen = vec![];
+        self.overlays.retain(|i| {
+            let new = !seen.contains(i);
+            seen.push(*i);
+            new
+        });
+        self
+    }
+
+    pub fn shows(&self, info: CellInfo) -> bool {
+        self.overlays.contains(&info)
+    }
+
+    pub fn set_shows(&mut self, info: CellInfo, value: bool) {
+        self.overlays.retain(|i| *i != info);
+        if value {
+            self.overlays.push(info);
+        }
+    }
+}
+
+/// Metrics and counts of a glyph shown in grid cells and list rows.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GlyphInfo {
+    pub name: String,
+    pub character: Option<char>,
+    pub width: Option<f64>,
+    pub lsb: Option<f64>,
+    pub rsb: Option<f64>,
+    pub contours: usize,
+    pub components: usize,
+    pub anchors: usize,
+    pub problems: Vec<String>,
+}
+
+impl GlyphInfo {
+    pub fn new(glyph: &Glyph) -> Self {
+        let extents = glyph.extents();
+        let width = glyph.width();
+        Self {
+            name: glyph.name().to_string(),
+            character: match glyph.kinds().0 {
+                GlyphKind::Char(c) => Some(c),
+                GlyphKind::Component(_) => None,
+            },
+            width,
+            lsb: extents.map(|(min, _)| min.x),
+            rsb: extents.zip(width).map(|((_, max), width)| width - max.x),
+            contours: glyph.contours.len(),
+            components: glyph.components.len(),
+            anchors: glyph.metadata.anchors.borrow().len(),
+            problems: problems(glyph),
+        }
+    }
+}
+
+/// Outline problems of `glyph`: open contours, duplicate contours, components of missing
+/// glyphs and a negative advance width.
+pub fn problems(glyph: &Glyph) -> Vec<String> {
+    let mut ret = vec![];
+    if glyph.width().map(|w| w < 0.0).unwrap_or(false) {
+        ret.push("Negative advance width.".to_string());
+    }
+    let shapes = glyph
+        .contours
+        .iter()
+        .map(|c| (ContourShape::new(c), control_box(std::slice::from_ref(c))))
+        .collect::<Vec<_>>();
+    for (i, c) in glyph.contours.iter().enumerate() {
+        if c.property::<bool>(crate::glyphs::Contour::OPEN) {
+            ret.push(format!("Contour {i} is open."));
+        }
+        if let Some(j) = shapes[..i].iter().position(|(shape, bbox)| {
+            *bbox == shapes[i].1 && shape.matches(&shapes[i].0, 0.0).is_some()
+        }) {
+            ret.push(format!("Contour {i} duplicates contour {j}."));
+        }
+    }
+    for (i, c) in glyph.components.iter().enumerate() {
+        if c.base.upgrade().is_none() {
+            ret.push(format!(
+                "Component {i} refers to missing glyph `{}`.",
+                c.base_name
+            ));
+        }
+    }
+    ret
+}
+
+#[test]
+fn test_collection_layout() {
+    let mut layout = CollectionLayout {
+        mode: ViewMode::List,
+        zoom_factor: 1.5,
+        columns: 8,
+        reference_glyph: "n".to_string(),
+        list_columns: vec![
+            ColumnLayout {
+                column: ListColumn::Width,
+                visible: false,
+                width: 80,
+            },
+            ColumnLayout {
+                column: ListColumn::Width,
+                visible: true,
+                width: 10,
+            },
+        ],
+        sort_column: Some(ListColumn::Width),
+        overlays: vec![CellInfo::Unicode, CellInfo::Counts, CellInfo::Unicode],
+        ..CollectionLayout::default()
+    }
+    .normalized();
+    assert_eq!(layout.list_columns.len(), ListColumn::ALL.len());
+    assert_eq!(layout.list_columns[0].width, 80);
+    assert_eq!(layout.list_columns[1].column, ListColumn::Name);
+    assert_eq!(layout.overlays, vec![CellInfo::Unicode, CellInfo::Counts]);
+    layout.set_shows(CellInfo::Counts, true);
+    layout.set_shows(CellInfo::Unicode, false);
+    assert_eq!(layout.overlays, vec![CellInfo::Counts]);
+
+    let document = toml_edit::ser::to_document(&layout).unwrap();
+    let parsed: CollectionLayout = toml_edit::de::from_document(document).unwrap();
+    assert_eq!(parsed, layout);
+    /* Unknown keys are ignored and missing keys fall back to the defaults. */
+    let parsed: CollectionLayout =
+        toml_edit::de::from_str("zoom-factor = 9.0\ncolumns = 3\nunknown = 1\n").unwrap();
+    assert_eq!(parsed.columns, 3);
+    assert_eq!(parsed.badge, CellBadge::GitStatus);
+    assert_eq!(parsed.normalized().zoom_factor, 2.0);
+
+    let info = GlyphInfo {
+        name: "a".to_string(),
+        character: Some('a'),
+        width: Some(500.0),
+        lsb: None,
+        ..GlyphInfo::default()
+    };
+    assert_eq!(
+        ListColumn::Unicode.value(&info),
+        ("U+0061".to_string(), 0.0)
+    );
+    assert_eq!(ListColumn::Width.value(&info), ("500".to_string(), 500.0));
+    assert_eq!(
+        ListColumn::LeftSidebearing.value(&info),
+        (String::new(), f64::MIN)
+    );
+}
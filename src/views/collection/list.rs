@@ -0,0 +1,232 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! List mode of the collection: one row per glyph with sortable columns.
+//!
+//! The store has two model columns per [`ListColumn`], in [`ListColumn::ALL`] order: the cell
+//! text, and the sort key of numeric columns.
+
+use super::*;
+
+/// Model column of the text of `column`.
+fn text_column(column: ListColumn) -> u32 {
+    2 * ListColumn::ALL.iter().position(|c| *c == column).unwrap() as u32
+}
+
+/// Model column that sorts `column`.
+fn sort_column(column: ListColumn) -> u32 {
+    text_column(column) + u32::from(column.is_numeric())
+}
+
+fn list_column(sort_column_id: u32) -> Option<ListColumn> {
+    ListColumn::ALL.get(sort_column_id as usize / 2).copied()
+}
+
+impl Collection {
+    pub(super) fn setup_list(&self) -> gtk::ScrolledWindow {
+        let store = gtk::ListStore::new(
+            &ListColumn::ALL
+                .iter()
+                .flat_map(|_| [String::static_type(), f64::static_type()])
+                .collect::<Vec<_>>(),
+        );
+        let tree = &self.list_tree;
+        tree.set_model(Some(&store));
+        tree.set_visible(true);
+        tree.set_headers_clickable(true);
+        tree.set_grid_lines(gtk::TreeViewGridLines::Vertical);
+        tree.set_search_column(text_column(ListColumn::Name) as i32);
+        for column in ListColumn::ALL {
+            let view_column = gtk::TreeViewColumn::new();
+            view_column.set_title(column.title());
+            let cell = gtk::CellRendererText::new();
+            if column.is_numeric() {
+                cell.set_xalign(1.0);
+            }
+            view_column.pack_start(&cell, true);
+            view_column.add_attribute(&cell, "text", text_column(column) as i32);
+            view_column.set_sort_column_id(sort_column(column) as i32);
+            view_column.set_resizable(true);
+            view_column.set_reorderable(true);
+            tree.append_column(&view_column);
+        }
+        tree.connect_row_activated(clone!(@weak self as obj => move |tree, path, _| {
+            let Some(model) = tree.model() else { return; };
+            let Some(iter) = model.iter(path) else { return; };
+            let Ok(name) = model
+                .value(&iter, text_column(ListColumn::Name) as i32)
+                .get::<String>() else { return; };
+            let glyph_box = obj
+                .widgets
+                .borrow()
+                .iter()
+                .find(|w| *w.imp().glyph.get().unwrap().borrow().name() == name)
+                .cloned();
+            if let Some(glyph_box) = glyph_box {
+                glyph_box.emit_open_glyph_edit();
+            }
+        }));
+        tree.connect_columns_changed(clone!(@weak self as obj => move |_| {
+            obj.save_layout();
+        }));
+        store.connect_sort_column_changed(clone!(@weak self as obj => move |_| {
+            obj.save_layout();
+        }));
+        /* Column widths are saved when the list is hidden, not on every resize. */
+        tree.connect_unmap(clone!(@weak self as obj => move |_| {
+            obj.save_layout();
+        }));
+        self.list_store.set(store).unwrap();
+        let scrolled_window = gtk::ScrolledWindow::builder()
+            .expand(true)
+            .visible(true)
+            .can_focus(true)
+            .margin_top(5)
+            .margin_start(5)
+            .build();
+        scrolled_window.set_child(Some(tree));
+        scrolled_window
+    }
+
+    /// Orders, sizes and sorts the list's columns as in the layout.
+    pub(super) fn apply_list_layout(&self) {
+        let Some(store) = self.list_store.get() else {
+            return;
+        };
+        let layout = self.layout.borrow().clone();
+        self.list_layout_applying.set(true);
+        let tree = &self.list_tree;
+        let columns = tree.columns();
+        let find = |column: ListColumn| {
+            columns
+                .iter()
+                .find(|c| list_column(c.sort_column_id() as u32) == Some(column))
+        };
+        let mut previous: Option<&gtk::TreeViewColumn> = None;
+        for c in layout.list_columns.iter() {
+            let Some(view_column) = find(c.column) else {
+                continue;
+            };
+            tree.move_column_after(view_column, previous);
+            view_column.set_visible(c.visible);
+            if c.width > 0 {
+                view_column.set_fixed_width(c.width);
+            }
+            previous = Some(view_column);
+        }
+        if let Some(column) = layout.sort_column {
+            store.set_sort_column_id(
+                gtk::SortColumn::Index(sort_column(column)),
+                if layout.sort_ascending {
+                    gtk::SortType::Ascending
+                } else {
+                    gtk::SortType::Descending
+                },
+            );
+        }
+        self.list_layout_applying.set(false);
+    }
+
+    /// Copies the order, widths and sorting of the list's columns into the layout.
+    pub(super) fn sync_list_layout(&self) {
+        let Some(store) = self.list_store.get() else {
+            return;
+        };
+        let columns = self.list_tree.columns();
+        if columns.len() != ListColumn::ALL.len() {
+            return;
+        }
+        let mut layout = self.layout.borrow_mut();
+        layout.list_columns = columns
+            .iter()
+            .filter_map(|view_column| {
+                Some(ColumnLayout {
+                    column: list_column(view_column.sort_column_id() as u32)?,
+                    visible: view_column.is_visible(),
+                    /* Only columns resized by the user have a fixed width. */
+                    width: view_column.fixed_width(),
+                })
+            })
+            .collect();
+        if let Some((gtk::SortColumn::Index(id), order)) = store.sort_column_id() {
+            layout.sort_column = list_column(id);
+            layout.sort_ascending = order == gtk::SortType::Ascending;
+        }
+    }
+
+    /// Updates the row of glyph `name`, if the list is shown.
+    pub(super) fn update_list_row(&self, name: &str) {
+        let Some(store) = self.list_store.get() else {
+            return;
+        };
+        if self.layout.borrow().mode != ViewMode::List {
+            return;
+        }
+        let Some(glyph) = self.project().default_layer.glyphs().get(name).cloned() else {
+            return;
+        };
+        let info = GlyphInfo::new(&glyph.borrow());
+        store.foreach(|model, _, iter| {
+            if model
+                .value(iter, text_column(ListColumn::Name) as i32)
+                .get::<String>()
+                .ok()
+                .as_deref()
+                != Some(name)
+            {
+                return false;
+            }
+            for column in ListColumn::ALL {
+                let (text, key) = column.value(&info);
+                store.set_value(iter, text_column(column), &text.to_value());
+                store.set_value(iter, text_column(column) + 1, &key.to_value());
+            }
+            true
+        });
+    }
+
+    /// Fills the list with the glyphs that pass the filters, if the list is shown.
+    pub(super) fn update_list(&self) {
+        let Some(store) = self.list_store.get() else {
+            return;
+        };
+        if self.layout.borrow().mode != ViewMode::List {
+            return;
+        }
+        let hide_empty = self.hide_empty.get();
+        let show_blocks = self.show_blocks.borrow();
+        let query = self.filter_query.borrow();
+        store.clear();
+        for c in self.widgets.borrow().iter() {
+            if !is_shown(c, hide_empty, &show_blocks, &query) {
+                continue;
+            }
+            let info = GlyphInfo::new(&c.imp().glyph.get().unwrap().borrow());
+            let values = ListColumn::ALL.map(|column| column.value(&info));
+            let mut row: Vec<(u32, &dyn ToValue)> = vec![];
+            for (column, (text, key)) in ListColumn::ALL.iter().zip(values.iter()) {
+                row.push((text_column(*column), text));
+                row.push((text_column(*column) + 1, key));
+            }
+            store.insert_with_values(None, &row);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Character set coverage report of a project.
+
+use crate::glyphs::GlyphKind;
+use crate::prelude::*;
+use crate::unicode::charsets::{Charset, Coverage};
+use crate::unicode::names::CharName;
+use crate::views::Collection;
+
+/// All characters mapped by the glyphs of the project's default layer.
+pub fn project_chars(project: &Project) -> HashSet<char> {
+    let mut ret = HashSet::default();
+    for glyph in project.default_layer.glyphs().values() {
+        let glyph = glyph.borrow();
+        let kinds = glyph.kinds();
+        for kind in std::iter::once(&kinds.0).chain(kinds.1.iter()) {
+            if let GlyphKind::Char(c) = kind {
+                ret.insert(*c);
+            }
+        }
+    }
+    ret
+}
+
+fn new_char_list() -> (gtk::ScrolledWindow, gtk::ListStore) {
+    /* codepoint, character, name */
+    let store = gtk::ListStore::new(&[
+        String::static_type(),
+        String::static_type(),
+        String::static_type(),
+    ]);
+    let treeview = gtk::TreeView::builder()
+        .model(&store)
+        .headers_visible(true)
+        .visible(true)
+        .expand(true)
+        .build();
+    for (i, n) in [(0, "codepoint"), (1, "character"), (2, "name")] {
+        let column = gtk::TreeViewColumn::new();
+        column.set_title(n);
+        column.set_resizable(true);
+        column.set_sort_column_id(i);
+        let cell = gtk::CellRendererText::new();
+
+        column.pack_start(&cell, true);
+        column.add_attribute(&cell, "text", i);
+        treeview.append_column(&column);
+    }
+    let scrolled_window = gtk::ScrolledWindow::builder()
+        .expand(true)
+        .visible(true)
+        .can_focus(true)
+        .build();
+    scrolled_window.set_child(Some(&treeview));
+    (scrolled_window, store)
+}
+
+fn fill_char_list(store: &gtk::ListStore, chars: &[char]) {
+    store.clear();
+    for c in chars {
+        store.insert_with_values(
+            None,
+            &[
+                (0, &format!("U+{:04X}", *c as u32)),
+                (1, &c.to_string()),
+                (2, &c.char_name().map(|n| n.to_string()).unwrap_or_default()),
+            ],
+        );
+    }
+}
+
+/// Shows a window that checks the project of `collection` against the built-in and
+/// user-defined character sets, and can create placeholder glyphs for missing characters.
+pub fn show_coverage_window(collection: &Collection) {
+    let app = collection.app().clone();
+    let charsets: Rc<RefCell<Vec<Charset>>> = Rc::new(RefCell::new(
+        Charset::builtin()
+            .into_iter()
+            .chain(Charset::user_defined())
+            .collect(),
+    ));
+    let coverage: Rc<RefCell<Coverage>> = Rc::new(RefCell::new(Coverage::default()));
+    let charset_combo = gtk::ComboBoxText::builder().visible(true).build();
+    for c in charsets.borrow().iter() {
+        charset_combo.append_text(&c.name);
+    }
+    let load_button = gtk::Button::builder()
+        .label("Load list…")
+        .tooltip_text("Load a character list, one character, codepoint or range per line")
+        .visible(true)
+        .build();
+    let create_button = gtk::Button::builder()
+        .label("Create placeholders for all missing")
+        .visible(true)
+        .sensitive(false)
+        .build();
+    let summary = gtk::Label::builder()
+        .visible(true)
+        .selectable(true)
+        .halign(gtk::Align::Start)
+        .build();
+    let (missing_window, missing_store) = new_char_list();
+    let (covered_window, covered_store) = new_char_list();
+    let notebook = gtk::Notebook::builder().visible(true).expand(true).build();
+    let missing_label = gtk::Label::builder().label("Missing").visible(true).build();
+    let covered_label = gtk::Label::builder().label("Covered").visible(true).build();
+    notebook.append_page(&missing_window, Some(&missing_label));
+    notebook.append_page(&covered_window, Some(&covered_label));
+
+    let update = Rc::new(
+        clone!(@weak collection, @weak charset_combo, @weak summary, @weak missing_store, @weak covered_store, @weak create_button, @strong charsets, @strong coverage => move || {
+            let Some(idx) = charset_combo.active().map(|i| i as usize) else {
+                return;
+            };
+            let charsets = charsets.borrow();
+            let Some(charset) = charsets.get(idx) else {
+                return;
+            };
+            let result = charset.coverage(&project_chars(collection.project()));
+            summary.set_text(&format!(
+                "Covered {} of {} ({:.1}%), {} missing.",
+                result.covered.len(),
+                charset.chars.len(),
+                result.percentage(),
+                result.missing.len()
+            ));
+            fill_char_list(&missing_store, &result.missing);
+            fill_char_list(&covered_store, &result.covered);
+            create_button.set_sensitive(!result.missing.is_empty());
+            *coverage.borrow_mut() = result;
+        }),
+    );
+    charset_combo.connect_changed(clone!(@strong update => move |_| {
+        update();
+    }));
+    load_button.connect_clicked(
+        clone!(@weak app, @weak charset_combo, @strong charsets => move |_| {
+            let dialog = gtk::FileChooserNative::new(
+                Some("Load character list..."),
+                Some(&app.window),
+                gtk::FileChooserAction::Open,
+                None,
+                None,
+            );
+            crate::return_if_not_ok_or_accept!(dialog.run());
+            let Some(path) = dialog.filename() else { return; };
+            dialog.hide();
+            match Charset::from_file(&path) {
+                Ok(charset) => {
+                    charset_combo.append_text(&charset.name);
+                    let idx = {
+                        let mut charsets = charsets.borrow_mut();
+                        charsets.push(charset);
+                        charsets.len() - 1
+                    };
+                    charset_combo.set_active(Some(idx as u32));
+                }
+                Err(err) => {
+                    let dialog = crate::utils::widgets::new_simple_error_dialog(
+                        Some("Error: Could not load character list."),
+                        &err.to_string(),
+                        None,
+                        app.window.upcast_ref(),
+                    );
+                    dialog.run();
+                    dialog.emit_close();
+                }
+            }
+        }),
+    );
+    create_button.connect_clicked(
+        clone!(@weak collection, @strong coverage, @strong update => move |_| {
+            let project = collection.project();
+            let missing = coverage.borrow().missing.clone();
+            for c in missing {
+                let glyph = match project.new_placeholder_glyph(c) {
+                    Ok(Some(glyph)) => glyph,
+                    /* A glyph with the same name that does not map the character. */
+                    Ok(None) => continue,
+                    Err(err) => {
+                        let dialog = crate::utils::widgets::new_simple_error_dialog(
+                            Some("Error: Could not create glyph."),
+                            &err.to_string(),
+                            None,
+                            collection.app().window.upcast_ref(),
+                        );
+                        dialog.run();
+                        dialog.emit_close();
+                        break;
+                    }
+                };
+                let metadata = glyph.borrow().metadata.clone();
+                collection.emit_by_name::<()>(Collection::NEW_GLYPH, &[&metadata]);
+            }
+            update();
+        }),
+    );
+
+    let toolbar = gtk::Box::builder()
+        .orientation(gtk::Orientation::Horizontal)
+        .spacing(5)
+        .visible(true)
+        .build();
+    toolbar.pack_start(&charset_combo, true, true, 0);
+    toolbar.pack_start(&load_button, false, false, 0);
+    toolbar.pack_end(&create_button, false, false, 0);
+    let vbox = gtk::Box::builder()
+        .orientation(gtk::Orientation::Vertical)
+        .spacing(5)
+        .margin(10)
+        .visible(true)
+        .build();
+    vbox.pack_start(&toolbar, false, false, 0);
+    vbox.pack_start(&summary, false, false, 0);
+    vbox.pack_start(&notebook, true, true, 0);
+    let window = gtk::Window::builder()
+        .title("Character set coverage")
+        .attached_to(&app.window)
+        .transient_for(&app.window)
+        .default_width(600)
+        .default_height(600)
+        .child(&vbox)
+        .build();
+    charset_combo.set_active(Some(0));
+    window.show_all();
+}
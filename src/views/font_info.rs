@@ -0,0 +1,1080 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Font info window: editors for all fields of `fontinfo.plist`, grouped in tabs per table.
+//!
+//! Changes are kept in the widgets until they are applied; the window then validates the changed
+//! fields against the UFO 3 specification and replaces the project's [`FontInfo`] source in one
+//! undoable step.
+
+use crate::prelude::*;
+use crate::ufo::{
+    self, opentype, NameRecord, OpenTypeGaspRangeRecord, PostscriptWindowsCharacterSet,
+    RangeGaspBehavior,
+};
+use std::str::FromStr;
+
+/// Writes the value of an editor widget into the edited fontinfo if it differs from the field's
+/// current value, and returns whether it did, or returns why it can't.
+type Commit = Box<dyn Fn(&mut ufo::FontInfo) -> Result<bool, String>>;
+
+/// Fields that are lists of bit numbers. The optional ones can be left undefined, which is not
+/// the same as an empty list.
+trait BitList {
+    const OPTIONAL: bool;
+
+    fn bits(&self) -> Option<&Vec<u32>>;
+
+    fn set_bits(&mut self, bits: Option<Vec<u32>>);
+}
+
+impl BitList for Vec<u32> {
+    const OPTIONAL: bool = false;
+
+    fn bits(&self) -> Option<&Vec<u32>> {
+        Some(self)
+    }
+
+    fn set_bits(&mut self, bits: Option<Vec<u32>>) {
+        *self = bits.unwrap_or_default();
+    }
+}
+
+impl BitList for Option<Vec<u32>> {
+    const OPTIONAL: bool = true;
+
+    fn bits(&self) -> Option<&Vec<u32>> {
+        self.as_ref()
+    }
+
+    fn set_bits(&mut self, bits: Option<Vec<u32>>) {
+        *self = bits;
+    }
+}
+
+/// A tab of the window: a grid of labelled field editors.
+struct Page {
+    grid: gtk::Grid,
+    row: Cell<i32>,
+    info: RefCell<ufo::FontInfo>,
+    commits: Rc<RefCell<Vec<(&'static str, Commit)>>>,
+}
+
+impl Page {
+    fn new(
+        notebook: &gtk::Notebook,
+        title: &str,
+        info: &ufo::FontInfo,
+        commits: &Rc<RefCell<Vec<(&'static str, Commit)>>>,
+    ) -> Self {
+        let grid = gtk::Grid::builder()
+            .column_spacing(10)
+            .row_spacing(5)
+            .margin(10)
+            .visible(true)
+            .build();
+        let scrolled_window = gtk::ScrolledWindow::builder()
+            .expand(true)
+            .visible(true)
+            .hscrollbar_policy(gtk::PolicyType::Never)
+            .build();
+        scrolled_window.set_child(Some(&grid));
+        let label = gtk::Label::builder().label(title).visible(true).build();
+        notebook.append_page(&scrolled_window, Some(&label));
+        Self {
+            grid,
+            row: Cell::new(0),
+            info: RefCell::new(info.clone()),
+            commits: commits.clone(),
+        }
+    }
+
+    fn section(&self, title: &str) {
+        let label = gtk::Label::builder()
+            .label(&format!("<b>{title}</b>"))
+            .use_markup(true)
+            .halign(gtk::Align::Start)
+            .margin_top(if self.row.get() == 0 { 0 } else { 10 })
+            .visible(true)
+            .build();
+        self.grid.attach(&label, 0, self.row.get(), 2, 1);
+        self.row.set(self.row.get() + 1);
+    }
+
+    /// Adds `widget` with a label showing `title`, and the fontinfo.plist `key` as a tooltip.
+    fn attach(&self, title: &str, key: &str, widget: &impl IsA<gtk::Widget>) {
+        let label = gtk::Label::builder()
+            .label(title)
+            .tooltip_text(key)
+            .halign(gtk::Align::End)
+            .valign(gtk::Align::Start)
+            .visible(true)
+            .build();
+        widget.set_tooltip_text(Some(key));
+        widget.set_hexpand(true);
+        widget.set_visible(true);
+        self.grid.attach(&label, 0, self.row.get(), 1, 1);
+        self.grid.attach(widget, 1, self.row.get(), 1, 1);
+        self.row.set(self.row.get() + 1);
+    }
+
+    fn commit(
+        &self,
+        key: &'static str,
+        commit: impl Fn(&mut ufo::FontInfo) -> Result<bool, String> + 'static,
+    ) {
+        self.commits.borrow_mut().push((key, Box::new(commit)));
+    }
+
+    /// A single line entry. Fields are only written if the entry's text differs from the text
+    /// of the field's current value, so that values round-trip unchanged.
+    fn entry(
+        &self,
+        title: &str,
+        key: &'static str,
+        current: impl Fn(&mut ufo::FontInfo) -> String + 'static,
+        parse: impl Fn(&mut ufo::FontInfo, &str) -> Result<(), String> + 'static,
+    ) {
+        let entry = gtk::Entry::builder()
+            .text(&current(&mut self.info.borrow_mut()))
+            .build();
+        self.attach(title, key, &entry);
+        self.commit(key, move |info| {
+            let text = entry.text();
+            if text.as_str() == current(info) {
+                return Ok(false);
+            }
+            parse(info, text.trim()).map_err(|err| format!("{key}: {err}"))?;
+            Ok(true)
+        });
+    }
+
+    fn string(&self, title: &str, key: &'static str, field: fn(&mut ufo::FontInfo) -> &mut String) {
+        let current = move |info: &mut ufo::FontInfo| field(info).clone();
+        self.entry(title, key, current, move |info, text| {
+            *field(info) = text.to_string();
+            Ok(())
+        });
+    }
+
+    fn opt_string(
+        &self,
+        title: &str,
+        key: &'static str,
+        field: fn(&mut ufo::FontInfo) -> &mut Option<String>,
+    ) {
+        let current = move |info: &mut ufo::FontInfo| field(info).clone().unwrap_or_default();
+        self.entry(title, key, current, move |info, text| {
+            *field(info) = Some(text.to_string()).filter(|t| !t.is_empty());
+            Ok(())
+        });
+    }
+
+    /// A multi-line text field.
+    fn text(
+        &self,
+        title: &str,
+        key: &'static str,
+        field: fn(&mut ufo::FontInfo) -> &mut Option<String>,
+    ) {
+        let current = move |info: &mut ufo::FontInfo| field(info).clone().unwrap_or_default();
+        let text_view = gtk::TextView::builder()
+            .wrap_mode(gtk::WrapMode::WordChar)
+            .visible(true)
+            .build();
+        let buffer = text_view.buffer().unwrap();
+        buffer.set_text(&current(&mut self.info.borrow_mut()));
+        let frame = gtk::Frame::builder()
+            .child(&text_view)
+            .height_request(60)
+            .build();
+        self.attach(title, key, &frame);
+        self.commit(key, move |info| {
+            let text = buffer
+                .text(&buffer.start_iter(), &buffer.end_iter(), false)
+                .map(|t| t.to_string())
+                .unwrap_or_default();
+            if text == current(info) {
+                return Ok(false);
+            }
+            *field(info) = Some(text).filter(|t| !t.is_empty());
+            Ok(true)
+        });
+    }
+
+    fn number<T: FromStr + ToString + 'static>(
+        &self,
+        title: &str,
+        key: &'static str,
+        field: fn(&mut ufo::FontInfo) -> &mut Option<T>,
+    ) {
+        let current = move |info: &mut ufo::FontInfo| {
+            field(info).as_ref().map(T::to_string).unwrap_or_default()
+        };
+        self.entry(title, key, current, move |info, text| {
+            *field(info) = if text.is_empty() {
+                None
+            } else {
+                Some(
+                    text.parse()
+                        .map_err(|_| format!("{text:?} is not a valid number."))?,
+                )
+            };
+            Ok(())
+        });
+    }
+
+    /// A list of numbers, separated by spaces or commas.
+    fn numbers<T: FromStr + ToString + 'static>(
+        &self,
+        title: &str,
+        key: &'static str,
+        field: fn(&mut ufo::FontInfo) -> &mut Option<Vec<T>>,
+    ) {
+        let current = move |info: &mut ufo::FontInfo| {
+            field(info)
+                .as_ref()
+                .map(|list| {
+                    list.iter()
+                        .map(T::to_string)
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                })
+                .unwrap_or_default()
+        };
+        self.entry(title, key, current, move |info, text| {
+            *field(info) = if text.is_empty() {
+                None
+            } else {
+                Some(
+                    text.split(|c: char| c == ',' || c.is_whitespace())
+                        .filter(|n| !n.is_empty())
+                        .map(|n| {
+                            n.parse()
+                                .map_err(|_| format!("{n:?} is not a valid number."))
+                        })
+                        .collect::<Result<Vec<T>, String>>()?,
+                )
+            };
+            Ok(())
+        });
+    }
+
+    fn choice(
+        &self,
+        title: &str,
+        key: &'static str,
+        choices: &[&str],
+        field: fn(&mut ufo::FontInfo) -> &mut String,
+    ) {
+        let initial = field(&mut self.info.borrow_mut()).clone();
+        let combo = gtk::ComboBoxText::new();
+        for c in choices {
+            combo.append_text(c);
+        }
+        if !choices.contains(&initial.as_str()) {
+            combo.append_text(&initial);
+        }
+        combo.set_active(
+            choices
+                .iter()
+                .position(|c| *c == initial)
+                .or(Some(choices.len()))
+                .map(|i| i as u32),
+        );
+        self.attach(title, key, &combo);
+        self.commit(key, move |info| {
+            let value = combo
+                .active_text()
+                .map(|t| t.to_string())
+                .unwrap_or_default();
+            if *field(info) == value {
+                return Ok(false);
+            }
+            *field(info) = value;
+            Ok(true)
+        });
+    }
+
+    fn boolean(
+        &self,
+        title: &str,
+        key: &'static str,
+        field: fn(&mut ufo::FontInfo) -> &mut Option<bool>,
+    ) {
+        let combo = gtk::ComboBoxText::new();
+        for c in ["Undefined", "Yes", "No"] {
+            combo.append_text(c);
+        }
+        combo.set_active(Some(match field(&mut self.info.borrow_mut()) {
+            None => 0,
+            Some(true) => 1,
+            Some(false) => 2,
+        }));
+        self.attach(title, key, &combo);
+        self.commit(key, move |info| {
+            let value = match combo.active() {
+                Some(1) => Some(true),
+                Some(2) => Some(false),
+                _ => None,
+            };
+            if *field(info) == value {
+                return Ok(false);
+            }
+            *field(info) = value;
+            Ok(true)
+        });
+    }
+
+    /// A checklist of the bits in `table`. Bits that are set but not in `table` are listed too,
+    /// so that they can be cleared.
+    fn bits<B: BitList + 'static>(
+        &self,
+        title: &str,
+        key: &'static str,
+        table: &'static [(u32, &'static str)],
+        field: fn(&mut ufo::FontInfo) -> &mut B,
+    ) {
+        let current = move |info: &mut ufo::FontInfo| {
+            field(info).bits().map(|bits| {
+                let mut bits = bits.clone();
+                bits.sort_unstable();
+                bits.dedup();
+                bits
+            })
+        };
+        let initial = current(&mut self.info.borrow_mut());
+        let set = initial.clone().unwrap_or_default();
+        let flow_box = gtk::FlowBox::builder()
+            .selection_mode(gtk::SelectionMode::None)
+            .homogeneous(true)
+            .min_children_per_line(2)
+            .max_children_per_line(3)
+            .visible(true)
+            .build();
+        let checks: Vec<(u32, gtk::CheckButton)> = table
+            .iter()
+            .map(|(bit, name)| (*bit, format!("{bit}: {name}")))
+            .chain(
+                opentype::invalid_bits(&set, table)
+                    .into_iter()
+                    .map(|bit| (bit, format!("{bit}: (undefined)"))),
+            )
+            .map(|(bit, label)| {
+                let check = gtk::CheckButton::builder()
+                    .label(&label)
+                    .active(set.contains(&bit))
+                    .visible(true)
+                    .build();
+                flow_box.add(&check);
+                (bit, check)
+            })
+            .collect();
+        let vbox = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .spacing(2)
+            .build();
+        let defined = gtk::CheckButton::builder()
+            .label("Defined")
+            .active(initial.is_some())
+            .visible(B::OPTIONAL)
+            .no_show_all(!B::OPTIONAL)
+            .build();
+        defined
+            .bind_property("active", &flow_box, "sensitive")
+            .flags(glib::BindingFlags::SYNC_CREATE)
+            .build();
+        vbox.pack_start(&defined, false, false, 0);
+        if table.len() > 16 {
+            let scrolled_window = gtk::ScrolledWindow::builder()
+                .height_request(200)
+                .visible(true)
+                .build();
+            scrolled_window.set_child(Some(&flow_box));
+            vbox.pack_start(&scrolled_window, true, true, 0);
+        } else {
+            vbox.pack_start(&flow_box, true, true, 0);
+        }
+        self.attach(title, key, &vbox);
+        self.commit(key, move |info| {
+            let bits = defined.is_active().then(|| {
+                let mut bits = checks
+                    .iter()
+                    .filter(|(_, c)| c.is_active())
+                    .map(|(b, _)| *b)
+                    .collect::<Vec<u32>>();
+                bits.sort_unstable();
+                bits
+            });
+            if bits == current(info) {
+                return Ok(false);
+            }
+            field(info).set_bits(bits);
+            Ok(true)
+        });
+    }
+
+    fn windows_character_set(&self) {
+        let combo = gtk::ComboBoxText::new();
+        combo.append_text("Undefined");
+        for c in PostscriptWindowsCharacterSet::ALL {
+            combo.append_text(&format!("{}: {:?}", c as u8, c));
+        }
+        combo.set_active(Some(
+            self.info
+                .borrow()
+                .postscript_windows_character_set
+                .map(|c| c as u32)
+                .unwrap_or(0),
+        ));
+        self.attach(
+            "Windows character set",
+            "postscriptWindowsCharacterSet",
+            &combo,
+        );
+        self.commit("postscriptWindowsCharacterSet", move |info| {
+            let value = combo
+                .active()
+                .filter(|i| *i > 0)
+                .and_then(|i| PostscriptWindowsCharacterSet::try_from(i as u8).ok());
+            if info.postscript_windows_character_set == value {
+                return Ok(false);
+            }
+            info.postscript_windows_character_set = value;
+            Ok(true)
+        });
+    }
+
+    /// An editable list of records, with one column per entry of `columns`.
+    fn list(&self, title: &str, key: &str, columns: &[(&str, glib::Type)]) -> gtk::ListStore {
+        let store = gtk::ListStore::new(&columns.iter().map(|(_, t)| *t).collect::<Vec<_>>());
+        let tree = gtk::TreeView::builder()
+            .model(&store)
+            .headers_visible(true)
+            .reorderable(true)
+            .visible(true)
+            .expand(true)
+            .build();
+        tree.set_grid_lines(gtk::TreeViewGridLines::Both);
+        for (i, (name, type_)) in columns.iter().enumerate() {
+            let column = gtk::TreeViewColumn::new();
+            column.set_title(name);
+            column.set_resizable(true);
+            if *type_ == bool::static_type() {
+                let cell = gtk::CellRendererToggle::new();
+                cell.set_activatable(true);
+                cell.connect_toggled(clone!(@weak store => move |_, treepath| {
+                    if let Some(iter) = store.iter(&treepath) {
+                        let value: bool = store.value(&iter, i as i32).get().unwrap();
+                        store.set_value(&iter, i as u32, &(!value).to_value());
+                    }
+                }));
+                column.pack_start(&cell, false);
+                column.add_attribute(&cell, "active", i as i32);
+            } else {
+                let cell = gtk::CellRendererText::new();
+                cell.set_editable(true);
+                cell.connect_edited(clone!(@weak store => move |_, treepath, text| {
+                    if let Some(iter) = store.iter(&treepath) {
+                        store.set_value(&iter, i as u32, &text.to_value());
+                    }
+                }));
+                column.set_expand(i + 1 == columns.len());
+                column.pack_start(&cell, true);
+                column.add_attribute(&cell, "text", i as i32);
+            }
+            tree.append_column(&column);
+        }
+        let scrolled_window = gtk::ScrolledWindow::builder()
+            .height_request(200)
+            .expand(true)
+            .visible(true)
+            .build();
+        scrolled_window.set_child(Some(&tree));
+        let add_button = gtk::Button::builder().label("Add").visible(true).build();
+        add_button.connect_clicked(clone!(@weak store, @weak tree => move |_| {
+            let iter = store.append();
+            if let Some(path) = store.path(&iter) {
+                tree.set_cursor(&path, tree.column(0).as_ref(), true);
+            }
+        }));
+        let remove_button = gtk::Button::builder().label("Remove").visible(true).build();
+        remove_button.connect_clicked(clone!(@weak store, @weak tree => move |_| {
+            if let Some((_, iter)) = tree.selection().selected() {
+                store.remove(&iter);
+            }
+        }));
+        let buttons = gtk::Box::builder()
+            .orientation(gtk::Orientation::Horizontal)
+            .spacing(5)
+            .visible(true)
+            .build();
+        buttons.pack_start(&add_button, false, false, 0);
+        buttons.pack_start(&remove_button, false, false, 0);
+        let vbox = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .spacing(5)
+            .build();
+        vbox.pack_start(&scrolled_window, true, true, 0);
+        vbox.pack_start(&buttons, false, false, 0);
+        self.attach(title, key, &vbox);
+        store
+    }
+
+    fn name_records(&self) {
+        let store = self.list(
+            "Name records",
+            "openTypeNameRecords",
+            &[
+                ("nameID", String::static_type()),
+                ("platformID", String::static_type()),
+                ("encodingID", String::static_type()),
+                ("languageID", String::static_type()),
+                ("string", String::static_type()),
+            ],
+        );
+        for r in self.info.borrow().open_type_name_records.iter().flatten() {
+            let id = |id: Option<u64>| id.map(|id| id.to_string()).unwrap_or_default();
+            store.insert_with_values(
+                None,
+                &[
+                    (0, &id(r.name_id)),
+                    (1, &id(r.platform_id)),
+                    (2, &id(r.encoding_id)),
+                    (3, &id(r.language_id)),
+                    (4, &r.string.clone().unwrap_or_default()),
+                ],
+            );
+        }
+        self.commit("openTypeNameRecords", move |info| {
+            let mut records = vec![];
+            for (i, row) in rows(&store).into_iter().enumerate() {
+                let id = |col: usize| -> Result<Option<u64>, String> {
+                    let value: String = row[col].get().unwrap_or_default();
+                    if value.trim().is_empty() {
+                        return Ok(None);
+                    }
+                    value.trim().parse().map(Some).map_err(|_| {
+                        format!(
+                            "openTypeNameRecords: record {}: {:?} is not a valid ID.",
+                            i + 1,
+                            value
+                        )
+                    })
+                };
+                records.push(NameRecord {
+                    name_id: id(0)?,
+                    platform_id: id(1)?,
+                    encoding_id: id(2)?,
+                    language_id: id(3)?,
+                    string: row[4].get().ok(),
+                });
+            }
+            let records = if records.is_empty() && info.open_type_name_records.is_none() {
+                None
+            } else {
+                Some(records)
+            };
+            if records == info.open_type_name_records {
+                return Ok(false);
+            }
+            info.open_type_name_records = records;
+            Ok(true)
+        });
+    }
+
+    fn gasp_range_records(&self) {
+        let columns = std::iter::once(("rangeMaxPPEM", String::static_type()))
+            .chain(
+                opentype::GASP_BEHAVIOR
+                    .iter()
+                    .map(|(_, name)| (*name, bool::static_type())),
+            )
+            .collect::<Vec<_>>();
+        let store = self.list("Range records", "openTypeGaspRangeRecords", &columns);
+        for r in self
+            .info
+            .borrow()
+            .open_type_gasp_range_records
+            .iter()
+            .flatten()
+        {
+            let iter = store.append();
+            store.set_value(&iter, 0, &r.range_max_ppem.to_string().to_value());
+            for b in &r.range_gasp_behavior {
+                store.set_value(&iter, *b as u32 + 1, &true.to_value());
+            }
+        }
+        self.commit("openTypeGaspRangeRecords", move |info| {
+            let mut records = vec![];
+            for (i, row) in rows(&store).into_iter().enumerate() {
+                let ppem: String = row[0].get().unwrap_or_default();
+                records.push(OpenTypeGaspRangeRecord {
+                    range_max_ppem: ppem.trim().parse().map_err(|_| {
+                        format!(
+                            "openTypeGaspRangeRecords: record {}: {:?} is not a valid PPEM in the range 0-65535.",
+                            i + 1,
+                            ppem
+                        )
+                    })?,
+                    range_gasp_behavior: RangeGaspBehavior::ALL
+                        .into_iter()
+                        .filter(|b| row[*b as usize + 1].get().unwrap_or(false))
+                        .collect(),
+                });
+            }
+            let records = if records.is_empty() && info.open_type_gasp_range_records.is_none() {
+                None
+            } else {
+                Some(records)
+            };
+            if records == info.open_type_gasp_range_records {
+                return Ok(false);
+            }
+            info.open_type_gasp_range_records = records;
+            Ok(true)
+        });
+    }
+}
+
+/// The values of all rows of `store`.
+fn rows(store: &gtk::ListStore) -> Vec<Vec<glib::Value>> {
+    let mut ret = vec![];
+    let Some(iter) = store.iter_first() else {
+        return ret;
+    };
+    loop {
+        ret.push(
+            (0..store.n_columns())
+                .map(|col| store.value(&iter, col))
+                .collect(),
+        );
+        if !store.iter_next(&iter) {
+            break;
+        }
+    }
+    ret
+}
+
+/// Shows a window that edits the `fontinfo.plist` of `project`.
+pub fn show_font_info_window(app: &Application, project: &Project) {
+    let fontinfo = project.fontinfo().clone();
+    let info = fontinfo.source.borrow().clone();
+    let commits: Rc<RefCell<Vec<(&'static str, Commit)>>> = Rc::new(RefCell::new(vec![]));
+    let notebook = gtk::Notebook::builder()
+        .scrollable(true)
+        .visible(true)
+        .expand(true)
+        .build();
+
+    let page = Page::new(&notebook, "General", &info, &commits);
+    page.section("Identification");
+    page.string("Family name", "familyName", |i| &mut i.family_name);
+    page.string("Style name", "styleName", |i| &mut i.style_name);
+    page.string("Style map family name", "styleMapFamilyName", |i| {
+        &mut i.style_map_family_name
+    });
+    page.choice(
+        "Style map style name",
+        "styleMapStyleName",
+        &["", "regular", "italic", "bold", "bold italic"],
+        |i| &mut i.style_map_style_name,
+    );
+    page.number("Major version", "versionMajor", |i| &mut i.version_major);
+    page.number("Minor version", "versionMinor", |i| &mut i.version_minor);
+    page.number("Year", "year", |i| &mut i.year);
+    page.section("Legal");
+    page.string("Copyright", "copyright", |i| &mut i.copyright);
+    page.string("Trademark", "trademark", |i| &mut i.trademark);
+    page.section("Dimensions");
+    page.number("Units per em", "unitsPerEm", |i| &mut i.units_per_em);
+    page.number("Descender", "descender", |i| &mut i.descender);
+    page.number("x-height", "xHeight", |i| &mut i.x_height);
+    page.number("Cap height", "capHeight", |i| &mut i.cap_height);
+    page.number("Ascender", "ascender", |i| &mut i.ascender);
+    page.number("Italic angle", "italicAngle", |i| &mut i.italic_angle);
+    page.section("Miscellaneous");
+    page.text("Note", "note", |i| &mut i.note);
+
+    let page = Page::new(&notebook, "head, hhea & vhea", &info, &commits);
+    page.section("head table");
+    page.opt_string(
+        "Created (YYYY/MM/DD HH:MM:SS)",
+        "openTypeHeadCreated",
+        |i| &mut i.open_type_head_created,
+    );
+    page.number(
+        "Lowest recommended PPEM",
+        "openTypeHeadLowestRecPPEM",
+        |i| &mut i.open_type_head_lowest_rec_ppem,
+    );
+    page.bits("Flags", "openTypeHeadFlags", opentype::HEAD_FLAGS, |i| {
+        &mut i.open_type_head_flags
+    });
+    page.section("hhea table");
+    page.number("Ascender", "openTypeHheaAscender", |i| {
+        &mut i.open_type_hhea_ascender
+    });
+    page.number("Descender", "openTypeHheaDescender", |i| {
+        &mut i.open_type_hhea_descender
+    });
+    page.number("Line gap", "openTypeHheaLineGap", |i| {
+        &mut i.open_type_hhea_line_gap
+    });
+    page.number("Caret slope rise", "openTypeHheaCaretSlopeRise", |i| {
+        &mut i.open_type_hhea_caret_slope_rise
+    });
+    page.number("Caret slope run", "openTypeHheaCaretSlopeRun", |i| {
+        &mut i.open_type_hhea_caret_slope_run
+    });
+    page.number("Caret offset", "openTypeHheaCaretOffset", |i| {
+        &mut i.open_type_hhea_caret_offset
+    });
+    page.section("vhea table");
+    page.number("Ascender", "openTypeVheaVertTypoAscender", |i| {
+        &mut i.open_type_vhea_vert_typo_ascender
+    });
+    page.number("Descender", "openTypeVheaVertTypoDescender", |i| {
+        &mut i.open_type_vhea_vert_typo_descender
+    });
+    page.number("Line gap", "openTypeVheaVertTypoLineGap", |i| {
+        &mut i.open_type_vhea_vert_typo_line_gap
+    });
+    page.number("Caret slope rise", "openTypeVheaCaretSlopeRise", |i| {
+        &mut i.open_type_vhea_caret_slope_rise
+    });
+    page.number("Caret slope run", "openTypeVheaCaretSlopeRun", |i| {
+        &mut i.open_type_vhea_caret_slope_run
+    });
+    page.number("Caret offset", "openTypeVheaCaretOffset", |i| {
+        &mut i.open_type_vhea_caret_offset
+    });
+
+    let page = Page::new(&notebook, "Names", &info, &commits);
+    page.section("name table");
+    page.opt_string("Designer", "openTypeNameDesigner", |i| {
+        &mut i.open_type_name_designer
+    });
+    page.opt_string("Designer URL", "openTypeNameDesignerURL", |i| {
+        &mut i.open_type_name_designer_url
+    });
+    page.opt_string("Manufacturer", "openTypeNameManufacturer", |i| {
+        &mut i.open_type_name_manufacturer
+    });
+    page.opt_string("Manufacturer URL", "openTypeNameManufacturerURL", |i| {
+        &mut i.open_type_name_manufacturer_url
+    });
+    page.text("License", "openTypeNameLicense", |i| {
+        &mut i.open_type_name_license
+    });
+    page.opt_string("License URL", "openTypeNameLicenseURL", |i| {
+        &mut i.open_type_name_license_url
+    });
+    page.opt_string("Version", "openTypeNameVersion", |i| {
+        &mut i.open_type_name_version
+    });
+    page.opt_string("Unique ID", "openTypeNameUniqueID", |i| {
+        &mut i.open_type_name_unique_id
+    });
+    page.text("Description", "openTypeNameDescription", |i| {
+        &mut i.open_type_name_description
+    });
+    page.opt_string(
+        "Preferred family name",
+        "openTypeNamePreferredFamilyName",
+        |i| &mut i.open_type_name_preferred_family_name,
+    );
+    page.opt_string(
+        "Preferred subfamily name",
+        "openTypeNamePreferredSubfamilyName",
+        |i| &mut i.open_type_name_preferred_subfamily_name,
+    );
+    page.opt_string(
+        "Compatible full name",
+        "openTypeNameCompatibleFullName",
+        |i| &mut i.open_type_name_compatible_full_name,
+    );
+    page.text("Sample text", "openTypeNameSampleText", |i| {
+        &mut i.open_type_name_sample_text
+    });
+    page.opt_string("WWS family name", "openTypeNameWWSFamilyName", |i| {
+        &mut i.open_type_name_wws_family_name
+    });
+    page.opt_string("WWS subfamily name", "openTypeNameWWSSubfamilyName", |i| {
+        &mut i.open_type_name_wws_sub_family_name
+    });
+    page.section("Localized records");
+    page.name_records();
+
+    let page = Page::new(&notebook, "OS/2", &info, &commits);
+    page.section("Classification");
+    page.number("Width class (1-9)", "openTypeOS2WidthClass", |i| {
+        &mut i.open_type_os2_width_class
+    });
+    page.number("Weight class", "openTypeOS2WeightClass", |i| {
+        &mut i.open_type_os2_weight_class
+    });
+    page.bits(
+        "Selection",
+        "openTypeOS2Selection",
+        opentype::OS2_SELECTION,
+        |i| &mut i.open_type_os2_selection,
+    );
+    page.opt_string("Vendor ID", "openTypeOS2VendorID", |i| {
+        &mut i.open_type_os2_vendor_id
+    });
+    page.numbers("Panose (10 numbers)", "openTypeOS2Panose", |i| {
+        &mut i.open_type_os2_panose
+    });
+    page.numbers("Family class and subclass", "openTypeOS2FamilyClass", |i| {
+        &mut i.open_type_os2_family_class
+    });
+    page.bits("Embedding", "openTypeOS2Type", opentype::OS2_TYPE, |i| {
+        &mut i.open_type_os2_type
+    });
+    page.section("Metrics");
+    page.number("Typo ascender", "openTypeOS2TypoAscender", |i| {
+        &mut i.open_type_os2_typo_ascender
+    });
+    page.number("Typo descender", "openTypeOS2TypoDescender", |i| {
+        &mut i.open_type_os2_typo_descender
+    });
+    page.number("Typo line gap", "openTypeOS2TypoLineGap", |i| {
+        &mut i.open_type_os2_typo_line_gap
+    });
+    page.number("Win ascent", "openTypeOS2WinAscent", |i| {
+        &mut i.open_type_os2_win_ascent
+    });
+    page.number("Win descent", "openTypeOS2WinDescent", |i| {
+        &mut i.open_type_os2_win_descent
+    });
+    page.number("Subscript x size", "openTypeOS2SubscriptXSize", |i| {
+        &mut i.open_type_os2_subscript_xsize
+    });
+    page.number("Subscript y size", "openTypeOS2SubscriptYSize", |i| {
+        &mut i.open_type_os2_subscript_ysize
+    });
+    page.number("Subscript x offset", "openTypeOS2SubscriptXOffset", |i| {
+        &mut i.open_type_os2_subscript_xoffset
+    });
+    page.number("Subscript y offset", "openTypeOS2SubscriptYOffset", |i| {
+        &mut i.open_type_os2_subscript_yoffset
+    });
+    page.number("Superscript x size", "openTypeOS2SuperscriptXSize", |i| {
+        &mut i.open_type_os2_superscript_xsize
+    });
+    page.number("Superscript y size", "openTypeOS2SuperscriptYSize", |i| {
+        &mut i.open_type_os2_superscript_ysize
+    });
+    page.number(
+        "Superscript x offset",
+        "openTypeOS2SuperscriptXOffset",
+        |i| &mut i.open_type_os2_superscript_xoffset,
+    );
+    page.number(
+        "Superscript y offset",
+        "openTypeOS2SuperscriptYOffset",
+        |i| &mut i.open_type_os2_superscript_yoffset,
+    );
+    page.number("Strikeout size", "openTypeOS2StrikeoutSize", |i| {
+        &mut i.open_type_os2_strikeout_size
+    });
+    page.number("Strikeout position", "openTypeOS2StrikeoutPosition", |i| {
+        &mut i.open_type_os2_strikeout_position
+    });
+    page.section("Coverage");
+    page.bits(
+        "Unicode ranges",
+        "openTypeOS2UnicodeRanges",
+        opentype::OS2_UNICODE_RANGES,
+        |i| &mut i.open_type_os2_unicode_ranges,
+    );
+    page.bits(
+        "Code page ranges",
+        "openTypeOS2CodePageRanges",
+        opentype::OS2_CODE_PAGE_RANGES,
+        |i| &mut i.open_type_os2_code_page_ranges,
+    );
+
+    let page = Page::new(&notebook, "PostScript", &info, &commits);
+    page.section("Names");
+    page.opt_string("Font name", "postscriptFontName", |i| {
+        &mut i.postscript_font_name
+    });
+    page.opt_string("Full name", "postscriptFullName", |i| {
+        &mut i.postscript_full_name
+    });
+    page.opt_string("Weight name", "postscriptWeightName", |i| {
+        &mut i.postscript_weight_name
+    });
+    page.number("Unique ID", "postscriptUniqueID", |i| {
+        &mut i.postscript_unique_id
+    });
+    page.section("Metrics");
+    page.number("Slant angle", "postscriptSlantAngle", |i| {
+        &mut i.postscript_slant_angle
+    });
+    page.number("Underline thickness", "postscriptUnderlineThickness", |i| {
+        &mut i.postscript_underline_thickness
+    });
+    page.number("Underline position", "postscriptUnderlinePosition", |i| {
+        &mut i.postscript_underline_position
+    });
+    page.boolean("Fixed pitch", "postscriptIsFixedPitch", |i| {
+        &mut i.postscript_is_fixed_pitch
+    });
+    page.number("Default width", "postscriptDefaultWidthX", |i| {
+        &mut i.postscript_default_width_x
+    });
+    page.number("Nominal width", "postscriptNominalWidthX", |i| {
+        &mut i.postscript_nominal_width_x
+    });
+    page.section("Hinting");
+    page.numbers("Blue values", "postscriptBlueValues", |i| {
+        &mut i.postscript_blue_values
+    });
+    page.numbers("Other blues", "postscriptOtherBlues", |i| {
+        &mut i.postscript_other_blues
+    });
+    page.numbers("Family blues", "postscriptFamilyBlues", |i| {
+        &mut i.postscript_family_blues
+    });
+    page.numbers("Family other blues", "postscriptFamilyOtherBlues", |i| {
+        &mut i.postscript_family_other_blues
+    });
+    page.numbers("Horizontal stems", "postscriptStemSnapH", |i| {
+        &mut i.postscript_stem_snap_h
+    });
+    page.numbers("Vertical stems", "postscriptStemSnapV", |i| {
+        &mut i.postscript_stem_snap_v
+    });
+    page.number("Blue fuzz", "postscriptBlueFuzz", |i| {
+        &mut i.postscript_blue_fuzz
+    });
+    page.number("Blue shift", "postscriptBlueShift", |i| {
+        &mut i.postscript_blue_shift
+    });
+    page.number("Blue scale", "postscriptBlueScale", |i| {
+        &mut i.postscript_blue_scale
+    });
+    page.boolean("Force bold", "postscriptForceBold", |i| {
+        &mut i.postscript_force_bold
+    });
+    page.section("PFM");
+    page.opt_string("Default character", "postscriptDefaultCharacter", |i| {
+        &mut i.postscript_default_character
+    });
+    page.windows_character_set();
+
+    let page = Page::new(&notebook, "gasp", &info, &commits);
+    page.section("gasp table");
+    page.gasp_range_records();
+
+    let page = Page::new(&notebook, "WOFF & Macintosh", &info, &commits);
+    page.section("WOFF");
+    page.number("Major version", "woffMajorVersion", |i| {
+        &mut i.woff_major_version
+    });
+    page.number("Minor version", "woffMinorVersion", |i| {
+        &mut i.woff_minor_version
+    });
+    let label = gtk::Label::builder()
+        .label("WOFF metadata records are kept as they are in fontinfo.plist.")
+        .halign(gtk::Align::Start)
+        .wrap(true)
+        .visible(true)
+        .build();
+    page.grid.attach(&label, 0, page.row.get(), 2, 1);
+    page.row.set(page.row.get() + 1);
+    page.section("Macintosh FOND resource");
+    page.number("Family ID", "macintoshFONDFamilyID", |i| {
+        &mut i.macintosh_fond_family_id
+    });
+    page.opt_string("Name", "macintoshFONDName", |i| &mut i.macintosh_fond_name);
+
+    let window = gtk::Window::builder()
+        .title(&format!("Font info: {}", fontinfo.family_name.borrow()))
+        .attached_to(&app.window)
+        .transient_for(&app.window)
+        .default_width(700)
+        .default_height(700)
+        .build();
+    let apply_button = gtk::Button::builder().label("Apply").visible(true).build();
+    apply_button.connect_clicked(
+        clone!(@weak app, @weak fontinfo, @strong commits => move |_| {
+            let mut info = fontinfo.source.borrow().clone();
+            let mut changed = vec![];
+            let mut problems = vec![];
+            for (key, commit) in commits.borrow().iter() {
+                match commit(&mut info) {
+                    Ok(true) => changed.push(*key),
+                    Ok(false) => {}
+                    Err(err) => problems.push(err),
+                }
+            }
+            /* Fields that were already invalid and were left alone don't block applying. */
+            problems.extend(info.problems().into_iter().filter(|problem| {
+                changed
+                    .iter()
+                    .any(|key| problem.split(':').next() == Some(*key))
+            }));
+            if !problems.is_empty() {
+                let dialog = crate::utils::widgets::new_simple_error_dialog(
+                    Some("Error: Invalid font info."),
+                    &problems.join("\n"),
+                    None,
+                    app.window.upcast_ref(),
+                );
+                dialog.run();
+                dialog.emit_close();
+                return;
+            }
+            if changed.is_empty() {
+                return;
+            }
+            let mut action = fontinfo.set_source_action(info);
+            (action.redo)();
+            app.undo_db.borrow_mut().event(action);
+        }),
+    );
+    let close_button = gtk::Button::builder().label("Close").visible(true).build();
+    close_button.connect_clicked(clone!(@weak window => move |_| {
+        window.close();
+    }));
+    let buttons = gtk::Box::builder()
+        .orientation(gtk::Orientation::Horizontal)
+        .spacing(5)
+        .halign(gtk::Align::End)
+        .visible(true)
+        .build();
+    buttons.pack_start(&close_button, false, false, 0);
+    buttons.pack_start(&apply_button, false, false, 0);
+    let vbox = gtk::Box::builder()
+        .orientation(gtk::Orientation::Vertical)
+        .spacing(5)
+        .margin(10)
+        .visible(true)
+        .build();
+    vbox.pack_start(&notebook, true, true, 0);
+    vbox.pack_start(&buttons, false, false, 0);
+    window.set_child(Some(&vbox));
+    window.show_all();
+}
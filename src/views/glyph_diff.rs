@@ -0,0 +1,312 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Overlay of two versions of a glyph with their differences colour-coded.
+
+use crate::glyphs::{Glyph, GlyphDrawingOptions};
+use crate::prelude::*;
+use crate::ufo::glif::diff::{AnchorDiff, ComponentDiff, ContourDiff, GlifDiff, PointDiff};
+use crate::ufo::glif::{self, Glif};
+use crate::ufo::objects::Layer;
+use gtk::cairo::{Context, Matrix};
+
+// [ref:hardcoded_color_value]
+const OLD_COLOR: Color = Color::from_hex("#d62728");
+// [ref:hardcoded_color_value]
+const NEW_COLOR: Color = Color::from_hex("#2ca02c");
+// [ref:hardcoded_color_value]
+const MOVED_COLOR: Color = Color::from_hex("#ff7f0e");
+
+#[derive(Debug, Default)]
+pub struct GlyphDiffViewInner {
+    app: OnceCell<Application>,
+    units_per_em: Cell<f64>,
+    old: RefCell<Option<Glyph>>,
+    new: RefCell<Option<Glyph>>,
+    diff: RefCell<GlifDiff>,
+    drawing_area: gtk::DrawingArea,
+    summary: gtk::Label,
+}
+
+#[glib::object_subclass]
+impl ObjectSubclass for GlyphDiffViewInner {
+    const NAME: &'static str = "GlyphDiffView";
+    type Type = GlyphDiffView;
+    type ParentType = gtk::Box;
+}
+
+impl ObjectImpl for GlyphDiffViewInner {
+    fn constructed(&self, obj: &Self::Type) {
+        self.parent_constructed(obj);
+        self.units_per_em.set(ufo::constants::UNITS_PER_EM);
+        obj.set_orientation(gtk::Orientation::Vertical);
+        obj.set_spacing(5);
+        obj.set_expand(true);
+        obj.set_visible(true);
+        self.drawing_area.set_expand(true);
+        self.drawing_area.set_visible(true);
+        self.drawing_area.connect_draw(
+            clone!(@weak obj => @default-return Inhibit(false), move |area, ctx| {
+                obj.draw(area, ctx);
+                Inhibit(false)
+            }),
+        );
+        self.summary.set_visible(true);
+        self.summary.set_selectable(true);
+        self.summary.set_halign(gtk::Align::Start);
+        self.summary.set_valign(gtk::Align::Start);
+        self.summary.set_wrap(true);
+        let scrolled_window = gtk::ScrolledWindow::builder()
+            .expand(false)
+            .visible(true)
+            .can_focus(true)
+            .min_content_height(80)
+            .build();
+        scrolled_window.set_child(Some(&self.summary));
+        obj.pack_start(&self.drawing_area, true, true, 0);
+        obj.pack_start(&scrolled_window, false, true, 0);
+    }
+}
+
+impl WidgetImpl for GlyphDiffViewInner {}
+impl ContainerImpl for GlyphDiffViewInner {}
+impl BoxImpl for GlyphDiffViewInner {}
+
+impl GlyphDiffViewInner {
+    fn draw(&self, area: &gtk::DrawingArea, mut ctx: &Context) {
+        let colors = self.app.get().unwrap().colors();
+        let width = f64::from(area.allocated_width());
+        let height = f64::from(area.allocated_height());
+        let cr = ctx.push();
+        cr.set_source_color(colors.theme_base_color);
+        cr.paint().expect("Invalid cairo surface state");
+
+        let old = self.old.borrow();
+        let new = self.new.borrow();
+        let units_per_em = self.units_per_em.get();
+        let advance = new
+            .as_ref()
+            .or(old.as_ref())
+            .and_then(|g| g.width())
+            .unwrap_or(units_per_em);
+        let scale = 0.8 * height.min(width) / units_per_em;
+        let mut matrix = Matrix::identity();
+        matrix.translate((width - advance * scale) / 2.0, 0.8 * height);
+        matrix.scale(scale, -scale);
+
+        for (glyph, color) in [(old.as_ref(), OLD_COLOR), (new.as_ref(), NEW_COLOR)] {
+            let Some(glyph) = glyph else {
+                continue;
+            };
+            let options = GlyphDrawingOptions {
+                outline: (color.with_alpha(200), 1.5).into(),
+                inner_fill: Some((color.with_alpha(40), 1.5).into()),
+                highlight: None,
+                matrix,
+                units_per_em,
+                ..Default::default()
+            };
+            let mut cr1: &Context = &cr;
+            glyph.draw(cr1.push(), options);
+        }
+
+        let dot = |(x, y): (f64, f64), color: Color| {
+            let (x, y) = matrix.transform_point(x, y);
+            cr.set_source_color(color);
+            cr.arc(x, y, 3.0, 0.0, 2.0 * std::f64::consts::PI);
+            cr.fill().expect("Invalid cairo surface state");
+        };
+        let arrow = |from: (f64, f64), to: (f64, f64)| {
+            let (fx, fy) = matrix.transform_point(from.0, from.1);
+            let (tx, ty) = matrix.transform_point(to.0, to.1);
+            cr.set_source_color(MOVED_COLOR);
+            cr.set_line_width(1.5);
+            cr.move_to(fx, fy);
+            cr.line_to(tx, ty);
+            cr.stroke().expect("Invalid cairo surface state");
+            cr.arc(tx, ty, 3.0, 0.0, 2.0 * std::f64::consts::PI);
+            cr.fill().expect("Invalid cairo surface state");
+        };
+        let diff = self.diff.borrow();
+        for c in diff.contours.iter() {
+            match c {
+                ContourDiff::Added { contour, .. } => {
+                    for p in contour.point.iter() {
+                        dot((p.x, p.y), NEW_COLOR);
+                    }
+                }
+                ContourDiff::Removed { contour, .. } => {
+                    for p in contour.point.iter() {
+                        dot((p.x, p.y), OLD_COLOR);
+                    }
+                }
+                ContourDiff::Changed { points, .. } => {
+                    for p in points.iter() {
+                        match p {
+                            PointDiff::Added { point, .. } => dot((point.x, point.y), NEW_COLOR),
+                            PointDiff::Removed { point, .. } => dot((point.x, point.y), OLD_COLOR),
+                            PointDiff::Moved { from, to, .. } => arrow(*from, *to),
+                            PointDiff::Retyped { at, .. } => dot(*at, MOVED_COLOR),
+                        }
+                    }
+                }
+            }
+        }
+        for a in diff.anchors.iter() {
+            match a {
+                AnchorDiff::Added(a) => dot((a.x, a.y), NEW_COLOR),
+                AnchorDiff::Removed(a) => dot((a.x, a.y), OLD_COLOR),
+                AnchorDiff::Moved { old, new } => arrow((old.x, old.y), (new.x, new.y)),
+            }
+        }
+        for c in diff.components.iter() {
+            let (label, origin, color) = match c {
+                ComponentDiff::Added(c) => (&c.base, (c.x_offset, c.y_offset), NEW_COLOR),
+                ComponentDiff::Removed(c) => (&c.base, (c.x_offset, c.y_offset), OLD_COLOR),
+                ComponentDiff::Transformed { old, new } => {
+                    arrow((old.x_offset, old.y_offset), (new.x_offset, new.y_offset));
+                    (&new.base, (new.x_offset, new.y_offset), MOVED_COLOR)
+                }
+            };
+            let (x, y) = matrix.transform_point(origin.0, origin.1);
+            cr.move_to(x + 5.0, y - 5.0);
+            cr.show_text_with_bg(label, 2.5, colors.theme_fg_color, color.with_alpha(60));
+        }
+
+        /* Legend */
+        cr.set_font_size(11.0);
+        for (i, (label, color)) in [
+            ("old", OLD_COLOR),
+            ("new", NEW_COLOR),
+            ("moved", MOVED_COLOR),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            cr.move_to(10.0, 15.0 * (i + 1) as f64);
+            cr.set_source_color(color);
+            cr.show_text(label).expect("Invalid cairo surface state");
+        }
+    }
+}
+
+glib::wrapper! {
+    pub struct GlyphDiffView(ObjectSubclass<GlyphDiffViewInner>)
+        @extends gtk::Widget, gtk::Container, gtk::Box;
+}
+
+impl std::ops::Deref for GlyphDiffView {
+    type Target = GlyphDiffViewInner;
+
+    fn deref(&self) -> &Self::Target {
+        self.imp()
+    }
+}
+
+impl GlyphDiffView {
+    pub fn new(app: Application, units_per_em: f64) -> Self {
+        let ret: Self = glib::Object::new(&[]).unwrap();
+        ret.app.set(app).unwrap();
+        ret.units_per_em.set(units_per_em);
+        ret
+    }
+
+    /// Compares `old` and `new`. Components are resolved against `layer`, if given, so that
+    /// they can be drawn.
+    pub fn set_glifs(&self, old: Option<&Glif>, new: Option<&Glif>, layer: Option<&Layer>) {
+        let diff = GlifDiff::new_optional(old, new);
+        let summary = diff.summary();
+        self.summary.set_text(&if summary.is_empty() {
+            "No differences.".to_string()
+        } else {
+            summary.join("\n")
+        });
+        *self.diff.borrow_mut() = diff;
+        *self.old.borrow_mut() = old.map(|g| Glyph::from_glif_in_layer(g.clone(), layer));
+        *self.new.borrow_mut() = new.map(|g| Glyph::from_glif_in_layer(g.clone(), layer));
+        self.drawing_area.queue_draw();
+    }
+}
+
+/// Opens a window listing every glyph of the project's default layer that differs from the
+/// default layer of the UFO at `other`, with an overlay of the selected glyph.
+pub fn compare_with_ufo(app: &Application, project: &Project, other: &Path) {
+    let old_dir = other.join("glyphs");
+    let new_dir = project.default_layer.path().clone();
+    let changes = match glif::diff::diff_glyph_dirs(&old_dir, &new_dir) {
+        Ok(changes) => changes,
+        Err(err) => {
+            let dialog = crate::utils::widgets::new_simple_error_dialog(
+                Some("Error: Could not compare projects"),
+                &err.to_string(),
+                None,
+                app.window.upcast_ref(),
+            );
+            dialog.run();
+            dialog.emit_close();
+            return;
+        }
+    };
+    let units_per_em = project.fontinfo().property(FontInfo::UNITS_PER_EM);
+    let view = GlyphDiffView::new(app.clone(), units_per_em);
+    let list = gtk::ListBox::builder()
+        .visible(true)
+        .selection_mode(gtk::SelectionMode::Browse)
+        .build();
+    for change in changes.iter() {
+        let (added, removed, moved) = change.diff.point_counts();
+        let label = gtk::Label::builder()
+            .label(&format!("{} (+{added} −{removed} ~{moved})", change.name))
+            .halign(gtk::Align::Start)
+            .visible(true)
+            .build();
+        list.add(&label);
+    }
+    let layer = project.default_layer.clone();
+    list.connect_row_selected(clone!(@weak view => move |_, row| {
+        let Some(row) = row else {
+            return;
+        };
+        if let Some(change) = usize::try_from(row.index()).ok().and_then(|i| changes.get(i)) {
+            view.set_glifs(change.old.as_ref(), change.new.as_ref(), Some(&layer));
+        }
+    }));
+    let scrolled_window = gtk::ScrolledWindow::builder()
+        .expand(true)
+        .visible(true)
+        .can_focus(true)
+        .min_content_width(200)
+        .build();
+    scrolled_window.set_child(Some(&list));
+    let paned = gtk::Paned::new(gtk::Orientation::Horizontal);
+    paned.set_visible(true);
+    paned.pack1(&scrolled_window, false, false);
+    paned.pack2(&view, true, false);
+    let window = gtk::Window::builder()
+        .title(&format!("Compare with {}", other.display()))
+        .attached_to(&app.window)
+        .transient_for(&app.window)
+        .default_width(800)
+        .default_height(600)
+        .child(&paned)
+        .build();
+    window.show_all();
+}
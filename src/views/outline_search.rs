@@ -0,0 +1,453 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Window that finds components, contours and anchors in every glyph of a layer and replaces
+//! the chosen matches in one undoable step.
+
+use crate::glyphs::outline_search::{self, ContourShape, Match, Replacement, SearchTarget};
+use crate::glyphs::Glyph;
+use crate::prelude::*;
+use crate::ufo::glif::Glif;
+use crate::ufo::objects::Layer;
+use crate::views::glyph_diff::GlyphDiffView;
+
+const KINDS: [&str; 3] = ["Component", "Contour", "Anchor"];
+
+#[derive(Default)]
+struct SearchState {
+    layer: Option<Layer>,
+    target: Option<SearchTarget>,
+    matches: Vec<Match>,
+}
+
+/// The contour at `index` of glyph `name` in `layer`.
+fn contour_shape(
+    layer: &Layer,
+    name: &str,
+    index: usize,
+) -> Result<ContourShape, Box<dyn std::error::Error>> {
+    let glyphs = layer.glyphs();
+    let Some(glyph) = glyphs.get(name) else {
+        return Err(format!("Glyph `{name}` does not exist.").into());
+    };
+    let glyph = glyph.borrow();
+    let Some(contour) = glyph.contours.get(index) else {
+        return Err(format!(
+            "Glyph `{name}` has {} contours, there is no contour {index}.",
+            glyph.contours.len()
+        )
+        .into());
+    };
+    Ok(ContourShape::new(contour))
+}
+
+/// The replacement entered in `entry`, a contour of the glyph it names when searching contours.
+fn entered_replacement(
+    entry: &gtk::Entry,
+    contour_index: &gtk::SpinButton,
+    state: &SearchState,
+) -> Result<Replacement, Box<dyn std::error::Error>> {
+    let text = entry.text();
+    let text = text.trim();
+    if text.is_empty() {
+        Ok(Replacement::Remove)
+    } else if let (Some(SearchTarget::Contour { .. }), Some(layer)) = (&state.target, &state.layer)
+    {
+        Ok(Replacement::Shape(contour_shape(
+            layer,
+            text,
+            contour_index.value() as usize,
+        )?))
+    } else {
+        Ok(Replacement::Rename(text.to_string()))
+    }
+}
+
+/// Shows the find and replace window for the outlines of `project`.
+pub fn show_outline_search_window(app: &Application, project: &Project) {
+    let state = Rc::new(RefCell::new(SearchState::default()));
+    let label = |text: &str| {
+        gtk::Label::builder()
+            .label(text)
+            .halign(gtk::Align::Start)
+            .visible(true)
+            .build()
+    };
+    let show_error = clone!(@weak app => move |title: &str, err: &dyn std::fmt::Display| {
+        let dialog = crate::utils::widgets::new_simple_error_dialog(
+            Some(title),
+            &err.to_string(),
+            None,
+            app.window.upcast_ref(),
+        );
+        dialog.run();
+        dialog.emit_close();
+    });
+
+    let layers = project.layers();
+    let layer_combo = gtk::ComboBoxText::builder().visible(true).build();
+    for layer in layers.iter() {
+        layer_combo.append_text(&layer.property::<String>(Layer::NAME));
+    }
+    layer_combo.set_active(Some(0));
+    let kind_combo = gtk::ComboBoxText::builder().visible(true).build();
+    for kind in KINDS {
+        kind_combo.append_text(kind);
+    }
+    let query = gtk::Entry::builder().visible(true).hexpand(true).build();
+    let contour_index = gtk::SpinButton::with_range(0.0, 10000.0, 1.0);
+    contour_index.set_tooltip_text(Some("Contour index in the glyph"));
+    let tolerance = gtk::SpinButton::with_range(0.0, 1000.0, 1.0);
+    tolerance.set_digits(1);
+    tolerance.set_tooltip_text(Some(
+        "Largest distance in units between corresponding points, 0 finds exact copies",
+    ));
+    let tolerance_label = label("Tolerance");
+    tolerance_label.set_no_show_all(true);
+    let replace_entry = gtk::Entry::builder()
+        .visible(true)
+        .hexpand(true)
+        .placeholder_text("Leave empty to remove the matches")
+        .build();
+    let replace_index = gtk::SpinButton::with_range(0.0, 10000.0, 1.0);
+    replace_index.set_tooltip_text(Some("Contour index in the glyph"));
+    for w in [&contour_index, &tolerance, &replace_index] {
+        w.set_no_show_all(true);
+    }
+    kind_combo.connect_changed(
+        clone!(@weak query, @weak contour_index, @weak tolerance, @weak tolerance_label, @weak replace_index => move |combo| {
+            let contour = combo.active() == Some(1);
+            query.set_placeholder_text(Some(match combo.active() {
+                Some(0) => "Base glyph name",
+                Some(1) => "Glyph name",
+                _ => "Anchor name",
+            }));
+            for w in [contour_index.upcast_ref::<gtk::Widget>(), tolerance.upcast_ref(), tolerance_label.upcast_ref(), replace_index.upcast_ref()] {
+                w.set_visible(contour);
+            }
+        }),
+    );
+    kind_combo.set_active(Some(0));
+
+    let store = gtk::ListStore::new(&[
+        bool::static_type(),
+        String::static_type(),
+        String::static_type(),
+        u32::static_type(),
+    ]);
+    let tree = gtk::TreeView::builder()
+        .model(&store)
+        .visible(true)
+        .expand(true)
+        .build();
+    {
+        let column = gtk::TreeViewColumn::new();
+        column.set_title("Replace");
+        let cell = gtk::CellRendererToggle::new();
+        cell.set_activatable(true);
+        cell.connect_toggled(clone!(@weak store => move |_, treepath| {
+            if let Some(iter) = store.iter(&treepath) {
+                let value: bool = store.value(&iter, 0).get().unwrap();
+                store.set_value(&iter, 0, &(!value).to_value());
+            }
+        }));
+        column.pack_start(&cell, false);
+        column.add_attribute(&cell, "active", 0);
+        tree.append_column(&column);
+        for (i, title) in [(1, "Glyph"), (2, "Match")] {
+            let column = gtk::TreeViewColumn::new();
+            column.set_title(title);
+            let cell = gtk::CellRendererText::new();
+            column.pack_start(&cell, true);
+            column.add_attribute(&cell, "text", i);
+            column.set_sort_column_id(i);
+            tree.append_column(&column);
+        }
+    }
+    let scrolled = gtk::ScrolledWindow::builder()
+        .visible(true)
+        .expand(true)
+        .min_content_height(200)
+        .build();
+    scrolled.set_child(Some(&tree));
+    let status = label("");
+
+    let units_per_em = project.fontinfo().property(FontInfo::UNITS_PER_EM);
+    let preview = GlyphDiffView::new(app.clone(), units_per_em);
+    preview.set_visible(true);
+    preview.set_size_request(300, 300);
+
+    let replacement = Rc::new(
+        clone!(@weak replace_entry, @weak replace_index, @strong state => @default-return Ok(Replacement::Remove), move || {
+            entered_replacement(&replace_entry, &replace_index, &state.borrow())
+        }),
+    );
+    let included = Rc::new(
+        clone!(@weak store, @strong state => @default-return vec![], move || {
+            let state = state.borrow();
+            let mut ret = vec![];
+            store.foreach(|model, _, iter| {
+                let include: bool = model.value(iter, 0).get().unwrap();
+                let index: u32 = model.value(iter, 3).get().unwrap();
+                if let Some(m) = state.matches.get(index as usize).filter(|_| include) {
+                    ret.push(m.clone());
+                }
+                false
+            });
+            ret
+        }),
+    );
+    let update_preview = Rc::new(
+        clone!(@weak tree, @weak preview, @strong state, @strong replacement, @strong included => move || {
+            let Some((model, iter)) = tree.selection().selected() else {
+                preview.set_glifs(None, None, None);
+                return;
+            };
+            let index: u32 = model.value(&iter, 3).get().unwrap();
+            let state = state.borrow();
+            let (Some(layer), Some(target)) = (&state.layer, &state.target) else {
+                return;
+            };
+            let Some(glyph) = state.matches.get(index as usize).map(|m| m.glyph.clone()) else {
+                return;
+            };
+            let old = Glif::from(&*glyph.borrow());
+            let matches = included()
+                .into_iter()
+                .filter(|m| Rc::ptr_eq(&m.glyph, &glyph))
+                .collect::<Vec<_>>();
+            let outline = replacement().and_then(|replacement| {
+                outline_search::replace(&matches, target, &replacement, &layer.glyphs())
+            });
+            let new = match outline.map(|mut swaps| swaps.pop()) {
+                Ok(Some((_, (contours, components, anchors)))) => {
+                    let mut new: Glyph = old.clone().into();
+                    new.contours = contours;
+                    new.components = components;
+                    *new.metadata.anchors.borrow_mut() = anchors;
+                    Glif::from(&new)
+                }
+                Ok(None) | Err(_) => old.clone(),
+            };
+            preview.set_glifs(Some(&old), Some(&new), Some(layer));
+        }),
+    );
+    tree.selection()
+        .connect_changed(clone!(@strong update_preview => move |_| {
+            update_preview();
+        }));
+    store.connect_row_changed(clone!(@strong update_preview => move |_, _, _| {
+        update_preview();
+    }));
+    replace_entry.connect_changed(clone!(@strong update_preview => move |_| {
+        update_preview();
+    }));
+    replace_index.connect_value_changed(clone!(@strong update_preview => move |_| {
+        update_preview();
+    }));
+
+    let show_matches = Rc::new(
+        clone!(@weak store, @weak status, @strong state => move |layer: &Layer, target: SearchTarget, matches: Vec<Match>| {
+            status.set_text(&format!(
+                "{} matches in {} glyphs.",
+                matches.len(),
+                matches
+                    .iter()
+                    .map(|m| m.glyph_name.as_str())
+                    .collect::<HashSet<_>>()
+                    .len()
+            ));
+            store.clear();
+            for (i, m) in matches.iter().enumerate() {
+                store.insert_with_values(
+                    None,
+                    &[
+                        (0, &true),
+                        (1, &m.glyph_name),
+                        (2, &m.description(&target)),
+                        (3, &(i as u32)),
+                    ],
+                );
+            }
+            *state.borrow_mut() = SearchState {
+                layer: Some(layer.clone()),
+                target: Some(target),
+                matches,
+            };
+        }),
+    );
+
+    let find_button = gtk::Button::builder().label("Find").visible(true).build();
+    let find = Rc::new(
+        clone!(@weak layer_combo, @weak kind_combo, @weak query, @weak contour_index, @weak tolerance, @strong show_matches, @strong show_error => move || {
+            let Some(layer) = layer_combo.active().and_then(|i| layers.get(i as usize)) else {
+                return;
+            };
+            let text = query.text();
+            let text = text.trim();
+            if text.is_empty() {
+                return;
+            }
+            let target = match kind_combo.active() {
+                Some(0) => SearchTarget::Component(text.to_string()),
+                Some(1) => match contour_shape(layer, text, contour_index.value() as usize) {
+                    Ok(shape) => SearchTarget::Contour {
+                        shape,
+                        tolerance: tolerance.value(),
+                    },
+                    Err(err) => {
+                        show_error("Error: could not search contours", &err);
+                        return;
+                    }
+                },
+                _ => SearchTarget::Anchor(text.to_string()),
+            };
+            let matches = outline_search::search(&layer.glyphs(), &target);
+            show_matches(layer, target, matches);
+        }),
+    );
+    find_button.connect_clicked(clone!(@strong find => move |_| {
+        find();
+    }));
+    query.connect_activate(clone!(@strong find => move |_| {
+        find();
+    }));
+
+    let replace_button = gtk::Button::builder()
+        .label("Replace")
+        .tooltip_text("Replace the ticked matches")
+        .visible(true)
+        .build();
+    replace_button.connect_clicked(
+        clone!(@weak app, @weak project, @weak store, @weak status, @strong state, @strong replacement, @strong included, @strong show_matches, @strong show_error => move |_| {
+            let (Some(layer), Some(target)) = ({
+                let state = state.borrow();
+                (state.layer.clone(), state.target.clone())
+            }) else {
+                return;
+            };
+            /* Match indices refer to the glyphs as they were when searching. */
+            let current = outline_search::search(&layer.glyphs(), &target);
+            let unchanged = {
+                let state = state.borrow();
+                current.len() == state.matches.len()
+                    && current.iter().zip(state.matches.iter()).all(|(a, b)| {
+                        Rc::ptr_eq(&a.glyph, &b.glyph) && a.index == b.index && a.reversed == b.reversed
+                    })
+            };
+            if !unchanged {
+                show_matches(&layer, target, current);
+                show_error(
+                    "Error: the glyphs changed since the search",
+                    &"The matches have been updated. Review them and replace again.",
+                );
+                return;
+            }
+            let matches = included();
+            if matches.is_empty() {
+                return;
+            }
+            let swaps = match replacement().and_then(|replacement| {
+                outline_search::replace(&matches, &target, &replacement, &layer.glyphs())
+            }) {
+                Ok(swaps) => swaps,
+                Err(err) => {
+                    show_error("Error: could not replace matches", &err);
+                    return;
+                }
+            };
+            let glyph_count = swaps.len();
+            let mut action = Glyph::swap_outlines(
+                swaps,
+                "outline-search",
+                Rc::new(clone!(@weak project => move |glyph: &Rc<RefCell<Glyph>>| {
+                    project.glyph_changed(glyph);
+                })),
+            );
+            (action.redo)();
+            app.undo_db.borrow_mut().event(action);
+            /* The indices of the remaining matches are stale now. */
+            store.clear();
+            state.borrow_mut().matches.clear();
+            status.set_text(&format!(
+                "Replaced {} matches in {glyph_count} glyphs.",
+                matches.len()
+            ));
+        }),
+    );
+
+    let window = gtk::Window::builder()
+        .title("Find and replace outlines")
+        .attached_to(&app.window)
+        .transient_for(&app.window)
+        .default_width(700)
+        .default_height(500)
+        .build();
+    let close_button = gtk::Button::builder().label("Close").visible(true).build();
+    close_button.connect_clicked(clone!(@weak window => move |_| {
+        window.close();
+    }));
+
+    let grid = gtk::Grid::builder()
+        .row_spacing(5)
+        .column_spacing(10)
+        .visible(true)
+        .build();
+    grid.attach(&label("Layer"), 0, 0, 1, 1);
+    grid.attach(&layer_combo, 1, 0, 1, 1);
+    grid.attach(&label("Find"), 0, 1, 1, 1);
+    grid.attach(&kind_combo, 1, 1, 1, 1);
+    grid.attach(&query, 2, 1, 1, 1);
+    grid.attach(&contour_index, 3, 1, 1, 1);
+    grid.attach(&tolerance_label, 4, 1, 1, 1);
+    grid.attach(&tolerance, 5, 1, 1, 1);
+    grid.attach(&find_button, 6, 1, 1, 1);
+    grid.attach(&label("Replace with"), 0, 2, 1, 1);
+    grid.attach(&replace_entry, 1, 2, 2, 1);
+    grid.attach(&replace_index, 3, 2, 1, 1);
+    let paned = gtk::Paned::builder()
+        .orientation(gtk::Orientation::Horizontal)
+        .visible(true)
+        .expand(true)
+        .build();
+    paned.pack1(&scrolled, true, false);
+    paned.pack2(&preview, true, false);
+    let buttons = gtk::Box::builder()
+        .orientation(gtk::Orientation::Horizontal)
+        .spacing(5)
+        .halign(gtk::Align::End)
+        .visible(true)
+        .build();
+    buttons.pack_start(&close_button, false, false, 0);
+    buttons.pack_start(&replace_button, false, false, 0);
+    let vbox = gtk::Box::builder()
+        .orientation(gtk::Orientation::Vertical)
+        .spacing(5)
+        .margin(10)
+        .visible(true)
+        .build();
+    vbox.pack_start(&grid, false, false, 0);
+    vbox.pack_start(&paned, true, true, 0);
+    vbox.pack_start(&status, false, false, 0);
+    vbox.pack_end(&buttons, false, false, 0);
+    window.set_child(Some(&vbox));
+    window.show_all();
+}
@@ -0,0 +1,188 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Panel with numeric inputs for moving, scaling, rotating, skewing and flipping outlines.
+
+use crate::glyphs::transform::{TransformOperation, TransformOrigin};
+use crate::prelude::*;
+
+fn spin_button(value: f64, (min, max): (f64, f64)) -> gtk::SpinButton {
+    let spin = gtk::SpinButton::with_range(min, max, 1.0);
+    spin.set_digits(2);
+    spin.set_value(value);
+    spin.set_visible(true);
+    spin
+}
+
+/// A non-modal window attached to `window`. Each row of the panel has its own button that
+/// calls `apply` with the row's operation and the origin chosen in the panel.
+pub fn new_transform_panel(
+    title: &str,
+    window: &gtk::Window,
+    apply: Rc<dyn Fn(TransformOperation, TransformOrigin)>,
+) -> gtk::Window {
+    let ret = gtk::Window::builder()
+        .title(title)
+        .transient_for(window)
+        .destroy_with_parent(true)
+        .type_hint(gdk::WindowTypeHint::Utility)
+        .resizable(false)
+        .visible(false)
+        .build();
+    let origin = Rc::new(Cell::new(TransformOrigin::default()));
+    let grid = gtk::Grid::builder()
+        .row_spacing(5)
+        .column_spacing(5)
+        .margin(10)
+        .visible(true)
+        .build();
+    let label = |text: &str| {
+        gtk::Label::builder()
+            .label(text)
+            .halign(gtk::Align::Start)
+            .visible(true)
+            .build()
+    };
+
+    /* Origin: the nine reference points of the bounding box. */
+    {
+        let origin_grid = gtk::Grid::builder()
+            .halign(gtk::Align::Start)
+            .visible(true)
+            .build();
+        let mut group: Option<gtk::RadioButton> = None;
+        for (i, o) in TransformOrigin::ALL.into_iter().enumerate() {
+            let button = match group.as_ref() {
+                Some(first) => gtk::RadioButton::from_widget(first),
+                None => gtk::RadioButton::new(),
+            };
+            group.get_or_insert_with(|| button.clone());
+            button.set_tooltip_text(Some(&format!("{:?}", o)));
+            button.set_visible(true);
+            button.set_active(o == TransformOrigin::default());
+            button.connect_toggled(clone!(@strong origin => move |button| {
+                if button.is_active() {
+                    origin.set(o);
+                }
+            }));
+            origin_grid.attach(&button, (i % 3) as i32, (i / 3) as i32, 1, 1);
+        }
+        grid.attach(&label("Origin"), 0, 0, 1, 1);
+        grid.attach(&origin_grid, 1, 0, 2, 1);
+    }
+
+    let mut row = 1;
+    let mut add_row = |name: &str, inputs: &[&gtk::SpinButton], button: &gtk::Button| {
+        grid.attach(&label(name), 0, row, 1, 1);
+        for (i, input) in inputs.iter().enumerate() {
+            grid.attach(*input, 1 + i as i32, row, 1, 1);
+        }
+        grid.attach(button, 3, row, 1, 1);
+        row += 1;
+    };
+    let apply_button = |text: &str, op: Box<dyn Fn() -> TransformOperation>| {
+        let button = gtk::Button::builder().label(text).visible(true).build();
+        button.connect_clicked(clone!(@strong apply, @strong origin => move |_| {
+            apply(op(), origin.get());
+        }));
+        button
+    };
+
+    let (move_x, move_y) = (
+        spin_button(0.0, (-10000.0, 10000.0)),
+        spin_button(0.0, (-10000.0, 10000.0)),
+    );
+    add_row(
+        "Move",
+        &[&move_x, &move_y],
+        &apply_button(
+            "Move",
+            Box::new(
+                clone!(@weak move_x, @weak move_y => @default-return TransformOperation::Move { x: 0.0, y: 0.0 }, move || {
+                    TransformOperation::Move { x: move_x.value(), y: move_y.value() }
+                }),
+            ),
+        ),
+    );
+    let (scale_x, scale_y) = (
+        spin_button(100.0, (-10000.0, 10000.0)),
+        spin_button(100.0, (-10000.0, 10000.0)),
+    );
+    scale_x.set_tooltip_text(Some("Horizontal scale in percent"));
+    scale_y.set_tooltip_text(Some("Vertical scale in percent"));
+    add_row(
+        "Scale %",
+        &[&scale_x, &scale_y],
+        &apply_button(
+            "Scale",
+            Box::new(
+                clone!(@weak scale_x, @weak scale_y => @default-return TransformOperation::Scale { x: 1.0, y: 1.0 }, move || {
+                    TransformOperation::Scale { x: scale_x.value() / 100.0, y: scale_y.value() / 100.0 }
+                }),
+            ),
+        ),
+    );
+    let rotate = spin_button(0.0, (-360.0, 360.0));
+    rotate.set_tooltip_text(Some("Counter-clockwise rotation in degrees"));
+    add_row(
+        "Rotate °",
+        &[&rotate],
+        &apply_button(
+            "Rotate",
+            Box::new(
+                clone!(@weak rotate => @default-return TransformOperation::Rotate { degrees: 0.0 }, move || {
+                    TransformOperation::Rotate { degrees: rotate.value() }
+                }),
+            ),
+        ),
+    );
+    let (skew_x, skew_y) = (
+        spin_button(0.0, (-89.0, 89.0)),
+        spin_button(0.0, (-89.0, 89.0)),
+    );
+    add_row(
+        "Skew °",
+        &[&skew_x, &skew_y],
+        &apply_button(
+            "Skew",
+            Box::new(
+                clone!(@weak skew_x, @weak skew_y => @default-return TransformOperation::Skew { x: 0.0, y: 0.0 }, move || {
+                    TransformOperation::Skew { x: skew_x.value(), y: skew_y.value() }
+                }),
+            ),
+        ),
+    );
+    {
+        let flip_h = apply_button(
+            "Flip horizontally",
+            Box::new(|| TransformOperation::FlipHorizontal),
+        );
+        let flip_v = apply_button(
+            "Flip vertically",
+            Box::new(|| TransformOperation::FlipVertical),
+        );
+        grid.attach(&label("Flip"), 0, row, 1, 1);
+        grid.attach(&flip_h, 1, row, 1, 1);
+        grid.attach(&flip_v, 2, row, 1, 1);
+    }
+    ret.add(&grid);
+    ret
+}
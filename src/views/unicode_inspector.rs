@@ -0,0 +1,228 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Panel with the Unicode properties of a glyph's characters and its possible glyph names.
+
+use crate::glyphs::{Glyph, GlyphKind};
+use crate::prelude::*;
+use crate::unicode::agl::{name_to_char, production_name, uni_name};
+use crate::unicode::blocks::{CharBlock, UNICODE_BLOCKS};
+use crate::unicode::names::CharName;
+use crate::unicode::properties::CharProperties;
+
+/// `U+00E9 é` for characters that can be displayed on their own, `U+0301 ◌́` for marks.
+fn char_label(c: char) -> String {
+    let display = if c.general_category().is_mark() {
+        format!("\u{25CC}{}", c)
+    } else if c.is_control() || c.is_whitespace() {
+        String::new()
+    } else {
+        c.to_string()
+    };
+    format!("U+{:04X} {}", c as u32, display)
+}
+
+fn char_rows(c: char) -> Vec<(&'static str, String)> {
+    let mut rows = vec![
+        ("Codepoint", char_label(c)),
+        (
+            "Name",
+            c.char_name().map(|n| n.to_string()).unwrap_or_default(),
+        ),
+        (
+            "Block",
+            c.char_block()
+                .map(|idx| UNICODE_BLOCKS[idx].1)
+                .unwrap_or("Unknown")
+                .to_string(),
+        ),
+        (
+            "General category",
+            format!(
+                "{} ({})",
+                c.general_category(),
+                c.general_category().abbreviation()
+            ),
+        ),
+        ("Script", c.script().to_string()),
+    ];
+    for (label, mapping) in [
+        ("Uppercase", c.to_uppercase().collect::<Vec<char>>()),
+        ("Lowercase", c.to_lowercase().collect::<Vec<char>>()),
+    ] {
+        if mapping != [c] {
+            rows.push((
+                label,
+                mapping
+                    .into_iter()
+                    .map(char_label)
+                    .collect::<Vec<_>>()
+                    .join(" + "),
+            ));
+        }
+    }
+    if let Some(decomposition) = c.decomposition() {
+        let chars = decomposition
+            .chars
+            .into_iter()
+            .map(char_label)
+            .collect::<Vec<_>>()
+            .join(" + ");
+        rows.push((
+            "Decomposition",
+            match decomposition.tag {
+                Some(tag) => format!("<{}> {}", tag, chars),
+                None => chars,
+            },
+        ));
+    }
+    rows
+}
+
+/// A non-modal window attached to `window` that shows `glyph`. The returned function changes
+/// the glyph shown; `on_rename` is called after the glyph is renamed from the panel.
+pub fn new_unicode_inspector(
+    app: &Application,
+    project: &Project,
+    glyph: Rc<RefCell<Glyph>>,
+    on_rename: Rc<dyn Fn()>,
+) -> (gtk::Window, Rc<dyn Fn(Rc<RefCell<Glyph>>)>) {
+    let window = gtk::Window::builder()
+        .title("Unicode inspector")
+        .transient_for(&app.window)
+        .destroy_with_parent(true)
+        .type_hint(gdk::WindowTypeHint::Utility)
+        .default_width(400)
+        .visible(false)
+        .build();
+    let content = gtk::Box::builder()
+        .orientation(gtk::Orientation::Vertical)
+        .margin(10)
+        .spacing(10)
+        .visible(true)
+        .build();
+    window.set_child(Some(&content));
+
+    /* Weak reference to `update` for the rename buttons, to refresh the panel. */
+    #[allow(clippy::type_complexity)]
+    let set_glyph: Rc<RefCell<Option<std::rc::Weak<dyn Fn(Rc<RefCell<Glyph>>)>>>> =
+        Rc::new(RefCell::new(None));
+    let update: Rc<dyn Fn(Rc<RefCell<Glyph>>)> = Rc::new(
+        clone!(@weak app, @weak project, @weak window, @weak content, @strong set_glyph => move |glyph: Rc<RefCell<Glyph>>| {
+            for child in content.children() {
+                content.remove(&child);
+            }
+            let label = |text: &str| {
+                gtk::Label::builder()
+                    .label(text)
+                    .halign(gtk::Align::Start)
+                    .selectable(true)
+                    .wrap(true)
+                    .visible(true)
+                    .build()
+            };
+            let heading = |text: &str| {
+                let l = label(text);
+                l.set_markup(&format!("<b>{}</b>", glib::markup_escape_text(text)));
+                l
+            };
+            let (name, chars) = {
+                let glyph = glyph.borrow();
+                let kinds = glyph.kinds();
+                let chars = std::iter::once(&kinds.0)
+                    .chain(kinds.1.iter())
+                    .filter_map(|k| match k {
+                        GlyphKind::Char(c) => Some(*c),
+                        GlyphKind::Component(_) => None,
+                    })
+                    .collect::<Vec<char>>();
+                (glyph.name().to_string(), chars)
+            };
+            window.set_title(&format!("Unicode inspector: {}", name));
+            if chars.is_empty() {
+                content.pack_start(&label(&format!("Glyph {} is not mapped to any character.", name)), false, false, 0);
+            }
+            for &c in chars.iter() {
+                let grid = gtk::Grid::builder()
+                    .row_spacing(5)
+                    .column_spacing(10)
+                    .visible(true)
+                    .build();
+                for (row, (key, value)) in char_rows(c).into_iter().enumerate() {
+                    grid.attach(&heading(key), 0, row as i32, 1, 1);
+                    grid.attach(&label(&value), 1, row as i32, 1, 1);
+                }
+                content.pack_start(&grid, false, false, 0);
+                content.pack_start(&gtk::Separator::builder().orientation(gtk::Orientation::Horizontal).visible(true).build(), false, false, 0);
+            }
+
+            /* Glyph names, from the first character. */
+            let names = gtk::Grid::builder()
+                .row_spacing(5)
+                .column_spacing(10)
+                .visible(true)
+                .build();
+            names.attach(&heading("Glyph name"), 0, 0, 1, 1);
+            let compliant = chars.contains(&name_to_char(&name).unwrap_or('\0'));
+            names.attach(&label(&format!("{}{}", name, if compliant { "" } else { " (not AGL-compliant)" })), 1, 0, 1, 1);
+            if let Some(&c) = chars.first() {
+                for (row, (key, new_name)) in [("Production name", production_name(c)), ("uni name", uni_name(c))].into_iter().enumerate() {
+                    let row = row as i32 + 1;
+                    names.attach(&heading(key), 0, row, 1, 1);
+                    names.attach(&label(&new_name), 1, row, 1, 1);
+                    let button = gtk::Button::builder()
+                        .label("Rename")
+                        .sensitive(new_name != name)
+                        .visible(true)
+                        .build();
+                    button.connect_clicked(clone!(@weak app, @weak project, @strong glyph, @strong name, @strong set_glyph, @strong on_rename => move |_| {
+                        let mut action = match project.rename_glyph_action(&name, &new_name) {
+                            Ok(action) => action,
+                            Err(err) => {
+                                let dialog = crate::utils::widgets::new_simple_error_dialog(
+                                    Some("Error: Could not rename glyph."),
+                                    &err.to_string(),
+                                    None,
+                                    app.window.upcast_ref(),
+                                );
+                                dialog.run();
+                                dialog.emit_close();
+                                return;
+                            }
+                        };
+                        (action.redo)();
+                        app.undo_db.borrow_mut().event(action);
+                        on_rename();
+                        let set_glyph = set_glyph.borrow().as_ref().and_then(std::rc::Weak::upgrade);
+                        if let Some(set_glyph) = set_glyph {
+                            set_glyph(glyph.clone());
+                        }
+                    }));
+                    names.attach(&button, 2, row, 1, 1);
+                }
+            }
+            content.pack_start(&names, false, false, 0);
+        }),
+    );
+    *set_glyph.borrow_mut() = Some(Rc::downgrade(&update));
+    update(glyph);
+    (window, update)
+}
@@ -0,0 +1,335 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+ */
+
+//! Window that compares the vertical metrics of a project with recommended values, lists
+//! clipped glyphs and inconsistent line heights, and applies the chosen values.
+
+use crate::prelude::*;
+use crate::ufo::vertical_metrics::{Extents, Strategy, VerticalMetrics};
+
+/// Extents of the glyphs of the project's default layer.
+pub fn project_extents(project: &Project) -> Extents {
+    Extents::new(
+        project
+            .default_layer
+            .glyphs()
+            .iter()
+            .filter_map(|(name, glyph)| {
+                let (y_min, y_max) = glyph.borrow().vertical_extents()?;
+                Some((name.clone(), y_min, y_max))
+            })
+            .collect(),
+    )
+}
+
+fn problems_text(problems: &[String]) -> String {
+    if problems.is_empty() {
+        "No problems found.".to_string()
+    } else {
+        problems
+            .iter()
+            .map(|p| format!("• {p}"))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// Shows the vertical metrics window for `project`.
+pub fn show_vertical_metrics_window(app: &Application, project: &Project) {
+    let fontinfo = project.fontinfo().clone();
+    let extents: Rc<RefCell<Extents>> = Rc::default();
+    let label = |text: &str| {
+        gtk::Label::builder()
+            .label(text)
+            .halign(gtk::Align::Start)
+            .visible(true)
+            .build()
+    };
+    let heading = |text: &str| {
+        let ret = label("");
+        ret.set_markup(&format!("<b>{text}</b>"));
+        ret
+    };
+    let summary = gtk::Label::builder()
+        .halign(gtk::Align::Start)
+        .selectable(true)
+        .wrap(true)
+        .visible(true)
+        .build();
+    let strategy_combo = gtk::ComboBoxText::builder().visible(true).build();
+    for s in Strategy::ALL {
+        strategy_combo.append_text(s.name());
+    }
+    let strategy_description = gtk::Label::builder()
+        .halign(gtk::Align::Start)
+        .wrap(true)
+        .visible(true)
+        .build();
+
+    let grid = gtk::Grid::builder()
+        .row_spacing(5)
+        .column_spacing(10)
+        .visible(true)
+        .build();
+    grid.attach(&heading("Current"), 1, 0, 1, 1);
+    grid.attach(&heading("Chosen"), 2, 0, 1, 1);
+    let rows: [(&str, &str, f64); 8] = [
+        ("hhea ascender", "openTypeHheaAscender", -10000.0),
+        ("hhea descender", "openTypeHheaDescender", -10000.0),
+        ("hhea line gap", "openTypeHheaLineGap", -10000.0),
+        ("Typo ascender", "openTypeOS2TypoAscender", -10000.0),
+        ("Typo descender", "openTypeOS2TypoDescender", -10000.0),
+        ("Typo line gap", "openTypeOS2TypoLineGap", -10000.0),
+        ("Win ascent", "openTypeOS2WinAscent", 0.0),
+        ("Win descent", "openTypeOS2WinDescent", 0.0),
+    ];
+    let mut current_labels = vec![];
+    let mut spins = vec![];
+    for (i, (title, key, min)) in rows.into_iter().enumerate() {
+        let title = label(title);
+        title.set_tooltip_text(Some(key));
+        grid.attach(&title, 0, i as i32 + 1, 1, 1);
+        let current = label("");
+        grid.attach(&current, 1, i as i32 + 1, 1, 1);
+        current_labels.push(current);
+        let spin = gtk::SpinButton::with_range(min, 10000.0, 1.0);
+        spin.set_digits(0);
+        spin.set_visible(true);
+        grid.attach(&spin, 2, i as i32 + 1, 1, 1);
+        spins.push(spin);
+    }
+    let use_typo_metrics_title = label("Use typo metrics");
+    use_typo_metrics_title.set_tooltip_text(Some("openTypeOS2Selection bit 7"));
+    grid.attach(&use_typo_metrics_title, 0, 9, 1, 1);
+    let current_use_typo_metrics = label("");
+    grid.attach(&current_use_typo_metrics, 1, 9, 1, 1);
+    let use_typo_metrics = gtk::CheckButton::builder().visible(true).build();
+    grid.attach(&use_typo_metrics, 2, 9, 1, 1);
+    let line_height_title = label("Line height (hhea/typo/win)");
+    grid.attach(&line_height_title, 0, 10, 1, 1);
+    let current_line_height = label("");
+    grid.attach(&current_line_height, 1, 10, 1, 1);
+    let chosen_line_height = label("");
+    grid.attach(&chosen_line_height, 2, 10, 1, 1);
+
+    let current_problems = label("");
+    current_problems.set_wrap(true);
+    current_problems.set_selectable(true);
+    let chosen_problems = label("");
+    chosen_problems.set_wrap(true);
+    chosen_problems.set_selectable(true);
+
+    let spins = Rc::new(spins);
+    let chosen = Rc::new(
+        clone!(@strong spins, @weak use_typo_metrics => @default-return VerticalMetrics::default(), move || {
+            let v = |i: usize| spins[i].value().round() as i64;
+            VerticalMetrics {
+                hhea_ascender: v(0),
+                hhea_descender: v(1),
+                hhea_line_gap: v(2),
+                typo_ascender: v(3),
+                typo_descender: v(4),
+                typo_line_gap: v(5),
+                win_ascent: v(6).max(0) as u64,
+                win_descent: v(7).max(0) as u64,
+                use_typo_metrics: use_typo_metrics.is_active(),
+            }
+        }),
+    );
+    let update_chosen = Rc::new(
+        clone!(@strong chosen, @strong extents, @weak chosen_problems, @weak chosen_line_height => move || {
+            let m = chosen();
+            chosen_line_height.set_text(&format!(
+                "{}/{}/{}",
+                m.hhea_line_height(),
+                m.typo_line_height(),
+                m.win_line_height()
+            ));
+            chosen_problems.set_text(&problems_text(&m.check(&extents.borrow())));
+        }),
+    );
+    for spin in spins.iter() {
+        spin.connect_value_changed(clone!(@strong update_chosen => move |_| {
+            update_chosen();
+        }));
+    }
+    use_typo_metrics.connect_toggled(clone!(@strong update_chosen => move |_| {
+        update_chosen();
+    }));
+    let update_current = Rc::new(
+        clone!(@weak fontinfo, @strong extents, @weak current_problems, @weak current_line_height, @weak current_use_typo_metrics => move || {
+            let (m, undefined) =
+                VerticalMetrics::from_fontinfo(&fontinfo.source.borrow(), &extents.borrow());
+            let values = [
+                m.hhea_ascender,
+                m.hhea_descender,
+                m.hhea_line_gap,
+                m.typo_ascender,
+                m.typo_descender,
+                m.typo_line_gap,
+                m.win_ascent as i64,
+                m.win_descent as i64,
+            ];
+            for ((l, value), (_, key, _)) in current_labels.iter().zip(values).zip(rows) {
+                if undefined.contains(&key) {
+                    l.set_markup(&format!("<i>{value} (undefined)</i>"));
+                } else {
+                    l.set_text(&value.to_string());
+                }
+            }
+            current_use_typo_metrics.set_text(if m.use_typo_metrics { "Yes" } else { "No" });
+            current_line_height.set_text(&format!(
+                "{}/{}/{}",
+                m.hhea_line_height(),
+                m.typo_line_height(),
+                m.win_line_height()
+            ));
+            let mut problems = m.check(&extents.borrow());
+            if !undefined.is_empty() {
+                problems.push(format!(
+                    "Undefined values are derived by the font compiler: {}.",
+                    undefined.join(", ")
+                ));
+            }
+            current_problems.set_text(&problems_text(&problems));
+        }),
+    );
+    strategy_combo.connect_changed(
+        clone!(@weak fontinfo, @strong extents, @strong spins, @weak use_typo_metrics, @weak strategy_description => move |combo| {
+            let Some(strategy) = combo.active().and_then(|i| Strategy::ALL.get(i as usize).copied()) else {
+                return;
+            };
+            strategy_description.set_text(strategy.description());
+            let m = VerticalMetrics::recommend(strategy, &fontinfo.source.borrow(), &extents.borrow());
+            let values = [
+                m.hhea_ascender,
+                m.hhea_descender,
+                m.hhea_line_gap,
+                m.typo_ascender,
+                m.typo_descender,
+                m.typo_line_gap,
+                m.win_ascent as i64,
+                m.win_descent as i64,
+            ];
+            for (spin, value) in spins.iter().zip(values) {
+                spin.set_value(value as f64);
+            }
+            use_typo_metrics.set_active(m.use_typo_metrics);
+        }),
+    );
+
+    /* Glyphs can change while the window is open, so the extents are recomputed after applying
+     * and whenever a glyph of the default layer changes. */
+    let refresh = Rc::new(
+        clone!(@weak project, @strong extents, @weak summary, @strong update_current, @strong update_chosen => move || {
+            *extents.borrow_mut() = project_extents(&project);
+            let extents = extents.borrow();
+            summary.set_text(&format!(
+                "Highest glyph: {} ({}). Lowest glyph: {} ({}).",
+                extents.y_max_glyph.as_deref().unwrap_or("none"),
+                extents.y_max,
+                extents.y_min_glyph.as_deref().unwrap_or("none"),
+                extents.y_min
+            ));
+            drop(extents);
+            update_current();
+            update_chosen();
+        }),
+    );
+
+    let window = gtk::Window::builder()
+        .title("Vertical metrics")
+        .attached_to(&app.window)
+        .transient_for(&app.window)
+        .default_width(500)
+        .build();
+    let apply_button = gtk::Button::builder()
+        .label("Apply")
+        .tooltip_text("Set the chosen values in the font info")
+        .visible(true)
+        .build();
+    apply_button.connect_clicked(
+        clone!(@weak app, @weak fontinfo, @strong chosen, @strong refresh => move |_| {
+            let mut source = fontinfo.source.borrow().clone();
+            chosen().apply(&mut source);
+            let mut action = fontinfo.set_source_action(source);
+            (action.redo)();
+            app.undo_db.borrow_mut().event(action);
+            refresh();
+        }),
+    );
+    let handlers = [Project::GLYPH_RELOADED, Project::GLYPH_CHANGED].map(|signal| {
+        project.connect_local(
+            signal,
+            false,
+            clone!(@weak project, @strong refresh => @default-return None, move |v: &[gtk::glib::Value]| {
+                let layer = v[1].get::<crate::ufo::objects::Layer>().unwrap();
+                if layer == project.default_layer {
+                    refresh();
+                }
+                None
+            }),
+        )
+    });
+    let handlers = Cell::new(Some(handlers));
+    window.connect_destroy(clone!(@weak project => move |_| {
+        for handler in handlers.take().into_iter().flatten() {
+            project.disconnect(handler);
+        }
+    }));
+    let close_button = gtk::Button::builder().label("Close").visible(true).build();
+    close_button.connect_clicked(clone!(@weak window => move |_| {
+        window.close();
+    }));
+    let buttons = gtk::Box::builder()
+        .orientation(gtk::Orientation::Horizontal)
+        .spacing(5)
+        .halign(gtk::Align::End)
+        .visible(true)
+        .build();
+    buttons.pack_start(&close_button, false, false, 0);
+    buttons.pack_start(&apply_button, false, false, 0);
+    let vbox = gtk::Box::builder()
+        .orientation(gtk::Orientation::Vertical)
+        .spacing(5)
+        .margin(10)
+        .visible(true)
+        .build();
+    vbox.pack_start(&summary, false, false, 0);
+    vbox.pack_start(&strategy_combo, false, false, 0);
+    vbox.pack_start(&strategy_description, false, false, 0);
+    vbox.pack_start(&grid, false, false, 0);
+    vbox.pack_start(
+        &heading("Problems with the current values"),
+        false,
+        false,
+        0,
+    );
+    vbox.pack_start(&current_problems, false, false, 0);
+    vbox.pack_start(&heading("Problems with the chosen values"), false, false, 0);
+    vbox.pack_start(&chosen_problems, false, false, 0);
+    vbox.pack_end(&buttons, false, false, 0);
+    window.set_child(Some(&vbox));
+    refresh();
+    strategy_combo.set_active(Some(0));
+    update_chosen();
+    window.show_all();
+}
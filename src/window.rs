@@ -356,6 +356,48 @@ impl WindowInner {
         {
             *self.application().runtime.project.borrow_mut() = project.clone();
         }
+        project.watch();
+        let window = self.instance();
+        project.connect_local(
+            Project::GLYPH_CHANGED_ON_DISK,
+            false,
+            clone!(@weak window => @default-return None, move |v: &[gtk::glib::Value]| {
+                let project = v[0].get::<Project>().unwrap();
+                let layer = v[1].get::<ufo::objects::Layer>().unwrap();
+                let name = v[2].get::<String>().unwrap();
+                let dialog = gtk::MessageDialog::builder()
+                    .attached_to(&window)
+                    .transient_for(&window)
+                    .modal(true)
+                    .buttons(gtk::ButtonsType::None)
+                    .message_type(gtk::MessageType::Question)
+                    .text(&format!(
+                        "Glyph <i>{}</i> was changed by another program.",
+                        glib::markup_escape_text(&name)
+                    ))
+                    .use_markup(true)
+                    .secondary_text("It also has unsaved changes in this window. Saving will overwrite the version on disk.")
+                    .title("Glyph changed on disk")
+                    .build();
+                dialog.add_button("Keep my changes", gtk::ResponseType::Reject);
+                dialog.add_button("Reload from disk", gtk::ResponseType::Accept);
+                let reload = dialog.run() == gtk::ResponseType::Accept;
+                dialog.emit_close();
+                if reload {
+                    if let Err(err) = project.reload_glyph(&layer, &name) {
+                        let dialog = crate::utils::widgets::new_simple_error_dialog(
+                            Some("Error: Could not reload glyph"),
+                            &err.to_string(),
+                            None,
+                            window.upcast_ref(),
+                        );
+                        dialog.run();
+                        dialog.emit_close();
+                    }
+                }
+                None
+            }),
+        );
         self.notebook.foreach(|tab| {
             self.notebook.remove(tab);
         });
@@ -381,6 +423,44 @@ impl WindowInner {
         );
     }
 
+    #[cfg(feature = "git")]
+    pub fn open_git_tab(&self) {
+        let app = self.application();
+        let project = app.runtime.project.borrow().clone();
+        let repository = match &*project.repository.borrow() {
+            Ok(Some(repository)) => repository.clone(),
+            Ok(None) => {
+                let dialog = crate::utils::widgets::new_simple_info_dialog(
+                    Some("Project is not in a git repository"),
+                    "Version control is only available for projects inside a git working directory.",
+                    None,
+                    self.instance().upcast_ref(),
+                );
+                dialog.run();
+                dialog.emit_close();
+                return;
+            }
+            Err(err) => {
+                let dialog = crate::utils::widgets::new_simple_error_dialog(
+                    Some("Error: Could not open git repository"),
+                    &err.to_string(),
+                    None,
+                    self.instance().upcast_ref(),
+                );
+                dialog.run();
+                dialog.emit_close();
+                return;
+            }
+        };
+        let git_space = crate::git::GitSpace::new(app, project, repository);
+        add_tab(
+            &self.notebook,
+            Workspace::new(git_space.upcast_ref::<gtk::Widget>()).upcast_ref::<gtk::Widget>(),
+            true,
+            true,
+        );
+    }
+
     pub fn unload_project(&self) {
         self.headerbar.set_subtitle(None);
         self.notebook.queue_draw();
@@ -0,0 +1,57 @@
+/*
+ * gerb
+ *
+ * Copyright 2022 - Manos Pitsidianakis
+ *
+ * This file is part of gerb.
+ *
+ * gerb is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * gerb is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with gerb. If not, see <http://www.gnu.org/licenses/>.
+*/
+
+mod utils;
+use utils::*;
+
+#[test]
+fn test_placeholders_save() {
+    use gerb::project::Project;
+
+    glib_test_wrapper(|| {
+        let path = std::env::temp_dir().join(format!("gerb-placeholders-{}", std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        let project = Project::create(&path).unwrap();
+        let mut names = vec![];
+        for c in ['a', 'A', 'é', 'ǆ', '\u{2126}'] {
+            let glyph = project.new_placeholder_glyph(c).unwrap().unwrap();
+            names.push(glyph.borrow().name().to_string());
+        }
+        assert!(project.new_placeholder_glyph('a').unwrap().is_none());
+        project.save().unwrap();
+
+        let project = Project::from_path(&path).unwrap();
+        let glyphs = project.default_layer.glyphs();
+        for (name, (filename, unicode)) in names.iter().zip([
+            ("a.glif", "0061"),
+            ("A_.glif", "0041"),
+            ("eacute.glif", "00E9"),
+            ("uni01C_6.glif", "01C6"),
+            ("O_mega.glif", "2126"),
+        ]) {
+            let glyph = glyphs[name].borrow();
+            assert_eq!(&*glyph.metadata.filename(), filename);
+            assert_eq!(glyph.metadata.unicode.borrow()[0].hex(), unicode);
+        }
+        drop(glyphs);
+        std::fs::remove_dir_all(&path).unwrap();
+    })
+}